@@ -12,7 +12,10 @@ CREATE TABLE connector_events_queue (
     `latency` UInt128,
     `method` LowCardinality(String),
     `dispute_id` Nullable(String),
-    `refund_id` Nullable(String)
+    `refund_id` Nullable(String),
+    `merchant_connector_id` Nullable(String),
+    `error_code` Nullable(String),
+    `is_timeout` Bool
 ) ENGINE = Kafka SETTINGS kafka_broker_list = 'kafka0:29092',
 kafka_topic_list = 'hyperswitch-outgoing-connector-events',
 kafka_group_name = 'hyper',
@@ -56,6 +59,9 @@ CREATE TABLE connector_events (
     `method` LowCardinality(String),
     `dispute_id` Nullable(String),
     `refund_id` Nullable(String),
+    `merchant_connector_id` Nullable(String),
+    `error_code` Nullable(String),
+    `is_timeout` Bool DEFAULT false,
     INDEX flowIndex flow TYPE bloom_filter GRANULARITY 1,
     INDEX connectorIndex connector_name TYPE bloom_filter GRANULARITY 1,
     INDEX statusIndex status_code TYPE bloom_filter GRANULARITY 1
@@ -150,7 +156,10 @@ CREATE MATERIALIZED VIEW connector_events_mv TO connector_events (
     `latency` UInt128,
     `method` LowCardinality(String),
     `refund_id` Nullable(String),
-    `dispute_id` Nullable(String)
+    `dispute_id` Nullable(String),
+    `merchant_connector_id` Nullable(String),
+    `error_code` Nullable(String),
+    `is_timeout` Bool
 ) AS
 SELECT
     merchant_id,
@@ -168,7 +177,10 @@ SELECT
     latency,
     method,
     refund_id,
-    dispute_id
+    dispute_id,
+    merchant_connector_id,
+    error_code,
+    is_timeout
 FROM
     connector_events_queue
 WHERE
@@ -15,6 +15,7 @@ use super::{
     payments::{
         distribution::PaymentDistributionRow, filters::PaymentFilterRow, metrics::PaymentMetricRow,
     },
+    payouts::{filters::PayoutFilterRow, metrics::PayoutMetricRow},
     query::{Aggregate, ToSql, Window},
     refunds::{filters::RefundFilterRow, metrics::RefundMetricRow},
     sdk_events::{filters::SdkEventFilter, metrics::SdkEventMetricRow},
@@ -26,7 +27,10 @@ use crate::{
         filters::ApiEventFilter,
         metrics::{latency::LatencyAvg, ApiEventMetricRow},
     },
-    connector_events::events::ConnectorEventsResult,
+    connector_events::{
+        events::ConnectorEventsResult, filters::ConnectorEventFilterRow,
+        metrics::ConnectorEventMetricRow,
+    },
     disputes::{filters::DisputeFilterRow, metrics::DisputeMetricRow},
     outgoing_webhook_event::events::OutgoingWebhookLogsResult,
     sdk_events::events::SdkEventsResult,
@@ -136,13 +140,14 @@ impl AnalyticsDataSource for ClickhouseClient {
             | AnalyticsCollection::FraudCheck
             | AnalyticsCollection::PaymentIntent
             | AnalyticsCollection::PaymentIntentSessionized
-            | AnalyticsCollection::Dispute => {
-                TableEngine::CollapsingMergeTree { sign: "sign_flag" }
-            }
+            | AnalyticsCollection::Dispute
+            | AnalyticsCollection::PaymentRecovery
+            | AnalyticsCollection::Payout => TableEngine::CollapsingMergeTree { sign: "sign_flag" },
             AnalyticsCollection::SdkEvents
             | AnalyticsCollection::SdkEventsAnalytics
             | AnalyticsCollection::ApiEvents
             | AnalyticsCollection::ConnectorEvents
+            | AnalyticsCollection::ConnectorEventsAnalytics
             | AnalyticsCollection::ApiEventsAnalytics
             | AnalyticsCollection::OutgoingWebhookEvent
             | AnalyticsCollection::ActivePaymentsAnalytics => TableEngine::BasicTree,
@@ -178,12 +183,16 @@ impl super::api_event::events::ApiLogsFilterAnalytics for ClickhouseClient {}
 impl super::api_event::filters::ApiEventFilterAnalytics for ClickhouseClient {}
 impl super::api_event::metrics::ApiEventMetricAnalytics for ClickhouseClient {}
 impl super::connector_events::events::ConnectorEventLogAnalytics for ClickhouseClient {}
+impl super::connector_events::filters::ConnectorEventFilterAnalytics for ClickhouseClient {}
+impl super::connector_events::metrics::ConnectorEventMetricAnalytics for ClickhouseClient {}
 impl super::outgoing_webhook_event::events::OutgoingWebhookLogsFilterAnalytics
     for ClickhouseClient
 {
 }
 impl super::disputes::filters::DisputeFilterAnalytics for ClickhouseClient {}
 impl super::disputes::metrics::DisputeMetricAnalytics for ClickhouseClient {}
+impl super::payouts::filters::PayoutFilterAnalytics for ClickhouseClient {}
+impl super::payouts::metrics::PayoutMetricAnalytics for ClickhouseClient {}
 
 #[derive(Debug, serde::Serialize)]
 struct CkhQuery {
@@ -227,6 +236,26 @@ impl TryInto<ConnectorEventsResult> for serde_json::Value {
     }
 }
 
+impl TryInto<ConnectorEventMetricRow> for serde_json::Value {
+    type Error = Report<ParsingError>;
+
+    fn try_into(self) -> Result<ConnectorEventMetricRow, Self::Error> {
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse ConnectorEventMetricRow in clickhouse results",
+        ))
+    }
+}
+
+impl TryInto<ConnectorEventFilterRow> for serde_json::Value {
+    type Error = Report<ParsingError>;
+
+    fn try_into(self) -> Result<ConnectorEventFilterRow, Self::Error> {
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse ConnectorEventFilterRow in clickhouse results",
+        ))
+    }
+}
+
 impl TryInto<PaymentMetricRow> for serde_json::Value {
     type Error = Report<ParsingError>;
 
@@ -297,6 +326,26 @@ impl TryInto<RefundFilterRow> for serde_json::Value {
     }
 }
 
+impl TryInto<PayoutMetricRow> for serde_json::Value {
+    type Error = Report<ParsingError>;
+
+    fn try_into(self) -> Result<PayoutMetricRow, Self::Error> {
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse PayoutMetricRow in clickhouse results",
+        ))
+    }
+}
+
+impl TryInto<PayoutFilterRow> for serde_json::Value {
+    type Error = Report<ParsingError>;
+
+    fn try_into(self) -> Result<PayoutFilterRow, Self::Error> {
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse PayoutFilterRow in clickhouse results",
+        ))
+    }
+}
+
 impl TryInto<FrmMetricRow> for serde_json::Value {
     type Error = Report<ParsingError>;
 
@@ -437,9 +486,13 @@ impl ToSql<ClickhouseClient> for AnalyticsCollection {
             Self::PaymentIntent => Ok("payment_intents".to_string()),
             Self::PaymentIntentSessionized => Ok("sessionizer_payment_intents".to_string()),
             Self::ConnectorEvents => Ok("connector_events_audit".to_string()),
+            Self::ConnectorEventsAnalytics => Ok("connector_events".to_string()),
             Self::OutgoingWebhookEvent => Ok("outgoing_webhook_events_audit".to_string()),
             Self::Dispute => Ok("dispute".to_string()),
+            Self::PaymentRecovery => Err(error_stack::report!(ParsingError::UnknownError)
+                .attach_printable("PaymentRecovery table is not implemented for Clickhouse"))?,
             Self::ActivePaymentsAnalytics => Ok("active_payments".to_string()),
+            Self::Payout => Ok("payout".to_string()),
         }
     }
 }
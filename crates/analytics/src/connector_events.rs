@@ -1,5 +1,11 @@
+pub mod accumulator;
 mod core;
 pub mod events;
+pub mod filters;
+pub mod metrics;
+pub mod types;
 pub trait ConnectorEventAnalytics: events::ConnectorEventLogAnalytics {}
 
-pub use self::core::connector_events_core;
+pub use accumulator::{ConnectorEventMetricAccumulator, ConnectorEventMetricsAccumulator};
+
+pub use self::core::{connector_events_core, get_filters, get_metrics};
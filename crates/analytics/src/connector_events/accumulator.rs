@@ -0,0 +1,177 @@
+use api_models::analytics::connector_events::ConnectorEventMetricsBucketValue;
+
+use super::metrics::ConnectorEventMetricRow;
+
+#[derive(Debug, Default)]
+pub struct ConnectorEventMetricsAccumulator {
+    pub request_count: CountAccumulator,
+    pub latency: LatencyAccumulator,
+    pub error_count: CountAccumulator,
+    pub timeout_rate: TimeoutRateAccumulator,
+}
+
+#[derive(Debug, Default)]
+#[repr(transparent)]
+pub struct CountAccumulator {
+    pub count: Option<i64>,
+}
+
+/// Percentiles cannot be combined across rows, so the first set of percentiles seen for a bucket
+/// is retained.
+#[derive(Debug, Default)]
+pub struct LatencyAccumulator {
+    pub p50: Option<u64>,
+    pub p95: Option<u64>,
+    pub p99: Option<u64>,
+}
+
+#[derive(Debug, Default)]
+pub struct TimeoutRateAccumulator {
+    pub timeouts: i64,
+    pub total: i64,
+}
+
+pub trait ConnectorEventMetricAccumulator {
+    type MetricOutput;
+
+    fn add_metrics_bucket(&mut self, metrics: &ConnectorEventMetricRow);
+
+    fn collect(self) -> Self::MetricOutput;
+}
+
+impl ConnectorEventMetricAccumulator for CountAccumulator {
+    type MetricOutput = Option<u64>;
+    #[inline]
+    fn add_metrics_bucket(&mut self, metrics: &ConnectorEventMetricRow) {
+        self.count = match (self.count, metrics.count) {
+            (None, None) => None,
+            (None, i @ Some(_)) | (i @ Some(_), None) => i,
+            (Some(a), Some(b)) => Some(a + b),
+        }
+    }
+    #[inline]
+    fn collect(self) -> Self::MetricOutput {
+        self.count.and_then(|i| u64::try_from(i).ok())
+    }
+}
+
+impl ConnectorEventMetricAccumulator for LatencyAccumulator {
+    type MetricOutput = (Option<u64>, Option<u64>, Option<u64>);
+    #[inline]
+    fn add_metrics_bucket(&mut self, metrics: &ConnectorEventMetricRow) {
+        self.p50 = self.p50.or(metrics.p50_latency);
+        self.p95 = self.p95.or(metrics.p95_latency);
+        self.p99 = self.p99.or(metrics.p99_latency);
+    }
+    #[inline]
+    fn collect(self) -> Self::MetricOutput {
+        (self.p50, self.p95, self.p99)
+    }
+}
+
+impl ConnectorEventMetricAccumulator for TimeoutRateAccumulator {
+    type MetricOutput = Option<f64>;
+
+    fn add_metrics_bucket(&mut self, metrics: &ConnectorEventMetricRow) {
+        self.timeouts += metrics.timeout_count.unwrap_or_default();
+        self.total += metrics.count.unwrap_or_default();
+    }
+
+    fn collect(self) -> Self::MetricOutput {
+        if self.total <= 0 {
+            None
+        } else {
+            Some(
+                f64::from(u32::try_from(self.timeouts).ok()?) * 100.0
+                    / f64::from(u32::try_from(self.total).ok()?),
+            )
+        }
+    }
+}
+
+impl ConnectorEventMetricsAccumulator {
+    pub fn collect(self) -> ConnectorEventMetricsBucketValue {
+        let (p50_latency, p95_latency, p99_latency) = self.latency.collect();
+        ConnectorEventMetricsBucketValue {
+            connector_request_count: self.request_count.collect(),
+            p50_latency,
+            p95_latency,
+            p99_latency,
+            connector_error_count: self.error_count.collect(),
+            connector_timeout_rate: self.timeout_rate.collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_metric_row(
+        count: Option<i64>,
+        timeout_count: Option<i64>,
+        latencies: Option<(u64, u64, u64)>,
+    ) -> ConnectorEventMetricRow {
+        ConnectorEventMetricRow {
+            connector_name: Some("stripe".to_string()),
+            flow: Some("Authorize".to_string()),
+            merchant_connector_id: None,
+            status_code: None,
+            error_code: None,
+            count,
+            timeout_count,
+            p50_latency: latencies.map(|(p50, _, _)| p50),
+            p95_latency: latencies.map(|(_, p95, _)| p95),
+            p99_latency: latencies.map(|(_, _, p99)| p99),
+            start_bucket: None,
+            end_bucket: None,
+        }
+    }
+
+    #[test]
+    fn test_count_accumulator_adds_counts_of_rows() {
+        let mut accumulator = CountAccumulator::default();
+        accumulator.add_metrics_bucket(&get_metric_row(None, None, None));
+        assert_eq!(accumulator.count, None);
+
+        accumulator.add_metrics_bucket(&get_metric_row(Some(10), None, None));
+        accumulator.add_metrics_bucket(&get_metric_row(Some(5), None, None));
+        assert_eq!(accumulator.collect(), Some(15));
+    }
+
+    #[test]
+    fn test_latency_accumulator_retains_first_percentiles() {
+        let mut accumulator = LatencyAccumulator::default();
+        accumulator.add_metrics_bucket(&get_metric_row(Some(1), None, None));
+        accumulator.add_metrics_bucket(&get_metric_row(Some(1), None, Some((120, 480, 950))));
+        accumulator.add_metrics_bucket(&get_metric_row(Some(1), None, Some((200, 600, 1200))));
+        assert_eq!(accumulator.collect(), (Some(120), Some(480), Some(950)));
+    }
+
+    #[test]
+    fn test_timeout_rate_accumulator_computes_percentage_of_timeouts() {
+        assert_eq!(TimeoutRateAccumulator::default().collect(), None);
+
+        let mut accumulator = TimeoutRateAccumulator::default();
+        accumulator.add_metrics_bucket(&get_metric_row(Some(6), Some(1), None));
+        accumulator.add_metrics_bucket(&get_metric_row(Some(2), None, None));
+        assert_eq!(accumulator.collect(), Some(12.5));
+    }
+
+    #[test]
+    fn test_connector_event_metrics_are_collected() {
+        let mut accumulator = ConnectorEventMetricsAccumulator::default();
+        let row = get_metric_row(Some(4), Some(1), Some((100, 300, 500)));
+        accumulator.request_count.add_metrics_bucket(&row);
+        accumulator.latency.add_metrics_bucket(&row);
+        accumulator.timeout_rate.add_metrics_bucket(&row);
+
+        let value = accumulator.collect();
+        assert_eq!(value.connector_request_count, Some(4));
+        assert_eq!(value.p50_latency, Some(100));
+        assert_eq!(value.p95_latency, Some(300));
+        assert_eq!(value.p99_latency, Some(500));
+        assert_eq!(value.connector_error_count, None);
+        assert_eq!(value.connector_timeout_rate, Some(25.0));
+    }
+}
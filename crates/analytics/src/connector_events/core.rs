@@ -1,9 +1,32 @@
-use api_models::analytics::connector_events::ConnectorEventsRequest;
+use std::collections::HashMap;
+
+use api_models::analytics::{
+    connector_events::{
+        ConnectorEventDimensions, ConnectorEventMetrics, ConnectorEventMetricsBucketIdentifier,
+        ConnectorEventMetricsBucketResponse, ConnectorEventsRequest,
+    },
+    AnalyticsMetadata, ConnectorEventFilterValue, ConnectorEventFiltersResponse,
+    GetConnectorEventFiltersRequest, GetConnectorEventMetricRequest, MetricsResponse,
+};
 use common_utils::errors::ReportSwitchExt;
 use error_stack::ResultExt;
+use router_env::{
+    instrument, logger,
+    metrics::add_attributes,
+    tracing::{self, Instrument},
+};
 
-use super::events::{get_connector_events, ConnectorEventsResult};
-use crate::{errors::AnalyticsResult, types::FiltersError, AnalyticsProvider};
+use super::{
+    events::{get_connector_events, ConnectorEventsResult},
+    filters::{get_connector_event_filter_for_dimension, ConnectorEventFilterRow},
+    ConnectorEventMetricAccumulator, ConnectorEventMetricsAccumulator,
+};
+use crate::{
+    errors::{AnalyticsError, AnalyticsResult},
+    metrics,
+    types::FiltersError,
+    AnalyticsProvider,
+};
 
 pub async fn connector_events_core(
     pool: &AnalyticsProvider,
@@ -24,3 +47,144 @@ pub async fn connector_events_core(
     .switch()?;
     Ok(data)
 }
+
+#[instrument(skip_all)]
+pub async fn get_metrics(
+    pool: &AnalyticsProvider,
+    merchant_id: &common_utils::id_type::MerchantId,
+    req: GetConnectorEventMetricRequest,
+) -> AnalyticsResult<MetricsResponse<ConnectorEventMetricsBucketResponse>> {
+    let mut metrics_accumulator: HashMap<
+        ConnectorEventMetricsBucketIdentifier,
+        ConnectorEventMetricsAccumulator,
+    > = HashMap::new();
+    let mut set = tokio::task::JoinSet::new();
+    for metric_type in req.metrics.iter().cloned() {
+        let req = req.clone();
+        let pool = pool.clone();
+        let task_span = tracing::debug_span!(
+            "analytics_connector_event_query",
+            connector_event_metric = metric_type.as_ref()
+        );
+        // Currently JoinSet works with only static lifetime references even if the task pool does not outlive the given reference
+        // We can optimize away this clone once that is fixed
+        let merchant_id_scoped = merchant_id.to_owned();
+        set.spawn(
+            async move {
+                let data = pool
+                    .get_connector_event_metrics(
+                        &metric_type,
+                        &req.group_by_names.clone(),
+                        &merchant_id_scoped,
+                        &req.filters,
+                        &req.time_series.map(|t| t.granularity),
+                        &req.time_range,
+                    )
+                    .await
+                    .change_context(AnalyticsError::UnknownError);
+                (metric_type, data)
+            }
+            .instrument(task_span),
+        );
+    }
+
+    while let Some((metric, data)) = set
+        .join_next()
+        .await
+        .transpose()
+        .change_context(AnalyticsError::UnknownError)?
+    {
+        let data = data?;
+        let attributes = &add_attributes([
+            ("metric_type", metric.to_string()),
+            ("source", pool.to_string()),
+        ]);
+
+        let value = u64::try_from(data.len());
+        if let Ok(val) = value {
+            metrics::BUCKETS_FETCHED.record(&metrics::CONTEXT, val, attributes);
+            logger::debug!("Attributes: {:?}, Buckets fetched: {}", attributes, val);
+        }
+
+        for (id, value) in data {
+            logger::debug!(bucket_id=?id, bucket_value=?value, "Bucket row for metric {metric}");
+            let metrics_builder = metrics_accumulator.entry(id).or_default();
+            match metric {
+                ConnectorEventMetrics::ConnectorRequestCount => {
+                    metrics_builder.request_count.add_metrics_bucket(&value)
+                }
+                ConnectorEventMetrics::ConnectorLatency => {
+                    metrics_builder.latency.add_metrics_bucket(&value)
+                }
+                ConnectorEventMetrics::ConnectorErrorCount => {
+                    metrics_builder.error_count.add_metrics_bucket(&value)
+                }
+                ConnectorEventMetrics::ConnectorTimeoutRate => {
+                    metrics_builder.timeout_rate.add_metrics_bucket(&value)
+                }
+            }
+        }
+
+        logger::debug!(
+            "Analytics Accumulated Results: metric: {}, results: {:#?}",
+            metric,
+            metrics_accumulator
+        );
+    }
+    let query_data: Vec<ConnectorEventMetricsBucketResponse> = metrics_accumulator
+        .into_iter()
+        .map(|(id, val)| ConnectorEventMetricsBucketResponse {
+            values: val.collect(),
+            dimensions: id,
+        })
+        .collect();
+
+    Ok(MetricsResponse {
+        query_data,
+        meta_data: [AnalyticsMetadata {
+            current_time_range: req.time_range,
+        }],
+    })
+}
+
+pub async fn get_filters(
+    pool: &AnalyticsProvider,
+    req: GetConnectorEventFiltersRequest,
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> AnalyticsResult<ConnectorEventFiltersResponse> {
+    let mut res = ConnectorEventFiltersResponse::default();
+    for dim in req.group_by_names {
+        let values = match pool {
+            AnalyticsProvider::Sqlx(_pool) => Err(FiltersError::NotImplemented(
+                "Connector Events not implemented for SQLX",
+            ))
+            .attach_printable("SQL Analytics is not implemented for Connector Events"),
+            AnalyticsProvider::Clickhouse(ckh_pool)
+            | AnalyticsProvider::CombinedSqlx(_, ckh_pool)
+            | AnalyticsProvider::CombinedCkh(_, ckh_pool) => {
+                get_connector_event_filter_for_dimension(
+                    dim,
+                    merchant_id,
+                    &req.time_range,
+                    ckh_pool,
+                )
+                .await
+            }
+        }
+        .switch()?
+        .into_iter()
+        .filter_map(|fil: ConnectorEventFilterRow| match dim {
+            ConnectorEventDimensions::ConnectorName => fil.connector_name,
+            ConnectorEventDimensions::Flow => fil.flow,
+            ConnectorEventDimensions::MerchantConnectorId => fil.merchant_connector_id,
+            ConnectorEventDimensions::StatusCode => fil.status_code.map(|i| i.to_string()),
+            ConnectorEventDimensions::ErrorCode => fil.error_code,
+        })
+        .collect::<Vec<String>>();
+        res.query_data.push(ConnectorEventFilterValue {
+            dimension: dim,
+            values,
+        })
+    }
+    Ok(res)
+}
@@ -0,0 +1,56 @@
+use api_models::analytics::{connector_events::ConnectorEventDimensions, Granularity, TimeRange};
+use common_utils::errors::ReportSwitchExt;
+use error_stack::ResultExt;
+use time::PrimitiveDateTime;
+
+use crate::{
+    query::{Aggregate, GroupByClause, QueryBuilder, QueryFilter, ToSql, Window},
+    types::{AnalyticsCollection, AnalyticsDataSource, FiltersError, FiltersResult, LoadRow},
+};
+
+pub trait ConnectorEventFilterAnalytics: LoadRow<ConnectorEventFilterRow> {}
+
+pub async fn get_connector_event_filter_for_dimension<T>(
+    dimension: ConnectorEventDimensions,
+    merchant_id: &common_utils::id_type::MerchantId,
+    time_range: &TimeRange,
+    pool: &T,
+) -> FiltersResult<Vec<ConnectorEventFilterRow>>
+where
+    T: AnalyticsDataSource + ConnectorEventFilterAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    let mut query_builder: QueryBuilder<T> =
+        QueryBuilder::new(AnalyticsCollection::ConnectorEventsAnalytics);
+
+    query_builder.add_select_column(dimension).switch()?;
+    time_range
+        .set_filter_clause(&mut query_builder)
+        .attach_printable("Error filtering time range")
+        .switch()?;
+
+    query_builder
+        .add_filter_clause("merchant_id", merchant_id)
+        .switch()?;
+
+    query_builder.set_distinct();
+
+    query_builder
+        .execute_query::<ConnectorEventFilterRow, _>(pool)
+        .await
+        .change_context(FiltersError::QueryBuildingError)?
+        .change_context(FiltersError::QueryExecutionFailure)
+}
+
+#[derive(Debug, serde::Serialize, Eq, PartialEq, serde::Deserialize)]
+pub struct ConnectorEventFilterRow {
+    pub connector_name: Option<String>,
+    pub flow: Option<String>,
+    pub merchant_connector_id: Option<String>,
+    pub status_code: Option<u16>,
+    pub error_code: Option<String>,
+}
@@ -0,0 +1,147 @@
+use api_models::analytics::{
+    connector_events::{
+        ConnectorEventDimensions, ConnectorEventFilters, ConnectorEventMetrics,
+        ConnectorEventMetricsBucketIdentifier,
+    },
+    Granularity, TimeRange,
+};
+use time::PrimitiveDateTime;
+
+mod connector_error_count;
+mod connector_latency;
+mod connector_request_count;
+mod connector_timeout_rate;
+use std::collections::HashSet;
+
+use connector_error_count::ConnectorErrorCount;
+use connector_latency::ConnectorLatency;
+use connector_request_count::ConnectorRequestCount;
+use connector_timeout_rate::ConnectorTimeoutRate;
+
+use crate::{
+    query::{Aggregate, GroupByClause, ToSql, Window},
+    types::{AnalyticsCollection, AnalyticsDataSource, LoadRow, MetricsResult},
+};
+
+#[derive(Debug, Eq, PartialEq, serde::Deserialize, Hash)]
+pub struct ConnectorEventMetricRow {
+    pub connector_name: Option<String>,
+    pub flow: Option<String>,
+    pub merchant_connector_id: Option<String>,
+    pub status_code: Option<u16>,
+    pub error_code: Option<String>,
+    pub count: Option<i64>,
+    pub timeout_count: Option<i64>,
+    pub p50_latency: Option<u64>,
+    pub p95_latency: Option<u64>,
+    pub p99_latency: Option<u64>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub start_bucket: Option<PrimitiveDateTime>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub end_bucket: Option<PrimitiveDateTime>,
+}
+
+pub trait ConnectorEventMetricAnalytics: LoadRow<ConnectorEventMetricRow> {}
+
+#[async_trait::async_trait]
+pub trait ConnectorEventMetric<T>
+where
+    T: AnalyticsDataSource + ConnectorEventMetricAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    async fn load_metrics(
+        &self,
+        dimensions: &[ConnectorEventDimensions],
+        merchant_id: &common_utils::id_type::MerchantId,
+        filters: &ConnectorEventFilters,
+        granularity: &Option<Granularity>,
+        time_range: &TimeRange,
+        pool: &T,
+    ) -> MetricsResult<
+        HashSet<(
+            ConnectorEventMetricsBucketIdentifier,
+            ConnectorEventMetricRow,
+        )>,
+    >;
+}
+
+#[async_trait::async_trait]
+impl<T> ConnectorEventMetric<T> for ConnectorEventMetrics
+where
+    T: AnalyticsDataSource + ConnectorEventMetricAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    async fn load_metrics(
+        &self,
+        dimensions: &[ConnectorEventDimensions],
+        merchant_id: &common_utils::id_type::MerchantId,
+        filters: &ConnectorEventFilters,
+        granularity: &Option<Granularity>,
+        time_range: &TimeRange,
+        pool: &T,
+    ) -> MetricsResult<
+        HashSet<(
+            ConnectorEventMetricsBucketIdentifier,
+            ConnectorEventMetricRow,
+        )>,
+    > {
+        match self {
+            Self::ConnectorRequestCount => {
+                ConnectorRequestCount::default()
+                    .load_metrics(
+                        dimensions,
+                        merchant_id,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                    .await
+            }
+            Self::ConnectorLatency => {
+                ConnectorLatency::default()
+                    .load_metrics(
+                        dimensions,
+                        merchant_id,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                    .await
+            }
+            Self::ConnectorErrorCount => {
+                ConnectorErrorCount::default()
+                    .load_metrics(
+                        dimensions,
+                        merchant_id,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                    .await
+            }
+            Self::ConnectorTimeoutRate => {
+                ConnectorTimeoutRate::default()
+                    .load_metrics(
+                        dimensions,
+                        merchant_id,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                    .await
+            }
+        }
+    }
+}
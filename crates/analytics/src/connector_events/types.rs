@@ -0,0 +1,53 @@
+use api_models::analytics::connector_events::{ConnectorEventDimensions, ConnectorEventFilters};
+use error_stack::ResultExt;
+
+use crate::{
+    query::{QueryBuilder, QueryFilter, QueryResult, ToSql},
+    types::{AnalyticsCollection, AnalyticsDataSource},
+};
+
+impl<T> QueryFilter<T> for ConnectorEventFilters
+where
+    T: AnalyticsDataSource,
+    AnalyticsCollection: ToSql<T>,
+{
+    fn set_filter_clause(&self, builder: &mut QueryBuilder<T>) -> QueryResult<()> {
+        if !self.connector_name.is_empty() {
+            builder
+                .add_filter_in_range_clause(
+                    ConnectorEventDimensions::ConnectorName,
+                    &self.connector_name,
+                )
+                .attach_printable("Error adding connector name filter")?;
+        }
+
+        if !self.flow.is_empty() {
+            builder
+                .add_filter_in_range_clause(ConnectorEventDimensions::Flow, &self.flow)
+                .attach_printable("Error adding flow filter")?;
+        }
+
+        if !self.merchant_connector_id.is_empty() {
+            builder
+                .add_filter_in_range_clause(
+                    ConnectorEventDimensions::MerchantConnectorId,
+                    &self.merchant_connector_id,
+                )
+                .attach_printable("Error adding merchant connector id filter")?;
+        }
+
+        if !self.status_code.is_empty() {
+            builder
+                .add_filter_in_range_clause(ConnectorEventDimensions::StatusCode, &self.status_code)
+                .attach_printable("Error adding status code filter")?;
+        }
+
+        if !self.error_code.is_empty() {
+            builder
+                .add_filter_in_range_clause(ConnectorEventDimensions::ErrorCode, &self.error_code)
+                .attach_printable("Error adding error code filter")?;
+        }
+
+        Ok(())
+    }
+}
@@ -46,6 +46,16 @@ pub async fn get_domain_info(
             download_dimensions: None,
             dimensions: utils::get_dispute_dimensions(),
         },
+        AnalyticsDomain::Payouts => GetInfoResponse {
+            metrics: utils::get_payout_metrics_info(),
+            download_dimensions: None,
+            dimensions: utils::get_payout_dimensions(),
+        },
+        AnalyticsDomain::ConnectorEvents => GetInfoResponse {
+            metrics: utils::get_connector_event_metrics_info(),
+            download_dimensions: None,
+            dimensions: utils::get_connector_event_dimensions(),
+        },
     };
     Ok(info)
 }
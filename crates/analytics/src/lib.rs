@@ -13,7 +13,9 @@ pub mod metrics;
 pub mod opensearch;
 pub mod outgoing_webhook_event;
 pub mod payment_intents;
+pub mod payment_recovery;
 pub mod payments;
+pub mod payouts;
 mod query;
 pub mod refunds;
 pub mod sdk_events;
@@ -41,13 +43,22 @@ use api_models::analytics::{
         ApiEventDimensions, ApiEventFilters, ApiEventMetrics, ApiEventMetricsBucketIdentifier,
     },
     auth_events::{AuthEventMetrics, AuthEventMetricsBucketIdentifier},
+    connector_events::{
+        ConnectorEventDimensions, ConnectorEventFilters, ConnectorEventMetrics,
+        ConnectorEventMetricsBucketIdentifier,
+    },
     disputes::{DisputeDimensions, DisputeFilters, DisputeMetrics, DisputeMetricsBucketIdentifier},
     frm::{FrmDimensions, FrmFilters, FrmMetrics, FrmMetricsBucketIdentifier},
     payment_intents::{
         PaymentIntentDimensions, PaymentIntentFilters, PaymentIntentMetrics,
         PaymentIntentMetricsBucketIdentifier,
     },
+    payment_recovery::{
+        PaymentRecoveryDimensions, PaymentRecoveryFilters, PaymentRecoveryMetrics,
+        PaymentRecoveryMetricsBucketIdentifier,
+    },
     payments::{PaymentDimensions, PaymentFilters, PaymentMetrics, PaymentMetricsBucketIdentifier},
+    payouts::{PayoutDimensions, PayoutFilters, PayoutMetrics, PayoutMetricsBucketIdentifier},
     refunds::{RefundDimensions, RefundFilters, RefundMetrics, RefundMetricsBucketIdentifier},
     sdk_events::{
         SdkEventDimensions, SdkEventFilters, SdkEventMetrics, SdkEventMetricsBucketIdentifier,
@@ -68,12 +79,15 @@ use strum::Display;
 use self::{
     active_payments::metrics::{ActivePaymentsMetric, ActivePaymentsMetricRow},
     auth_events::metrics::{AuthEventMetric, AuthEventMetricRow},
+    connector_events::metrics::{ConnectorEventMetric, ConnectorEventMetricRow},
     frm::metrics::{FrmMetric, FrmMetricRow},
     payment_intents::metrics::{PaymentIntentMetric, PaymentIntentMetricRow},
+    payment_recovery::metrics::{PaymentRecoveryMetric, PaymentRecoveryMetricRow},
     payments::{
         distribution::{PaymentDistribution, PaymentDistributionRow},
         metrics::{PaymentMetric, PaymentMetricRow},
     },
+    payouts::metrics::{PayoutMetric, PayoutMetricRow},
     refunds::metrics::{RefundMetric, RefundMetricRow},
     sdk_events::metrics::{SdkEventMetric, SdkEventMetricRow},
     sqlx::SqlxClient,
@@ -728,6 +742,69 @@ impl AnalyticsProvider {
         .await
     }
 
+    pub async fn get_payment_recovery_metrics(
+        &self,
+        metric: &PaymentRecoveryMetrics,
+        dimensions: &[PaymentRecoveryDimensions],
+        auth: &AuthInfo,
+        filters: &PaymentRecoveryFilters,
+        granularity: &Option<Granularity>,
+        time_range: &TimeRange,
+    ) -> types::MetricsResult<
+        HashSet<(
+            PaymentRecoveryMetricsBucketIdentifier,
+            PaymentRecoveryMetricRow,
+        )>,
+    > {
+        // Recovery records are only stored in postgres, hence clickhouse is never queried
+        metrics::request::record_operation_time(
+            async {
+                match self {
+                    Self::Sqlx(pool) | Self::CombinedCkh(pool, _) | Self::CombinedSqlx(pool, _) => {
+                        metric
+                            .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
+                            .await
+                    }
+                    Self::Clickhouse(_pool) => Err(report!(MetricsError::NotImplemented)),
+                }
+            },
+            &metrics::METRIC_FETCH_TIME,
+            metric,
+            self,
+        )
+        .await
+    }
+
+    pub async fn get_payout_metrics(
+        &self,
+        metric: &PayoutMetrics,
+        dimensions: &[PayoutDimensions],
+        auth: &AuthInfo,
+        filters: &PayoutFilters,
+        granularity: &Option<Granularity>,
+        time_range: &TimeRange,
+    ) -> types::MetricsResult<HashSet<(PayoutMetricsBucketIdentifier, PayoutMetricRow)>> {
+        // Payout events are only stored in clickhouse, hence postgres is never queried
+        metrics::request::record_operation_time(
+            async {
+                match self {
+                    Self::Sqlx(_pool) => Err(report!(MetricsError::NotImplemented)),
+                    Self::Clickhouse(pool)
+                    | Self::CombinedCkh(_, pool)
+                    | Self::CombinedSqlx(_, pool) => {
+                        metric
+                            .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
+                            .await
+                    }
+                }
+            },
+            &metrics::METRIC_FETCH_TIME,
+            metric,
+            self,
+        )
+        .await
+    }
+
     pub async fn get_sdk_event_metrics(
         &self,
         metric: &SdkEventMetrics,
@@ -853,6 +930,48 @@ impl AnalyticsProvider {
         }
     }
 
+    pub async fn get_connector_event_metrics(
+        &self,
+        metric: &ConnectorEventMetrics,
+        dimensions: &[ConnectorEventDimensions],
+        merchant_id: &common_utils::id_type::MerchantId,
+        filters: &ConnectorEventFilters,
+        granularity: &Option<Granularity>,
+        time_range: &TimeRange,
+    ) -> types::MetricsResult<
+        HashSet<(
+            ConnectorEventMetricsBucketIdentifier,
+            ConnectorEventMetricRow,
+        )>,
+    > {
+        // Connector events are only stored in clickhouse, hence postgres is never queried
+        metrics::request::record_operation_time(
+            async {
+                match self {
+                    Self::Sqlx(_pool) => Err(report!(MetricsError::NotImplemented)),
+                    Self::Clickhouse(ckh_pool)
+                    | Self::CombinedCkh(_, ckh_pool)
+                    | Self::CombinedSqlx(_, ckh_pool) => {
+                        metric
+                            .load_metrics(
+                                dimensions,
+                                merchant_id,
+                                filters,
+                                granularity,
+                                time_range,
+                                ckh_pool,
+                            )
+                            .await
+                    }
+                }
+            },
+            &metrics::METRIC_FETCH_TIME,
+            metric,
+            self,
+        )
+        .await
+    }
+
     pub async fn from_conf(
         config: &AnalyticsConfig,
         tenant: &dyn storage_impl::config::TenantConfig,
@@ -991,12 +1110,26 @@ pub enum AnalyticsFlow {
     GetApiEventMetrics,
     GetApiEventFilters,
     GetConnectorEvents,
+    GetConnectorEventMetrics,
+    GetConnectorEventFilters,
     GetOutgoingWebhookEvents,
     GetGlobalSearchResults,
     GetSearchResults,
     GetDisputeFilters,
     GetDisputeMetrics,
+    GetPaymentRecoveryMetrics,
+    GetPayoutMetrics,
+    GetPayoutFilters,
     GetSankey,
+    CreateReportSchedule,
+    RetrieveReportSchedule,
+    ListReportSchedules,
+    UpdateReportSchedule,
+    DeleteReportSchedule,
+    CreateExport,
+    ListExports,
+    RetrieveExport,
+    DownloadExport,
 }
 
 impl FlowMetric for AnalyticsFlow {}
@@ -0,0 +1,8 @@
+pub mod accumulators;
+mod core;
+pub mod metrics;
+pub mod types;
+pub use accumulators::{PaymentRecoveryMetricAccumulator, PaymentRecoveryMetricsAccumulator};
+
+pub trait PaymentRecoveryAnalytics: metrics::PaymentRecoveryMetricAnalytics {}
+pub use self::core::get_metrics;
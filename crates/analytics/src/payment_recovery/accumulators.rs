@@ -0,0 +1,102 @@
+use api_models::analytics::payment_recovery::PaymentRecoveryMetricsBucketValue;
+use diesel_models::enums as storage_enums;
+
+use super::metrics::PaymentRecoveryMetricRow;
+#[derive(Debug, Default)]
+pub struct PaymentRecoveryMetricsAccumulator {
+    pub payment_recovery_status: StatusCountAccumulator,
+    pub total_recovered_amount: SumAccumulator,
+    pub total_lost_amount: SumAccumulator,
+}
+#[derive(Debug, Default)]
+pub struct StatusCountAccumulator {
+    pub scheduled_count: i64,
+    pub recovered_count: i64,
+    pub lost_count: i64,
+    pub total: i64,
+}
+#[derive(Debug, Default)]
+#[repr(transparent)]
+pub struct SumAccumulator {
+    pub total: Option<i64>,
+}
+
+pub trait PaymentRecoveryMetricAccumulator {
+    type MetricOutput;
+
+    fn add_metrics_bucket(&mut self, metrics: &PaymentRecoveryMetricRow);
+
+    fn collect(self) -> Self::MetricOutput;
+}
+
+impl PaymentRecoveryMetricAccumulator for SumAccumulator {
+    type MetricOutput = Option<u64>;
+    #[inline]
+    fn add_metrics_bucket(&mut self, metrics: &PaymentRecoveryMetricRow) {
+        self.total = match (
+            self.total,
+            metrics
+                .total
+                .as_ref()
+                .and_then(bigdecimal::ToPrimitive::to_i64),
+        ) {
+            (None, None) => None,
+            (None, i @ Some(_)) | (i @ Some(_), None) => i,
+            (Some(a), Some(b)) => Some(a + b),
+        }
+    }
+    #[inline]
+    fn collect(self) -> Self::MetricOutput {
+        self.total.and_then(|i| u64::try_from(i).ok())
+    }
+}
+
+impl PaymentRecoveryMetricAccumulator for StatusCountAccumulator {
+    type MetricOutput = Option<(Option<u64>, Option<u64>, Option<u64>, Option<u64>)>;
+
+    fn add_metrics_bucket(&mut self, metrics: &PaymentRecoveryMetricRow) {
+        if let Some(ref status) = metrics.status {
+            match status.as_ref() {
+                storage_enums::PaymentRecoveryStatus::Scheduled => {
+                    self.scheduled_count += metrics.count.unwrap_or_default()
+                }
+                storage_enums::PaymentRecoveryStatus::Recovered => {
+                    self.recovered_count += metrics.count.unwrap_or_default()
+                }
+                storage_enums::PaymentRecoveryStatus::Lost => {
+                    self.lost_count += metrics.count.unwrap_or_default()
+                }
+            }
+        };
+
+        self.total += metrics.count.unwrap_or_default();
+    }
+
+    fn collect(self) -> Self::MetricOutput {
+        if self.total <= 0 {
+            Some((None, None, None, None))
+        } else {
+            Some((
+                u64::try_from(self.scheduled_count).ok(),
+                u64::try_from(self.recovered_count).ok(),
+                u64::try_from(self.lost_count).ok(),
+                u64::try_from(self.total).ok(),
+            ))
+        }
+    }
+}
+
+impl PaymentRecoveryMetricsAccumulator {
+    pub fn collect(self) -> PaymentRecoveryMetricsBucketValue {
+        let (payments_scheduled, payments_recovered, payments_lost, total_payment_recovery) =
+            self.payment_recovery_status.collect().unwrap_or_default();
+        PaymentRecoveryMetricsBucketValue {
+            payments_scheduled,
+            payments_recovered,
+            payments_lost,
+            total_recovered_amount: self.total_recovered_amount.collect(),
+            total_lost_amount: self.total_lost_amount.collect(),
+            total_payment_recovery,
+        }
+    }
+}
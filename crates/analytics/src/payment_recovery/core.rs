@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+
+use api_models::analytics::{
+    payment_recovery::{
+        PaymentRecoveryMetrics, PaymentRecoveryMetricsBucketIdentifier,
+        PaymentRecoveryMetricsBucketResponse,
+    },
+    AnalyticsMetadata, GetPaymentRecoveryMetricRequest, MetricsResponse,
+};
+use error_stack::ResultExt;
+use router_env::{
+    logger,
+    metrics::add_attributes,
+    tracing::{self, Instrument},
+};
+
+use super::PaymentRecoveryMetricsAccumulator;
+use crate::{
+    enums::AuthInfo,
+    errors::{AnalyticsError, AnalyticsResult},
+    metrics,
+    payment_recovery::PaymentRecoveryMetricAccumulator,
+    AnalyticsProvider,
+};
+
+pub async fn get_metrics(
+    pool: &AnalyticsProvider,
+    auth: &AuthInfo,
+    req: GetPaymentRecoveryMetricRequest,
+) -> AnalyticsResult<MetricsResponse<PaymentRecoveryMetricsBucketResponse>> {
+    let mut metrics_accumulator: HashMap<
+        PaymentRecoveryMetricsBucketIdentifier,
+        PaymentRecoveryMetricsAccumulator,
+    > = HashMap::new();
+    let mut set = tokio::task::JoinSet::new();
+    for metric_type in req.metrics.iter().cloned() {
+        let req = req.clone();
+        let pool = pool.clone();
+        let task_span = tracing::debug_span!(
+            "analytics_payment_recovery_query",
+            payment_recovery_metric = metric_type.as_ref()
+        );
+        // Currently JoinSet works with only static lifetime references even if the task pool does not outlive the given reference
+        // We can optimize away this clone once that is fixed
+        let auth_scoped = auth.to_owned();
+        set.spawn(
+            async move {
+                let data = pool
+                    .get_payment_recovery_metrics(
+                        &metric_type,
+                        &req.group_by_names.clone(),
+                        &auth_scoped,
+                        &req.filters,
+                        &req.time_series.map(|t| t.granularity),
+                        &req.time_range,
+                    )
+                    .await
+                    .change_context(AnalyticsError::UnknownError);
+                (metric_type, data)
+            }
+            .instrument(task_span),
+        );
+    }
+
+    while let Some((metric, data)) = set
+        .join_next()
+        .await
+        .transpose()
+        .change_context(AnalyticsError::UnknownError)?
+    {
+        let data = data?;
+        let attributes = &add_attributes([
+            ("metric_type", metric.to_string()),
+            ("source", pool.to_string()),
+        ]);
+
+        let value = u64::try_from(data.len());
+        if let Ok(val) = value {
+            metrics::BUCKETS_FETCHED.record(&metrics::CONTEXT, val, attributes);
+            logger::debug!("Attributes: {:?}, Buckets fetched: {}", attributes, val);
+        }
+
+        for (id, value) in data {
+            logger::debug!(bucket_id=?id, bucket_value=?value, "Bucket row for metric {metric}");
+            let metrics_builder = metrics_accumulator.entry(id).or_default();
+            match metric {
+                PaymentRecoveryMetrics::PaymentRecoveryStatusMetric => metrics_builder
+                    .payment_recovery_status
+                    .add_metrics_bucket(&value),
+                PaymentRecoveryMetrics::TotalRecoveredAmount => metrics_builder
+                    .total_recovered_amount
+                    .add_metrics_bucket(&value),
+                PaymentRecoveryMetrics::TotalLostAmount => {
+                    metrics_builder.total_lost_amount.add_metrics_bucket(&value)
+                }
+            }
+        }
+
+        logger::debug!(
+            "Analytics Accumulated Results: metric: {}, results: {:#?}",
+            metric,
+            metrics_accumulator
+        );
+    }
+    let query_data: Vec<PaymentRecoveryMetricsBucketResponse> = metrics_accumulator
+        .into_iter()
+        .map(|(id, val)| PaymentRecoveryMetricsBucketResponse {
+            values: val.collect(),
+            dimensions: id,
+        })
+        .collect();
+
+    Ok(MetricsResponse {
+        query_data,
+        meta_data: [AnalyticsMetadata {
+            current_time_range: req.time_range,
+        }],
+    })
+}
@@ -0,0 +1,111 @@
+mod payment_recovery_status_metric;
+mod total_lost_amount;
+mod total_recovered_amount;
+
+use std::collections::HashSet;
+
+use api_models::analytics::{
+    payment_recovery::{
+        PaymentRecoveryDimensions, PaymentRecoveryFilters, PaymentRecoveryMetrics,
+        PaymentRecoveryMetricsBucketIdentifier,
+    },
+    Granularity,
+};
+use common_utils::types::TimeRange;
+use diesel_models::enums as storage_enums;
+use time::PrimitiveDateTime;
+
+use self::{
+    payment_recovery_status_metric::PaymentRecoveryStatusMetric,
+    total_lost_amount::TotalLostAmount, total_recovered_amount::TotalRecoveredAmount,
+};
+use crate::{
+    enums::AuthInfo,
+    query::{Aggregate, GroupByClause, ToSql, Window},
+    types::{AnalyticsCollection, AnalyticsDataSource, DBEnumWrapper, LoadRow, MetricsResult},
+};
+#[derive(Debug, Eq, PartialEq, serde::Deserialize, Hash)]
+pub struct PaymentRecoveryMetricRow {
+    pub connector: Option<String>,
+    pub currency: Option<DBEnumWrapper<storage_enums::Currency>>,
+    pub decline_category: Option<String>,
+    pub status: Option<DBEnumWrapper<storage_enums::PaymentRecoveryStatus>>,
+    pub total: Option<bigdecimal::BigDecimal>,
+    pub count: Option<i64>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub start_bucket: Option<PrimitiveDateTime>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub end_bucket: Option<PrimitiveDateTime>,
+}
+
+pub trait PaymentRecoveryMetricAnalytics: LoadRow<PaymentRecoveryMetricRow> {}
+
+#[async_trait::async_trait]
+pub trait PaymentRecoveryMetric<T>
+where
+    T: AnalyticsDataSource + PaymentRecoveryMetricAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    async fn load_metrics(
+        &self,
+        dimensions: &[PaymentRecoveryDimensions],
+        auth: &AuthInfo,
+        filters: &PaymentRecoveryFilters,
+        granularity: &Option<Granularity>,
+        time_range: &TimeRange,
+        pool: &T,
+    ) -> MetricsResult<
+        HashSet<(
+            PaymentRecoveryMetricsBucketIdentifier,
+            PaymentRecoveryMetricRow,
+        )>,
+    >;
+}
+
+#[async_trait::async_trait]
+impl<T> PaymentRecoveryMetric<T> for PaymentRecoveryMetrics
+where
+    T: AnalyticsDataSource + PaymentRecoveryMetricAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    async fn load_metrics(
+        &self,
+        dimensions: &[PaymentRecoveryDimensions],
+        auth: &AuthInfo,
+        filters: &PaymentRecoveryFilters,
+        granularity: &Option<Granularity>,
+        time_range: &TimeRange,
+        pool: &T,
+    ) -> MetricsResult<
+        HashSet<(
+            PaymentRecoveryMetricsBucketIdentifier,
+            PaymentRecoveryMetricRow,
+        )>,
+    > {
+        match self {
+            Self::PaymentRecoveryStatusMetric => {
+                PaymentRecoveryStatusMetric::default()
+                    .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
+                    .await
+            }
+            Self::TotalRecoveredAmount => {
+                TotalRecoveredAmount::default()
+                    .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
+                    .await
+            }
+            Self::TotalLostAmount => {
+                TotalLostAmount::default()
+                    .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
+                    .await
+            }
+        }
+    }
+}
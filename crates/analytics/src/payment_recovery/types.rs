@@ -0,0 +1,38 @@
+use api_models::analytics::payment_recovery::{PaymentRecoveryDimensions, PaymentRecoveryFilters};
+use error_stack::ResultExt;
+
+use crate::{
+    query::{QueryBuilder, QueryFilter, QueryResult, ToSql},
+    types::{AnalyticsCollection, AnalyticsDataSource},
+};
+
+impl<T> QueryFilter<T> for PaymentRecoveryFilters
+where
+    T: AnalyticsDataSource,
+    AnalyticsCollection: ToSql<T>,
+{
+    fn set_filter_clause(&self, builder: &mut QueryBuilder<T>) -> QueryResult<()> {
+        if !self.connector.is_empty() {
+            builder
+                .add_filter_in_range_clause(PaymentRecoveryDimensions::Connector, &self.connector)
+                .attach_printable("Error adding connector filter")?;
+        }
+
+        if !self.currency.is_empty() {
+            builder
+                .add_filter_in_range_clause(PaymentRecoveryDimensions::Currency, &self.currency)
+                .attach_printable("Error adding currency filter")?;
+        }
+
+        if !self.decline_category.is_empty() {
+            builder
+                .add_filter_in_range_clause(
+                    PaymentRecoveryDimensions::DeclineCategory,
+                    &self.decline_category,
+                )
+                .attach_printable("Error adding decline category filter")?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,230 @@
+use api_models::analytics::payouts::PayoutMetricsBucketValue;
+use bigdecimal::ToPrimitive;
+use diesel_models::enums as storage_enums;
+use router_env::logger;
+
+use super::metrics::PayoutMetricRow;
+#[derive(Debug, Default)]
+pub struct PayoutMetricsAccumulator {
+    pub payout_count: CountAccumulator,
+    pub payout_success: CountAccumulator,
+    pub payout_success_rate: SuccessRateAccumulator,
+    pub processed_amount: SumAccumulator,
+    pub fulfilment_time: AverageAccumulator,
+}
+
+#[derive(Debug, Default)]
+pub struct SuccessRateAccumulator {
+    pub success: i64,
+    pub total: i64,
+}
+#[derive(Debug, Default)]
+#[repr(transparent)]
+pub struct CountAccumulator {
+    pub count: Option<i64>,
+}
+#[derive(Debug, Default)]
+#[repr(transparent)]
+pub struct SumAccumulator {
+    pub total: Option<i64>,
+}
+#[derive(Debug, Default)]
+pub struct AverageAccumulator {
+    pub total: u32,
+    pub count: u32,
+}
+
+pub trait PayoutMetricAccumulator {
+    type MetricOutput;
+
+    fn add_metrics_bucket(&mut self, metrics: &PayoutMetricRow);
+
+    fn collect(self) -> Self::MetricOutput;
+}
+
+impl PayoutMetricAccumulator for CountAccumulator {
+    type MetricOutput = Option<u64>;
+    #[inline]
+    fn add_metrics_bucket(&mut self, metrics: &PayoutMetricRow) {
+        self.count = match (self.count, metrics.count) {
+            (None, None) => None,
+            (None, i @ Some(_)) | (i @ Some(_), None) => i,
+            (Some(a), Some(b)) => Some(a + b),
+        }
+    }
+    #[inline]
+    fn collect(self) -> Self::MetricOutput {
+        self.count.and_then(|i| u64::try_from(i).ok())
+    }
+}
+
+impl PayoutMetricAccumulator for SumAccumulator {
+    type MetricOutput = Option<u64>;
+    #[inline]
+    fn add_metrics_bucket(&mut self, metrics: &PayoutMetricRow) {
+        self.total = match (
+            self.total,
+            metrics.total.as_ref().and_then(ToPrimitive::to_i64),
+        ) {
+            (None, None) => None,
+            (None, i @ Some(_)) | (i @ Some(_), None) => i,
+            (Some(a), Some(b)) => Some(a + b),
+        }
+    }
+    #[inline]
+    fn collect(self) -> Self::MetricOutput {
+        self.total.and_then(|i| u64::try_from(i).ok())
+    }
+}
+
+impl PayoutMetricAccumulator for SuccessRateAccumulator {
+    type MetricOutput = Option<f64>;
+
+    fn add_metrics_bucket(&mut self, metrics: &PayoutMetricRow) {
+        if let Some(ref status) = metrics.status {
+            if status.as_ref() == &storage_enums::PayoutStatus::Success {
+                self.success += metrics.count.unwrap_or_default();
+            }
+        };
+        self.total += metrics.count.unwrap_or_default();
+    }
+
+    fn collect(self) -> Self::MetricOutput {
+        if self.total <= 0 {
+            None
+        } else {
+            Some(
+                f64::from(u32::try_from(self.success).ok()?) * 100.0
+                    / f64::from(u32::try_from(self.total).ok()?),
+            )
+        }
+    }
+}
+
+impl PayoutMetricAccumulator for AverageAccumulator {
+    type MetricOutput = Option<f64>;
+
+    fn add_metrics_bucket(&mut self, metrics: &PayoutMetricRow) {
+        let total = metrics.total.as_ref().and_then(ToPrimitive::to_u32);
+        let count = metrics.count.and_then(|total| u32::try_from(total).ok());
+
+        match (total, count) {
+            (Some(total), Some(count)) => {
+                self.total += total;
+                self.count += count;
+            }
+            _ => {
+                logger::error!(message="Dropping metrics for average accumulator", metric=?metrics);
+            }
+        }
+    }
+
+    fn collect(self) -> Self::MetricOutput {
+        if self.count == 0 {
+            None
+        } else {
+            Some(f64::from(self.total) / f64::from(self.count))
+        }
+    }
+}
+
+impl PayoutMetricsAccumulator {
+    pub fn collect(self) -> PayoutMetricsBucketValue {
+        PayoutMetricsBucketValue {
+            payout_count: self.payout_count.collect(),
+            payout_success_count: self.payout_success.collect(),
+            payout_success_rate: self.payout_success_rate.collect(),
+            payout_processed_amount: self.processed_amount.collect(),
+            payout_fulfilment_time: self.fulfilment_time.collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::DBEnumWrapper;
+
+    fn get_metric_row(
+        status: Option<storage_enums::PayoutStatus>,
+        total: Option<i64>,
+        count: Option<i64>,
+    ) -> PayoutMetricRow {
+        PayoutMetricRow {
+            destination_currency: None,
+            payout_type: None,
+            connector: None,
+            status: status.map(DBEnumWrapper),
+            error_code: None,
+            profile_id: None,
+            total: total.map(bigdecimal::BigDecimal::from),
+            count,
+            start_bucket: None,
+            end_bucket: None,
+        }
+    }
+
+    #[test]
+    fn test_count_accumulator_adds_counts_of_rows() {
+        let mut accumulator = CountAccumulator::default();
+        accumulator.add_metrics_bucket(&get_metric_row(None, None, None));
+        assert_eq!(accumulator.count, None);
+
+        accumulator.add_metrics_bucket(&get_metric_row(None, None, Some(3)));
+        accumulator.add_metrics_bucket(&get_metric_row(None, None, Some(4)));
+        assert_eq!(accumulator.collect(), Some(7));
+    }
+
+    #[test]
+    fn test_success_rate_accumulator_counts_successful_payouts() {
+        let mut accumulator = SuccessRateAccumulator::default();
+        assert_eq!(SuccessRateAccumulator::default().collect(), None);
+
+        accumulator.add_metrics_bucket(&get_metric_row(
+            Some(storage_enums::PayoutStatus::Success),
+            None,
+            Some(3),
+        ));
+        accumulator.add_metrics_bucket(&get_metric_row(
+            Some(storage_enums::PayoutStatus::Failed),
+            None,
+            Some(1),
+        ));
+        accumulator.add_metrics_bucket(&get_metric_row(None, None, None));
+        assert_eq!(accumulator.collect(), Some(75.0));
+    }
+
+    #[test]
+    fn test_fulfilment_time_is_averaged_over_payouts() {
+        let mut accumulator = AverageAccumulator::default();
+        assert_eq!(AverageAccumulator::default().collect(), None);
+
+        // 2 payouts fulfilled in 100 seconds in total, and 3 payouts in 350 seconds in total
+        accumulator.add_metrics_bucket(&get_metric_row(None, Some(100), Some(2)));
+        accumulator.add_metrics_bucket(&get_metric_row(None, Some(350), Some(3)));
+        // Rows without a total or count are dropped
+        accumulator.add_metrics_bucket(&get_metric_row(None, None, Some(5)));
+        assert_eq!(accumulator.collect(), Some(90.0));
+    }
+
+    #[test]
+    fn test_payout_metrics_are_collected() {
+        let mut accumulator = PayoutMetricsAccumulator::default();
+        let row = get_metric_row(
+            Some(storage_enums::PayoutStatus::Success),
+            Some(500),
+            Some(2),
+        );
+        accumulator.payout_count.add_metrics_bucket(&row);
+        accumulator.payout_success.add_metrics_bucket(&row);
+        accumulator.payout_success_rate.add_metrics_bucket(&row);
+        accumulator.processed_amount.add_metrics_bucket(&row);
+
+        let value = accumulator.collect();
+        assert_eq!(value.payout_count, Some(2));
+        assert_eq!(value.payout_success_count, Some(2));
+        assert_eq!(value.payout_success_rate, Some(100.0));
+        assert_eq!(value.payout_processed_amount, Some(500));
+        assert_eq!(value.payout_fulfilment_time, None);
+    }
+}
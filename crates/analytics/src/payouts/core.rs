@@ -0,0 +1,166 @@
+use std::collections::HashMap;
+
+use api_models::analytics::{
+    payouts::{
+        PayoutDimensions, PayoutMetrics, PayoutMetricsBucketIdentifier, PayoutMetricsBucketResponse,
+    },
+    AnalyticsMetadata, GetPayoutFilterRequest, GetPayoutMetricRequest, MetricsResponse,
+    PayoutFilterValue, PayoutFiltersResponse,
+};
+use error_stack::ResultExt;
+use router_env::{
+    logger,
+    metrics::add_attributes,
+    tracing::{self, Instrument},
+};
+
+use super::{
+    filters::{get_payout_filter_for_dimension, PayoutFilterRow},
+    PayoutMetricsAccumulator,
+};
+use crate::{
+    enums::AuthInfo,
+    errors::{AnalyticsError, AnalyticsResult},
+    metrics,
+    payouts::PayoutMetricAccumulator,
+    types::FiltersError,
+    AnalyticsProvider,
+};
+
+pub async fn get_metrics(
+    pool: &AnalyticsProvider,
+    auth: &AuthInfo,
+    req: GetPayoutMetricRequest,
+) -> AnalyticsResult<MetricsResponse<PayoutMetricsBucketResponse>> {
+    let mut metrics_accumulator: HashMap<PayoutMetricsBucketIdentifier, PayoutMetricsAccumulator> =
+        HashMap::new();
+    let mut set = tokio::task::JoinSet::new();
+    for metric_type in req.metrics.iter().cloned() {
+        let req = req.clone();
+        let pool = pool.clone();
+        let task_span = tracing::debug_span!(
+            "analytics_payout_query",
+            payout_metric = metric_type.as_ref()
+        );
+        // Currently JoinSet works with only static lifetime references even if the task pool does not outlive the given reference
+        // We can optimize away this clone once that is fixed
+        let auth_scoped = auth.to_owned();
+        set.spawn(
+            async move {
+                let data = pool
+                    .get_payout_metrics(
+                        &metric_type,
+                        &req.group_by_names.clone(),
+                        &auth_scoped,
+                        &req.filters,
+                        &req.time_series.map(|t| t.granularity),
+                        &req.time_range,
+                    )
+                    .await
+                    .change_context(AnalyticsError::UnknownError);
+                (metric_type, data)
+            }
+            .instrument(task_span),
+        );
+    }
+
+    while let Some((metric, data)) = set
+        .join_next()
+        .await
+        .transpose()
+        .change_context(AnalyticsError::UnknownError)?
+    {
+        let data = data?;
+        let attributes = &add_attributes([
+            ("metric_type", metric.to_string()),
+            ("source", pool.to_string()),
+        ]);
+
+        let value = u64::try_from(data.len());
+        if let Ok(val) = value {
+            metrics::BUCKETS_FETCHED.record(&metrics::CONTEXT, val, attributes);
+            logger::debug!("Attributes: {:?}, Buckets fetched: {}", attributes, val);
+        }
+
+        for (id, value) in data {
+            logger::debug!(bucket_id=?id, bucket_value=?value, "Bucket row for metric {metric}");
+            let metrics_builder = metrics_accumulator.entry(id).or_default();
+            match metric {
+                PayoutMetrics::PayoutCount => {
+                    metrics_builder.payout_count.add_metrics_bucket(&value)
+                }
+                PayoutMetrics::PayoutSuccessCount => {
+                    metrics_builder.payout_success.add_metrics_bucket(&value)
+                }
+                PayoutMetrics::PayoutSuccessRate => metrics_builder
+                    .payout_success_rate
+                    .add_metrics_bucket(&value),
+                PayoutMetrics::PayoutProcessedAmount => {
+                    metrics_builder.processed_amount.add_metrics_bucket(&value)
+                }
+                PayoutMetrics::PayoutFulfilmentTime => {
+                    metrics_builder.fulfilment_time.add_metrics_bucket(&value)
+                }
+            }
+        }
+
+        logger::debug!(
+            "Analytics Accumulated Results: metric: {}, results: {:#?}",
+            metric,
+            metrics_accumulator
+        );
+    }
+    let query_data: Vec<PayoutMetricsBucketResponse> = metrics_accumulator
+        .into_iter()
+        .map(|(id, val)| PayoutMetricsBucketResponse {
+            values: val.collect(),
+            dimensions: id,
+        })
+        .collect();
+
+    Ok(MetricsResponse {
+        query_data,
+        meta_data: [AnalyticsMetadata {
+            current_time_range: req.time_range,
+        }],
+    })
+}
+
+pub async fn get_filters(
+    pool: &AnalyticsProvider,
+    req: GetPayoutFilterRequest,
+    auth: &AuthInfo,
+) -> AnalyticsResult<PayoutFiltersResponse> {
+    let mut res = PayoutFiltersResponse::default();
+    for dim in req.group_by_names {
+        let values = match pool {
+            AnalyticsProvider::Sqlx(_pool) => Err(FiltersError::NotImplemented(
+                "Payout analytics not implemented for SQLX",
+            ))
+            .attach_printable("SQL Analytics is not implemented for Payouts"),
+            AnalyticsProvider::Clickhouse(pool) => {
+                get_payout_filter_for_dimension(dim, auth, &req.time_range, pool).await
+            }
+            AnalyticsProvider::CombinedCkh(_sqlx_pool, ckh_pool)
+            | AnalyticsProvider::CombinedSqlx(_sqlx_pool, ckh_pool) => {
+                get_payout_filter_for_dimension(dim, auth, &req.time_range, ckh_pool).await
+            }
+        }
+        .change_context(AnalyticsError::UnknownError)?
+        .into_iter()
+        .filter_map(|fil: PayoutFilterRow| match dim {
+            PayoutDimensions::Currency => fil.destination_currency.map(|i| i.as_ref().to_string()),
+            PayoutDimensions::PayoutType => fil.payout_type.map(|i| i.as_ref().to_string()),
+            PayoutDimensions::Connector => fil.connector,
+            PayoutDimensions::PayoutStatus => fil.status.map(|i| i.as_ref().to_string()),
+            PayoutDimensions::ErrorCode => fil.error_code,
+            PayoutDimensions::ProfileId => fil.profile_id,
+        })
+        .collect::<Vec<String>>();
+        res.query_data.push(PayoutFilterValue {
+            dimension: dim,
+            values,
+        })
+    }
+    Ok(res)
+}
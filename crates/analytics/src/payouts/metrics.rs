@@ -0,0 +1,112 @@
+use api_models::analytics::{
+    payouts::{PayoutDimensions, PayoutFilters, PayoutMetrics, PayoutMetricsBucketIdentifier},
+    Granularity, TimeRange,
+};
+use diesel_models::enums as storage_enums;
+use time::PrimitiveDateTime;
+mod payout_count;
+mod payout_fulfilment_time;
+mod payout_processed_amount;
+mod payout_success_count;
+mod payout_success_rate;
+use std::collections::HashSet;
+
+use payout_count::PayoutCount;
+use payout_fulfilment_time::PayoutFulfilmentTime;
+use payout_processed_amount::PayoutProcessedAmount;
+use payout_success_count::PayoutSuccessCount;
+use payout_success_rate::PayoutSuccessRate;
+
+use crate::{
+    enums::AuthInfo,
+    query::{Aggregate, GroupByClause, ToSql, Window},
+    types::{AnalyticsCollection, AnalyticsDataSource, DBEnumWrapper, LoadRow, MetricsResult},
+};
+
+#[derive(Debug, Eq, PartialEq, serde::Deserialize, Hash)]
+pub struct PayoutMetricRow {
+    pub destination_currency: Option<DBEnumWrapper<storage_enums::Currency>>,
+    pub payout_type: Option<DBEnumWrapper<storage_enums::PayoutType>>,
+    pub connector: Option<String>,
+    pub status: Option<DBEnumWrapper<storage_enums::PayoutStatus>>,
+    pub error_code: Option<String>,
+    pub profile_id: Option<String>,
+    pub total: Option<bigdecimal::BigDecimal>,
+    pub count: Option<i64>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub start_bucket: Option<PrimitiveDateTime>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub end_bucket: Option<PrimitiveDateTime>,
+}
+
+pub trait PayoutMetricAnalytics: LoadRow<PayoutMetricRow> {}
+
+#[async_trait::async_trait]
+pub trait PayoutMetric<T>
+where
+    T: AnalyticsDataSource + PayoutMetricAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    async fn load_metrics(
+        &self,
+        dimensions: &[PayoutDimensions],
+        auth: &AuthInfo,
+        filters: &PayoutFilters,
+        granularity: &Option<Granularity>,
+        time_range: &TimeRange,
+        pool: &T,
+    ) -> MetricsResult<HashSet<(PayoutMetricsBucketIdentifier, PayoutMetricRow)>>;
+}
+
+#[async_trait::async_trait]
+impl<T> PayoutMetric<T> for PayoutMetrics
+where
+    T: AnalyticsDataSource + PayoutMetricAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    async fn load_metrics(
+        &self,
+        dimensions: &[PayoutDimensions],
+        auth: &AuthInfo,
+        filters: &PayoutFilters,
+        granularity: &Option<Granularity>,
+        time_range: &TimeRange,
+        pool: &T,
+    ) -> MetricsResult<HashSet<(PayoutMetricsBucketIdentifier, PayoutMetricRow)>> {
+        match self {
+            Self::PayoutCount => {
+                PayoutCount::default()
+                    .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
+                    .await
+            }
+            Self::PayoutSuccessCount => {
+                PayoutSuccessCount::default()
+                    .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
+                    .await
+            }
+            Self::PayoutSuccessRate => {
+                PayoutSuccessRate::default()
+                    .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
+                    .await
+            }
+            Self::PayoutProcessedAmount => {
+                PayoutProcessedAmount::default()
+                    .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
+                    .await
+            }
+            Self::PayoutFulfilmentTime => {
+                PayoutFulfilmentTime::default()
+                    .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
+                    .await
+            }
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use api_models::analytics::payouts::{PayoutDimensions, PayoutFilters};
+use error_stack::ResultExt;
+
+use crate::{
+    query::{QueryBuilder, QueryFilter, QueryResult, ToSql},
+    types::{AnalyticsCollection, AnalyticsDataSource},
+};
+
+impl<T> QueryFilter<T> for PayoutFilters
+where
+    T: AnalyticsDataSource,
+    AnalyticsCollection: ToSql<T>,
+{
+    fn set_filter_clause(&self, builder: &mut QueryBuilder<T>) -> QueryResult<()> {
+        if !self.currency.is_empty() {
+            builder
+                .add_filter_in_range_clause(PayoutDimensions::Currency, &self.currency)
+                .attach_printable("Error adding currency filter")?;
+        }
+
+        if !self.payout_type.is_empty() {
+            builder
+                .add_filter_in_range_clause(PayoutDimensions::PayoutType, &self.payout_type)
+                .attach_printable("Error adding payout type filter")?;
+        }
+
+        if !self.connector.is_empty() {
+            builder
+                .add_filter_in_range_clause(PayoutDimensions::Connector, &self.connector)
+                .attach_printable("Error adding connector filter")?;
+        }
+
+        if !self.status.is_empty() {
+            builder
+                .add_filter_in_range_clause(PayoutDimensions::PayoutStatus, &self.status)
+                .attach_printable("Error adding payout status filter")?;
+        }
+
+        if !self.error_code.is_empty() {
+            builder
+                .add_filter_in_range_clause(PayoutDimensions::ErrorCode, &self.error_code)
+                .attach_printable("Error adding error code filter")?;
+        }
+
+        if !self.profile_id.is_empty() {
+            builder
+                .add_filter_in_range_clause(PayoutDimensions::ProfileId, &self.profile_id)
+                .attach_printable("Error adding profile id filter")?;
+        }
+
+        Ok(())
+    }
+}
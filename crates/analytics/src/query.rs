@@ -5,17 +5,20 @@ use api_models::{
         self as analytics_api,
         api_event::ApiEventDimensions,
         auth_events::AuthEventFlows,
+        connector_events::ConnectorEventDimensions,
         disputes::DisputeDimensions,
         frm::{FrmDimensions, FrmTransactionType},
         payment_intents::PaymentIntentDimensions,
+        payment_recovery::PaymentRecoveryDimensions,
         payments::{PaymentDimensions, PaymentDistributions},
+        payouts::PayoutDimensions,
         refunds::{RefundDimensions, RefundType},
         sdk_events::{SdkEventDimensions, SdkEventNames},
         Granularity,
     },
     enums::{
         AttemptStatus, AuthenticationType, Connector, Currency, DisputeStage, IntentStatus,
-        PaymentMethod, PaymentMethodType,
+        PaymentMethod, PaymentMethodType, PayoutStatus, PayoutType,
     },
     refunds::RefundStatus,
 };
@@ -509,9 +512,17 @@ impl_to_sql_for_to_string!(
     AuthEventFlows,
     &ApiEventDimensions,
     ApiEventDimensions,
+    &ConnectorEventDimensions,
+    ConnectorEventDimensions,
     &DisputeDimensions,
     DisputeDimensions,
-    DisputeStage
+    DisputeStage,
+    &PaymentRecoveryDimensions,
+    PaymentRecoveryDimensions,
+    &PayoutDimensions,
+    PayoutDimensions,
+    PayoutStatus,
+    PayoutType
 );
 
 #[derive(Debug, Clone, Copy)]
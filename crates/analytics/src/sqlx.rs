@@ -10,7 +10,7 @@ use common_utils::{
 };
 use diesel_models::enums::{
     AttemptStatus, AuthenticationType, Currency, FraudCheckStatus, IntentStatus, PaymentMethod,
-    RefundStatus,
+    PaymentRecoveryStatus, RefundStatus,
 };
 use error_stack::ResultExt;
 use sqlx::{
@@ -96,6 +96,7 @@ db_type!(FraudCheckStatus);
 db_type!(FrmTransactionType);
 db_type!(DisputeStage);
 db_type!(DisputeStatus);
+db_type!(PaymentRecoveryStatus, TEXT);
 
 impl<'q, Type> Encode<'q, Postgres> for DBEnumWrapper<Type>
 where
@@ -156,6 +157,7 @@ impl super::refunds::metrics::RefundMetricAnalytics for SqlxClient {}
 impl super::refunds::filters::RefundFilterAnalytics for SqlxClient {}
 impl super::disputes::filters::DisputeFilterAnalytics for SqlxClient {}
 impl super::disputes::metrics::DisputeMetricAnalytics for SqlxClient {}
+impl super::payment_recovery::metrics::PaymentRecoveryMetricAnalytics for SqlxClient {}
 impl super::frm::metrics::FrmMetricAnalytics for SqlxClient {}
 impl super::frm::filters::FrmFilterAnalytics for SqlxClient {}
 
@@ -795,6 +797,55 @@ impl<'a> FromRow<'a, PgRow> for super::disputes::metrics::DisputeMetricRow {
     }
 }
 
+impl<'a> FromRow<'a, PgRow> for super::payment_recovery::metrics::PaymentRecoveryMetricRow {
+    fn from_row(row: &'a PgRow) -> sqlx::Result<Self> {
+        let connector: Option<String> = row.try_get("connector").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let currency: Option<DBEnumWrapper<Currency>> =
+            row.try_get("currency").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let decline_category: Option<String> =
+            row.try_get("decline_category").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let status: Option<DBEnumWrapper<PaymentRecoveryStatus>> =
+            row.try_get("status").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let total: Option<bigdecimal::BigDecimal> = row.try_get("total").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let count: Option<i64> = row.try_get("count").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        // Removing millisecond precision to get accurate diffs against clickhouse
+        let start_bucket: Option<PrimitiveDateTime> = row
+            .try_get::<Option<PrimitiveDateTime>, _>("start_bucket")?
+            .and_then(|dt| dt.replace_millisecond(0).ok());
+        let end_bucket: Option<PrimitiveDateTime> = row
+            .try_get::<Option<PrimitiveDateTime>, _>("end_bucket")?
+            .and_then(|dt| dt.replace_millisecond(0).ok());
+        Ok(Self {
+            connector,
+            currency,
+            decline_category,
+            status,
+            total,
+            count,
+            start_bucket,
+            end_bucket,
+        })
+    }
+}
+
 impl ToSql<SqlxClient> for PrimitiveDateTime {
     fn to_sql(&self, _table_engine: &TableEngine) -> error_stack::Result<String, ParsingError> {
         Ok(self.to_string())
@@ -822,8 +873,10 @@ impl ToSql<SqlxClient> for AnalyticsCollection {
                 ParsingError::UnknownError
             )
             .attach_printable("PaymentIntentSessionized table is not implemented for Sqlx"))?,
-            Self::ConnectorEvents => Err(error_stack::report!(ParsingError::UnknownError)
-                .attach_printable("ConnectorEvents table is not implemented for Sqlx"))?,
+            Self::ConnectorEvents | Self::ConnectorEventsAnalytics => {
+                Err(error_stack::report!(ParsingError::UnknownError)
+                    .attach_printable("ConnectorEvents table is not implemented for Sqlx"))?
+            }
             Self::ApiEventsAnalytics => Err(error_stack::report!(ParsingError::UnknownError)
                 .attach_printable("ApiEvents table is not implemented for Sqlx"))?,
             Self::ActivePaymentsAnalytics => Err(error_stack::report!(ParsingError::UnknownError)
@@ -831,6 +884,9 @@ impl ToSql<SqlxClient> for AnalyticsCollection {
             Self::OutgoingWebhookEvent => Err(error_stack::report!(ParsingError::UnknownError)
                 .attach_printable("OutgoingWebhookEvents table is not implemented for Sqlx"))?,
             Self::Dispute => Ok("dispute".to_string()),
+            Self::PaymentRecovery => Ok("payment_recovery".to_string()),
+            Self::Payout => Err(error_stack::report!(ParsingError::UnknownError)
+                .attach_printable("Payout table is not implemented for Sqlx"))?,
         }
     }
 }
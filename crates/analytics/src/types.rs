@@ -21,6 +21,8 @@ pub enum AnalyticsDomain {
     SdkEvents,
     ApiEvents,
     Dispute,
+    Payouts,
+    ConnectorEvents,
 }
 
 #[derive(Debug, strum::AsRefStr, strum::Display, Clone, Copy)]
@@ -36,10 +38,13 @@ pub enum AnalyticsCollection {
     PaymentIntent,
     PaymentIntentSessionized,
     ConnectorEvents,
+    ConnectorEventsAnalytics,
     OutgoingWebhookEvent,
     Dispute,
+    PaymentRecovery,
     ApiEventsAnalytics,
     ActivePaymentsAnalytics,
+    Payout,
 }
 
 #[allow(dead_code)]
@@ -1,10 +1,12 @@
 use api_models::analytics::{
     api_event::{ApiEventDimensions, ApiEventMetrics},
     auth_events::AuthEventMetrics,
+    connector_events::{ConnectorEventDimensions, ConnectorEventMetrics},
     disputes::{DisputeDimensions, DisputeMetrics},
     frm::{FrmDimensions, FrmMetrics},
     payment_intents::{PaymentIntentDimensions, PaymentIntentMetrics},
     payments::{PaymentDimensions, PaymentMetrics},
+    payouts::{PayoutDimensions, PayoutMetrics},
     refunds::{RefundDimensions, RefundMetrics},
     sdk_events::{SdkEventDimensions, SdkEventMetrics},
     NameDescription,
@@ -92,3 +94,19 @@ pub fn get_dispute_metrics_info() -> Vec<NameDescription> {
 pub fn get_dispute_dimensions() -> Vec<NameDescription> {
     DisputeDimensions::iter().map(Into::into).collect()
 }
+
+pub fn get_payout_metrics_info() -> Vec<NameDescription> {
+    PayoutMetrics::iter().map(Into::into).collect()
+}
+
+pub fn get_payout_dimensions() -> Vec<NameDescription> {
+    PayoutDimensions::iter().map(Into::into).collect()
+}
+
+pub fn get_connector_event_metrics_info() -> Vec<NameDescription> {
+    ConnectorEventMetrics::iter().map(Into::into).collect()
+}
+
+pub fn get_connector_event_dimensions() -> Vec<NameDescription> {
+    ConnectorEventDimensions::iter().map(Into::into).collect()
+}
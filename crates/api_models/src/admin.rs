@@ -1828,6 +1828,83 @@ pub struct ToggleKVRequest {
     pub kv_enabled: bool,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ToggleAccountUpdaterRequest {
+    #[serde(skip_deserializing)]
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// Whether the saved cards of the merchant's customers should be checked for updates
+    #[schema(example = true)]
+    pub enabled: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AccountUpdaterStatusResponse {
+    /// The identifier for the Merchant Account
+    #[schema(max_length = 255, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// Whether the saved cards of the merchant's customers are checked for updates
+    #[schema(example = true)]
+    pub enabled: bool,
+    /// Time at which the saved cards will be checked next
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub next_run_at: Option<time::PrimitiveDateTime>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct StorageSchemeMigrationRequest {
+    #[serde(skip_deserializing)]
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// The storage scheme the merchant account should be migrated to
+    #[schema(value_type = String, example = "redis_kv")]
+    pub storage_scheme: api_enums::MerchantStorageScheme,
+    /// Number of the merchant's KV entries to be compared against the database before switching.
+    /// Defaults to the configured sample size.
+    #[schema(example = 20)]
+    pub verification_sample_size: Option<u16>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct StorageSchemeMigrationResponse {
+    /// The identifier for the Merchant Account
+    #[schema(value_type = String, max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub merchant_id: id_type::MerchantId,
+    /// Current status of the migration
+    #[schema(value_type = StorageSchemeMigrationStatus, example = "draining")]
+    pub status: api_enums::StorageSchemeMigrationStatus,
+    /// The storage scheme of the merchant account when the migration was started
+    #[schema(value_type = String, example = "postgres_only")]
+    pub source_storage_scheme: api_enums::MerchantStorageScheme,
+    /// The storage scheme the merchant account is being migrated to
+    #[schema(value_type = String, example = "redis_kv")]
+    pub target_storage_scheme: api_enums::MerchantStorageScheme,
+    /// Number of the merchant's entries waiting to be drained to the database during the last check
+    #[schema(example = 0)]
+    pub pending_kv_entries: u64,
+    /// Number of KV entries that were compared against the database
+    #[schema(example = 20)]
+    pub sampled_keys: u16,
+    /// KV entries whose state differed from the database
+    pub mismatched_keys: Vec<String>,
+    /// Reason for the failure of the migration, if any
+    pub failure_reason: Option<String>,
+    /// Time at which the migration was started
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub started_at: time::PrimitiveDateTime,
+    /// Time at which the storage scheme of the merchant account was switched
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub switched_at: Option<time::PrimitiveDateTime>,
+    /// Time at which the migration was completed
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub completed_at: Option<time::PrimitiveDateTime>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ToggleAllKVRequest {
     /// Status of KV for the specific merchant
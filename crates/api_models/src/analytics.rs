@@ -8,10 +8,13 @@ use self::{
     active_payments::ActivePaymentsMetrics,
     api_event::{ApiEventDimensions, ApiEventMetrics},
     auth_events::AuthEventMetrics,
+    connector_events::{ConnectorEventDimensions, ConnectorEventMetrics},
     disputes::{DisputeDimensions, DisputeMetrics},
     frm::{FrmDimensions, FrmMetrics},
     payment_intents::{PaymentIntentDimensions, PaymentIntentMetrics},
+    payment_recovery::{PaymentRecoveryDimensions, PaymentRecoveryMetrics},
     payments::{PaymentDimensions, PaymentDistributions, PaymentMetrics},
+    payouts::{PayoutDimensions, PayoutMetrics},
     refunds::{RefundDimensions, RefundMetrics},
     sdk_events::{SdkEventDimensions, SdkEventMetrics},
 };
@@ -20,13 +23,18 @@ pub mod api_event;
 pub mod auth_events;
 pub mod connector_events;
 pub mod disputes;
+pub mod exports;
 pub mod frm;
 pub mod outgoing_webhook_event;
 pub mod payment_intents;
+pub mod payment_recovery;
 pub mod payments;
+pub mod payouts;
 pub mod refunds;
+pub mod report_schedules;
 pub mod sdk_events;
 pub mod search;
+pub mod success_rate_alerts;
 
 #[derive(Debug, serde::Serialize)]
 pub struct NameDescription {
@@ -146,6 +154,20 @@ pub struct GetRefundMetricRequest {
     pub delta: bool,
 }
 
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPayoutMetricRequest {
+    pub time_series: Option<TimeSeries>,
+    pub time_range: TimeRange,
+    #[serde(default)]
+    pub group_by_names: Vec<PayoutDimensions>,
+    #[serde(default)]
+    pub filters: payouts::PayoutFilters,
+    pub metrics: HashSet<PayoutMetrics>,
+    #[serde(default)]
+    pub delta: bool,
+}
+
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetFrmMetricRequest {
@@ -287,6 +309,27 @@ pub struct RefundFilterValue {
     pub values: Vec<String>,
 }
 
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPayoutFilterRequest {
+    pub time_range: TimeRange,
+    #[serde(default)]
+    pub group_by_names: Vec<PayoutDimensions>,
+}
+
+#[derive(Debug, Default, serde::Serialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PayoutFiltersResponse {
+    pub query_data: Vec<PayoutFilterValue>,
+}
+
+#[derive(Debug, serde::Serialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PayoutFilterValue {
+    pub dimension: PayoutDimensions,
+    pub values: Vec<String>,
+}
+
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetFrmFilterRequest {
@@ -391,6 +434,41 @@ pub struct GetApiEventMetricRequest {
     pub delta: bool,
 }
 
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetConnectorEventFiltersRequest {
+    pub time_range: TimeRange,
+    #[serde(default)]
+    pub group_by_names: Vec<ConnectorEventDimensions>,
+}
+
+#[derive(Debug, Default, serde::Serialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectorEventFiltersResponse {
+    pub query_data: Vec<ConnectorEventFilterValue>,
+}
+
+#[derive(Debug, serde::Serialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectorEventFilterValue {
+    pub dimension: ConnectorEventDimensions,
+    pub values: Vec<String>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetConnectorEventMetricRequest {
+    pub time_series: Option<TimeSeries>,
+    pub time_range: TimeRange,
+    #[serde(default)]
+    pub group_by_names: Vec<ConnectorEventDimensions>,
+    #[serde(default)]
+    pub filters: connector_events::ConnectorEventFilters,
+    pub metrics: HashSet<ConnectorEventMetrics>,
+    #[serde(default)]
+    pub delta: bool,
+}
+
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetDisputeFilterRequest {
@@ -427,6 +505,20 @@ pub struct GetDisputeMetricRequest {
     pub delta: bool,
 }
 
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPaymentRecoveryMetricRequest {
+    pub time_series: Option<TimeSeries>,
+    pub time_range: TimeRange,
+    #[serde(default)]
+    pub group_by_names: Vec<PaymentRecoveryDimensions>,
+    #[serde(default)]
+    pub filters: payment_recovery::PaymentRecoveryFilters,
+    pub metrics: HashSet<PaymentRecoveryMetrics>,
+    #[serde(default)]
+    pub delta: bool,
+}
+
 #[derive(Clone, Debug, Default, serde::Serialize)]
 #[serde(rename_all = "snake_case")]
 pub struct SankeyResponse {
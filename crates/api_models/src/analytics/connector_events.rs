@@ -1,6 +1,174 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
+use super::{NameDescription, TimeRange};
+
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
 pub struct ConnectorEventsRequest {
     pub payment_id: common_utils::id_type::PaymentId,
     pub refund_id: Option<String>,
     pub dispute_id: Option<String>,
 }
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+pub struct ConnectorEventFilters {
+    #[serde(default)]
+    pub connector_name: Vec<String>,
+    #[serde(default)]
+    pub flow: Vec<String>,
+    #[serde(default)]
+    pub merchant_connector_id: Vec<String>,
+    #[serde(default)]
+    pub status_code: Vec<u64>,
+    #[serde(default)]
+    pub error_code: Vec<String>,
+}
+
+#[derive(
+    Debug,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::AsRefStr,
+    PartialEq,
+    PartialOrd,
+    Eq,
+    Ord,
+    strum::Display,
+    strum::EnumIter,
+    Clone,
+    Copy,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ConnectorEventDimensions {
+    ConnectorName,
+    // The connector integration flow, such as `Authorize`, `Capture`, `PSync` or `Execute`
+    Flow,
+    MerchantConnectorId,
+    StatusCode,
+    ErrorCode,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Hash,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumIter,
+    strum::AsRefStr,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ConnectorEventMetrics {
+    ConnectorRequestCount,
+    ConnectorLatency,
+    ConnectorErrorCount,
+    ConnectorTimeoutRate,
+}
+
+pub mod metric_behaviour {
+    pub struct ConnectorRequestCount;
+    pub struct ConnectorLatency;
+    pub struct ConnectorErrorCount;
+    pub struct ConnectorTimeoutRate;
+}
+
+impl From<ConnectorEventMetrics> for NameDescription {
+    fn from(value: ConnectorEventMetrics) -> Self {
+        Self {
+            name: value.to_string(),
+            desc: String::new(),
+        }
+    }
+}
+
+impl From<ConnectorEventDimensions> for NameDescription {
+    fn from(value: ConnectorEventDimensions) -> Self {
+        Self {
+            name: value.to_string(),
+            desc: String::new(),
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize, Eq)]
+pub struct ConnectorEventMetricsBucketIdentifier {
+    pub connector_name: Option<String>,
+    pub flow: Option<String>,
+    pub merchant_connector_id: Option<String>,
+    pub status_code: Option<u16>,
+    pub error_code: Option<String>,
+    #[serde(rename = "time_range")]
+    pub time_bucket: TimeRange,
+    #[serde(rename = "time_bucket")]
+    #[serde(with = "common_utils::custom_serde::iso8601custom")]
+    pub start_time: time::PrimitiveDateTime,
+}
+
+impl Hash for ConnectorEventMetricsBucketIdentifier {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.connector_name.hash(state);
+        self.flow.hash(state);
+        self.merchant_connector_id.hash(state);
+        self.status_code.hash(state);
+        self.error_code.hash(state);
+        self.time_bucket.hash(state);
+    }
+}
+
+impl PartialEq for ConnectorEventMetricsBucketIdentifier {
+    fn eq(&self, other: &Self) -> bool {
+        let mut left = DefaultHasher::new();
+        self.hash(&mut left);
+        let mut right = DefaultHasher::new();
+        other.hash(&mut right);
+        left.finish() == right.finish()
+    }
+}
+
+impl ConnectorEventMetricsBucketIdentifier {
+    pub fn new(
+        connector_name: Option<String>,
+        flow: Option<String>,
+        merchant_connector_id: Option<String>,
+        status_code: Option<u16>,
+        error_code: Option<String>,
+        normalized_time_range: TimeRange,
+    ) -> Self {
+        Self {
+            connector_name,
+            flow,
+            merchant_connector_id,
+            status_code,
+            error_code,
+            time_bucket: normalized_time_range,
+            start_time: normalized_time_range.start_time,
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ConnectorEventMetricsBucketValue {
+    pub connector_request_count: Option<u64>,
+    /// Latencies of the connector API calls, in milliseconds
+    pub p50_latency: Option<u64>,
+    pub p95_latency: Option<u64>,
+    pub p99_latency: Option<u64>,
+    pub connector_error_count: Option<u64>,
+    /// Percentage of connector API calls which timed out
+    pub connector_timeout_rate: Option<f64>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ConnectorEventMetricsBucketResponse {
+    #[serde(flatten)]
+    pub values: ConnectorEventMetricsBucketValue,
+    #[serde(flatten)]
+    pub dimensions: ConnectorEventMetricsBucketIdentifier,
+}
@@ -0,0 +1,66 @@
+use common_enums::{AnalyticsReportDomain, ExportFormat, ExportStatus, ExportType};
+use common_utils::{
+    events::{ApiEventMetric, ApiEventsType},
+    id_type, pii,
+};
+use time::PrimitiveDateTime;
+
+#[derive(Clone, Debug, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ExportCreateRequest {
+    pub export_type: ExportType,
+    pub format: ExportFormat,
+    /// The filters of the export, in the same format as accepted by the list endpoint of the
+    /// export type, or the metrics request of `analytics_domain` for analytics metrics exports.
+    /// The `limit` and `offset` of list constraints are ignored, all the matching records are
+    /// exported.
+    pub constraints: serde_json::Value,
+    /// The analytics domain whose metrics are exported, required for analytics metrics exports
+    pub analytics_domain: Option<AnalyticsReportDomain>,
+    /// The email addresses the download link is sent to once the export is completed
+    #[serde(default)]
+    pub recipients: Vec<pii::Email>,
+    /// Restricts the export to the given profile. The export covers all profiles of the merchant
+    /// when not provided.
+    pub profile_id: Option<id_type::ProfileId>,
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ExportListRequest {
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct ExportResponse {
+    pub export_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: Option<id_type::ProfileId>,
+    pub export_type: ExportType,
+    pub analytics_domain: Option<AnalyticsReportDomain>,
+    pub format: ExportFormat,
+    pub constraints: serde_json::Value,
+    pub status: ExportStatus,
+    /// The number of records in the exported file
+    pub row_count: Option<i32>,
+    pub error_message: Option<String>,
+    /// The URL the exported file can be downloaded from, once the export is completed
+    pub download_url: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub completed_at: Option<PrimitiveDateTime>,
+}
+
+impl ApiEventMetric for ExportCreateRequest {}
+
+impl ApiEventMetric for ExportListRequest {}
+
+impl ApiEventMetric for ExportResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ExportJob {
+            export_id: self.export_id.clone(),
+        })
+    }
+}
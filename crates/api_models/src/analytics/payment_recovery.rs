@@ -0,0 +1,143 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
+use super::{NameDescription, TimeRange};
+use crate::enums::Currency;
+
+#[derive(
+    Clone,
+    Debug,
+    Hash,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumIter,
+    strum::AsRefStr,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentRecoveryMetrics {
+    PaymentRecoveryStatusMetric,
+    TotalRecoveredAmount,
+    TotalLostAmount,
+}
+
+#[derive(
+    Debug,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::AsRefStr,
+    PartialEq,
+    PartialOrd,
+    Eq,
+    Ord,
+    strum::Display,
+    strum::EnumIter,
+    Clone,
+    Copy,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PaymentRecoveryDimensions {
+    // Do not change the order of these enums
+    // Consult the Dashboard FE folks since these also affects the order of metrics on FE
+    Connector,
+    Currency,
+    DeclineCategory,
+}
+
+impl From<PaymentRecoveryDimensions> for NameDescription {
+    fn from(value: PaymentRecoveryDimensions) -> Self {
+        Self {
+            name: value.to_string(),
+            desc: String::new(),
+        }
+    }
+}
+
+impl From<PaymentRecoveryMetrics> for NameDescription {
+    fn from(value: PaymentRecoveryMetrics) -> Self {
+        Self {
+            name: value.to_string(),
+            desc: String::new(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+pub struct PaymentRecoveryFilters {
+    #[serde(default)]
+    pub connector: Vec<String>,
+    #[serde(default)]
+    pub currency: Vec<Currency>,
+    #[serde(default)]
+    pub decline_category: Vec<String>,
+}
+
+#[derive(Debug, serde::Serialize, Eq)]
+pub struct PaymentRecoveryMetricsBucketIdentifier {
+    pub connector: Option<String>,
+    pub currency: Option<Currency>,
+    pub decline_category: Option<String>,
+    #[serde(rename = "time_range")]
+    pub time_bucket: TimeRange,
+    #[serde(rename = "time_bucket")]
+    #[serde(with = "common_utils::custom_serde::iso8601custom")]
+    pub start_time: time::PrimitiveDateTime,
+}
+
+impl Hash for PaymentRecoveryMetricsBucketIdentifier {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.connector.hash(state);
+        self.currency.hash(state);
+        self.decline_category.hash(state);
+        self.time_bucket.hash(state);
+    }
+}
+impl PartialEq for PaymentRecoveryMetricsBucketIdentifier {
+    fn eq(&self, other: &Self) -> bool {
+        let mut left = DefaultHasher::new();
+        self.hash(&mut left);
+        let mut right = DefaultHasher::new();
+        other.hash(&mut right);
+        left.finish() == right.finish()
+    }
+}
+
+impl PaymentRecoveryMetricsBucketIdentifier {
+    pub fn new(
+        connector: Option<String>,
+        currency: Option<Currency>,
+        decline_category: Option<String>,
+        normalized_time_range: TimeRange,
+    ) -> Self {
+        Self {
+            connector,
+            currency,
+            decline_category,
+            time_bucket: normalized_time_range,
+            start_time: normalized_time_range.start_time,
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct PaymentRecoveryMetricsBucketValue {
+    pub payments_scheduled: Option<u64>,
+    pub payments_recovered: Option<u64>,
+    pub payments_lost: Option<u64>,
+    pub total_recovered_amount: Option<u64>,
+    pub total_lost_amount: Option<u64>,
+    pub total_payment_recovery: Option<u64>,
+}
+#[derive(Debug, serde::Serialize)]
+pub struct PaymentRecoveryMetricsBucketResponse {
+    #[serde(flatten)]
+    pub values: PaymentRecoveryMetricsBucketValue,
+    #[serde(flatten)]
+    pub dimensions: PaymentRecoveryMetricsBucketIdentifier,
+}
@@ -0,0 +1,181 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
+use common_utils::id_type;
+
+use super::{NameDescription, TimeRange};
+use crate::enums::{Currency, PayoutStatus, PayoutType};
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+pub struct PayoutFilters {
+    #[serde(default)]
+    pub currency: Vec<Currency>,
+    #[serde(default)]
+    pub payout_type: Vec<PayoutType>,
+    #[serde(default)]
+    pub connector: Vec<String>,
+    #[serde(default)]
+    pub status: Vec<PayoutStatus>,
+    #[serde(default)]
+    pub error_code: Vec<String>,
+    #[serde(default)]
+    pub profile_id: Vec<id_type::ProfileId>,
+}
+
+#[derive(
+    Debug,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::AsRefStr,
+    PartialEq,
+    PartialOrd,
+    Eq,
+    Ord,
+    strum::Display,
+    strum::EnumIter,
+    Clone,
+    Copy,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutDimensions {
+    // Payouts are analysed by the currency the payee receives the funds in
+    #[strum(serialize = "destination_currency")]
+    #[serde(rename = "destination_currency")]
+    Currency,
+    PayoutType,
+    Connector,
+    #[strum(serialize = "status")]
+    #[serde(rename = "status")]
+    PayoutStatus,
+    ErrorCode,
+    ProfileId,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Hash,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumIter,
+    strum::AsRefStr,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum PayoutMetrics {
+    PayoutCount,
+    PayoutSuccessCount,
+    PayoutSuccessRate,
+    PayoutProcessedAmount,
+    PayoutFulfilmentTime,
+}
+
+pub mod metric_behaviour {
+    pub struct PayoutCount;
+    pub struct PayoutSuccessCount;
+    pub struct PayoutSuccessRate;
+    pub struct PayoutProcessedAmount;
+    pub struct PayoutFulfilmentTime;
+}
+
+impl From<PayoutMetrics> for NameDescription {
+    fn from(value: PayoutMetrics) -> Self {
+        Self {
+            name: value.to_string(),
+            desc: String::new(),
+        }
+    }
+}
+
+impl From<PayoutDimensions> for NameDescription {
+    fn from(value: PayoutDimensions) -> Self {
+        Self {
+            name: value.to_string(),
+            desc: String::new(),
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize, Eq)]
+pub struct PayoutMetricsBucketIdentifier {
+    pub currency: Option<Currency>,
+    pub payout_type: Option<String>,
+    pub connector: Option<String>,
+    pub status: Option<String>,
+    pub error_code: Option<String>,
+    pub profile_id: Option<String>,
+    #[serde(rename = "time_range")]
+    pub time_bucket: TimeRange,
+    #[serde(rename = "time_bucket")]
+    #[serde(with = "common_utils::custom_serde::iso8601custom")]
+    pub start_time: time::PrimitiveDateTime,
+}
+
+impl Hash for PayoutMetricsBucketIdentifier {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.currency.hash(state);
+        self.payout_type.hash(state);
+        self.connector.hash(state);
+        self.status.hash(state);
+        self.error_code.hash(state);
+        self.profile_id.hash(state);
+        self.time_bucket.hash(state);
+    }
+}
+
+impl PartialEq for PayoutMetricsBucketIdentifier {
+    fn eq(&self, other: &Self) -> bool {
+        let mut left = DefaultHasher::new();
+        self.hash(&mut left);
+        let mut right = DefaultHasher::new();
+        other.hash(&mut right);
+        left.finish() == right.finish()
+    }
+}
+
+impl PayoutMetricsBucketIdentifier {
+    pub fn new(
+        currency: Option<Currency>,
+        payout_type: Option<String>,
+        connector: Option<String>,
+        status: Option<String>,
+        error_code: Option<String>,
+        profile_id: Option<String>,
+        normalized_time_range: TimeRange,
+    ) -> Self {
+        Self {
+            currency,
+            payout_type,
+            connector,
+            status,
+            error_code,
+            profile_id,
+            time_bucket: normalized_time_range,
+            start_time: normalized_time_range.start_time,
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct PayoutMetricsBucketValue {
+    pub payout_count: Option<u64>,
+    pub payout_success_count: Option<u64>,
+    pub payout_success_rate: Option<f64>,
+    pub payout_processed_amount: Option<u64>,
+    /// Average time taken for successful payouts to be fulfilled, in seconds
+    pub payout_fulfilment_time: Option<f64>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct PayoutMetricsBucketResponse {
+    #[serde(flatten)]
+    pub values: PayoutMetricsBucketValue,
+    #[serde(flatten)]
+    pub dimensions: PayoutMetricsBucketIdentifier,
+}
@@ -0,0 +1,85 @@
+use common_enums::{
+    AnalyticsReportDomain, ReportDeliveryMethod, ReportFrequency, ReportScheduleStatus,
+};
+use common_utils::{
+    events::{ApiEventMetric, ApiEventsType},
+    id_type, pii,
+};
+use time::PrimitiveDateTime;
+
+#[derive(Clone, Debug, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ReportScheduleCreateRequest {
+    pub name: String,
+    pub domain: AnalyticsReportDomain,
+    /// The metrics request of the domain, in the same format as accepted by the metrics endpoint
+    /// of the domain. The `timeRange` must not be provided, it is computed for every run from the
+    /// frequency of the report. Filters and the granularity of the time series are provided
+    /// through the `filters` and `timeSeries` fields of the request.
+    pub metric_request: serde_json::Value,
+    pub frequency: ReportFrequency,
+    pub delivery_method: ReportDeliveryMethod,
+    /// The email addresses the report is sent to, required when the report is delivered by email
+    #[serde(default)]
+    pub recipients: Vec<pii::Email>,
+    /// Restricts the report to the given profile. The report covers all profiles of the merchant
+    /// when not provided.
+    pub profile_id: Option<id_type::ProfileId>,
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ReportScheduleUpdateRequest {
+    pub name: Option<String>,
+    pub metric_request: Option<serde_json::Value>,
+    pub frequency: Option<ReportFrequency>,
+    pub delivery_method: Option<ReportDeliveryMethod>,
+    pub recipients: Option<Vec<pii::Email>>,
+    pub status: Option<ReportScheduleStatus>,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct ReportScheduleResponse {
+    pub report_schedule_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: Option<id_type::ProfileId>,
+    pub name: String,
+    pub domain: AnalyticsReportDomain,
+    pub metric_request: serde_json::Value,
+    pub frequency: ReportFrequency,
+    pub delivery_method: ReportDeliveryMethod,
+    pub recipients: Vec<pii::Email>,
+    pub status: ReportScheduleStatus,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub last_run_at: Option<PrimitiveDateTime>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub next_run_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct ReportScheduleDeleteResponse {
+    pub report_schedule_id: String,
+    pub deleted: bool,
+}
+
+impl ApiEventMetric for ReportScheduleCreateRequest {}
+
+impl ApiEventMetric for ReportScheduleUpdateRequest {}
+
+impl ApiEventMetric for ReportScheduleResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::AnalyticsReportSchedule {
+            report_schedule_id: self.report_schedule_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for ReportScheduleDeleteResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::AnalyticsReportSchedule {
+            report_schedule_id: self.report_schedule_id.clone(),
+        })
+    }
+}
@@ -68,6 +68,7 @@ pub enum SearchIndex {
     PaymentIntents,
     Refunds,
     Disputes,
+    Payouts,
     SessionizerPaymentAttempts,
     SessionizerPaymentIntents,
     SessionizerRefunds,
@@ -0,0 +1,264 @@
+use common_utils::{
+    events::{ApiEventMetric, ApiEventsType},
+    id_type, pii,
+};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+/// The success rate metric evaluated by an alert rule
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    Hash,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SuccessRateAlertMetric {
+    /// The success rate of all payment attempts, as computed by the `payment_success_rate`
+    /// payment metric
+    SuccessRate,
+    /// The success rate of the payment attempts which reached a connector, as computed by the
+    /// `connector_success_rate` payment metric
+    ConnectorSuccessRate,
+}
+
+/// The dimension the success rate is broken down by before being compared with its baseline
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    Hash,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SuccessRateAlertDimension {
+    Connector,
+    PaymentMethod,
+    PaymentMethodType,
+    CardNetwork,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    Hash,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SuccessRateAlertChannel {
+    /// Alerts are sent as `payment_success_rate_dropped` and `payment_success_rate_recovered`
+    /// outgoing webhooks of the profile
+    OutgoingWebhook,
+    /// Alerts are emailed to the recipients of the rule
+    Email,
+    /// Alerts are published to the success rate alerts Kafka topic
+    Kafka,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SuccessRateAlertStatus {
+    /// The success rate dropped significantly below its baseline
+    Triggered,
+    /// The success rate of a previously triggered alert is back within its baseline
+    Resolved,
+}
+
+/// The alert rules of a profile. The success rate of every segment of the configured dimensions
+/// over the evaluation window is compared with the success rate of the same segment over the
+/// baseline window preceding it.
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct SuccessRateAlertConfig {
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    #[serde(default = "default_metrics")]
+    pub metrics: Vec<SuccessRateAlertMetric>,
+    #[serde(default = "default_dimensions")]
+    pub dimensions: Vec<SuccessRateAlertDimension>,
+    /// The length of the rolling window the success rate is evaluated over, which is also the
+    /// interval between two evaluations
+    #[serde(default = "default_evaluation_window_minutes")]
+    pub evaluation_window_minutes: u32,
+    /// The length of the window preceding the evaluation window that the baseline success rate
+    /// is learned from
+    #[serde(default = "default_baseline_window_days")]
+    pub baseline_window_days: u32,
+    /// Segments with fewer payments than this in the evaluation window are not evaluated
+    #[serde(default = "default_min_payment_count")]
+    pub min_payment_count: u64,
+    /// The minimum drop of the success rate below its baseline, in percentage points, for an
+    /// alert to be triggered
+    #[serde(default = "default_min_drop_percentage")]
+    pub min_drop_percentage: f64,
+    /// The minimum z-score of the drop for it to be considered significant, which filters out
+    /// drops which are explained by the volume of payments in the windows
+    #[serde(default = "default_min_z_score")]
+    pub min_z_score: f64,
+    #[serde(default = "default_channels")]
+    pub channels: Vec<SuccessRateAlertChannel>,
+    /// The email addresses alerts are sent to, required when alerts are delivered by email
+    #[serde(default)]
+    pub recipients: Vec<pii::Email>,
+}
+
+impl Default for SuccessRateAlertConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_enabled(),
+            metrics: default_metrics(),
+            dimensions: default_dimensions(),
+            evaluation_window_minutes: default_evaluation_window_minutes(),
+            baseline_window_days: default_baseline_window_days(),
+            min_payment_count: default_min_payment_count(),
+            min_drop_percentage: default_min_drop_percentage(),
+            min_z_score: default_min_z_score(),
+            channels: default_channels(),
+            recipients: Vec::new(),
+        }
+    }
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+fn default_metrics() -> Vec<SuccessRateAlertMetric> {
+    vec![
+        SuccessRateAlertMetric::SuccessRate,
+        SuccessRateAlertMetric::ConnectorSuccessRate,
+    ]
+}
+
+fn default_dimensions() -> Vec<SuccessRateAlertDimension> {
+    vec![SuccessRateAlertDimension::Connector]
+}
+
+fn default_evaluation_window_minutes() -> u32 {
+    60
+}
+
+fn default_baseline_window_days() -> u32 {
+    7
+}
+
+fn default_min_payment_count() -> u64 {
+    50
+}
+
+fn default_min_drop_percentage() -> f64 {
+    10.0
+}
+
+fn default_min_z_score() -> f64 {
+    3.0
+}
+
+fn default_channels() -> Vec<SuccessRateAlertChannel> {
+    vec![SuccessRateAlertChannel::OutgoingWebhook]
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct SuccessRateAlertConfigResponse {
+    pub profile_id: id_type::ProfileId,
+    #[serde(flatten)]
+    pub config: SuccessRateAlertConfig,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct SuccessRateAlertConfigDeleteResponse {
+    pub profile_id: id_type::ProfileId,
+    pub deleted: bool,
+}
+
+/// The segment of payments an alert was raised for. Only the dimensions configured in the alert
+/// rules of the profile are populated.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash, serde::Serialize, ToSchema)]
+pub struct SuccessRateAlertSegment {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connector: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub card_network: Option<String>,
+}
+
+/// An alert raised when the success rate of a segment of payments drops significantly below its
+/// baseline, or recovers from such a drop
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct SuccessRateAlert {
+    /// Identifies the metric and segment of the alert. Stays the same between an alert being
+    /// triggered and resolved.
+    pub alert_id: String,
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    #[schema(value_type = String)]
+    pub profile_id: id_type::ProfileId,
+    pub status: SuccessRateAlertStatus,
+    pub metric: SuccessRateAlertMetric,
+    pub segment: SuccessRateAlertSegment,
+    /// The success rate over the evaluation window, as a percentage
+    pub success_rate: f64,
+    /// The success rate over the baseline window, as a percentage
+    pub baseline_success_rate: f64,
+    pub payment_count: u64,
+    pub baseline_payment_count: u64,
+    pub z_score: f64,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub window_start: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub window_end: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+impl ApiEventMetric for SuccessRateAlertConfig {}
+
+impl ApiEventMetric for SuccessRateAlertConfigResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::BusinessProfile {
+            profile_id: self.profile_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for SuccessRateAlertConfigDeleteResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::BusinessProfile {
+            profile_id: self.profile_id.clone(),
+        })
+    }
+}
@@ -60,12 +60,11 @@ pub struct CreateApiKeyResponse {
     /// The expiration date for the API Key.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
-    /*
+
     /// The date and time indicating when the API Key was last used.
     #[schema(example = "2022-09-10T10:11:12Z")]
     #[serde(with = "common_utils::custom_serde::iso8601::option")]
     pub last_used: Option<PrimitiveDateTime>,
-    */
 }
 
 /// The response body for retrieving an API Key.
@@ -102,12 +101,11 @@ pub struct RetrieveApiKeyResponse {
     /// The expiration date for the API Key.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
-    /*
+
     /// The date and time indicating when the API Key was last used.
     #[schema(example = "2022-09-10T10:11:12Z")]
     #[serde(with = "common_utils::custom_serde::iso8601::option")]
     pub last_used: Option<PrimitiveDateTime>,
-    */
 }
 
 /// The request body for updating an API Key.
@@ -154,6 +152,46 @@ pub struct RevokeApiKeyResponse {
     pub revoked: bool,
 }
 
+/// The request body for rotating an API Key.
+#[derive(Debug, Default, Deserialize, ToSchema, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RotateApiKeyRequest {
+    /// The duration (in seconds) for which the API Key being rotated remains valid after the
+    /// successor key is issued. The API Key being rotated is automatically revoked once this
+    /// grace period ends. Defaults to the grace period configured on the server.
+    #[schema(example = 86400)]
+    pub grace_period_in_secs: Option<u32>,
+
+    /// An expiration date for the successor API Key. If not provided, the successor API Key
+    /// inherits the expiration of the API Key being rotated.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    pub expiration: Option<ApiKeyExpiration>,
+
+    #[serde(skip_deserializing)]
+    #[schema(value_type = String)]
+    pub key_id: common_utils::id_type::ApiKeyId,
+
+    #[serde(skip_deserializing)]
+    #[schema(value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+}
+
+/// The response body for rotating an API Key.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RotateApiKeyResponse {
+    /// The newly issued API Key that replaces the API Key being rotated.
+    pub successor: CreateApiKeyResponse,
+
+    /// The identifier for the API Key that was rotated.
+    #[schema(max_length = 64, example = "5hEEqkgJUyuxgSKGArHA4mWSnX", value_type = String)]
+    pub rotated_key_id: common_utils::id_type::ApiKeyId,
+
+    /// The time at which the rotated API Key stops being accepted and is revoked.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub rotated_key_expiration: PrimitiveDateTime,
+}
+
 /// The constraints that are applicable when listing API Keys associated with a merchant account.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
@@ -0,0 +1,29 @@
+/// Statistics of the in-memory caches maintained by the application
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct CacheStatsResponse {
+    pub caches: Vec<CacheStats>,
+}
+
+impl common_utils::events::ApiEventMetric for CacheStatsResponse {}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct CacheStats {
+    /// Name of the cache
+    pub name: String,
+    /// Approximate number of entries held by the cache
+    pub entry_count: u64,
+    /// Approximate memory held by the cache entries, in bytes
+    pub weighted_size: u64,
+    /// Memory bound of the cache, in bytes
+    pub max_capacity: Option<u64>,
+    /// Number of lookups served by fresh entries
+    pub hits: u64,
+    /// Number of lookups served by stale entries, while the entry was being revalidated
+    pub stale_hits: u64,
+    /// Number of lookups that did not find an entry
+    pub misses: u64,
+    /// Number of misses served by an entry populated by a concurrent caller
+    pub coalesced_loads: u64,
+    /// Number of entries evicted due to expiry or memory bounds
+    pub evictions: u64,
+}
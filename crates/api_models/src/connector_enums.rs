@@ -138,15 +138,37 @@ impl Connector {
             (Self::Paypal, Some(PayoutType::Wallet))
                 | (_, Some(PayoutType::Card))
                 | (Self::Adyenplatform, _)
-        )
+        ) || self.is_dummy_connector()
     }
     #[cfg(feature = "payouts")]
     pub fn supports_create_recipient(&self, payout_method: Option<PayoutType>) -> bool {
-        matches!((self, payout_method), (_, Some(PayoutType::Bank)))
+        matches!((self, payout_method), (_, Some(PayoutType::Bank))) && !self.is_dummy_connector()
     }
     #[cfg(feature = "payouts")]
     pub fn supports_payout_eligibility(&self, payout_method: Option<PayoutType>) -> bool {
-        matches!((self, payout_method), (_, Some(PayoutType::Card)))
+        matches!((self, payout_method), (_, Some(PayoutType::Card))) && !self.is_dummy_connector()
+    }
+    /// Dummy connectors fulfill payouts of every type in a single call, without an eligibility
+    /// check or a recipient being created first
+    #[cfg(feature = "payouts")]
+    fn is_dummy_connector(&self) -> bool {
+        #[cfg(feature = "dummy_connector")]
+        {
+            matches!(
+                self,
+                Self::DummyConnector1
+                    | Self::DummyConnector2
+                    | Self::DummyConnector3
+                    | Self::DummyConnector4
+                    | Self::DummyConnector5
+                    | Self::DummyConnector6
+                    | Self::DummyConnector7
+            )
+        }
+        #[cfg(not(feature = "dummy_connector"))]
+        {
+            false
+        }
     }
     #[cfg(feature = "payouts")]
     pub fn is_payout_quote_call_required(&self) -> bool {
@@ -3,6 +3,7 @@ pub mod connector_onboarding;
 pub mod customer;
 pub mod dispute;
 pub mod gsm;
+pub mod invoice;
 mod locker_migration;
 pub mod payment;
 #[cfg(feature = "payouts")]
@@ -11,6 +12,7 @@ pub mod payouts;
 pub mod recon;
 pub mod refund;
 pub mod routing;
+pub mod subscription;
 pub mod user;
 pub mod user_role;
 
@@ -85,6 +87,10 @@ impl_api_event_type!(
         ToggleKVRequest,
         ToggleAllKVRequest,
         ToggleAllKVResponse,
+        StorageSchemeMigrationRequest,
+        StorageSchemeMigrationResponse,
+        ToggleAccountUpdaterRequest,
+        AccountUpdaterStatusResponse,
         MerchantAccountDeleteResponse,
         MerchantAccountUpdate,
         CardInfoResponse,
@@ -104,6 +110,8 @@ impl_api_event_type!(
         ApplepayMerchantResponse,
         ApplepayVerifiedDomainsResponse,
         UpdateApiKeyRequest,
+        RotateApiKeyRequest,
+        RotateApiKeyResponse,
         GetApiEventFiltersRequest,
         ApiEventFiltersResponse,
         GetInfoResponse,
@@ -116,10 +124,16 @@ impl_api_event_type!(
         PaymentFiltersResponse,
         GetRefundFilterRequest,
         RefundFiltersResponse,
+        GetPayoutMetricRequest,
+        GetPayoutFilterRequest,
+        PayoutFiltersResponse,
         GetSdkEventFiltersRequest,
         SdkEventFiltersResponse,
         ApiLogsRequest,
         GetApiEventMetricRequest,
+        GetConnectorEventFiltersRequest,
+        ConnectorEventFiltersResponse,
+        GetConnectorEventMetricRequest,
         SdkEventsRequest,
         ReportRequest,
         ConnectorEventsRequest,
@@ -131,6 +145,7 @@ impl_api_event_type!(
         GetDisputeFilterRequest,
         DisputeFiltersResponse,
         GetDisputeMetricRequest,
+        GetPaymentRecoveryMetricRequest,
         SankeyResponse,
         OrganizationResponse,
         OrganizationCreateRequest,
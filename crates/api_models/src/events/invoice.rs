@@ -0,0 +1,27 @@
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+
+use crate::invoices::{
+    InvoiceCreateRequest, InvoiceListConstraints, InvoiceListResponse, InvoiceResponse,
+};
+
+impl ApiEventMetric for InvoiceResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Invoice {
+            invoice_id: self.invoice_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for InvoiceListResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
+
+impl ApiEventMetric for InvoiceListConstraints {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
+
+impl ApiEventMetric for InvoiceCreateRequest {}
@@ -30,7 +30,9 @@ use crate::{
         PaymentsManualUpdateRequest, PaymentsManualUpdateResponse,
         PaymentsPostSessionTokensRequest, PaymentsPostSessionTokensResponse, PaymentsRejectRequest,
         PaymentsResponse, PaymentsRetrieveRequest, PaymentsSessionResponse, PaymentsStartRequest,
-        RedirectionResponse,
+        RedirectionResponse, ReusablePaymentLinkCreateRequest, ReusablePaymentLinkInitiateRequest,
+        ReusablePaymentLinkListConstraints, ReusablePaymentLinkListResponse,
+        ReusablePaymentLinkResponse, ReusablePaymentLinkUpdateRequest,
     },
 };
 
@@ -355,3 +357,35 @@ impl ApiEventMetric for PaymentsSessionResponse {
         })
     }
 }
+
+impl ApiEventMetric for ReusablePaymentLinkResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ReusablePaymentLink {
+            link_id: self.link_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for ReusablePaymentLinkInitiateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ReusablePaymentLink {
+            link_id: self.link_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for ReusablePaymentLinkListResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
+
+impl ApiEventMetric for ReusablePaymentLinkListConstraints {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
+
+impl ApiEventMetric for ReusablePaymentLinkCreateRequest {}
+
+impl ApiEventMetric for ReusablePaymentLinkUpdateRequest {}
@@ -1,9 +1,10 @@
 use common_utils::events::{ApiEventMetric, ApiEventsType};
 
 use crate::refunds::{
-    RefundAggregateResponse, RefundListFilters, RefundListMetaData, RefundListRequest,
-    RefundListResponse, RefundManualUpdateRequest, RefundRequest, RefundResponse,
-    RefundUpdateRequest, RefundsRetrieveRequest,
+    RefundAggregateResponse, RefundBatchCreateRequest, RefundBatchId, RefundBatchResponse,
+    RefundListFilters, RefundListMetaData, RefundListRequest, RefundListResponse,
+    RefundManualUpdateRequest, RefundRequest, RefundResponse, RefundUpdateRequest,
+    RefundsRetrieveRequest,
 };
 
 #[cfg(feature = "v1")]
@@ -98,3 +99,21 @@ impl ApiEventMetric for RefundListFilters {
         Some(ApiEventsType::ResourceListAPI)
     }
 }
+
+impl ApiEventMetric for RefundBatchCreateRequest {}
+
+impl ApiEventMetric for RefundBatchId {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::RefundBatch {
+            batch_id: self.batch_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for RefundBatchResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::RefundBatch {
+            batch_id: self.batch_id.clone(),
+        })
+    }
+}
@@ -0,0 +1,49 @@
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+
+use crate::subscriptions::{
+    SubscriptionCancelRequest, SubscriptionCreateRequest, SubscriptionListConstraints,
+    SubscriptionListResponse, SubscriptionPlanCreateRequest, SubscriptionPlanListResponse,
+    SubscriptionPlanResponse, SubscriptionPlanUpdateRequest, SubscriptionResponse,
+};
+
+impl ApiEventMetric for SubscriptionPlanResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::SubscriptionPlan {
+            plan_id: self.plan_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for SubscriptionResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Subscription {
+            subscription_id: self.subscription_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for SubscriptionPlanListResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
+
+impl ApiEventMetric for SubscriptionListResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
+
+impl ApiEventMetric for SubscriptionListConstraints {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
+
+impl ApiEventMetric for SubscriptionPlanCreateRequest {}
+
+impl ApiEventMetric for SubscriptionPlanUpdateRequest {}
+
+impl ApiEventMetric for SubscriptionCreateRequest {}
+
+impl ApiEventMetric for SubscriptionCancelRequest {}
@@ -0,0 +1,167 @@
+use common_enums::IncomingWebhookProcessingStatus;
+use masking::Secret;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+/// The constraints to apply when filtering archived incoming webhooks.
+#[derive(Debug, Default, Serialize, Deserialize, ToSchema)]
+pub struct IncomingWebhookListConstraints {
+    /// Filter webhooks received after the specified time.
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_after: Option<PrimitiveDateTime>,
+
+    /// Filter webhooks received before the specified time.
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_before: Option<PrimitiveDateTime>,
+
+    /// Include at most the specified number of webhooks.
+    pub limit: Option<u16>,
+
+    /// Include webhooks after the specified offset.
+    pub offset: Option<u16>,
+
+    /// Filter webhooks received from the specified connector.
+    #[schema(example = "stripe")]
+    pub connector_name: Option<String>,
+
+    /// Filter webhooks received for the specified merchant connector account.
+    #[schema(value_type = Option<String>)]
+    pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+
+    /// Filter webhooks associated with the specified business profile.
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+
+    /// Filter webhooks with the specified processing outcome.
+    pub processing_status: Option<IncomingWebhookProcessingStatus>,
+}
+
+/// The response body for each item when listing archived incoming webhooks.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct IncomingWebhookListItemResponse {
+    /// The identifier for the archived incoming webhook.
+    #[schema(max_length = 64, example = "iwh_018e31720d1b7a2b82677d3032cab959")]
+    pub webhook_id: String,
+
+    /// The identifier for the Merchant Account.
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// The connector name or merchant connector account ID the webhook was received on.
+    #[schema(max_length = 64, example = "mca_5apGeP94tMts6rg3U3kR")]
+    pub connector_id_or_name: String,
+
+    /// The name of the connector that sent the webhook.
+    #[schema(max_length = 64, example = "stripe")]
+    pub connector_name: Option<String>,
+
+    /// The identifier for the merchant connector account the webhook was processed with.
+    #[schema(max_length = 64, value_type = Option<String>, example = "mca_5apGeP94tMts6rg3U3kR")]
+    pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+
+    /// The identifier for the Business Profile.
+    #[schema(max_length = 64, value_type = Option<String>, example = "SqB0zwDGR5wHppWf0bx7GKr1f2")]
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+
+    /// The type of the incoming webhook event, as identified from the webhook body.
+    #[schema(example = "payment_intent_success")]
+    pub event_type: Option<String>,
+
+    /// Indicates whether the source of the webhook was verified.
+    pub source_verified: Option<bool>,
+
+    /// The outcome of the most recent attempt to process the webhook.
+    pub processing_status: IncomingWebhookProcessingStatus,
+
+    /// The error code, in case processing the webhook failed.
+    #[schema(example = "WE_03")]
+    pub error_code: Option<String>,
+
+    /// The error message, in case processing the webhook failed.
+    #[schema(example = "Webhook authentication failed")]
+    pub error_message: Option<String>,
+
+    /// The number of times the webhook was reprocessed.
+    #[schema(example = 1)]
+    pub reprocess_count: i32,
+
+    /// Time at which the webhook was last reprocessed.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub last_reprocessed_at: Option<PrimitiveDateTime>,
+
+    /// Time at which the webhook was received.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+}
+
+/// The response body for retrieving an archived incoming webhook.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct IncomingWebhookRetrieveResponse {
+    #[serde(flatten)]
+    pub webhook_information: IncomingWebhookListItemResponse,
+
+    /// The request (method, URI, headers and body) received from the connector.
+    pub request: IncomingWebhookRequestContent,
+}
+
+impl common_utils::events::ApiEventMetric for IncomingWebhookRetrieveResponse {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::IncomingWebhooks {
+            merchant_id: self.webhook_information.merchant_id.clone(),
+        })
+    }
+}
+
+/// The request (method, URI, headers and body) received from the connector.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct IncomingWebhookRequestContent {
+    /// The HTTP method of the request.
+    #[schema(example = "POST")]
+    pub method: String,
+
+    /// The URI the request was received on, including the query string.
+    #[schema(example = "/webhooks/merchant_1668273825/stripe")]
+    pub uri: String,
+
+    /// The request headers received from the connector.
+    #[schema(
+        value_type = Vec<(String, String)>,
+        example = json!([["content-type", "application/json"], ["stripe-signature", "t=1492774577,v1=5257a869e7ecebeda32affa62cdca3fa51cad7e77a0e56ff536d0ce8e108d8bd"]]))
+    ]
+    pub headers: Vec<(String, Secret<String>)>,
+
+    /// The request body received from the connector.
+    #[schema(value_type = String)]
+    pub body: Secret<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct IncomingWebhookListRequestInternal {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub constraints: IncomingWebhookListConstraints,
+}
+
+impl common_utils::events::ApiEventMetric for IncomingWebhookListRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::IncomingWebhooks {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct IncomingWebhookRequestInternal {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub webhook_id: String,
+}
+
+impl common_utils::events::ApiEventMetric for IncomingWebhookRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::IncomingWebhooks {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
@@ -0,0 +1,130 @@
+use common_utils::{id_type, pii, types::MinorUnit};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::{enums as api_enums, payments::PaymentCreatePaymentLinkConfig};
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct InvoiceLineItem {
+    /// Name of the product or service being billed
+    #[schema(max_length = 255, example = "Consulting hours")]
+    pub product_name: String,
+    /// The quantity being billed
+    #[schema(example = 3)]
+    pub quantity: u16,
+    /// The amount per unit, in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 5000)]
+    pub unit_amount: MinorUnit,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct InvoiceCreateRequest {
+    /// The identifier for the customer being billed
+    #[schema(value_type = String, max_length = 64, min_length = 1, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+    /// The profile through which the payment is created. Defaults to the default profile of the
+    /// merchant account.
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<id_type::ProfileId>,
+    /// The three-letter ISO currency code
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+    /// The items being billed, at least one is required
+    pub line_items: Vec<InvoiceLineItem>,
+    /// The tax charged on top of the line items, in the lowest denomination of the currency
+    #[schema(value_type = Option<i64>, example = 1500)]
+    pub tax_amount: Option<MinorUnit>,
+    /// The time by which the invoice is to be paid
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub due_date: PrimitiveDateTime,
+    /// A description shown on the invoice
+    #[schema(example = "Invoice for October")]
+    pub description: Option<String>,
+    /// Theme config for the hosted invoice page
+    #[schema(value_type = Option<PaymentCreatePaymentLinkConfig>)]
+    pub payment_link_config: Option<PaymentCreatePaymentLinkConfig>,
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
+    #[schema(value_type = Option<Object>, example = r#"{ "city": "NY", "unit": "245" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct InvoiceResponse {
+    /// The identifier for the invoice
+    #[schema(example = "inv_9dEdKDwmUPrCFfrEQJhC")]
+    pub invoice_id: String,
+    /// The identifier for the merchant account
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// The profile through which the payment is created
+    #[schema(value_type = String)]
+    pub profile_id: id_type::ProfileId,
+    /// The identifier for the billed customer
+    #[schema(value_type = String)]
+    pub customer_id: id_type::CustomerId,
+    /// The status of the invoice
+    #[schema(value_type = InvoiceStatus, example = "open")]
+    pub status: api_enums::InvoiceStatus,
+    /// The items being billed
+    pub line_items: Vec<InvoiceLineItem>,
+    /// The three-letter ISO currency code
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+    /// The sum of all the line items
+    #[schema(value_type = i64, example = 15000)]
+    pub subtotal: MinorUnit,
+    /// The tax charged on top of the line items
+    #[schema(value_type = i64, example = 1500)]
+    pub tax_amount: MinorUnit,
+    /// The amount to be paid by the customer
+    #[schema(value_type = i64, example = 16500)]
+    pub total_amount: MinorUnit,
+    /// The time by which the invoice is to be paid
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub due_date: PrimitiveDateTime,
+    /// The identifier for the payment through which the invoice is paid
+    #[schema(value_type = String)]
+    pub payment_id: id_type::PaymentId,
+    /// URL of the hosted invoice page to be shared with the customer
+    pub payment_link: Option<String>,
+    /// A description shown on the invoice
+    pub description: Option<String>,
+    /// Metadata attached to the invoice
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+    /// Time at which the invoice was paid
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub paid_at: Option<PrimitiveDateTime>,
+    /// Time at which the invoice was created
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct InvoiceListConstraints {
+    /// Only return invoices of the given customer
+    #[schema(value_type = Option<String>)]
+    pub customer_id: Option<id_type::CustomerId>,
+    /// Only return invoices with the given status
+    #[schema(value_type = Option<InvoiceStatus>)]
+    pub status: Option<api_enums::InvoiceStatus>,
+    /// The maximum number of invoices to be returned
+    #[schema(example = 10)]
+    pub limit: Option<i64>,
+    /// The number of invoices to skip
+    #[schema(example = 0)]
+    pub offset: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct InvoiceListResponse {
+    /// The number of invoices returned
+    pub count: usize,
+    /// The list of invoices
+    pub data: Vec<InvoiceResponse>,
+}
@@ -3,6 +3,7 @@ pub mod analytics;
 pub mod api_keys;
 pub mod apple_pay_certificates_migration;
 pub mod blocklist;
+pub mod cache;
 pub mod cards_info;
 pub mod conditional_configs;
 pub mod connector_enums;
@@ -19,6 +20,8 @@ pub mod events;
 pub mod files;
 pub mod gsm;
 pub mod health_check;
+pub mod incoming_webhook_archive;
+pub mod invoices;
 pub mod locker_migration;
 pub mod mandates;
 pub mod organization;
@@ -32,10 +35,12 @@ pub mod poll;
 pub mod recon;
 pub mod refunds;
 pub mod routing;
+pub mod subscriptions;
 pub mod surcharge_decision_configs;
 pub mod user;
 pub mod user_role;
 pub mod verifications;
 pub mod verify_connector;
+pub mod webhook_endpoints;
 pub mod webhook_events;
 pub mod webhooks;
@@ -644,7 +644,7 @@ pub enum PaymentMethodResponseData {
     any(feature = "v1", feature = "v2"),
     not(feature = "payment_methods_v2")
 ))]
-#[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema, Clone)]
 pub struct PaymentMethodResponse {
     /// Unique identifier for a merchant
     #[schema(example = "merchant_1671528864", value_type = String)]
@@ -1131,6 +1131,9 @@ pub struct ResponsePaymentMethodTypes {
 
     /// auth service connector label for this payment method type, if exists
     pub pm_auth_connector: Option<String>,
+
+    /// The installment plans offered for the card, if the card BIN was provided
+    pub installment_plans: Option<Vec<payments::InstallmentPlan>>,
 }
 
 #[derive(Clone, Debug, PartialEq, serde::Serialize, ToSchema)]
@@ -1320,6 +1323,11 @@ pub struct PaymentMethodListRequest {
     #[schema(value_type = Option<Vec<CardNetwork>>, example = json!(["visa", "mastercard"]))]
     pub card_networks: Option<Vec<api_enums::CardNetwork>>,
 
+    /// The first digits of the card being entered by the customer. If provided, the installment
+    /// plans offered for the card are included in the list.
+    #[schema(max_length = 8, min_length = 6, example = "424242")]
+    pub card_bin: Option<String>,
+
     /// Indicates the limit of last used payment methods
     #[schema(example = 1)]
     pub limit: Option<i64>,
@@ -1395,6 +1403,13 @@ impl<'de> serde::Deserialize<'de> for PaymentMethodListRequest {
                             Some(inner) => inner.push(map.next_value()?),
                             None => output.card_networks = Some(vec![map.next_value()?]),
                         },
+                        "card_bin" => {
+                            set_or_reject_duplicate(
+                                &mut output.card_bin,
+                                "card_bin",
+                                map.next_value()?,
+                            )?;
+                        }
                         "limit" => {
                             set_or_reject_duplicate(&mut output.limit, "limit", map.next_value()?)?;
                         }
@@ -833,6 +833,15 @@ pub struct PaymentsRequest {
     /// Fee information to be charged on the payment being collected
     pub charges: Option<PaymentChargeRequest>,
 
+    /// Details of how the payment is to be split among the recipients of a marketplace payment.
+    /// This can only be provided while creating the payment.
+    #[remove_in(PaymentsUpdateRequest, PaymentsConfirmRequest)]
+    pub split_payments: Option<SplitPaymentsRequest>,
+
+    /// The installment plan to pay a card payment with. The plans available for a card are
+    /// listed in the payment methods list when the card BIN is provided.
+    pub installment_plan: Option<InstallmentPlanSelection>,
+
     /// Merchant's identifier for the payment/invoice. This will be sent to the connector
     /// if the connector provides support to accept multiple reference ids.
     /// In case the connector supports only one reference id, Hyperswitch's Payment ID will be sent as reference.
@@ -1051,6 +1060,172 @@ pub struct PaymentChargeRequest {
     pub transfer_account_id: String,
 }
 
+/// An installment plan offered by a connector for paying with a card
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone, PartialEq, ToSchema)]
+pub struct InstallmentPlan {
+    /// The connector offering the plan
+    #[schema(example = "dlocal")]
+    pub connector: String,
+
+    /// Identifier of the plan at the connector, if the connector requires the plan to be
+    /// referenced while paying
+    #[schema(example = "INS54434-3")]
+    pub plan_id: Option<String>,
+
+    /// The number of installments the amount is paid in
+    #[schema(example = 3)]
+    pub number_of_installments: u16,
+
+    /// The amount of a single installment, in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 3400)]
+    pub installment_amount: MinorUnit,
+
+    /// The total amount paid by the customer, including interest and fees
+    #[schema(value_type = i64, example = 10200)]
+    pub total_amount: MinorUnit,
+
+    /// The interest rate charged on the plan, in percent
+    #[schema(example = 2.0)]
+    pub interest_rate: Option<f64>,
+
+    /// The fees charged for paying in installments, in the lowest denomination of the currency
+    #[schema(value_type = Option<i64>, example = 0)]
+    pub fee_amount: Option<MinorUnit>,
+}
+
+/// The installment plan selected by the customer
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone, PartialEq, Eq, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct InstallmentPlanSelection {
+    /// The number of installments the amount is to be paid in
+    #[schema(example = 3)]
+    pub number_of_installments: u16,
+
+    /// Identifier of the plan, as returned in the payment methods list
+    #[schema(example = "INS54434-3")]
+    pub plan_id: Option<String>,
+}
+
+/// The installment plan a payment is paid with
+#[derive(Debug, serde::Serialize, Clone, PartialEq, Eq, ToSchema)]
+pub struct InstallmentPlanDetails {
+    /// Identifier of the plan at the connector
+    #[schema(example = "INS54434-3")]
+    pub plan_id: Option<String>,
+
+    /// The number of installments the amount is paid in
+    #[schema(example = 3)]
+    pub number_of_installments: u16,
+
+    /// The amount of a single installment, available if the plan was offered in the payment
+    /// methods list
+    #[schema(value_type = Option<i64>, example = 3400)]
+    pub installment_amount: Option<MinorUnit>,
+
+    /// The total amount paid by the customer, available if the plan was offered in the payment
+    /// methods list
+    #[schema(value_type = Option<i64>, example = 10200)]
+    pub total_amount: Option<MinorUnit>,
+
+    /// The fees charged for paying in installments
+    #[schema(value_type = Option<i64>, example = 0)]
+    pub fee_amount: Option<MinorUnit>,
+}
+
+/// Details of how a payment is split among the recipients of a marketplace payment. The part of
+/// the amount which is not transferred to any of the recipients is retained by the platform.
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct SplitPaymentsRequest {
+    /// The recipients of the payment, along with their shares
+    #[schema(min_items = 1)]
+    pub recipients: Vec<SplitPaymentRecipient>,
+
+    /// The party bearing the processing fees charged by the connector
+    #[serde(default)]
+    pub fee_bearer: SplitFeeBearer,
+}
+
+/// A recipient of a split payment
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct SplitPaymentRecipient {
+    /// Identifier of the recipient's account at the connector
+    #[schema(example = "acct_1MqT6uQ9YaEkM1qE")]
+    pub recipient_account_id: String,
+
+    /// The share of the payment amount to be transferred to the recipient
+    pub share: SplitPaymentShare,
+}
+
+/// The share of a split payment to be transferred to a recipient
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
+#[serde(rename_all = "snake_case", tag = "type")]
+pub enum SplitPaymentShare {
+    /// A fixed amount, in the lowest denomination of the currency of the payment
+    Fixed {
+        #[schema(value_type = i64, example = 6540)]
+        amount: MinorUnit,
+    },
+    /// A percentage of the amount of the payment, with up to two decimal digits
+    Percentage {
+        #[schema(example = 12.5)]
+        percentage: f32,
+    },
+}
+
+/// The party bearing the processing fees charged by the connector for a split payment
+#[derive(Debug, Default, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
+#[serde(rename_all = "snake_case", tag = "type")]
+pub enum SplitFeeBearer {
+    /// The fees are deducted from the amount retained by the platform
+    #[default]
+    Platform,
+    /// The fees are deducted from the share of one of the recipients
+    Recipient {
+        /// Identifier of the recipient's account at the connector
+        recipient_account_id: String,
+    },
+}
+
+/// Details of how a payment is split among the recipients of a marketplace payment
+#[derive(Clone, Debug, PartialEq, serde::Serialize, ToSchema)]
+pub struct SplitPaymentsResponse {
+    /// The shares of the recipients of the payment
+    pub recipients: Vec<SplitPaymentRecipientResponse>,
+
+    /// The amount retained by the platform
+    #[schema(value_type = i64, example = 1000)]
+    pub platform_amount: MinorUnit,
+
+    /// How the shares are transferred to the recipients, available once the payment has been
+    /// routed to a connector
+    #[schema(value_type = Option<SplitSettlementType>, example = "connector")]
+    pub settlement_type: Option<api_enums::SplitSettlementType>,
+}
+
+/// The share of a recipient of a split payment
+#[derive(Clone, Debug, PartialEq, serde::Serialize, ToSchema)]
+pub struct SplitPaymentRecipientResponse {
+    /// Unique identifier of the split
+    #[schema(example = "split_fZyPhZRxMh8rHxMgoQ4S")]
+    pub split_id: String,
+
+    /// Identifier of the recipient's account at the connector
+    pub recipient_account_id: String,
+
+    /// The amount transferred to the recipient
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: MinorUnit,
+
+    /// The part of the amount which has been reversed due to refunds of the payment
+    #[schema(value_type = i64, example = 0)]
+    pub refunded_amount: MinorUnit,
+
+    /// Whether the processing fees are deducted from the share of this recipient
+    pub is_fee_bearer: bool,
+}
+
 /// Details of surcharge applied on this payment, if applicable
 #[derive(
     Default, Debug, Clone, serde::Serialize, serde::Deserialize, Copy, ToSchema, PartialEq,
@@ -4414,6 +4589,12 @@ pub struct PaymentsResponse {
     /// Fee information to be charged on the payment being collected
     pub charges: Option<PaymentChargeResponse>,
 
+    /// Details of how the payment is split among the recipients of a marketplace payment
+    pub split_payments: Option<SplitPaymentsResponse>,
+
+    /// The installment plan the payment is paid with
+    pub installment_plan: Option<InstallmentPlanDetails>,
+
     /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. FRM Metadata is useful for storing additional, structured information on an object related to FRM.
     #[schema(value_type = Option<Object>, example = r#"{ "fulfillment_method" : "deliver", "coverage_request" : "fraud" }"#)]
     pub frm_metadata: Option<pii::SecretSerdeValue>,
@@ -6463,6 +6644,125 @@ pub struct PaymentLinkListResponse {
     pub data: Vec<PaymentLinkResponse>,
 }
 
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ReusablePaymentLinkCreateRequest {
+    /// The profile through which the payments are created. Defaults to the default profile of
+    /// the merchant account.
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<id_type::ProfileId>,
+    /// Whether every payment is made for the same amount, or the customer chooses the amount
+    #[schema(value_type = ReusablePaymentLinkAmountType, example = "fixed")]
+    pub amount_type: api_enums::ReusablePaymentLinkAmountType,
+    /// The amount of every payment for `fixed` links, and the suggested amount for
+    /// `customer_chosen` links, in the lowest denomination of the currency
+    #[schema(value_type = Option<i64>, example = 6540)]
+    pub amount: Option<MinorUnit>,
+    /// The minimum amount the customer can choose, for `customer_chosen` links
+    #[schema(value_type = Option<i64>, example = 100)]
+    pub min_amount: Option<MinorUnit>,
+    /// The maximum amount the customer can choose, for `customer_chosen` links
+    #[schema(value_type = Option<i64>, example = 100000)]
+    pub max_amount: Option<MinorUnit>,
+    /// The three-letter ISO currency code
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+    /// A description shown on the payment page and attached to every payment
+    #[schema(example = "Donation to the animal shelter")]
+    pub description: Option<String>,
+    /// Theme config applied to the payment page of every payment created through the link
+    #[schema(value_type = Option<PaymentCreatePaymentLinkConfig>)]
+    pub payment_link_config: Option<PaymentCreatePaymentLinkConfig>,
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
+    #[schema(value_type = Option<Object>, example = r#"{ "city": "NY", "unit": "245" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ReusablePaymentLinkUpdateRequest {
+    /// A description shown on the payment page and attached to every payment
+    pub description: Option<String>,
+    /// Whether new payments can be made through the link
+    pub is_active: Option<bool>,
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
+    #[schema(value_type = Option<Object>, example = r#"{ "city": "NY", "unit": "245" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct ReusablePaymentLinkResponse {
+    /// Identifier for the reusable payment link
+    #[schema(example = "rpl_9dEdKDwmUPrCFfrEQJhC")]
+    pub link_id: String,
+    /// Identifier for the merchant
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// The profile through which the payments are created
+    #[schema(value_type = String)]
+    pub profile_id: id_type::ProfileId,
+    /// URL to be shared with customers. Every visit creates a new payment.
+    pub link: String,
+    /// Whether every payment is made for the same amount, or the customer chooses the amount
+    #[schema(value_type = ReusablePaymentLinkAmountType, example = "fixed")]
+    pub amount_type: api_enums::ReusablePaymentLinkAmountType,
+    /// The amount of every payment, or the suggested amount for `customer_chosen` links
+    #[schema(value_type = Option<i64>, example = 6540)]
+    pub amount: Option<MinorUnit>,
+    /// The minimum amount the customer can choose
+    #[schema(value_type = Option<i64>, example = 100)]
+    pub min_amount: Option<MinorUnit>,
+    /// The maximum amount the customer can choose
+    #[schema(value_type = Option<i64>, example = 100000)]
+    pub max_amount: Option<MinorUnit>,
+    /// The three-letter ISO currency code
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+    /// A description shown on the payment page
+    pub description: Option<String>,
+    /// Whether new payments can be made through the link
+    pub is_active: bool,
+    /// Metadata attached to the link
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+    /// Time at which the link was created
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ReusablePaymentLinkListConstraints {
+    /// The maximum number of links to be returned
+    #[schema(example = 10)]
+    pub limit: Option<i64>,
+    /// The number of links to skip
+    #[schema(example = 0)]
+    pub offset: Option<i64>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct ReusablePaymentLinkListResponse {
+    /// The number of links returned
+    pub count: usize,
+    /// The list of links
+    pub data: Vec<ReusablePaymentLinkResponse>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct ReusablePaymentLinkInitiateRequest {
+    pub merchant_id: id_type::MerchantId,
+    pub link_id: String,
+    /// The amount chosen by the customer, for `customer_chosen` links
+    pub amount: Option<MinorUnit>,
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize)]
+pub struct ReusablePaymentLinkInitiateQuery {
+    /// The amount chosen by the customer, for `customer_chosen` links
+    pub amount: Option<MinorUnit>,
+}
+
 /// Configure a custom payment link for the particular payment
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize, PartialEq, ToSchema)]
 pub struct PaymentCreatePaymentLinkConfig {
@@ -809,6 +809,135 @@ pub struct PayoutListFilters {
     pub payout_method: Vec<common_enums::PayoutType>,
 }
 
+/// Request to create payouts in bulk from a file uploaded with the purpose `payout_batch`.
+///
+/// The file can either be a JSON array of payout create requests, or a CSV file whose rows follow
+/// the format of [`PayoutBatchRecord`].
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutBatchCreateRequest {
+    /// The identifier of the uploaded file containing the payouts
+    #[schema(example = "file_4Lp3vIfqjFAZqtRHd8FH")]
+    pub file_id: String,
+
+    /// The business profile under which the payouts are created
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<id_type::ProfileId>,
+}
+
+/// A row of a CSV file used for creating payouts in bulk
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct PayoutBatchRecord {
+    pub payout_id: Option<String>,
+    pub amount: i64,
+    pub currency: api_enums::Currency,
+    pub customer_id: Option<id_type::CustomerId>,
+    pub payout_type: Option<api_enums::PayoutType>,
+    pub payout_token: Option<String>,
+    pub connector: Option<api_enums::PayoutConnectors>,
+    pub description: Option<String>,
+    pub priority: Option<api_enums::PayoutSendPriority>,
+    pub auto_fulfill: Option<bool>,
+    pub email: Option<Email>,
+    pub name: Option<Secret<String>>,
+}
+
+impl From<PayoutBatchRecord> for PayoutCreateRequest {
+    fn from(record: PayoutBatchRecord) -> Self {
+        Self {
+            payout_id: record.payout_id,
+            amount: Some(payments::Amount::from(common_utils::types::MinorUnit::new(
+                record.amount,
+            ))),
+            currency: Some(record.currency),
+            customer_id: record.customer_id,
+            payout_type: record.payout_type,
+            payout_token: record.payout_token,
+            connector: record.connector.map(|connector| vec![connector]),
+            description: record.description,
+            priority: record.priority,
+            auto_fulfill: record.auto_fulfill,
+            email: record.email,
+            name: record.name,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct PayoutBatchId {
+    pub batch_id: String,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct PayoutBatchResponse {
+    /// Unique identifier of the batch
+    #[schema(example = "batch_fZyPhZRxMh8rHxMgoQ4S")]
+    pub batch_id: String,
+
+    /// The identifier of the merchant the batch belongs to
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+
+    /// The business profile under which the payouts are created
+    #[schema(value_type = String)]
+    pub profile_id: id_type::ProfileId,
+
+    /// The identifier of the uploaded file containing the payouts
+    pub file_id: String,
+
+    /// The status of the batch
+    #[schema(value_type = PayoutBatchStatus)]
+    pub status: api_enums::PayoutBatchStatus,
+
+    /// The number of payouts in the file
+    pub total_count: i32,
+
+    /// The number of rows of the file which have been processed
+    pub processed_count: i32,
+
+    /// The number of payouts which have been created
+    pub succeeded_count: i32,
+
+    /// The number of rows of the file for which a payout could not be created
+    pub failed_count: i32,
+
+    /// The result of each of the processed rows of the file
+    pub results: Vec<PayoutBatchRowResult>,
+
+    /// The identifier of the file containing the results of the batch, available once all the
+    /// rows have been processed
+    pub result_file_id: Option<String>,
+
+    /// Time when the batch was created
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+
+    /// Time when the batch was last updated
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub updated: PrimitiveDateTime,
+}
+
+/// The result of creating the payout for a row of the file
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct PayoutBatchRowResult {
+    /// The number of the row in the file, starting at 1
+    pub row_number: i32,
+
+    /// The identifier of the payout created for the row
+    pub payout_id: Option<String>,
+
+    /// The status of the payout created for the row
+    #[schema(value_type = Option<PayoutStatus>)]
+    pub status: Option<api_enums::PayoutStatus>,
+
+    /// The error code, if the payout could not be created
+    pub error_code: Option<String>,
+
+    /// The error message, if the payout could not be created
+    pub error_message: Option<String>,
+}
+
 #[derive(Clone, Debug, serde::Serialize, ToSchema)]
 pub struct PayoutLinkResponse {
     pub payout_link_id: String,
@@ -396,3 +396,109 @@ impl From<RefundStatus> for enums::RefundStatus {
         }
     }
 }
+
+/// Request to create refunds in bulk from a file uploaded with the purpose `refund_batch`.
+///
+/// The file can either be a JSON array or a CSV file, whose rows follow the format of
+/// [`RefundBatchRecord`].
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RefundBatchCreateRequest {
+    /// The identifier of the uploaded file containing the refunds
+    #[schema(example = "file_4Lp3vIfqjFAZqtRHd8FH")]
+    pub file_id: String,
+}
+
+/// A row of a file used for creating refunds in bulk
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RefundBatchRecord {
+    /// The payment id against which the refund is to be initiated
+    pub payment_id: common_utils::id_type::PaymentId,
+
+    /// The amount to be refunded, defaults to the full payment amount if not provided
+    pub amount: Option<MinorUnit>,
+
+    /// Reason for the refund
+    pub reason: Option<String>,
+
+    /// Unique identifier for the refund, derived from the batch if not provided
+    pub refund_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RefundBatchId {
+    pub batch_id: String,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct RefundBatchResponse {
+    /// Unique identifier of the batch
+    #[schema(example = "batch_fZyPhZRxMh8rHxMgoQ4S")]
+    pub batch_id: String,
+
+    /// The identifier of the merchant the batch belongs to
+    #[schema(value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// The identifier of the uploaded file containing the refunds
+    pub file_id: String,
+
+    /// The status of the batch
+    #[schema(value_type = RefundBatchStatus)]
+    pub status: enums::RefundBatchStatus,
+
+    /// The number of refunds in the file
+    pub total_count: i32,
+
+    /// The number of rows of the file which have been processed
+    pub processed_count: i32,
+
+    /// The number of refunds which have been created
+    pub succeeded_count: i32,
+
+    /// The number of rows of the file for which a refund could not be created
+    pub failed_count: i32,
+
+    /// The result of each of the processed rows of the file
+    pub results: Vec<RefundBatchRowResult>,
+
+    /// Time when the batch was created
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+
+    /// Time when the batch was last updated
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub updated: PrimitiveDateTime,
+}
+
+/// The result of creating the refund for a row of the file
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct RefundBatchRowResult {
+    /// The number of the row in the file, starting at 1
+    pub row_number: i32,
+
+    /// The payment id against which the refund was initiated
+    #[schema(value_type = String)]
+    pub payment_id: common_utils::id_type::PaymentId,
+
+    /// The identifier of the refund created for the row
+    pub refund_id: Option<String>,
+
+    /// The status of the refund. This is the status at the time the refund was created, the batch
+    /// report contains the latest status of the refunds
+    pub status: Option<RefundStatus>,
+
+    /// The connector through which the refund is processed
+    pub connector: Option<String>,
+
+    /// The time at which the refund is scheduled to be sent to the connector
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub scheduled_at: Option<PrimitiveDateTime>,
+
+    /// The error code, if the refund could not be created
+    pub error_code: Option<String>,
+
+    /// The error message, if the refund could not be created
+    pub error_message: Option<String>,
+}
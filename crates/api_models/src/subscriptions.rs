@@ -0,0 +1,202 @@
+use common_utils::{id_type, pii, types::MinorUnit};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::{enums as api_enums, mandates::RecurringDetails};
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionPlanCreateRequest {
+    /// The name of the plan
+    #[schema(example = "Premium monthly")]
+    pub name: String,
+    /// A description of the plan
+    #[schema(example = "Access to all premium features, billed monthly")]
+    pub description: Option<String>,
+    /// The amount charged on every billing date, in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 1999)]
+    pub amount: MinorUnit,
+    /// The three-letter ISO currency code
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+    /// The unit of time after which subscriptions to the plan are billed again
+    #[schema(value_type = SubscriptionInterval, example = "month")]
+    pub interval: api_enums::SubscriptionInterval,
+    /// The number of intervals between two billing dates. Defaults to 1.
+    #[schema(example = 1)]
+    pub interval_count: Option<u16>,
+    /// The number of days for which subscriptions to the plan are not charged
+    #[schema(example = 14)]
+    pub trial_period_days: Option<u16>,
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
+    #[schema(value_type = Option<Object>, example = r#"{ "city": "NY", "unit": "245" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionPlanUpdateRequest {
+    /// The name of the plan
+    pub name: Option<String>,
+    /// A description of the plan
+    pub description: Option<String>,
+    /// Whether new subscriptions can be created for the plan. Existing subscriptions are not
+    /// affected.
+    pub is_active: Option<bool>,
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
+    #[schema(value_type = Option<Object>, example = r#"{ "city": "NY", "unit": "245" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct SubscriptionPlanResponse {
+    /// The identifier for the plan
+    #[schema(example = "plan_9dEdKDwmUPrCFfrEQJhC")]
+    pub plan_id: String,
+    /// The identifier for the merchant account
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// The name of the plan
+    pub name: String,
+    /// A description of the plan
+    pub description: Option<String>,
+    /// The amount charged on every billing date, in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 1999)]
+    pub amount: MinorUnit,
+    /// The three-letter ISO currency code
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+    /// The unit of time after which subscriptions to the plan are billed again
+    #[schema(value_type = SubscriptionInterval, example = "month")]
+    pub interval: api_enums::SubscriptionInterval,
+    /// The number of intervals between two billing dates
+    pub interval_count: u16,
+    /// The number of days for which subscriptions to the plan are not charged
+    pub trial_period_days: Option<u16>,
+    /// Whether new subscriptions can be created for the plan
+    pub is_active: bool,
+    /// Metadata attached to the plan
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+    /// Time at which the plan was created
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct SubscriptionPlanListResponse {
+    /// The number of plans returned
+    pub count: usize,
+    /// The list of plans
+    pub data: Vec<SubscriptionPlanResponse>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionCreateRequest {
+    /// The identifier for the customer being subscribed
+    #[schema(value_type = String, max_length = 64, min_length = 1, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+    /// The identifier for the plan the customer is subscribed to
+    #[schema(example = "plan_9dEdKDwmUPrCFfrEQJhC")]
+    pub plan_id: String,
+    /// The profile through which the recurring payments are created. Defaults to the default
+    /// profile of the merchant account.
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<id_type::ProfileId>,
+    /// The mandate or the saved payment method of the customer to be charged. Only `mandate_id`
+    /// and `payment_method_id` are supported.
+    pub recurring_details: RecurringDetails,
+    /// The time from which the subscription starts. The first charge is made at this time, or at
+    /// the end of the trial period if the plan has one. Defaults to the current time.
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub start_date: Option<PrimitiveDateTime>,
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
+    #[schema(value_type = Option<Object>, example = r#"{ "city": "NY", "unit": "245" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionCancelRequest {
+    /// If set, the subscription stays active until the end of the current billing period and is
+    /// cancelled instead of being renewed. Otherwise the subscription is cancelled immediately.
+    #[serde(default)]
+    pub cancel_at_period_end: bool,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct SubscriptionResponse {
+    /// The identifier for the subscription
+    #[schema(example = "sub_9dEdKDwmUPrCFfrEQJhC")]
+    pub subscription_id: String,
+    /// The identifier for the merchant account
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// The profile through which the recurring payments are created
+    #[schema(value_type = String)]
+    pub profile_id: id_type::ProfileId,
+    /// The identifier for the subscribed customer
+    #[schema(value_type = String)]
+    pub customer_id: id_type::CustomerId,
+    /// The identifier for the plan the customer is subscribed to
+    pub plan_id: String,
+    /// The status of the subscription
+    #[schema(value_type = SubscriptionStatus, example = "active")]
+    pub status: api_enums::SubscriptionStatus,
+    /// The mandate used for charging the customer
+    pub mandate_id: Option<String>,
+    /// The saved payment method used for charging the customer
+    pub payment_method_id: Option<String>,
+    /// Start of the current billing period
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub current_period_start: PrimitiveDateTime,
+    /// End of the current billing period, at which the subscription is billed again
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub current_period_end: PrimitiveDateTime,
+    /// End of the trial period, if the plan has one
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub trial_end: Option<PrimitiveDateTime>,
+    /// The number of billing periods which have been charged successfully
+    pub billing_cycle: u32,
+    /// The identifier for the most recent payment created for the subscription
+    pub latest_payment_id: Option<String>,
+    /// Whether the subscription will be cancelled at the end of the current billing period
+    pub cancel_at_period_end: bool,
+    /// Time at which the subscription was cancelled
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub cancelled_at: Option<PrimitiveDateTime>,
+    /// Metadata attached to the subscription
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+    /// Time at which the subscription was created
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionListConstraints {
+    /// Only return subscriptions of the given customer
+    #[schema(value_type = Option<String>)]
+    pub customer_id: Option<id_type::CustomerId>,
+    /// Only return subscriptions with the given status
+    #[schema(value_type = Option<SubscriptionStatus>)]
+    pub status: Option<api_enums::SubscriptionStatus>,
+    /// The maximum number of subscriptions to be returned
+    #[schema(example = 10)]
+    pub limit: Option<i64>,
+    /// The number of subscriptions to skip
+    #[schema(example = 0)]
+    pub offset: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct SubscriptionListResponse {
+    /// The number of subscriptions returned
+    pub count: usize,
+    /// The list of subscriptions
+    pub data: Vec<SubscriptionResponse>,
+}
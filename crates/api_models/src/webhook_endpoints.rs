@@ -0,0 +1,268 @@
+use common_enums::EventType;
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+use masking::Secret;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+/// The request body for creating a webhook endpoint.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookEndpointCreateRequest {
+    /// The URL to which the webhooks are delivered.
+    #[schema(max_length = 2048, example = "https://www.example.com/webhooks")]
+    pub url: String,
+
+    /// The secret used to sign the webhooks delivered to this endpoint. If the value is not
+    /// provided, a value is automatically generated.
+    #[schema(value_type = Option<String>, max_length = 255)]
+    pub secret: Option<Secret<String>>,
+
+    /// The event types the endpoint is subscribed to.
+    #[schema(value_type = Vec<EventType>, example = json!(["payment_succeeded", "refund_succeeded"]))]
+    pub enabled_events: Vec<EventType>,
+
+    /// Indicates whether webhooks are delivered to the endpoint. Defaults to `true`.
+    #[schema(default = true, example = true)]
+    pub is_enabled: Option<bool>,
+
+    /// An arbitrary description of the endpoint.
+    #[schema(max_length = 255, example = "Order service")]
+    pub description: Option<String>,
+
+    /// Indicates whether webhooks for an object are delivered to the endpoint strictly in the
+    /// order of their sequence numbers. When enabled, a webhook is held back until the earlier
+    /// webhooks for the same object have been acknowledged by the endpoint or have exhausted
+    /// their retries. Defaults to `false`.
+    #[schema(default = false, example = true)]
+    pub strict_ordering: Option<bool>,
+}
+
+/// The request body for updating a webhook endpoint.
+#[derive(Debug, Clone, Default, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookEndpointUpdateRequest {
+    /// The URL to which the webhooks are delivered.
+    #[schema(max_length = 2048, example = "https://www.example.com/webhooks")]
+    pub url: Option<String>,
+
+    /// The event types the endpoint is subscribed to.
+    #[schema(value_type = Option<Vec<EventType>>, example = json!(["payment_succeeded"]))]
+    pub enabled_events: Option<Vec<EventType>>,
+
+    /// Indicates whether webhooks are delivered to the endpoint.
+    #[schema(example = false)]
+    pub is_enabled: Option<bool>,
+
+    /// An arbitrary description of the endpoint.
+    #[schema(max_length = 255, example = "Order service")]
+    pub description: Option<String>,
+
+    /// Indicates whether webhooks for an object are delivered to the endpoint strictly in the
+    /// order of their sequence numbers.
+    #[schema(example = true)]
+    pub strict_ordering: Option<bool>,
+}
+
+/// The response body for a webhook endpoint.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct WebhookEndpointResponse {
+    /// The identifier for the webhook endpoint.
+    #[schema(max_length = 64, example = "whe_6TOL4mRqRQgAhuOtSAQa")]
+    pub endpoint_id: String,
+
+    /// The identifier for the Merchant Account.
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// The identifier for the Business Profile.
+    #[schema(max_length = 64, value_type = String, example = "SqB0zwDGR5wHppWf0bx7GKr1f2")]
+    pub profile_id: common_utils::id_type::ProfileId,
+
+    /// The URL to which the webhooks are delivered.
+    #[schema(example = "https://www.example.com/webhooks")]
+    pub url: String,
+
+    /// The secret used to sign the webhooks delivered to this endpoint. This is only returned
+    /// when the endpoint is created.
+    #[schema(value_type = Option<String>)]
+    pub secret: Option<Secret<String>>,
+
+    /// The event types the endpoint is subscribed to.
+    #[schema(value_type = Vec<EventType>)]
+    pub enabled_events: Vec<EventType>,
+
+    /// Indicates whether webhooks are delivered to the endpoint.
+    pub is_enabled: bool,
+
+    /// An arbitrary description of the endpoint.
+    pub description: Option<String>,
+
+    /// Indicates whether webhooks for an object are delivered to the endpoint strictly in the
+    /// order of their sequence numbers.
+    pub strict_ordering: bool,
+
+    /// Time at which the endpoint was created.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+
+    /// Time at which the endpoint was last modified.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+/// The response body for deleting a webhook endpoint.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct WebhookEndpointDeleteResponse {
+    /// The identifier for the webhook endpoint.
+    #[schema(max_length = 64, example = "whe_6TOL4mRqRQgAhuOtSAQa")]
+    pub endpoint_id: String,
+
+    /// Indicates whether the endpoint was deleted.
+    pub deleted: bool,
+}
+
+/// The status of a webhook endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookEndpointStatus {
+    /// Webhooks are delivered to the endpoint.
+    Enabled,
+    /// The endpoint was disabled by the merchant.
+    Disabled,
+    /// The endpoint was disabled automatically after repeatedly failing to accept webhooks.
+    /// Events are still recorded for the endpoint, and are delivered once it is re-enabled.
+    Suspended,
+}
+
+/// The response body for the health of a webhook endpoint. The delivery statistics are computed
+/// over the delivery attempts made since `window_start`.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct WebhookEndpointHealthResponse {
+    /// The identifier for the webhook endpoint.
+    #[schema(max_length = 64, example = "whe_6TOL4mRqRQgAhuOtSAQa")]
+    pub endpoint_id: String,
+
+    /// The status of the webhook endpoint.
+    pub status: WebhookEndpointStatus,
+
+    /// The number of consecutive delivery attempts which failed.
+    #[schema(example = 0)]
+    pub consecutive_failures: i32,
+
+    /// Time of the last successful delivery attempt.
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub last_successful_delivery_at: Option<PrimitiveDateTime>,
+
+    /// Time of the last failed delivery attempt.
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub last_failed_delivery_at: Option<PrimitiveDateTime>,
+
+    /// Time at which the endpoint was suspended.
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub suspended_at: Option<PrimitiveDateTime>,
+
+    /// Start of the window over which the delivery statistics are computed.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub window_start: PrimitiveDateTime,
+
+    /// The number of delivery attempts made in the window.
+    #[schema(example = 120)]
+    pub delivery_attempts: u32,
+
+    /// The number of successful delivery attempts made in the window.
+    #[schema(example = 118)]
+    pub successful_delivery_attempts: u32,
+
+    /// The fraction of delivery attempts in the window which were successful.
+    #[schema(example = 0.98)]
+    pub success_rate: Option<f64>,
+
+    /// The 95th percentile of the time taken by the endpoint to respond, in milliseconds.
+    #[schema(example = 350)]
+    pub p95_latency_ms: Option<i32>,
+}
+
+/// The response body for re-enabling a suspended webhook endpoint.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct WebhookEndpointReenableResponse {
+    /// The identifier for the webhook endpoint.
+    #[schema(max_length = 64, example = "whe_6TOL4mRqRQgAhuOtSAQa")]
+    pub endpoint_id: String,
+
+    /// The number of events missed by the endpoint, which are being delivered to it again.
+    #[schema(example = 42)]
+    pub replayed_events: u32,
+}
+
+/// The request body for rotating the secret used to sign outgoing webhooks.
+#[derive(Debug, Clone, Default, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookSecretRotateRequest {
+    /// The number of seconds for which webhooks continue to be signed using the previous secret
+    /// as well, allowing consumers to switch over to the new secret. Defaults to 86400 seconds
+    /// (24 hours), and cannot exceed 604800 seconds (7 days). A value of `0` expires the previous
+    /// secret immediately.
+    #[schema(maximum = 604800, example = 86400)]
+    pub expires_in: Option<u32>,
+}
+
+/// The response body for rotating the secret used to sign outgoing webhooks.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct WebhookSecretRotateResponse {
+    /// The newly generated secret used to sign outgoing webhooks.
+    #[schema(value_type = String)]
+    pub secret: Secret<String>,
+
+    /// Time until which webhooks continue to be signed using the previous secret as well. This
+    /// is not set if no secret was configured previously.
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub previous_secret_expires_at: Option<PrimitiveDateTime>,
+}
+
+impl ApiEventMetric for WebhookEndpointCreateRequest {}
+
+impl ApiEventMetric for WebhookEndpointUpdateRequest {}
+
+impl ApiEventMetric for WebhookEndpointResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::WebhookEndpoint {
+            endpoint_id: self.endpoint_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for WebhookEndpointDeleteResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::WebhookEndpoint {
+            endpoint_id: self.endpoint_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for WebhookEndpointHealthResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::WebhookEndpoint {
+            endpoint_id: self.endpoint_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for WebhookEndpointReenableResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::WebhookEndpoint {
+            endpoint_id: self.endpoint_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for WebhookSecretRotateRequest {}
+
+impl ApiEventMetric for WebhookSecretRotateResponse {}
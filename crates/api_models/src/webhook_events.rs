@@ -76,6 +76,11 @@ pub struct EventListItemResponse {
     #[schema(max_length = 64, example = "evt_018e31720d1b7a2b82677d3032cab959")]
     pub initial_attempt_id: String,
 
+    /// The identifier for the webhook endpoint the event was delivered to. This is not populated
+    /// for events delivered to the webhook URL configured on the business profile.
+    #[schema(max_length = 64, example = "whe_6TOL4mRqRQgAhuOtSAQa")]
+    pub webhook_endpoint_id: Option<String>,
+
     /// Time at which the event was created.
     #[schema(example = "2022-09-10T10:11:12Z")]
     #[serde(with = "common_utils::custom_serde::iso8601")]
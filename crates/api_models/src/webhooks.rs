@@ -5,7 +5,10 @@ use utoipa::ToSchema;
 
 #[cfg(feature = "payouts")]
 use crate::payouts;
-use crate::{disputes, enums as api_enums, mandates, payments, refunds};
+use crate::{
+    analytics::success_rate_alerts, disputes, enums as api_enums, mandates, payment_methods,
+    payments, refunds, subscriptions,
+};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Copy)]
 #[serde(rename_all = "snake_case")]
@@ -223,6 +226,18 @@ pub struct OutgoingWebhook {
     /// The time at which webhook was sent
     #[serde(default, with = "custom_serde::iso8601")]
     pub timestamp: PrimitiveDateTime,
+
+    /// Monotonically increasing sequence number of the event among the events of the same object.
+    /// Consumers can use it to discard events received out of order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = 2)]
+    pub sequence_number: Option<i64>,
+
+    /// The identifiers of earlier events of the same object which had not been acknowledged by
+    /// the webhook endpoint when this event was created. Only populated for webhook endpoints
+    /// with strict ordering enabled.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub previous_event_ids: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, ToSchema)]
@@ -239,6 +254,12 @@ pub enum OutgoingWebhookContent {
     #[cfg(feature = "payouts")]
     #[schema(value_type = PayoutCreateResponse, title = "PayoutCreateResponse")]
     PayoutDetails(Box<payouts::PayoutCreateResponse>),
+    #[schema(value_type = SubscriptionResponse, title = "SubscriptionResponse")]
+    SubscriptionDetails(Box<subscriptions::SubscriptionResponse>),
+    #[schema(value_type = PaymentMethodResponse, title = "PaymentMethodResponse")]
+    PaymentMethodDetails(Box<payment_methods::PaymentMethodResponse>),
+    #[schema(value_type = SuccessRateAlert, title = "SuccessRateAlert")]
+    SuccessRateAlertDetails(Box<success_rate_alerts::SuccessRateAlert>),
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -1156,6 +1156,9 @@ pub enum EventClass {
     Mandates,
     #[cfg(feature = "payouts")]
     Payouts,
+    Subscriptions,
+    PaymentMethods,
+    Alerts,
 }
 
 #[derive(
@@ -1192,6 +1195,7 @@ pub enum EventType {
     DisputeChallenged,
     DisputeWon,
     DisputeLost,
+    DisputeDeadlineApproaching,
     MandateActive,
     MandateRevoked,
     PayoutSuccess,
@@ -1201,6 +1205,13 @@ pub enum EventType {
     PayoutCancelled,
     PayoutExpired,
     PayoutReversed,
+    SubscriptionCreated,
+    SubscriptionRenewed,
+    SubscriptionPaymentFailed,
+    SubscriptionCancelled,
+    PaymentMethodUpdated,
+    PaymentSuccessRateDropped,
+    PaymentSuccessRateRecovered,
 }
 
 #[derive(
@@ -1224,6 +1235,29 @@ pub enum WebhookDeliveryAttempt {
     ManualRetry,
 }
 
+/// The outcome of processing an incoming webhook received from a connector.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum IncomingWebhookProcessingStatus {
+    /// The webhook was processed successfully, or acknowledged without any effect.
+    Processed,
+    /// Processing the webhook failed.
+    Failed,
+}
+
 // TODO: This decision about using KV mode or not,
 // should be taken at a top level rather than pushing it down to individual functions via an enum.
 #[derive(
@@ -1247,6 +1281,36 @@ pub enum MerchantStorageScheme {
     RedisKv,
 }
 
+/// The status of a managed storage scheme migration of a merchant account
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum StorageSchemeMigrationStatus {
+    /// Waiting for the drainer to flush the merchant's pending KV entries to the database. A
+    /// merchant leaving KV is switched to the target storage scheme before draining.
+    Draining,
+    /// Comparing a sample of the merchant's KV entries against the database. A merchant entering KV
+    /// is switched to the target storage scheme once verified.
+    Verifying,
+    /// The migration completed successfully
+    Completed,
+    /// The migration could not be completed within the configured number of attempts
+    Failed,
+    /// The migration was rolled back to the source storage scheme
+    RolledBack,
+}
+
 /// The status of the current payment that was made
 #[derive(
     Clone,
@@ -2393,6 +2457,429 @@ pub enum PayoutSendPriority {
     Internal,
 }
 
+/// The status of a subscription
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+    Hash,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SubscriptionStatus {
+    /// The subscription is in its trial period, the customer is not charged until the trial ends
+    Trialing,
+    /// The customer is charged on every billing date
+    Active,
+    /// The charge for the current billing period has failed
+    PastDue,
+    /// The subscription has been cancelled and the customer will not be charged anymore
+    Cancelled,
+}
+
+/// The status of the recovery of a failed recurring payment
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+    Hash,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PaymentRecoveryStatus {
+    /// Another attempt to charge the customer has been scheduled
+    Scheduled,
+    /// One of the retries succeeded
+    Recovered,
+    /// The payment was declined with a hard decline, or all retries failed
+    Lost,
+}
+
+/// How the shares of a split payment are transferred to its recipients
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+    Hash,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SplitSettlementType {
+    /// The connector transfers the shares to the accounts of the recipients
+    Connector,
+    /// The shares are only recorded, and have to be paid out to the recipients by the platform
+    Ledger,
+}
+
+/// The status of a batch of payouts created from a file
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+    Hash,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutBatchStatus {
+    /// The payouts of the batch are yet to be created
+    #[default]
+    Pending,
+    /// The payouts of the batch are being created
+    Processing,
+    /// All the payouts of the batch have been created
+    Completed,
+    /// Some of the payouts of the batch could not be created
+    PartiallyCompleted,
+    /// None of the payouts of the batch could be created
+    Failed,
+}
+
+/// The status of a batch of refunds created from a file
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+    Hash,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RefundBatchStatus {
+    /// The refunds of the batch are yet to be created
+    #[default]
+    Pending,
+    /// The refunds of the batch are being created
+    Processing,
+    /// All the refunds of the batch have been created
+    Completed,
+    /// Some of the refunds of the batch could not be created
+    PartiallyCompleted,
+    /// None of the refunds of the batch could be created
+    Failed,
+}
+
+/// The unit of time after which a subscription is billed again
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+    Hash,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SubscriptionInterval {
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+/// The change to a stored card reported by the account updater
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+    Hash,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CardAccountUpdateType {
+    /// The card has been given a new expiry date
+    ExpiryUpdated,
+    /// The card has been replaced with a card having a new card number
+    CardReissued,
+    /// The account of the card has been closed
+    AccountClosed,
+}
+
+/// The way in which the amount of the payments made through a reusable payment link is determined
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+    Hash,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ReusablePaymentLinkAmountType {
+    /// Every payment is made for the amount configured on the link
+    Fixed,
+    /// The customer chooses the amount of the payment, within the limits configured on the link
+    CustomerChosen,
+}
+
+/// The status of an invoice
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+    Hash,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum InvoiceStatus {
+    /// The invoice is awaiting payment and is not due yet
+    Open,
+    /// The invoice has been paid
+    Paid,
+    /// The due date of the invoice has passed without it being paid
+    Overdue,
+    /// The invoice has been voided by the merchant and can no longer be paid
+    Void,
+}
+
+/// The analytics domain whose metrics are computed by a scheduled report
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+    Hash,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AnalyticsReportDomain {
+    /// Payment attempt metrics
+    Payments,
+    /// Payment intent metrics
+    PaymentIntents,
+    /// Refund metrics
+    Refunds,
+    /// Dispute metrics
+    Disputes,
+    /// Payout metrics
+    Payouts,
+}
+
+/// How often a scheduled analytics report is generated
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+    Hash,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ReportFrequency {
+    /// The report is generated every day and covers the previous day
+    Daily,
+    /// The report is generated every week and covers the previous seven days
+    Weekly,
+}
+
+/// How a generated analytics report is delivered to the merchant
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+    Hash,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ReportDeliveryMethod {
+    /// The report is sent to the configured recipients by email
+    Email,
+    /// The report is uploaded as a CSV file to the configured file storage
+    FileStorage,
+}
+
+/// The status of a scheduled analytics report
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+    Hash,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ReportScheduleStatus {
+    /// The report is generated on its schedule
+    Active,
+    /// The report is not generated until it is resumed
+    Paused,
+}
+
+/// The records exported by an export job
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+    Hash,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ExportType {
+    /// Payments, filtered with the constraints of the payments list
+    Payments,
+    /// Refunds, filtered with the constraints of the refunds list
+    Refunds,
+    /// Disputes, filtered with the constraints of the disputes list
+    Disputes,
+    /// The metrics of an analytics domain
+    AnalyticsMetrics,
+}
+
+/// The file format records are exported to
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+    Hash,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ExportFormat {
+    Csv,
+    Parquet,
+}
+
+/// The status of an export job
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+    Hash,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ExportStatus {
+    /// The export is waiting to be picked up by the scheduler
+    Pending,
+    /// The records are being exported
+    Processing,
+    /// The file is available for download
+    Completed,
+    /// The export could not be completed
+    Failed,
+}
+
 #[derive(
     Clone,
     Copy,
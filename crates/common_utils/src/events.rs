@@ -15,6 +15,9 @@ pub enum ApiEventsType {
     Payout {
         payout_id: String,
     },
+    PayoutBatch {
+        batch_id: String,
+    },
     #[cfg(feature = "v1")]
     Payment {
         payment_id: id_type::PaymentId,
@@ -33,6 +36,9 @@ pub enum ApiEventsType {
         payment_id: id_type::GlobalPaymentId,
         refund_id: id_type::GlobalRefundId,
     },
+    RefundBatch {
+        batch_id: String,
+    },
     PaymentMethod {
         payment_method_id: String,
         payment_method: Option<PaymentMethod>,
@@ -84,12 +90,36 @@ pub enum ApiEventsType {
     Events {
         merchant_id: id_type::MerchantId,
     },
+    IncomingWebhooks {
+        merchant_id: id_type::MerchantId,
+    },
     PaymentMethodCollectLink {
         link_id: String,
     },
     Poll {
         poll_id: String,
     },
+    SubscriptionPlan {
+        plan_id: String,
+    },
+    Subscription {
+        subscription_id: String,
+    },
+    ReusablePaymentLink {
+        link_id: String,
+    },
+    Invoice {
+        invoice_id: String,
+    },
+    WebhookEndpoint {
+        endpoint_id: String,
+    },
+    AnalyticsReportSchedule {
+        report_schedule_id: String,
+    },
+    ExportJob {
+        export_id: String,
+    },
     Analytics,
 }
 
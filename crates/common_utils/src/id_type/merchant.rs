@@ -204,6 +204,35 @@ impl MerchantId {
         format!("should_call_gsm_{}", self.get_string_repr())
     }
 
+    /// Get the key of the config which enables the recovery of failed recurring payments
+    pub fn get_payment_recovery_enabled_key(&self) -> String {
+        format!("payment_recovery_enabled_{}", self.get_string_repr())
+    }
+
+    /// Get the key of the config which limits the number of refunds of a batch sent to a
+    /// connector every minute
+    pub fn get_refund_batch_rate_limit_key(&self, connector: &str) -> String {
+        format!(
+            "refund_batch_rate_limit_{}_{connector}",
+            self.get_string_repr()
+        )
+    }
+
+    /// Get the key in redis which tracks the refunds of the batches of the merchant scheduled to be
+    /// sent to a connector
+    pub fn get_refund_batch_schedule_key(&self, connector: &str) -> String {
+        format!(
+            "refund_batch_schedule_{}_{connector}",
+            self.get_string_repr()
+        )
+    }
+
+    /// Get the key of the config which holds the dispute deadline reminder and auto acceptance
+    /// settings of the merchant
+    pub fn get_dispute_deadline_config_key(&self) -> String {
+        format!("dispute_deadline_config_{}", self.get_string_repr())
+    }
+
     /// get_max_auto_single_connector_payout_retries_enabled_
     pub fn get_max_auto_single_connector_payout_retries_enabled(
         &self,
@@ -49,6 +49,11 @@ impl PaymentId {
         format!("pm_auth_{}", self.get_string_repr())
     }
 
+    /// Generate a key for caching the installment plans offered for the payment
+    pub fn get_installment_plans_key(&self) -> String {
+        format!("installment_plans_{}", self.get_string_repr())
+    }
+
     /// Get external authentication request poll id
     pub fn get_external_authentication_request_poll_id(&self) -> String {
         format!("external_authentication_{}", self.get_string_repr())
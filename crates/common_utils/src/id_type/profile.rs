@@ -23,6 +23,13 @@ impl crate::events::ApiEventMetric for ProfileId {
     }
 }
 
+impl ProfileId {
+    /// Get the key of the config which holds the success rate alert rules of the profile
+    pub fn get_success_rate_alert_config_key(&self) -> String {
+        format!("success_rate_alert_config_{}", self.get_string_repr())
+    }
+}
+
 impl FromStr for ProfileId {
     type Err = error_stack::Report<crate::errors::ValidationError>;
 
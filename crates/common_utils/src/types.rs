@@ -99,6 +99,33 @@ impl<const PRECISION: u8> Percentage<PRECISION> {
         }
     }
 
+    /// apply the percentage to amount and floor the result
+    #[allow(clippy::as_conversions)]
+    pub fn apply_and_floor_result(
+        &self,
+        amount: MinorUnit,
+    ) -> CustomResult<MinorUnit, PercentageError> {
+        let max_amount = i64::MAX / 10000;
+        let amount = amount.0;
+        if amount > max_amount {
+            Err(report!(PercentageError::UnableToApplyPercentage {
+                percentage: self.percentage,
+                amount: MinorUnit::new(amount),
+            }))
+            .attach_printable(format!(
+                "Cannot calculate percentage for amount greater than {}",
+                max_amount
+            ))
+        } else {
+            // the percentage is scaled to an integer before being applied, as flooring the float
+            // result would lose a unit for percentages which are not exactly representable
+            let scale = 10_i128.pow(u32::from(PRECISION));
+            let scaled_percentage = (f64::from(self.percentage) * scale as f64).round() as i128;
+            let result = i128::from(amount) * scaled_percentage / (100 * scale);
+            Ok(MinorUnit::new(result as i64))
+        }
+    }
+
     fn is_valid_string_value(value: &str) -> CustomResult<bool, PercentageError> {
         let float_value = Self::is_valid_float_string(value)?;
         Ok(Self::is_valid_range(float_value) && Self::is_valid_precision_length(value))
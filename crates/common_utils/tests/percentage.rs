@@ -1,5 +1,8 @@
 #![allow(clippy::panic_in_result_fn)]
-use common_utils::{errors::PercentageError, types::Percentage};
+use common_utils::{
+    errors::PercentageError,
+    types::{MinorUnit, Percentage},
+};
 const PRECISION_2: u8 = 2;
 const PRECISION_0: u8 = 0;
 
@@ -85,6 +88,45 @@ fn valid_precision() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     Ok(())
 }
 
+#[test]
+fn apply_and_floor_result() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let percentage = Percentage::<PRECISION_2>::from_string("50".to_string());
+    assert!(percentage.is_ok());
+    if let Ok(percentage) = percentage {
+        assert_eq!(
+            percentage
+                .apply_and_floor_result(MinorUnit::new(101))
+                .map_err(|err| err.to_string())?,
+            MinorUnit::new(50)
+        )
+    }
+
+    // 29% is not exactly representable as a float
+    let percentage = Percentage::<PRECISION_2>::from_string("29".to_string());
+    assert!(percentage.is_ok());
+    if let Ok(percentage) = percentage {
+        assert_eq!(
+            percentage
+                .apply_and_floor_result(MinorUnit::new(100))
+                .map_err(|err| err.to_string())?,
+            MinorUnit::new(29)
+        )
+    }
+
+    let percentage = Percentage::<PRECISION_2>::from_string("12.34".to_string());
+    assert!(percentage.is_ok());
+    if let Ok(percentage) = percentage {
+        assert_eq!(
+            percentage
+                .apply_and_floor_result(MinorUnit::new(1999))
+                .map_err(|err| err.to_string())?,
+            MinorUnit::new(246)
+        )
+    }
+
+    Ok(())
+}
+
 #[test]
 fn invalid_precision() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     let percentage = Percentage::<PRECISION_2>::from_string("2.221".to_string());
@@ -0,0 +1,129 @@
+use common_utils::pii;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::analytics_report_schedule};
+
+#[derive(Clone, Debug, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = analytics_report_schedule)]
+pub struct AnalyticsReportScheduleNew {
+    pub report_schedule_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub organization_id: common_utils::id_type::OrganizationId,
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+    pub name: String,
+    pub domain: storage_enums::AnalyticsReportDomain,
+    pub metric_request: serde_json::Value,
+    pub frequency: storage_enums::ReportFrequency,
+    pub delivery_method: storage_enums::ReportDeliveryMethod,
+    pub recipients: Vec<pii::Email>,
+    pub status: storage_enums::ReportScheduleStatus,
+    pub last_run_at: Option<PrimitiveDateTime>,
+    pub next_run_at: PrimitiveDateTime,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(
+    table_name = analytics_report_schedule,
+    primary_key(report_schedule_id),
+    check_for_backend(diesel::pg::Pg)
+)]
+pub struct AnalyticsReportSchedule {
+    pub report_schedule_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub organization_id: common_utils::id_type::OrganizationId,
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+    pub name: String,
+    pub domain: storage_enums::AnalyticsReportDomain,
+    pub metric_request: serde_json::Value,
+    pub frequency: storage_enums::ReportFrequency,
+    pub delivery_method: storage_enums::ReportDeliveryMethod,
+    #[diesel(deserialize_as = super::DieselArray<pii::Email>)]
+    pub recipients: Vec<pii::Email>,
+    pub status: storage_enums::ReportScheduleStatus,
+    pub last_run_at: Option<PrimitiveDateTime>,
+    pub next_run_at: PrimitiveDateTime,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum AnalyticsReportScheduleUpdate {
+    Update {
+        name: Option<String>,
+        metric_request: Option<serde_json::Value>,
+        frequency: Option<storage_enums::ReportFrequency>,
+        delivery_method: Option<storage_enums::ReportDeliveryMethod>,
+        recipients: Option<Vec<pii::Email>>,
+        status: Option<storage_enums::ReportScheduleStatus>,
+        next_run_at: Option<PrimitiveDateTime>,
+    },
+    RunUpdate {
+        last_run_at: PrimitiveDateTime,
+        next_run_at: PrimitiveDateTime,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset)]
+#[diesel(table_name = analytics_report_schedule)]
+pub struct AnalyticsReportScheduleUpdateInternal {
+    pub name: Option<String>,
+    pub metric_request: Option<serde_json::Value>,
+    pub frequency: Option<storage_enums::ReportFrequency>,
+    pub delivery_method: Option<storage_enums::ReportDeliveryMethod>,
+    pub recipients: Option<Vec<pii::Email>>,
+    pub status: Option<storage_enums::ReportScheduleStatus>,
+    pub last_run_at: Option<PrimitiveDateTime>,
+    pub next_run_at: Option<PrimitiveDateTime>,
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl From<AnalyticsReportScheduleUpdate> for AnalyticsReportScheduleUpdateInternal {
+    fn from(schedule_update: AnalyticsReportScheduleUpdate) -> Self {
+        match schedule_update {
+            AnalyticsReportScheduleUpdate::Update {
+                name,
+                metric_request,
+                frequency,
+                delivery_method,
+                recipients,
+                status,
+                next_run_at,
+            } => Self {
+                name,
+                metric_request,
+                frequency,
+                delivery_method,
+                recipients,
+                status,
+                last_run_at: None,
+                next_run_at,
+                modified_at: common_utils::date_time::now(),
+            },
+            AnalyticsReportScheduleUpdate::RunUpdate {
+                last_run_at,
+                next_run_at,
+            } => Self {
+                name: None,
+                metric_request: None,
+                frequency: None,
+                delivery_method: None,
+                recipients: None,
+                status: None,
+                last_run_at: Some(last_run_at),
+                next_run_at: Some(next_run_at),
+                modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
+
+// Tracking data by process_tracker
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnalyticsReportTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub report_schedule_id: String,
+}
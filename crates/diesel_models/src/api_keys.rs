@@ -149,3 +149,14 @@ pub struct ApiKeyExpiryTrackingData {
     // Days on which email reminder about api_key expiry has to be sent, prior to it's expiry.
     pub expiry_reminder_days: Vec<u8>,
 }
+
+// Tracking data by process_tracker
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ApiKeyRotationTrackingData {
+    pub key_id: common_utils::id_type::ApiKeyId,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    // The API key issued in place of the rotated key, recorded for audit purposes.
+    pub successor_key_id: common_utils::id_type::ApiKeyId,
+    // Time after which the rotated key is revoked.
+    pub revoke_at: PrimitiveDateTime,
+}
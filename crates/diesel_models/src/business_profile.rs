@@ -57,6 +57,8 @@ pub struct Profile {
     pub is_network_tokenization_enabled: bool,
     pub is_auto_retries_enabled: Option<bool>,
     pub max_auto_retries_enabled: Option<i16>,
+    pub previous_payment_response_hash_key: Option<String>,
+    pub previous_payment_response_hash_key_expires_at: Option<time::PrimitiveDateTime>,
 }
 
 #[cfg(feature = "v1")]
@@ -100,6 +102,8 @@ pub struct ProfileNew {
     pub is_network_tokenization_enabled: bool,
     pub is_auto_retries_enabled: Option<bool>,
     pub max_auto_retries_enabled: Option<i16>,
+    pub previous_payment_response_hash_key: Option<String>,
+    pub previous_payment_response_hash_key_expires_at: Option<time::PrimitiveDateTime>,
 }
 
 #[cfg(feature = "v1")]
@@ -140,6 +144,8 @@ pub struct ProfileUpdateInternal {
     pub is_network_tokenization_enabled: Option<bool>,
     pub is_auto_retries_enabled: Option<bool>,
     pub max_auto_retries_enabled: Option<i16>,
+    pub previous_payment_response_hash_key: Option<String>,
+    pub previous_payment_response_hash_key_expires_at: Option<time::PrimitiveDateTime>,
 }
 
 #[cfg(feature = "v1")]
@@ -179,6 +185,8 @@ impl ProfileUpdateInternal {
             is_network_tokenization_enabled,
             is_auto_retries_enabled,
             max_auto_retries_enabled,
+            previous_payment_response_hash_key,
+            previous_payment_response_hash_key_expires_at,
         } = self;
         Profile {
             profile_id: source.profile_id,
@@ -238,6 +246,11 @@ impl ProfileUpdateInternal {
                 .unwrap_or(source.is_network_tokenization_enabled),
             is_auto_retries_enabled: is_auto_retries_enabled.or(source.is_auto_retries_enabled),
             max_auto_retries_enabled: max_auto_retries_enabled.or(source.max_auto_retries_enabled),
+            previous_payment_response_hash_key: previous_payment_response_hash_key
+                .or(source.previous_payment_response_hash_key),
+            previous_payment_response_hash_key_expires_at:
+                previous_payment_response_hash_key_expires_at
+                    .or(source.previous_payment_response_hash_key_expires_at),
         }
     }
 }
@@ -291,6 +304,8 @@ pub struct Profile {
     pub is_network_tokenization_enabled: bool,
     pub is_auto_retries_enabled: Option<bool>,
     pub max_auto_retries_enabled: Option<i16>,
+    pub previous_payment_response_hash_key: Option<String>,
+    pub previous_payment_response_hash_key_expires_at: Option<time::PrimitiveDateTime>,
 }
 
 impl Profile {
@@ -348,6 +363,8 @@ pub struct ProfileNew {
     pub is_network_tokenization_enabled: bool,
     pub is_auto_retries_enabled: Option<bool>,
     pub max_auto_retries_enabled: Option<i16>,
+    pub previous_payment_response_hash_key: Option<String>,
+    pub previous_payment_response_hash_key_expires_at: Option<time::PrimitiveDateTime>,
 }
 
 #[cfg(feature = "v2")]
@@ -389,6 +406,8 @@ pub struct ProfileUpdateInternal {
     pub is_network_tokenization_enabled: Option<bool>,
     pub is_auto_retries_enabled: Option<bool>,
     pub max_auto_retries_enabled: Option<i16>,
+    pub previous_payment_response_hash_key: Option<String>,
+    pub previous_payment_response_hash_key_expires_at: Option<time::PrimitiveDateTime>,
 }
 
 #[cfg(feature = "v2")]
@@ -429,6 +448,8 @@ impl ProfileUpdateInternal {
             is_network_tokenization_enabled,
             is_auto_retries_enabled,
             max_auto_retries_enabled,
+            previous_payment_response_hash_key,
+            previous_payment_response_hash_key_expires_at,
         } = self;
         Profile {
             id: source.id,
@@ -491,6 +512,11 @@ impl ProfileUpdateInternal {
                 .unwrap_or(source.is_network_tokenization_enabled),
             is_auto_retries_enabled: is_auto_retries_enabled.or(source.is_auto_retries_enabled),
             max_auto_retries_enabled: max_auto_retries_enabled.or(source.max_auto_retries_enabled),
+            previous_payment_response_hash_key: previous_payment_response_hash_key
+                .or(source.previous_payment_response_hash_key),
+            previous_payment_response_hash_key_expires_at:
+                previous_payment_response_hash_key_expires_at
+                    .or(source.previous_payment_response_hash_key_expires_at),
         }
     }
 }
@@ -558,3 +584,14 @@ pub struct BusinessGenericLinkConfig {
 }
 
 common_utils::impl_to_sql_from_sql_json!(BusinessPayoutLinkConfig);
+
+// Tracking data by process_tracker
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct SuccessRateAlertTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    // Identifiers of the alerts which have been triggered and not resolved yet, so that an alert
+    // is raised only once for a drop of the success rate
+    #[serde(default)]
+    pub active_alerts: Vec<String>,
+}
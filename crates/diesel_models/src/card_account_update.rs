@@ -0,0 +1,48 @@
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::card_account_update};
+
+#[derive(Clone, Debug, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = card_account_update)]
+pub struct CardAccountUpdateNew {
+    pub update_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub customer_id: common_utils::id_type::CustomerId,
+    pub payment_method_id: String,
+    pub update_type: storage_enums::CardAccountUpdateType,
+    pub previous_last4: Option<String>,
+    pub previous_expiry_month: Option<String>,
+    pub previous_expiry_year: Option<String>,
+    pub updated_last4: Option<String>,
+    pub updated_expiry_month: Option<String>,
+    pub updated_expiry_year: Option<String>,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = card_account_update, primary_key(update_id), check_for_backend(diesel::pg::Pg))]
+pub struct CardAccountUpdate {
+    pub update_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub customer_id: common_utils::id_type::CustomerId,
+    pub payment_method_id: String,
+    pub update_type: storage_enums::CardAccountUpdateType,
+    pub previous_last4: Option<String>,
+    pub previous_expiry_month: Option<String>,
+    pub previous_expiry_year: Option<String>,
+    pub updated_last4: Option<String>,
+    pub updated_expiry_month: Option<String>,
+    pub updated_expiry_year: Option<String>,
+    pub created_at: PrimitiveDateTime,
+}
+
+// Tracking data by process_tracker
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountUpdaterTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    /// Number of customers of the merchant whose cards have already been checked in the current
+    /// cycle
+    pub customer_offset: u32,
+}
@@ -1,7 +1,7 @@
 use common_utils::custom_serde;
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
 use masking::Secret;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 
 use crate::{enums as storage_enums, schema::dispute};
@@ -147,3 +147,15 @@ impl From<DisputeUpdate> for DisputeUpdateInternal {
         }
     }
 }
+
+// Tracking data by process_tracker
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct DisputeDeadlineTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub dispute_id: String,
+    // Deadline for which the reminders have been sent. Reminders are sent afresh if the connector
+    // extends the deadline.
+    pub challenge_required_by: PrimitiveDateTime,
+    // Number of hours before the deadline at which the last reminder was sent.
+    pub last_reminder_hours: Option<u16>,
+}
@@ -73,6 +73,9 @@ pub enum EventObjectType {
     DisputeDetails,
     MandateDetails,
     PayoutDetails,
+    SubscriptionDetails,
+    PaymentMethodDetails,
+    SuccessRateAlertDetails,
 }
 
 #[derive(
@@ -30,6 +30,9 @@ pub struct EventNew {
     pub response: Option<Encryption>,
     pub delivery_attempt: Option<storage_enums::WebhookDeliveryAttempt>,
     pub metadata: Option<EventMetadata>,
+    pub webhook_endpoint_id: Option<String>,
+    pub delivery_latency_ms: Option<i32>,
+    pub sequence_number: Option<i64>,
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -37,6 +40,7 @@ pub struct EventNew {
 pub struct EventUpdateInternal {
     pub is_webhook_notified: Option<bool>,
     pub response: Option<Encryption>,
+    pub delivery_latency_ms: Option<i32>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable, Selectable)]
@@ -61,6 +65,9 @@ pub struct Event {
     pub response: Option<Encryption>,
     pub delivery_attempt: Option<storage_enums::WebhookDeliveryAttempt>,
     pub metadata: Option<EventMetadata>,
+    pub webhook_endpoint_id: Option<String>,
+    pub delivery_latency_ms: Option<i32>,
+    pub sequence_number: Option<i64>,
 }
 
 pub struct EventWithEncryption {
@@ -117,6 +124,15 @@ pub enum EventMetadata {
         payment_method_id: String,
         mandate_id: String,
     },
+    Subscription {
+        subscription_id: String,
+    },
+    PaymentMethod {
+        payment_method_id: String,
+    },
+    SuccessRateAlert {
+        alert_id: String,
+    },
 }
 
 common_utils::impl_to_sql_from_sql_json!(EventMetadata);
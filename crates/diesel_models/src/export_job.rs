@@ -0,0 +1,105 @@
+use common_utils::pii;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::export_job};
+
+#[derive(Clone, Debug, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = export_job)]
+pub struct ExportJobNew {
+    pub export_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub organization_id: common_utils::id_type::OrganizationId,
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+    pub export_type: storage_enums::ExportType,
+    pub analytics_domain: Option<storage_enums::AnalyticsReportDomain>,
+    pub format: storage_enums::ExportFormat,
+    pub constraints: serde_json::Value,
+    pub recipients: Vec<pii::Email>,
+    pub status: storage_enums::ExportStatus,
+    pub file_key: Option<String>,
+    pub row_count: Option<i32>,
+    pub error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+    pub completed_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = export_job, primary_key(export_id), check_for_backend(diesel::pg::Pg))]
+pub struct ExportJob {
+    pub export_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub organization_id: common_utils::id_type::OrganizationId,
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+    pub export_type: storage_enums::ExportType,
+    pub analytics_domain: Option<storage_enums::AnalyticsReportDomain>,
+    pub format: storage_enums::ExportFormat,
+    pub constraints: serde_json::Value,
+    #[diesel(deserialize_as = super::DieselArray<pii::Email>)]
+    pub recipients: Vec<pii::Email>,
+    pub status: storage_enums::ExportStatus,
+    pub file_key: Option<String>,
+    pub row_count: Option<i32>,
+    pub error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+    pub completed_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug)]
+pub enum ExportJobUpdate {
+    StatusUpdate { status: storage_enums::ExportStatus },
+    CompletionUpdate { file_key: String, row_count: i32 },
+    FailureUpdate { error_message: String },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset)]
+#[diesel(table_name = export_job)]
+pub struct ExportJobUpdateInternal {
+    pub status: Option<storage_enums::ExportStatus>,
+    pub file_key: Option<String>,
+    pub row_count: Option<i32>,
+    pub error_message: Option<String>,
+    pub modified_at: Option<PrimitiveDateTime>,
+    pub completed_at: Option<PrimitiveDateTime>,
+}
+
+impl From<ExportJobUpdate> for ExportJobUpdateInternal {
+    fn from(export_update: ExportJobUpdate) -> Self {
+        let now = common_utils::date_time::now();
+        match export_update {
+            ExportJobUpdate::StatusUpdate { status } => Self {
+                status: Some(status),
+                modified_at: Some(now),
+                ..Default::default()
+            },
+            ExportJobUpdate::CompletionUpdate {
+                file_key,
+                row_count,
+            } => Self {
+                status: Some(storage_enums::ExportStatus::Completed),
+                file_key: Some(file_key),
+                row_count: Some(row_count),
+                modified_at: Some(now),
+                completed_at: Some(now),
+                ..Default::default()
+            },
+            ExportJobUpdate::FailureUpdate { error_message } => Self {
+                status: Some(storage_enums::ExportStatus::Failed),
+                error_message: Some(error_message),
+                modified_at: Some(now),
+                completed_at: Some(now),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+// Tracking data by process_tracker
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportJobTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub export_id: String,
+}
@@ -0,0 +1,156 @@
+use common_utils::encryption::Encryption;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::incoming_webhook_archive};
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = incoming_webhook_archive)]
+pub struct IncomingWebhookArchiveNew {
+    pub webhook_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub connector_id_or_name: String,
+    pub connector_name: Option<String>,
+    pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+    pub request: Encryption,
+    pub event_type: Option<String>,
+    pub source_verified: Option<bool>,
+    pub processing_status: storage_enums::IncomingWebhookProcessingStatus,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub reprocess_count: i32,
+    pub last_reprocessed_at: Option<PrimitiveDateTime>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = incoming_webhook_archive, primary_key(webhook_id), check_for_backend(diesel::pg::Pg))]
+pub struct IncomingWebhookArchive {
+    pub webhook_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub connector_id_or_name: String,
+    pub connector_name: Option<String>,
+    pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+    pub request: Encryption,
+    pub event_type: Option<String>,
+    pub source_verified: Option<bool>,
+    pub processing_status: storage_enums::IncomingWebhookProcessingStatus,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub reprocess_count: i32,
+    pub last_reprocessed_at: Option<PrimitiveDateTime>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum IncomingWebhookArchiveUpdate {
+    /// Records the outcome of reprocessing the archived webhook.
+    ReprocessUpdate {
+        connector_name: Option<String>,
+        merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+        profile_id: Option<common_utils::id_type::ProfileId>,
+        event_type: Option<String>,
+        source_verified: Option<bool>,
+        processing_status: storage_enums::IncomingWebhookProcessingStatus,
+        error_code: Option<String>,
+        error_message: Option<String>,
+        reprocess_count: i32,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = incoming_webhook_archive)]
+pub struct IncomingWebhookArchiveUpdateInternal {
+    pub connector_name: Option<String>,
+    pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+    pub event_type: Option<String>,
+    pub source_verified: Option<bool>,
+    pub processing_status: Option<storage_enums::IncomingWebhookProcessingStatus>,
+    pub error_code: Option<Option<String>>,
+    pub error_message: Option<Option<String>>,
+    pub reprocess_count: Option<i32>,
+    pub last_reprocessed_at: Option<PrimitiveDateTime>,
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl From<IncomingWebhookArchiveUpdate> for IncomingWebhookArchiveUpdateInternal {
+    fn from(archive_update: IncomingWebhookArchiveUpdate) -> Self {
+        let now = common_utils::date_time::now();
+        match archive_update {
+            IncomingWebhookArchiveUpdate::ReprocessUpdate {
+                connector_name,
+                merchant_connector_id,
+                profile_id,
+                event_type,
+                source_verified,
+                processing_status,
+                error_code,
+                error_message,
+                reprocess_count,
+            } => Self {
+                connector_name,
+                merchant_connector_id,
+                profile_id,
+                event_type,
+                source_verified,
+                processing_status: Some(processing_status),
+                error_code: Some(error_code),
+                error_message: Some(error_message),
+                reprocess_count: Some(reprocess_count),
+                last_reprocessed_at: Some(now),
+                modified_at: now,
+            },
+        }
+    }
+}
+
+impl IncomingWebhookArchiveUpdate {
+    pub fn apply_changeset(self, source: IncomingWebhookArchive) -> IncomingWebhookArchive {
+        let IncomingWebhookArchiveUpdateInternal {
+            connector_name,
+            merchant_connector_id,
+            profile_id,
+            event_type,
+            source_verified,
+            processing_status,
+            error_code,
+            error_message,
+            reprocess_count,
+            last_reprocessed_at,
+            modified_at,
+        } = self.into();
+        IncomingWebhookArchive {
+            connector_name: connector_name.or(source.connector_name),
+            merchant_connector_id: merchant_connector_id.or(source.merchant_connector_id),
+            profile_id: profile_id.or(source.profile_id),
+            event_type: event_type.or(source.event_type),
+            source_verified: source_verified.or(source.source_verified),
+            processing_status: processing_status.unwrap_or(source.processing_status),
+            error_code: error_code.unwrap_or(source.error_code),
+            error_message: error_message.unwrap_or(source.error_message),
+            reprocess_count: reprocess_count.unwrap_or(source.reprocess_count),
+            last_reprocessed_at: last_reprocessed_at.or(source.last_reprocessed_at),
+            modified_at,
+            ..source
+        }
+    }
+}
+
+/// The constraints to apply when listing archived incoming webhooks.
+#[derive(Clone, Debug, Default)]
+pub struct IncomingWebhookArchiveListConstraints {
+    pub created_after: Option<PrimitiveDateTime>,
+    pub created_before: Option<PrimitiveDateTime>,
+    pub connector_name: Option<String>,
+    pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+    pub processing_status: Option<storage_enums::IncomingWebhookProcessingStatus>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
@@ -0,0 +1,89 @@
+use common_utils::{pii, types::MinorUnit};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::invoice};
+
+#[derive(Clone, Debug, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = invoice)]
+pub struct InvoiceNew {
+    pub invoice_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub customer_id: common_utils::id_type::CustomerId,
+    pub status: storage_enums::InvoiceStatus,
+    pub line_items: serde_json::Value,
+    pub currency: storage_enums::Currency,
+    pub subtotal: MinorUnit,
+    pub tax_amount: MinorUnit,
+    pub total_amount: MinorUnit,
+    pub due_date: PrimitiveDateTime,
+    pub payment_id: common_utils::id_type::PaymentId,
+    pub payment_link: Option<String>,
+    pub description: Option<String>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub paid_at: Option<PrimitiveDateTime>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = invoice, primary_key(invoice_id), check_for_backend(diesel::pg::Pg))]
+pub struct Invoice {
+    pub invoice_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub customer_id: common_utils::id_type::CustomerId,
+    pub status: storage_enums::InvoiceStatus,
+    pub line_items: serde_json::Value,
+    pub currency: storage_enums::Currency,
+    pub subtotal: MinorUnit,
+    pub tax_amount: MinorUnit,
+    pub total_amount: MinorUnit,
+    pub due_date: PrimitiveDateTime,
+    pub payment_id: common_utils::id_type::PaymentId,
+    pub payment_link: Option<String>,
+    pub description: Option<String>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub paid_at: Option<PrimitiveDateTime>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum InvoiceUpdate {
+    StatusUpdate {
+        status: storage_enums::InvoiceStatus,
+        paid_at: Option<PrimitiveDateTime>,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset)]
+#[diesel(table_name = invoice)]
+pub struct InvoiceUpdateInternal {
+    pub status: Option<storage_enums::InvoiceStatus>,
+    pub paid_at: Option<PrimitiveDateTime>,
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl From<InvoiceUpdate> for InvoiceUpdateInternal {
+    fn from(invoice_update: InvoiceUpdate) -> Self {
+        match invoice_update {
+            InvoiceUpdate::StatusUpdate { status, paid_at } => Self {
+                status: Some(status),
+                paid_at,
+                modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
+
+// Tracking data by process_tracker
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InvoiceReminderTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub invoice_id: String,
+    /// Number of days before the due date at which the last reminder was sent
+    pub last_reminder_days: Option<u16>,
+}
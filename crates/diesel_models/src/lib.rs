@@ -1,8 +1,10 @@
 pub mod address;
+pub mod analytics_report_schedule;
 pub mod api_keys;
 pub mod blocklist_lookup;
 pub mod business_profile;
 pub mod capture;
+pub mod card_account_update;
 pub mod cards_info;
 pub mod configs;
 
@@ -16,11 +18,14 @@ pub mod enums;
 pub mod ephemeral_key;
 pub mod errors;
 pub mod events;
+pub mod export_job;
 pub mod file;
 #[allow(unused)]
 pub mod fraud_check;
 pub mod generic_link;
 pub mod gsm;
+pub mod incoming_webhook_archive;
+pub mod invoice;
 #[cfg(feature = "kv_store")]
 pub mod kv;
 pub mod locker_mock_up;
@@ -33,14 +38,20 @@ pub mod payment_attempt;
 pub mod payment_intent;
 pub mod payment_link;
 pub mod payment_method;
+pub mod payment_recovery;
+pub mod payment_split;
 pub mod payout_attempt;
+pub mod payout_batch;
 pub mod payouts;
 pub mod process_tracker;
 pub mod query;
 pub mod refund;
+pub mod refund_batch;
+pub mod reusable_payment_link;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod subscription;
 pub mod unified_translations;
 
 #[allow(unused_qualifications)]
@@ -52,17 +63,20 @@ pub mod user;
 pub mod user_authentication_method;
 pub mod user_key_store;
 pub mod user_role;
+pub mod webhook_endpoint;
 
 use diesel_impl::{DieselArray, OptionalDieselArray};
 
 pub type StorageResult<T> = error_stack::Result<T, errors::DatabaseError>;
 pub type PgPooledConn = async_bb8_diesel::Connection<diesel::PgConnection>;
 pub use self::{
-    address::*, api_keys::*, cards_info::*, configs::*, customers::*, dispute::*, ephemeral_key::*,
-    events::*, file::*, generic_link::*, locker_mock_up::*, mandate::*, merchant_account::*,
-    merchant_connector_account::*, payment_attempt::*, payment_intent::*, payment_method::*,
-    payout_attempt::*, payouts::*, process_tracker::*, refund::*, reverse_lookup::*,
-    user_authentication_method::*,
+    address::*, analytics_report_schedule::*, api_keys::*, card_account_update::*, cards_info::*,
+    configs::*, customers::*, dispute::*, ephemeral_key::*, events::*, export_job::*, file::*,
+    generic_link::*, incoming_webhook_archive::*, invoice::*, locker_mock_up::*, mandate::*,
+    merchant_account::*, merchant_connector_account::*, payment_attempt::*, payment_intent::*,
+    payment_method::*, payment_recovery::*, payment_split::*, payout_attempt::*, payout_batch::*,
+    payouts::*, process_tracker::*, refund::*, refund_batch::*, reusable_payment_link::*,
+    reverse_lookup::*, subscription::*, user_authentication_method::*, webhook_endpoint::*,
 };
 
 /// The types and implementations provided by this module are required for the schema generated by
@@ -290,3 +290,24 @@ pub struct MerchantAccountUpdateInternal {
     pub payment_link_config: Option<serde_json::Value>,
     pub pm_collect_link_config: Option<serde_json::Value>,
 }
+
+// Tracking data by process_tracker
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct StorageSchemeMigrationTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub source_storage_scheme: storage_enums::MerchantStorageScheme,
+    pub target_storage_scheme: storage_enums::MerchantStorageScheme,
+    pub status: storage_enums::StorageSchemeMigrationStatus,
+    // Number of KV entries of the merchant to be compared against the database before switching
+    pub verification_sample_size: u16,
+    // Number of the merchant's entries found in the drainer streams during the last check
+    pub pending_kv_entries: u64,
+    // Number of times the drainer streams have been checked in the current step
+    pub drain_attempts: u32,
+    pub sampled_keys: u16,
+    pub mismatched_keys: Vec<String>,
+    pub failure_reason: Option<String>,
+    pub started_at: time::PrimitiveDateTime,
+    pub switched_at: Option<time::PrimitiveDateTime>,
+    pub completed_at: Option<time::PrimitiveDateTime>,
+}
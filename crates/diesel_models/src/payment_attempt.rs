@@ -22,6 +22,25 @@ pub struct ConnectorMandateReferenceId {
     pub payment_method_id: Option<String>,
     pub mandate_metadata: Option<serde_json::Value>,
 }
+common_utils::impl_to_sql_from_sql_json!(InstallmentPlanDetails);
+/// The installment plan selected for a card payment. The amounts, the connector and the card BIN
+/// are only known if the plan was offered to the customer in the payment methods list.
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, Eq, PartialEq, diesel::AsExpression,
+)]
+#[diesel(sql_type = diesel::sql_types::Jsonb)]
+pub struct InstallmentPlanDetails {
+    pub plan_id: Option<String>,
+    pub number_of_installments: u16,
+    pub installment_amount: Option<MinorUnit>,
+    pub total_amount: Option<MinorUnit>,
+    pub fee_amount: Option<MinorUnit>,
+    /// The connector which offered the plan
+    pub connector: Option<String>,
+    /// The BIN of the card for which the plan was offered
+    pub card_bin: Option<String>,
+}
+
 #[cfg(feature = "v2")]
 #[derive(
     Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Serialize, Deserialize, Selectable,
@@ -164,6 +183,7 @@ pub struct PaymentAttempt {
     pub order_tax_amount: Option<MinorUnit>,
     pub connector_transaction_data: Option<String>,
     pub connector_mandate_detail: Option<ConnectorMandateReferenceId>,
+    pub installment_plan: Option<InstallmentPlanDetails>,
 }
 
 #[cfg(feature = "v1")]
@@ -343,6 +363,7 @@ pub struct PaymentAttemptNew {
     pub shipping_cost: Option<MinorUnit>,
     pub order_tax_amount: Option<MinorUnit>,
     pub connector_mandate_detail: Option<ConnectorMandateReferenceId>,
+    pub installment_plan: Option<InstallmentPlanDetails>,
 }
 
 #[cfg(feature = "v1")]
@@ -414,6 +435,7 @@ pub enum PaymentAttemptUpdate {
         customer_acceptance: Option<pii::SecretSerdeValue>,
         shipping_cost: Option<MinorUnit>,
         order_tax_amount: Option<MinorUnit>,
+        installment_plan: Option<InstallmentPlanDetails>,
     },
     VoidUpdate {
         status: storage_enums::AttemptStatus,
@@ -831,6 +853,7 @@ pub struct PaymentAttemptUpdateInternal {
     pub order_tax_amount: Option<MinorUnit>,
     pub connector_transaction_data: Option<String>,
     pub connector_mandate_detail: Option<ConnectorMandateReferenceId>,
+    pub installment_plan: Option<InstallmentPlanDetails>,
 }
 
 #[cfg(feature = "v1")]
@@ -1014,6 +1037,7 @@ impl PaymentAttemptUpdate {
             order_tax_amount,
             connector_transaction_data,
             connector_mandate_detail,
+            installment_plan,
         } = PaymentAttemptUpdateInternal::from(self).populate_derived_fields(&source);
         PaymentAttempt {
             amount: amount.unwrap_or(source.amount),
@@ -1072,6 +1096,7 @@ impl PaymentAttemptUpdate {
             connector_transaction_data: connector_transaction_data
                 .or(source.connector_transaction_data),
             connector_mandate_detail: connector_mandate_detail.or(source.connector_mandate_detail),
+            installment_plan: installment_plan.or(source.installment_plan),
             ..source
         }
     }
@@ -2068,6 +2093,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 order_tax_amount: None,
                 connector_transaction_data: None,
                 connector_mandate_detail: None,
+                installment_plan: None,
             },
             PaymentAttemptUpdate::AuthenticationTypeUpdate {
                 authentication_type,
@@ -2124,6 +2150,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 order_tax_amount: None,
                 connector_transaction_data: None,
                 connector_mandate_detail: None,
+                installment_plan: None,
             },
             PaymentAttemptUpdate::ConfirmUpdate {
                 amount,
@@ -2158,6 +2185,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 customer_acceptance,
                 shipping_cost,
                 order_tax_amount,
+                installment_plan,
             } => Self {
                 amount: Some(amount),
                 currency: Some(currency),
@@ -2210,6 +2238,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 order_tax_amount,
                 connector_transaction_data: None,
                 connector_mandate_detail: None,
+                installment_plan,
             },
             PaymentAttemptUpdate::VoidUpdate {
                 status,
@@ -2267,6 +2296,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 order_tax_amount: None,
                 connector_transaction_data: None,
                 connector_mandate_detail: None,
+                installment_plan: None,
             },
             PaymentAttemptUpdate::RejectUpdate {
                 status,
@@ -2325,6 +2355,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 order_tax_amount: None,
                 connector_transaction_data: None,
                 connector_mandate_detail: None,
+                installment_plan: None,
             },
             PaymentAttemptUpdate::BlocklistUpdate {
                 status,
@@ -2383,6 +2414,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 order_tax_amount: None,
                 connector_transaction_data: None,
                 connector_mandate_detail: None,
+                installment_plan: None,
             },
             PaymentAttemptUpdate::PaymentMethodDetailsUpdate {
                 payment_method_id,
@@ -2439,6 +2471,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 order_tax_amount: None,
                 connector_transaction_data: None,
                 connector_mandate_detail: None,
+                installment_plan: None,
             },
             PaymentAttemptUpdate::ResponseUpdate {
                 status,
@@ -2462,6 +2495,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 payment_method_data,
                 charge_id,
                 connector_mandate_detail,
+                installment_plan: None,
             } => {
                 let (connector_transaction_id, connector_transaction_data) =
                     connector_transaction_id
@@ -2520,6 +2554,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     shipping_cost: None,
                     order_tax_amount: None,
                     connector_mandate_detail,
+                    installment_plan: None,
                 }
             }
             PaymentAttemptUpdate::ErrorUpdate {
@@ -2593,6 +2628,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     shipping_cost: None,
                     order_tax_amount: None,
                     connector_mandate_detail: None,
+                    installment_plan: None,
                 }
             }
             PaymentAttemptUpdate::StatusUpdate { status, updated_by } => Self {
@@ -2647,6 +2683,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 order_tax_amount: None,
                 connector_transaction_data: None,
                 connector_mandate_detail: None,
+                installment_plan: None,
             },
             PaymentAttemptUpdate::UpdateTrackers {
                 payment_token,
@@ -2709,6 +2746,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 order_tax_amount: None,
                 connector_transaction_data: None,
                 connector_mandate_detail: None,
+                installment_plan: None,
             },
             PaymentAttemptUpdate::UnresolvedResponseUpdate {
                 status,
@@ -2778,6 +2816,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     shipping_cost: None,
                     order_tax_amount: None,
                     connector_mandate_detail: None,
+                    installment_plan: None,
                 }
             }
             PaymentAttemptUpdate::PreprocessingUpdate {
@@ -2846,6 +2885,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     shipping_cost: None,
                     order_tax_amount: None,
                     connector_mandate_detail: None,
+                    installment_plan: None,
                 }
             }
             PaymentAttemptUpdate::CaptureUpdate {
@@ -2904,6 +2944,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 order_tax_amount: None,
                 connector_transaction_data: None,
                 connector_mandate_detail: None,
+                installment_plan: None,
             },
             PaymentAttemptUpdate::AmountToCaptureUpdate {
                 status,
@@ -2961,6 +3002,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 order_tax_amount: None,
                 connector_transaction_data: None,
                 connector_mandate_detail: None,
+                installment_plan: None,
             },
             PaymentAttemptUpdate::ConnectorResponse {
                 authentication_data,
@@ -3027,6 +3069,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     shipping_cost: None,
                     order_tax_amount: None,
                     connector_mandate_detail: None,
+                    installment_plan: None,
                 }
             }
             PaymentAttemptUpdate::IncrementalAuthorizationAmountUpdate {
@@ -3084,6 +3127,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 order_tax_amount: None,
                 connector_transaction_data: None,
                 connector_mandate_detail: None,
+                installment_plan: None,
             },
             PaymentAttemptUpdate::AuthenticationUpdate {
                 status,
@@ -3143,6 +3187,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 order_tax_amount: None,
                 connector_transaction_data: None,
                 connector_mandate_detail: None,
+                installment_plan: None,
             },
             PaymentAttemptUpdate::ManualUpdate {
                 status,
@@ -3211,6 +3256,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     shipping_cost: None,
                     order_tax_amount: None,
                     connector_mandate_detail: None,
+                    installment_plan: None,
                 }
             }
             PaymentAttemptUpdate::PostSessionTokensUpdate {
@@ -3268,6 +3314,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 order_tax_amount: None,
                 connector_transaction_data: None,
                 connector_mandate_detail: None,
+                installment_plan: None,
             },
         }
     }
@@ -0,0 +1,145 @@
+use common_utils::types::MinorUnit;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::payment_recovery};
+
+#[derive(Clone, Debug, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = payment_recovery)]
+pub struct PaymentRecoveryNew {
+    pub recovery_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub organization_id: common_utils::id_type::OrganizationId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub payment_id: common_utils::id_type::PaymentId,
+    pub customer_id: common_utils::id_type::CustomerId,
+    pub subscription_id: Option<String>,
+    pub mandate_id: Option<String>,
+    pub payment_method_id: Option<String>,
+    pub connector: Option<String>,
+    pub amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub status: storage_enums::PaymentRecoveryStatus,
+    pub decline_category: String,
+    pub attempt_count: i32,
+    pub latest_payment_id: Option<String>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = payment_recovery, primary_key(recovery_id), check_for_backend(diesel::pg::Pg))]
+pub struct PaymentRecovery {
+    pub recovery_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub organization_id: common_utils::id_type::OrganizationId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub payment_id: common_utils::id_type::PaymentId,
+    pub customer_id: common_utils::id_type::CustomerId,
+    pub subscription_id: Option<String>,
+    pub mandate_id: Option<String>,
+    pub payment_method_id: Option<String>,
+    pub connector: Option<String>,
+    pub amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub status: storage_enums::PaymentRecoveryStatus,
+    pub decline_category: String,
+    pub attempt_count: i32,
+    pub latest_payment_id: Option<String>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum PaymentRecoveryUpdate {
+    RetryScheduledUpdate {
+        decline_category: String,
+        attempt_count: i32,
+        latest_payment_id: Option<String>,
+        error_code: Option<String>,
+        error_message: Option<String>,
+    },
+    RecoveredUpdate {
+        attempt_count: i32,
+        latest_payment_id: String,
+    },
+    LostUpdate {
+        decline_category: Option<String>,
+        attempt_count: i32,
+        latest_payment_id: Option<String>,
+        error_code: Option<String>,
+        error_message: Option<String>,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset)]
+#[diesel(table_name = payment_recovery)]
+pub struct PaymentRecoveryUpdateInternal {
+    pub status: Option<storage_enums::PaymentRecoveryStatus>,
+    pub decline_category: Option<String>,
+    pub attempt_count: Option<i32>,
+    pub latest_payment_id: Option<String>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub modified_at: Option<PrimitiveDateTime>,
+}
+
+impl From<PaymentRecoveryUpdate> for PaymentRecoveryUpdateInternal {
+    fn from(recovery_update: PaymentRecoveryUpdate) -> Self {
+        let modified_at = Some(common_utils::date_time::now());
+        match recovery_update {
+            PaymentRecoveryUpdate::RetryScheduledUpdate {
+                decline_category,
+                attempt_count,
+                latest_payment_id,
+                error_code,
+                error_message,
+            } => Self {
+                status: Some(storage_enums::PaymentRecoveryStatus::Scheduled),
+                decline_category: Some(decline_category),
+                attempt_count: Some(attempt_count),
+                latest_payment_id,
+                error_code,
+                error_message,
+                modified_at,
+            },
+            PaymentRecoveryUpdate::RecoveredUpdate {
+                attempt_count,
+                latest_payment_id,
+            } => Self {
+                status: Some(storage_enums::PaymentRecoveryStatus::Recovered),
+                attempt_count: Some(attempt_count),
+                latest_payment_id: Some(latest_payment_id),
+                modified_at,
+                ..Default::default()
+            },
+            PaymentRecoveryUpdate::LostUpdate {
+                decline_category,
+                attempt_count,
+                latest_payment_id,
+                error_code,
+                error_message,
+            } => Self {
+                status: Some(storage_enums::PaymentRecoveryStatus::Lost),
+                decline_category,
+                attempt_count: Some(attempt_count),
+                latest_payment_id,
+                error_code,
+                error_message,
+                modified_at,
+            },
+        }
+    }
+}
+
+// Tracking data by process_tracker
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaymentRecoveryTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub recovery_id: String,
+}
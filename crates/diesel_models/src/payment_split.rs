@@ -0,0 +1,59 @@
+use common_utils::types::MinorUnit;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::payment_split};
+
+#[derive(Clone, Debug, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = payment_split)]
+pub struct PaymentSplitNew {
+    pub split_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub payment_id: common_utils::id_type::PaymentId,
+    pub recipient_account_id: String,
+    pub amount: MinorUnit,
+    pub refunded_amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub is_fee_bearer: bool,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = payment_split, primary_key(split_id), check_for_backend(diesel::pg::Pg))]
+pub struct PaymentSplit {
+    pub split_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub payment_id: common_utils::id_type::PaymentId,
+    pub recipient_account_id: String,
+    pub amount: MinorUnit,
+    pub refunded_amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub is_fee_bearer: bool,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum PaymentSplitUpdate {
+    RefundUpdate { refunded_amount: MinorUnit },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset)]
+#[diesel(table_name = payment_split)]
+pub struct PaymentSplitUpdateInternal {
+    pub refunded_amount: Option<MinorUnit>,
+    pub modified_at: Option<PrimitiveDateTime>,
+}
+
+impl From<PaymentSplitUpdate> for PaymentSplitUpdateInternal {
+    fn from(split_update: PaymentSplitUpdate) -> Self {
+        match split_update {
+            PaymentSplitUpdate::RefundUpdate { refunded_amount } => Self {
+                refunded_amount: Some(refunded_amount),
+                modified_at: Some(common_utils::date_time::now()),
+            },
+        }
+    }
+}
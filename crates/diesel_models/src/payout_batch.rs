@@ -0,0 +1,105 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::payout_batch};
+
+#[derive(Clone, Debug, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = payout_batch)]
+pub struct PayoutBatchNew {
+    pub batch_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub file_id: String,
+    pub status: storage_enums::PayoutBatchStatus,
+    pub total_count: i32,
+    pub processed_count: i32,
+    pub succeeded_count: i32,
+    pub failed_count: i32,
+    pub results: serde_json::Value,
+    pub result_file_id: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = payout_batch, primary_key(batch_id), check_for_backend(diesel::pg::Pg))]
+pub struct PayoutBatch {
+    pub batch_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub file_id: String,
+    pub status: storage_enums::PayoutBatchStatus,
+    pub total_count: i32,
+    pub processed_count: i32,
+    pub succeeded_count: i32,
+    pub failed_count: i32,
+    pub results: serde_json::Value,
+    pub result_file_id: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum PayoutBatchUpdate {
+    ProgressUpdate {
+        processed_count: i32,
+        succeeded_count: i32,
+        failed_count: i32,
+        results: serde_json::Value,
+    },
+    CompletionUpdate {
+        status: storage_enums::PayoutBatchStatus,
+        result_file_id: Option<String>,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset)]
+#[diesel(table_name = payout_batch)]
+pub struct PayoutBatchUpdateInternal {
+    pub status: Option<storage_enums::PayoutBatchStatus>,
+    pub processed_count: Option<i32>,
+    pub succeeded_count: Option<i32>,
+    pub failed_count: Option<i32>,
+    pub results: Option<serde_json::Value>,
+    pub result_file_id: Option<String>,
+    pub modified_at: Option<PrimitiveDateTime>,
+}
+
+impl From<PayoutBatchUpdate> for PayoutBatchUpdateInternal {
+    fn from(batch_update: PayoutBatchUpdate) -> Self {
+        let modified_at = Some(common_utils::date_time::now());
+        match batch_update {
+            PayoutBatchUpdate::ProgressUpdate {
+                processed_count,
+                succeeded_count,
+                failed_count,
+                results,
+            } => Self {
+                status: Some(storage_enums::PayoutBatchStatus::Processing),
+                processed_count: Some(processed_count),
+                succeeded_count: Some(succeeded_count),
+                failed_count: Some(failed_count),
+                results: Some(results),
+                modified_at,
+                ..Default::default()
+            },
+            PayoutBatchUpdate::CompletionUpdate {
+                status,
+                result_file_id,
+            } => Self {
+                status: Some(status),
+                result_file_id,
+                modified_at,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+// Tracking data by process_tracker
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PayoutBatchTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub batch_id: String,
+}
@@ -189,6 +189,30 @@ impl From<ProcessTrackerUpdate> for ProcessTrackerUpdateInternal {
     }
 }
 
+impl ProcessTrackerUpdate {
+    pub fn apply_changeset(self, source: ProcessTracker) -> ProcessTracker {
+        let ProcessTrackerUpdateInternal {
+            name,
+            retry_count,
+            schedule_time,
+            tracking_data,
+            business_status,
+            status,
+            updated_at,
+        } = self.into();
+        ProcessTracker {
+            name: name.or(source.name),
+            retry_count: retry_count.unwrap_or(source.retry_count),
+            schedule_time: schedule_time.or(source.schedule_time),
+            tracking_data: tracking_data.unwrap_or(source.tracking_data),
+            business_status: business_status.unwrap_or(source.business_status),
+            status: status.unwrap_or(source.status),
+            updated_at: updated_at.unwrap_or(source.updated_at),
+            ..source
+        }
+    }
+}
+
 #[derive(
     serde::Serialize,
     serde::Deserialize,
@@ -207,9 +231,21 @@ pub enum ProcessTrackerRunner {
     RefundWorkflowRouter,
     DeleteTokenizeDataWorkflow,
     ApiKeyExpiryWorkflow,
+    ApiKeyRotationWorkflow,
     OutgoingWebhookRetryWorkflow,
     AttachPayoutAccountWorkflow,
     PaymentMethodStatusUpdateWorkflow,
+    StorageSchemeMigrationWorkflow,
+    SubscriptionBillingWorkflow,
+    PaymentRecoveryWorkflow,
+    PayoutBatchWorkflow,
+    RefundBatchWorkflow,
+    DisputeDeadlineWorkflow,
+    AccountUpdaterWorkflow,
+    InvoiceReminderWorkflow,
+    AnalyticsReportWorkflow,
+    SuccessRateAlertWorkflow,
+    ExportJobWorkflow,
 }
 
 #[cfg(test)]
@@ -263,6 +299,11 @@ pub mod business_status {
     /// A task that reaches this status should not be retried (rescheduled for execution) later.
     pub const RESOURCE_STATUS_MISMATCH: &str = "RESOURCE_STATUS_MISMATCH";
 
+    /// The webhook endpoint associated with the task was suspended after repeated delivery
+    /// failures. The events missed by the endpoint are delivered again once it is re-enabled.
+    /// A task that reaches this status should not be retried (rescheduled for execution) later.
+    pub const WEBHOOK_ENDPOINT_SUSPENDED: &str = "WEBHOOK_ENDPOINT_SUSPENDED";
+
     /// Business status set for newly created tasks.
     pub const PENDING: &str = "Pending";
 }
@@ -1,8 +1,10 @@
 pub mod address;
+pub mod analytics_report_schedule;
 pub mod api_keys;
 pub mod blocklist_lookup;
 pub mod business_profile;
 mod capture;
+pub mod card_account_update;
 pub mod cards_info;
 pub mod configs;
 
@@ -14,11 +16,14 @@ pub mod customers;
 pub mod dashboard_metadata;
 pub mod dispute;
 pub mod events;
+pub mod export_job;
 pub mod file;
 pub mod fraud_check;
 pub mod generic_link;
 pub mod generics;
 pub mod gsm;
+pub mod incoming_webhook_archive;
+pub mod invoice;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
@@ -29,15 +34,22 @@ pub mod payment_attempt;
 pub mod payment_intent;
 pub mod payment_link;
 pub mod payment_method;
+pub mod payment_recovery;
+pub mod payment_split;
 pub mod payout_attempt;
+pub mod payout_batch;
 pub mod payouts;
 pub mod process_tracker;
 pub mod refund;
+pub mod refund_batch;
+pub mod reusable_payment_link;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod subscription;
 pub mod unified_translations;
 pub mod user;
 pub mod user_authentication_method;
 pub mod user_key_store;
 pub mod user_role;
+pub mod webhook_endpoint;
@@ -0,0 +1,92 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    analytics_report_schedule::{
+        AnalyticsReportSchedule, AnalyticsReportScheduleNew, AnalyticsReportScheduleUpdate,
+        AnalyticsReportScheduleUpdateInternal,
+    },
+    errors,
+    schema::analytics_report_schedule::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl AnalyticsReportScheduleNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<AnalyticsReportSchedule> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl AnalyticsReportSchedule {
+    pub async fn find_by_merchant_id_report_schedule_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        report_schedule_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::report_schedule_id.eq(report_schedule_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn update_by_merchant_id_report_schedule_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        report_schedule_id: &str,
+        schedule_update: AnalyticsReportScheduleUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::report_schedule_id.eq(report_schedule_id.to_owned())),
+            AnalyticsReportScheduleUpdateInternal::from(schedule_update),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NotFound => Err(error.attach_printable(
+                    "Analytics report schedule with the given ID does not exist",
+                )),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+
+    pub async fn delete_by_merchant_id_report_schedule_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        report_schedule_id: &str,
+    ) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::report_schedule_id.eq(report_schedule_id.to_owned())),
+        )
+        .await
+    }
+}
@@ -0,0 +1,11 @@
+use super::generics;
+use crate::{
+    card_account_update::{CardAccountUpdate, CardAccountUpdateNew},
+    PgPooledConn, StorageResult,
+};
+
+impl CardAccountUpdateNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<CardAccountUpdate> {
+        generics::generic_insert(conn, self).await
+    }
+}
@@ -202,6 +202,65 @@ impl Event {
         .await
     }
 
+    pub async fn list_by_merchant_id_webhook_endpoint_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        webhook_endpoint_id: &str,
+        created_after: time::PrimitiveDateTime,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::webhook_endpoint_id.eq(webhook_endpoint_id.to_owned()))
+                .and(dsl::created_at.ge(created_after)),
+            Some(limit),
+            None,
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    pub async fn list_initial_attempts_by_merchant_id_webhook_endpoint_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        webhook_endpoint_id: &str,
+        created_after: Option<time::PrimitiveDateTime>,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        use async_bb8_diesel::AsyncRunQueryDsl;
+        use diesel::{debug_query, pg::Pg, QueryDsl};
+        use error_stack::ResultExt;
+        use router_env::logger;
+
+        use super::generics::db_metrics::{track_database_call, DatabaseOperation};
+        use crate::errors::DatabaseError;
+
+        let mut query = Self::table()
+            .filter(
+                dsl::event_id
+                    .nullable()
+                    .eq(dsl::initial_attempt_id) // Filter initial attempts only
+                    .and(dsl::merchant_id.eq(merchant_id.to_owned()))
+                    .and(dsl::webhook_endpoint_id.eq(webhook_endpoint_id.to_owned())),
+            )
+            .order(dsl::created_at.asc())
+            .limit(limit)
+            .into_boxed();
+
+        if let Some(created_after) = created_after {
+            query = query.filter(dsl::created_at.gt(created_after));
+        }
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Filter)
+            .await
+            .change_context(DatabaseError::Others) // Query returns empty Vec when no records are found
+            .attach_printable("Error filtering events by webhook endpoint")
+    }
+
     pub async fn update_by_merchant_id_event_id(
         conn: &PgPooledConn,
         merchant_id: &common_utils::id_type::MerchantId,
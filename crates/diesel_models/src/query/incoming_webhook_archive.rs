@@ -0,0 +1,121 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    errors,
+    incoming_webhook_archive::{
+        IncomingWebhookArchive, IncomingWebhookArchiveListConstraints, IncomingWebhookArchiveNew,
+        IncomingWebhookArchiveUpdate, IncomingWebhookArchiveUpdateInternal,
+    },
+    schema::incoming_webhook_archive::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl IncomingWebhookArchiveNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<IncomingWebhookArchive> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl IncomingWebhookArchive {
+    pub async fn find_by_merchant_id_webhook_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        webhook_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::webhook_id.eq(webhook_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        constraints: IncomingWebhookArchiveListConstraints,
+    ) -> StorageResult<Vec<Self>> {
+        use async_bb8_diesel::AsyncRunQueryDsl;
+        use diesel::{debug_query, pg::Pg, QueryDsl};
+        use error_stack::ResultExt;
+        use router_env::logger;
+
+        use super::generics::db_metrics::{track_database_call, DatabaseOperation};
+
+        let mut query = Self::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .order(dsl::created_at.desc())
+            .into_boxed();
+
+        if let Some(created_after) = constraints.created_after {
+            query = query.filter(dsl::created_at.ge(created_after));
+        }
+
+        if let Some(created_before) = constraints.created_before {
+            query = query.filter(dsl::created_at.le(created_before));
+        }
+
+        if let Some(connector_name) = constraints.connector_name {
+            query = query.filter(dsl::connector_name.eq(connector_name));
+        }
+
+        if let Some(merchant_connector_id) = constraints.merchant_connector_id {
+            query = query.filter(dsl::merchant_connector_id.eq(merchant_connector_id));
+        }
+
+        if let Some(profile_id) = constraints.profile_id {
+            query = query.filter(dsl::profile_id.eq(profile_id));
+        }
+
+        if let Some(processing_status) = constraints.processing_status {
+            query = query.filter(dsl::processing_status.eq(processing_status));
+        }
+
+        if let Some(limit) = constraints.limit {
+            query = query.limit(limit);
+        }
+
+        if let Some(offset) = constraints.offset {
+            query = query.offset(offset);
+        }
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Filter)
+            .await
+            .change_context(errors::DatabaseError::Others) // Query returns empty Vec when no records are found
+            .attach_printable("Error filtering incoming webhooks by constraints")
+    }
+
+    pub async fn update_by_merchant_id_webhook_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        webhook_id: &str,
+        archive_update: IncomingWebhookArchiveUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::webhook_id.eq(webhook_id.to_owned())),
+            IncomingWebhookArchiveUpdateInternal::from(archive_update),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NotFound => Err(error.attach_printable(
+                    "Incoming webhook with the given ID does not exist in the archive",
+                )),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+}
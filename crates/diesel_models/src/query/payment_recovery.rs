@@ -0,0 +1,77 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    errors,
+    payment_recovery::{
+        PaymentRecovery, PaymentRecoveryNew, PaymentRecoveryUpdate, PaymentRecoveryUpdateInternal,
+    },
+    schema::payment_recovery::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl PaymentRecoveryNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<PaymentRecovery> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl PaymentRecovery {
+    pub async fn find_by_merchant_id_recovery_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        recovery_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::recovery_id.eq(recovery_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn find_optional_by_merchant_id_payment_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_find_one_optional::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payment_id.eq(payment_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn update_by_merchant_id_recovery_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        recovery_id: &str,
+        recovery_update: PaymentRecoveryUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::recovery_id.eq(recovery_id.to_owned())),
+            PaymentRecoveryUpdateInternal::from(recovery_update),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NotFound => {
+                    Err(error.attach_printable("Payment recovery with the given ID does not exist"))
+                }
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+}
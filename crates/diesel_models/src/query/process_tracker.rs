@@ -41,6 +41,26 @@ impl ProcessTracker {
         }
     }
 
+    /// Updates the process only if it is in one of the `statuses` and has not been updated since
+    /// it was read, returning the number of updated processes
+    #[instrument(skip(conn))]
+    pub async fn update_if_unchanged(
+        self,
+        conn: &PgPooledConn,
+        statuses: Vec<enums::ProcessTrackerStatus>,
+        process: ProcessTrackerUpdate,
+    ) -> StorageResult<usize> {
+        generics::generic_update::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::id
+                .eq(self.id)
+                .and(dsl::status.eq_any(statuses))
+                .and(dsl::updated_at.eq(self.updated_at)),
+            ProcessTrackerUpdateInternal::from(process),
+        )
+        .await
+    }
+
     #[instrument(skip(conn))]
     pub async fn update_process_status_by_ids(
         conn: &PgPooledConn,
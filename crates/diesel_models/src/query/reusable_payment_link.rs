@@ -0,0 +1,79 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    errors,
+    reusable_payment_link::{
+        ReusablePaymentLink, ReusablePaymentLinkNew, ReusablePaymentLinkUpdate,
+        ReusablePaymentLinkUpdateInternal,
+    },
+    schema::reusable_payment_link::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl ReusablePaymentLinkNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<ReusablePaymentLink> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl ReusablePaymentLink {
+    pub async fn find_by_merchant_id_link_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        link_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::link_id.eq(link_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()),
+            limit,
+            offset,
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    pub async fn update_by_merchant_id_link_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        link_id: &str,
+        link_update: ReusablePaymentLinkUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::link_id.eq(link_id.to_owned())),
+            ReusablePaymentLinkUpdateInternal::from(link_update),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NotFound => Err(error
+                    .attach_printable("Reusable payment link with the given ID does not exist")),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::refund_batch};
+
+#[derive(Clone, Debug, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = refund_batch)]
+pub struct RefundBatchNew {
+    pub batch_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub file_id: String,
+    pub status: storage_enums::RefundBatchStatus,
+    pub total_count: i32,
+    pub processed_count: i32,
+    pub succeeded_count: i32,
+    pub failed_count: i32,
+    pub results: serde_json::Value,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = refund_batch, primary_key(batch_id), check_for_backend(diesel::pg::Pg))]
+pub struct RefundBatch {
+    pub batch_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub file_id: String,
+    pub status: storage_enums::RefundBatchStatus,
+    pub total_count: i32,
+    pub processed_count: i32,
+    pub succeeded_count: i32,
+    pub failed_count: i32,
+    pub results: serde_json::Value,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum RefundBatchUpdate {
+    ProgressUpdate {
+        processed_count: i32,
+        succeeded_count: i32,
+        failed_count: i32,
+        results: serde_json::Value,
+    },
+    CompletionUpdate {
+        status: storage_enums::RefundBatchStatus,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset)]
+#[diesel(table_name = refund_batch)]
+pub struct RefundBatchUpdateInternal {
+    pub status: Option<storage_enums::RefundBatchStatus>,
+    pub processed_count: Option<i32>,
+    pub succeeded_count: Option<i32>,
+    pub failed_count: Option<i32>,
+    pub results: Option<serde_json::Value>,
+    pub modified_at: Option<PrimitiveDateTime>,
+}
+
+impl From<RefundBatchUpdate> for RefundBatchUpdateInternal {
+    fn from(batch_update: RefundBatchUpdate) -> Self {
+        let modified_at = Some(common_utils::date_time::now());
+        match batch_update {
+            RefundBatchUpdate::ProgressUpdate {
+                processed_count,
+                succeeded_count,
+                failed_count,
+                results,
+            } => Self {
+                status: Some(storage_enums::RefundBatchStatus::Processing),
+                processed_count: Some(processed_count),
+                succeeded_count: Some(succeeded_count),
+                failed_count: Some(failed_count),
+                results: Some(results),
+                modified_at,
+                ..Default::default()
+            },
+            RefundBatchUpdate::CompletionUpdate { status } => Self {
+                status: Some(status),
+                modified_at,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+// Tracking data by process_tracker
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RefundBatchTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub batch_id: String,
+}
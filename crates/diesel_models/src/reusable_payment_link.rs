@@ -0,0 +1,79 @@
+use common_utils::{pii, types::MinorUnit};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::reusable_payment_link};
+
+#[derive(Clone, Debug, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = reusable_payment_link)]
+pub struct ReusablePaymentLinkNew {
+    pub link_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub amount_type: storage_enums::ReusablePaymentLinkAmountType,
+    pub amount: Option<MinorUnit>,
+    pub min_amount: Option<MinorUnit>,
+    pub max_amount: Option<MinorUnit>,
+    pub currency: storage_enums::Currency,
+    pub description: Option<String>,
+    pub payment_link_config: Option<serde_json::Value>,
+    pub is_active: bool,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = reusable_payment_link, primary_key(link_id), check_for_backend(diesel::pg::Pg))]
+pub struct ReusablePaymentLink {
+    pub link_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub amount_type: storage_enums::ReusablePaymentLinkAmountType,
+    pub amount: Option<MinorUnit>,
+    pub min_amount: Option<MinorUnit>,
+    pub max_amount: Option<MinorUnit>,
+    pub currency: storage_enums::Currency,
+    pub description: Option<String>,
+    pub payment_link_config: Option<serde_json::Value>,
+    pub is_active: bool,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum ReusablePaymentLinkUpdate {
+    Update {
+        description: Option<String>,
+        is_active: Option<bool>,
+        metadata: Option<pii::SecretSerdeValue>,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset)]
+#[diesel(table_name = reusable_payment_link)]
+pub struct ReusablePaymentLinkUpdateInternal {
+    pub description: Option<String>,
+    pub is_active: Option<bool>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl From<ReusablePaymentLinkUpdate> for ReusablePaymentLinkUpdateInternal {
+    fn from(link_update: ReusablePaymentLinkUpdate) -> Self {
+        match link_update {
+            ReusablePaymentLinkUpdate::Update {
+                description,
+                is_active,
+                metadata,
+            } => Self {
+                description,
+                is_active,
+                metadata,
+                modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
@@ -34,6 +34,38 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    analytics_report_schedule (report_schedule_id) {
+        #[max_length = 64]
+        report_schedule_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 32]
+        organization_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Nullable<Varchar>,
+        #[max_length = 255]
+        name -> Varchar,
+        #[max_length = 32]
+        domain -> Varchar,
+        metric_request -> Jsonb,
+        #[max_length = 32]
+        frequency -> Varchar,
+        #[max_length = 32]
+        delivery_method -> Varchar,
+        recipients -> Array<Nullable<Text>>,
+        #[max_length = 32]
+        status -> Varchar,
+        last_run_at -> Nullable<Timestamp>,
+        next_run_at -> Timestamp,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -213,6 +245,9 @@ diesel::table! {
         is_network_tokenization_enabled -> Bool,
         is_auto_retries_enabled -> Nullable<Bool>,
         max_auto_retries_enabled -> Nullable<Int2>,
+        #[max_length = 255]
+        previous_payment_response_hash_key -> Nullable<Varchar>,
+        previous_payment_response_hash_key_expires_at -> Nullable<Timestamp>,
     }
 }
 
@@ -253,6 +288,37 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    card_account_update (update_id) {
+        #[max_length = 64]
+        update_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        customer_id -> Varchar,
+        #[max_length = 64]
+        payment_method_id -> Varchar,
+        #[max_length = 32]
+        update_type -> Varchar,
+        #[max_length = 4]
+        previous_last4 -> Nullable<Varchar>,
+        #[max_length = 2]
+        previous_expiry_month -> Nullable<Varchar>,
+        #[max_length = 4]
+        previous_expiry_year -> Nullable<Varchar>,
+        #[max_length = 4]
+        updated_last4 -> Nullable<Varchar>,
+        #[max_length = 2]
+        updated_expiry_month -> Nullable<Varchar>,
+        #[max_length = 4]
+        updated_expiry_year -> Nullable<Varchar>,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -406,7 +472,7 @@ diesel::table! {
         #[max_length = 64]
         business_profile_id -> Nullable<Varchar>,
         primary_object_created_at -> Nullable<Timestamp>,
-        #[max_length = 64]
+        #[max_length = 255]
         idempotent_event_id -> Nullable<Varchar>,
         #[max_length = 64]
         initial_attempt_id -> Nullable<Varchar>,
@@ -414,6 +480,43 @@ diesel::table! {
         response -> Nullable<Bytea>,
         delivery_attempt -> Nullable<WebhookDeliveryAttempt>,
         metadata -> Nullable<Jsonb>,
+        #[max_length = 64]
+        webhook_endpoint_id -> Nullable<Varchar>,
+        delivery_latency_ms -> Nullable<Int4>,
+        sequence_number -> Nullable<Int8>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    export_job (export_id) {
+        #[max_length = 64]
+        export_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 32]
+        organization_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Nullable<Varchar>,
+        #[max_length = 32]
+        export_type -> Varchar,
+        #[max_length = 32]
+        analytics_domain -> Nullable<Varchar>,
+        #[max_length = 32]
+        format -> Varchar,
+        constraints -> Jsonb,
+        recipients -> Array<Nullable<Text>>,
+        #[max_length = 32]
+        status -> Varchar,
+        #[max_length = 255]
+        file_key -> Nullable<Varchar>,
+        row_count -> Nullable<Int4>,
+        error_message -> Nullable<Text>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+        completed_at -> Nullable<Timestamp>,
     }
 }
 
@@ -532,6 +635,39 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    incoming_webhook_archive (webhook_id) {
+        #[max_length = 64]
+        webhook_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        connector_id_or_name -> Varchar,
+        #[max_length = 64]
+        connector_name -> Nullable<Varchar>,
+        #[max_length = 64]
+        merchant_connector_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        profile_id -> Nullable<Varchar>,
+        request -> Bytea,
+        #[max_length = 64]
+        event_type -> Nullable<Varchar>,
+        source_verified -> Nullable<Bool>,
+        #[max_length = 32]
+        processing_status -> Varchar,
+        #[max_length = 64]
+        error_code -> Nullable<Varchar>,
+        error_message -> Nullable<Text>,
+        reprocess_count -> Int4,
+        last_reprocessed_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -557,6 +693,40 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    invoice (invoice_id) {
+        #[max_length = 64]
+        invoice_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 64]
+        customer_id -> Varchar,
+        #[max_length = 32]
+        status -> Varchar,
+        line_items -> Jsonb,
+        currency -> Currency,
+        subtotal -> Int8,
+        tax_amount -> Int8,
+        total_amount -> Int8,
+        due_date -> Timestamp,
+        #[max_length = 64]
+        payment_id -> Varchar,
+        #[max_length = 255]
+        payment_link -> Nullable<Varchar>,
+        #[max_length = 255]
+        description -> Nullable<Varchar>,
+        metadata -> Nullable<Jsonb>,
+        paid_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -851,6 +1021,7 @@ diesel::table! {
         #[max_length = 512]
         connector_transaction_data -> Nullable<Varchar>,
         connector_mandate_detail -> Nullable<Jsonb>,
+        installment_plan -> Nullable<Jsonb>,
     }
 }
 
@@ -1025,6 +1196,70 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payment_recovery (recovery_id) {
+        #[max_length = 64]
+        recovery_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 32]
+        organization_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 64]
+        payment_id -> Varchar,
+        #[max_length = 64]
+        customer_id -> Varchar,
+        #[max_length = 64]
+        subscription_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        mandate_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        payment_method_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        connector -> Nullable<Varchar>,
+        amount -> Int8,
+        currency -> Currency,
+        #[max_length = 32]
+        status -> Varchar,
+        #[max_length = 32]
+        decline_category -> Varchar,
+        attempt_count -> Int4,
+        #[max_length = 64]
+        latest_payment_id -> Nullable<Varchar>,
+        #[max_length = 255]
+        error_code -> Nullable<Varchar>,
+        error_message -> Nullable<Text>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payment_split (split_id) {
+        #[max_length = 64]
+        split_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        payment_id -> Varchar,
+        #[max_length = 255]
+        recipient_account_id -> Varchar,
+        amount -> Int8,
+        refunded_amount -> Int8,
+        currency -> Currency,
+        is_fee_bearer -> Bool,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1069,6 +1304,33 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payout_batch (batch_id) {
+        #[max_length = 64]
+        batch_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 64]
+        file_id -> Varchar,
+        #[max_length = 32]
+        status -> Varchar,
+        total_count -> Int4,
+        processed_count -> Int4,
+        succeeded_count -> Int4,
+        failed_count -> Int4,
+        results -> Jsonb,
+        #[max_length = 64]
+        result_file_id -> Nullable<Varchar>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1110,6 +1372,8 @@ diesel::table! {
         client_secret -> Nullable<Varchar>,
         #[max_length = 32]
         priority -> Nullable<Varchar>,
+        #[max_length = 32]
+        organization_id -> Nullable<Varchar>,
     }
 }
 
@@ -1195,6 +1459,56 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    refund_batch (batch_id) {
+        #[max_length = 64]
+        batch_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        file_id -> Varchar,
+        #[max_length = 32]
+        status -> Varchar,
+        total_count -> Int4,
+        processed_count -> Int4,
+        succeeded_count -> Int4,
+        failed_count -> Int4,
+        results -> Jsonb,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    reusable_payment_link (link_id) {
+        #[max_length = 64]
+        link_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 32]
+        amount_type -> Varchar,
+        amount -> Nullable<Int8>,
+        min_amount -> Nullable<Int8>,
+        max_amount -> Nullable<Int8>,
+        currency -> Currency,
+        #[max_length = 255]
+        description -> Nullable<Varchar>,
+        payment_link_config -> Nullable<Jsonb>,
+        is_active -> Bool,
+        metadata -> Nullable<Jsonb>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1262,6 +1576,68 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    subscription (subscription_id) {
+        #[max_length = 64]
+        subscription_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 64]
+        customer_id -> Varchar,
+        #[max_length = 64]
+        plan_id -> Varchar,
+        #[max_length = 32]
+        status -> Varchar,
+        #[max_length = 64]
+        mandate_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        payment_method_id -> Nullable<Varchar>,
+        current_period_start -> Timestamp,
+        current_period_end -> Timestamp,
+        trial_end -> Nullable<Timestamp>,
+        billing_cycle -> Int4,
+        #[max_length = 64]
+        latest_payment_id -> Nullable<Varchar>,
+        cancel_at_period_end -> Bool,
+        cancelled_at -> Nullable<Timestamp>,
+        metadata -> Nullable<Jsonb>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+        billing_anchor -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    subscription_plan (plan_id) {
+        #[max_length = 64]
+        plan_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 255]
+        name -> Varchar,
+        #[max_length = 255]
+        description -> Nullable<Varchar>,
+        amount -> Int8,
+        currency -> Currency,
+        #[max_length = 32]
+        billing_interval -> Varchar,
+        interval_count -> Int4,
+        trial_period_days -> Nullable<Int4>,
+        is_active -> Bool,
+        metadata -> Nullable<Jsonb>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1369,8 +1745,40 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    webhook_endpoint (endpoint_id) {
+        #[max_length = 64]
+        endpoint_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        url -> Text,
+        #[max_length = 255]
+        secret -> Varchar,
+        enabled_events -> Array<Nullable<EventType>>,
+        is_enabled -> Bool,
+        #[max_length = 255]
+        description -> Nullable<Varchar>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+        #[max_length = 255]
+        previous_secret -> Nullable<Varchar>,
+        previous_secret_expires_at -> Nullable<Timestamp>,
+        consecutive_failures -> Int4,
+        last_successful_delivery_at -> Nullable<Timestamp>,
+        last_failed_delivery_at -> Nullable<Timestamp>,
+        suspended_at -> Nullable<Timestamp>,
+        strict_ordering -> Bool,
+    }
+}
+
 diesel::allow_tables_to_appear_in_same_query!(
     address,
+    analytics_report_schedule,
     api_keys,
     authentication,
     blocklist,
@@ -1378,17 +1786,21 @@ diesel::allow_tables_to_appear_in_same_query!(
     blocklist_lookup,
     business_profile,
     captures,
+    card_account_update,
     cards_info,
     configs,
     customers,
     dashboard_metadata,
     dispute,
     events,
+    export_job,
     file_metadata,
     fraud_check,
     gateway_status_map,
     generic_link,
+    incoming_webhook_archive,
     incremental_authorization,
+    invoice,
     locker_mock_up,
     mandate,
     merchant_account,
@@ -1399,16 +1811,24 @@ diesel::allow_tables_to_appear_in_same_query!(
     payment_intent,
     payment_link,
     payment_methods,
+    payment_recovery,
+    payment_split,
     payout_attempt,
+    payout_batch,
     payouts,
     process_tracker,
     refund,
+    refund_batch,
+    reusable_payment_link,
     reverse_lookup,
     roles,
     routing_algorithm,
+    subscription,
+    subscription_plan,
     unified_translations,
     user_authentication_methods,
     user_key_store,
     user_roles,
     users,
+    webhook_endpoint,
 );
@@ -0,0 +1,211 @@
+use common_utils::{pii, types::MinorUnit};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums as storage_enums,
+    schema::{subscription, subscription_plan},
+};
+
+#[derive(Clone, Debug, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = subscription_plan)]
+pub struct SubscriptionPlanNew {
+    pub plan_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub name: String,
+    pub description: Option<String>,
+    pub amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub billing_interval: storage_enums::SubscriptionInterval,
+    pub interval_count: i32,
+    pub trial_period_days: Option<i32>,
+    pub is_active: bool,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = subscription_plan, primary_key(plan_id), check_for_backend(diesel::pg::Pg))]
+pub struct SubscriptionPlan {
+    pub plan_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub name: String,
+    pub description: Option<String>,
+    pub amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub billing_interval: storage_enums::SubscriptionInterval,
+    pub interval_count: i32,
+    pub trial_period_days: Option<i32>,
+    pub is_active: bool,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum SubscriptionPlanUpdate {
+    Update {
+        name: Option<String>,
+        description: Option<String>,
+        is_active: Option<bool>,
+        metadata: Option<pii::SecretSerdeValue>,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset)]
+#[diesel(table_name = subscription_plan)]
+pub struct SubscriptionPlanUpdateInternal {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub is_active: Option<bool>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl From<SubscriptionPlanUpdate> for SubscriptionPlanUpdateInternal {
+    fn from(plan_update: SubscriptionPlanUpdate) -> Self {
+        match plan_update {
+            SubscriptionPlanUpdate::Update {
+                name,
+                description,
+                is_active,
+                metadata,
+            } => Self {
+                name,
+                description,
+                is_active,
+                metadata,
+                modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = subscription)]
+pub struct SubscriptionNew {
+    pub subscription_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub customer_id: common_utils::id_type::CustomerId,
+    pub plan_id: String,
+    pub status: storage_enums::SubscriptionStatus,
+    pub mandate_id: Option<String>,
+    pub payment_method_id: Option<String>,
+    pub current_period_start: PrimitiveDateTime,
+    pub current_period_end: PrimitiveDateTime,
+    pub trial_end: Option<PrimitiveDateTime>,
+    pub billing_cycle: i32,
+    pub latest_payment_id: Option<String>,
+    pub cancel_at_period_end: bool,
+    pub cancelled_at: Option<PrimitiveDateTime>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+    /// Start of the first billing period, from which the start of every billing period is computed
+    pub billing_anchor: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = subscription, primary_key(subscription_id), check_for_backend(diesel::pg::Pg))]
+pub struct Subscription {
+    pub subscription_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub customer_id: common_utils::id_type::CustomerId,
+    pub plan_id: String,
+    pub status: storage_enums::SubscriptionStatus,
+    pub mandate_id: Option<String>,
+    pub payment_method_id: Option<String>,
+    pub current_period_start: PrimitiveDateTime,
+    pub current_period_end: PrimitiveDateTime,
+    pub trial_end: Option<PrimitiveDateTime>,
+    pub billing_cycle: i32,
+    pub latest_payment_id: Option<String>,
+    pub cancel_at_period_end: bool,
+    pub cancelled_at: Option<PrimitiveDateTime>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+    /// Start of the first billing period, from which the start of every billing period is computed
+    pub billing_anchor: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum SubscriptionUpdate {
+    RenewalUpdate {
+        current_period_start: PrimitiveDateTime,
+        current_period_end: PrimitiveDateTime,
+        billing_cycle: i32,
+        latest_payment_id: String,
+    },
+    PaymentFailedUpdate {
+        latest_payment_id: Option<String>,
+    },
+    CancellationUpdate {
+        status: Option<storage_enums::SubscriptionStatus>,
+        cancel_at_period_end: bool,
+        cancelled_at: Option<PrimitiveDateTime>,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset)]
+#[diesel(table_name = subscription)]
+pub struct SubscriptionUpdateInternal {
+    pub status: Option<storage_enums::SubscriptionStatus>,
+    pub current_period_start: Option<PrimitiveDateTime>,
+    pub current_period_end: Option<PrimitiveDateTime>,
+    pub billing_cycle: Option<i32>,
+    pub latest_payment_id: Option<String>,
+    pub cancel_at_period_end: Option<bool>,
+    pub cancelled_at: Option<PrimitiveDateTime>,
+    pub modified_at: Option<PrimitiveDateTime>,
+}
+
+impl From<SubscriptionUpdate> for SubscriptionUpdateInternal {
+    fn from(subscription_update: SubscriptionUpdate) -> Self {
+        let modified_at = Some(common_utils::date_time::now());
+        match subscription_update {
+            SubscriptionUpdate::RenewalUpdate {
+                current_period_start,
+                current_period_end,
+                billing_cycle,
+                latest_payment_id,
+            } => Self {
+                status: Some(storage_enums::SubscriptionStatus::Active),
+                current_period_start: Some(current_period_start),
+                current_period_end: Some(current_period_end),
+                billing_cycle: Some(billing_cycle),
+                latest_payment_id: Some(latest_payment_id),
+                modified_at,
+                ..Default::default()
+            },
+            SubscriptionUpdate::PaymentFailedUpdate { latest_payment_id } => Self {
+                status: Some(storage_enums::SubscriptionStatus::PastDue),
+                latest_payment_id,
+                modified_at,
+                ..Default::default()
+            },
+            SubscriptionUpdate::CancellationUpdate {
+                status,
+                cancel_at_period_end,
+                cancelled_at,
+            } => Self {
+                status,
+                cancel_at_period_end: Some(cancel_at_period_end),
+                cancelled_at,
+                modified_at,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+// Tracking data by process_tracker
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubscriptionBillingTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub subscription_id: String,
+}
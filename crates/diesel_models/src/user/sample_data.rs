@@ -12,7 +12,7 @@ use crate::schema::payment_attempt;
 use crate::schema_v2::payment_attempt;
 use crate::{
     enums::{MandateDataType, MandateDetails},
-    ConnectorMandateReferenceId, PaymentAttemptNew,
+    ConnectorMandateReferenceId, InstallmentPlanDetails, PaymentAttemptNew,
 };
 
 // #[cfg(feature = "v2")]
@@ -203,6 +203,7 @@ pub struct PaymentAttemptBatchNew {
     pub order_tax_amount: Option<MinorUnit>,
     pub connector_transaction_data: Option<String>,
     pub connector_mandate_detail: Option<ConnectorMandateReferenceId>,
+    pub installment_plan: Option<InstallmentPlanDetails>,
 }
 
 #[cfg(feature = "v1")]
@@ -282,6 +283,7 @@ impl PaymentAttemptBatchNew {
             shipping_cost: self.shipping_cost,
             order_tax_amount: self.order_tax_amount,
             connector_mandate_detail: self.connector_mandate_detail,
+            installment_plan: self.installment_plan,
         }
     }
 }
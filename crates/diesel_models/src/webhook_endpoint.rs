@@ -0,0 +1,197 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use masking::Secret;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::webhook_endpoint};
+
+#[derive(Clone, Debug, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = webhook_endpoint)]
+pub struct WebhookEndpointNew {
+    pub endpoint_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub url: String,
+    pub secret: Secret<String>,
+    pub enabled_events: Vec<storage_enums::EventType>,
+    pub is_enabled: bool,
+    pub description: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+    pub previous_secret: Option<Secret<String>>,
+    pub previous_secret_expires_at: Option<PrimitiveDateTime>,
+    pub consecutive_failures: i32,
+    pub last_successful_delivery_at: Option<PrimitiveDateTime>,
+    pub last_failed_delivery_at: Option<PrimitiveDateTime>,
+    pub suspended_at: Option<PrimitiveDateTime>,
+    pub strict_ordering: bool,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = webhook_endpoint, primary_key(endpoint_id), check_for_backend(diesel::pg::Pg))]
+pub struct WebhookEndpoint {
+    pub endpoint_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub url: String,
+    pub secret: Secret<String>,
+    #[diesel(deserialize_as = super::DieselArray<storage_enums::EventType>)]
+    pub enabled_events: Vec<storage_enums::EventType>,
+    pub is_enabled: bool,
+    pub description: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+    pub previous_secret: Option<Secret<String>>,
+    pub previous_secret_expires_at: Option<PrimitiveDateTime>,
+    pub consecutive_failures: i32,
+    pub last_successful_delivery_at: Option<PrimitiveDateTime>,
+    pub last_failed_delivery_at: Option<PrimitiveDateTime>,
+    pub suspended_at: Option<PrimitiveDateTime>,
+    pub strict_ordering: bool,
+}
+
+impl WebhookEndpoint {
+    /// Whether events of the specified type are created for the endpoint. Events are created for
+    /// suspended endpoints as well, so that they can be delivered once the endpoint is re-enabled.
+    pub fn is_subscribed_to(&self, event_type: storage_enums::EventType) -> bool {
+        (self.is_enabled || self.is_suspended()) && self.enabled_events.contains(&event_type)
+    }
+
+    /// Whether the endpoint was disabled automatically after repeatedly failing to accept
+    /// webhooks.
+    pub fn is_suspended(&self) -> bool {
+        !self.is_enabled && self.suspended_at.is_some()
+    }
+}
+
+#[derive(Debug)]
+pub enum WebhookEndpointUpdate {
+    Update {
+        url: Option<String>,
+        enabled_events: Option<Vec<storage_enums::EventType>>,
+        is_enabled: Option<bool>,
+        description: Option<String>,
+        strict_ordering: Option<bool>,
+    },
+    SecretUpdate {
+        secret: Secret<String>,
+        previous_secret: Secret<String>,
+        previous_secret_expires_at: PrimitiveDateTime,
+    },
+    DeliverySucceeded {
+        delivered_at: PrimitiveDateTime,
+    },
+    Suspend {
+        suspended_at: PrimitiveDateTime,
+    },
+    Reenable,
+}
+
+#[derive(Clone, Debug, Default, AsChangeset)]
+#[diesel(table_name = webhook_endpoint)]
+pub struct WebhookEndpointUpdateInternal {
+    pub url: Option<String>,
+    pub secret: Option<Secret<String>>,
+    pub enabled_events: Option<Vec<storage_enums::EventType>>,
+    pub is_enabled: Option<bool>,
+    pub description: Option<String>,
+    pub modified_at: Option<PrimitiveDateTime>,
+    pub previous_secret: Option<Secret<String>>,
+    pub previous_secret_expires_at: Option<PrimitiveDateTime>,
+    pub consecutive_failures: Option<i32>,
+    pub last_successful_delivery_at: Option<PrimitiveDateTime>,
+    pub last_failed_delivery_at: Option<PrimitiveDateTime>,
+    pub suspended_at: Option<Option<PrimitiveDateTime>>,
+    pub strict_ordering: Option<bool>,
+}
+
+impl From<WebhookEndpointUpdate> for WebhookEndpointUpdateInternal {
+    fn from(endpoint_update: WebhookEndpointUpdate) -> Self {
+        match endpoint_update {
+            WebhookEndpointUpdate::Update {
+                url,
+                enabled_events,
+                is_enabled,
+                description,
+                strict_ordering,
+            } => Self {
+                url,
+                enabled_events,
+                // Explicitly enabling or disabling the endpoint clears any suspension
+                suspended_at: is_enabled.map(|_| None),
+                is_enabled,
+                description,
+                strict_ordering,
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+            WebhookEndpointUpdate::SecretUpdate {
+                secret,
+                previous_secret,
+                previous_secret_expires_at,
+            } => Self {
+                secret: Some(secret),
+                previous_secret: Some(previous_secret),
+                previous_secret_expires_at: Some(previous_secret_expires_at),
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+            WebhookEndpointUpdate::DeliverySucceeded { delivered_at } => Self {
+                consecutive_failures: Some(0),
+                last_successful_delivery_at: Some(delivered_at),
+                ..Default::default()
+            },
+            WebhookEndpointUpdate::Suspend { suspended_at } => Self {
+                is_enabled: Some(false),
+                suspended_at: Some(Some(suspended_at)),
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+            WebhookEndpointUpdate::Reenable => Self {
+                is_enabled: Some(true),
+                consecutive_failures: Some(0),
+                suspended_at: Some(None),
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl WebhookEndpointUpdate {
+    pub fn apply_changeset(self, source: WebhookEndpoint) -> WebhookEndpoint {
+        let WebhookEndpointUpdateInternal {
+            url,
+            secret,
+            enabled_events,
+            is_enabled,
+            description,
+            modified_at,
+            previous_secret,
+            previous_secret_expires_at,
+            consecutive_failures,
+            last_successful_delivery_at,
+            last_failed_delivery_at,
+            suspended_at,
+            strict_ordering,
+        } = self.into();
+        WebhookEndpoint {
+            url: url.unwrap_or(source.url),
+            secret: secret.unwrap_or(source.secret),
+            enabled_events: enabled_events.unwrap_or(source.enabled_events),
+            is_enabled: is_enabled.unwrap_or(source.is_enabled),
+            description: description.or(source.description),
+            modified_at: modified_at.unwrap_or(source.modified_at),
+            previous_secret: previous_secret.or(source.previous_secret),
+            previous_secret_expires_at: previous_secret_expires_at
+                .or(source.previous_secret_expires_at),
+            consecutive_failures: consecutive_failures.unwrap_or(source.consecutive_failures),
+            last_successful_delivery_at: last_successful_delivery_at
+                .or(source.last_successful_delivery_at),
+            last_failed_delivery_at: last_failed_delivery_at.or(source.last_failed_delivery_at),
+            suspended_at: suspended_at.unwrap_or(source.suspended_at),
+            strict_ordering: strict_ordering.unwrap_or(source.strict_ordering),
+            ..source
+        }
+    }
+}
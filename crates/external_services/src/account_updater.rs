@@ -0,0 +1,140 @@
+//!
+//! Module for querying card account updater services for changes to stored cards.
+//!
+
+use std::{
+    fmt::{Display, Formatter},
+    sync::Arc,
+};
+
+use common_utils::errors::CustomResult;
+use masking::Secret;
+
+mod file_system;
+
+/// Enum representing the sources from which updates to stored cards can be obtained.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(tag = "account_updater_source")]
+#[serde(rename_all = "snake_case")]
+pub enum AccountUpdaterConfig {
+    /// Stored cards are not checked for updates.
+    #[default]
+    Disabled,
+    /// Updates are read from a JSON file on the local file system, intended for testing.
+    FileSystem {
+        /// Path of the file containing the updates.
+        file_path: String,
+    },
+}
+
+impl AccountUpdaterConfig {
+    /// Validates the account updater configuration.
+    pub fn validate(&self) -> Result<(), InvalidAccountUpdaterConfig> {
+        match self {
+            Self::Disabled => Ok(()),
+            Self::FileSystem { file_path } => {
+                if file_path.trim().is_empty() {
+                    Err(InvalidAccountUpdaterConfig("file path must not be empty"))
+                } else {
+                    Ok(())
+                }
+            }
+        }
+    }
+
+    /// Retrieves the account updater client based on the configuration, if a source is enabled.
+    pub fn get_account_updater_client(&self) -> Option<Arc<dyn AccountUpdaterInterface>> {
+        match self {
+            Self::Disabled => None,
+            Self::FileSystem { file_path } => Some(Arc::new(
+                file_system::FileSystemAccountUpdater::new(file_path),
+            )),
+        }
+    }
+}
+
+/// Details of a stored card which is to be checked for updates.
+#[derive(Debug, Clone)]
+pub struct AccountUpdateRequest {
+    /// Reference used to match the update to the stored card.
+    pub reference_id: String,
+    /// Card number of the stored card.
+    pub card_number: Secret<String>,
+    /// Expiry month of the stored card.
+    pub card_exp_month: Secret<String>,
+    /// Expiry year of the stored card.
+    pub card_exp_year: Secret<String>,
+}
+
+/// Update reported for a stored card.
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(tag = "update_type")]
+#[serde(rename_all = "snake_case")]
+pub enum AccountUpdate {
+    /// The card has not changed.
+    NoChange,
+    /// The card has been given a new expiry date.
+    ExpiryUpdated {
+        /// New expiry month of the card.
+        card_exp_month: Secret<String>,
+        /// New expiry year of the card.
+        card_exp_year: Secret<String>,
+    },
+    /// The card has been replaced with a card having a new card number.
+    CardReissued {
+        /// Card number of the new card.
+        card_number: Secret<String>,
+        /// Expiry month of the new card.
+        card_exp_month: Secret<String>,
+        /// Expiry year of the new card.
+        card_exp_year: Secret<String>,
+    },
+    /// The account of the card has been closed.
+    AccountClosed,
+}
+
+/// Update reported for a stored card, along with the reference of the card.
+#[derive(Debug, Clone)]
+pub struct AccountUpdateResponse {
+    /// Reference of the stored card, as provided in the request.
+    pub reference_id: String,
+    /// Update reported for the card.
+    pub update: AccountUpdate,
+}
+
+/// Trait for obtaining updates to stored cards
+#[async_trait::async_trait]
+pub trait AccountUpdaterInterface: dyn_clone::DynClone + Sync + Send {
+    /// Retrieves the updates for the provided cards. A response is returned for every card in
+    /// the request.
+    async fn get_account_updates(
+        &self,
+        requests: Vec<AccountUpdateRequest>,
+    ) -> CustomResult<Vec<AccountUpdateResponse>, AccountUpdaterError>;
+}
+
+dyn_clone::clone_trait_object!(AccountUpdaterInterface);
+
+/// Error thrown when the account updater config is invalid
+#[derive(Debug, Clone)]
+pub struct InvalidAccountUpdaterConfig(&'static str);
+
+impl std::error::Error for InvalidAccountUpdaterConfig {}
+
+impl Display for InvalidAccountUpdaterConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "account_updater: {}", self.0)
+    }
+}
+
+/// Represents errors that can occur while obtaining updates to stored cards.
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum AccountUpdaterError {
+    /// Indicates that the updates could not be obtained from the source.
+    #[error("Failed to fetch account updates")]
+    FetchUpdatesFailed,
+
+    /// Indicates that the updates obtained from the source could not be parsed.
+    #[error("Failed to parse account updates")]
+    ParsingFailed,
+}
@@ -0,0 +1,73 @@
+//!
+//! Module for reading updates to stored cards from the local file system
+//!
+
+use std::path::PathBuf;
+
+use common_utils::errors::CustomResult;
+use error_stack::ResultExt;
+use masking::{PeekInterface, Secret};
+
+use crate::account_updater::{
+    AccountUpdate, AccountUpdateRequest, AccountUpdateResponse, AccountUpdaterError,
+    AccountUpdaterInterface,
+};
+
+/// An update listed in the file, for the card with the specified card number.
+#[derive(Debug, serde::Deserialize)]
+struct AccountUpdateRecord {
+    card_number: Secret<String>,
+    update: AccountUpdate,
+}
+
+/// Reads updates to stored cards from a JSON file containing a list of updates, each of which
+/// consists of a `card_number` and an `update`. Cards which are not listed in the file are
+/// reported as unchanged.
+#[derive(Debug, Clone)]
+pub(super) struct FileSystemAccountUpdater {
+    file_path: PathBuf,
+}
+
+impl FileSystemAccountUpdater {
+    pub(super) fn new(file_path: impl Into<PathBuf>) -> Self {
+        Self {
+            file_path: file_path.into(),
+        }
+    }
+
+    fn read_updates(&self) -> CustomResult<Vec<AccountUpdateRecord>, AccountUpdaterError> {
+        let file = std::fs::read(&self.file_path)
+            .change_context(AccountUpdaterError::FetchUpdatesFailed)
+            .attach_printable_lazy(|| {
+                format!("Failed to read file: {}", self.file_path.display())
+            })?;
+
+        serde_json::from_slice(&file).change_context(AccountUpdaterError::ParsingFailed)
+    }
+}
+
+#[async_trait::async_trait]
+impl AccountUpdaterInterface for FileSystemAccountUpdater {
+    async fn get_account_updates(
+        &self,
+        requests: Vec<AccountUpdateRequest>,
+    ) -> CustomResult<Vec<AccountUpdateResponse>, AccountUpdaterError> {
+        let records = self.read_updates()?;
+
+        Ok(requests
+            .into_iter()
+            .map(|request| {
+                let update = records
+                    .iter()
+                    .find(|record| record.card_number.peek() == request.card_number.peek())
+                    .map(|record| record.update.clone())
+                    .unwrap_or(AccountUpdate::NoChange);
+
+                AccountUpdateResponse {
+                    reference_id: request.reference_id,
+                    update,
+                }
+            })
+            .collect())
+    }
+}
@@ -2,6 +2,8 @@
 
 #![warn(missing_docs, missing_debug_implementations)]
 
+pub mod account_updater;
+
 #[cfg(feature = "email")]
 pub mod email;
 
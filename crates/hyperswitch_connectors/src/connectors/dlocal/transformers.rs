@@ -1,13 +1,20 @@
 use api_models::payments::AddressDetails;
 use common_enums::enums;
-use common_utils::{pii::Email, request::Method};
+use common_utils::{
+    pii::Email,
+    request::Method,
+    types::{FloatMajorUnit, FloatMajorUnitForConnector},
+};
 use error_stack::ResultExt;
 use hyperswitch_domain_models::{
     payment_method_data::PaymentMethodData,
     router_data::{ConnectorAuthType, RouterData},
     router_flow_types::{refunds::Execute, RSync},
     router_request_types::ResponseId,
-    router_response_types::{PaymentsResponseData, RedirectForm, RefundsResponseData},
+    router_response_types::{
+        InstallmentPlanOption, InstallmentPlansResponseData, PaymentsResponseData, RedirectForm,
+        RefundsResponseData,
+    },
     types,
 };
 use hyperswitch_interfaces::{api::CurrencyUnit, errors};
@@ -17,7 +24,10 @@ use url::Url;
 
 use crate::{
     types::{RefundsResponseRouterData, ResponseRouterData},
-    utils::{AddressDetailsData, PaymentsAuthorizeRequestData, RouterData as _},
+    utils::{
+        convert_amount, convert_back_amount_to_minor_units, AddressDetailsData,
+        PaymentsAuthorizeRequestData, RouterData as _,
+    },
 };
 
 #[derive(Debug, Default, Eq, PartialEq, Serialize)]
@@ -108,6 +118,26 @@ impl TryFrom<&DlocalRouterData<&types::PaymentsAuthorizeRouterData>> for DlocalP
                     item.router_data.request.capture_method,
                     Some(enums::CaptureMethod::Automatic)
                 );
+                let (installments_id, installments) =
+                    match item.router_data.request.installment_plan.as_ref() {
+                        Some(plan) => (
+                            plan.plan_id.clone(),
+                            Some(plan.number_of_installments.to_string()),
+                        ),
+                        None => (
+                            item.router_data
+                                .request
+                                .mandate_id
+                                .as_ref()
+                                .and_then(|ids| ids.mandate_id.clone()),
+                            // [#595[FEATURE] Pass Mandate history information in payment flows/request]
+                            item.router_data
+                                .request
+                                .mandate_id
+                                .clone()
+                                .map(|_| "1".to_string()),
+                        ),
+                    };
                 let payment_request = Self {
                     amount: item.amount,
                     currency: item.router_data.request.currency,
@@ -130,19 +160,8 @@ impl TryFrom<&DlocalRouterData<&types::PaymentsAuthorizeRouterData>> for DlocalP
                         expiration_month: ccard.card_exp_month.clone(),
                         expiration_year: ccard.card_exp_year.clone(),
                         capture: should_capture.to_string(),
-                        installments_id: item
-                            .router_data
-                            .request
-                            .mandate_id
-                            .as_ref()
-                            .and_then(|ids| ids.mandate_id.clone()),
-                        // [#595[FEATURE] Pass Mandate history information in payment flows/request]
-                        installments: item
-                            .router_data
-                            .request
-                            .mandate_id
-                            .clone()
-                            .map(|_| "1".to_string()),
+                        installments_id,
+                        installments,
                     }),
                     order_id: item.router_data.connector_request_reference_id.clone(),
                     three_dsecure: match item.router_data.auth_type {
@@ -181,6 +200,96 @@ impl TryFrom<&DlocalRouterData<&types::PaymentsAuthorizeRouterData>> for DlocalP
     }
 }
 
+#[derive(Debug, Serialize)]
+pub struct DlocalInstallmentPlansRequest {
+    pub country: enums::CountryAlpha2,
+    pub currency: enums::Currency,
+    pub amount: FloatMajorUnit,
+    pub bin: String,
+}
+
+impl TryFrom<&types::PaymentsInstallmentPlansRouterData> for DlocalInstallmentPlansRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsInstallmentPlansRouterData) -> Result<Self, Self::Error> {
+        let country = item
+            .request
+            .country
+            .ok_or(errors::ConnectorError::MissingRequiredField {
+                field_name: "billing.address.country",
+            })?;
+        let amount = convert_amount(
+            &FloatMajorUnitForConnector,
+            item.request.amount,
+            item.request.currency,
+        )?;
+        Ok(Self {
+            country,
+            currency: item.request.currency,
+            amount,
+            bin: item.request.card_bin.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DlocalInstallmentPlansResponse {
+    pub id: Option<String>,
+    pub currency: enums::Currency,
+    pub installments: Vec<DlocalInstallmentOption>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DlocalInstallmentOption {
+    pub id: Option<String>,
+    pub installment_amount: FloatMajorUnit,
+    pub total_amount: FloatMajorUnit,
+    pub installments: u16,
+}
+
+impl<F, T>
+    TryFrom<ResponseRouterData<F, DlocalInstallmentPlansResponse, T, InstallmentPlansResponseData>>
+    for RouterData<F, T, InstallmentPlansResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: ResponseRouterData<
+            F,
+            DlocalInstallmentPlansResponse,
+            T,
+            InstallmentPlansResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let currency = item.response.currency;
+        let plans = item
+            .response
+            .installments
+            .into_iter()
+            .map(|option| {
+                Ok(InstallmentPlanOption {
+                    plan_id: option.id.or_else(|| item.response.id.clone()),
+                    number_of_installments: option.installments,
+                    installment_amount: convert_back_amount_to_minor_units(
+                        &FloatMajorUnitForConnector,
+                        option.installment_amount,
+                        currency,
+                    )?,
+                    total_amount: convert_back_amount_to_minor_units(
+                        &FloatMajorUnitForConnector,
+                        option.total_amount,
+                        currency,
+                    )?,
+                    interest_rate: None,
+                    fee_amount: None,
+                })
+            })
+            .collect::<Result<Vec<_>, Self::Error>>()?;
+        Ok(Self {
+            response: Ok(InstallmentPlansResponseData { plans }),
+            ..item.data
+        })
+    }
+}
+
 fn get_payer_name(address: &AddressDetails) -> Option<Secret<String>> {
     let first_name = address
         .first_name
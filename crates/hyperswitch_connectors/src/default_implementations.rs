@@ -29,23 +29,25 @@ use hyperswitch_domain_models::{
         mandate_revoke::MandateRevoke,
         payments::{
             Approve, AuthorizeSessionToken, CalculateTax, CompleteAuthorize,
-            CreateConnectorCustomer, IncrementalAuthorization, PostProcessing, PostSessionTokens,
-            PreProcessing, Reject, SdkSessionUpdate,
+            CreateConnectorCustomer, IncrementalAuthorization, InstallmentPlans, PostProcessing,
+            PostSessionTokens, PreProcessing, Reject, SdkSessionUpdate,
         },
         webhooks::VerifyWebhookSource,
     },
     router_request_types::{
         AcceptDisputeRequestData, AuthorizeSessionTokenData, CompleteAuthorizeData,
-        ConnectorCustomerData, DefendDisputeRequestData, MandateRevokeRequestData,
-        PaymentsApproveData, PaymentsIncrementalAuthorizationData, PaymentsPostProcessingData,
-        PaymentsPostSessionTokensData, PaymentsPreProcessingData, PaymentsRejectData,
-        PaymentsTaxCalculationData, RetrieveFileRequestData, SdkPaymentsSessionUpdateData,
-        SubmitEvidenceRequestData, UploadFileRequestData, VerifyWebhookSourceRequestData,
+        ConnectorCustomerData, DefendDisputeRequestData, InstallmentPlansRequestData,
+        MandateRevokeRequestData, PaymentsApproveData, PaymentsIncrementalAuthorizationData,
+        PaymentsPostProcessingData, PaymentsPostSessionTokensData, PaymentsPreProcessingData,
+        PaymentsRejectData, PaymentsTaxCalculationData, RetrieveFileRequestData,
+        SdkPaymentsSessionUpdateData, SubmitEvidenceRequestData, UploadFileRequestData,
+        VerifyWebhookSourceRequestData,
     },
     router_response_types::{
-        AcceptDisputeResponse, DefendDisputeResponse, MandateRevokeResponseData,
-        PaymentsResponseData, RetrieveFileResponse, SubmitEvidenceResponse,
-        TaxCalculationResponseData, UploadFileResponse, VerifyWebhookSourceResponseData,
+        AcceptDisputeResponse, DefendDisputeResponse, InstallmentPlansResponseData,
+        MandateRevokeResponseData, PaymentsResponseData, RetrieveFileResponse,
+        SubmitEvidenceResponse, TaxCalculationResponseData, UploadFileResponse,
+        VerifyWebhookSourceResponseData,
     },
 };
 #[cfg(feature = "frm")]
@@ -65,8 +67,8 @@ use hyperswitch_interfaces::{
         files::{FileUpload, RetrieveFile, UploadFile},
         payments::{
             ConnectorCustomer, PaymentApprove, PaymentAuthorizeSessionToken,
-            PaymentIncrementalAuthorization, PaymentPostSessionTokens, PaymentReject,
-            PaymentSessionUpdate, PaymentsCompleteAuthorize, PaymentsPostProcessing,
+            PaymentIncrementalAuthorization, PaymentInstallmentPlans, PaymentPostSessionTokens,
+            PaymentReject, PaymentSessionUpdate, PaymentsCompleteAuthorize, PaymentsPostProcessing,
             PaymentsPreProcessing, TaxCalculation,
         },
         ConnectorIntegration, ConnectorMandateRevoke, ConnectorRedirectResponse,
@@ -260,6 +262,52 @@ default_imp_for_post_session_tokens!(
     connectors::Zen
 );
 
+macro_rules! default_imp_for_installment_plans {
+    ($($path:ident::$connector:ident),*) => {
+        $( impl PaymentInstallmentPlans for $path::$connector {}
+            impl
+            ConnectorIntegration<
+                InstallmentPlans,
+                InstallmentPlansRequestData,
+                InstallmentPlansResponseData,
+        > for $path::$connector
+        {}
+    )*
+    };
+}
+
+default_imp_for_installment_plans!(
+    connectors::Bambora,
+    connectors::Bitpay,
+    connectors::Billwerk,
+    connectors::Cashtocode,
+    connectors::Coinbase,
+    connectors::Cryptopay,
+    connectors::Digitalvirgo,
+    connectors::Square,
+    connectors::Fiserv,
+    connectors::Fiservemea,
+    connectors::Forte,
+    connectors::Helcim,
+    connectors::Stax,
+    connectors::Taxjar,
+    connectors::Mollie,
+    connectors::Novalnet,
+    connectors::Nexinets,
+    connectors::Nexixpay,
+    connectors::Payeezy,
+    connectors::Payu,
+    connectors::Fiuu,
+    connectors::Globepay,
+    connectors::Worldline,
+    connectors::Powertranz,
+    connectors::Thunes,
+    connectors::Tsys,
+    connectors::Deutschebank,
+    connectors::Volt,
+    connectors::Zen
+);
+
 use crate::connectors;
 macro_rules! default_imp_for_complete_authorize {
     ($($path:ident::$connector:ident),*) => {
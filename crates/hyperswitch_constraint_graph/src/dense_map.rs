@@ -63,6 +63,16 @@ where
         self.data.get_mut(idx.get_id())
     }
 
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
     #[inline]
     pub fn contains_key(&self, key: K) -> bool {
         key.get_id() < self.data.len()
@@ -482,6 +482,50 @@ where
         Ok(())
     }
 
+    /// Estimates the memory held by the graph in bytes, used to weigh it in memory bounded caches
+    pub fn approximate_size(&self) -> usize {
+        let edge_list_size = self
+            .nodes
+            .values()
+            .map(|node| {
+                let aggregated_values = match &node.node_type {
+                    NodeType::InAggregator(values) => values.len(),
+                    NodeType::AllAggregator | NodeType::AnyAggregator | NodeType::Value(_) => 0,
+                };
+                node.preds
+                    .len()
+                    .saturating_add(node.succs.len())
+                    .saturating_mul(std::mem::size_of::<EdgeId>())
+                    .saturating_add(aggregated_values.saturating_mul(std::mem::size_of::<V>()))
+            })
+            .fold(0_usize, usize::saturating_add);
+
+        [
+            self.domain
+                .len()
+                .saturating_mul(std::mem::size_of::<DomainInfo>()),
+            self.domain_identifier_map
+                .len()
+                .saturating_mul(std::mem::size_of::<(DomainIdentifier, DomainId)>()),
+            self.nodes
+                .len()
+                .saturating_mul(std::mem::size_of::<Node<V>>()),
+            self.edges.len().saturating_mul(std::mem::size_of::<Edge>()),
+            self.value_map
+                .len()
+                .saturating_mul(std::mem::size_of::<(NodeValue<V>, NodeId)>()),
+            self.node_info
+                .len()
+                .saturating_mul(std::mem::size_of::<Option<&'static str>>()),
+            self.node_metadata
+                .len()
+                .saturating_mul(std::mem::size_of::<Option<Arc<dyn Metadata>>>()),
+            edge_list_size,
+        ]
+        .into_iter()
+        .fold(std::mem::size_of::<Self>(), usize::saturating_add)
+    }
+
     pub fn combine(g1: &Self, g2: &Self) -> Result<Self, GraphError<V>> {
         let mut node_builder = builder::ConstraintGraphBuilder::new();
         let mut g1_old2new_id = DenseMap::<NodeId, NodeId>::new();
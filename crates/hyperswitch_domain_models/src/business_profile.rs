@@ -58,6 +58,8 @@ pub struct Profile {
     pub is_network_tokenization_enabled: bool,
     pub is_auto_retries_enabled: bool,
     pub max_auto_retries_enabled: Option<i16>,
+    pub previous_payment_response_hash_key: Option<String>,
+    pub previous_payment_response_hash_key_expires_at: Option<time::PrimitiveDateTime>,
 }
 
 #[cfg(feature = "v1")]
@@ -98,6 +100,8 @@ pub struct ProfileSetter {
     pub is_network_tokenization_enabled: bool,
     pub is_auto_retries_enabled: bool,
     pub max_auto_retries_enabled: Option<i16>,
+    pub previous_payment_response_hash_key: Option<String>,
+    pub previous_payment_response_hash_key_expires_at: Option<time::PrimitiveDateTime>,
 }
 
 #[cfg(feature = "v1")]
@@ -145,6 +149,9 @@ impl From<ProfileSetter> for Profile {
             is_network_tokenization_enabled: value.is_network_tokenization_enabled,
             is_auto_retries_enabled: value.is_auto_retries_enabled,
             max_auto_retries_enabled: value.max_auto_retries_enabled,
+            previous_payment_response_hash_key: value.previous_payment_response_hash_key,
+            previous_payment_response_hash_key_expires_at: value
+                .previous_payment_response_hash_key_expires_at,
         }
     }
 }
@@ -216,6 +223,11 @@ pub enum ProfileUpdate {
     NetworkTokenizationUpdate {
         is_network_tokenization_enabled: Option<bool>,
     },
+    PaymentResponseHashKeyUpdate {
+        payment_response_hash_key: String,
+        previous_payment_response_hash_key: Option<String>,
+        previous_payment_response_hash_key_expires_at: Option<time::PrimitiveDateTime>,
+    },
 }
 
 #[cfg(feature = "v1")]
@@ -293,6 +305,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                     is_network_tokenization_enabled,
                     is_auto_retries_enabled,
                     max_auto_retries_enabled,
+                    previous_payment_response_hash_key: None,
+                    previous_payment_response_hash_key_expires_at: None,
                 }
             }
             ProfileUpdate::RoutingAlgorithmUpdate {
@@ -332,6 +346,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_network_tokenization_enabled: None,
                 is_auto_retries_enabled: None,
                 max_auto_retries_enabled: None,
+                previous_payment_response_hash_key: None,
+                previous_payment_response_hash_key_expires_at: None,
             },
             ProfileUpdate::DynamicRoutingAlgorithmUpdate {
                 dynamic_routing_algorithm,
@@ -369,6 +385,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_network_tokenization_enabled: None,
                 is_auto_retries_enabled: None,
                 max_auto_retries_enabled: None,
+                previous_payment_response_hash_key: None,
+                previous_payment_response_hash_key_expires_at: None,
             },
             ProfileUpdate::ExtendedCardInfoUpdate {
                 is_extended_card_info_enabled,
@@ -406,6 +424,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_network_tokenization_enabled: None,
                 is_auto_retries_enabled: None,
                 max_auto_retries_enabled: None,
+                previous_payment_response_hash_key: None,
+                previous_payment_response_hash_key_expires_at: None,
             },
             ProfileUpdate::ConnectorAgnosticMitUpdate {
                 is_connector_agnostic_mit_enabled,
@@ -443,6 +463,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_network_tokenization_enabled: None,
                 is_auto_retries_enabled: None,
                 max_auto_retries_enabled: None,
+                previous_payment_response_hash_key: None,
+                previous_payment_response_hash_key_expires_at: None,
             },
             ProfileUpdate::NetworkTokenizationUpdate {
                 is_network_tokenization_enabled,
@@ -480,6 +502,49 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_network_tokenization_enabled,
                 is_auto_retries_enabled: None,
                 max_auto_retries_enabled: None,
+                previous_payment_response_hash_key: None,
+                previous_payment_response_hash_key_expires_at: None,
+            },
+            ProfileUpdate::PaymentResponseHashKeyUpdate {
+                payment_response_hash_key,
+                previous_payment_response_hash_key,
+                previous_payment_response_hash_key_expires_at,
+            } => Self {
+                profile_name: None,
+                modified_at: now,
+                return_url: None,
+                enable_payment_response_hash: None,
+                payment_response_hash_key: Some(payment_response_hash_key),
+                redirect_to_merchant_with_http_post: None,
+                webhook_details: None,
+                metadata: None,
+                routing_algorithm: None,
+                intent_fulfillment_time: None,
+                frm_routing_algorithm: None,
+                payout_routing_algorithm: None,
+                is_recon_enabled: None,
+                applepay_verified_domains: None,
+                payment_link_config: None,
+                session_expiry: None,
+                authentication_connector_details: None,
+                payout_link_config: None,
+                is_extended_card_info_enabled: None,
+                extended_card_info_config: None,
+                is_connector_agnostic_mit_enabled: None,
+                use_billing_as_payment_method_billing: None,
+                collect_shipping_details_from_wallet_connector: None,
+                collect_billing_details_from_wallet_connector: None,
+                outgoing_webhook_custom_http_headers: None,
+                always_collect_billing_details_from_wallet_connector: None,
+                always_collect_shipping_details_from_wallet_connector: None,
+                tax_connector_id: None,
+                is_tax_connector_enabled: None,
+                dynamic_routing_algorithm: None,
+                is_network_tokenization_enabled: None,
+                is_auto_retries_enabled: None,
+                max_auto_retries_enabled: None,
+                previous_payment_response_hash_key,
+                previous_payment_response_hash_key_expires_at,
             },
         }
     }
@@ -536,6 +601,9 @@ impl super::behaviour::Conversion for Profile {
             is_network_tokenization_enabled: self.is_network_tokenization_enabled,
             is_auto_retries_enabled: Some(self.is_auto_retries_enabled),
             max_auto_retries_enabled: self.max_auto_retries_enabled,
+            previous_payment_response_hash_key: self.previous_payment_response_hash_key,
+            previous_payment_response_hash_key_expires_at: self
+                .previous_payment_response_hash_key_expires_at,
         })
     }
 
@@ -604,6 +672,9 @@ impl super::behaviour::Conversion for Profile {
                 is_network_tokenization_enabled: item.is_network_tokenization_enabled,
                 is_auto_retries_enabled: item.is_auto_retries_enabled.unwrap_or(false),
                 max_auto_retries_enabled: item.max_auto_retries_enabled,
+                previous_payment_response_hash_key: item.previous_payment_response_hash_key,
+                previous_payment_response_hash_key_expires_at: item
+                    .previous_payment_response_hash_key_expires_at,
             })
         }
         .await
@@ -656,6 +727,9 @@ impl super::behaviour::Conversion for Profile {
             is_network_tokenization_enabled: self.is_network_tokenization_enabled,
             is_auto_retries_enabled: Some(self.is_auto_retries_enabled),
             max_auto_retries_enabled: self.max_auto_retries_enabled,
+            previous_payment_response_hash_key: self.previous_payment_response_hash_key,
+            previous_payment_response_hash_key_expires_at: self
+                .previous_payment_response_hash_key_expires_at,
         })
     }
 }
@@ -926,6 +1000,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                     is_network_tokenization_enabled,
                     is_auto_retries_enabled: None,
                     max_auto_retries_enabled: None,
+                    previous_payment_response_hash_key: None,
+                    previous_payment_response_hash_key_expires_at: None,
                 }
             }
             ProfileUpdate::RoutingAlgorithmUpdate {
@@ -966,6 +1042,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_network_tokenization_enabled: None,
                 is_auto_retries_enabled: None,
                 max_auto_retries_enabled: None,
+                previous_payment_response_hash_key: None,
+                previous_payment_response_hash_key_expires_at: None,
             },
             ProfileUpdate::ExtendedCardInfoUpdate {
                 is_extended_card_info_enabled,
@@ -1004,6 +1082,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_network_tokenization_enabled: None,
                 is_auto_retries_enabled: None,
                 max_auto_retries_enabled: None,
+                previous_payment_response_hash_key: None,
+                previous_payment_response_hash_key_expires_at: None,
             },
             ProfileUpdate::ConnectorAgnosticMitUpdate {
                 is_connector_agnostic_mit_enabled,
@@ -1042,6 +1122,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_network_tokenization_enabled: None,
                 is_auto_retries_enabled: None,
                 max_auto_retries_enabled: None,
+                previous_payment_response_hash_key: None,
+                previous_payment_response_hash_key_expires_at: None,
             },
             ProfileUpdate::DefaultRoutingFallbackUpdate {
                 default_fallback_routing,
@@ -1080,6 +1162,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_network_tokenization_enabled: None,
                 is_auto_retries_enabled: None,
                 max_auto_retries_enabled: None,
+                previous_payment_response_hash_key: None,
+                previous_payment_response_hash_key_expires_at: None,
             },
             ProfileUpdate::NetworkTokenizationUpdate {
                 is_network_tokenization_enabled,
@@ -1118,6 +1202,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_network_tokenization_enabled,
                 is_auto_retries_enabled: None,
                 max_auto_retries_enabled: None,
+                previous_payment_response_hash_key: None,
+                previous_payment_response_hash_key_expires_at: None,
             },
         }
     }
@@ -1176,6 +1262,8 @@ impl super::behaviour::Conversion for Profile {
             is_network_tokenization_enabled: self.is_network_tokenization_enabled,
             is_auto_retries_enabled: None,
             max_auto_retries_enabled: None,
+            previous_payment_response_hash_key: None,
+            previous_payment_response_hash_key_expires_at: None,
         })
     }
 
@@ -1297,6 +1385,8 @@ impl super::behaviour::Conversion for Profile {
             is_network_tokenization_enabled: self.is_network_tokenization_enabled,
             is_auto_retries_enabled: None,
             max_auto_retries_enabled: None,
+            previous_payment_response_hash_key: None,
+            previous_payment_response_hash_key_expires_at: None,
         })
     }
 }
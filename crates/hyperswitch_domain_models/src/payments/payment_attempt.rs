@@ -9,6 +9,8 @@ use common_utils::{
         ConnectorTransactionId, ConnectorTransactionIdTrait, MinorUnit,
     },
 };
+#[cfg(feature = "v1")]
+use diesel_models::InstallmentPlanDetails;
 use diesel_models::{
     ConnectorMandateReferenceId, PaymentAttempt as DieselPaymentAttempt,
     PaymentAttemptNew as DieselPaymentAttemptNew,
@@ -477,6 +479,7 @@ pub struct PaymentAttempt {
     pub profile_id: id_type::ProfileId,
     pub organization_id: id_type::OrganizationId,
     pub connector_mandate_detail: Option<ConnectorMandateReferenceId>,
+    pub installment_plan: Option<InstallmentPlanDetails>,
 }
 
 #[cfg(feature = "v1")]
@@ -722,6 +725,7 @@ pub struct PaymentAttemptNew {
     pub profile_id: id_type::ProfileId,
     pub organization_id: id_type::OrganizationId,
     pub connector_mandate_detail: Option<ConnectorMandateReferenceId>,
+    pub installment_plan: Option<InstallmentPlanDetails>,
 }
 
 #[cfg(feature = "v1")]
@@ -787,6 +791,7 @@ pub enum PaymentAttemptUpdate {
         client_source: Option<String>,
         client_version: Option<String>,
         customer_acceptance: Option<pii::SecretSerdeValue>,
+        installment_plan: Option<InstallmentPlanDetails>,
     },
     RejectUpdate {
         status: storage_enums::AttemptStatus,
@@ -1027,6 +1032,7 @@ impl PaymentAttemptUpdate {
                 client_source,
                 client_version,
                 customer_acceptance,
+                installment_plan,
             } => DieselPaymentAttemptUpdate::ConfirmUpdate {
                 amount: net_amount.get_order_amount(),
                 currency,
@@ -1060,6 +1066,7 @@ impl PaymentAttemptUpdate {
                 customer_acceptance,
                 shipping_cost: net_amount.get_shipping_cost(),
                 order_tax_amount: net_amount.get_order_tax_amount(),
+                installment_plan,
             },
             Self::VoidUpdate {
                 status,
@@ -1403,6 +1410,7 @@ impl behaviour::Conversion for PaymentAttempt {
             order_tax_amount: self.net_amount.get_order_tax_amount(),
             shipping_cost: self.net_amount.get_shipping_cost(),
             connector_mandate_detail: self.connector_mandate_detail,
+            installment_plan: self.installment_plan,
         })
     }
 
@@ -1484,6 +1492,7 @@ impl behaviour::Conversion for PaymentAttempt {
                 profile_id: storage_model.profile_id,
                 organization_id: storage_model.organization_id,
                 connector_mandate_detail: storage_model.connector_mandate_detail,
+                installment_plan: storage_model.installment_plan,
             })
         }
         .await
@@ -1566,6 +1575,7 @@ impl behaviour::Conversion for PaymentAttempt {
             order_tax_amount: self.net_amount.get_order_tax_amount(),
             shipping_cost: self.net_amount.get_shipping_cost(),
             connector_mandate_detail: self.connector_mandate_detail,
+            installment_plan: self.installment_plan,
         })
     }
 }
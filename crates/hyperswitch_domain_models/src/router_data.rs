@@ -83,6 +83,9 @@ pub struct RouterData<Flow, Request, Response> {
 
     pub additional_merchant_data: Option<api_models::admin::AdditionalMerchantData>,
 
+    /// The merchant connector account used to process this request, if known
+    pub merchant_connector_id: Option<id_type::MerchantConnectorAccountId>,
+
     pub header_payload: Option<payments::HeaderPayload>,
 }
 
@@ -61,3 +61,6 @@ pub struct CreateIntent;
 
 #[derive(Debug, Clone)]
 pub struct PostSessionTokens;
+
+#[derive(Debug, Clone)]
+pub struct InstallmentPlans;
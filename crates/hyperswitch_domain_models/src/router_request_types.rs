@@ -62,6 +62,9 @@ pub struct PaymentsAuthorizeData {
     pub metadata: Option<serde_json::Value>,
     pub authentication_data: Option<AuthenticationData>,
     pub charges: Option<PaymentCharges>,
+    /// Only populated if the connector transfers the shares to the recipients itself
+    pub split_payments: Option<SplitPaymentsData>,
+    pub installment_plan: Option<diesel_models::InstallmentPlanDetails>,
 
     // New amount for amount frame work
     pub minor_amount: MinorUnit,
@@ -112,6 +115,23 @@ pub struct PaymentCharges {
     pub transfer_account_id: String,
 }
 
+/// The shares of the recipients of a split payment. The remaining amount is retained by the
+/// platform.
+#[derive(Debug, Clone)]
+pub struct SplitPaymentsData {
+    pub recipients: Vec<SplitPaymentRecipientData>,
+    /// Amount retained by the platform
+    pub platform_amount: MinorUnit,
+}
+
+#[derive(Debug, Clone)]
+pub struct SplitPaymentRecipientData {
+    pub split_id: String,
+    pub recipient_account_id: String,
+    pub amount: MinorUnit,
+    pub is_fee_bearer: bool,
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct PaymentsCaptureData {
     pub amount_to_capture: i64,
@@ -609,6 +629,8 @@ pub struct RefundsData {
     pub browser_info: Option<BrowserInformation>,
     /// Charges associated with the payment
     pub charges: Option<ChargeRefunds>,
+    /// Shares of the recipients of a split payment to be reversed by the refund
+    pub split_refunds: Option<SplitPaymentsData>,
 
     // New amount for amount frame work
     pub minor_payment_amount: MinorUnit,
@@ -835,6 +857,15 @@ pub struct PaymentsTaxCalculationData {
     pub shipping_address: Address,
 }
 
+#[derive(Debug, Clone)]
+pub struct InstallmentPlansRequestData {
+    /// The first six to eight digits of the card
+    pub card_bin: String,
+    pub amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub country: Option<common_enums::CountryAlpha2>,
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct SdkPaymentsSessionUpdateData {
     pub order_tax_amount: MinorUnit,
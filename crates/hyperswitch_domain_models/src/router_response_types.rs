@@ -78,6 +78,21 @@ pub struct TaxCalculationResponseData {
     pub order_tax_amount: MinorUnit,
 }
 
+#[derive(Debug, Clone)]
+pub struct InstallmentPlansResponseData {
+    pub plans: Vec<InstallmentPlanOption>,
+}
+
+#[derive(Debug, Clone)]
+pub struct InstallmentPlanOption {
+    pub plan_id: Option<String>,
+    pub number_of_installments: u16,
+    pub installment_amount: MinorUnit,
+    pub total_amount: MinorUnit,
+    pub interest_rate: Option<f64>,
+    pub fee_amount: Option<MinorUnit>,
+}
+
 #[derive(serde::Serialize, Debug, Clone)]
 pub struct MandateReference {
     pub connector_mandate_id: Option<String>,
@@ -2,18 +2,19 @@ use crate::{
     router_data::{AccessToken, RouterData},
     router_flow_types::{
         AccessTokenAuth, Authorize, AuthorizeSessionToken, CalculateTax, Capture,
-        CompleteAuthorize, CreateConnectorCustomer, PSync, PaymentMethodToken, PostSessionTokens,
-        PreProcessing, RSync, SetupMandate, Void,
+        CompleteAuthorize, CreateConnectorCustomer, InstallmentPlans, PSync, PaymentMethodToken,
+        PostSessionTokens, PreProcessing, RSync, SetupMandate, Void,
     },
     router_request_types::{
         AccessTokenRequestData, AuthorizeSessionTokenData, CompleteAuthorizeData,
-        ConnectorCustomerData, PaymentMethodTokenizationData, PaymentsAuthorizeData,
-        PaymentsCancelData, PaymentsCaptureData, PaymentsPostSessionTokensData,
-        PaymentsPreProcessingData, PaymentsSyncData, PaymentsTaxCalculationData, RefundsData,
-        SetupMandateRequestData,
+        ConnectorCustomerData, InstallmentPlansRequestData, PaymentMethodTokenizationData,
+        PaymentsAuthorizeData, PaymentsCancelData, PaymentsCaptureData,
+        PaymentsPostSessionTokensData, PaymentsPreProcessingData, PaymentsSyncData,
+        PaymentsTaxCalculationData, RefundsData, SetupMandateRequestData,
     },
     router_response_types::{
-        PaymentsResponseData, RefundsResponseData, TaxCalculationResponseData,
+        InstallmentPlansResponseData, PaymentsResponseData, RefundsResponseData,
+        TaxCalculationResponseData,
     },
 };
 
@@ -41,3 +42,5 @@ pub type PaymentsTaxCalculationRouterData =
 pub type RefreshTokenRouterData = RouterData<AccessTokenAuth, AccessTokenRequestData, AccessToken>;
 pub type PaymentsPostSessionTokensRouterData =
     RouterData<PostSessionTokens, PaymentsPostSessionTokensData, PaymentsResponseData>;
+pub type PaymentsInstallmentPlansRouterData =
+    RouterData<InstallmentPlans, InstallmentPlansRequestData, InstallmentPlansResponseData>;
@@ -18,6 +18,10 @@ use crate::{
 pub enum FilePurpose {
     /// DisputeEvidence
     DisputeEvidence,
+    /// PayoutBatch
+    PayoutBatch,
+    /// RefundBatch
+    RefundBatch,
 }
 
 /// trait UploadFile
@@ -25,6 +25,9 @@ pub struct ConnectorEvent {
     refund_id: Option<String>,
     dispute_id: Option<String>,
     status_code: u16,
+    merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+    error_code: Option<String>,
+    is_timeout: bool,
 }
 
 impl ConnectorEvent {
@@ -66,6 +69,9 @@ impl ConnectorEvent {
             refund_id,
             dispute_id,
             status_code,
+            merchant_connector_id: None,
+            error_code: None,
+            is_timeout: false,
         }
     }
 
@@ -93,4 +99,22 @@ impl ConnectorEvent {
     pub fn set_error(&mut self, error: serde_json::Value) {
         self.error = Some(error.to_string());
     }
+
+    /// fn set_merchant_connector_id
+    pub fn set_merchant_connector_id(
+        &mut self,
+        merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+    ) {
+        self.merchant_connector_id = merchant_connector_id;
+    }
+
+    /// fn set_error_code
+    pub fn set_error_code(&mut self, error_code: String) {
+        self.error_code = Some(error_code);
+    }
+
+    /// fn set_timeout
+    pub fn set_timeout(&mut self) {
+        self.is_timeout = true;
+    }
 }
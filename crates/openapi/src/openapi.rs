@@ -58,6 +58,8 @@ Never share your secret api keys. Keep them guarded and secure.
         (name = "Payments", description = "Create and manage one-time payments, recurring payments and mandates"),
         (name = "Refunds", description = "Create and manage refunds for successful payments"),
         (name = "Mandates", description = "Manage mandates"),
+        (name = "Subscriptions", description = "Manage subscription plans and subscriptions"),
+        (name = "Invoices", description = "Create and manage invoices with hosted invoice pages"),
         (name = "Customers", description = "Create and manage customers"),
         (name = "Payment Methods", description = "Create and manage payment methods of customers"),
         (name = "Disputes", description = "Manage disputes"),
@@ -66,6 +68,8 @@ Never share your secret api keys. Keep them guarded and secure.
         (name = "payment link", description = "Create payment link"),
         (name = "Routing", description = "Create and manage routing configurations"),
         (name = "Event", description = "Manage events"),
+        (name = "Incoming Webhook", description = "Inspect and reprocess incoming webhooks received from connectors"),
+        (name = "Webhook Endpoint", description = "Create and manage webhook endpoints"),
     ),
     // The paths will be displayed in the same order as they are registered here
     paths(
@@ -80,6 +84,10 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::payments::payments_list,
         routes::payments::payments_incremental_authorization,
         routes::payment_link::payment_link_retrieve,
+        routes::payment_link::reusable_payment_link_create,
+        routes::payment_link::reusable_payment_link_retrieve,
+        routes::payment_link::reusable_payment_link_update,
+        routes::payment_link::reusable_payment_link_list,
         routes::payments::payments_external_authentication,
         routes::payments::payments_complete_authorize,
         routes::payments::payments_post_session_tokens,
@@ -89,6 +97,9 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::refunds::refunds_retrieve,
         routes::refunds::refunds_update,
         routes::refunds::refunds_list,
+        routes::refunds::refund_batch_create,
+        routes::refunds::refund_batch_retrieve,
+        routes::refunds::refund_batch_report,
 
         // Routes for Organization
         routes::organization::organization_create,
@@ -120,6 +131,22 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::mandates::revoke_mandate,
         routes::mandates::customers_mandates_list,
 
+        // Routes for subscriptions
+        routes::subscriptions::create_subscription_plan,
+        routes::subscriptions::retrieve_subscription_plan,
+        routes::subscriptions::update_subscription_plan,
+        routes::subscriptions::list_subscription_plans,
+        routes::subscriptions::create_subscription,
+        routes::subscriptions::retrieve_subscription,
+        routes::subscriptions::list_subscriptions,
+        routes::subscriptions::cancel_subscription,
+
+        // Routes for invoices
+        routes::invoices::create_invoice,
+        routes::invoices::retrieve_invoice,
+        routes::invoices::list_invoices,
+        routes::invoices::void_invoice,
+
         //Routes for customers
         routes::customers::customers_create,
         routes::customers::customers_retrieve,
@@ -142,6 +169,7 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::profile::profile_list,
         routes::profile::profile_retrieve,
         routes::profile::profile_update,
+        routes::profile::rotate_profile_webhook_secret,
         routes::profile::profile_delete,
 
         // Routes for disputes
@@ -177,12 +205,16 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::payouts::payouts_confirm,
         routes::payouts::payouts_list_filters,
         routes::payouts::payouts_list_by_filter,
+        routes::payouts::payout_batch_create,
+        routes::payouts::payout_batch_retrieve,
+        routes::payouts::payout_batch_results_retrieve,
 
         // Routes for api keys
         routes::api_keys::api_key_create,
         routes::api_keys::api_key_retrieve,
         routes::api_keys::api_key_update,
         routes::api_keys::api_key_revoke,
+        routes::api_keys::api_key_rotate,
         routes::api_keys::api_key_list,
 
         // Routes for events
@@ -190,6 +222,21 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::webhook_events::list_webhook_delivery_attempts,
         routes::webhook_events::retry_webhook_delivery_attempt,
 
+        // Routes for incoming webhooks
+        routes::incoming_webhook_archive::list_incoming_webhooks,
+        routes::incoming_webhook_archive::retrieve_incoming_webhook,
+        routes::incoming_webhook_archive::reprocess_incoming_webhook,
+
+        // Routes for webhook endpoints
+        routes::webhook_endpoints::create_webhook_endpoint,
+        routes::webhook_endpoints::list_webhook_endpoints,
+        routes::webhook_endpoints::retrieve_webhook_endpoint,
+        routes::webhook_endpoints::update_webhook_endpoint,
+        routes::webhook_endpoints::delete_webhook_endpoint,
+        routes::webhook_endpoints::rotate_webhook_endpoint_secret,
+        routes::webhook_endpoints::retrieve_webhook_endpoint_health,
+        routes::webhook_endpoints::reenable_webhook_endpoint,
+
         // Routes for poll apis
         routes::poll::retrieve_poll_status,
     ),
@@ -212,6 +259,10 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::refunds::RefundType,
         api_models::refunds::RefundResponse,
         api_models::refunds::RefundStatus,
+        api_models::refunds::RefundBatchCreateRequest,
+        api_models::refunds::RefundBatchResponse,
+        api_models::refunds::RefundBatchRowResult,
+        api_models::enums::RefundBatchStatus,
         api_models::refunds::RefundUpdateRequest,
         api_models::organization::OrganizationCreateRequest,
         api_models::organization::OrganizationUpdateRequest,
@@ -497,6 +548,21 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::mandates::MandateResponse,
         api_models::mandates::MandateCardDetails,
         api_models::mandates::RecurringDetails,
+        api_models::subscriptions::SubscriptionPlanCreateRequest,
+        api_models::subscriptions::SubscriptionPlanUpdateRequest,
+        api_models::subscriptions::SubscriptionPlanResponse,
+        api_models::subscriptions::SubscriptionPlanListResponse,
+        api_models::subscriptions::SubscriptionCreateRequest,
+        api_models::subscriptions::SubscriptionCancelRequest,
+        api_models::subscriptions::SubscriptionResponse,
+        api_models::subscriptions::SubscriptionListResponse,
+        api_models::enums::SubscriptionStatus,
+        api_models::invoices::InvoiceLineItem,
+        api_models::invoices::InvoiceCreateRequest,
+        api_models::invoices::InvoiceResponse,
+        api_models::invoices::InvoiceListResponse,
+        api_models::enums::InvoiceStatus,
+        api_models::enums::SubscriptionInterval,
         api_models::mandates::NetworkTransactionIdAndCardDetails,
         api_models::mandates::ProcessorPaymentToken,
         api_models::ephemeral_key::EphemeralKeyCreateResponse,
@@ -528,16 +594,24 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payouts::PayoutMethodData,
         api_models::payouts::PayoutMethodDataResponse,
         api_models::payouts::PayoutLinkResponse,
+        api_models::payouts::PayoutBatchCreateRequest,
+        api_models::payouts::PayoutBatchResponse,
+        api_models::payouts::PayoutBatchRowResult,
         api_models::payouts::Bank,
         api_models::payouts::PayoutCreatePayoutLinkConfig,
         api_models::enums::PayoutEntityType,
         api_models::enums::PayoutSendPriority,
         api_models::enums::PayoutStatus,
+        api_models::enums::PayoutBatchStatus,
         api_models::enums::PayoutType,
         api_models::enums::TransactionType,
         api_models::payments::FrmMessage,
         api_models::webhooks::OutgoingWebhook,
         api_models::webhooks::OutgoingWebhookContent,
+        api_models::analytics::success_rate_alerts::SuccessRateAlert,
+        api_models::analytics::success_rate_alerts::SuccessRateAlertSegment,
+        api_models::analytics::success_rate_alerts::SuccessRateAlertStatus,
+        api_models::analytics::success_rate_alerts::SuccessRateAlertMetric,
         api_models::enums::EventClass,
         api_models::enums::EventType,
         api_models::enums::DecoupledAuthenticationType,
@@ -553,11 +627,18 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::api_keys::CreateApiKeyResponse,
         api_models::api_keys::RetrieveApiKeyResponse,
         api_models::api_keys::RevokeApiKeyResponse,
+        api_models::api_keys::RotateApiKeyRequest,
+        api_models::api_keys::RotateApiKeyResponse,
         api_models::api_keys::UpdateApiKeyRequest,
         api_models::payments::RetrievePaymentLinkRequest,
         api_models::payments::PaymentLinkResponse,
         api_models::payments::RetrievePaymentLinkResponse,
         api_models::payments::PaymentLinkInitiateRequest,
+        api_models::payments::ReusablePaymentLinkCreateRequest,
+        api_models::payments::ReusablePaymentLinkUpdateRequest,
+        api_models::payments::ReusablePaymentLinkResponse,
+        api_models::payments::ReusablePaymentLinkListResponse,
+        api_models::enums::ReusablePaymentLinkAmountType,
         api_models::payouts::PayoutLinkInitiateRequest,
         api_models::payments::ExtendedCardInfoResponse,
         api_models::payments::GooglePayAssuranceDetails,
@@ -599,11 +680,34 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::webhook_events::EventRetrieveResponse,
         api_models::webhook_events::OutgoingWebhookRequestContent,
         api_models::webhook_events::OutgoingWebhookResponseContent,
+        api_models::webhook_endpoints::WebhookEndpointCreateRequest,
+        api_models::webhook_endpoints::WebhookEndpointUpdateRequest,
+        api_models::webhook_endpoints::WebhookEndpointResponse,
+        api_models::webhook_endpoints::WebhookEndpointDeleteResponse,
+        api_models::webhook_endpoints::WebhookSecretRotateRequest,
+        api_models::webhook_endpoints::WebhookSecretRotateResponse,
+        api_models::webhook_endpoints::WebhookEndpointStatus,
+        api_models::webhook_endpoints::WebhookEndpointHealthResponse,
+        api_models::webhook_endpoints::WebhookEndpointReenableResponse,
         api_models::enums::WebhookDeliveryAttempt,
+        api_models::enums::IncomingWebhookProcessingStatus,
+        api_models::incoming_webhook_archive::IncomingWebhookListItemResponse,
+        api_models::incoming_webhook_archive::IncomingWebhookRetrieveResponse,
+        api_models::incoming_webhook_archive::IncomingWebhookRequestContent,
         api_models::enums::PaymentChargeType,
         api_models::enums::StripeChargeType,
         api_models::payments::PaymentChargeRequest,
         api_models::payments::PaymentChargeResponse,
+        api_models::payments::InstallmentPlan,
+        api_models::payments::InstallmentPlanSelection,
+        api_models::payments::InstallmentPlanDetails,
+        api_models::payments::SplitPaymentsRequest,
+        api_models::payments::SplitPaymentRecipient,
+        api_models::payments::SplitPaymentShare,
+        api_models::payments::SplitFeeBearer,
+        api_models::payments::SplitPaymentsResponse,
+        api_models::payments::SplitPaymentRecipientResponse,
+        api_models::enums::SplitSettlementType,
         api_models::refunds::ChargeRefunds,
         api_models::payments::CustomerDetailsResponse,
         api_models::payments::OpenBankingData,
@@ -164,6 +164,55 @@ pub async fn api_key_revoke() {}
 )]
 pub async fn api_key_revoke() {}
 
+#[cfg(feature = "v1")]
+/// API Key - Rotate
+///
+/// Issue a successor for the specified API Key. The API Key being rotated remains valid for the
+/// requested grace period, after which it is automatically revoked. The plaintext successor API
+/// Key will be displayed only once, so ensure you store it securely.
+#[utoipa::path(
+    post,
+    path = "/api_keys/{merchant_id}/{key_id}/rotate",
+    request_body = RotateApiKeyRequest,
+    params (
+        ("merchant_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("key_id" = String, Path, description = "The unique identifier for the API Key")
+    ),
+    responses(
+        (status = 200, description = "API Key rotated", body = RotateApiKeyResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "API Key not found")
+    ),
+    tag = "API Key",
+    operation_id = "Rotate an API Key",
+    security(("admin_api_key" = []))
+)]
+pub async fn api_key_rotate() {}
+
+#[cfg(feature = "v2")]
+/// API Key - Rotate
+///
+/// Issue a successor for the specified API Key. The API Key being rotated remains valid for the
+/// requested grace period, after which it is automatically revoked. The plaintext successor API
+/// Key will be displayed only once, so ensure you store it securely.
+#[utoipa::path(
+    post,
+    path = "/v2/api_keys/{key_id}/rotate",
+    request_body = RotateApiKeyRequest,
+    params (
+        ("key_id" = String, Path, description = "The unique identifier for the API Key")
+    ),
+    responses(
+        (status = 200, description = "API Key rotated", body = RotateApiKeyResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "API Key not found")
+    ),
+    tag = "API Key",
+    operation_id = "Rotate an API Key",
+    security(("admin_api_key" = []))
+)]
+pub async fn api_key_rotate() {}
+
 #[cfg(feature = "v1")]
 /// API Key - List
 ///
@@ -0,0 +1,70 @@
+/// Incoming Webhooks - List
+///
+/// List the incoming webhooks received from connectors for a Merchant Account, most recent first.
+#[utoipa::path(
+    get,
+    path = "/incoming_webhooks/{merchant_id}",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the Merchant Account."),
+        ("created_after" = Option<PrimitiveDateTime>, Query, description = "Only include webhooks received after the specified time."),
+        ("created_before" = Option<PrimitiveDateTime>, Query, description = "Only include webhooks received before the specified time."),
+        ("limit" = Option<i64>, Query, description = "The maximum number of webhooks to include in the response."),
+        ("offset" = Option<i64>, Query, description = "The number of webhooks to skip when retrieving the list of webhooks."),
+        ("connector_name" = Option<String>, Query, description = "Only include webhooks received from the specified connector."),
+        ("merchant_connector_id" = Option<String>, Query, description = "Only include webhooks processed with the specified merchant connector account."),
+        ("profile_id" = Option<String>, Query, description = "Only include webhooks associated with the specified Profile."),
+        ("processing_status" = Option<IncomingWebhookProcessingStatus>, Query, description = "Only include webhooks with the specified processing outcome."),
+    ),
+    responses(
+        (status = 200, description = "List of incoming webhooks retrieved successfully", body = Vec<IncomingWebhookListItemResponse>),
+    ),
+    tag = "Incoming Webhook",
+    operation_id = "List all incoming webhooks received for a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub fn list_incoming_webhooks() {}
+
+/// Incoming Webhooks - Retrieve
+///
+/// Retrieve an incoming webhook, along with the request received from the connector.
+#[utoipa::path(
+    get,
+    path = "/incoming_webhooks/{merchant_id}/{webhook_id}",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the Merchant Account."),
+        ("webhook_id" = String, Path, description = "The unique identifier for the incoming webhook"),
+    ),
+    responses(
+        (status = 200, description = "Incoming webhook retrieved successfully", body = IncomingWebhookRetrieveResponse),
+        (status = 404, description = "Incoming webhook not found"),
+    ),
+    tag = "Incoming Webhook",
+    operation_id = "Retrieve an incoming webhook",
+    security(("admin_api_key" = []))
+)]
+pub fn retrieve_incoming_webhook() {}
+
+/// Incoming Webhooks - Reprocess
+///
+/// Run the request received from the connector through the incoming webhooks flow again.
+#[utoipa::path(
+    post,
+    path = "/incoming_webhooks/{merchant_id}/{webhook_id}/reprocess",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the Merchant Account."),
+        ("webhook_id" = String, Path, description = "The unique identifier for the incoming webhook"),
+    ),
+    responses(
+        (
+            status = 200,
+            description = "The incoming webhook was reprocessed. \
+                           Check the `processing_status` field in the response payload to identify the outcome.",
+            body = IncomingWebhookRetrieveResponse
+        ),
+        (status = 404, description = "Incoming webhook not found"),
+    ),
+    tag = "Incoming Webhook",
+    operation_id = "Reprocess an incoming webhook",
+    security(("admin_api_key" = []))
+)]
+pub fn reprocess_incoming_webhook() {}
@@ -0,0 +1,76 @@
+/// Invoices - Create
+///
+/// Creates an invoice for a customer along with a hosted invoice page through which it can be paid
+#[utoipa::path(
+    post,
+    path = "/invoices",
+    request_body = InvoiceCreateRequest,
+    responses(
+        (status = 200, description = "Invoice created", body = InvoiceResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Invoices",
+    operation_id = "Create an Invoice",
+    security(("api_key" = []))
+)]
+pub async fn create_invoice() {}
+
+/// Invoices - Retrieve
+///
+/// Retrieves an invoice. The status of the invoice is refreshed from its payment.
+#[utoipa::path(
+    get,
+    path = "/invoices/{invoice_id}",
+    params(
+        ("invoice_id" = String, Path, description = "The identifier for the invoice")
+    ),
+    responses(
+        (status = 200, description = "Invoice retrieved", body = InvoiceResponse),
+        (status = 404, description = "Invoice does not exist in our records")
+    ),
+    tag = "Invoices",
+    operation_id = "Retrieve an Invoice",
+    security(("api_key" = []))
+)]
+pub async fn retrieve_invoice() {}
+
+/// Invoices - List
+///
+/// Lists the invoices of the merchant, optionally filtered by customer and status
+#[utoipa::path(
+    get,
+    path = "/invoices",
+    params(
+        ("customer_id" = Option<String>, Query, description = "Only return invoices of the given customer"),
+        ("status" = Option<InvoiceStatus>, Query, description = "Only return invoices with the given status"),
+        ("limit" = Option<i64>, Query, description = "The maximum number of invoices to be returned"),
+        ("offset" = Option<i64>, Query, description = "The number of invoices to skip")
+    ),
+    responses(
+        (status = 200, description = "Invoices retrieved", body = InvoiceListResponse),
+    ),
+    tag = "Invoices",
+    operation_id = "List all Invoices",
+    security(("api_key" = []))
+)]
+pub async fn list_invoices() {}
+
+/// Invoices - Void
+///
+/// Voids an unpaid invoice. The hosted invoice page can no longer be used to pay it.
+#[utoipa::path(
+    post,
+    path = "/invoices/{invoice_id}/void",
+    params(
+        ("invoice_id" = String, Path, description = "The identifier for the invoice")
+    ),
+    responses(
+        (status = 200, description = "Invoice voided", body = InvoiceResponse),
+        (status = 400, description = "Invoice has already been paid"),
+        (status = 404, description = "Invoice does not exist in our records")
+    ),
+    tag = "Invoices",
+    operation_id = "Void an Invoice",
+    security(("api_key" = []))
+)]
+pub async fn void_invoice() {}
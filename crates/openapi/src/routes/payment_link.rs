@@ -17,3 +17,80 @@
     security(("api_key" = []), ("publishable_key" = []))
 )]
 pub async fn payment_link_retrieve() {}
+
+/// Reusable Payment Link - Create
+///
+/// Creates a payment link which can be paid through any number of times. Every visit to the link
+/// creates a new payment.
+#[utoipa::path(
+    post,
+    path = "/payment_link/reusable",
+    request_body = ReusablePaymentLinkCreateRequest,
+    responses(
+        (status = 200, description = "Reusable payment link created", body = ReusablePaymentLinkResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Payments",
+    operation_id = "Create a Reusable Payment Link",
+    security(("api_key" = []))
+)]
+pub async fn reusable_payment_link_create() {}
+
+/// Reusable Payment Link - Retrieve
+///
+/// Retrieves a reusable payment link
+#[utoipa::path(
+    get,
+    path = "/payment_link/reusable/{link_id}",
+    params(
+        ("link_id" = String, Path, description = "The identifier for the reusable payment link")
+    ),
+    responses(
+        (status = 200, description = "Reusable payment link retrieved", body = ReusablePaymentLinkResponse),
+        (status = 404, description = "No payment link found")
+    ),
+    tag = "Payments",
+    operation_id = "Retrieve a Reusable Payment Link",
+    security(("api_key" = []))
+)]
+pub async fn reusable_payment_link_retrieve() {}
+
+/// Reusable Payment Link - Update
+///
+/// Updates a reusable payment link. The amount of a link cannot be changed, a link can be
+/// deactivated instead.
+#[utoipa::path(
+    post,
+    path = "/payment_link/reusable/{link_id}",
+    params(
+        ("link_id" = String, Path, description = "The identifier for the reusable payment link")
+    ),
+    request_body = ReusablePaymentLinkUpdateRequest,
+    responses(
+        (status = 200, description = "Reusable payment link updated", body = ReusablePaymentLinkResponse),
+        (status = 404, description = "No payment link found")
+    ),
+    tag = "Payments",
+    operation_id = "Update a Reusable Payment Link",
+    security(("api_key" = []))
+)]
+pub async fn reusable_payment_link_update() {}
+
+/// Reusable Payment Link - List
+///
+/// Lists the reusable payment links of the merchant
+#[utoipa::path(
+    get,
+    path = "/payment_link/reusable",
+    params(
+        ("limit" = Option<i64>, Query, description = "The maximum number of links to be returned"),
+        ("offset" = Option<i64>, Query, description = "The number of links to skip")
+    ),
+    responses(
+        (status = 200, description = "Reusable payment links retrieved", body = ReusablePaymentLinkListResponse),
+    ),
+    tag = "Payments",
+    operation_id = "List all Reusable Payment Links",
+    security(("api_key" = []))
+)]
+pub async fn reusable_payment_link_list() {}
@@ -48,6 +48,7 @@ pub async fn create_payment_method_api() {}
         ("maximum_amount" = i64, Query, description = "The maximum amount accepted for processing by the particular payment method."),
         ("recurring_payment_enabled" = bool, Query, description = "Indicates whether the payment method is eligible for recurring payments"),
         ("installment_payment_enabled" = bool, Query, description = "Indicates whether the payment method is eligible for installment payments"),
+        ("card_bin" = String, Query, description = "The first six to eight digits of the card, used to list the installment plans offered for the card"),
     ),
     responses(
         (status = 200, description = "Payment Methods retrieved", body = PaymentMethodListResponse),
@@ -190,3 +190,56 @@ pub async fn payouts_list_by_filter_profile() {}
     security(("api_key" = []))
 )]
 pub async fn payouts_confirm() {}
+
+/// Payouts - Batch Create
+///
+/// Creates payouts in bulk from a CSV or JSON file uploaded with the purpose `payout_batch`. The rows of the file are validated before the batch is accepted, and the payouts are created asynchronously.
+#[utoipa::path(
+    post,
+    path = "/payouts/batch",
+    request_body=PayoutBatchCreateRequest,
+    responses(
+        (status = 200, description = "Payout batch created", body = PayoutBatchResponse),
+        (status = 400, description = "Invalid payouts in the file")
+    ),
+    tag = "Payouts",
+    operation_id = "Create a Payout Batch",
+    security(("api_key" = []))
+)]
+pub async fn payout_batch_create() {}
+
+/// Payouts - Batch Retrieve
+#[utoipa::path(
+    get,
+    path = "/payouts/batch/{batch_id}",
+    params(
+        ("batch_id" = String, Path, description = "The identifier for the payout batch")
+    ),
+    responses(
+        (status = 200, description = "Payout batch retrieved", body = PayoutBatchResponse),
+        (status = 404, description = "Payout batch does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "Retrieve a Payout Batch",
+    security(("api_key" = []))
+)]
+pub async fn payout_batch_retrieve() {}
+
+/// Payouts - Batch Results Retrieve
+///
+/// Downloads the results of all the rows of a processed payout batch as a CSV file.
+#[utoipa::path(
+    get,
+    path = "/payouts/batch/{batch_id}/results",
+    params(
+        ("batch_id" = String, Path, description = "The identifier for the payout batch")
+    ),
+    responses(
+        (status = 200, description = "Payout batch results retrieved", content_type = "text/csv"),
+        (status = 404, description = "Payout batch does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "Retrieve the Results of a Payout Batch",
+    security(("api_key" = []))
+)]
+pub async fn payout_batch_results_retrieve() {}
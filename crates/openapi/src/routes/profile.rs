@@ -69,6 +69,31 @@ pub async fn profile_create() {}
 )]
 pub async fn profile_update() {}
 
+#[cfg(feature = "v1")]
+/// Profile - Rotate Webhook Secret
+///
+/// Replace the payment response hash key of the *profile*, which is used to sign outgoing
+/// webhooks, with a newly generated one. Until the previous key expires, webhooks carry signatures
+/// computed using both keys.
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/business_profile/{profile_id}/rotate_webhook_secret",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    request_body = WebhookSecretRotateRequest,
+    responses(
+        (status = 200, description = "Webhook Secret Rotated", body = WebhookSecretRotateResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Profile not found")
+    ),
+    tag = "Profile",
+    operation_id = "Rotate the Webhook Secret of a Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn rotate_profile_webhook_secret() {}
+
 #[cfg(feature = "v1")]
 /// Profile - Retrieve
 ///
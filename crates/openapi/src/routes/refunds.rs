@@ -161,6 +161,59 @@ pub fn refunds_list_profile() {}
 )]
 pub async fn refunds_filter_list() {}
 
+/// Refunds - Batch Create
+///
+/// Creates refunds in bulk from a CSV or JSON file uploaded with the purpose `refund_batch`, whose rows contain the `payment_id` and optionally the `amount`, `reason` and `refund_id`. The refunds are created asynchronously, and are sent to each connector at a throttled rate.
+#[utoipa::path(
+    post,
+    path = "/refunds/batch",
+    request_body=RefundBatchCreateRequest,
+    responses(
+        (status = 200, description = "Refund batch created", body = RefundBatchResponse),
+        (status = 400, description = "Invalid refunds in the file")
+    ),
+    tag = "Refunds",
+    operation_id = "Create a Refund Batch",
+    security(("api_key" = []))
+)]
+pub async fn refund_batch_create() {}
+
+/// Refunds - Batch Retrieve
+#[utoipa::path(
+    get,
+    path = "/refunds/batch/{batch_id}",
+    params(
+        ("batch_id" = String, Path, description = "The identifier for the refund batch")
+    ),
+    responses(
+        (status = 200, description = "Refund batch retrieved", body = RefundBatchResponse),
+        (status = 404, description = "Refund batch does not exist in our records")
+    ),
+    tag = "Refunds",
+    operation_id = "Retrieve a Refund Batch",
+    security(("api_key" = []))
+)]
+pub async fn refund_batch_retrieve() {}
+
+/// Refunds - Batch Report
+///
+/// Downloads a CSV report of the rows of a refund batch, with the latest status of each refund.
+#[utoipa::path(
+    get,
+    path = "/refunds/batch/{batch_id}/report",
+    params(
+        ("batch_id" = String, Path, description = "The identifier for the refund batch")
+    ),
+    responses(
+        (status = 200, description = "Refund batch report retrieved", content_type = "text/csv"),
+        (status = 404, description = "Refund batch does not exist in our records")
+    ),
+    tag = "Refunds",
+    operation_id = "Retrieve the Report of a Refund Batch",
+    security(("api_key" = []))
+)]
+pub async fn refund_batch_report() {}
+
 /// Refunds - Create
 ///
 /// Creates a refund against an already processed payment. In case of some processors, you can even opt to refund only a partial amount multiple times until the original charge amount has been refunded
@@ -0,0 +1,148 @@
+/// Subscription Plans - Create
+///
+/// Creates a plan which customers can be subscribed to
+#[utoipa::path(
+    post,
+    path = "/subscriptions/plans",
+    request_body = SubscriptionPlanCreateRequest,
+    responses(
+        (status = 200, description = "Subscription plan created", body = SubscriptionPlanResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Create a Subscription Plan",
+    security(("api_key" = []))
+)]
+pub async fn create_subscription_plan() {}
+
+/// Subscription Plans - Retrieve
+///
+/// Retrieves a subscription plan
+#[utoipa::path(
+    get,
+    path = "/subscriptions/plans/{plan_id}",
+    params(
+        ("plan_id" = String, Path, description = "The identifier for the subscription plan")
+    ),
+    responses(
+        (status = 200, description = "Subscription plan retrieved", body = SubscriptionPlanResponse),
+        (status = 404, description = "Subscription plan does not exist in our records")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Retrieve a Subscription Plan",
+    security(("api_key" = []))
+)]
+pub async fn retrieve_subscription_plan() {}
+
+/// Subscription Plans - Update
+///
+/// Updates a subscription plan. The amount and the billing interval of a plan cannot be changed.
+#[utoipa::path(
+    post,
+    path = "/subscriptions/plans/{plan_id}",
+    params(
+        ("plan_id" = String, Path, description = "The identifier for the subscription plan")
+    ),
+    request_body = SubscriptionPlanUpdateRequest,
+    responses(
+        (status = 200, description = "Subscription plan updated", body = SubscriptionPlanResponse),
+        (status = 404, description = "Subscription plan does not exist in our records")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Update a Subscription Plan",
+    security(("api_key" = []))
+)]
+pub async fn update_subscription_plan() {}
+
+/// Subscription Plans - List
+///
+/// Lists the subscription plans of the merchant
+#[utoipa::path(
+    get,
+    path = "/subscriptions/plans",
+    responses(
+        (status = 200, description = "List of subscription plans", body = SubscriptionPlanListResponse)
+    ),
+    tag = "Subscriptions",
+    operation_id = "List all Subscription Plans",
+    security(("api_key" = []))
+)]
+pub async fn list_subscription_plans() {}
+
+/// Subscriptions - Create
+///
+/// Subscribes a customer to a plan. The customer is charged off-session on every billing date,
+/// using the mandate or the saved payment method provided.
+#[utoipa::path(
+    post,
+    path = "/subscriptions",
+    request_body = SubscriptionCreateRequest,
+    responses(
+        (status = 200, description = "Subscription created", body = SubscriptionResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Create a Subscription",
+    security(("api_key" = []))
+)]
+pub async fn create_subscription() {}
+
+/// Subscriptions - Retrieve
+///
+/// Retrieves a subscription
+#[utoipa::path(
+    get,
+    path = "/subscriptions/{subscription_id}",
+    params(
+        ("subscription_id" = String, Path, description = "The identifier for the subscription")
+    ),
+    responses(
+        (status = 200, description = "Subscription retrieved", body = SubscriptionResponse),
+        (status = 404, description = "Subscription does not exist in our records")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Retrieve a Subscription",
+    security(("api_key" = []))
+)]
+pub async fn retrieve_subscription() {}
+
+/// Subscriptions - List
+///
+/// Lists the subscriptions of the merchant, optionally filtered by customer and status
+#[utoipa::path(
+    get,
+    path = "/subscriptions",
+    params(
+        ("customer_id" = Option<String>, Query, description = "Only return subscriptions of the given customer"),
+        ("status" = Option<SubscriptionStatus>, Query, description = "Only return subscriptions with the given status"),
+        ("limit" = Option<i64>, Query, description = "The maximum number of subscriptions to include in the response"),
+        ("offset" = Option<i64>, Query, description = "The number of subscriptions to skip"),
+    ),
+    responses(
+        (status = 200, description = "List of subscriptions", body = SubscriptionListResponse)
+    ),
+    tag = "Subscriptions",
+    operation_id = "List all Subscriptions",
+    security(("api_key" = []))
+)]
+pub async fn list_subscriptions() {}
+
+/// Subscriptions - Cancel
+///
+/// Cancels a subscription, either right away or at the end of the current billing period
+#[utoipa::path(
+    post,
+    path = "/subscriptions/{subscription_id}/cancel",
+    params(
+        ("subscription_id" = String, Path, description = "The identifier for the subscription")
+    ),
+    request_body = SubscriptionCancelRequest,
+    responses(
+        (status = 200, description = "Subscription cancelled", body = SubscriptionResponse),
+        (status = 404, description = "Subscription does not exist in our records")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Cancel a Subscription",
+    security(("api_key" = []))
+)]
+pub async fn cancel_subscription() {}
@@ -0,0 +1,176 @@
+/// Webhook Endpoint - Create
+///
+/// Create a webhook endpoint for a Profile, subscribed to the specified event types.
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/business_profile/{profile_id}/webhook_endpoints",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the Merchant Account."),
+        ("profile_id" = String, Path, description = "The unique identifier for the Profile.")
+    ),
+    request_body = WebhookEndpointCreateRequest,
+    responses(
+        (status = 200, description = "Webhook endpoint created", body = WebhookEndpointResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Webhook Endpoint",
+    operation_id = "Create a Webhook Endpoint",
+    security(("admin_api_key" = []))
+)]
+pub async fn create_webhook_endpoint() {}
+
+/// Webhook Endpoint - List
+///
+/// List all webhook endpoints of a Profile.
+#[utoipa::path(
+    get,
+    path = "/account/{account_id}/business_profile/{profile_id}/webhook_endpoints",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the Merchant Account."),
+        ("profile_id" = String, Path, description = "The unique identifier for the Profile.")
+    ),
+    responses(
+        (status = 200, description = "Webhook endpoints retrieved", body = Vec<WebhookEndpointResponse>),
+    ),
+    tag = "Webhook Endpoint",
+    operation_id = "List all Webhook Endpoints of a Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn list_webhook_endpoints() {}
+
+/// Webhook Endpoint - Retrieve
+///
+/// Retrieve a webhook endpoint.
+#[utoipa::path(
+    get,
+    path = "/account/{account_id}/business_profile/{profile_id}/webhook_endpoints/{endpoint_id}",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the Merchant Account."),
+        ("profile_id" = String, Path, description = "The unique identifier for the Profile."),
+        ("endpoint_id" = String, Path, description = "The unique identifier for the Webhook Endpoint.")
+    ),
+    responses(
+        (status = 200, description = "Webhook endpoint retrieved", body = WebhookEndpointResponse),
+        (status = 404, description = "Webhook endpoint not found")
+    ),
+    tag = "Webhook Endpoint",
+    operation_id = "Retrieve a Webhook Endpoint",
+    security(("admin_api_key" = []))
+)]
+pub async fn retrieve_webhook_endpoint() {}
+
+/// Webhook Endpoint - Update
+///
+/// Update the URL, subscribed event types, description or status of a webhook endpoint.
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/business_profile/{profile_id}/webhook_endpoints/{endpoint_id}",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the Merchant Account."),
+        ("profile_id" = String, Path, description = "The unique identifier for the Profile."),
+        ("endpoint_id" = String, Path, description = "The unique identifier for the Webhook Endpoint.")
+    ),
+    request_body = WebhookEndpointUpdateRequest,
+    responses(
+        (status = 200, description = "Webhook endpoint updated", body = WebhookEndpointResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Webhook endpoint not found")
+    ),
+    tag = "Webhook Endpoint",
+    operation_id = "Update a Webhook Endpoint",
+    security(("admin_api_key" = []))
+)]
+pub async fn update_webhook_endpoint() {}
+
+/// Webhook Endpoint - Delete
+///
+/// Delete a webhook endpoint. Webhooks are no longer delivered to the endpoint, including pending
+/// retries.
+#[utoipa::path(
+    delete,
+    path = "/account/{account_id}/business_profile/{profile_id}/webhook_endpoints/{endpoint_id}",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the Merchant Account."),
+        ("profile_id" = String, Path, description = "The unique identifier for the Profile."),
+        ("endpoint_id" = String, Path, description = "The unique identifier for the Webhook Endpoint.")
+    ),
+    responses(
+        (status = 200, description = "Webhook endpoint deleted", body = WebhookEndpointDeleteResponse),
+        (status = 404, description = "Webhook endpoint not found")
+    ),
+    tag = "Webhook Endpoint",
+    operation_id = "Delete a Webhook Endpoint",
+    security(("admin_api_key" = []))
+)]
+pub async fn delete_webhook_endpoint() {}
+
+/// Webhook Endpoint - Rotate Secret
+///
+/// Replace the secret used to sign the webhooks delivered to a webhook endpoint with a newly
+/// generated one. Until the previous secret expires, webhooks carry signatures computed using both
+/// secrets.
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/business_profile/{profile_id}/webhook_endpoints/{endpoint_id}/rotate_secret",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the Merchant Account."),
+        ("profile_id" = String, Path, description = "The unique identifier for the Profile."),
+        ("endpoint_id" = String, Path, description = "The unique identifier for the Webhook Endpoint.")
+    ),
+    request_body = WebhookSecretRotateRequest,
+    responses(
+        (status = 200, description = "Webhook endpoint secret rotated", body = WebhookSecretRotateResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Webhook endpoint not found")
+    ),
+    tag = "Webhook Endpoint",
+    operation_id = "Rotate the Secret of a Webhook Endpoint",
+    security(("admin_api_key" = []))
+)]
+pub async fn rotate_webhook_endpoint_secret() {}
+
+/// Webhook Endpoint - Health
+///
+/// Retrieves the delivery health of a webhook endpoint, computed from the delivery attempts made
+/// to it in the last 24 hours.
+#[utoipa::path(
+    get,
+    path = "/account/{account_id}/business_profile/{profile_id}/webhook_endpoints/{endpoint_id}/health",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the Merchant Account."),
+        ("profile_id" = String, Path, description = "The unique identifier for the Profile."),
+        ("endpoint_id" = String, Path, description = "The unique identifier for the Webhook Endpoint.")
+    ),
+    responses(
+        (status = 200, description = "Webhook endpoint health retrieved", body = WebhookEndpointHealthResponse),
+        (status = 404, description = "Webhook endpoint not found")
+    ),
+    tag = "Webhook Endpoint",
+    operation_id = "Retrieve the Health of a Webhook Endpoint",
+    security(("admin_api_key" = []))
+)]
+pub async fn retrieve_webhook_endpoint_health() {}
+
+/// Webhook Endpoint - Re-enable
+///
+/// Re-enables a webhook endpoint that was suspended after repeated delivery failures. The events
+/// missed by the endpoint since its last successful delivery are delivered again in the
+/// background.
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/business_profile/{profile_id}/webhook_endpoints/{endpoint_id}/reenable",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the Merchant Account."),
+        ("profile_id" = String, Path, description = "The unique identifier for the Profile."),
+        ("endpoint_id" = String, Path, description = "The unique identifier for the Webhook Endpoint.")
+    ),
+    responses(
+        (status = 200, description = "Webhook endpoint re-enabled", body = WebhookEndpointReenableResponse),
+        (status = 404, description = "Webhook endpoint not found"),
+        (status = 400, description = "Webhook endpoint is not suspended")
+    ),
+    tag = "Webhook Endpoint",
+    operation_id = "Re-enable a Suspended Webhook Endpoint",
+    security(("admin_api_key" = []))
+)]
+pub async fn reenable_webhook_endpoint() {}
@@ -15,15 +15,17 @@ pub mod routes {
     };
     use api_models::analytics::{
         api_event::QueryType,
+        exports, report_schedules,
         search::{
             GetGlobalSearchRequest, GetSearchRequest, GetSearchRequestWithIndex, SearchIndex,
         },
         GenerateReportRequest, GetActivePaymentsMetricRequest, GetApiEventFiltersRequest,
-        GetApiEventMetricRequest, GetAuthEventMetricRequest, GetDisputeMetricRequest,
-        GetFrmFilterRequest, GetFrmMetricRequest, GetPaymentFiltersRequest,
-        GetPaymentIntentFiltersRequest, GetPaymentIntentMetricRequest, GetPaymentMetricRequest,
-        GetRefundFilterRequest, GetRefundMetricRequest, GetSdkEventFiltersRequest,
-        GetSdkEventMetricRequest, ReportRequest,
+        GetApiEventMetricRequest, GetAuthEventMetricRequest, GetConnectorEventFiltersRequest,
+        GetConnectorEventMetricRequest, GetDisputeMetricRequest, GetFrmFilterRequest,
+        GetFrmMetricRequest, GetPaymentFiltersRequest, GetPaymentIntentFiltersRequest,
+        GetPaymentIntentMetricRequest, GetPaymentMetricRequest, GetPaymentRecoveryMetricRequest,
+        GetPayoutFilterRequest, GetPayoutMetricRequest, GetRefundFilterRequest,
+        GetRefundMetricRequest, GetSdkEventFiltersRequest, GetSdkEventMetricRequest, ReportRequest,
     };
     use common_enums::EntityType;
     use common_utils::types::TimeRange;
@@ -32,7 +34,10 @@ pub mod routes {
 
     use crate::{
         consts::opensearch::SEARCH_INDEXES,
-        core::{api_locking, errors::user::UserErrors, verification::utils},
+        core::{
+            analytics_reports, api_locking, errors::user::UserErrors, exports as export_core,
+            verification::utils,
+        },
         db::{user::UserInterface, user_role::ListUserRolesByUserIdPayload},
         routes::AppState,
         services::{
@@ -127,6 +132,14 @@ pub mod routes {
                             web::resource("filters/api_events")
                                 .route(web::post().to(get_merchant_api_event_filters)),
                         )
+                        .service(
+                            web::resource("metrics/connector_events")
+                                .route(web::post().to(get_merchant_connector_event_metrics)),
+                        )
+                        .service(
+                            web::resource("filters/connector_events")
+                                .route(web::post().to(get_merchant_connector_event_filters)),
+                        )
                         .service(
                             web::resource("search")
                                 .route(web::post().to(get_global_search_results)),
@@ -188,14 +201,61 @@ pub mod routes {
                                     web::resource("filters/api_events")
                                         .route(web::post().to(get_merchant_api_event_filters)),
                                 )
+                                .service(
+                                    web::resource("metrics/connector_events").route(
+                                        web::post().to(get_merchant_connector_event_metrics),
+                                    ),
+                                )
+                                .service(
+                                    web::resource("filters/connector_events").route(
+                                        web::post().to(get_merchant_connector_event_filters),
+                                    ),
+                                )
                                 .service(
                                     web::resource("metrics/disputes")
                                         .route(web::post().to(get_merchant_dispute_metrics)),
                                 )
+                                .service(
+                                    web::resource("metrics/payment_recovery").route(
+                                        web::post().to(get_merchant_payment_recovery_metrics),
+                                    ),
+                                )
                                 .service(
                                     web::resource("filters/disputes")
                                         .route(web::post().to(get_merchant_dispute_filters)),
                                 )
+                                .service(
+                                    web::resource("metrics/payouts")
+                                        .route(web::post().to(get_merchant_payout_metrics)),
+                                )
+                                .service(
+                                    web::resource("filters/payouts")
+                                        .route(web::post().to(get_merchant_payout_filters)),
+                                )
+                                .service(
+                                    web::resource("report_schedules")
+                                        .route(web::post().to(create_report_schedule))
+                                        .route(web::get().to(list_report_schedules)),
+                                )
+                                .service(
+                                    web::resource("report_schedules/{report_schedule_id}")
+                                        .route(web::get().to(retrieve_report_schedule))
+                                        .route(web::post().to(update_report_schedule))
+                                        .route(web::delete().to(delete_report_schedule)),
+                                )
+                                .service(
+                                    web::resource("exports")
+                                        .route(web::post().to(create_export))
+                                        .route(web::get().to(list_exports)),
+                                )
+                                .service(
+                                    web::resource("exports/{export_id}")
+                                        .route(web::get().to(retrieve_export)),
+                                )
+                                .service(
+                                    web::resource("exports/{export_id}/download")
+                                        .route(web::get().to(download_export)),
+                                )
                                 .service(
                                     web::resource("metrics/sankey")
                                         .route(web::post().to(get_merchant_sankey)),
@@ -226,10 +286,22 @@ pub mod routes {
                                     web::resource("metrics/disputes")
                                         .route(web::post().to(get_org_dispute_metrics)),
                                 )
+                                .service(
+                                    web::resource("metrics/payment_recovery")
+                                        .route(web::post().to(get_org_payment_recovery_metrics)),
+                                )
                                 .service(
                                     web::resource("filters/disputes")
                                         .route(web::post().to(get_org_dispute_filters)),
                                 )
+                                .service(
+                                    web::resource("metrics/payouts")
+                                        .route(web::post().to(get_org_payout_metrics)),
+                                )
+                                .service(
+                                    web::resource("filters/payouts")
+                                        .route(web::post().to(get_org_payout_filters)),
+                                )
                                 .service(
                                     web::resource("report/dispute")
                                         .route(web::post().to(generate_org_dispute_report)),
@@ -272,10 +344,23 @@ pub mod routes {
                                     web::resource("metrics/disputes")
                                         .route(web::post().to(get_profile_dispute_metrics)),
                                 )
+                                .service(
+                                    web::resource("metrics/payment_recovery").route(
+                                        web::post().to(get_profile_payment_recovery_metrics),
+                                    ),
+                                )
                                 .service(
                                     web::resource("filters/disputes")
                                         .route(web::post().to(get_profile_dispute_filters)),
                                 )
+                                .service(
+                                    web::resource("metrics/payouts")
+                                        .route(web::post().to(get_profile_payout_metrics)),
+                                )
+                                .service(
+                                    web::resource("filters/payouts")
+                                        .route(web::post().to(get_profile_payout_filters)),
+                                )
                                 .service(
                                     web::resource("connector_event_logs")
                                         .route(web::get().to(get_profile_connector_events)),
@@ -1770,6 +1855,72 @@ pub mod routes {
         .await
     }
 
+    /// # Panics
+    ///
+    /// Panics if `json_payload` array does not contain one `GetConnectorEventMetricRequest` element.
+    pub async fn get_merchant_connector_event_metrics(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<[GetConnectorEventMetricRequest; 1]>,
+    ) -> impl Responder {
+        // safety: This shouldn't panic owing to the data type
+        #[allow(clippy::expect_used)]
+        let payload = json_payload
+            .into_inner()
+            .to_vec()
+            .pop()
+            .expect("Couldn't get GetConnectorEventMetricRequest");
+        let flow = AnalyticsFlow::GetConnectorEventMetrics;
+        Box::pin(api::server_wrap(
+            flow,
+            state.clone(),
+            &req,
+            payload,
+            |state, auth: AuthenticationData, req, _| async move {
+                analytics::connector_events::get_metrics(
+                    &state.pool,
+                    auth.merchant_account.get_id(),
+                    req,
+                )
+                .await
+                .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    pub async fn get_merchant_connector_event_filters(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<GetConnectorEventFiltersRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetConnectorEventFilters;
+        Box::pin(api::server_wrap(
+            flow,
+            state.clone(),
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req, _| async move {
+                analytics::connector_events::get_filters(
+                    &state.pool,
+                    req,
+                    auth.merchant_account.get_id(),
+                )
+                .await
+                .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
     pub async fn get_profile_connector_events(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
@@ -2250,6 +2401,129 @@ pub mod routes {
         .await
     }
 
+    /// # Panics
+    ///
+    /// Panics if `json_payload` array does not contain one `GetPaymentRecoveryMetricRequest` element.
+    pub async fn get_merchant_payment_recovery_metrics(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<[GetPaymentRecoveryMetricRequest; 1]>,
+    ) -> impl Responder {
+        // safety: This shouldn't panic owing to the data type
+        #[allow(clippy::expect_used)]
+        let payload = json_payload
+            .into_inner()
+            .to_vec()
+            .pop()
+            .expect("Couldn't get GetPaymentRecoveryMetricRequest");
+        let flow = AnalyticsFlow::GetPaymentRecoveryMetrics;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            payload,
+            |state, auth: AuthenticationData, req, _| async move {
+                let org_id = auth.merchant_account.get_org_id();
+                let merchant_id = auth.merchant_account.get_id();
+                let auth: AuthInfo = AuthInfo::MerchantLevel {
+                    org_id: org_id.clone(),
+                    merchant_ids: vec![merchant_id.clone()],
+                };
+                analytics::payment_recovery::get_metrics(&state.pool, &auth, req)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `json_payload` array does not contain one `GetPaymentRecoveryMetricRequest` element.
+    pub async fn get_profile_payment_recovery_metrics(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<[GetPaymentRecoveryMetricRequest; 1]>,
+    ) -> impl Responder {
+        // safety: This shouldn't panic owing to the data type
+        #[allow(clippy::expect_used)]
+        let payload = json_payload
+            .into_inner()
+            .to_vec()
+            .pop()
+            .expect("Couldn't get GetPaymentRecoveryMetricRequest");
+        let flow = AnalyticsFlow::GetPaymentRecoveryMetrics;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            payload,
+            |state, auth: AuthenticationData, req, _| async move {
+                let org_id = auth.merchant_account.get_org_id();
+                let merchant_id = auth.merchant_account.get_id();
+                let profile_id = auth
+                    .profile_id
+                    .ok_or(report!(UserErrors::JwtProfileIdMissing))
+                    .change_context(AnalyticsError::AccessForbiddenError)?;
+                let auth: AuthInfo = AuthInfo::ProfileLevel {
+                    org_id: org_id.clone(),
+                    merchant_id: merchant_id.clone(),
+                    profile_ids: vec![profile_id.clone()],
+                };
+                analytics::payment_recovery::get_metrics(&state.pool, &auth, req)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::ProfileAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `json_payload` array does not contain one `GetPaymentRecoveryMetricRequest` element.
+    pub async fn get_org_payment_recovery_metrics(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<[GetPaymentRecoveryMetricRequest; 1]>,
+    ) -> impl Responder {
+        // safety: This shouldn't panic owing to the data type
+        #[allow(clippy::expect_used)]
+        let payload = json_payload
+            .into_inner()
+            .to_vec()
+            .pop()
+            .expect("Couldn't get GetPaymentRecoveryMetricRequest");
+        let flow = AnalyticsFlow::GetPaymentRecoveryMetrics;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            payload,
+            |state, auth: AuthenticationData, req, _| async move {
+                let org_id = auth.merchant_account.get_org_id();
+                let auth: AuthInfo = AuthInfo::OrgLevel {
+                    org_id: org_id.clone(),
+                };
+                analytics::payment_recovery::get_metrics(&state.pool, &auth, req)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::OrganizationAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
     pub async fn get_merchant_sankey(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
@@ -2345,4 +2619,437 @@ pub mod routes {
         ))
         .await
     }
+
+    /// # Panics
+    ///
+    /// Panics if `json_payload` array does not contain one `GetPayoutMetricRequest` element.
+    pub async fn get_merchant_payout_metrics(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<[GetPayoutMetricRequest; 1]>,
+    ) -> impl Responder {
+        #[allow(clippy::expect_used)]
+        // safety: This shouldn't panic owing to the data type
+        let payload = json_payload
+            .into_inner()
+            .to_vec()
+            .pop()
+            .expect("Couldn't get GetPayoutMetricRequest");
+        let flow = AnalyticsFlow::GetPayoutMetrics;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            payload,
+            |state, auth: AuthenticationData, req, _| async move {
+                let org_id = auth.merchant_account.get_org_id();
+                let merchant_id = auth.merchant_account.get_id();
+                let auth: AuthInfo = AuthInfo::MerchantLevel {
+                    org_id: org_id.clone(),
+                    merchant_ids: vec![merchant_id.clone()],
+                };
+                analytics::payouts::get_metrics(&state.pool, &auth, req)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `json_payload` array does not contain one `GetPayoutMetricRequest` element.
+    pub async fn get_org_payout_metrics(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<[GetPayoutMetricRequest; 1]>,
+    ) -> impl Responder {
+        #[allow(clippy::expect_used)]
+        // safety: This shouldn't panic owing to the data type
+        let payload = json_payload
+            .into_inner()
+            .to_vec()
+            .pop()
+            .expect("Couldn't get GetPayoutMetricRequest");
+        let flow = AnalyticsFlow::GetPayoutMetrics;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            payload,
+            |state, auth: AuthenticationData, req, _| async move {
+                let org_id = auth.merchant_account.get_org_id();
+                let auth: AuthInfo = AuthInfo::OrgLevel {
+                    org_id: org_id.clone(),
+                };
+                analytics::payouts::get_metrics(&state.pool, &auth, req)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::OrganizationAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `json_payload` array does not contain one `GetPayoutMetricRequest` element.
+    pub async fn get_profile_payout_metrics(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<[GetPayoutMetricRequest; 1]>,
+    ) -> impl Responder {
+        #[allow(clippy::expect_used)]
+        // safety: This shouldn't panic owing to the data type
+        let payload = json_payload
+            .into_inner()
+            .to_vec()
+            .pop()
+            .expect("Couldn't get GetPayoutMetricRequest");
+        let flow = AnalyticsFlow::GetPayoutMetrics;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            payload,
+            |state, auth: AuthenticationData, req, _| async move {
+                let org_id = auth.merchant_account.get_org_id();
+                let merchant_id = auth.merchant_account.get_id();
+                let profile_id = auth
+                    .profile_id
+                    .ok_or(report!(UserErrors::JwtProfileIdMissing))
+                    .change_context(AnalyticsError::AccessForbiddenError)?;
+                let auth: AuthInfo = AuthInfo::ProfileLevel {
+                    org_id: org_id.clone(),
+                    merchant_id: merchant_id.clone(),
+                    profile_ids: vec![profile_id.clone()],
+                };
+                analytics::payouts::get_metrics(&state.pool, &auth, req)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::ProfileAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    pub async fn get_merchant_payout_filters(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<GetPayoutFilterRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetPayoutFilters;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req: GetPayoutFilterRequest, _| async move {
+                let org_id = auth.merchant_account.get_org_id();
+                let merchant_id = auth.merchant_account.get_id();
+                let auth: AuthInfo = AuthInfo::MerchantLevel {
+                    org_id: org_id.clone(),
+                    merchant_ids: vec![merchant_id.clone()],
+                };
+                analytics::payouts::get_filters(&state.pool, req, &auth)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    pub async fn get_org_payout_filters(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<GetPayoutFilterRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetPayoutFilters;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req: GetPayoutFilterRequest, _| async move {
+                let org_id = auth.merchant_account.get_org_id();
+                let auth: AuthInfo = AuthInfo::OrgLevel {
+                    org_id: org_id.clone(),
+                };
+                analytics::payouts::get_filters(&state.pool, req, &auth)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::OrganizationAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    pub async fn get_profile_payout_filters(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<GetPayoutFilterRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetPayoutFilters;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req: GetPayoutFilterRequest, _| async move {
+                let org_id = auth.merchant_account.get_org_id();
+                let merchant_id = auth.merchant_account.get_id();
+                let profile_id = auth
+                    .profile_id
+                    .ok_or(report!(UserErrors::JwtProfileIdMissing))
+                    .change_context(AnalyticsError::AccessForbiddenError)?;
+                let auth: AuthInfo = AuthInfo::ProfileLevel {
+                    org_id: org_id.clone(),
+                    merchant_id: merchant_id.clone(),
+                    profile_ids: vec![profile_id.clone()],
+                };
+                analytics::payouts::get_filters(&state.pool, req, &auth)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::ProfileAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    pub async fn create_report_schedule(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<report_schedules::ReportScheduleCreateRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::CreateReportSchedule;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req, _| {
+                analytics_reports::create_report_schedule(
+                    state,
+                    auth.merchant_account,
+                    auth.key_store,
+                    req,
+                )
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantReportRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    pub async fn list_report_schedules(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::ListReportSchedules;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            (),
+            |state, auth: AuthenticationData, _, _| {
+                analytics_reports::list_report_schedules(state, auth.merchant_account)
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantReportRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    pub async fn retrieve_report_schedule(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        path: web::Path<String>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::RetrieveReportSchedule;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            path.into_inner(),
+            |state, auth: AuthenticationData, report_schedule_id, _| {
+                analytics_reports::retrieve_report_schedule(
+                    state,
+                    auth.merchant_account,
+                    report_schedule_id,
+                )
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantReportRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    pub async fn update_report_schedule(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        path: web::Path<String>,
+        json_payload: web::Json<report_schedules::ReportScheduleUpdateRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::UpdateReportSchedule;
+        let report_schedule_id = path.into_inner();
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req, _| {
+                analytics_reports::update_report_schedule(
+                    state,
+                    auth.merchant_account,
+                    report_schedule_id.clone(),
+                    req,
+                )
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantReportRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    pub async fn delete_report_schedule(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        path: web::Path<String>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::DeleteReportSchedule;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            path.into_inner(),
+            |state, auth: AuthenticationData, report_schedule_id, _| {
+                analytics_reports::delete_report_schedule(
+                    state,
+                    auth.merchant_account,
+                    report_schedule_id,
+                )
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantReportRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    pub async fn create_export(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<exports::ExportCreateRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::CreateExport;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req, _| {
+                export_core::create_export(state, auth.merchant_account, auth.key_store, req)
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantReportRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    pub async fn list_exports(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        query: web::Query<exports::ExportListRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::ListExports;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            query.into_inner(),
+            |state, auth: AuthenticationData, req, _| {
+                export_core::list_exports(state, auth.merchant_account, req)
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantReportRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    pub async fn retrieve_export(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        path: web::Path<String>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::RetrieveExport;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            path.into_inner(),
+            |state, auth: AuthenticationData, export_id, _| {
+                export_core::retrieve_export(state, auth.merchant_account, export_id)
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantReportRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    pub async fn download_export(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        path: web::Path<String>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::DownloadExport;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            path.into_inner(),
+            |state, auth: AuthenticationData, export_id, _| {
+                export_core::download_export(state, auth.merchant_account, export_id)
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantReportRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
 }
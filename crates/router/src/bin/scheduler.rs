@@ -306,6 +306,9 @@ impl ProcessTrackerWorkflows<routes::SessionState> for WorkflowRunner {
                             )
                     }
                 }
+                storage::ProcessTrackerRunner::ApiKeyRotationWorkflow => Ok(Box::new(
+                    workflows::api_key_rotation::ApiKeyRotationWorkflow,
+                )),
                 storage::ProcessTrackerRunner::OutgoingWebhookRetryWorkflow => Ok(Box::new(
                     workflows::outgoing_webhook_retry::OutgoingWebhookRetryWorkflow,
                 )),
@@ -329,6 +332,95 @@ impl ProcessTrackerWorkflows<routes::SessionState> for WorkflowRunner {
                 storage::ProcessTrackerRunner::PaymentMethodStatusUpdateWorkflow => Ok(Box::new(
                     workflows::payment_method_status_update::PaymentMethodStatusUpdateWorkflow,
                 )),
+                storage::ProcessTrackerRunner::StorageSchemeMigrationWorkflow => {
+                    #[cfg(feature = "kv_store")]
+                    {
+                        Ok(Box::new(
+                            workflows::storage_scheme_migration::StorageSchemeMigrationWorkflow,
+                        ))
+                    }
+                    #[cfg(not(feature = "kv_store"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run storage scheme migration workflow when kv_store feature is disabled",
+                            )
+                    }
+                }
+                storage::ProcessTrackerRunner::SubscriptionBillingWorkflow => Ok(Box::new(
+                    workflows::subscription_billing::SubscriptionBillingWorkflow,
+                )),
+                storage::ProcessTrackerRunner::PaymentRecoveryWorkflow => Ok(Box::new(
+                    workflows::payment_recovery::PaymentRecoveryWorkflow,
+                )),
+                storage::ProcessTrackerRunner::PayoutBatchWorkflow => {
+                    #[cfg(feature = "payouts")]
+                    {
+                        Ok(Box::new(workflows::payout_batch::PayoutBatchWorkflow))
+                    }
+                    #[cfg(not(feature = "payouts"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run payout batch workflow when payouts feature is disabled",
+                            )
+                    }
+                }
+                storage::ProcessTrackerRunner::RefundBatchWorkflow => {
+                    Ok(Box::new(workflows::refund_batch::RefundBatchWorkflow))
+                }
+                storage::ProcessTrackerRunner::DisputeDeadlineWorkflow => Ok(Box::new(
+                    workflows::dispute_deadline::DisputeDeadlineWorkflow,
+                )),
+                storage::ProcessTrackerRunner::AccountUpdaterWorkflow => {
+                    Ok(Box::new(workflows::account_updater::AccountUpdaterWorkflow))
+                }
+                storage::ProcessTrackerRunner::InvoiceReminderWorkflow => Ok(Box::new(
+                    workflows::invoice_reminder::InvoiceReminderWorkflow,
+                )),
+                storage::ProcessTrackerRunner::AnalyticsReportWorkflow => {
+                    #[cfg(feature = "olap")]
+                    {
+                        Ok(Box::new(
+                            workflows::analytics_report::AnalyticsReportWorkflow,
+                        ))
+                    }
+                    #[cfg(not(feature = "olap"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                            "Cannot run analytics report workflow when olap feature is disabled",
+                        )
+                    }
+                }
+                storage::ProcessTrackerRunner::SuccessRateAlertWorkflow => {
+                    #[cfg(feature = "olap")]
+                    {
+                        Ok(Box::new(
+                            workflows::success_rate_alert::SuccessRateAlertWorkflow,
+                        ))
+                    }
+                    #[cfg(not(feature = "olap"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                            "Cannot run success rate alert workflow when olap feature is disabled",
+                        )
+                    }
+                }
+                storage::ProcessTrackerRunner::ExportJobWorkflow => {
+                    #[cfg(feature = "olap")]
+                    {
+                        Ok(Box::new(workflows::export_job::ExportJobWorkflow))
+                    }
+                    #[cfg(not(feature = "olap"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run export job workflow when olap feature is disabled",
+                            )
+                    }
+                }
             }
         };
 
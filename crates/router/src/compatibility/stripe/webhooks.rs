@@ -6,18 +6,22 @@ use api_models::{
 };
 #[cfg(feature = "payouts")]
 use common_utils::pii::{self, Email};
-use common_utils::{crypto::SignMessage, date_time, ext_traits::Encode};
+use common_utils::{date_time, ext_traits::Encode};
 use error_stack::ResultExt;
 use router_env::logger;
 use serde::Serialize;
 
 use super::{
-    payment_intents::types::StripePaymentIntentResponse, refunds::types::StripeRefundResponse,
+    customers::types::PaymentMethodData, payment_intents::types::StripePaymentIntentResponse,
+    refunds::types::StripeRefundResponse,
 };
 use crate::{
     core::{
         errors,
-        webhooks::types::{OutgoingWebhookPayloadWithSignature, OutgoingWebhookType},
+        webhooks::types::{
+            get_timestamped_signature, OutgoingWebhookPayloadWithSignature, OutgoingWebhookType,
+            WebhookSignatureAlgorithm, WebhookSigningSecrets,
+        },
     },
     headers,
     services::request::Maskable,
@@ -37,11 +41,10 @@ pub struct StripeOutgoingWebhook {
 impl OutgoingWebhookType for StripeOutgoingWebhook {
     fn get_outgoing_webhooks_signature(
         &self,
-        payment_response_hash_key: Option<impl AsRef<[u8]>>,
+        signing_secrets: &WebhookSigningSecrets,
     ) -> errors::CustomResult<OutgoingWebhookPayloadWithSignature, errors::WebhooksFlowError> {
-        let timestamp = self.created;
-
-        let payment_response_hash_key = payment_response_hash_key
+        signing_secrets
+            .get_current_secret()
             .ok_or(errors::WebhooksFlowError::MerchantConfigNotFound)
             .attach_printable("For stripe compatibility payment_response_hash_key is mandatory")?;
 
@@ -50,30 +53,26 @@ impl OutgoingWebhookType for StripeOutgoingWebhook {
             .change_context(errors::WebhooksFlowError::OutgoingWebhookEncodingFailed)
             .attach_printable("failed encoding outgoing webhook payload")?;
 
-        let new_signature_payload = format!("{timestamp}.{webhook_signature_payload}");
-        let v1 = hex::encode(
-            common_utils::crypto::HmacSha256::sign_message(
-                &common_utils::crypto::HmacSha256,
-                payment_response_hash_key.as_ref(),
-                new_signature_payload.as_bytes(),
-            )
-            .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
-            .attach_printable("Failed to sign the message")?,
-        );
-
-        let t = timestamp;
-        let signature = Some(format!("t={t},v1={v1}"));
+        let timestamped_signature = get_timestamped_signature(
+            &webhook_signature_payload,
+            signing_secrets,
+            WebhookSignatureAlgorithm::HmacSha256,
+        )?;
 
         Ok(OutgoingWebhookPayloadWithSignature {
             payload: webhook_signature_payload.into(),
-            signature,
+            signature: None,
+            timestamped_signature,
         })
     }
 
-    fn add_webhook_header(header: &mut Vec<(String, Maskable<String>)>, signature: String) {
+    fn add_timestamped_webhook_header(
+        header: &mut Vec<(String, Maskable<String>)>,
+        timestamped_signature: String,
+    ) {
         header.push((
             headers::STRIPE_COMPATIBLE_WEBHOOK_SIGNATURE.to_string(),
-            signature.into(),
+            timestamped_signature.into(),
         ))
     }
 }
@@ -87,6 +86,9 @@ pub enum StripeWebhookObject {
     Mandate(StripeMandateResponse),
     #[cfg(feature = "payouts")]
     Payout(StripePayoutResponse),
+    Subscription(StripeSubscriptionResponse),
+    PaymentMethod(PaymentMethodData),
+    SuccessRateAlert(Box<api_models::analytics::success_rate_alerts::SuccessRateAlert>),
 }
 
 #[derive(Serialize, Debug)]
@@ -107,6 +109,22 @@ pub struct StripeMandateResponse {
     pub payment_method: String,
 }
 
+#[derive(Serialize, Debug)]
+pub struct StripeSubscriptionResponse {
+    pub id: String,
+    pub customer: common_utils::id_type::CustomerId,
+    pub plan: String,
+    pub status: StripeSubscriptionStatus,
+    pub current_period_start: i64,
+    pub current_period_end: i64,
+    pub trial_end: Option<i64>,
+    pub cancel_at_period_end: bool,
+    pub canceled_at: Option<i64>,
+    pub latest_invoice: Option<String>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub created: i64,
+}
+
 #[cfg(feature = "payouts")]
 #[derive(Clone, Serialize, Debug)]
 pub struct StripePayoutResponse {
@@ -193,6 +211,45 @@ impl From<payout_models::PayoutCreateResponse> for StripePayoutResponse {
     }
 }
 
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeSubscriptionStatus {
+    Trialing,
+    Active,
+    PastDue,
+    Canceled,
+}
+
+impl From<common_enums::SubscriptionStatus> for StripeSubscriptionStatus {
+    fn from(status: common_enums::SubscriptionStatus) -> Self {
+        match status {
+            common_enums::SubscriptionStatus::Trialing => Self::Trialing,
+            common_enums::SubscriptionStatus::Active => Self::Active,
+            common_enums::SubscriptionStatus::PastDue => Self::PastDue,
+            common_enums::SubscriptionStatus::Cancelled => Self::Canceled,
+        }
+    }
+}
+
+impl From<api_models::subscriptions::SubscriptionResponse> for StripeSubscriptionResponse {
+    fn from(res: api_models::subscriptions::SubscriptionResponse) -> Self {
+        Self {
+            id: res.subscription_id,
+            customer: res.customer_id,
+            plan: res.plan_id,
+            status: StripeSubscriptionStatus::from(res.status),
+            current_period_start: res.current_period_start.assume_utc().unix_timestamp(),
+            current_period_end: res.current_period_end.assume_utc().unix_timestamp(),
+            trial_end: res.trial_end.map(|t| t.assume_utc().unix_timestamp()),
+            cancel_at_period_end: res.cancel_at_period_end,
+            canceled_at: res.cancelled_at.map(|t| t.assume_utc().unix_timestamp()),
+            latest_invoice: res.latest_payment_id,
+            metadata: res.metadata,
+            created: res.created_at.assume_utc().unix_timestamp(),
+        }
+    }
+}
+
 #[derive(Serialize, Debug)]
 #[serde(rename_all = "snake_case")]
 pub enum StripeMandateStatus {
@@ -280,6 +337,7 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
         api_models::enums::EventType::DisputeChallenged => "dispute.challenged",
         api_models::enums::EventType::DisputeWon => "dispute.won",
         api_models::enums::EventType::DisputeLost => "dispute.lost",
+        api_models::enums::EventType::DisputeDeadlineApproaching => "dispute.deadline_approaching",
         api_models::enums::EventType::MandateActive => "mandate.active",
         api_models::enums::EventType::MandateRevoked => "mandate.revoked",
 
@@ -296,6 +354,17 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
         api_models::enums::EventType::PayoutProcessing => "payout.created",
         api_models::enums::EventType::PayoutExpired => "payout.failed",
         api_models::enums::EventType::PayoutReversed => "payout.reconciliation_completed",
+        api_models::enums::EventType::SubscriptionCreated => "customer.subscription.created",
+        api_models::enums::EventType::SubscriptionRenewed => "invoice.paid",
+        api_models::enums::EventType::SubscriptionPaymentFailed => "invoice.payment_failed",
+        api_models::enums::EventType::SubscriptionCancelled => "customer.subscription.deleted",
+        api_models::enums::EventType::PaymentMethodUpdated => "payment_method.updated",
+
+        // stripe does not provide alerts on the success rate of payments
+        api_models::enums::EventType::PaymentSuccessRateDropped => "payment_success_rate.dropped",
+        api_models::enums::EventType::PaymentSuccessRateRecovered => {
+            "payment_success_rate.recovered"
+        }
     }
 }
 
@@ -338,6 +407,15 @@ impl From<api::OutgoingWebhookContent> for StripeWebhookObject {
             }
             #[cfg(feature = "payouts")]
             api::OutgoingWebhookContent::PayoutDetails(payout) => Self::Payout((*payout).into()),
+            api::OutgoingWebhookContent::SubscriptionDetails(subscription) => {
+                Self::Subscription((*subscription).into())
+            }
+            api::OutgoingWebhookContent::PaymentMethodDetails(payment_method) => {
+                Self::PaymentMethod((*payment_method).into())
+            }
+            api::OutgoingWebhookContent::SuccessRateAlertDetails(alert) => {
+                Self::SuccessRateAlert(alert)
+            }
         }
     }
 }
@@ -128,6 +128,38 @@ impl Default for super::settings::DrainerSettings {
     }
 }
 
+#[cfg(feature = "kv_store")]
+impl Default for super::settings::StorageSchemeMigrationSettings {
+    fn default() -> Self {
+        Self {
+            poll_interval_in_secs: 30,
+            max_drain_attempts: 60,
+            default_verification_sample_size: 20,
+            max_verification_sample_size: 200,
+        }
+    }
+}
+
+impl Default for super::settings::AccountUpdaterSettings {
+    fn default() -> Self {
+        Self {
+            expiry_window_in_days: 60,
+            run_interval_in_hours: 24,
+            customers_per_run: 100,
+            source: Default::default(),
+        }
+    }
+}
+
+impl Default for super::settings::InvoiceSettings {
+    fn default() -> Self {
+        Self {
+            reminder_days_before_due_date: vec![7, 3, 1],
+            payment_window_after_due_date_in_days: 30,
+        }
+    }
+}
+
 #[cfg(feature = "kv_store")]
 impl Default for super::settings::KvConfig {
     fn default() -> Self {
@@ -150,6 +182,15 @@ impl Default for super::settings::ApiKeys {
             #[cfg(feature = "email")]
             expiry_reminder_days: vec![7, 3, 1],
 
+            // Specifies the number of seconds a rotated API key remains valid (1 day)
+            rotation_grace_period_in_secs: 86400,
+
+            // Specifies the maximum grace period that can be requested while rotating (30 days)
+            max_rotation_grace_period_in_secs: 2592000,
+
+            // Specifies the minimum number of seconds between two last used time updates
+            last_used_update_interval_in_secs: 60,
+
             // Hex-encoded key used for calculating checksum for partial auth
             #[cfg(feature = "partial-auth")]
             checksum_auth_key: String::new().into(),
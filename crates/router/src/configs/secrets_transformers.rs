@@ -114,6 +114,10 @@ impl SecretsHandler for settings::ApiKeys {
         #[cfg(feature = "email")]
         let expiry_reminder_days = api_keys.expiry_reminder_days.clone();
 
+        let rotation_grace_period_in_secs = api_keys.rotation_grace_period_in_secs;
+        let max_rotation_grace_period_in_secs = api_keys.max_rotation_grace_period_in_secs;
+        let last_used_update_interval_in_secs = api_keys.last_used_update_interval_in_secs;
+
         #[cfg(feature = "partial-auth")]
         let enable_partial_auth = api_keys.enable_partial_auth;
 
@@ -136,6 +140,9 @@ impl SecretsHandler for settings::ApiKeys {
             hash_key,
             #[cfg(feature = "email")]
             expiry_reminder_days,
+            rotation_grace_period_in_secs,
+            max_rotation_grace_period_in_secs,
+            last_used_update_interval_in_secs,
 
             #[cfg(feature = "partial-auth")]
             checksum_auth_key,
@@ -472,6 +479,8 @@ pub(crate) async fn fetch_raw_secrets(
         log: conf.log,
         #[cfg(feature = "kv_store")]
         drainer: conf.drainer,
+        #[cfg(feature = "kv_store")]
+        storage_scheme_migration: conf.storage_scheme_migration,
         encryption_management: conf.encryption_management,
         secrets_management: conf.secrets_management,
         proxy: conf.proxy,
@@ -493,6 +502,8 @@ pub(crate) async fn fetch_raw_secrets(
         bank_config: conf.bank_config,
         api_keys,
         file_storage: conf.file_storage,
+        account_updater: conf.account_updater,
+        invoices: conf.invoices,
         tokenization: conf.tokenization,
         connector_customer: conf.connector_customer,
         #[cfg(feature = "dummy_connector")]
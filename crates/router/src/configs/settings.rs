@@ -12,6 +12,7 @@ use error_stack::ResultExt;
 #[cfg(feature = "email")]
 use external_services::email::EmailSettings;
 use external_services::{
+    account_updater::AccountUpdaterConfig,
     file_storage::FileStorageConfig,
     grpc_client::GrpcClientSettings,
     managers::{
@@ -69,12 +70,16 @@ pub struct Settings<S: SecretState> {
     pub scheduler: Option<SchedulerSettings>,
     #[cfg(feature = "kv_store")]
     pub drainer: DrainerSettings,
+    #[cfg(feature = "kv_store")]
+    pub storage_scheme_migration: StorageSchemeMigrationSettings,
     pub jwekey: SecretStateContainer<Jwekey, S>,
     pub webhooks: WebhooksSettings,
     pub pm_filters: ConnectorFilters,
     pub bank_config: BankRedirectConfig,
     pub api_keys: SecretStateContainer<ApiKeys, S>,
     pub file_storage: FileStorageConfig,
+    pub account_updater: AccountUpdaterSettings,
+    pub invoices: InvoiceSettings,
     pub encryption_management: EncryptionManagementConfig,
     pub secrets_management: SecretsManagementConfig,
     pub tokenization: TokenizationConfig,
@@ -375,7 +380,13 @@ pub struct DummyConnector {
     pub refund_tolerance: u64,
     pub refund_retrieve_duration: u64,
     pub refund_retrieve_tolerance: u64,
+    pub payout_ttl: i64,
+    pub payout_duration: u64,
+    pub payout_tolerance: u64,
+    pub payout_retrieve_duration: u64,
+    pub payout_retrieve_tolerance: u64,
     pub authorize_ttl: i64,
+    pub scenario_ttl: i64,
     pub assets_base_url: String,
     pub default_return_url: String,
     pub slack_invite_url: String,
@@ -672,11 +683,50 @@ pub struct DrainerSettings {
     pub loop_interval: u32,     // in milliseconds
 }
 
+#[cfg(feature = "kv_store")]
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct StorageSchemeMigrationSettings {
+    // Interval (in seconds) between two consecutive checks of a migration in progress
+    pub poll_interval_in_secs: u32,
+    // Number of times the drainer streams are checked before a migration is marked as failed
+    pub max_drain_attempts: u32,
+    // Number of KV entries verified against the database, unless overridden in the request
+    pub default_verification_sample_size: u16,
+    // Maximum number of KV entries that can be requested for verification
+    pub max_verification_sample_size: u16,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct AccountUpdaterSettings {
+    // Saved cards expiring within this many days are checked for updates
+    pub expiry_window_in_days: u16,
+    // Interval (in hours) between two consecutive checks of the saved cards of a merchant
+    pub run_interval_in_hours: u16,
+    // Number of customers whose saved cards are checked in a single run of the workflow
+    pub customers_per_run: u16,
+    // Source from which the updates to saved cards are obtained
+    pub source: AccountUpdaterConfig,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct InvoiceSettings {
+    // Number of days before the due date at which the customer is reminded of an unpaid invoice
+    pub reminder_days_before_due_date: Vec<u16>,
+    // Number of days after the due date for which an overdue invoice can still be paid
+    pub payment_window_after_due_date_in_days: u16,
+}
+
 #[derive(Debug, Clone, Default, Deserialize)]
 #[serde(default)]
 pub struct WebhooksSettings {
     pub outgoing_enabled: bool,
     pub ignore_error: WebhookIgnoreErrorSettings,
+    // Number of consecutive failed deliveries after which a webhook endpoint is suspended,
+    // suspension is disabled if set to 0
+    pub endpoint_suspension_failure_threshold: u32,
 }
 
 #[derive(Debug, Clone, Deserialize, Default)]
@@ -697,6 +747,16 @@ pub struct ApiKeys {
     #[cfg(feature = "email")]
     pub expiry_reminder_days: Vec<u8>,
 
+    // Specifies the number of seconds a rotated API key remains valid, unless overridden in the
+    // rotation request
+    pub rotation_grace_period_in_secs: u32,
+
+    // Specifies the maximum grace period (in seconds) that can be requested while rotating an API key
+    pub max_rotation_grace_period_in_secs: u32,
+
+    // Specifies the minimum number of seconds between two updates of an API key's last used time
+    pub last_used_update_interval_in_secs: u32,
+
     #[cfg(feature = "partial-auth")]
     pub checksum_auth_context: Secret<String>,
 
@@ -845,12 +905,17 @@ impl Settings<SecuredSecret> {
             .transpose()?;
         #[cfg(feature = "kv_store")]
         self.drainer.validate()?;
+        #[cfg(feature = "kv_store")]
+        self.storage_scheme_migration.validate()?;
         self.api_keys.get_inner().validate()?;
 
         self.file_storage
             .validate()
             .map_err(|err| ApplicationError::InvalidConfigurationValueError(err.to_string()))?;
 
+        self.account_updater.validate()?;
+        self.invoices.validate()?;
+
         self.lock_settings.validate()?;
         self.events.validate()?;
 
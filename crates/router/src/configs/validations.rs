@@ -132,6 +132,63 @@ impl super::settings::DrainerSettings {
     }
 }
 
+#[cfg(feature = "kv_store")]
+impl super::settings::StorageSchemeMigrationSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.poll_interval_in_secs == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "storage scheme migration poll interval must be greater than zero".into(),
+            ))
+        })?;
+
+        when(
+            self.default_verification_sample_size > self.max_verification_sample_size,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "storage scheme migration default verification sample size must not exceed the maximum sample size"
+                        .into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::AccountUpdaterSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.run_interval_in_hours == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "account updater run interval must be greater than zero".into(),
+            ))
+        })?;
+
+        when(self.customers_per_run == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "account updater customers per run must be greater than zero".into(),
+            ))
+        })?;
+
+        self.source
+            .validate()
+            .map_err(|err| ApplicationError::InvalidConfigurationValueError(err.to_string()))
+    }
+}
+
+impl super::settings::InvoiceSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.reminder_days_before_due_date.contains(&0), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "invoice reminder days before due date must be greater than zero".into(),
+            ))
+        })
+    }
+}
+
 impl super::settings::ApiKeys {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
@@ -149,6 +206,16 @@ impl super::settings::ApiKeys {
             ))
         })?;
 
+        when(
+            self.rotation_grace_period_in_secs > self.max_rotation_grace_period_in_secs,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "API key rotation grace period must not exceed the maximum rotation grace period"
+                        .into(),
+                ))
+            },
+        )?;
+
         Ok(())
     }
 }
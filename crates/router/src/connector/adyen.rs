@@ -2236,6 +2236,12 @@ impl api::FileUpload for Adyen {
                     })?
                 }
             }
+            api::FilePurpose::PayoutBatch | api::FilePurpose::RefundBatch => {
+                Err(errors::ConnectorError::NotSupported {
+                    message: "Batch files".to_string(),
+                    connector: "Adyen",
+                })?
+            }
         }
         Ok(())
     }
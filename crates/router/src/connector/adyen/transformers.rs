@@ -166,6 +166,29 @@ pub struct AdyenPaymentRequest<'a> {
     channel: Option<Channel>,
     metadata: Option<pii::SecretSerdeValue>,
     merchant_order_reference: Option<String>,
+    splits: Option<Vec<AdyenSplitData>>,
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenSplitData {
+    amount: Option<Amount>,
+    #[serde(rename = "type")]
+    split_type: AdyenSplitType,
+    account: Option<String>,
+    reference: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum AdyenSplitType {
+    /// The share of a sub-merchant, booked to their balance account
+    BalanceAccount,
+    /// The share retained by the platform, booked to its liable balance account
+    Commission,
+    /// The fees charged for the payment, booked to the given balance account or to the liable
+    /// balance account of the platform
+    PaymentFee,
 }
 
 #[derive(Debug, Serialize)]
@@ -1214,6 +1237,8 @@ pub struct AdyenRefundRequest {
     amount: Amount,
     merchant_refund_reason: Option<String>,
     reference: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    splits: Option<Vec<AdyenSplitData>>,
 }
 
 #[derive(Default, Debug, Clone, Serialize, Deserialize)]
@@ -1736,6 +1761,58 @@ fn get_channel_type(pm_type: &Option<storage_enums::PaymentMethodType>) -> Optio
     })
 }
 
+fn get_adyen_split_request(request: &types::PaymentsAuthorizeData) -> Option<Vec<AdyenSplitData>> {
+    request
+        .split_payments
+        .as_ref()
+        .map(|split_payments| get_adyen_splits(split_payments, request.currency))
+}
+
+fn get_adyen_splits(
+    split_payments: &types::SplitPaymentsData,
+    currency: storage_enums::Currency,
+) -> Vec<AdyenSplitData> {
+    let mut splits: Vec<AdyenSplitData> = split_payments
+        .recipients
+        .iter()
+        .map(|recipient| AdyenSplitData {
+            amount: Some(Amount {
+                currency,
+                value: recipient.amount,
+            }),
+            split_type: AdyenSplitType::BalanceAccount,
+            account: Some(recipient.recipient_account_id.clone()),
+            reference: recipient.split_id.clone(),
+        })
+        .collect();
+
+    if split_payments.platform_amount > MinorUnit::zero() {
+        splits.push(AdyenSplitData {
+            amount: Some(Amount {
+                currency,
+                value: split_payments.platform_amount,
+            }),
+            split_type: AdyenSplitType::Commission,
+            account: None,
+            reference: "commission".to_string(),
+        });
+    }
+
+    // When no account is given, the fees are booked to the liable balance account
+    let fee_bearer = split_payments
+        .recipients
+        .iter()
+        .find(|recipient| recipient.is_fee_bearer);
+    splits.push(AdyenSplitData {
+        amount: None,
+        split_type: AdyenSplitType::PaymentFee,
+        account: fee_bearer.map(|recipient| recipient.recipient_account_id.clone()),
+        reference: "payment_fee".to_string(),
+    });
+
+    splits
+}
+
 fn get_amount_data(item: &AdyenRouterData<&types::PaymentsAuthorizeRouterData>) -> Amount {
     Amount {
         currency: item.router_data.request.currency,
@@ -2670,6 +2747,7 @@ impl<'a>
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
             metadata: item.router_data.request.metadata.clone().map(Into::into),
             merchant_order_reference: item.router_data.request.merchant_order_reference_id.clone(),
+            splits: get_adyen_split_request(&item.router_data.request),
         })
     }
 }
@@ -2733,6 +2811,7 @@ impl<'a>
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
             metadata: item.router_data.request.metadata.clone().map(Into::into),
             merchant_order_reference: item.router_data.request.merchant_order_reference_id.clone(),
+            splits: get_adyen_split_request(&item.router_data.request),
         })
     }
 }
@@ -2788,6 +2867,7 @@ impl<'a>
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
             metadata: item.router_data.request.metadata.clone().map(Into::into),
             merchant_order_reference: item.router_data.request.merchant_order_reference_id.clone(),
+            splits: get_adyen_split_request(&item.router_data.request),
         };
         Ok(request)
     }
@@ -2847,6 +2927,7 @@ impl<'a>
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
             metadata: item.router_data.request.metadata.clone().map(Into::into),
             merchant_order_reference: item.router_data.request.merchant_order_reference_id.clone(),
+            splits: get_adyen_split_request(&item.router_data.request),
         };
         Ok(request)
     }
@@ -2898,6 +2979,7 @@ impl<'a>
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
             metadata: item.router_data.request.metadata.clone().map(Into::into),
             merchant_order_reference: item.router_data.request.merchant_order_reference_id.clone(),
+            splits: get_adyen_split_request(&item.router_data.request),
         };
         Ok(request)
     }
@@ -2949,6 +3031,7 @@ impl<'a>
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
             metadata: item.router_data.request.metadata.clone().map(Into::into),
             merchant_order_reference: item.router_data.request.merchant_order_reference_id.clone(),
+            splits: get_adyen_split_request(&item.router_data.request),
         };
         Ok(request)
     }
@@ -3012,6 +3095,7 @@ impl<'a>
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
             metadata: item.router_data.request.metadata.clone().map(Into::into),
             merchant_order_reference: item.router_data.request.merchant_order_reference_id.clone(),
+            splits: get_adyen_split_request(&item.router_data.request),
         })
     }
 }
@@ -3111,6 +3195,7 @@ impl<'a>
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
             metadata: item.router_data.request.metadata.clone().map(Into::into),
             merchant_order_reference: item.router_data.request.merchant_order_reference_id.clone(),
+            splits: get_adyen_split_request(&item.router_data.request),
         })
     }
 }
@@ -3186,6 +3271,7 @@ impl<'a>
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
             metadata: item.router_data.request.metadata.clone().map(Into::into),
             merchant_order_reference: item.router_data.request.merchant_order_reference_id.clone(),
+            splits: get_adyen_split_request(&item.router_data.request),
         })
     }
 }
@@ -3245,6 +3331,7 @@ impl<'a>
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
             metadata: item.router_data.request.metadata.clone().map(Into::into),
             merchant_order_reference: item.router_data.request.merchant_order_reference_id.clone(),
+            splits: get_adyen_split_request(&item.router_data.request),
         })
     }
 }
@@ -4096,6 +4183,15 @@ impl<F> TryFrom<&AdyenRouterData<&types::RefundsRouterData<F>>> for AdyenRefundR
             },
             merchant_refund_reason: item.router_data.request.reason.clone(),
             reference: item.router_data.request.refund_id.clone(),
+            // The shares of the recipients are reversed from their balance accounts
+            splits: item
+                .router_data
+                .request
+                .split_refunds
+                .as_ref()
+                .map(|split_refunds| {
+                    get_adyen_splits(split_refunds, item.router_data.request.currency)
+                }),
         })
     }
 }
@@ -963,6 +963,12 @@ impl api::FileUpload for Checkout {
                     })?
                 }
             }
+            api::FilePurpose::PayoutBatch | api::FilePurpose::RefundBatch => {
+                Err(errors::ConnectorError::NotSupported {
+                    message: "Batch files".to_string(),
+                    connector: "Checkout",
+                })?
+            }
         }
         Ok(())
     }
@@ -1,6 +1,7 @@
 use diesel_models::enums::Currency;
 use masking::Secret;
 use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
 use url::Url;
 
 use crate::{
@@ -65,6 +66,7 @@ pub struct DummyConnectorPaymentsRequest<const T: u8> {
     payment_method_data: PaymentMethodData,
     return_url: Option<String>,
     connector: DummyConnectors,
+    capture_method: Option<enums::CaptureMethod>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
@@ -175,6 +177,7 @@ impl<const T: u8> TryFrom<&types::PaymentsAuthorizeRouterData>
             payment_method_data: payment_method_data?,
             return_url: item.request.router_return_url.clone(),
             connector: Into::<DummyConnectors>::into(T),
+            capture_method: item.request.capture_method,
         })
     }
 }
@@ -204,6 +207,8 @@ pub enum DummyConnectorPaymentStatus {
     Failed,
     #[default]
     Processing,
+    Authorized,
+    Pending,
 }
 
 impl From<DummyConnectorPaymentStatus> for enums::AttemptStatus {
@@ -212,6 +217,8 @@ impl From<DummyConnectorPaymentStatus> for enums::AttemptStatus {
             DummyConnectorPaymentStatus::Succeeded => Self::Charged,
             DummyConnectorPaymentStatus::Failed => Self::Failure,
             DummyConnectorPaymentStatus::Processing => Self::AuthenticationPending,
+            DummyConnectorPaymentStatus::Authorized => Self::Authorized,
+            DummyConnectorPaymentStatus::Pending => Self::Pending,
         }
     }
 }
@@ -221,10 +228,26 @@ pub struct PaymentsResponse {
     status: DummyConnectorPaymentStatus,
     id: String,
     amount: i64,
+    amount_captured: Option<i64>,
     currency: Currency,
     created: String,
     payment_method_type: PaymentMethodType,
     next_action: Option<DummyConnectorNextAction>,
+    error_code: Option<String>,
+    error_message: Option<String>,
+}
+
+impl PaymentsResponse {
+    fn get_attempt_status(&self) -> enums::AttemptStatus {
+        match (&self.status, self.amount_captured) {
+            (DummyConnectorPaymentStatus::Succeeded, Some(amount_captured))
+                if amount_captured < self.amount =>
+            {
+                enums::AttemptStatus::PartialCharged
+            }
+            (status, _) => enums::AttemptStatus::from(status.clone()),
+        }
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
@@ -242,6 +265,7 @@ impl<F, T> TryFrom<types::ResponseRouterData<F, PaymentsResponse, T, types::Paym
     fn try_from(
         item: types::ResponseRouterData<F, PaymentsResponse, T, types::PaymentsResponseData>,
     ) -> Result<Self, Self::Error> {
+        let status = item.response.get_attempt_status();
         let redirection_data = item
             .response
             .next_action
@@ -249,9 +273,21 @@ impl<F, T> TryFrom<types::ResponseRouterData<F, PaymentsResponse, T, types::Paym
             .map(|redirection_url| {
                 services::RedirectForm::from((redirection_url, services::Method::Get))
             });
-        Ok(Self {
-            status: enums::AttemptStatus::from(item.response.status),
-            response: Ok(types::PaymentsResponseData::TransactionResponse {
+        let response = match item.response.error_code {
+            // Declines are responded with the failed payment, along with the decline code
+            Some(code) if status == enums::AttemptStatus::Failure => Err(types::ErrorResponse {
+                status_code: item.http_code,
+                message: item
+                    .response
+                    .error_message
+                    .clone()
+                    .unwrap_or_else(|| code.clone()),
+                reason: item.response.error_message,
+                code,
+                attempt_status: Some(status),
+                connector_transaction_id: Some(item.response.id),
+            }),
+            _ => Ok(types::PaymentsResponseData::TransactionResponse {
                 resource_id: types::ResponseId::ConnectorTransactionId(item.response.id),
                 redirection_data: Box::new(redirection_data),
                 mandate_reference: Box::new(None),
@@ -261,6 +297,10 @@ impl<F, T> TryFrom<types::ResponseRouterData<F, PaymentsResponse, T, types::Paym
                 incremental_authorization_allowed: None,
                 charge_id: None,
             }),
+        };
+        Ok(Self {
+            status,
+            response,
             ..item.data
         })
     }
@@ -280,6 +320,20 @@ impl DummyConnectorNextAction {
     }
 }
 
+#[derive(Debug, Serialize)]
+pub struct DummyConnectorCaptureRequest {
+    pub amount: i64,
+}
+
+impl TryFrom<&types::PaymentsCaptureRouterData> for DummyConnectorCaptureRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsCaptureRouterData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount: item.request.amount_to_capture,
+        })
+    }
+}
+
 // REFUND :
 // Type definition for RefundRequest
 #[derive(Default, Debug, Serialize)]
@@ -363,6 +417,83 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, RefundResponse>>
     }
 }
 
+// PAYOUTS :
+#[cfg(feature = "payouts")]
+#[derive(Debug, Serialize)]
+pub struct DummyConnectorPayoutRequest<const T: u8> {
+    amount: i64,
+    currency: Currency,
+    connector: DummyConnectors,
+}
+
+#[cfg(feature = "payouts")]
+impl<const T: u8> TryFrom<&types::PayoutsRouterData<api::PoFulfill>>
+    for DummyConnectorPayoutRequest<T>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PayoutsRouterData<api::PoFulfill>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount: item.request.amount,
+            currency: item.request.destination_currency,
+            connector: Into::<DummyConnectors>::into(T),
+        })
+    }
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum DummyConnectorPayoutStatus {
+    Succeeded,
+    Failed,
+    Processing,
+}
+
+#[cfg(feature = "payouts")]
+impl From<DummyConnectorPayoutStatus> for enums::PayoutStatus {
+    fn from(item: DummyConnectorPayoutStatus) -> Self {
+        match item {
+            DummyConnectorPayoutStatus::Succeeded => Self::Success,
+            DummyConnectorPayoutStatus::Failed => Self::Failed,
+            DummyConnectorPayoutStatus::Processing => Self::Pending,
+        }
+    }
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DummyConnectorPayoutResponse {
+    id: String,
+    status: DummyConnectorPayoutStatus,
+    amount: i64,
+    currency: Currency,
+    created: String,
+    error_code: Option<String>,
+    error_message: Option<String>,
+}
+
+#[cfg(feature = "payouts")]
+impl<F> TryFrom<types::PayoutsResponseRouterData<F, DummyConnectorPayoutResponse>>
+    for types::PayoutsRouterData<F>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::PayoutsResponseRouterData<F, DummyConnectorPayoutResponse>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::PayoutsResponseData {
+                status: Some(enums::PayoutStatus::from(item.response.status)),
+                connector_payout_id: Some(item.response.id),
+                payout_eligible: None,
+                should_add_next_step_to_process_tracker: false,
+                error_code: item.response.error_code,
+                error_message: item.response.error_message,
+            }),
+            ..item.data
+        })
+    }
+}
+
 #[derive(Default, Debug, Serialize, Deserialize, PartialEq)]
 pub struct DummyConnectorErrorResponse {
     pub error: ErrorData,
@@ -374,3 +505,125 @@ pub struct ErrorData {
     pub message: String,
     pub reason: Option<String>,
 }
+
+// WEBHOOKS :
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DummyConnectorWebhook {
+    pub event_type: DummyConnectorWebhookEventType,
+    pub data: DummyConnectorWebhookData,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DummyConnectorWebhookEventType {
+    PaymentSucceeded,
+    PaymentAuthorized,
+    PaymentFailed,
+    RefundSucceeded,
+    RefundFailed,
+    DisputeOpened,
+    #[cfg(feature = "payouts")]
+    PayoutSucceeded,
+    #[cfg(feature = "payouts")]
+    PayoutFailed,
+}
+
+impl From<DummyConnectorWebhookEventType> for api::IncomingWebhookEvent {
+    fn from(event_type: DummyConnectorWebhookEventType) -> Self {
+        match event_type {
+            DummyConnectorWebhookEventType::PaymentSucceeded => Self::PaymentIntentSuccess,
+            DummyConnectorWebhookEventType::PaymentAuthorized => {
+                Self::PaymentIntentAuthorizationSuccess
+            }
+            DummyConnectorWebhookEventType::PaymentFailed => Self::PaymentIntentFailure,
+            DummyConnectorWebhookEventType::RefundSucceeded => Self::RefundSuccess,
+            DummyConnectorWebhookEventType::RefundFailed => Self::RefundFailure,
+            DummyConnectorWebhookEventType::DisputeOpened => Self::DisputeOpened,
+            #[cfg(feature = "payouts")]
+            DummyConnectorWebhookEventType::PayoutSucceeded => Self::PayoutSuccess,
+            #[cfg(feature = "payouts")]
+            DummyConnectorWebhookEventType::PayoutFailed => Self::PayoutFailure,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type", content = "object", rename_all = "snake_case")]
+pub enum DummyConnectorWebhookData {
+    Payment(PaymentsResponse),
+    Refund(RefundResponse),
+    Dispute(DummyConnectorDispute),
+    #[cfg(feature = "payouts")]
+    Payout(DummyConnectorPayoutResponse),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DummyConnectorDispute {
+    id: String,
+    payment_id: String,
+    amount: i64,
+    currency: Currency,
+    reason: Option<String>,
+    status: String,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    created: Option<PrimitiveDateTime>,
+}
+
+impl DummyConnectorWebhookData {
+    pub fn get_object_reference_id(&self) -> api_models::webhooks::ObjectReferenceId {
+        match self {
+            Self::Payment(payment) => api_models::webhooks::ObjectReferenceId::PaymentId(
+                api_models::payments::PaymentIdType::ConnectorTransactionId(payment.id.clone()),
+            ),
+            Self::Refund(refund) => api_models::webhooks::ObjectReferenceId::RefundId(
+                api_models::webhooks::RefundIdType::ConnectorRefundId(refund.id.clone()),
+            ),
+            Self::Dispute(dispute) => api_models::webhooks::ObjectReferenceId::PaymentId(
+                api_models::payments::PaymentIdType::ConnectorTransactionId(
+                    dispute.payment_id.clone(),
+                ),
+            ),
+            #[cfg(feature = "payouts")]
+            Self::Payout(payout) => api_models::webhooks::ObjectReferenceId::PayoutId(
+                api_models::webhooks::PayoutIdType::ConnectorPayoutId(payout.id.clone()),
+            ),
+        }
+    }
+
+    pub fn get_resource_object(self) -> Box<dyn masking::ErasedMaskSerialize> {
+        match self {
+            Self::Payment(payment) => Box::new(payment),
+            Self::Refund(refund) => Box::new(refund),
+            Self::Dispute(dispute) => Box::new(dispute),
+            #[cfg(feature = "payouts")]
+            Self::Payout(payout) => Box::new(payout),
+        }
+    }
+}
+
+impl TryFrom<DummyConnectorWebhookData> for api::disputes::DisputePayload {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(data: DummyConnectorWebhookData) -> Result<Self, Self::Error> {
+        match data {
+            DummyConnectorWebhookData::Dispute(dispute) => Ok(Self {
+                amount: dispute.amount.to_string(),
+                currency: dispute.currency.to_string(),
+                dispute_stage: api_models::enums::DisputeStage::Dispute,
+                connector_status: dispute.status,
+                connector_dispute_id: dispute.id,
+                connector_reason: dispute.reason,
+                connector_reason_code: None,
+                challenge_required_by: None,
+                created_at: dispute.created,
+                updated_at: None,
+            }),
+            DummyConnectorWebhookData::Payment(_) | DummyConnectorWebhookData::Refund(_) => {
+                Err(errors::ConnectorError::WebhookBodyDecodingFailed.into())
+            }
+            #[cfg(feature = "payouts")]
+            DummyConnectorWebhookData::Payout(_) => {
+                Err(errors::ConnectorError::WebhookBodyDecodingFailed.into())
+            }
+        }
+    }
+}
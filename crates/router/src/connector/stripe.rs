@@ -1765,6 +1765,12 @@ impl api::FileUpload for Stripe {
                     })?
                 }
             }
+            api::FilePurpose::PayoutBatch | api::FilePurpose::RefundBatch => {
+                Err(errors::ConnectorError::NotSupported {
+                    message: "Batch files".to_string(),
+                    connector: "Stripe",
+                })?
+            }
         }
         Ok(())
     }
@@ -1930,7 +1930,26 @@ impl TryFrom<(&types::PaymentsAuthorizeRouterData, MinorUnit)> for PaymentIntent
                 };
                 (charges, None)
             }
-            None => (None, item.connector_customer.to_owned().map(Secret::new)),
+            None => {
+                // A split payment among a single connected account is processed as a destination
+                // charge, with the amount retained by the platform collected as application fees
+                let charges = item
+                    .request
+                    .split_payments
+                    .as_ref()
+                    .and_then(
+                        |split_payments| match split_payments.recipients.as_slice() {
+                            [recipient] => Some(IntentCharges {
+                                application_fee_amount: split_payments.platform_amount,
+                                destination_account_id: Some(
+                                    recipient.recipient_account_id.clone(),
+                                ),
+                            }),
+                            _ => None,
+                        },
+                    );
+                (charges, item.connector_customer.to_owned().map(Secret::new))
+            }
         };
 
         Ok(Self {
@@ -2967,6 +2986,10 @@ pub struct BacsFinancialDetails {
 pub struct RefundRequest {
     pub amount: Option<MinorUnit>, //amount in cents, hence passed as integer
     pub payment_intent: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refund_application_fee: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reverse_transfer: Option<bool>,
     #[serde(flatten)]
     pub meta_data: StripeMetadata,
 }
@@ -2977,9 +3000,14 @@ impl<F> TryFrom<(&types::RefundsRouterData<F>, MinorUnit)> for RefundRequest {
         (item, refund_amount): (&types::RefundsRouterData<F>, MinorUnit),
     ) -> Result<Self, Self::Error> {
         let payment_intent = item.request.connector_transaction_id.clone();
+        // A split payment is processed as a destination charge, so the transfer to the recipient
+        // and the application fee of the platform are reversed in proportion to the refund
+        let is_split_refund = item.request.split_refunds.is_some().then_some(true);
         Ok(Self {
             amount: Some(refund_amount),
             payment_intent,
+            refund_application_fee: is_split_refund,
+            reverse_transfer: is_split_refund,
             meta_data: StripeMetadata {
                 order_id: Some(item.request.refund_id.clone()),
                 is_refund_id_as_reference: Some("true".to_string()),
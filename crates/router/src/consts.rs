@@ -30,6 +30,9 @@ pub const DEFAULT_FULFILLMENT_TIME: i64 = 15 * 60;
 /// Payment intent default client secret expiry (in seconds)
 pub const DEFAULT_SESSION_EXPIRY: i64 = 15 * 60;
 
+/// Time for which the installment plans offered for a payment are cached (in seconds)
+pub const INSTALLMENT_PLANS_REDIS_EXPIRY: i64 = 15 * 60;
+
 /// The length of a merchant fingerprint secret
 pub const FINGERPRINT_SECRET_LENGTH: usize = 64;
 
@@ -88,6 +91,15 @@ pub const EMAIL_TOKEN_TIME_IN_SECS: u64 = 60 * 60 * 24; // 1 day
 pub const EMAIL_TOKEN_BLACKLIST_PREFIX: &str = "BET_";
 
 pub const EMAIL_SUBJECT_API_KEY_EXPIRY: &str = "API Key Expiry Notice";
+pub const EMAIL_SUBJECT_DISPUTE_DEADLINE: &str = "Dispute Response Deadline Approaching";
+pub const EMAIL_SUBJECT_INVOICE_REMINDER: &str = "Payment Reminder for Your Invoice";
+pub const EMAIL_SUBJECT_INVOICE_OVERDUE: &str = "Your Invoice Is Overdue";
+pub const EMAIL_SUBJECT_WEBHOOK_ENDPOINT_SUSPENDED: &str =
+    "Your Webhook Endpoint Has Been Suspended";
+pub const EMAIL_SUBJECT_ANALYTICS_REPORT: &str = "Your Scheduled Analytics Report";
+pub const EMAIL_SUBJECT_EXPORT_COMPLETED: &str = "Your Export Is Ready for Download";
+pub const EMAIL_SUBJECT_SUCCESS_RATE_DROPPED: &str = "Payment Success Rate Dropped";
+pub const EMAIL_SUBJECT_SUCCESS_RATE_RECOVERED: &str = "Payment Success Rate Recovered";
 pub const EMAIL_SUBJECT_DASHBOARD_FEATURE_REQUEST: &str = "Dashboard Pro Feature Request by";
 pub const EMAIL_SUBJECT_APPROVAL_RECON_REQUEST: &str =
     "Approval of Recon Request - Access Granted to Recon Dashboard";
@@ -1,11 +1,12 @@
 use api_models::analytics::search::SearchIndex;
 
-pub const fn get_search_indexes() -> [SearchIndex; 8] {
+pub const fn get_search_indexes() -> [SearchIndex; 9] {
     [
         SearchIndex::PaymentAttempts,
         SearchIndex::PaymentIntents,
         SearchIndex::Refunds,
         SearchIndex::Disputes,
+        SearchIndex::Payouts,
         SearchIndex::SessionizerPaymentAttempts,
         SearchIndex::SessionizerPaymentIntents,
         SearchIndex::SessionizerRefunds,
@@ -13,4 +14,4 @@ pub const fn get_search_indexes() -> [SearchIndex; 8] {
     ]
 }
 
-pub const SEARCH_INDEXES: [SearchIndex; 8] = get_search_indexes();
+pub const SEARCH_INDEXES: [SearchIndex; 9] = get_search_indexes();
@@ -1,4 +1,6 @@
 pub mod admin;
+#[cfg(feature = "olap")]
+pub mod analytics_reports;
 pub mod api_keys;
 pub mod api_locking;
 #[cfg(feature = "v1")]
@@ -18,18 +20,28 @@ pub mod customers;
 pub mod disputes;
 pub mod encryption;
 pub mod errors;
+#[cfg(feature = "olap")]
+pub mod exports;
 pub mod files;
 #[cfg(feature = "frm")]
 pub mod fraud_check;
 pub mod gsm;
 pub mod health_check;
 #[cfg(feature = "v1")]
+pub mod installments;
+#[cfg(feature = "v1")]
+pub mod invoices;
+#[cfg(feature = "v1")]
 pub mod locker_migration;
 pub mod mandate;
 pub mod metrics;
 pub mod payment_link;
 pub mod payment_methods;
+#[cfg(feature = "v1")]
+pub mod payment_recovery;
 pub mod payments;
+#[cfg(all(feature = "payouts", feature = "v1"))]
+pub mod payout_batch;
 #[cfg(feature = "payouts")]
 pub mod payout_link;
 #[cfg(feature = "payouts")]
@@ -39,8 +51,17 @@ pub mod poll;
 #[cfg(feature = "recon")]
 pub mod recon;
 #[cfg(feature = "v1")]
+pub mod refund_batch;
+#[cfg(feature = "v1")]
 pub mod refunds;
 pub mod routing;
+pub mod split_payments;
+#[cfg(all(feature = "v1", feature = "kv_store"))]
+pub mod storage_scheme_migration;
+#[cfg(feature = "v1")]
+pub mod subscriptions;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod success_rate_alerts;
 pub mod surcharge_decision_config;
 #[cfg(feature = "olap")]
 pub mod user;
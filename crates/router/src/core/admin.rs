@@ -3544,6 +3544,8 @@ impl ProfileCreateBridge for api::ProfileCreate {
             is_network_tokenization_enabled: self.is_network_tokenization_enabled,
             is_auto_retries_enabled: self.is_auto_retries_enabled.unwrap_or_default(),
             max_auto_retries_enabled: self.max_auto_retries_enabled.map(i16::from),
+            previous_payment_response_hash_key: None,
+            previous_payment_response_hash_key_expires_at: None,
         }))
     }
 
@@ -4284,6 +4286,68 @@ pub async fn connector_agnostic_mit_toggle(
     ))
 }
 
+/// Replaces the payment response hash key of the business profile, which is used to sign outgoing
+/// webhooks, with a newly generated one. Webhooks continue to be signed using the previous key as
+/// well, until it expires.
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub async fn rotate_profile_webhook_secret(
+    state: SessionState,
+    key_store: domain::MerchantKeyStore,
+    merchant_id: &id_type::MerchantId,
+    profile_id: &id_type::ProfileId,
+    request: api_models::webhook_endpoints::WebhookSecretRotateRequest,
+) -> RouterResponse<api_models::webhook_endpoints::WebhookSecretRotateResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let business_profile = db
+        .find_business_profile_by_merchant_id_profile_id(
+            key_manager_state,
+            &key_store,
+            merchant_id,
+            profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?;
+
+    let previous_secret_expires_at =
+        crate::core::webhooks::webhook_endpoints::get_previous_webhook_secret_expires_at(
+            request.expires_in,
+        )?;
+    let previous_payment_response_hash_key = business_profile.payment_response_hash_key.clone();
+    let previous_payment_response_hash_key_expires_at = previous_payment_response_hash_key
+        .as_ref()
+        .map(|_| previous_secret_expires_at);
+
+    let payment_response_hash_key =
+        crate::core::webhooks::webhook_endpoints::generate_webhook_secret();
+    let profile_update = domain::ProfileUpdate::PaymentResponseHashKeyUpdate {
+        payment_response_hash_key: payment_response_hash_key.clone(),
+        previous_payment_response_hash_key,
+        previous_payment_response_hash_key_expires_at,
+    };
+
+    db.update_profile_by_profile_id(
+        key_manager_state,
+        &key_store,
+        business_profile,
+        profile_update,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+        id: profile_id.get_string_repr().to_owned(),
+    })?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        api_models::webhook_endpoints::WebhookSecretRotateResponse {
+            secret: payment_response_hash_key.into(),
+            previous_secret_expires_at: previous_payment_response_hash_key_expires_at,
+        },
+    ))
+}
+
 pub async fn transfer_key_store_to_key_manager(
     state: SessionState,
     req: admin_types::MerchantKeyTransferRequest,
@@ -0,0 +1,1141 @@
+use std::collections::HashMap;
+
+use analytics::enums::AuthInfo;
+use api_models::analytics::{self as analytics_types, report_schedules as report_types};
+use common_utils::{date_time, types::TimeRange};
+use diesel_models::{
+    enums::{self as storage_enums, ProcessTrackerStatus},
+    process_tracker::business_status,
+};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, metrics::add_attributes, tracing};
+use time::PrimitiveDateTime;
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::{metrics, SessionState},
+    services::ApplicationResponse,
+    types::{
+        domain, storage,
+        transformers::{ForeignFrom, ForeignInto},
+    },
+};
+
+const ANALYTICS_REPORT_TAG: &str = "ANALYTICS_REPORT";
+const ANALYTICS_REPORT_NAME: &str = "ANALYTICS_REPORT";
+const ANALYTICS_REPORT_RUNNER: diesel_models::ProcessTrackerRunner =
+    diesel_models::ProcessTrackerRunner::AnalyticsReportWorkflow;
+
+/// The number of rows written to each row group of a parquet file written by a [`ReportWriter`].
+const PARQUET_ROW_GROUP_SIZE: usize = 10_000;
+
+/// The metrics request of a report, parsed into the request type of the domain of the report.
+pub(crate) enum ReportMetricRequest {
+    Payments(analytics_types::GetPaymentMetricRequest),
+    PaymentIntents(analytics_types::GetPaymentIntentMetricRequest),
+    Refunds(analytics_types::GetRefundMetricRequest),
+    Disputes(analytics_types::GetDisputeMetricRequest),
+    Payouts(analytics_types::GetPayoutMetricRequest),
+}
+
+#[instrument(skip_all)]
+pub async fn create_report_schedule(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: report_types::ReportScheduleCreateRequest,
+) -> RouterResponse<report_types::ReportScheduleResponse> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_account.get_id();
+
+    validate_metric_request(req.domain, &req.metric_request)?;
+    validate_recipients(req.delivery_method, &req.recipients)?;
+    if let Some(profile_id) = &req.profile_id {
+        db.find_business_profile_by_merchant_id_profile_id(
+            &(&state).into(),
+            &key_store,
+            merchant_id,
+            profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?;
+    }
+
+    let now = date_time::now();
+    let schedule_new = storage::AnalyticsReportScheduleNew {
+        report_schedule_id: common_utils::generate_id(consts::ID_LENGTH, "rsc"),
+        merchant_id: merchant_id.to_owned(),
+        organization_id: merchant_account.get_org_id().to_owned(),
+        profile_id: req.profile_id,
+        name: req.name,
+        domain: req.domain,
+        metric_request: req.metric_request,
+        frequency: req.frequency,
+        delivery_method: req.delivery_method,
+        recipients: req.recipients,
+        status: storage_enums::ReportScheduleStatus::Active,
+        last_run_at: None,
+        next_run_at: get_next_report_run_time(req.frequency, now),
+        created_at: now,
+        modified_at: now,
+    };
+    let schedule = db
+        .insert_analytics_report_schedule(schedule_new)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert analytics report schedule")?;
+
+    schedule_report_task(&state, &schedule).await?;
+
+    Ok(ApplicationResponse::Json(schedule.foreign_into()))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_report_schedule(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    report_schedule_id: String,
+) -> RouterResponse<report_types::ReportScheduleResponse> {
+    let schedule =
+        find_report_schedule(&state, merchant_account.get_id(), &report_schedule_id).await?;
+
+    Ok(ApplicationResponse::Json(schedule.foreign_into()))
+}
+
+#[instrument(skip_all)]
+pub async fn list_report_schedules(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<Vec<report_types::ReportScheduleResponse>> {
+    let schedules = state
+        .store
+        .list_analytics_report_schedules_by_merchant_id(merchant_account.get_id())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list analytics report schedules")?;
+
+    Ok(ApplicationResponse::Json(
+        schedules
+            .into_iter()
+            .map(ForeignInto::foreign_into)
+            .collect(),
+    ))
+}
+
+/// Updates a report schedule. The next run of the report is recomputed when its frequency is
+/// changed or when a paused report is resumed.
+#[instrument(skip_all)]
+pub async fn update_report_schedule(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    report_schedule_id: String,
+    req: report_types::ReportScheduleUpdateRequest,
+) -> RouterResponse<report_types::ReportScheduleResponse> {
+    let merchant_id = merchant_account.get_id();
+    let schedule = find_report_schedule(&state, merchant_id, &report_schedule_id).await?;
+
+    if let Some(metric_request) = &req.metric_request {
+        validate_metric_request(schedule.domain, metric_request)?;
+    }
+    validate_recipients(
+        req.delivery_method.unwrap_or(schedule.delivery_method),
+        req.recipients.as_ref().unwrap_or(&schedule.recipients),
+    )?;
+
+    let frequency = req.frequency.unwrap_or(schedule.frequency);
+    let status = req.status.unwrap_or(schedule.status);
+    let is_resumed = schedule.status == storage_enums::ReportScheduleStatus::Paused
+        && status == storage_enums::ReportScheduleStatus::Active;
+    let next_run_at = (frequency != schedule.frequency || is_resumed)
+        .then(|| get_next_report_run_time(frequency, date_time::now()));
+
+    let schedule = state
+        .store
+        .update_analytics_report_schedule_by_merchant_id_report_schedule_id(
+            merchant_id,
+            &report_schedule_id,
+            storage::AnalyticsReportScheduleUpdate::Update {
+                name: req.name,
+                metric_request: req.metric_request,
+                frequency: req.frequency,
+                delivery_method: req.delivery_method,
+                recipients: req.recipients,
+                status: req.status,
+                next_run_at,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update analytics report schedule")?;
+
+    match schedule.status {
+        storage_enums::ReportScheduleStatus::Active if next_run_at.is_some() => {
+            schedule_report_task(&state, &schedule).await?
+        }
+        storage_enums::ReportScheduleStatus::Active => {}
+        storage_enums::ReportScheduleStatus::Paused => {
+            revoke_report_task(&state, &schedule.report_schedule_id).await?
+        }
+    }
+
+    Ok(ApplicationResponse::Json(schedule.foreign_into()))
+}
+
+#[instrument(skip_all)]
+pub async fn delete_report_schedule(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    report_schedule_id: String,
+) -> RouterResponse<report_types::ReportScheduleDeleteResponse> {
+    let merchant_id = merchant_account.get_id();
+    find_report_schedule(&state, merchant_id, &report_schedule_id).await?;
+
+    let deleted = state
+        .store
+        .delete_analytics_report_schedule_by_merchant_id_report_schedule_id(
+            merchant_id,
+            &report_schedule_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to delete analytics report schedule")?;
+    revoke_report_task(&state, &report_schedule_id).await?;
+
+    Ok(ApplicationResponse::Json(
+        report_types::ReportScheduleDeleteResponse {
+            report_schedule_id,
+            deleted,
+        },
+    ))
+}
+
+/// Generates the report for the period preceding its scheduled run and delivers it to the
+/// merchant.
+#[instrument(skip_all)]
+pub async fn generate_and_deliver_report(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    schedule: &storage::AnalyticsReportSchedule,
+) -> RouterResult<()> {
+    let period = get_report_period(schedule.frequency, schedule.next_run_at);
+    let metric_request = build_metric_request(schedule.domain, &schedule.metric_request, period)?;
+    let auth = get_auth_info(merchant_account, schedule.profile_id.as_ref());
+    let table = ReportTable::from_rows(get_metrics(state, &auth, metric_request).await?);
+
+    match schedule.delivery_method {
+        storage_enums::ReportDeliveryMethod::Email => {
+            send_report_email(state, schedule, &period, &table).await?
+        }
+        storage_enums::ReportDeliveryMethod::FileStorage => {
+            upload_report(state, schedule, &period, &table).await?
+        }
+    }
+
+    logger::info!(
+        report_schedule_id = %schedule.report_schedule_id,
+        rows = table.rows.len(),
+        "Delivered analytics report"
+    );
+    metrics::ANALYTICS_REPORTS_DELIVERED_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &add_attributes([("delivery_method", schedule.delivery_method.to_string())]),
+    );
+
+    Ok(())
+}
+
+#[cfg(feature = "email")]
+async fn send_report_email(
+    state: &SessionState,
+    schedule: &storage::AnalyticsReportSchedule,
+    period: &TimeRange,
+    table: &ReportTable,
+) -> RouterResult<()> {
+    use crate::services::email::types::AnalyticsReport;
+
+    let report_table = table.to_html();
+    let mut delivered = false;
+    for recipient in schedule.recipients.iter() {
+        let result = async {
+            let email_contents = AnalyticsReport {
+                recipient_email: domain::UserEmail::from_pii_email(recipient.clone())
+                    .change_context(errors::ApiErrorResponse::InternalServerError)?,
+                subject: consts::EMAIL_SUBJECT_ANALYTICS_REPORT,
+                report_name: schedule.name.clone(),
+                period_start: period.start_time.to_string(),
+                period_end: period.end_time.unwrap_or(period.start_time).to_string(),
+                report_table: report_table.clone(),
+            };
+
+            state
+                .email_client
+                .clone()
+                .compose_and_send_email(
+                    Box::new(email_contents),
+                    state.conf.proxy.https_url.as_ref(),
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+        }
+        .await;
+
+        match result {
+            Ok(_) => delivered = true,
+            // The report is not sent again to the other recipients when it could not be
+            // delivered to some of them
+            Err(error) => logger::error!(
+                ?error,
+                report_schedule_id = %schedule.report_schedule_id,
+                "Failed to send analytics report email"
+            ),
+        }
+    }
+
+    if !delivered {
+        Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Failed to send the analytics report to any of the recipients")?
+    }
+
+    Ok(())
+}
+
+#[cfg(not(feature = "email"))]
+async fn send_report_email(
+    _state: &SessionState,
+    _schedule: &storage::AnalyticsReportSchedule,
+    _period: &TimeRange,
+    _table: &ReportTable,
+) -> RouterResult<()> {
+    Err(report!(errors::ApiErrorResponse::NotSupported {
+        message: "Delivering analytics reports by email is not supported".to_owned(),
+    }))
+}
+
+async fn upload_report(
+    state: &SessionState,
+    schedule: &storage::AnalyticsReportSchedule,
+    period: &TimeRange,
+    table: &ReportTable,
+) -> RouterResult<()> {
+    let file_key = format!(
+        "analytics_reports/{}/{}/{}_{}.csv",
+        schedule.merchant_id.get_string_repr(),
+        schedule.report_schedule_id,
+        period.start_time.date(),
+        period.end_time.unwrap_or(period.start_time).date(),
+    );
+
+    state
+        .file_storage_client
+        .upload_file(&file_key, table.to_csv()?)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to upload analytics report")
+}
+
+/// Returns the auth info the metrics are computed with, restricted to the profile when one is
+/// provided.
+pub(crate) fn get_auth_info(
+    merchant_account: &domain::MerchantAccount,
+    profile_id: Option<&common_utils::id_type::ProfileId>,
+) -> AuthInfo {
+    let org_id = merchant_account.get_org_id().to_owned();
+    let merchant_id = merchant_account.get_id().to_owned();
+    match profile_id {
+        Some(profile_id) => AuthInfo::ProfileLevel {
+            org_id,
+            merchant_id,
+            profile_ids: vec![profile_id.clone()],
+        },
+        None => AuthInfo::MerchantLevel {
+            org_id,
+            merchant_ids: vec![merchant_id],
+        },
+    }
+}
+
+/// Computes the metrics of the request, returning a row for every bucket of the metrics.
+pub(crate) async fn get_metrics(
+    state: &SessionState,
+    auth: &AuthInfo,
+    metric_request: ReportMetricRequest,
+) -> RouterResult<Vec<serde_json::Value>> {
+    let query_data = match metric_request {
+        ReportMetricRequest::Payments(req) => {
+            analytics::payments::get_metrics(&state.pool, auth, req)
+                .await
+                .map(|response| serde_json::to_value(response.query_data))
+        }
+        ReportMetricRequest::PaymentIntents(req) => {
+            analytics::payment_intents::get_metrics(&state.pool, auth, req)
+                .await
+                .map(|response| serde_json::to_value(response.query_data))
+        }
+        ReportMetricRequest::Refunds(req) => {
+            analytics::refunds::get_metrics(&state.pool, auth, req)
+                .await
+                .map(|response| serde_json::to_value(response.query_data))
+        }
+        ReportMetricRequest::Disputes(req) => {
+            analytics::disputes::get_metrics(&state.pool, auth, req)
+                .await
+                .map(|response| serde_json::to_value(response.query_data))
+        }
+        ReportMetricRequest::Payouts(req) => {
+            analytics::payouts::get_metrics(&state.pool, auth, req)
+                .await
+                .map(|response| serde_json::to_value(response.query_data))
+        }
+    }
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to compute analytics metrics")?
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to serialize analytics metrics")?;
+
+    Ok(match query_data {
+        serde_json::Value::Array(rows) => rows,
+        _ => Vec::new(),
+    })
+}
+
+async fn find_report_schedule(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    report_schedule_id: &str,
+) -> RouterResult<storage::AnalyticsReportSchedule> {
+    state
+        .store
+        .find_analytics_report_schedule_by_merchant_id_report_schedule_id(
+            merchant_id,
+            report_schedule_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Report schedule `{report_schedule_id}` does not exist"),
+        })
+}
+
+fn validate_metric_request(
+    domain: storage_enums::AnalyticsReportDomain,
+    metric_request: &serde_json::Value,
+) -> RouterResult<()> {
+    let metric_request =
+        metric_request
+            .as_object()
+            .ok_or(errors::ApiErrorResponse::InvalidRequestData {
+                message: "`metric_request` must be a JSON object".to_owned(),
+            })?;
+    if metric_request.contains_key("timeRange") {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`timeRange` must not be provided in `metric_request`, it is computed for \
+                      every run of the report"
+                .to_owned(),
+        })?
+    }
+
+    let now = date_time::now();
+    build_metric_request(
+        domain,
+        &serde_json::Value::Object(metric_request.clone()),
+        TimeRange {
+            start_time: now,
+            end_time: Some(now),
+        },
+    )
+    .map(|_| ())
+}
+
+fn validate_recipients(
+    delivery_method: storage_enums::ReportDeliveryMethod,
+    recipients: &[common_utils::pii::Email],
+) -> RouterResult<()> {
+    if delivery_method == storage_enums::ReportDeliveryMethod::Email && recipients.is_empty() {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "At least one recipient is required for reports delivered by email".to_owned(),
+        })?
+    }
+
+    Ok(())
+}
+
+fn build_metric_request(
+    domain: storage_enums::AnalyticsReportDomain,
+    metric_request: &serde_json::Value,
+    time_range: TimeRange,
+) -> RouterResult<ReportMetricRequest> {
+    let mut metric_request = metric_request.clone();
+    if let Some(metric_request) = metric_request.as_object_mut() {
+        let time_range = serde_json::to_value(time_range)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to serialize the time range of the report")?;
+        metric_request.insert("timeRange".to_owned(), time_range);
+    }
+
+    parse_metric_request(domain, metric_request)
+}
+
+/// Parses a metrics request into the request type of the domain, in the same format as accepted
+/// by the metrics endpoint of the domain.
+pub(crate) fn parse_metric_request(
+    domain: storage_enums::AnalyticsReportDomain,
+    metric_request: serde_json::Value,
+) -> RouterResult<ReportMetricRequest> {
+    match domain {
+        storage_enums::AnalyticsReportDomain::Payments => {
+            serde_json::from_value(metric_request).map(ReportMetricRequest::Payments)
+        }
+        storage_enums::AnalyticsReportDomain::PaymentIntents => {
+            serde_json::from_value(metric_request).map(ReportMetricRequest::PaymentIntents)
+        }
+        storage_enums::AnalyticsReportDomain::Refunds => {
+            serde_json::from_value(metric_request).map(ReportMetricRequest::Refunds)
+        }
+        storage_enums::AnalyticsReportDomain::Disputes => {
+            serde_json::from_value(metric_request).map(ReportMetricRequest::Disputes)
+        }
+        storage_enums::AnalyticsReportDomain::Payouts => {
+            serde_json::from_value(metric_request).map(ReportMetricRequest::Payouts)
+        }
+    }
+    .map_err(|error| {
+        report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("Invalid metrics request for the {domain} domain: {error}"),
+        })
+    })
+}
+
+/// Returns the period covered by a run of the report scheduled at `run_at`: the day, or the seven
+/// days, preceding the day of the run.
+pub fn get_report_period(
+    frequency: storage_enums::ReportFrequency,
+    run_at: PrimitiveDateTime,
+) -> TimeRange {
+    let end_time = run_at.replace_time(time::Time::MIDNIGHT);
+    let period = match frequency {
+        storage_enums::ReportFrequency::Daily => time::Duration::days(1),
+        storage_enums::ReportFrequency::Weekly => time::Duration::weeks(1),
+    };
+
+    TimeRange {
+        start_time: end_time.saturating_sub(period),
+        end_time: Some(end_time),
+    }
+}
+
+/// Returns the time of the next run of a report after `now`. Daily reports run at the start of
+/// every day, and weekly reports at the start of every Monday (UTC).
+pub fn get_next_report_run_time(
+    frequency: storage_enums::ReportFrequency,
+    now: PrimitiveDateTime,
+) -> PrimitiveDateTime {
+    let days_until_next_run = match frequency {
+        storage_enums::ReportFrequency::Daily => 1,
+        storage_enums::ReportFrequency::Weekly => 7 - now.weekday().number_days_from_monday(),
+    };
+
+    now.replace_time(time::Time::MIDNIGHT)
+        .saturating_add(time::Duration::days(i64::from(days_until_next_run)))
+}
+
+/// Schedules the task generating the report at the next run of the report. The task of the
+/// report is reused if the report was scheduled before.
+async fn schedule_report_task(
+    state: &SessionState,
+    schedule: &storage::AnalyticsReportSchedule,
+) -> RouterResult<()> {
+    let db = state.store.as_ref();
+    let task_id = generate_task_id_for_analytics_report_workflow(&schedule.report_schedule_id);
+    let existing_process = db
+        .find_process_by_id(&task_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch analytics report task")?;
+    let tracking_data = storage::AnalyticsReportTrackingData {
+        merchant_id: schedule.merchant_id.clone(),
+        report_schedule_id: schedule.report_schedule_id.clone(),
+    };
+
+    match existing_process {
+        Some(process) => {
+            let tracking_data = serde_json::to_value(&tracking_data)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to serialize analytics report tracking data")?;
+            db.as_scheduler()
+                .update_process(
+                    process,
+                    storage::ProcessTrackerUpdate::Update {
+                        name: None,
+                        retry_count: Some(0),
+                        schedule_time: Some(schedule.next_run_at),
+                        tracking_data: Some(tracking_data),
+                        business_status: Some(String::from(business_status::PENDING)),
+                        status: Some(ProcessTrackerStatus::New),
+                        updated_at: Some(date_time::now()),
+                    },
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to reschedule analytics report task")?;
+        }
+        None => {
+            let process_tracker_entry = storage::ProcessTrackerNew::new(
+                task_id,
+                ANALYTICS_REPORT_NAME,
+                ANALYTICS_REPORT_RUNNER,
+                [ANALYTICS_REPORT_TAG],
+                tracking_data,
+                schedule.next_run_at,
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to construct analytics report task")?;
+
+            db.insert_process(process_tracker_entry)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to insert analytics report task")?;
+            metrics::TASKS_ADDED_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &add_attributes([("flow", "AnalyticsReport")]),
+            );
+        }
+    }
+
+    Ok(())
+}
+
+async fn revoke_report_task(state: &SessionState, report_schedule_id: &str) -> RouterResult<()> {
+    let db = state.store.as_ref();
+    let process = db
+        .find_process_by_id(&generate_task_id_for_analytics_report_workflow(
+            report_schedule_id,
+        ))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch analytics report task")?;
+
+    if let Some(process) = process.filter(|process| process.status != ProcessTrackerStatus::Finish)
+    {
+        db.as_scheduler()
+            .finish_process_with_business_status(process, business_status::REVOKED)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to revoke analytics report task")?;
+    }
+
+    Ok(())
+}
+
+/// Reschedules the task of the report at its next run, once the report has been delivered.
+pub async fn reschedule_report_task(
+    state: &SessionState,
+    process: storage::ProcessTracker,
+    schedule: &storage::AnalyticsReportSchedule,
+) -> Result<(), errors::ProcessTrackerError> {
+    let now = date_time::now();
+    let next_run_at = get_next_report_run_time(schedule.frequency, now);
+    state
+        .store
+        .update_analytics_report_schedule_by_merchant_id_report_schedule_id(
+            &schedule.merchant_id,
+            &schedule.report_schedule_id,
+            storage::AnalyticsReportScheduleUpdate::RunUpdate {
+                last_run_at: now,
+                next_run_at,
+            },
+        )
+        .await?;
+
+    state
+        .store
+        .as_scheduler()
+        .update_process(
+            process,
+            storage::ProcessTrackerUpdate::Update {
+                name: None,
+                retry_count: Some(0),
+                schedule_time: Some(next_run_at),
+                tracking_data: None,
+                business_status: None,
+                status: Some(ProcessTrackerStatus::New),
+                updated_at: Some(now),
+            },
+        )
+        .await?;
+    metrics::TASKS_RESET_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &add_attributes([("flow", "AnalyticsReport")]),
+    );
+
+    Ok(())
+}
+
+pub fn generate_task_id_for_analytics_report_workflow(report_schedule_id: &str) -> String {
+    format!("{ANALYTICS_REPORT_RUNNER}_{ANALYTICS_REPORT_NAME}_{report_schedule_id}")
+}
+
+/// The rows of a report, with nested fields of the metrics flattened into columns named by their
+/// path, such as `time_range.start_time`.
+pub(crate) struct ReportTable {
+    pub(crate) headers: Vec<String>,
+    pub(crate) rows: Vec<Vec<String>>,
+}
+
+impl ReportTable {
+    pub(crate) fn from_rows(rows: Vec<serde_json::Value>) -> Self {
+        let mut headers: Vec<String> = Vec::new();
+        let records = rows
+            .into_iter()
+            .map(|row| {
+                let mut columns = Vec::new();
+                flatten_value(None, row, &mut columns);
+                for (column, _) in columns.iter() {
+                    if !headers.contains(column) {
+                        headers.push(column.clone());
+                    }
+                }
+                columns.into_iter().collect::<HashMap<_, _>>()
+            })
+            .collect::<Vec<_>>();
+
+        let rows = records
+            .iter()
+            .map(|record| {
+                headers
+                    .iter()
+                    .map(|column| record.get(column).cloned().unwrap_or_default())
+                    .collect()
+            })
+            .collect();
+
+        Self { headers, rows }
+    }
+
+    pub(crate) fn to_csv(&self) -> RouterResult<Vec<u8>> {
+        let mut writer = csv::Writer::from_writer(Vec::new());
+        writer
+            .write_record(&self.headers)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to write analytics report")?;
+        for row in self.rows.iter() {
+            writer
+                .write_record(row)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to write analytics report")?;
+        }
+
+        writer
+            .into_inner()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to write analytics report")
+    }
+
+    /// Writes the table as a single row group.
+    pub(crate) fn to_parquet(&self) -> RouterResult<Vec<u8>> {
+        write_parquet(&self.headers, std::iter::once(Ok(self.rows.as_slice())))
+    }
+
+    fn to_html(&self) -> String {
+        if self.rows.is_empty() {
+            return "<p>No data was recorded for the period of the report.</p>".to_owned();
+        }
+
+        let cell = |tag: &str, value: &str| {
+            format!(
+                "<{tag} style=\"border: 1px solid #dddddd; padding: 4px 8px;\">{}</{tag}>",
+                escape_html(value)
+            )
+        };
+        let header = self
+            .headers
+            .iter()
+            .map(|column| cell("th", column))
+            .collect::<String>();
+        let rows = self
+            .rows
+            .iter()
+            .map(|row| {
+                format!(
+                    "<tr>{}</tr>",
+                    row.iter()
+                        .map(|value| cell("td", value))
+                        .collect::<String>()
+                )
+            })
+            .collect::<String>();
+
+        format!(
+            "<table style=\"border-collapse: collapse; font-size: 0.8rem;\"><tr>{header}</tr>{rows}</table>"
+        )
+    }
+}
+
+/// Writes the rows of a report as they are obtained, so that the rows of large reports, such as
+/// exports, are not held in memory at once. The rows are buffered as CSV records until the report
+/// is complete, since the columns of the report are only known once all of its rows are written.
+pub(crate) struct ReportWriter {
+    headers: Vec<String>,
+    records: csv::Writer<Vec<u8>>,
+    row_count: usize,
+}
+
+impl ReportWriter {
+    pub(crate) fn new() -> Self {
+        Self {
+            headers: Vec::new(),
+            // Columns first found in later rows are appended to the columns of the earlier rows,
+            // so the records of the earlier rows are shorter until they are padded
+            records: csv::WriterBuilder::new()
+                .flexible(true)
+                .from_writer(Vec::new()),
+            row_count: 0,
+        }
+    }
+
+    pub(crate) fn row_count(&self) -> usize {
+        self.row_count
+    }
+
+    pub(crate) fn write_rows(
+        &mut self,
+        rows: impl IntoIterator<Item = serde_json::Value>,
+    ) -> RouterResult<()> {
+        for row in rows {
+            let mut columns = Vec::new();
+            flatten_value(None, row, &mut columns);
+            for (column, _) in columns.iter() {
+                if !self.headers.contains(column) {
+                    self.headers.push(column.clone());
+                }
+            }
+
+            let mut record = columns.into_iter().collect::<HashMap<_, _>>();
+            self.records
+                .write_record(
+                    self.headers
+                        .iter()
+                        .map(|column| record.remove(column).unwrap_or_default()),
+                )
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to write report row")?;
+            self.row_count = self.row_count.saturating_add(1);
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn into_csv(self) -> RouterResult<Vec<u8>> {
+        let (headers, records) = self.into_records()?;
+        let mut writer = csv::Writer::from_writer(Vec::new());
+        writer
+            .write_record(&headers)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to write report")?;
+        for record in records {
+            writer
+                .write_record(&record?)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to write report")?;
+        }
+
+        writer
+            .into_inner()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to write report")
+    }
+
+    /// Writes the report in row groups of [`PARQUET_ROW_GROUP_SIZE`] rows.
+    pub(crate) fn into_parquet(self) -> RouterResult<Vec<u8>> {
+        let (headers, mut records) = self.into_records()?;
+        let row_groups = std::iter::from_fn(move || {
+            match records
+                .by_ref()
+                .take(PARQUET_ROW_GROUP_SIZE)
+                .collect::<RouterResult<Vec<_>>>()
+            {
+                Ok(rows) if rows.is_empty() => None,
+                row_group => Some(row_group),
+            }
+        });
+
+        write_parquet(&headers, row_groups)
+    }
+
+    /// Reads back the buffered records, padded to the columns of the complete report.
+    fn into_records(
+        self,
+    ) -> RouterResult<(Vec<String>, impl Iterator<Item = RouterResult<Vec<String>>>)> {
+        let buffer = self
+            .records
+            .into_inner()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to write report")?;
+        let column_count = self.headers.len();
+        let records = csv::ReaderBuilder::new()
+            .has_headers(false)
+            .flexible(true)
+            .from_reader(std::io::Cursor::new(buffer))
+            .into_records()
+            .map(move |record| {
+                record
+                    .map(|record| {
+                        let mut record = record.iter().map(ToOwned::to_owned).collect::<Vec<_>>();
+                        record.resize(column_count, String::new());
+                        record
+                    })
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to read buffered report row")
+            });
+
+        Ok((self.headers, records))
+    }
+}
+
+/// Writes the rows as row groups of optional UTF-8 columns. Empty cells are written as nulls.
+fn write_parquet<R>(
+    headers: &[String],
+    row_groups: impl IntoIterator<Item = RouterResult<R>>,
+) -> RouterResult<Vec<u8>>
+where
+    R: AsRef<[Vec<String>]>,
+{
+    use parquet::{
+        basic::{ConvertedType, Repetition, Type as PhysicalType},
+        data_type::{ByteArray, ByteArrayType},
+        file::{properties::WriterProperties, writer::SerializedFileWriter},
+        schema::types::Type,
+    };
+
+    let fields = headers
+        .iter()
+        .map(|column| {
+            Type::primitive_type_builder(column, PhysicalType::BYTE_ARRAY)
+                .with_repetition(Repetition::OPTIONAL)
+                .with_converted_type(ConvertedType::UTF8)
+                .build()
+                .map(std::sync::Arc::new)
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to build the parquet schema")?;
+    let schema = Type::group_type_builder("schema")
+        .with_fields(fields)
+        .build()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to build the parquet schema")?;
+
+    let mut writer = SerializedFileWriter::new(
+        Vec::new(),
+        std::sync::Arc::new(schema),
+        std::sync::Arc::new(WriterProperties::builder().build()),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to write parquet file")?;
+
+    for rows in row_groups {
+        let rows = rows?;
+        let rows = rows.as_ref();
+        let mut row_group = writer
+            .next_row_group()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to write parquet file")?;
+
+        for index in 0..headers.len() {
+            let mut values = Vec::new();
+            let mut definition_levels = Vec::with_capacity(rows.len());
+            for value in rows.iter().map(|row| row.get(index)) {
+                match value.filter(|value| !value.is_empty()) {
+                    Some(value) => {
+                        values.push(ByteArray::from(value.as_str()));
+                        definition_levels.push(1);
+                    }
+                    None => definition_levels.push(0),
+                }
+            }
+
+            let column = row_group
+                .next_column()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to write parquet file")?;
+            if let Some(mut column) = column {
+                column
+                    .typed::<ByteArrayType>()
+                    .write_batch(&values, Some(definition_levels.as_slice()), None)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to write parquet column")?;
+                column
+                    .close()
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to write parquet column")?;
+            }
+        }
+
+        row_group
+            .close()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to write parquet file")?;
+    }
+
+    writer
+        .into_inner()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to write parquet file")
+}
+
+fn flatten_value(
+    path: Option<&str>,
+    value: serde_json::Value,
+    columns: &mut Vec<(String, String)>,
+) {
+    let column = || path.unwrap_or("value").to_owned();
+    match value {
+        serde_json::Value::Object(fields) => {
+            for (key, value) in fields {
+                let path = path.map_or_else(|| key.clone(), |path| format!("{path}.{key}"));
+                flatten_value(Some(&path), value, columns);
+            }
+        }
+        serde_json::Value::Null => columns.push((column(), String::new())),
+        serde_json::Value::String(value) => columns.push((column(), value)),
+        value => columns.push((column(), value.to_string())),
+    }
+}
+
+fn escape_html(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+impl ForeignFrom<storage::AnalyticsReportSchedule> for report_types::ReportScheduleResponse {
+    fn foreign_from(schedule: storage::AnalyticsReportSchedule) -> Self {
+        Self {
+            report_schedule_id: schedule.report_schedule_id,
+            merchant_id: schedule.merchant_id,
+            profile_id: schedule.profile_id,
+            name: schedule.name,
+            domain: schedule.domain,
+            metric_request: schedule.metric_request,
+            frequency: schedule.frequency,
+            delivery_method: schedule.delivery_method,
+            recipients: schedule.recipients,
+            status: schedule.status,
+            last_run_at: schedule.last_run_at,
+            next_run_at: schedule.next_run_at,
+            created_at: schedule.created_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn test_report_schedule() {
+        // 2024-11-27 is a Wednesday
+        let now = datetime!(2024-11-27 10:30);
+
+        assert_eq!(
+            get_next_report_run_time(storage_enums::ReportFrequency::Daily, now),
+            datetime!(2024-11-28 0:00)
+        );
+        assert_eq!(
+            get_next_report_run_time(storage_enums::ReportFrequency::Weekly, now),
+            datetime!(2024-12-02 0:00)
+        );
+        assert_eq!(
+            get_next_report_run_time(
+                storage_enums::ReportFrequency::Weekly,
+                datetime!(2024-12-02 0:00)
+            ),
+            datetime!(2024-12-09 0:00)
+        );
+
+        let period = get_report_period(
+            storage_enums::ReportFrequency::Weekly,
+            datetime!(2024-12-02 0:05),
+        );
+        assert_eq!(period.start_time, datetime!(2024-11-25 0:00));
+        assert_eq!(period.end_time, Some(datetime!(2024-12-02 0:00)));
+    }
+
+    #[test]
+    fn test_report_table() {
+        let rows = vec![
+            serde_json::json!({
+                "payment_count": 3,
+                "currency": "USD",
+                "time_range": { "start_time": "2024-11-26T00:00:00.000Z" }
+            }),
+            serde_json::json!({
+                "payment_count": null,
+                "currency": "EUR",
+                "connector": "<stripe>"
+            }),
+        ];
+        let table = ReportTable::from_rows(rows);
+
+        assert_eq!(
+            table.headers,
+            vec![
+                "currency",
+                "payment_count",
+                "time_range.start_time",
+                "connector"
+            ]
+        );
+        assert_eq!(
+            String::from_utf8(table.to_csv().unwrap_or_default()).unwrap_or_default(),
+            "currency,payment_count,time_range.start_time,connector\n\
+             USD,3,2024-11-26T00:00:00.000Z,\n\
+             EUR,,,<stripe>\n"
+        );
+        assert!(table.to_html().contains("&lt;stripe&gt;"));
+        assert!(table.to_parquet().unwrap_or_default().starts_with(b"PAR1"));
+    }
+
+    #[test]
+    fn test_report_writer() {
+        let mut writer = ReportWriter::new();
+        writer
+            .write_rows(vec![
+                serde_json::json!({ "currency": "USD", "note": "a,\nb" }),
+            ])
+            .unwrap_or_default();
+        writer
+            .write_rows(vec![
+                serde_json::json!({ "currency": "EUR", "connector": "stripe" }),
+                serde_json::json!({ "note": null }),
+            ])
+            .unwrap_or_default();
+        assert_eq!(writer.row_count(), 3);
+
+        // Columns first found in a later page are written for the rows of the earlier pages
+        assert_eq!(
+            String::from_utf8(writer.into_csv().unwrap_or_default()).unwrap_or_default(),
+            "currency,note,connector\n\
+             USD,\"a,\nb\",\n\
+             EUR,,stripe\n\
+             ,,\n"
+        );
+
+        let mut writer = ReportWriter::new();
+        writer
+            .write_rows(vec![serde_json::json!({ "currency": "USD" })])
+            .unwrap_or_default();
+        assert!(writer
+            .into_parquet()
+            .unwrap_or_default()
+            .starts_with(b"PAR1"));
+    }
+}
@@ -23,6 +23,11 @@ const API_KEY_EXPIRY_NAME: &str = "API_KEY_EXPIRY";
 const API_KEY_EXPIRY_RUNNER: diesel_models::ProcessTrackerRunner =
     diesel_models::ProcessTrackerRunner::ApiKeyExpiryWorkflow;
 
+const API_KEY_ROTATION_TAG: &str = "API_KEY";
+const API_KEY_ROTATION_NAME: &str = "API_KEY_ROTATION";
+const API_KEY_ROTATION_RUNNER: diesel_models::ProcessTrackerRunner =
+    diesel_models::ProcessTrackerRunner::ApiKeyRotationWorkflow;
+
 static HASH_KEY: once_cell::sync::OnceCell<StrongSecret<[u8; PlaintextApiKey::HASH_KEY_LEN]>> =
     once_cell::sync::OnceCell::new();
 
@@ -512,6 +517,284 @@ pub async fn revoke_api_key_expiry_task(
     Ok(())
 }
 
+#[instrument(skip_all)]
+pub async fn rotate_api_key(
+    state: SessionState,
+    api_key: api::RotateApiKeyRequest,
+) -> RouterResponse<api::RotateApiKeyResponse> {
+    let api_key_config = state.conf.api_keys.get_inner();
+    let store = state.store.as_ref();
+    let merchant_id = api_key.merchant_id.clone();
+    let key_id = api_key.key_id.clone();
+
+    let grace_period_in_secs = api_key
+        .grace_period_in_secs
+        .unwrap_or(api_key_config.rotation_grace_period_in_secs);
+    if grace_period_in_secs > api_key_config.max_rotation_grace_period_in_secs {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "`grace_period_in_secs` must not exceed {} seconds",
+                api_key_config.max_rotation_grace_period_in_secs
+            ),
+        }));
+    }
+
+    let existing_api_key = store
+        .find_api_key_by_merchant_id_key_id_optional(&merchant_id, &key_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError) // If retrieve failed
+        .attach_printable("Failed to retrieve API key")?
+        .ok_or(report!(errors::ApiErrorResponse::ApiKeyNotFound))?; // If retrieve returned `None`
+
+    let current_time = date_time::now();
+    if existing_api_key
+        .expires_at
+        .is_some_and(|expires_at| expires_at <= current_time)
+    {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "API key has already expired and cannot be rotated".to_string(),
+        }));
+    }
+
+    // The rotation task is keyed by the rotated key, so a key which has already been rotated
+    // must be rejected before the successor key is created
+    let existing_rotation_task = store
+        .find_process_by_id(generate_task_id_for_api_key_rotation_workflow(&key_id).as_str())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve API key rotation task from process tracker")?;
+    if existing_rotation_task.is_some() {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "API key has already been rotated".to_string(),
+        }));
+    }
+
+    let rotated_key_expiry = get_rotated_key_expiry(
+        current_time,
+        existing_api_key.expires_at,
+        grace_period_in_secs,
+    );
+
+    let hash_key = api_key_config.get_hash_key()?;
+    let plaintext_api_key = PlaintextApiKey::new(consts::API_KEY_LENGTH);
+    let successor_api_key = storage::ApiKeyNew {
+        key_id: PlaintextApiKey::new_key_id(),
+        merchant_id: merchant_id.to_owned(),
+        name: existing_api_key.name.clone(),
+        description: existing_api_key.description.clone(),
+        hashed_api_key: plaintext_api_key.keyed_hash(hash_key.peek()).into(),
+        prefix: plaintext_api_key.prefix(),
+        created_at: current_time,
+        expires_at: api_key
+            .expiration
+            .map_or(existing_api_key.expires_at, Into::into),
+        last_used: None,
+    };
+
+    let successor_api_key = store
+        .insert_api_key(successor_api_key)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert successor API key")?;
+
+    let rotated_api_key = store
+        .update_api_key(
+            merchant_id.to_owned(),
+            key_id.to_owned(),
+            storage::ApiKeyUpdate::Update {
+                name: None,
+                description: None,
+                expires_at: Some(Some(rotated_key_expiry)),
+                last_used: None,
+            },
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ApiKeyNotFound)?;
+
+    for api_key in [&successor_api_key, &rotated_api_key] {
+        let state_inner = state.clone();
+        let hashed_api_key = api_key.hashed_api_key.clone();
+        let merchant_id_inner = api_key.merchant_id.clone();
+        let key_id_inner = api_key.key_id.clone();
+        let expires_at = api_key.expires_at;
+
+        authentication::decision::spawn_tracked_job(
+            async move {
+                authentication::decision::add_api_key(
+                    &state_inner,
+                    hashed_api_key.into_inner().into(),
+                    merchant_id_inner,
+                    key_id_inner,
+                    expires_at.map(authentication::decision::convert_expiry),
+                )
+                .await
+            },
+            authentication::decision::ADD,
+        );
+    }
+
+    add_api_key_rotation_task(store, &rotated_api_key, &successor_api_key.key_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert API key rotation task to process tracker")?;
+
+    #[cfg(feature = "email")]
+    {
+        // The rotated key is revoked at the end of the grace period, so the merchant should no
+        // longer be reminded about its expiry. Reminders are scheduled for the successor instead.
+        let task_id = generate_task_id_for_api_key_expiry_workflow(&key_id);
+        let existing_process_tracker_task = store
+            .find_process_by_id(task_id.as_str())
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError) // If retrieve failed
+            .attach_printable(
+                "Failed to retrieve API key expiry reminder task from process tracker",
+            )?;
+
+        if existing_process_tracker_task.is_some() {
+            revoke_api_key_expiry_task(store, &key_id)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable(
+                    "Failed to revoke API key expiry reminder task in process tracker",
+                )?;
+        }
+
+        if successor_api_key.expires_at.is_some() {
+            let expiry_reminder_days = api_key_config.expiry_reminder_days.clone();
+
+            add_api_key_expiry_task(store, &successor_api_key, expiry_reminder_days)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to insert API key expiry reminder to process tracker")?;
+        }
+    }
+
+    metrics::API_KEY_ROTATED.add(
+        &metrics::CONTEXT,
+        1,
+        &add_attributes([("merchant", merchant_id.get_string_repr().to_owned())]),
+    );
+
+    Ok(ApplicationResponse::Json(api::RotateApiKeyResponse {
+        successor: (successor_api_key, plaintext_api_key).foreign_into(),
+        rotated_key_id: rotated_api_key.key_id,
+        rotated_key_expiration: rotated_key_expiry,
+    }))
+}
+
+/// Computes the time at which a rotated API key stops being accepted.
+///
+/// The rotated key is never allowed to outlive the expiry it was originally created with.
+fn get_rotated_key_expiry(
+    current_time: time::PrimitiveDateTime,
+    expires_at: Option<time::PrimitiveDateTime>,
+    grace_period_in_secs: u32,
+) -> time::PrimitiveDateTime {
+    let grace_period_end =
+        current_time.saturating_add(time::Duration::seconds(i64::from(grace_period_in_secs)));
+    expires_at.map_or(grace_period_end, |expires_at| {
+        expires_at.min(grace_period_end)
+    })
+}
+
+// Add api_key_rotation task to the process_tracker table.
+// The task is scheduled at the end of the grace period of the rotated key, and revokes the rotated
+// key when executed.
+#[instrument(skip_all)]
+pub async fn add_api_key_rotation_task(
+    store: &dyn crate::db::StorageInterface,
+    rotated_api_key: &storage::ApiKey,
+    successor_key_id: &common_utils::id_type::ApiKeyId,
+) -> Result<(), errors::ProcessTrackerError> {
+    let revoke_at = rotated_api_key
+        .expires_at
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Rotated API key does not have an expiry set")?;
+
+    let api_key_rotation_tracker = storage::ApiKeyRotationTrackingData {
+        key_id: rotated_api_key.key_id.clone(),
+        merchant_id: rotated_api_key.merchant_id.clone(),
+        successor_key_id: successor_key_id.clone(),
+        revoke_at,
+    };
+
+    let process_tracker_id =
+        generate_task_id_for_api_key_rotation_workflow(&rotated_api_key.key_id);
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        API_KEY_ROTATION_NAME,
+        API_KEY_ROTATION_RUNNER,
+        [API_KEY_ROTATION_TAG],
+        api_key_rotation_tracker,
+        revoke_at,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct API key rotation process tracker task")?;
+
+    store
+        .insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while inserting API key rotation task to process_tracker: {:?}",
+                rotated_api_key.key_id
+            )
+        })?;
+    metrics::TASKS_ADDED_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &add_attributes([("flow", "ApiKeyRotation")]),
+    );
+
+    Ok(())
+}
+
+/// Records the time at which the API key was used for authenticating a request.
+///
+/// To avoid a database write on every request, the timestamp is only updated once the configured
+/// `last_used_update_interval_in_secs` has elapsed since the previously recorded usage.
+pub fn update_api_key_last_used(
+    store: Box<dyn crate::db::StorageInterface>,
+    api_key: &storage::ApiKey,
+    update_interval_in_secs: u32,
+) {
+    let current_time = date_time::now();
+    if !is_last_used_update_required(api_key.last_used, current_time, update_interval_in_secs) {
+        return;
+    }
+
+    let merchant_id = api_key.merchant_id.clone();
+    let key_id = api_key.key_id.clone();
+
+    tokio::spawn(async move {
+        if let Err(error) = store
+            .update_api_key(
+                merchant_id,
+                key_id,
+                storage::ApiKeyUpdate::LastUsedUpdate {
+                    last_used: current_time,
+                },
+            )
+            .await
+        {
+            router_env::logger::error!(?error, "Failed to update API key last used time");
+        }
+    });
+}
+
+fn is_last_used_update_required(
+    last_used: Option<time::PrimitiveDateTime>,
+    current_time: time::PrimitiveDateTime,
+    update_interval_in_secs: u32,
+) -> bool {
+    last_used.map_or(true, |last_used| {
+        last_used.saturating_add(time::Duration::seconds(i64::from(update_interval_in_secs)))
+            <= current_time
+    })
+}
+
 #[instrument(skip_all)]
 pub async fn list_api_keys(
     state: SessionState,
@@ -543,6 +826,15 @@ fn generate_task_id_for_api_key_expiry_workflow(
     )
 }
 
+fn generate_task_id_for_api_key_rotation_workflow(
+    key_id: &common_utils::id_type::ApiKeyId,
+) -> String {
+    format!(
+        "{API_KEY_ROTATION_RUNNER}_{API_KEY_ROTATION_NAME}_{}",
+        key_id.get_string_repr()
+    )
+}
+
 impl From<&str> for PlaintextApiKey {
     fn from(s: &str) -> Self {
         Self(s.to_owned().into())
@@ -570,7 +862,58 @@ impl From<storage::HashedApiKey> for HashedApiKey {
 #[cfg(test)]
 mod tests {
     #![allow(clippy::expect_used, clippy::unwrap_used)]
+    use std::sync::Arc;
+
+    use diesel_models::process_tracker::business_status;
+    use scheduler::workflows::ProcessTrackerWorkflow;
+    use time::macros::datetime;
+
     use super::*;
+    use crate::{
+        routes::{self, app::StorageImpl},
+        services,
+        workflows::api_key_rotation::ApiKeyRotationWorkflow,
+    };
+
+    async fn get_state() -> SessionState {
+        let (tx, _) = tokio::sync::oneshot::channel();
+        let app_state = Box::pin(routes::AppState::with_storage(
+            settings::Settings::new().expect("invalid settings"),
+            StorageImpl::Mock,
+            tx,
+            Box::new(services::MockApiClient),
+        ))
+        .await;
+        Arc::new(app_state)
+            .get_session_state("public", || {})
+            .unwrap()
+    }
+
+    async fn insert_api_key(
+        state: &SessionState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        expires_at: Option<time::PrimitiveDateTime>,
+    ) -> (storage::ApiKey, PlaintextApiKey) {
+        let hash_key = state.conf.api_keys.get_inner().get_hash_key().unwrap();
+        let plaintext_api_key = PlaintextApiKey::new(consts::API_KEY_LENGTH);
+        let api_key = state
+            .store
+            .insert_api_key(storage::ApiKeyNew {
+                key_id: PlaintextApiKey::new_key_id(),
+                merchant_id: merchant_id.clone(),
+                name: "rotated key".to_string(),
+                description: None,
+                hashed_api_key: plaintext_api_key.keyed_hash(hash_key.peek()).into(),
+                prefix: plaintext_api_key.prefix(),
+                created_at: date_time::now(),
+                expires_at,
+                last_used: None,
+            })
+            .await
+            .unwrap();
+
+        (api_key, plaintext_api_key)
+    }
 
     #[tokio::test]
     async fn test_hashing_and_verification() {
@@ -588,4 +931,147 @@ mod tests {
         let new_hashed_api_key = plaintext_api_key.keyed_hash(hash_key.peek());
         assert_eq!(hashed_api_key, new_hashed_api_key)
     }
+
+    #[test]
+    fn test_rotated_key_expiry_is_capped_by_grace_period() {
+        let current_time = datetime!(2024-01-01 0:00);
+
+        // Keys without an expiry remain valid for the whole grace period
+        assert_eq!(
+            get_rotated_key_expiry(current_time, None, 3600),
+            datetime!(2024-01-01 1:00)
+        );
+        // Keys expiring after the grace period are cut short at the end of the grace period
+        assert_eq!(
+            get_rotated_key_expiry(current_time, Some(datetime!(2024-02-01 0:00)), 3600),
+            datetime!(2024-01-01 1:00)
+        );
+        // Keys expiring during the grace period keep their original expiry
+        assert_eq!(
+            get_rotated_key_expiry(current_time, Some(datetime!(2024-01-01 0:30)), 3600),
+            datetime!(2024-01-01 0:30)
+        );
+    }
+
+    #[test]
+    fn test_last_used_update_is_throttled() {
+        let current_time = datetime!(2024-01-01 1:00);
+
+        assert!(is_last_used_update_required(None, current_time, 300));
+        assert!(!is_last_used_update_required(
+            Some(datetime!(2024-01-01 0:58)),
+            current_time,
+            300
+        ));
+        assert!(is_last_used_update_required(
+            Some(datetime!(2024-01-01 0:55)),
+            current_time,
+            300
+        ));
+        assert!(is_last_used_update_required(
+            Some(datetime!(2024-01-01 0:50)),
+            current_time,
+            300
+        ));
+    }
+
+    #[allow(clippy::panic)]
+    #[tokio::test]
+    async fn test_rotated_key_is_valid_until_rotation_task_revokes_it() {
+        let state = get_state().await;
+        let merchant_id =
+            common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1"))
+                .unwrap();
+        let (existing_api_key, plaintext_api_key) =
+            insert_api_key(&state, &merchant_id, None).await;
+        let hash_key = state.conf.api_keys.get_inner().get_hash_key().unwrap();
+        let hashed_api_key: storage::HashedApiKey =
+            plaintext_api_key.keyed_hash(hash_key.peek()).into();
+
+        let response = rotate_api_key(
+            state.clone(),
+            api::RotateApiKeyRequest {
+                grace_period_in_secs: Some(3600),
+                expiration: None,
+                key_id: existing_api_key.key_id.clone(),
+                merchant_id: merchant_id.clone(),
+            },
+        )
+        .await
+        .unwrap();
+        let ApplicationResponse::Json(response) = response else {
+            panic!("Unexpected response from API key rotation");
+        };
+
+        // The rotated key is still accepted during the grace period
+        let rotated_api_key = state
+            .store
+            .find_api_key_by_hash_optional(hashed_api_key.clone())
+            .await
+            .unwrap()
+            .expect("rotated API key revoked before the end of the grace period");
+        assert_eq!(
+            rotated_api_key.expires_at,
+            Some(response.rotated_key_expiration)
+        );
+        assert!(response.rotated_key_expiration > date_time::now());
+
+        // A key can only be rotated once
+        let result = rotate_api_key(
+            state.clone(),
+            api::RotateApiKeyRequest {
+                grace_period_in_secs: Some(3600),
+                expiration: None,
+                key_id: existing_api_key.key_id.clone(),
+                merchant_id: merchant_id.clone(),
+            },
+        )
+        .await;
+        assert!(result.is_err());
+
+        // The rotation task is scheduled to revoke the rotated key at the end of the grace period
+        let process = state
+            .store
+            .find_process_by_id(
+                generate_task_id_for_api_key_rotation_workflow(&existing_api_key.key_id).as_str(),
+            )
+            .await
+            .unwrap()
+            .expect("API key rotation task not scheduled");
+        assert_eq!(process.schedule_time, Some(response.rotated_key_expiration));
+
+        ApiKeyRotationWorkflow
+            .execute_workflow(&state, process.clone())
+            .await
+            .unwrap();
+
+        // Once the grace period ends, the rotated key is no longer accepted
+        assert!(state
+            .store
+            .find_api_key_by_hash_optional(hashed_api_key)
+            .await
+            .unwrap()
+            .is_none());
+        assert!(state
+            .store
+            .find_api_key_by_merchant_id_key_id_optional(&merchant_id, &response.successor.key_id)
+            .await
+            .unwrap()
+            .is_some());
+
+        let process = state
+            .store
+            .find_process_by_id(&process.id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            process.status,
+            diesel_models::enums::ProcessTrackerStatus::Finish
+        );
+        assert_eq!(
+            process.business_status,
+            business_status::COMPLETED_BY_PT.to_string()
+        );
+    }
 }
@@ -183,6 +183,7 @@ pub fn construct_router_data<F: Clone, Req, Res>(
         connector_response: None,
         integrity_check: Ok(()),
         additional_merchant_data: None,
+        merchant_connector_id: merchant_connector_account.get_mca_id(),
         header_payload: None,
     })
 }
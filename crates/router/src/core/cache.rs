@@ -1,6 +1,6 @@
 use common_utils::errors::CustomResult;
 use error_stack::{report, ResultExt};
-use storage_impl::redis::cache::{publish_into_redact_channel, CacheKind};
+use storage_impl::redis::cache::{self as redis_cache, publish_into_redact_channel, CacheKind};
 
 use super::errors;
 use crate::{routes::SessionState, services};
@@ -26,3 +26,29 @@ pub async fn invalidate(
             .attach_printable("Failed to invalidate cache"))
     }
 }
+
+pub async fn retrieve_stats() -> errors::RouterResponse<api_models::cache::CacheStatsResponse> {
+    let caches = futures::future::join_all(
+        redis_cache::get_all_caches()
+            .into_iter()
+            .map(|cache| cache.get_stats()),
+    )
+    .await
+    .into_iter()
+    .map(|stats| api_models::cache::CacheStats {
+        name: stats.name.to_owned(),
+        entry_count: stats.entry_count,
+        weighted_size: stats.weighted_size,
+        max_capacity: stats.max_capacity,
+        hits: stats.hits,
+        stale_hits: stats.stale_hits,
+        misses: stats.misses,
+        coalesced_loads: stats.coalesced_loads,
+        evictions: stats.evictions,
+    })
+    .collect();
+
+    Ok(services::api::ApplicationResponse::Json(
+        api_models::cache::CacheStatsResponse { caches },
+    ))
+}
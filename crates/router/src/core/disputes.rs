@@ -7,6 +7,10 @@ use common_utils::ext_traits::{Encode, ValueExt};
 use error_stack::ResultExt;
 use router_env::{instrument, tracing};
 use strum::IntoEnumIterator;
+#[cfg(feature = "v1")]
+pub mod deadlines;
+#[cfg(feature = "v1")]
+pub mod evidence;
 pub mod transformers;
 
 use super::{
@@ -141,7 +145,6 @@ pub async fn accept_dispute(
     key_store: domain::MerchantKeyStore,
     req: disputes::DisputeId,
 ) -> RouterResponse<dispute_models::DisputeResponse> {
-    let db = &state.store;
     let dispute = state
         .store
         .find_dispute_by_merchant_id_dispute_id(merchant_account.get_id(), &req.dispute_id)
@@ -150,6 +153,23 @@ pub async fn accept_dispute(
             dispute_id: req.dispute_id,
         })?;
     core_utils::validate_profile_id_from_auth_layer(profile_id, &dispute)?;
+    let updated_dispute =
+        accept_dispute_with_connector(&state, &merchant_account, &key_store, dispute).await?;
+    let dispute_response = api_models::disputes::DisputeResponse::foreign_from(updated_dispute);
+    Ok(services::ApplicationResponse::Json(dispute_response))
+}
+
+/// Accepts the dispute at the connector, and updates the dispute with the status returned by the
+/// connector.
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+pub async fn accept_dispute_with_connector(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    dispute: diesel_models::dispute::Dispute,
+) -> errors::RouterResult<diesel_models::dispute::Dispute> {
+    let db = &state.store;
     let dispute_id = dispute.dispute_id.clone();
     common_utils::fp_utils::when(
         !(dispute.dispute_stage == storage_enums::DisputeStage::Dispute
@@ -167,10 +187,10 @@ pub async fn accept_dispute(
 
     let payment_intent = db
         .find_payment_intent_by_payment_id_merchant_id(
-            &(&state).into(),
+            &state.into(),
             &dispute.payment_id,
             merchant_account.get_id(),
-            &key_store,
+            key_store,
             merchant_account.storage_scheme,
         )
         .await
@@ -196,16 +216,16 @@ pub async fn accept_dispute(
         AcceptDisputeResponse,
     > = connector_data.connector.get_connector_integration();
     let router_data = core_utils::construct_accept_dispute_router_data(
-        &state,
+        state,
         &payment_intent,
         &payment_attempt,
-        &merchant_account,
-        &key_store,
+        merchant_account,
+        key_store,
         &dispute,
     )
     .await?;
     let response = services::execute_connector_processing_step(
-        &state,
+        state,
         connector_integration,
         &router_data,
         payments::CallConnectorAction::Trigger,
@@ -228,15 +248,10 @@ pub async fn accept_dispute(
         dispute_status: accept_dispute_response.dispute_status,
         connector_status: accept_dispute_response.connector_status.clone(),
     };
-    let updated_dispute = db
-        .update_dispute(dispute.clone(), update_dispute)
+    db.update_dispute(dispute.clone(), update_dispute)
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable_lazy(|| {
-            format!("Unable to update dispute with dispute_id: {dispute_id}")
-        })?;
-    let dispute_response = api_models::disputes::DisputeResponse::foreign_from(updated_dispute);
-    Ok(services::ApplicationResponse::Json(dispute_response))
+        .attach_printable_lazy(|| format!("Unable to update dispute with dispute_id: {dispute_id}"))
 }
 
 #[cfg(feature = "v2")]
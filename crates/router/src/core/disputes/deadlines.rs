@@ -0,0 +1,439 @@
+use std::{collections::HashMap, str::FromStr};
+
+use common_utils::{
+    date_time,
+    ext_traits::{StringExt, ValueExt},
+    id_type,
+    types::MinorUnit,
+};
+use diesel_models::{enums as storage_enums, process_tracker::business_status};
+use error_stack::ResultExt;
+use router_env::{instrument, logger, metrics::add_attributes, tracing};
+use time::PrimitiveDateTime;
+
+use crate::{
+    core::{
+        errors::{self, RouterResult, StorageErrorExt},
+        webhooks,
+    },
+    db::StorageInterface,
+    routes::{metrics, SessionState},
+    types::{api, domain, storage, transformers::ForeignInto},
+};
+
+const DISPUTE_DEADLINE_TAG: &str = "DISPUTE";
+const DISPUTE_DEADLINE_NAME: &str = "DISPUTE_DEADLINE";
+const DISPUTE_DEADLINE_RUNNER: diesel_models::ProcessTrackerRunner =
+    diesel_models::ProcessTrackerRunner::DisputeDeadlineWorkflow;
+
+/// Settings for tracking the response deadlines of the disputes of a merchant.
+///
+/// The settings can be configured in configs with the key returned by
+/// `MerchantId::get_dispute_deadline_config_key`.
+///
+/// ```json
+/// {
+///   "reminder_hours": [168, 72, 24],
+///   "auto_accept": {
+///     "hours_before_deadline": 12,
+///     "amount_thresholds": { "USD": 2000, "EUR": 2000 }
+///   }
+/// }
+/// ```
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(default)]
+pub struct DisputeDeadlineConfig {
+    /// Number of hours before the deadline at which the merchant is reminded about the dispute
+    pub reminder_hours: Vec<u16>,
+    /// Automatic acceptance of low value disputes, which is disabled if not set
+    pub auto_accept: Option<DisputeAutoAcceptConfig>,
+}
+
+impl Default for DisputeDeadlineConfig {
+    fn default() -> Self {
+        Self {
+            // Reminders are sent a week, three days and a day before the deadline
+            reminder_hours: vec![168, 72, 24],
+            auto_accept: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct DisputeAutoAcceptConfig {
+    /// Number of hours before the deadline at which disputes without any evidence are accepted
+    pub hours_before_deadline: u16,
+    /// Disputes with an amount below the threshold configured for their currency are accepted.
+    /// Disputes in currencies without a threshold are never accepted.
+    pub amount_thresholds: HashMap<storage_enums::Currency, MinorUnit>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DisputeDeadlineAction {
+    /// Remind the merchant that the given number of hours are left to respond to the dispute
+    Reminder { hours_left: u16 },
+    /// Accept the dispute, if it is eligible for automatic acceptance
+    AutoAccept,
+}
+
+impl DisputeDeadlineConfig {
+    /// Returns the actions to be taken for a dispute with the given deadline, along with the time
+    /// at which each of them is due, ordered by the time.
+    pub fn get_actions(
+        &self,
+        challenge_required_by: PrimitiveDateTime,
+    ) -> Vec<(PrimitiveDateTime, DisputeDeadlineAction)> {
+        let before_deadline = |hours: u16| {
+            challenge_required_by.saturating_sub(time::Duration::hours(i64::from(hours)))
+        };
+
+        let mut actions = self
+            .reminder_hours
+            .iter()
+            .map(|&hours_left| {
+                (
+                    before_deadline(hours_left),
+                    DisputeDeadlineAction::Reminder { hours_left },
+                )
+            })
+            .chain(self.auto_accept.as_ref().map(|auto_accept| {
+                (
+                    before_deadline(auto_accept.hours_before_deadline),
+                    DisputeDeadlineAction::AutoAccept,
+                )
+            }))
+            .collect::<Vec<_>>();
+        actions.sort_by_key(|(due_at, _)| *due_at);
+
+        actions
+    }
+}
+
+/// Get the dispute deadline settings of the merchant, falling back to the default settings if
+/// they have not been configured.
+#[instrument(skip_all)]
+pub async fn get_dispute_deadline_config(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+) -> DisputeDeadlineConfig {
+    let key = merchant_id.get_dispute_deadline_config_key();
+
+    let result = db
+        .find_config_by_key(&key)
+        .await
+        .map(|value| value.config)
+        .and_then(|config| {
+            config
+                .parse_struct("DisputeDeadlineConfig")
+                .change_context(errors::StorageError::DeserializationFailed)
+        });
+
+    result.unwrap_or_else(|error| {
+        if !error.current_context().is_db_not_found() {
+            logger::error!(?error, "Failed to read dispute deadline config `{key}`");
+        }
+        DisputeDeadlineConfig::default()
+    })
+}
+
+/// Disputes are accepted automatically only if no evidence has been attached to them, and their
+/// amount is below the threshold configured for their currency.
+pub fn is_eligible_for_auto_accept(
+    auto_accept: &DisputeAutoAcceptConfig,
+    dispute: &storage::Dispute,
+) -> RouterResult<bool> {
+    let evidence: api::DisputeEvidence = dispute
+        .evidence
+        .clone()
+        .parse_value("DisputeEvidence")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error while parsing dispute evidence record")?;
+    let has_evidence = [
+        &evidence.cancellation_policy,
+        &evidence.customer_communication,
+        &evidence.customer_signature,
+        &evidence.receipt,
+        &evidence.refund_policy,
+        &evidence.service_documentation,
+        &evidence.shipping_documentation,
+        &evidence.invoice_showing_distinct_transactions,
+        &evidence.recurring_transaction_agreement,
+        &evidence.uncategorized_file,
+    ]
+    .iter()
+    .any(|file_id| file_id.is_some());
+
+    let is_below_threshold = storage_enums::Currency::from_str(&dispute.currency)
+        .ok()
+        .and_then(|currency| auto_accept.amount_thresholds.get(&currency))
+        .is_some_and(|threshold| MinorUnit::new(dispute.dispute_amount) < *threshold);
+
+    Ok(!has_evidence && is_below_threshold)
+}
+
+/// Starts tracking the deadline of an open dispute. If the dispute is already being tracked, the
+/// tracking is restarted when the connector has changed the deadline of the dispute.
+#[instrument(skip_all)]
+pub async fn track_dispute_deadline(
+    db: &dyn StorageInterface,
+    dispute: &storage::Dispute,
+) -> RouterResult<()> {
+    let Some(challenge_required_by) = dispute.challenge_required_by else {
+        return Ok(());
+    };
+    if dispute.dispute_status != storage_enums::DisputeStatus::DisputeOpened
+        || challenge_required_by <= date_time::now()
+    {
+        return Ok(());
+    }
+
+    let task_id = generate_task_id_for_dispute_deadline_workflow(&dispute.dispute_id);
+    let tracking_data = storage::DisputeDeadlineTrackingData {
+        merchant_id: dispute.merchant_id.clone(),
+        dispute_id: dispute.dispute_id.clone(),
+        challenge_required_by,
+        last_reminder_hours: None,
+    };
+
+    let existing_process = db
+        .find_process_by_id(&task_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch dispute deadline task")?;
+
+    match existing_process {
+        // The task decides when the next reminder is due, so it is run right away
+        None => {
+            let process_tracker_entry = storage::ProcessTrackerNew::new(
+                task_id,
+                DISPUTE_DEADLINE_NAME,
+                DISPUTE_DEADLINE_RUNNER,
+                [DISPUTE_DEADLINE_TAG],
+                tracking_data,
+                date_time::now(),
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to construct dispute deadline task")?;
+
+            db.insert_process(process_tracker_entry)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to insert dispute deadline task")?;
+            metrics::TASKS_ADDED_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &add_attributes([("flow", "DisputeDeadline")]),
+            );
+        }
+        Some(process) => {
+            let tracked_deadline = process
+                .tracking_data
+                .clone()
+                .parse_value::<storage::DisputeDeadlineTrackingData>("DisputeDeadlineTrackingData")
+                .ok()
+                .map(|tracking_data| tracking_data.challenge_required_by);
+            if tracked_deadline == Some(challenge_required_by) {
+                return Ok(());
+            }
+
+            let tracking_data = serde_json::to_value(tracking_data)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to serialize dispute deadline tracking data")?;
+            db.as_scheduler()
+                .update_process(
+                    process,
+                    storage::ProcessTrackerUpdate::Update {
+                        name: None,
+                        retry_count: Some(0),
+                        schedule_time: Some(date_time::now()),
+                        tracking_data: Some(tracking_data),
+                        business_status: Some(String::from(business_status::PENDING)),
+                        status: Some(storage_enums::ProcessTrackerStatus::New),
+                        updated_at: Some(date_time::now()),
+                    },
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to reset dispute deadline task")?;
+            metrics::TASKS_RESET_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &add_attributes([("flow", "DisputeDeadline")]),
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Reminds the merchant about the deadline of the dispute, with an outgoing webhook and an email
+/// to the primary email address of the merchant.
+#[instrument(skip_all)]
+pub async fn send_dispute_deadline_reminder(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    dispute: &storage::Dispute,
+    hours_left: u16,
+) -> RouterResult<()> {
+    // Each reminder is a separate event, so that it is not deduplicated with earlier reminders
+    let primary_object_id = format!("{}_{hours_left}h", dispute.dispute_id);
+    trigger_dispute_webhook(
+        state,
+        merchant_account,
+        key_store,
+        dispute,
+        storage_enums::EventType::DisputeDeadlineApproaching,
+        primary_object_id,
+    )
+    .await?;
+
+    #[cfg(feature = "email")]
+    send_dispute_deadline_email(state, merchant_account, dispute, hours_left).await;
+
+    metrics::DISPUTE_DEADLINE_REMINDER_SENT_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &add_attributes([("connector", dispute.connector.clone())]),
+    );
+
+    Ok(())
+}
+
+/// Triggers an outgoing webhook for the dispute, to the business profile of the dispute.
+#[instrument(skip_all)]
+pub async fn trigger_dispute_webhook(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    dispute: &storage::Dispute,
+    event_type: storage_enums::EventType,
+    primary_object_id: String,
+) -> RouterResult<()> {
+    let Some(profile_id) = dispute.profile_id.as_ref() else {
+        logger::warn!(
+            dispute_id = %dispute.dispute_id,
+            "Dispute is not associated with a business profile, skipping outgoing webhook"
+        );
+        return Ok(());
+    };
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(&state.into(), key_store, profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?;
+
+    Box::pin(webhooks::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_account.clone(),
+        business_profile,
+        key_store,
+        event_type,
+        storage_enums::EventClass::Disputes,
+        primary_object_id,
+        storage_enums::EventObjectType::DisputeDetails,
+        api::OutgoingWebhookContent::DisputeDetails(Box::new(dispute.clone().foreign_into())),
+        Some(dispute.created_at),
+    ))
+    .await
+}
+
+/// Failures in sending the email are only logged, as the merchant is notified with an outgoing
+/// webhook as well.
+#[cfg(feature = "email")]
+async fn send_dispute_deadline_email(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    dispute: &storage::Dispute,
+    hours_left: u16,
+) {
+    use crate::{consts, services::email::types::DisputeDeadlineReminder, utils::OptionExt};
+
+    let result = async {
+        let email_id = merchant_account
+            .merchant_details
+            .clone()
+            .parse_value::<api::MerchantDetails>("MerchantDetails")
+            .change_context(errors::ApiErrorResponse::InternalServerError)?
+            .primary_email
+            .ok_or(errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "primary_email",
+            })?;
+        let email_contents = DisputeDeadlineReminder {
+            recipient_email: domain::UserEmail::from_pii_email(email_id)
+                .change_context(errors::ApiErrorResponse::InternalServerError)?,
+            subject: consts::EMAIL_SUBJECT_DISPUTE_DEADLINE,
+            dispute_id: dispute.dispute_id.clone(),
+            payment_id: dispute.payment_id.clone(),
+            challenge_required_by: dispute.challenge_required_by.unwrap_or(dispute.created_at),
+            hours_left,
+        };
+
+        state
+            .email_client
+            .clone()
+            .compose_and_send_email(
+                Box::new(email_contents),
+                state.conf.proxy.https_url.as_ref(),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+    }
+    .await;
+
+    if let Err(error) = result {
+        logger::error!(
+            ?error,
+            dispute_id = %dispute.dispute_id,
+            "Failed to send dispute deadline reminder email"
+        );
+    }
+}
+
+pub fn generate_task_id_for_dispute_deadline_workflow(dispute_id: &str) -> String {
+    format!("{DISPUTE_DEADLINE_RUNNER}_{DISPUTE_DEADLINE_NAME}_{dispute_id}")
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn test_get_dispute_deadline_actions() {
+        let config = DisputeDeadlineConfig {
+            reminder_hours: vec![24, 168, 72],
+            auto_accept: Some(DisputeAutoAcceptConfig {
+                hours_before_deadline: 12,
+                amount_thresholds: HashMap::new(),
+            }),
+        };
+
+        let actions = config.get_actions(datetime!(2024-11-15 12:00));
+
+        assert_eq!(
+            actions,
+            vec![
+                (
+                    datetime!(2024-11-08 12:00),
+                    DisputeDeadlineAction::Reminder { hours_left: 168 }
+                ),
+                (
+                    datetime!(2024-11-12 12:00),
+                    DisputeDeadlineAction::Reminder { hours_left: 72 }
+                ),
+                (
+                    datetime!(2024-11-14 12:00),
+                    DisputeDeadlineAction::Reminder { hours_left: 24 }
+                ),
+                (
+                    datetime!(2024-11-15 00:00),
+                    DisputeDeadlineAction::AutoAccept
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,643 @@
+use api_models::{disputes as dispute_models, payments::AdditionalPaymentData};
+use common_utils::{crypto::Encryptable, ext_traits::ValueExt, types::MinorUnit};
+use error_stack::ResultExt;
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, StorageErrorExt},
+        files,
+        payments::helpers,
+        utils as core_utils,
+    },
+    routes::SessionState,
+    services,
+    types::{
+        api::{self, disputes},
+        domain,
+        storage::{self, enums as storage_enums},
+    },
+};
+
+/// Maximum number of characters in a line of the receipt, beyond which the line is wrapped
+const RECEIPT_LINE_WIDTH: usize = 90;
+
+/// Maximum number of lines on a page of the receipt
+const RECEIPT_LINES_PER_PAGE: usize = 50;
+
+/// Details of the disputed payment, collected from the payment, the customer, the addresses,
+/// the authentication and the refunds of the payment.
+struct PaymentEvidenceDetails {
+    merchant_name: String,
+    payment_id: String,
+    payment_date: time::PrimitiveDateTime,
+    amount: String,
+    status: storage_enums::IntentStatus,
+    product_description: Option<String>,
+    order_items: Vec<String>,
+    payment_method: Option<String>,
+    customer_name: Option<String>,
+    customer_email: Option<String>,
+    customer_ip: Option<String>,
+    billing_address: Option<String>,
+    shipping_address: Option<String>,
+    authentication: Option<String>,
+    payment_checks: Option<String>,
+    refunds: Vec<String>,
+}
+
+/// Prepares a draft of the evidence for a dispute from the data stored for the disputed payment.
+///
+/// Unless a receipt has already been attached to the dispute, a receipt of the payment is rendered
+/// and stored as a file, which is referenced as the `receipt` of the draft. Neither the draft nor
+/// the receipt is attached to the dispute, so that the merchant can review and complete the draft
+/// before submitting it.
+#[instrument(skip(state))]
+pub async fn create_evidence_draft(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    key_store: domain::MerchantKeyStore,
+    req: disputes::DisputeId,
+) -> RouterResponse<dispute_models::SubmitEvidenceRequest> {
+    let dispute = state
+        .store
+        .find_dispute_by_merchant_id_dispute_id(merchant_account.get_id(), &req.dispute_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
+            dispute_id: req.dispute_id,
+        })?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &dispute)?;
+
+    let details =
+        get_payment_evidence_details(&state, &merchant_account, &key_store, &dispute).await?;
+
+    let dispute_evidence: api::DisputeEvidence = dispute
+        .evidence
+        .clone()
+        .parse_value("DisputeEvidence")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error while parsing dispute evidence record")?;
+    // A receipt already attached by the merchant is kept, as it is likely more complete than the
+    // generated one
+    let receipt_file_id = match dispute_evidence.receipt {
+        Some(receipt_file_id) => receipt_file_id,
+        None => {
+            let receipt = render_pdf(&get_receipt_lines(&details));
+            let create_file_request = api::CreateFileRequest {
+                file_size: i32::try_from(receipt.len())
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Receipt is too large")?,
+                file: receipt,
+                file_name: Some(format!("receipt_{}.pdf", details.payment_id)),
+                file_type: mime::APPLICATION_PDF,
+                purpose: api::FilePurpose::DisputeEvidence,
+                dispute_id: Some(dispute.dispute_id.clone()),
+            };
+            let create_file_response = Box::pin(files::files_create_core(
+                state,
+                merchant_account,
+                key_store,
+                create_file_request,
+            ))
+            .await?;
+            match create_file_response {
+                services::ApplicationResponse::Json(response) => response.file_id,
+                _ => Err(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Unexpected response received from files create core")?,
+            }
+        }
+    };
+
+    let statements = [details.authentication, details.payment_checks]
+        .into_iter()
+        .flatten()
+        .chain(
+            details
+                .refunds
+                .iter()
+                .map(|refund| format!("Refund: {refund}")),
+        )
+        .collect::<Vec<_>>();
+
+    Ok(services::ApplicationResponse::Json(
+        dispute_models::SubmitEvidenceRequest {
+            dispute_id: dispute.dispute_id,
+            billing_address: details.billing_address,
+            customer_email_address: details.customer_email,
+            customer_name: details.customer_name,
+            customer_purchase_ip: details.customer_ip,
+            product_description: details.product_description,
+            receipt: Some(receipt_file_id),
+            shipping_address: details.shipping_address,
+            uncategorized_text: (!statements.is_empty()).then(|| statements.join("\n")),
+            ..Default::default()
+        },
+    ))
+}
+
+async fn get_payment_evidence_details(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    dispute: &storage::Dispute,
+) -> errors::RouterResult<PaymentEvidenceDetails> {
+    let db = &*state.store;
+    let key_manager_state = &state.into();
+    let merchant_id = merchant_account.get_id();
+    let storage_scheme = merchant_account.storage_scheme;
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            key_manager_state,
+            &dispute.payment_id,
+            merchant_id,
+            key_store,
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+    let payment_attempt = db
+        .find_payment_attempt_by_attempt_id_merchant_id(
+            &dispute.attempt_id,
+            merchant_id,
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let customer = match payment_intent.customer_id.as_ref() {
+        Some(customer_id) => db
+            .find_customer_optional_by_customer_id_merchant_id(
+                key_manager_state,
+                customer_id,
+                merchant_id,
+                key_store,
+                storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch customer of the disputed payment")?,
+        None => None,
+    };
+    let billing_address = helpers::get_address_by_id(
+        state,
+        payment_intent
+            .billing_address_id
+            .clone()
+            .or(payment_attempt.payment_method_billing_address_id.clone()),
+        key_store,
+        &payment_intent.payment_id,
+        merchant_id,
+        storage_scheme,
+    )
+    .await?;
+    let shipping_address = helpers::get_address_by_id(
+        state,
+        payment_intent.shipping_address_id.clone(),
+        key_store,
+        &payment_intent.payment_id,
+        merchant_id,
+        storage_scheme,
+    )
+    .await?;
+
+    let authentication = match payment_attempt.authentication_id.clone() {
+        Some(authentication_id) => Some(
+            db.find_authentication_by_merchant_id_authentication_id(merchant_id, authentication_id)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch authentication of the disputed payment")?,
+        ),
+        None => None,
+    };
+
+    let refunds = match db
+        .find_refund_by_payment_id_merchant_id(
+            &payment_intent.payment_id,
+            merchant_id,
+            storage_scheme,
+        )
+        .await
+    {
+        Ok(refunds) => refunds,
+        Err(error) if error.current_context().is_db_not_found() => Vec::new(),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch refunds of the disputed payment")?,
+    };
+
+    let card = payment_attempt
+        .payment_method_data
+        .clone()
+        .and_then(|data| match data {
+            serde_json::Value::Null => None,
+            _ => Some(data.parse_value::<AdditionalPaymentData>("AdditionalPaymentData")),
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the additional payment data of the disputed payment")?
+        .and_then(|data| match data {
+            AdditionalPaymentData::Card(card) => Some(card),
+            _ => None,
+        });
+
+    let order_details = payment_intent
+        .order_details
+        .iter()
+        .flatten()
+        .filter_map(|order_detail| {
+            order_detail
+                .peek()
+                .clone()
+                .parse_value::<api_models::payments::OrderDetailsWithAmount>(
+                    "OrderDetailsWithAmount",
+                )
+                .ok()
+        })
+        .collect::<Vec<_>>();
+    let order_items = order_details
+        .iter()
+        .map(|item| {
+            format!(
+                "{} x {} at {}",
+                item.quantity,
+                item.product_name,
+                format_amount(item.amount, payment_intent.currency)
+            )
+        })
+        .collect::<Vec<_>>();
+    let product_description = payment_intent.description.clone().or_else(|| {
+        (!order_details.is_empty()).then(|| {
+            order_details
+                .iter()
+                .map(|item| item.product_name.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        })
+    });
+
+    let payment_method = match card.as_ref() {
+        Some(card) => Some(format!(
+            "{} card ending in {}",
+            card.card_network
+                .as_ref()
+                .map(ToString::to_string)
+                .unwrap_or_else(|| "Card".to_owned()),
+            card.last4.clone().unwrap_or_default()
+        )),
+        None => payment_attempt
+            .payment_method_type
+            .map(|payment_method_type| payment_method_type.to_string())
+            .or(payment_attempt
+                .payment_method
+                .map(|payment_method| payment_method.to_string())),
+    };
+
+    let billing_name = billing_address.as_ref().and_then(|address| {
+        let name = [&address.first_name, &address.last_name]
+            .into_iter()
+            .filter_map(peek_encryptable)
+            .collect::<Vec<_>>()
+            .join(" ");
+        (!name.is_empty()).then_some(name)
+    });
+    let customer_name = customer
+        .as_ref()
+        .and_then(|customer| peek_encryptable(&customer.name))
+        .or(billing_name)
+        .or_else(|| {
+            card.as_ref()
+                .and_then(|card| card.card_holder_name.as_ref())
+                .map(|name| name.peek().to_owned())
+        });
+    let customer_email = customer
+        .as_ref()
+        .and_then(|customer| peek_encryptable(&customer.email))
+        .or_else(|| {
+            billing_address
+                .as_ref()
+                .and_then(|address| peek_encryptable(&address.email))
+        });
+    let customer_ip = payment_attempt
+        .browser_info
+        .clone()
+        .and_then(|browser_info| {
+            browser_info
+                .parse_value::<common_utils::types::BrowserInformation>("BrowserInformation")
+                .ok()
+        })
+        .and_then(|browser_info| browser_info.ip_address)
+        .map(|ip_address| ip_address.to_string());
+
+    let authentication = match authentication {
+        Some(authentication) => Some(format!(
+            "The payment was authenticated with 3D Secure{} by {}. Authentication status: {}, \
+             transaction status: {}, ECI: {}.",
+            authentication
+                .message_version
+                .as_ref()
+                .map(|version| format!(" {version}"))
+                .unwrap_or_default(),
+            authentication.authentication_connector,
+            authentication.authentication_status,
+            authentication
+                .trans_status
+                .as_ref()
+                .map(ToString::to_string)
+                .unwrap_or_else(|| "unknown".to_owned()),
+            authentication.eci.as_deref().unwrap_or("unknown"),
+        )),
+        None => match payment_attempt.authentication_type {
+            Some(storage_enums::AuthenticationType::ThreeDs) => Some(format!(
+                "The payment was authenticated with 3D Secure by {}.{}",
+                payment_attempt.connector.clone().unwrap_or_default(),
+                card.as_ref()
+                    .and_then(|card| card.authentication_data.as_ref())
+                    .map(|data| format!(" Authentication details: {data}"))
+                    .unwrap_or_default()
+            )),
+            _ => None,
+        },
+    };
+    let payment_checks = card
+        .as_ref()
+        .and_then(|card| card.payment_checks.as_ref())
+        .map(|payment_checks| {
+            format!(
+                "Address and security code verification results from {}: {payment_checks}",
+                payment_attempt.connector.clone().unwrap_or_default()
+            )
+        });
+
+    let refunds = refunds
+        .iter()
+        .map(|refund| {
+            format!(
+                "{} of {} {} on {} UTC ({})",
+                refund.refund_id,
+                format_amount(refund.refund_amount, Some(refund.currency)),
+                refund.currency,
+                refund.created_at,
+                refund.refund_status
+            )
+        })
+        .collect();
+
+    Ok(PaymentEvidenceDetails {
+        merchant_name: peek_encryptable(&merchant_account.merchant_name)
+            .unwrap_or_else(|| merchant_id.get_string_repr().to_owned()),
+        payment_id: payment_intent.payment_id.get_string_repr().to_owned(),
+        payment_date: payment_intent.created_at,
+        amount: format!(
+            "{} {}",
+            format_amount(payment_intent.amount, payment_intent.currency),
+            payment_intent
+                .currency
+                .as_ref()
+                .map(ToString::to_string)
+                .unwrap_or_default()
+        ),
+        status: payment_intent.status,
+        product_description,
+        order_items,
+        payment_method,
+        customer_name,
+        customer_email,
+        customer_ip,
+        billing_address: billing_address.as_ref().and_then(format_address),
+        shipping_address: shipping_address.as_ref().and_then(format_address),
+        authentication,
+        payment_checks,
+        refunds,
+    })
+}
+
+fn get_receipt_lines(details: &PaymentEvidenceDetails) -> Vec<String> {
+    let mut lines = vec![
+        "Payment Receipt".to_owned(),
+        String::new(),
+        format!("Merchant: {}", details.merchant_name),
+        format!("Payment ID: {}", details.payment_id),
+        format!("Date: {} UTC", details.payment_date),
+        format!("Amount: {}", details.amount),
+        format!("Status: {}", details.status),
+    ];
+    let optional_lines = [
+        ("Description", &details.product_description),
+        ("Payment method", &details.payment_method),
+        ("Customer", &details.customer_name),
+        ("Email", &details.customer_email),
+        ("IP address", &details.customer_ip),
+        ("Billing address", &details.billing_address),
+        ("Shipping address", &details.shipping_address),
+    ];
+    lines.extend(
+        optional_lines
+            .into_iter()
+            .filter_map(|(label, value)| value.as_ref().map(|value| format!("{label}: {value}"))),
+    );
+
+    if !details.order_items.is_empty() {
+        lines.push(String::new());
+        lines.push("Items:".to_owned());
+        lines.extend(details.order_items.iter().map(|item| format!("  {item}")));
+    }
+    if let Some(authentication) = details.authentication.as_ref() {
+        lines.push(String::new());
+        lines.push(authentication.clone());
+    }
+    if !details.refunds.is_empty() {
+        lines.push(String::new());
+        lines.push("Refunds:".to_owned());
+        lines.extend(details.refunds.iter().map(|refund| format!("  {refund}")));
+    }
+
+    lines
+        .iter()
+        .flat_map(|line| wrap_line(line, RECEIPT_LINE_WIDTH))
+        .collect()
+}
+
+fn peek_encryptable<S>(value: &Option<Encryptable<Secret<String, S>>>) -> Option<String>
+where
+    S: masking::Strategy<String>,
+{
+    value
+        .as_ref()
+        .map(|value| value.get_inner().peek().trim().to_owned())
+        .filter(|value| !value.is_empty())
+}
+
+fn format_address(address: &domain::Address) -> Option<String> {
+    let parts = [
+        peek_encryptable(&address.line1),
+        peek_encryptable(&address.line2),
+        peek_encryptable(&address.line3),
+        address.city.clone(),
+        peek_encryptable(&address.state),
+        peek_encryptable(&address.zip),
+        address.country.map(|country| country.to_string()),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>();
+
+    (!parts.is_empty()).then(|| parts.join(", "))
+}
+
+fn format_amount(amount: MinorUnit, currency: Option<storage_enums::Currency>) -> String {
+    currency
+        .and_then(|currency| {
+            currency
+                .to_currency_base_unit(amount.get_amount_as_i64())
+                .ok()
+        })
+        .unwrap_or_else(|| amount.to_string())
+}
+
+/// Splits the line at whitespace into lines of at most `width` characters. Words longer than the
+/// width are placed on a line of their own.
+fn wrap_line(line: &str, width: usize) -> Vec<String> {
+    let indent = line.len() - line.trim_start().len();
+    let mut lines = Vec::new();
+    let mut current = line.get(..indent).unwrap_or_default().to_owned();
+
+    for word in line.split_whitespace() {
+        if current.trim().is_empty() || current.len() + word.len() < width {
+            if !current.trim().is_empty() {
+                current.push(' ');
+            }
+            current.push_str(word);
+        } else {
+            lines.push(std::mem::take(&mut current));
+            current.push_str(word);
+        }
+    }
+    lines.push(current);
+
+    lines
+}
+
+/// Renders the lines of text as a PDF document, using the standard Helvetica font so that no
+/// fonts have to be embedded. Characters outside printable ASCII are replaced with `?`.
+fn render_pdf(lines: &[String]) -> Vec<u8> {
+    let pages = lines.chunks(RECEIPT_LINES_PER_PAGE).collect::<Vec<_>>();
+    let page_count = pages.len().max(1);
+
+    // Objects 1 to 3 are the catalog, the page tree and the font, which are followed by a page
+    // object and a content stream for each page
+    let kids = (0..page_count)
+        .map(|page| format!("{} 0 R", 4 + 2 * page))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let mut objects = vec![
+        "<< /Type /Catalog /Pages 2 0 R >>".to_owned(),
+        format!("<< /Type /Pages /Kids [{kids}] /Count {page_count} >>"),
+        "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica >>".to_owned(),
+    ];
+    for (page, page_lines) in pages.iter().enumerate() {
+        let text = page_lines
+            .iter()
+            .map(|line| format!("({}) Tj T*", escape_pdf_text(line)))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let content = format!("BT\n/F1 10 Tf\n14 TL\n50 800 Td\n{text}\nET");
+        objects.push(format!(
+            "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 595 842] \
+             /Resources << /Font << /F1 3 0 R >> >> /Contents {} 0 R >>",
+            5 + 2 * page
+        ));
+        objects.push(format!(
+            "<< /Length {} >>\nstream\n{content}\nendstream",
+            content.len()
+        ));
+    }
+
+    let mut document = String::from("%PDF-1.4\n");
+    let mut offsets = Vec::with_capacity(objects.len());
+    for (index, object) in objects.iter().enumerate() {
+        offsets.push(document.len());
+        document.push_str(&format!("{} 0 obj\n{object}\nendobj\n", index + 1));
+    }
+
+    let xref_offset = document.len();
+    document.push_str(&format!(
+        "xref\n0 {}\n0000000000 65535 f \n",
+        objects.len() + 1
+    ));
+    for offset in offsets {
+        document.push_str(&format!("{offset:010} 00000 n \n"));
+    }
+    document.push_str(&format!(
+        "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{xref_offset}\n%%EOF\n",
+        objects.len() + 1
+    ));
+
+    document.into_bytes()
+}
+
+fn escape_pdf_text(text: &str) -> String {
+    text.chars().fold(
+        String::with_capacity(text.len()),
+        |mut escaped, character| {
+            match character {
+                '\\' | '(' | ')' => {
+                    escaped.push('\\');
+                    escaped.push(character);
+                }
+                ' '..='~' => escaped.push(character),
+                _ => escaped.push('?'),
+            }
+            escaped
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wrap_line() {
+        assert_eq!(
+            wrap_line("  Refund ref_1 of 10.00 USD", 16),
+            vec!["  Refund ref_1", "of 10.00 USD"]
+        );
+        assert_eq!(wrap_line("", 16), vec![""]);
+    }
+
+    #[test]
+    fn test_render_pdf() {
+        let lines = (0..60)
+            .map(|line| format!("Line {line} (café)"))
+            .collect::<Vec<_>>();
+        let document = String::from_utf8(render_pdf(&lines)).unwrap_or_default();
+
+        assert!(document.starts_with("%PDF-1.4\n"));
+        assert!(document.ends_with("%%EOF\n"));
+        assert!(document.contains("/Count 2"));
+        assert!(document.contains("(Line 59 \\(caf?\\)) Tj T*"));
+
+        // The cross reference table has to point to the start of each object
+        let xref_offset = document
+            .rsplit("startxref\n")
+            .next()
+            .and_then(|tail| tail.lines().next())
+            .and_then(|offset| offset.parse::<usize>().ok())
+            .unwrap_or_default();
+        let xref = document.get(xref_offset..).unwrap_or_default();
+        assert!(xref.starts_with("xref\n0 8\n"));
+        for (index, entry) in xref.lines().skip(3).take(7).enumerate() {
+            let offset = entry
+                .get(..10)
+                .and_then(|offset| offset.parse::<usize>().ok())
+                .unwrap_or_default();
+            assert!(document
+                .get(offset..)
+                .unwrap_or_default()
+                .starts_with(&format!("{} 0 obj", index + 1)));
+        }
+    }
+}
@@ -196,6 +196,16 @@ pub enum WebhooksFlowError {
     OutgoingWebhookRetrySchedulingFailed,
     #[error("Outgoing webhook response encoding failed")]
     OutgoingWebhookResponseEncodingFailed,
+    #[error("Webhook endpoint not found")]
+    WebhookEndpointNotFound,
+    #[error("Webhook endpoint is disabled")]
+    WebhookEndpointDisabled,
+    #[error("Webhook endpoint updation failed")]
+    WebhookEndpointUpdationFailed,
+    #[error("Failed to generate event sequence number")]
+    EventSequenceNumberGenerationFailed,
+    #[error("Failed to check the delivery order of events")]
+    EventOrderingCheckFailed,
 }
 
 impl WebhooksFlowError {
@@ -204,9 +214,14 @@ impl WebhooksFlowError {
             Self::MerchantConfigNotFound
             | Self::MerchantWebhookDetailsNotFound
             | Self::MerchantWebhookUrlNotConfigured
-            | Self::OutgoingWebhookResponseEncodingFailed => false,
+            | Self::OutgoingWebhookResponseEncodingFailed
+            | Self::WebhookEndpointNotFound
+            | Self::WebhookEndpointDisabled => false,
 
             Self::WebhookEventUpdationFailed
+            | Self::WebhookEndpointUpdationFailed
+            | Self::EventSequenceNumberGenerationFailed
+            | Self::EventOrderingCheckFailed
             | Self::OutgoingWebhookSigningFailed
             | Self::CallToMerchantFailed
             | Self::NotReceivedByMerchant
@@ -0,0 +1,703 @@
+use api_models::analytics::exports as export_types;
+use common_utils::date_time;
+use diesel_models::{enums as storage_enums, process_tracker::business_status};
+use error_stack::{report, ResultExt};
+#[cfg(feature = "v1")]
+use hyperswitch_domain_models::payments::payment_intent::PaymentIntentFetchConstraints;
+use hyperswitch_domain_models::{disputes::DisputeListConstraints, refunds::RefundListConstraints};
+use router_env::{instrument, logger, metrics::add_attributes, tracing};
+
+use crate::{
+    consts,
+    core::{
+        analytics_reports::{self, ReportWriter},
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    },
+    routes::{metrics, SessionState},
+    services::ApplicationResponse,
+    types::{api, domain, storage, transformers::ForeignFrom},
+};
+
+const EXPORT_JOB_TAG: &str = "EXPORT_JOB";
+const EXPORT_JOB_NAME: &str = "EXPORT_JOB";
+const EXPORT_JOB_RUNNER: diesel_models::ProcessTrackerRunner =
+    diesel_models::ProcessTrackerRunner::ExportJobWorkflow;
+
+/// The number of records fetched from the database at a time while exporting a list.
+const EXPORT_PAGE_SIZE: u32 = 1000;
+
+/// The maximum number of records which can be exported to a single file.
+const MAX_EXPORT_ROWS: usize = 100_000;
+
+/// The maximum number of records which can be exported to a single parquet file. Parquet files are
+/// assembled in memory once all the records of the export are written, so they are capped lower.
+const MAX_PARQUET_EXPORT_ROWS: usize = 20_000;
+
+const DEFAULT_EXPORT_LIST_LIMIT: i64 = 20;
+
+/// The constraints of an export, parsed into the request type of the list endpoint or the metrics
+/// request of the analytics domain.
+enum ExportConstraints {
+    Payments(Box<api::PaymentListFilterConstraints>),
+    Refunds(api_models::refunds::RefundListRequest),
+    Disputes(api_models::disputes::DisputeListGetConstraints),
+    AnalyticsMetrics(analytics_reports::ReportMetricRequest),
+}
+
+#[instrument(skip_all)]
+pub async fn create_export(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: export_types::ExportCreateRequest,
+) -> RouterResponse<export_types::ExportResponse> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_account.get_id();
+
+    parse_export_constraints(req.export_type, req.analytics_domain, &req.constraints)?;
+    if let Some(profile_id) = &req.profile_id {
+        db.find_business_profile_by_merchant_id_profile_id(
+            &(&state).into(),
+            &key_store,
+            merchant_id,
+            profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?;
+    }
+
+    let now = date_time::now();
+    let export_new = storage::ExportJobNew {
+        export_id: common_utils::generate_id(consts::ID_LENGTH, "exp"),
+        merchant_id: merchant_id.to_owned(),
+        organization_id: merchant_account.get_org_id().to_owned(),
+        profile_id: req.profile_id,
+        export_type: req.export_type,
+        analytics_domain: req.analytics_domain,
+        format: req.format,
+        constraints: req.constraints,
+        recipients: req.recipients,
+        status: storage_enums::ExportStatus::Pending,
+        file_key: None,
+        row_count: None,
+        error_message: None,
+        created_at: now,
+        modified_at: now,
+        completed_at: None,
+    };
+    let export = db
+        .insert_export_job(export_new)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert export job")?;
+
+    add_export_task(&state, &export).await?;
+
+    Ok(ApplicationResponse::Json(export_response(&state, export)))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_export(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    export_id: String,
+) -> RouterResponse<export_types::ExportResponse> {
+    let export = find_export(&state, merchant_account.get_id(), &export_id).await?;
+
+    Ok(ApplicationResponse::Json(export_response(&state, export)))
+}
+
+#[instrument(skip_all)]
+pub async fn list_exports(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    req: export_types::ExportListRequest,
+) -> RouterResponse<Vec<export_types::ExportResponse>> {
+    let exports = state
+        .store
+        .list_export_jobs_by_merchant_id(
+            merchant_account.get_id(),
+            Some(req.limit.unwrap_or(DEFAULT_EXPORT_LIST_LIMIT)),
+            req.offset,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list export jobs")?;
+
+    Ok(ApplicationResponse::Json(
+        exports
+            .into_iter()
+            .map(|export| export_response(&state, export))
+            .collect(),
+    ))
+}
+
+/// Returns the exported file of a completed export.
+#[instrument(skip_all)]
+pub async fn download_export(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    export_id: String,
+) -> RouterResponse<serde_json::Value> {
+    let export = find_export(&state, merchant_account.get_id(), &export_id).await?;
+    let file_key = export
+        .file_key
+        .filter(|_| export.status == storage_enums::ExportStatus::Completed)
+        .ok_or(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!("Export `{export_id}` has not been completed"),
+        })?;
+
+    let file_data = state
+        .file_storage_client
+        .retrieve_file(&file_key)
+        .await
+        .change_context(errors::ApiErrorResponse::FileNotAvailable)
+        .attach_printable("Failed to retrieve the exported file")?;
+    let content_type = match export.format {
+        storage_enums::ExportFormat::Csv => mime::TEXT_CSV,
+        storage_enums::ExportFormat::Parquet => mime::APPLICATION_OCTET_STREAM,
+    };
+
+    Ok(ApplicationResponse::FileData((file_data, content_type)))
+}
+
+/// Exports all the records matching the constraints of the export to a file in the file storage,
+/// and notifies the recipients of the export once the file is available.
+#[instrument(skip_all)]
+pub async fn run_export(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    export: &storage::ExportJob,
+) -> RouterResult<()> {
+    let db = state.store.as_ref();
+    db.update_export_job_by_merchant_id_export_id(
+        &export.merchant_id,
+        &export.export_id,
+        storage::ExportJobUpdate::StatusUpdate {
+            status: storage_enums::ExportStatus::Processing,
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update export job")?;
+
+    let mut writer = ReportWriter::new();
+    if let Err(error) =
+        write_export_rows(state, merchant_account, key_store, export, &mut writer).await
+    {
+        // Exports which are invalid, such as those matching too many records, are not retried
+        return match error.current_context() {
+            errors::ApiErrorResponse::InvalidRequestData { message } => {
+                let tracking_data = storage::ExportJobTrackingData {
+                    merchant_id: export.merchant_id.clone(),
+                    export_id: export.export_id.clone(),
+                };
+                fail_export(state, &tracking_data, message.clone()).await
+            }
+            _ => Err(error),
+        };
+    }
+    let row_count = i32::try_from(writer.row_count())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to convert the number of exported rows")?;
+
+    let (file_data, extension) = match export.format {
+        storage_enums::ExportFormat::Csv => (writer.into_csv()?, "csv"),
+        storage_enums::ExportFormat::Parquet => (writer.into_parquet()?, "parquet"),
+    };
+    let file_key = format!(
+        "exports/{}/{}.{extension}",
+        export.merchant_id.get_string_repr(),
+        export.export_id
+    );
+    state
+        .file_storage_client
+        .upload_file(&file_key, file_data)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to upload the exported file")?;
+
+    let export = db
+        .update_export_job_by_merchant_id_export_id(
+            &export.merchant_id,
+            &export.export_id,
+            storage::ExportJobUpdate::CompletionUpdate {
+                file_key,
+                row_count,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update export job")?;
+
+    logger::info!(export_id = %export.export_id, row_count, "Completed export");
+    metrics::EXPORTS_COMPLETED_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &add_attributes([
+            ("export_type", export.export_type.to_string()),
+            ("format", export.format.to_string()),
+        ]),
+    );
+
+    // The export is available for download even if the recipients could not be notified
+    if let Err(error) = send_export_email(state, &export, row_count).await {
+        logger::error!(?error, export_id = %export.export_id, "Failed to send export email");
+    }
+
+    Ok(())
+}
+
+/// Marks the export as failed, when it is invalid or once the task exporting it has exhausted its
+/// retries.
+pub async fn fail_export(
+    state: &SessionState,
+    tracking_data: &storage::ExportJobTrackingData,
+    error_message: String,
+) -> RouterResult<()> {
+    state
+        .store
+        .update_export_job_by_merchant_id_export_id(
+            &tracking_data.merchant_id,
+            &tracking_data.export_id,
+            storage::ExportJobUpdate::FailureUpdate { error_message },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update export job")?;
+    metrics::EXPORTS_FAILED_COUNT.add(&metrics::CONTEXT, 1, &[]);
+
+    Ok(())
+}
+
+/// Writes the records matching the constraints of the export to the writer, a page at a time.
+async fn write_export_rows(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    export: &storage::ExportJob,
+    writer: &mut ReportWriter,
+) -> RouterResult<()> {
+    let constraints = parse_export_constraints(
+        export.export_type,
+        export.analytics_domain,
+        &export.constraints,
+    )?;
+
+    match constraints {
+        #[cfg(feature = "v1")]
+        ExportConstraints::Payments(constraints) => {
+            write_payment_rows(
+                state,
+                merchant_account,
+                key_store,
+                export,
+                *constraints,
+                writer,
+            )
+            .await
+        }
+        #[cfg(feature = "v2")]
+        ExportConstraints::Payments(_) => Err(report!(errors::ApiErrorResponse::NotSupported {
+            message: "Exporting payments is not supported".to_owned(),
+        })),
+        ExportConstraints::Refunds(req) => {
+            write_refund_rows(state, merchant_account, export, req, writer).await
+        }
+        ExportConstraints::Disputes(constraints) => {
+            write_dispute_rows(state, merchant_account, export, constraints, writer).await
+        }
+        ExportConstraints::AnalyticsMetrics(metric_request) => {
+            let auth =
+                analytics_reports::get_auth_info(merchant_account, export.profile_id.as_ref());
+            let metrics = analytics_reports::get_metrics(state, &auth, metric_request).await?;
+            writer.write_rows(metrics)
+        }
+    }
+}
+
+#[cfg(feature = "v1")]
+async fn write_payment_rows(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    export: &storage::ExportJob,
+    constraints: api::PaymentListFilterConstraints,
+    writer: &mut ReportWriter,
+) -> RouterResult<()> {
+    let db = state.store.as_ref();
+    let mut fetch_constraints: PaymentIntentFetchConstraints =
+        (constraints, get_profile_id_list(export)).try_into()?;
+
+    loop {
+        // The limit of the constraints is capped to the maximum page size of the payments list,
+        // which is overridden to fetch larger pages
+        if let PaymentIntentFetchConstraints::List(params) = &mut fetch_constraints {
+            params.offset = get_page_offset(writer)?;
+            params.limit = Some(EXPORT_PAGE_SIZE);
+        }
+        let payments = db
+            .get_filtered_payment_intents_attempt(
+                &state.into(),
+                merchant_account.get_id(),
+                &fetch_constraints,
+                key_store,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch payments to export")?;
+        let is_last_page = !matches!(fetch_constraints, PaymentIntentFetchConstraints::List(_))
+            || is_last_page(payments.len());
+
+        let rows = payments
+            .into_iter()
+            .map(|payment| masked_row(&api::PaymentsResponse::foreign_from(payment)))
+            .collect::<RouterResult<Vec<_>>>()?;
+        writer.write_rows(rows)?;
+        validate_row_count(writer.row_count(), export.format)?;
+        if is_last_page {
+            return Ok(());
+        }
+    }
+}
+
+async fn write_refund_rows(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    export: &storage::ExportJob,
+    req: api_models::refunds::RefundListRequest,
+    writer: &mut ReportWriter,
+) -> RouterResult<()> {
+    let db = state.store.as_ref();
+    let constraints: RefundListConstraints = (req, get_profile_id_list(export)).try_into()?;
+
+    loop {
+        let refunds = db
+            .filter_refund_by_constraints(
+                merchant_account.get_id(),
+                &constraints,
+                merchant_account.storage_scheme,
+                i64::from(EXPORT_PAGE_SIZE),
+                i64::from(get_page_offset(writer)?),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch refunds to export")?;
+        let is_last_page = is_last_page(refunds.len());
+
+        let rows = refunds
+            .into_iter()
+            .map(|refund| masked_row(&api::RefundResponse::foreign_from(refund)))
+            .collect::<RouterResult<Vec<_>>>()?;
+        writer.write_rows(rows)?;
+        validate_row_count(writer.row_count(), export.format)?;
+        if is_last_page {
+            return Ok(());
+        }
+    }
+}
+
+async fn write_dispute_rows(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    export: &storage::ExportJob,
+    constraints: api_models::disputes::DisputeListGetConstraints,
+    writer: &mut ReportWriter,
+) -> RouterResult<()> {
+    let db = state.store.as_ref();
+    let mut constraints: DisputeListConstraints =
+        (constraints, get_profile_id_list(export)).try_into()?;
+
+    loop {
+        constraints.offset = Some(get_page_offset(writer)?);
+        constraints.limit = Some(EXPORT_PAGE_SIZE);
+        let disputes = db
+            .find_disputes_by_constraints(merchant_account.get_id(), &constraints)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch disputes to export")?;
+        let is_last_page = is_last_page(disputes.len());
+
+        let rows = disputes
+            .into_iter()
+            .map(|dispute| {
+                masked_row(&api_models::disputes::DisputeResponse::foreign_from(
+                    dispute,
+                ))
+            })
+            .collect::<RouterResult<Vec<_>>>()?;
+        writer.write_rows(rows)?;
+        validate_row_count(writer.row_count(), export.format)?;
+        if is_last_page {
+            return Ok(());
+        }
+    }
+}
+
+/// Serializes an exported record with its personal information masked according to the masking
+/// strategy of each field.
+fn masked_row<T: serde::Serialize>(record: &T) -> RouterResult<serde_json::Value> {
+    masking::masked_serialize(record)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize exported record")
+}
+
+fn get_page_offset(writer: &ReportWriter) -> RouterResult<u32> {
+    u32::try_from(writer.row_count())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to convert the number of exported rows")
+}
+
+fn is_last_page(page_len: usize) -> bool {
+    u32::try_from(page_len).map_or(true, |page_len| page_len < EXPORT_PAGE_SIZE)
+}
+
+fn validate_row_count(row_count: usize, format: storage_enums::ExportFormat) -> RouterResult<()> {
+    let max_export_rows = match format {
+        storage_enums::ExportFormat::Csv => MAX_EXPORT_ROWS,
+        storage_enums::ExportFormat::Parquet => MAX_PARQUET_EXPORT_ROWS,
+    };
+    if row_count > max_export_rows {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "The export matches more than {max_export_rows} records, narrow down its \
+                 constraints"
+            ),
+        }))?
+    }
+
+    Ok(())
+}
+
+fn get_profile_id_list(
+    export: &storage::ExportJob,
+) -> Option<Vec<common_utils::id_type::ProfileId>> {
+    export.profile_id.clone().map(|profile_id| vec![profile_id])
+}
+
+fn parse_export_constraints(
+    export_type: storage_enums::ExportType,
+    analytics_domain: Option<storage_enums::AnalyticsReportDomain>,
+    constraints: &serde_json::Value,
+) -> RouterResult<ExportConstraints> {
+    let invalid_constraints = |error: serde_json::Error| {
+        report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("Invalid `constraints` for a {export_type} export: {error}"),
+        })
+    };
+
+    match (export_type, analytics_domain) {
+        (storage_enums::ExportType::AnalyticsMetrics, Some(domain)) => {
+            analytics_reports::parse_metric_request(domain, constraints.clone())
+                .map(ExportConstraints::AnalyticsMetrics)
+        }
+        (storage_enums::ExportType::AnalyticsMetrics, None) => {
+            Err(report!(errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "analytics_domain",
+            }))
+        }
+        (_, Some(_)) => Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`analytics_domain` can only be provided for analytics metrics exports"
+                .to_owned(),
+        })),
+        (storage_enums::ExportType::Payments, None) => serde_json::from_value(constraints.clone())
+            .map(|constraints| ExportConstraints::Payments(Box::new(constraints)))
+            .map_err(invalid_constraints),
+        (storage_enums::ExportType::Refunds, None) => serde_json::from_value(constraints.clone())
+            .map(ExportConstraints::Refunds)
+            .map_err(invalid_constraints),
+        (storage_enums::ExportType::Disputes, None) => serde_json::from_value(constraints.clone())
+            .map(ExportConstraints::Disputes)
+            .map_err(invalid_constraints),
+    }
+}
+
+#[cfg(feature = "email")]
+async fn send_export_email(
+    state: &SessionState,
+    export: &storage::ExportJob,
+    row_count: i32,
+) -> RouterResult<()> {
+    use crate::services::email::types::ExportCompleted;
+
+    for recipient in export.recipients.iter() {
+        let email_contents = ExportCompleted {
+            recipient_email: domain::UserEmail::from_pii_email(recipient.clone())
+                .change_context(errors::ApiErrorResponse::InternalServerError)?,
+            subject: consts::EMAIL_SUBJECT_EXPORT_COMPLETED,
+            export_id: export.export_id.clone(),
+            export_type: export.export_type.to_string(),
+            format: export.format.to_string(),
+            row_count,
+            download_url: get_download_url(state, &export.export_id),
+        };
+
+        state
+            .email_client
+            .clone()
+            .compose_and_send_email(
+                Box::new(email_contents),
+                state.conf.proxy.https_url.as_ref(),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to send export email")?;
+    }
+
+    Ok(())
+}
+
+#[cfg(not(feature = "email"))]
+async fn send_export_email(
+    _state: &SessionState,
+    export: &storage::ExportJob,
+    _row_count: i32,
+) -> RouterResult<()> {
+    if !export.recipients.is_empty() {
+        logger::warn!(
+            export_id = %export.export_id,
+            "Email is not supported, the recipients of the export are not notified"
+        );
+    }
+
+    Ok(())
+}
+
+async fn find_export(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    export_id: &str,
+) -> RouterResult<storage::ExportJob> {
+    state
+        .store
+        .find_export_job_by_merchant_id_export_id(merchant_id, export_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Export `{export_id}` does not exist"),
+        })
+}
+
+fn get_download_url(state: &SessionState, export_id: &str) -> String {
+    format!(
+        "{}/analytics/v1/merchant/exports/{export_id}/download",
+        state.base_url
+    )
+}
+
+async fn add_export_task(state: &SessionState, export: &storage::ExportJob) -> RouterResult<()> {
+    let tracking_data = storage::ExportJobTrackingData {
+        merchant_id: export.merchant_id.clone(),
+        export_id: export.export_id.clone(),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        generate_task_id_for_export_job_workflow(&export.export_id),
+        EXPORT_JOB_NAME,
+        EXPORT_JOB_RUNNER,
+        [EXPORT_JOB_TAG],
+        tracking_data,
+        export.created_at,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct export task")?;
+
+    state
+        .store
+        .insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert export task")?;
+    metrics::TASKS_ADDED_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &add_attributes([("flow", "ExportJob")]),
+    );
+
+    Ok(())
+}
+
+/// Finishes the task of an export once the file has been exported.
+pub async fn finish_export_task(
+    state: &SessionState,
+    process: storage::ProcessTracker,
+) -> Result<(), errors::ProcessTrackerError> {
+    state
+        .store
+        .as_scheduler()
+        .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+        .await
+        .map_err(Into::into)
+}
+
+pub fn generate_task_id_for_export_job_workflow(export_id: &str) -> String {
+    format!("{EXPORT_JOB_RUNNER}_{EXPORT_JOB_NAME}_{export_id}")
+}
+
+fn export_response(
+    state: &SessionState,
+    export: storage::ExportJob,
+) -> export_types::ExportResponse {
+    let download_url = (export.status == storage_enums::ExportStatus::Completed)
+        .then(|| get_download_url(state, &export.export_id));
+
+    export_types::ExportResponse {
+        export_id: export.export_id,
+        merchant_id: export.merchant_id,
+        profile_id: export.profile_id,
+        export_type: export.export_type,
+        analytics_domain: export.analytics_domain,
+        format: export.format,
+        constraints: export.constraints,
+        status: export.status,
+        row_count: export.row_count,
+        error_message: export.error_message,
+        download_url,
+        created_at: export.created_at,
+        completed_at: export.completed_at,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_export_constraints() {
+        assert!(matches!(
+            parse_export_constraints(
+                storage_enums::ExportType::Refunds,
+                None,
+                &serde_json::json!({ "refund_status": ["success"] }),
+            ),
+            Ok(ExportConstraints::Refunds(_))
+        ));
+        assert!(parse_export_constraints(
+            storage_enums::ExportType::AnalyticsMetrics,
+            None,
+            &serde_json::json!({}),
+        )
+        .is_err());
+        assert!(parse_export_constraints(
+            storage_enums::ExportType::Disputes,
+            Some(storage_enums::AnalyticsReportDomain::Disputes),
+            &serde_json::json!({}),
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_export_paging() {
+        assert!(is_last_page(0));
+        assert!(is_last_page(999));
+        assert!(!is_last_page(1000));
+        let csv = storage_enums::ExportFormat::Csv;
+        assert!(validate_row_count(MAX_EXPORT_ROWS, csv).is_ok());
+        assert!(validate_row_count(MAX_EXPORT_ROWS + 1, csv).is_err());
+        let parquet = storage_enums::ExportFormat::Parquet;
+        assert!(validate_row_count(MAX_PARQUET_EXPORT_ROWS, parquet).is_ok());
+        assert!(validate_row_count(MAX_PARQUET_EXPORT_ROWS + 1, parquet).is_err());
+    }
+}
@@ -27,6 +27,8 @@ pub async fn get_file_purpose(field: &mut Field) -> Option<api::FilePurpose> {
     let purpose = read_string(field).await;
     match purpose.as_deref() {
         Some("dispute_evidence") => Some(api::FilePurpose::DisputeEvidence),
+        Some("payout_batch") => Some(api::FilePurpose::PayoutBatch),
+        Some("refund_batch") => Some(api::FilePurpose::RefundBatch),
         _ => None,
     }
 }
@@ -78,6 +80,21 @@ pub async fn validate_file_upload(
                 },
             }
         }
+        api::FilePurpose::PayoutBatch | api::FilePurpose::RefundBatch => {
+            let supported_file_types = ["text/csv", "application/json"];
+            if !supported_file_types.contains(&create_file_request.file_type.essence_str()) {
+                Err(errors::ApiErrorResponse::FileValidationFailed {
+                    reason: "file_type does not match CSV or JSON format".to_owned(),
+                })?
+            }
+            // 10 Megabytes (MB)
+            if create_file_request.file_size > 10000000 {
+                Err(errors::ApiErrorResponse::FileValidationFailed {
+                    reason: "file_size exceeded the max file size of 10MB".to_owned(),
+                })?
+            }
+            Ok(())
+        }
     }
 }
 
@@ -371,5 +388,18 @@ pub async fn upload_and_get_provider_provider_file_id_profile_id(
                 ))
             }
         }
+        api::FilePurpose::PayoutBatch | api::FilePurpose::RefundBatch => {
+            state
+                .file_storage_client
+                .upload_file(&file_key, create_file_request.file.clone())
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)?;
+            Ok((
+                file_key,
+                api_models::enums::FileUploadProvider::Router,
+                None,
+                None,
+            ))
+        }
     }
 }
@@ -159,6 +159,7 @@ impl ConstructFlowSpecificData<frm_api::Checkout, FraudCheckCheckoutData, FraudC
             connector_response: None,
             integrity_check: Ok(()),
             additional_merchant_data: None,
+            merchant_connector_id: None,
             header_payload,
         };
 
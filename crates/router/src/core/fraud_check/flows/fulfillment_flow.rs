@@ -126,6 +126,7 @@ pub async fn construct_fulfillment_router_data<'a>(
         connector_response: None,
         integrity_check: Ok(()),
         additional_merchant_data: None,
+        merchant_connector_id: None,
         header_payload: None,
     };
     Ok(router_data)
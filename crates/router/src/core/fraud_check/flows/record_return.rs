@@ -127,6 +127,7 @@ impl ConstructFlowSpecificData<RecordReturn, FraudCheckRecordReturnData, FraudCh
             connector_response: None,
             integrity_check: Ok(()),
             additional_merchant_data: None,
+            merchant_connector_id: None,
             header_payload,
         };
 
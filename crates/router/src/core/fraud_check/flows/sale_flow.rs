@@ -135,6 +135,7 @@ impl ConstructFlowSpecificData<frm_api::Sale, FraudCheckSaleData, FraudCheckResp
             connector_response: None,
             integrity_check: Ok(()),
             additional_merchant_data: None,
+            merchant_connector_id: None,
             header_payload,
         };
 
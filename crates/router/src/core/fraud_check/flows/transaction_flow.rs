@@ -144,6 +144,7 @@ impl
             connector_response: None,
             integrity_check: Ok(()),
             additional_merchant_data: None,
+            merchant_connector_id: None,
             header_payload,
         };
 
@@ -0,0 +1,557 @@
+use std::marker::PhantomData;
+
+use api_models::{
+    admin::PaymentMethodsEnabled,
+    payments::{InstallmentPlan, InstallmentPlanSelection},
+};
+use cards::CardNumber;
+use common_utils::id_type;
+use diesel_models::InstallmentPlanDetails;
+use error_stack::ResultExt;
+use hyperswitch_domain_models::{
+    merchant_connector_account::MerchantConnectorAccount, payment_address::PaymentAddress,
+    router_data::ErrorResponse,
+};
+use masking::ExposeInterface;
+use redis_interface::errors::RedisError;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResult},
+        payments::CallConnectorAction,
+        utils as core_utils,
+    },
+    routes::SessionState,
+    services,
+    types::{self, api, api::enums as api_enums, domain, storage},
+    utils::{OptionExt, ValueExt},
+};
+
+/// The installment plans offered for a payment, along with the card BIN they were offered for.
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+struct OfferedInstallmentPlans {
+    card_bin: String,
+    plans: Vec<InstallmentPlan>,
+}
+
+/// Checks whether installment payments are enabled for any of the card payment method types
+/// configured on the merchant connector account.
+fn is_installment_payment_enabled(merchant_connector_account: &MerchantConnectorAccount) -> bool {
+    merchant_connector_account
+        .payment_methods_enabled
+        .iter()
+        .flatten()
+        .filter_map(|payment_method| {
+            payment_method
+                .clone()
+                .expose()
+                .parse_value::<PaymentMethodsEnabled>("PaymentMethodsEnabled")
+                .ok()
+        })
+        .filter(|payment_methods_enabled| {
+            payment_methods_enabled.payment_method == api_enums::PaymentMethod::Card
+        })
+        .flat_map(|payment_methods_enabled| {
+            payment_methods_enabled
+                .payment_method_types
+                .unwrap_or_default()
+        })
+        .any(|payment_method_type| payment_method_type.installment_payment_enabled)
+}
+
+/// Validates that the card BIN provided in the payment methods list request is between six and
+/// eight digits long.
+pub fn validate_card_bin(card_bin: &str) -> RouterResult<()> {
+    if (6..=8).contains(&card_bin.len()) && card_bin.chars().all(|c| c.is_ascii_digit()) {
+        Ok(())
+    } else {
+        Err(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "card_bin",
+        }
+        .into())
+    }
+}
+
+/// Validates the installment plan selected in a payment request.
+pub fn validate_installment_plan_selection(
+    selection: &InstallmentPlanSelection,
+    payment_method: Option<api_enums::PaymentMethod>,
+) -> RouterResult<()> {
+    if selection.number_of_installments == 0 {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "installment_plan.number_of_installments must be greater than zero"
+                .to_string(),
+        }
+        .into());
+    }
+
+    match payment_method {
+        Some(api_enums::PaymentMethod::Card) | None => Ok(()),
+        Some(_) => Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Installment plans are only supported for card payments".to_string(),
+        }
+        .into()),
+    }
+}
+
+#[instrument(skip_all)]
+fn construct_installment_plans_router_data(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    merchant_connector_account: &MerchantConnectorAccount,
+    payment_attempt: &storage::PaymentAttempt,
+    request: types::InstallmentPlansRequestData,
+) -> RouterResult<types::PaymentsInstallmentPlansRouterData> {
+    let connector_auth_type: types::ConnectorAuthType = merchant_connector_account
+        .connector_account_details
+        .clone()
+        .parse_value("ConnectorAuthType")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while parsing value for ConnectorAuthType")?;
+
+    Ok(types::RouterData {
+        flow: PhantomData,
+        merchant_id: merchant_account.get_id().to_owned(),
+        customer_id: None,
+        connector_customer: None,
+        connector: merchant_connector_account.connector_name.clone(),
+        payment_id: payment_attempt.payment_id.get_string_repr().to_owned(),
+        attempt_id: payment_attempt.attempt_id.clone(),
+        status: payment_attempt.status,
+        payment_method: diesel_models::enums::PaymentMethod::Card,
+        connector_auth_type,
+        description: None,
+        return_url: None,
+        address: PaymentAddress::default(),
+        auth_type: payment_attempt.authentication_type.unwrap_or_default(),
+        connector_meta_data: merchant_connector_account.metadata.clone(),
+        connector_wallets_details: None,
+        amount_captured: None,
+        access_token: None,
+        session_token: None,
+        reference_id: None,
+        payment_method_token: None,
+        recurring_mandate_payment_data: None,
+        preprocessing_id: None,
+        payment_method_balance: None,
+        connector_api_version: None,
+        request,
+        response: Err(ErrorResponse::default()),
+        connector_request_reference_id: core_utils::get_connector_request_reference_id(
+            &state.conf,
+            merchant_account.get_id(),
+            payment_attempt,
+        ),
+        #[cfg(feature = "payouts")]
+        payout_method_data: None,
+        #[cfg(feature = "payouts")]
+        quote_id: None,
+        test_mode: merchant_connector_account.test_mode,
+        connector_http_status_code: None,
+        external_latency: None,
+        apple_pay_flow: None,
+        frm_metadata: None,
+        refund_id: None,
+        dispute_id: None,
+        connector_response: None,
+        payment_method_status: None,
+        minor_amount_captured: None,
+        integrity_check: Ok(()),
+        additional_merchant_data: None,
+        merchant_connector_id: Some(merchant_connector_account.get_id()),
+        header_payload: None,
+    })
+}
+
+#[instrument(skip_all)]
+async fn get_installment_plans_from_connector(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    merchant_connector_account: &MerchantConnectorAccount,
+    payment_attempt: &storage::PaymentAttempt,
+    request: types::InstallmentPlansRequestData,
+) -> RouterResult<Vec<InstallmentPlan>> {
+    let connector_data = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &merchant_connector_account.connector_name,
+        api::GetToken::Connector,
+        Some(merchant_connector_account.get_id()),
+    )?;
+
+    let router_data = construct_installment_plans_router_data(
+        state,
+        merchant_account,
+        merchant_connector_account,
+        payment_attempt,
+        request,
+    )?;
+
+    let connector_integration: services::BoxedPaymentConnectorIntegrationInterface<
+        api::InstallmentPlans,
+        types::InstallmentPlansRequestData,
+        types::InstallmentPlansResponseData,
+    > = connector_data.connector.get_connector_integration();
+
+    let response = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        CallConnectorAction::Trigger,
+        None,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to fetch installment plans from the connector")?;
+
+    let plans = response
+        .response
+        .map_err(|err| errors::ApiErrorResponse::ExternalConnectorError {
+            code: err.code,
+            message: err.message,
+            connector: connector_data.connector_name.to_string(),
+            status_code: err.status_code,
+            reason: err.reason,
+        })?
+        .plans;
+
+    Ok(plans
+        .into_iter()
+        .map(|plan| InstallmentPlan {
+            connector: connector_data.connector_name.to_string(),
+            plan_id: plan.plan_id,
+            number_of_installments: plan.number_of_installments,
+            installment_amount: plan.installment_amount,
+            total_amount: plan.total_amount,
+            interest_rate: plan.interest_rate,
+            fee_amount: plan.fee_amount,
+        })
+        .collect())
+}
+
+/// Fetches the installment plans offered for the card BIN by the connectors which have
+/// installment payments enabled. Connectors failing to report plans are skipped, so that the
+/// payment methods list is still returned. The plans are cached against the payment, so that the
+/// plan selected while confirming the payment can be validated.
+#[instrument(skip_all)]
+pub async fn list_installment_plans(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    merchant_connector_accounts: &[MerchantConnectorAccount],
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+    card_bin: &str,
+    country: Option<api_enums::CountryAlpha2>,
+) -> RouterResult<Vec<InstallmentPlan>> {
+    validate_card_bin(card_bin)?;
+    let currency = payment_intent.currency.get_required_value("currency")?;
+
+    let mut installment_plans = Vec::new();
+    for merchant_connector_account in merchant_connector_accounts
+        .iter()
+        .filter(|mca| is_installment_payment_enabled(mca))
+    {
+        let request = types::InstallmentPlansRequestData {
+            card_bin: card_bin.to_owned(),
+            amount: payment_attempt.get_total_amount(),
+            currency,
+            country,
+        };
+        match get_installment_plans_from_connector(
+            state,
+            merchant_account,
+            merchant_connector_account,
+            payment_attempt,
+            request,
+        )
+        .await
+        {
+            Ok(plans) => installment_plans.extend(plans),
+            Err(error) => logger::error!(
+                ?error,
+                connector = %merchant_connector_account.connector_name,
+                "Failed to fetch installment plans"
+            ),
+        }
+    }
+
+    if !installment_plans.is_empty() {
+        let redis_conn = state
+            .store
+            .get_redis_conn()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to get redis connection")?;
+        redis_conn
+            .serialize_and_set_key_with_expiry(
+                payment_intent
+                    .payment_id
+                    .get_installment_plans_key()
+                    .as_str(),
+                &OfferedInstallmentPlans {
+                    card_bin: card_bin.to_owned(),
+                    plans: installment_plans.clone(),
+                },
+                consts::INSTALLMENT_PLANS_REDIS_EXPIRY,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to store installment plans in redis")?;
+    }
+
+    Ok(installment_plans)
+}
+
+/// Resolves the installment plan selected in the payment request into the plan details stored
+/// on the payment attempt. If plans were offered for the payment in the payment methods list,
+/// the selection has to match one of them, and the amounts of the matched plan are stored along
+/// with the connector and the card BIN the plan was offered for, which are validated again once
+/// the connector and the card of the payment are known. Otherwise the selection is passed on to
+/// the connector as is.
+#[instrument(skip_all)]
+pub async fn resolve_installment_plan(
+    state: &SessionState,
+    payment_id: &id_type::PaymentId,
+    connector: Option<&str>,
+    selection: &InstallmentPlanSelection,
+) -> RouterResult<InstallmentPlanDetails> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let offered_plans = match redis_conn
+        .get_and_deserialize_key::<OfferedInstallmentPlans>(
+            payment_id.get_installment_plans_key().as_str(),
+            "OfferedInstallmentPlans",
+        )
+        .await
+    {
+        Ok(offered_plans) => Some(offered_plans),
+        Err(err) if err.current_context() == &RedisError::NotFound => None,
+        Err(err) => Err(err)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch installment plans from redis")?,
+    };
+
+    match offered_plans {
+        Some(offered_plans) => find_offered_installment_plan(offered_plans, connector, selection)
+            .ok_or(errors::ApiErrorResponse::InvalidRequestData {
+                message: "The selected installment plan was not offered for this payment"
+                    .to_string(),
+            })
+            .map_err(Into::into),
+        None => Ok(InstallmentPlanDetails {
+            plan_id: selection.plan_id.clone(),
+            number_of_installments: selection.number_of_installments,
+            installment_amount: None,
+            total_amount: None,
+            fee_amount: None,
+            connector: None,
+            card_bin: None,
+        }),
+    }
+}
+
+fn find_offered_installment_plan(
+    offered_plans: OfferedInstallmentPlans,
+    connector: Option<&str>,
+    selection: &InstallmentPlanSelection,
+) -> Option<InstallmentPlanDetails> {
+    let OfferedInstallmentPlans { card_bin, plans } = offered_plans;
+    plans
+        .into_iter()
+        .find(|plan| {
+            plan.number_of_installments == selection.number_of_installments
+                && selection
+                    .plan_id
+                    .as_ref()
+                    .map_or(true, |plan_id| plan.plan_id.as_ref() == Some(plan_id))
+                && connector.map_or(true, |connector| plan.connector == connector)
+        })
+        .map(|plan| InstallmentPlanDetails {
+            plan_id: plan.plan_id,
+            number_of_installments: plan.number_of_installments,
+            installment_amount: Some(plan.installment_amount),
+            total_amount: Some(plan.total_amount),
+            fee_amount: plan.fee_amount,
+            connector: Some(plan.connector),
+            card_bin: Some(card_bin),
+        })
+}
+
+/// Validates that the payment is processed through the connector which offered the selected
+/// installment plan, with a card having the BIN the plan was offered for, as the amounts of the
+/// plan are specific to both.
+pub fn validate_installment_plan_for_payment(
+    installment_plan: &InstallmentPlanDetails,
+    connector: Option<&str>,
+    card_number: Option<&CardNumber>,
+) -> RouterResult<()> {
+    if let (Some(plan_connector), Some(connector)) =
+        (installment_plan.connector.as_deref(), connector)
+    {
+        if plan_connector != connector {
+            return Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "The selected installment plan was offered by {plan_connector}, but the \
+                     payment is being processed through {connector}"
+                ),
+            }
+            .into());
+        }
+    }
+
+    if let Some(card_bin) = installment_plan.card_bin.as_deref() {
+        if !card_number
+            .is_some_and(|card_number| card_number.get_extended_card_bin().starts_with(card_bin))
+        {
+            return Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "The selected installment plan was not offered for this card".to_string(),
+            }
+            .into());
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::str::FromStr;
+
+    use common_utils::types::MinorUnit;
+
+    use super::*;
+
+    fn installment_plan(
+        connector: &str,
+        plan_id: &str,
+        number_of_installments: u16,
+    ) -> InstallmentPlan {
+        InstallmentPlan {
+            connector: connector.to_string(),
+            plan_id: Some(plan_id.to_string()),
+            number_of_installments,
+            installment_amount: MinorUnit::new(3400),
+            total_amount: MinorUnit::new(10200),
+            interest_rate: None,
+            fee_amount: None,
+        }
+    }
+
+    fn offered_plans() -> OfferedInstallmentPlans {
+        OfferedInstallmentPlans {
+            card_bin: "411111".to_string(),
+            plans: vec![
+                installment_plan("dlocal", "INS-3", 3),
+                installment_plan("dlocal", "INS-6", 6),
+                installment_plan("adyen", "ADY-3", 3),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_validate_card_bin() {
+        assert!(validate_card_bin("411111").is_ok());
+        assert!(validate_card_bin("41111111").is_ok());
+        assert!(validate_card_bin("41111").is_err());
+        assert!(validate_card_bin("411111111").is_err());
+        assert!(validate_card_bin("4111a1").is_err());
+    }
+
+    #[test]
+    fn test_validate_installment_plan_selection() {
+        let selection = |number_of_installments| InstallmentPlanSelection {
+            number_of_installments,
+            plan_id: None,
+        };
+
+        assert!(validate_installment_plan_selection(&selection(3), None).is_ok());
+        assert!(validate_installment_plan_selection(
+            &selection(3),
+            Some(api_enums::PaymentMethod::Card)
+        )
+        .is_ok());
+        assert!(validate_installment_plan_selection(&selection(0), None).is_err());
+        assert!(validate_installment_plan_selection(
+            &selection(3),
+            Some(api_enums::PaymentMethod::Wallet)
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_find_offered_installment_plan() {
+        let selection = InstallmentPlanSelection {
+            number_of_installments: 3,
+            plan_id: None,
+        };
+
+        let plan =
+            find_offered_installment_plan(offered_plans(), Some("adyen"), &selection).unwrap();
+        assert_eq!(plan.plan_id.as_deref(), Some("ADY-3"));
+        assert_eq!(plan.connector.as_deref(), Some("adyen"));
+        assert_eq!(plan.card_bin.as_deref(), Some("411111"));
+        assert_eq!(plan.total_amount, Some(MinorUnit::new(10200)));
+
+        let plan = find_offered_installment_plan(offered_plans(), None, &selection).unwrap();
+        assert_eq!(plan.connector.as_deref(), Some("dlocal"));
+
+        let selection = InstallmentPlanSelection {
+            number_of_installments: 6,
+            plan_id: Some("INS-6".to_string()),
+        };
+        assert!(
+            find_offered_installment_plan(offered_plans(), Some("dlocal"), &selection).is_some()
+        );
+        assert!(
+            find_offered_installment_plan(offered_plans(), Some("adyen"), &selection).is_none()
+        );
+
+        let selection = InstallmentPlanSelection {
+            number_of_installments: 12,
+            plan_id: None,
+        };
+        assert!(find_offered_installment_plan(offered_plans(), None, &selection).is_none());
+    }
+
+    #[test]
+    fn test_validate_installment_plan_for_payment() {
+        let selection = InstallmentPlanSelection {
+            number_of_installments: 3,
+            plan_id: Some("INS-3".to_string()),
+        };
+        let plan = find_offered_installment_plan(offered_plans(), None, &selection).unwrap();
+        let offered_card = CardNumber::from_str("4111111111111111").unwrap();
+        let other_card = CardNumber::from_str("5555555555554444").unwrap();
+
+        assert!(
+            validate_installment_plan_for_payment(&plan, Some("dlocal"), Some(&offered_card))
+                .is_ok()
+        );
+        assert!(
+            validate_installment_plan_for_payment(&plan, Some("adyen"), Some(&offered_card))
+                .is_err()
+        );
+        assert!(
+            validate_installment_plan_for_payment(&plan, Some("dlocal"), Some(&other_card))
+                .is_err()
+        );
+        assert!(validate_installment_plan_for_payment(&plan, Some("dlocal"), None).is_err());
+
+        // Plans which were not offered in the payment methods list are passed on as is
+        let plan = InstallmentPlanDetails {
+            plan_id: None,
+            number_of_installments: 3,
+            installment_amount: None,
+            total_amount: None,
+            fee_amount: None,
+            connector: None,
+            card_bin: None,
+        };
+        assert!(validate_installment_plan_for_payment(&plan, Some("adyen"), None).is_ok());
+    }
+}
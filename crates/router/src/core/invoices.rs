@@ -0,0 +1,765 @@
+use api_models::{admin, invoices as invoice_types, payments as payment_types};
+use common_utils::{date_time, ext_traits::ValueExt, id_type, types::MinorUnit};
+use diesel_models::enums as storage_enums;
+use error_stack::ResultExt;
+use router_env::{instrument, logger, metrics::add_attributes, tracing};
+use time::PrimitiveDateTime;
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments,
+    },
+    routes::{metrics, SessionState},
+    services::{self, ApplicationResponse},
+    types::{
+        api as api_types, domain, storage,
+        transformers::{ForeignFrom, ForeignInto},
+    },
+};
+
+const INVOICE_REMINDER_TAG: &str = "INVOICE";
+const INVOICE_REMINDER_NAME: &str = "INVOICE_REMINDER";
+const INVOICE_REMINDER_RUNNER: diesel_models::ProcessTrackerRunner =
+    diesel_models::ProcessTrackerRunner::InvoiceReminderWorkflow;
+
+#[instrument(skip_all)]
+pub async fn create_invoice(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: invoice_types::InvoiceCreateRequest,
+) -> RouterResponse<invoice_types::InvoiceResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let merchant_id = merchant_account.get_id();
+
+    let (subtotal, tax_amount, total_amount) =
+        calculate_invoice_amounts(&req.line_items, req.tax_amount)?;
+
+    let now = date_time::now();
+    if req.due_date <= now {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`due_date` must be in the future".to_owned(),
+        })?
+    }
+    // The hosted invoice page stays payable for a while after the due date, within the maximum
+    // session expiry supported for payments
+    let payable_until = req.due_date.saturating_add(time::Duration::days(i64::from(
+        state.conf.invoices.payment_window_after_due_date_in_days,
+    )));
+    let session_expiry = u32::try_from((payable_until - now).whole_seconds())
+        .unwrap_or(consts::MAX_SESSION_EXPIRY)
+        .clamp(consts::MIN_SESSION_EXPIRY, consts::MAX_SESSION_EXPIRY);
+    if req.due_date > now.saturating_add(time::Duration::seconds(i64::from(session_expiry))) {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "`due_date` must be within {} days from now",
+                consts::MAX_SESSION_EXPIRY / (24 * 60 * 60)
+            ),
+        })?
+    }
+
+    db.find_customer_by_customer_id_merchant_id(
+        key_manager_state,
+        &req.customer_id,
+        merchant_id,
+        &key_store,
+        merchant_account.storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+
+    let profile_id = req
+        .profile_id
+        .or(merchant_account.default_profile.clone())
+        .ok_or(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "profile_id",
+        })?;
+    db.find_business_profile_by_merchant_id_profile_id(
+        key_manager_state,
+        &key_store,
+        merchant_id,
+        &profile_id,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+        id: profile_id.get_string_repr().to_owned(),
+    })?;
+
+    let invoice_id = common_utils::generate_id(consts::ID_LENGTH, "inv");
+    // The payment ID is derived from the invoice ID, so that the payment of an invoice can always
+    // be looked up
+    let payment_id = id_type::PaymentId::try_from(std::borrow::Cow::Owned(invoice_id.clone()))
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to construct payment ID for invoice")?;
+
+    let mut order_details = req
+        .line_items
+        .iter()
+        .map(|line_item| payment_types::OrderDetailsWithAmount {
+            product_name: line_item.product_name.clone(),
+            quantity: line_item.quantity,
+            amount: line_item.unit_amount,
+            ..Default::default()
+        })
+        .collect::<Vec<_>>();
+    if tax_amount.get_amount_as_i64() > 0 {
+        order_details.push(payment_types::OrderDetailsWithAmount {
+            product_name: "Tax".to_owned(),
+            quantity: 1,
+            amount: tax_amount,
+            ..Default::default()
+        });
+    }
+
+    let mut theme_config = req
+        .payment_link_config
+        .map(|config| config.theme_config)
+        .unwrap_or(admin::PaymentLinkConfigRequest {
+            theme: None,
+            logo: None,
+            seller_name: None,
+            sdk_layout: None,
+            display_sdk_only: None,
+            enabled_saved_payment_method: None,
+            transaction_details: None,
+        });
+    theme_config
+        .transaction_details
+        .get_or_insert_with(Vec::new)
+        .extend([
+            admin::PaymentLinkTransactionDetails {
+                key: "Invoice number".to_owned(),
+                value: invoice_id.clone(),
+                ui_configuration: None,
+            },
+            admin::PaymentLinkTransactionDetails {
+                key: "Due date".to_owned(),
+                value: format!("{} UTC", req.due_date.date()),
+                ui_configuration: None,
+            },
+        ]);
+
+    let payment_request = payment_types::PaymentsRequest {
+        payment_id: Some(payment_types::PaymentIdType::PaymentIntentId(
+            payment_id.clone(),
+        )),
+        merchant_id: Some(merchant_id.to_owned()),
+        amount: Some(total_amount.into()),
+        currency: Some(req.currency),
+        customer_id: Some(req.customer_id.clone()),
+        profile_id: Some(profile_id.clone()),
+        description: req.description.clone(),
+        order_details: Some(order_details),
+        payment_link: Some(true),
+        payment_link_config: Some(payment_types::PaymentCreatePaymentLinkConfig { theme_config }),
+        session_expiry: Some(session_expiry),
+        metadata: Some(serde_json::json!({ "invoice_id": invoice_id })),
+        ..Default::default()
+    };
+
+    let payments_response = match Box::pin(payments::payments_core::<
+        api_types::Authorize,
+        payment_types::PaymentsResponse,
+        _,
+        _,
+        _,
+        payments::PaymentData<api_types::Authorize>,
+    >(
+        state.clone(),
+        state.get_req_state(),
+        merchant_account.clone(),
+        Some(profile_id.clone()),
+        key_store.clone(),
+        payments::PaymentCreate,
+        payment_request,
+        services::AuthFlow::Merchant,
+        payments::CallConnectorAction::Trigger,
+        None,
+        hyperswitch_domain_models::payments::HeaderPayload::default(),
+    ))
+    .await?
+    {
+        ApplicationResponse::Json(response)
+        | ApplicationResponse::JsonWithHeaders((response, _)) => response,
+        _ => Err(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unexpected response from payments core")?,
+    };
+
+    let line_items = serde_json::to_value(&req.line_items)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize invoice line items")?;
+
+    let invoice_new = storage::InvoiceNew {
+        invoice_id,
+        merchant_id: merchant_id.to_owned(),
+        profile_id,
+        customer_id: req.customer_id,
+        status: storage_enums::InvoiceStatus::Open,
+        line_items,
+        currency: req.currency,
+        subtotal,
+        tax_amount,
+        total_amount,
+        due_date: req.due_date,
+        payment_id,
+        payment_link: payments_response
+            .payment_link
+            .map(|payment_link| payment_link.link),
+        description: req.description,
+        metadata: req.metadata,
+        paid_at: None,
+        created_at: now,
+        modified_at: now,
+    };
+    let invoice = db
+        .insert_invoice(invoice_new)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert invoice")?;
+
+    add_invoice_reminder_task(&state, &invoice).await?;
+
+    Ok(ApplicationResponse::Json(invoice.foreign_into()))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_invoice(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    invoice_id: String,
+) -> RouterResponse<invoice_types::InvoiceResponse> {
+    let invoice = state
+        .store
+        .find_invoice_by_merchant_id_invoice_id(merchant_account.get_id(), &invoice_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Invoice `{invoice_id}` does not exist"),
+        })?;
+
+    let invoice = sync_invoice_status(&state, &merchant_account, &key_store, invoice).await?;
+
+    Ok(ApplicationResponse::Json(invoice.foreign_into()))
+}
+
+#[instrument(skip_all)]
+pub async fn list_invoices(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    constraints: invoice_types::InvoiceListConstraints,
+) -> RouterResponse<invoice_types::InvoiceListResponse> {
+    let invoices = state
+        .store
+        .filter_invoices_by_merchant_id_constraints(
+            merchant_account.get_id(),
+            constraints.customer_id.as_ref(),
+            constraints.status,
+            constraints.limit,
+            constraints.offset,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list invoices")?;
+
+    let data: Vec<invoice_types::InvoiceResponse> = invoices
+        .into_iter()
+        .map(ForeignInto::foreign_into)
+        .collect();
+
+    Ok(ApplicationResponse::Json(
+        invoice_types::InvoiceListResponse {
+            count: data.len(),
+            data,
+        },
+    ))
+}
+
+/// Voids an unpaid invoice. The payment of the invoice is cancelled, so that the hosted invoice
+/// page cannot be used to pay it anymore.
+#[instrument(skip_all)]
+pub async fn void_invoice(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    invoice_id: String,
+) -> RouterResponse<invoice_types::InvoiceResponse> {
+    let db = state.store.as_ref();
+    let invoice = db
+        .find_invoice_by_merchant_id_invoice_id(merchant_account.get_id(), &invoice_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Invoice `{invoice_id}` does not exist"),
+        })?;
+
+    let invoice = sync_invoice_status(&state, &merchant_account, &key_store, invoice).await?;
+    match invoice.status {
+        storage_enums::InvoiceStatus::Open | storage_enums::InvoiceStatus::Overdue => {}
+        storage_enums::InvoiceStatus::Paid | storage_enums::InvoiceStatus::Void => {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!("Invoice is already {}", invoice.status),
+            })?
+        }
+    }
+
+    let cancel_request = payment_types::PaymentsCancelRequest {
+        payment_id: invoice.payment_id.clone(),
+        cancellation_reason: Some("Invoice was voided".to_owned()),
+        merchant_connector_details: None,
+    };
+    Box::pin(payments::payments_core::<
+        api_types::Void,
+        payment_types::PaymentsResponse,
+        _,
+        _,
+        _,
+        payments::PaymentData<api_types::Void>,
+    >(
+        state.clone(),
+        state.get_req_state(),
+        merchant_account.clone(),
+        None,
+        key_store,
+        payments::PaymentCancel,
+        cancel_request,
+        services::AuthFlow::Merchant,
+        payments::CallConnectorAction::Trigger,
+        None,
+        hyperswitch_domain_models::payments::HeaderPayload::default(),
+    ))
+    .await?;
+
+    let invoice = db
+        .update_invoice_by_merchant_id_invoice_id(
+            merchant_account.get_id(),
+            &invoice_id,
+            storage::InvoiceUpdate::StatusUpdate {
+                status: storage_enums::InvoiceStatus::Void,
+                paid_at: None,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update invoice")?;
+
+    Ok(ApplicationResponse::Json(invoice.foreign_into()))
+}
+
+/// Marks an open or overdue invoice as paid once its payment has succeeded.
+#[instrument(skip_all)]
+pub async fn sync_invoice_status(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    invoice: storage::Invoice,
+) -> RouterResult<storage::Invoice> {
+    if !matches!(
+        invoice.status,
+        storage_enums::InvoiceStatus::Open | storage_enums::InvoiceStatus::Overdue
+    ) {
+        return Ok(invoice);
+    }
+
+    let payment_intent = state
+        .store
+        .find_payment_intent_by_payment_id_merchant_id(
+            &state.into(),
+            &invoice.payment_id,
+            merchant_account.get_id(),
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    if payment_intent.status != storage_enums::IntentStatus::Succeeded {
+        return Ok(invoice);
+    }
+
+    mark_invoice_paid(state, &invoice, payment_intent.modified_at).await
+}
+
+/// Marks the invoice paid by a payment as paid. This is called whenever a payment succeeds, both
+/// from the payments API and from webhooks, so that the invoice does not stay open until it is
+/// next retrieved.
+#[instrument(skip_all)]
+pub async fn update_invoice_on_payment_success(
+    state: &SessionState,
+    payment_intent: &hyperswitch_domain_models::payments::PaymentIntent,
+) -> RouterResult<()> {
+    // Only the payments created for invoices carry the invoice ID in their metadata
+    let Some(invoice_id) = payment_intent
+        .metadata
+        .as_ref()
+        .and_then(|metadata| metadata.get("invoice_id"))
+        .and_then(serde_json::Value::as_str)
+    else {
+        return Ok(());
+    };
+
+    let invoice = state
+        .store
+        .find_invoice_by_merchant_id_invoice_id(&payment_intent.merchant_id, invoice_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Invoice `{invoice_id}` does not exist"),
+        })?;
+    if invoice.payment_id != payment_intent.payment_id
+        || !matches!(
+            invoice.status,
+            storage_enums::InvoiceStatus::Open | storage_enums::InvoiceStatus::Overdue
+        )
+    {
+        return Ok(());
+    }
+
+    mark_invoice_paid(state, &invoice, payment_intent.modified_at)
+        .await
+        .map(|_| ())
+}
+
+async fn mark_invoice_paid(
+    state: &SessionState,
+    invoice: &storage::Invoice,
+    paid_at: PrimitiveDateTime,
+) -> RouterResult<storage::Invoice> {
+    let invoice = state
+        .store
+        .update_invoice_by_merchant_id_invoice_id(
+            &invoice.merchant_id,
+            &invoice.invoice_id,
+            storage::InvoiceUpdate::StatusUpdate {
+                status: storage_enums::InvoiceStatus::Paid,
+                paid_at: Some(paid_at),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update invoice")?;
+    metrics::INVOICE_PAID_COUNT.add(&metrics::CONTEXT, 1, &[]);
+
+    Ok(invoice)
+}
+
+/// Marks an open invoice as overdue, and lets the customer know about it.
+#[instrument(skip_all)]
+pub async fn mark_invoice_overdue(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    invoice: storage::Invoice,
+) -> RouterResult<storage::Invoice> {
+    let invoice = state
+        .store
+        .update_invoice_by_merchant_id_invoice_id(
+            merchant_account.get_id(),
+            &invoice.invoice_id,
+            storage::InvoiceUpdate::StatusUpdate {
+                status: storage_enums::InvoiceStatus::Overdue,
+                paid_at: None,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update invoice")?;
+
+    send_invoice_reminder(state, merchant_account, key_store, &invoice).await?;
+
+    Ok(invoice)
+}
+
+/// Sends a reminder of the invoice to the customer, or a notice if the invoice is overdue.
+#[instrument(skip_all)]
+pub async fn send_invoice_reminder(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    invoice: &storage::Invoice,
+) -> RouterResult<()> {
+    logger::info!(
+        invoice_id = %invoice.invoice_id,
+        invoice_status = %invoice.status,
+        "Sending invoice reminder"
+    );
+    metrics::INVOICE_REMINDER_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &add_attributes([("invoice_status", invoice.status.to_string())]),
+    );
+
+    #[cfg(feature = "email")]
+    send_invoice_reminder_email(state, merchant_account, key_store, invoice).await;
+    #[cfg(not(feature = "email"))]
+    let _ = (state, merchant_account, key_store);
+
+    Ok(())
+}
+
+/// Failures in sending the email are only logged, as the reminder is not retried.
+#[cfg(feature = "email")]
+async fn send_invoice_reminder_email(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    invoice: &storage::Invoice,
+) {
+    use common_utils::types::{AmountConvertor, StringMajorUnitForCore};
+    use masking::PeekInterface;
+
+    use crate::services::email::types::InvoiceReminder;
+
+    let result =
+        async {
+            let customer = state
+                .store
+                .find_customer_by_customer_id_merchant_id(
+                    &state.into(),
+                    &invoice.customer_id,
+                    merchant_account.get_id(),
+                    key_store,
+                    merchant_account.storage_scheme,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+            let email = customer.email.map(common_utils::pii::Email::from).ok_or(
+                errors::ApiErrorResponse::MissingRequiredField {
+                    field_name: "customer.email",
+                },
+            )?;
+            let amount = StringMajorUnitForCore
+                .convert(invoice.total_amount, invoice.currency)
+                .change_context(errors::ApiErrorResponse::InternalServerError)?;
+            let payment_link = invoice.payment_link.clone().ok_or(
+                errors::ApiErrorResponse::MissingRequiredField {
+                    field_name: "payment_link",
+                },
+            )?;
+            let is_overdue = invoice.status == storage_enums::InvoiceStatus::Overdue;
+
+            let email_contents = InvoiceReminder {
+                recipient_email: domain::UserEmail::from_pii_email(email)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)?,
+                subject: if is_overdue {
+                    consts::EMAIL_SUBJECT_INVOICE_OVERDUE
+                } else {
+                    consts::EMAIL_SUBJECT_INVOICE_REMINDER
+                },
+                merchant_name: merchant_account
+                    .merchant_name
+                    .clone()
+                    .map(|name| name.into_inner().peek().to_owned())
+                    .unwrap_or_default(),
+                invoice_id: invoice.invoice_id.clone(),
+                amount: format!("{} {}", amount.get_amount_as_string(), invoice.currency),
+                due_date: invoice.due_date,
+                payment_link,
+                is_overdue,
+            };
+
+            state
+                .email_client
+                .clone()
+                .compose_and_send_email(
+                    Box::new(email_contents),
+                    state.conf.proxy.https_url.as_ref(),
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+        }
+        .await;
+
+    if let Err(error) = result {
+        logger::error!(
+            ?error,
+            invoice_id = %invoice.invoice_id,
+            "Failed to send invoice reminder email"
+        );
+    }
+}
+
+fn calculate_invoice_amounts(
+    line_items: &[invoice_types::InvoiceLineItem],
+    tax_amount: Option<MinorUnit>,
+) -> RouterResult<(MinorUnit, MinorUnit, MinorUnit)> {
+    let invalid = |message: &str| errors::ApiErrorResponse::InvalidRequestData {
+        message: message.to_owned(),
+    };
+
+    if line_items.is_empty() {
+        Err(invalid("An invoice must have at least one line item"))?
+    }
+    let subtotal = line_items.iter().try_fold(0i64, |subtotal, line_item| {
+        if line_item.quantity == 0 || line_item.unit_amount.get_amount_as_i64() <= 0 {
+            return Err(invalid(
+                "`quantity` and `unit_amount` of line items must be greater than zero",
+            ));
+        }
+        line_item
+            .unit_amount
+            .get_amount_as_i64()
+            .checked_mul(i64::from(line_item.quantity))
+            .and_then(|line_amount| subtotal.checked_add(line_amount))
+            .ok_or_else(|| invalid("Total amount of the invoice is too large"))
+    })?;
+
+    let tax_amount = tax_amount.unwrap_or_default().get_amount_as_i64();
+    if tax_amount < 0 {
+        Err(invalid("`tax_amount` must not be negative"))?
+    }
+    let total_amount = subtotal
+        .checked_add(tax_amount)
+        .ok_or_else(|| invalid("Total amount of the invoice is too large"))?;
+
+    Ok((
+        MinorUnit::new(subtotal),
+        MinorUnit::new(tax_amount),
+        MinorUnit::new(total_amount),
+    ))
+}
+
+/// Returns the reminder which is due at `now`, as the number of days before the due date. Only
+/// the latest of the reminders which are due is returned, so that a customer is not sent several
+/// reminders at once for an invoice created close to its due date.
+pub fn get_due_invoice_reminder(
+    due_date: PrimitiveDateTime,
+    reminder_days: &[u16],
+    now: PrimitiveDateTime,
+) -> Option<u16> {
+    reminder_days
+        .iter()
+        .copied()
+        .filter(|days| {
+            due_date.saturating_sub(time::Duration::days(i64::from(*days))) <= now && now < due_date
+        })
+        .min()
+}
+
+/// Returns the time at which the invoice reminder task has to run next: the next reminder, or
+/// the due date once all the reminders have been sent.
+pub fn get_next_invoice_reminder_time(
+    due_date: PrimitiveDateTime,
+    reminder_days: &[u16],
+    now: PrimitiveDateTime,
+) -> PrimitiveDateTime {
+    reminder_days
+        .iter()
+        .map(|days| due_date.saturating_sub(time::Duration::days(i64::from(*days))))
+        .filter(|reminder_time| *reminder_time > now)
+        .min()
+        .unwrap_or(due_date)
+}
+
+async fn add_invoice_reminder_task(
+    state: &SessionState,
+    invoice: &storage::Invoice,
+) -> RouterResult<()> {
+    let task_id = generate_task_id_for_invoice_reminder_workflow(&invoice.invoice_id);
+    let tracking_data = storage::InvoiceReminderTrackingData {
+        merchant_id: invoice.merchant_id.clone(),
+        invoice_id: invoice.invoice_id.clone(),
+        last_reminder_days: None,
+    };
+    let schedule_time = get_next_invoice_reminder_time(
+        invoice.due_date,
+        &state.conf.invoices.reminder_days_before_due_date,
+        date_time::now(),
+    );
+
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        task_id,
+        INVOICE_REMINDER_NAME,
+        INVOICE_REMINDER_RUNNER,
+        [INVOICE_REMINDER_TAG],
+        tracking_data,
+        schedule_time,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct invoice reminder task")?;
+
+    state
+        .store
+        .insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert invoice reminder task")?;
+
+    metrics::TASKS_ADDED_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &add_attributes([("flow", "InvoiceReminder")]),
+    );
+
+    Ok(())
+}
+
+pub fn generate_task_id_for_invoice_reminder_workflow(invoice_id: &str) -> String {
+    format!("{INVOICE_REMINDER_RUNNER}_{INVOICE_REMINDER_NAME}_{invoice_id}")
+}
+
+impl ForeignFrom<storage::Invoice> for invoice_types::InvoiceResponse {
+    fn foreign_from(invoice: storage::Invoice) -> Self {
+        let line_items = invoice
+            .line_items
+            .parse_value("InvoiceLineItems")
+            .map_err(|error| {
+                logger::error!(?error, invoice_id = %invoice.invoice_id, "Failed to parse invoice line items");
+            })
+            .unwrap_or_default();
+
+        Self {
+            invoice_id: invoice.invoice_id,
+            merchant_id: invoice.merchant_id,
+            profile_id: invoice.profile_id,
+            customer_id: invoice.customer_id,
+            status: invoice.status,
+            line_items,
+            currency: invoice.currency,
+            subtotal: invoice.subtotal,
+            tax_amount: invoice.tax_amount,
+            total_amount: invoice.total_amount,
+            due_date: invoice.due_date,
+            payment_id: invoice.payment_id,
+            payment_link: invoice.payment_link,
+            description: invoice.description,
+            metadata: invoice.metadata,
+            paid_at: invoice.paid_at,
+            created_at: invoice.created_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn test_invoice_reminder_schedule() {
+        let due_date = datetime!(2024-11-20 12:00);
+        let reminder_days = [7, 3, 1];
+
+        assert_eq!(
+            get_next_invoice_reminder_time(due_date, &reminder_days, datetime!(2024-11-10 12:00)),
+            datetime!(2024-11-13 12:00)
+        );
+        assert_eq!(
+            get_next_invoice_reminder_time(due_date, &reminder_days, datetime!(2024-11-19 13:00)),
+            due_date
+        );
+        assert_eq!(
+            get_due_invoice_reminder(due_date, &reminder_days, datetime!(2024-11-10 12:00)),
+            None
+        );
+        // Only the latest of the reminders which are due is sent
+        assert_eq!(
+            get_due_invoice_reminder(due_date, &reminder_days, datetime!(2024-11-19 13:00)),
+            Some(1)
+        );
+        assert_eq!(
+            get_due_invoice_reminder(due_date, &reminder_days, due_date),
+            None
+        );
+    }
+}
@@ -79,6 +79,7 @@ pub async fn construct_mandate_revoke_router_data(
         connector_response: None,
         integrity_check: Ok(()),
         additional_merchant_data: None,
+        merchant_connector_id: None,
         header_payload: None,
     };
 
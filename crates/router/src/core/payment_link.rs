@@ -1,3 +1,5 @@
+#[cfg(feature = "v1")]
+pub mod reusable;
 pub mod validator;
 use actix_web::http::header;
 use api_models::{
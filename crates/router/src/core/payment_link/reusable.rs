@@ -0,0 +1,414 @@
+use actix_web::http::header;
+use api_models::payments as payment_types;
+use common_utils::{date_time, ext_traits::ValueExt, id_type, types::MinorUnit};
+use diesel_models::enums as storage_enums;
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments,
+    },
+    headers::ACCEPT_LANGUAGE,
+    routes::SessionState,
+    services::{self, authentication::get_header_value_by_key, ApplicationResponse},
+    types::{api as api_types, domain, storage, transformers::ForeignFrom},
+};
+
+#[instrument(skip_all)]
+pub async fn create_reusable_payment_link(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: payment_types::ReusablePaymentLinkCreateRequest,
+) -> RouterResponse<payment_types::ReusablePaymentLinkResponse> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_account.get_id();
+
+    validate_amount_configuration(req.amount_type, req.amount, req.min_amount, req.max_amount)?;
+
+    let profile_id = req
+        .profile_id
+        .or(merchant_account.default_profile.clone())
+        .ok_or(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "profile_id",
+        })?;
+    db.find_business_profile_by_merchant_id_profile_id(
+        &(&state).into(),
+        &key_store,
+        merchant_id,
+        &profile_id,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+        id: profile_id.get_string_repr().to_owned(),
+    })?;
+
+    let payment_link_config = req
+        .payment_link_config
+        .map(serde_json::to_value)
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "payment_link_config",
+        })?;
+
+    let now = date_time::now();
+    let link_new = storage::ReusablePaymentLinkNew {
+        link_id: common_utils::generate_id(consts::ID_LENGTH, "rpl"),
+        merchant_id: merchant_id.to_owned(),
+        profile_id,
+        amount_type: req.amount_type,
+        amount: req.amount,
+        min_amount: req.min_amount,
+        max_amount: req.max_amount,
+        currency: req.currency,
+        description: req.description,
+        payment_link_config,
+        is_active: true,
+        metadata: req.metadata,
+        created_at: now,
+        modified_at: now,
+    };
+
+    let link = db
+        .insert_reusable_payment_link(link_new)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert reusable payment link")?;
+
+    Ok(ApplicationResponse::Json(
+        payment_types::ReusablePaymentLinkResponse::foreign_from((link, state.base_url.as_str())),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_reusable_payment_link(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    link_id: String,
+) -> RouterResponse<payment_types::ReusablePaymentLinkResponse> {
+    let link = state
+        .store
+        .find_reusable_payment_link_by_merchant_id_link_id(merchant_account.get_id(), &link_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
+
+    Ok(ApplicationResponse::Json(
+        payment_types::ReusablePaymentLinkResponse::foreign_from((link, state.base_url.as_str())),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn update_reusable_payment_link(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    link_id: String,
+    req: payment_types::ReusablePaymentLinkUpdateRequest,
+) -> RouterResponse<payment_types::ReusablePaymentLinkResponse> {
+    let link_update = storage::ReusablePaymentLinkUpdate::Update {
+        description: req.description,
+        is_active: req.is_active,
+        metadata: req.metadata,
+    };
+
+    let link = state
+        .store
+        .update_reusable_payment_link_by_merchant_id_link_id(
+            merchant_account.get_id(),
+            &link_id,
+            link_update,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
+
+    Ok(ApplicationResponse::Json(
+        payment_types::ReusablePaymentLinkResponse::foreign_from((link, state.base_url.as_str())),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn list_reusable_payment_links(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    constraints: payment_types::ReusablePaymentLinkListConstraints,
+) -> RouterResponse<payment_types::ReusablePaymentLinkListResponse> {
+    let links = state
+        .store
+        .list_reusable_payment_links_by_merchant_id(
+            merchant_account.get_id(),
+            constraints.limit,
+            constraints.offset,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list reusable payment links")?;
+
+    let data: Vec<payment_types::ReusablePaymentLinkResponse> = links
+        .into_iter()
+        .map(|link| {
+            payment_types::ReusablePaymentLinkResponse::foreign_from((
+                link,
+                state.base_url.as_str(),
+            ))
+        })
+        .collect();
+
+    Ok(ApplicationResponse::Json(
+        payment_types::ReusablePaymentLinkListResponse {
+            count: data.len(),
+            data,
+        },
+    ))
+}
+
+/// Creates a new payment for a visit to a reusable payment link, and redirects the customer to
+/// the payment link of the newly created payment.
+#[instrument(skip_all)]
+pub async fn initiate_reusable_payment_link_flow(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: payment_types::ReusablePaymentLinkInitiateRequest,
+    request_headers: &header::HeaderMap,
+) -> RouterResponse<()> {
+    let link = state
+        .store
+        .find_reusable_payment_link_by_merchant_id_link_id(&req.merchant_id, &req.link_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
+    if !link.is_active {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Payment link is no longer active".to_owned(),
+        })?
+    }
+
+    let amount = get_payment_amount(&link, req.amount)?;
+    let payment_link_config = link
+        .payment_link_config
+        .clone()
+        .map(|config| config.parse_value("PaymentCreatePaymentLinkConfig"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse payment link config of reusable payment link")?;
+    let locale = get_header_value_by_key(ACCEPT_LANGUAGE.into(), request_headers)?
+        .map(|val| val.to_string());
+
+    let payment_request = payment_types::PaymentsRequest {
+        merchant_id: Some(link.merchant_id.clone()),
+        amount: Some(amount.into()),
+        currency: Some(link.currency),
+        profile_id: Some(link.profile_id.clone()),
+        description: link.description.clone(),
+        payment_link: Some(true),
+        payment_link_config,
+        metadata: Some(serde_json::json!({ "reusable_payment_link_id": link.link_id })),
+        ..Default::default()
+    };
+
+    let payments_response = match Box::pin(payments::payments_core::<
+        api_types::Authorize,
+        payment_types::PaymentsResponse,
+        _,
+        _,
+        _,
+        payments::PaymentData<api_types::Authorize>,
+    >(
+        state.clone(),
+        state.get_req_state(),
+        merchant_account,
+        Some(link.profile_id.clone()),
+        key_store,
+        payments::PaymentCreate,
+        payment_request,
+        services::AuthFlow::Merchant,
+        payments::CallConnectorAction::Trigger,
+        None,
+        hyperswitch_domain_models::payments::HeaderPayload {
+            locale,
+            ..Default::default()
+        },
+    ))
+    .await?
+    {
+        ApplicationResponse::Json(response)
+        | ApplicationResponse::JsonWithHeaders((response, _)) => response,
+        _ => Err(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unexpected response from payments core")?,
+    };
+
+    let payment_link = payments_response
+        .payment_link
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Payment link was not created for the payment")?;
+
+    Ok(ApplicationResponse::JsonForRedirection(
+        payment_types::RedirectionResponse {
+            return_url: String::new(),
+            params: vec![],
+            return_url_with_query_params: payment_link.link,
+            http_method: services::Method::Get.to_string(),
+            headers: vec![],
+        },
+    ))
+}
+
+fn validate_amount_configuration(
+    amount_type: storage_enums::ReusablePaymentLinkAmountType,
+    amount: Option<MinorUnit>,
+    min_amount: Option<MinorUnit>,
+    max_amount: Option<MinorUnit>,
+) -> RouterResult<()> {
+    let invalid = |message: &str| errors::ApiErrorResponse::InvalidRequestData {
+        message: message.to_owned(),
+    };
+
+    if [amount, min_amount, max_amount]
+        .iter()
+        .flatten()
+        .any(|amount| amount.get_amount_as_i64() <= 0)
+    {
+        Err(invalid(
+            "Amounts of a payment link must be greater than zero",
+        ))?
+    }
+
+    match amount_type {
+        storage_enums::ReusablePaymentLinkAmountType::Fixed => {
+            if amount.is_none() {
+                Err(invalid("`amount` is required for `fixed` payment links"))?
+            }
+            if min_amount.is_some() || max_amount.is_some() {
+                Err(invalid(
+                    "`min_amount` and `max_amount` are only supported for `customer_chosen` \
+                     payment links",
+                ))?
+            }
+        }
+        storage_enums::ReusablePaymentLinkAmountType::CustomerChosen => {
+            if let (Some(min_amount), Some(max_amount)) = (min_amount, max_amount) {
+                if min_amount > max_amount {
+                    Err(invalid(
+                        "`min_amount` must not be greater than `max_amount`",
+                    ))?
+                }
+            }
+            if let Some(amount) = amount {
+                if min_amount.is_some_and(|min_amount| amount < min_amount)
+                    || max_amount.is_some_and(|max_amount| amount > max_amount)
+                {
+                    Err(invalid(
+                        "`amount` must lie between `min_amount` and `max_amount`",
+                    ))?
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the amount of the payment to be created for a visit to the link. Customers can only
+/// choose the amount of `customer_chosen` links, within the bounds configured for the link.
+fn get_payment_amount(
+    link: &storage::ReusablePaymentLink,
+    requested_amount: Option<MinorUnit>,
+) -> RouterResult<MinorUnit> {
+    match link.amount_type {
+        storage_enums::ReusablePaymentLinkAmountType::Fixed => link
+            .amount
+            .ok_or(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Fixed amount payment link has no amount"),
+        storage_enums::ReusablePaymentLinkAmountType::CustomerChosen => {
+            let amount = requested_amount.or(link.amount).ok_or(
+                errors::ApiErrorResponse::MissingRequiredField {
+                    field_name: "amount",
+                },
+            )?;
+            let is_below_min = link
+                .min_amount
+                .map_or(amount.get_amount_as_i64() <= 0, |min_amount| {
+                    amount < min_amount
+                });
+            let is_above_max = link
+                .max_amount
+                .is_some_and(|max_amount| amount > max_amount);
+            if is_below_min || is_above_max {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "Amount is outside of the range allowed by the payment link"
+                        .to_owned(),
+                })?
+            }
+            Ok(amount)
+        }
+    }
+}
+
+pub fn get_reusable_payment_link_url(
+    base_url: &str,
+    merchant_id: &id_type::MerchantId,
+    link_id: &str,
+) -> String {
+    format!(
+        "{base_url}/payment_link/reusable/{}/{link_id}",
+        merchant_id.get_string_repr()
+    )
+}
+
+impl ForeignFrom<(storage::ReusablePaymentLink, &str)>
+    for payment_types::ReusablePaymentLinkResponse
+{
+    fn foreign_from((link, base_url): (storage::ReusablePaymentLink, &str)) -> Self {
+        Self {
+            link: get_reusable_payment_link_url(base_url, &link.merchant_id, &link.link_id),
+            link_id: link.link_id,
+            merchant_id: link.merchant_id,
+            profile_id: link.profile_id,
+            amount_type: link.amount_type,
+            amount: link.amount,
+            min_amount: link.min_amount,
+            max_amount: link.max_amount,
+            currency: link.currency,
+            description: link.description,
+            is_active: link.is_active,
+            metadata: link.metadata,
+            created_at: link.created_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_amount_configuration() {
+        use storage_enums::ReusablePaymentLinkAmountType::{CustomerChosen, Fixed};
+
+        let amount = |value| Some(MinorUnit::new(value));
+
+        assert!(validate_amount_configuration(Fixed, amount(1000), None, None).is_ok());
+        assert!(validate_amount_configuration(Fixed, None, None, None).is_err());
+        assert!(validate_amount_configuration(Fixed, amount(1000), amount(100), None).is_err());
+        assert!(validate_amount_configuration(CustomerChosen, None, None, None).is_ok());
+        assert!(validate_amount_configuration(
+            CustomerChosen,
+            amount(500),
+            amount(100),
+            amount(1000)
+        )
+        .is_ok());
+        assert!(validate_amount_configuration(
+            CustomerChosen,
+            amount(50),
+            amount(100),
+            amount(1000)
+        )
+        .is_err());
+        assert!(
+            validate_amount_configuration(CustomerChosen, None, amount(1000), amount(100)).is_err()
+        );
+        assert!(validate_amount_configuration(CustomerChosen, amount(0), None, None).is_err());
+    }
+}
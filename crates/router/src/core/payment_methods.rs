@@ -1,3 +1,5 @@
+#[cfg(feature = "v1")]
+pub mod account_updater;
 pub mod cards;
 pub mod migration;
 pub mod network_tokenization;
@@ -0,0 +1,693 @@
+use std::{collections::HashMap, str::FromStr};
+
+use api_models::admin as admin_types;
+use common_utils::{
+    date_time,
+    ext_traits::{AsyncExt, ValueExt},
+    id_type,
+};
+use diesel_models::{
+    enums::{self as storage_enums, ProcessTrackerStatus},
+    process_tracker::business_status,
+};
+use error_stack::ResultExt;
+use external_services::account_updater::{
+    AccountUpdate, AccountUpdateRequest, AccountUpdaterInterface,
+};
+use masking::{ExposeInterface, PeekInterface, Secret};
+use router_env::{instrument, logger, metrics::add_attributes, tracing};
+
+use super::cards;
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments::helpers,
+        webhooks,
+    },
+    db::customers::CustomerListConstraints,
+    routes::{metrics, SessionState},
+    services::ApplicationResponse,
+    types::{api, domain, storage},
+};
+
+const ACCOUNT_UPDATER_TAG: &str = "PAYMENT_METHOD";
+const ACCOUNT_UPDATER_NAME: &str = "ACCOUNT_UPDATER";
+const ACCOUNT_UPDATER_RUNNER: diesel_models::ProcessTrackerRunner =
+    diesel_models::ProcessTrackerRunner::AccountUpdaterWorkflow;
+
+/// Saved card which is due to expire, along with the card details fetched from the locker.
+struct CardToBeUpdated {
+    payment_method: domain::PaymentMethod,
+    card_details: api::CardDetailsPaymentMethod,
+    card: api_models::payment_methods::Card,
+}
+
+#[instrument(skip_all)]
+pub async fn toggle_account_updater(
+    state: SessionState,
+    req: admin_types::ToggleAccountUpdaterRequest,
+) -> RouterResponse<admin_types::AccountUpdaterStatusResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &req.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    db.find_merchant_account_by_merchant_id(key_manager_state, &req.merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let task_id = generate_task_id_for_account_updater_workflow(&req.merchant_id);
+    let existing_process = db
+        .find_process_by_id(&task_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch account updater task")?;
+
+    let current_time = date_time::now();
+    let tracking_data = storage::AccountUpdaterTrackingData {
+        merchant_id: req.merchant_id.clone(),
+        customer_offset: 0,
+    };
+
+    match (existing_process, req.enabled) {
+        (Some(process), true) if process.status != ProcessTrackerStatus::Finish => {
+            logger::debug!("Account updater is already enabled for the merchant");
+        }
+        // The account updater has been enabled for this merchant before, the same task is reused
+        (Some(process), true) => {
+            let tracking_data = serde_json::to_value(&tracking_data)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to serialize account updater tracking data")?;
+            db.as_scheduler()
+                .update_process(
+                    process,
+                    storage::ProcessTrackerUpdate::Update {
+                        name: None,
+                        retry_count: Some(0),
+                        schedule_time: Some(current_time),
+                        tracking_data: Some(tracking_data),
+                        business_status: Some(String::from(business_status::PENDING)),
+                        status: Some(ProcessTrackerStatus::New),
+                        updated_at: Some(current_time),
+                    },
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to reschedule account updater task")?;
+            metrics::TASKS_ADDED_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &add_attributes([("flow", "AccountUpdater")]),
+            );
+        }
+        (None, true) => {
+            let process_tracker_entry = storage::ProcessTrackerNew::new(
+                task_id,
+                ACCOUNT_UPDATER_NAME,
+                ACCOUNT_UPDATER_RUNNER,
+                [ACCOUNT_UPDATER_TAG],
+                tracking_data,
+                current_time,
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to construct account updater task")?;
+
+            db.insert_process(process_tracker_entry)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to insert account updater task")?;
+            metrics::TASKS_ADDED_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &add_attributes([("flow", "AccountUpdater")]),
+            );
+        }
+        (Some(process), false) if process.status != ProcessTrackerStatus::Finish => {
+            db.as_scheduler()
+                .finish_process_with_business_status(process, business_status::REVOKED)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to revoke account updater task")?;
+        }
+        (_, false) => {
+            logger::debug!("Account updater is already disabled for the merchant");
+        }
+    }
+
+    retrieve_account_updater_status(state, req.merchant_id).await
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_account_updater_status(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+) -> RouterResponse<admin_types::AccountUpdaterStatusResponse> {
+    let process = state
+        .store
+        .find_process_by_id(&generate_task_id_for_account_updater_workflow(&merchant_id))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch account updater task")?
+        .filter(|process| process.status != ProcessTrackerStatus::Finish);
+
+    Ok(ApplicationResponse::Json(
+        admin_types::AccountUpdaterStatusResponse {
+            merchant_id,
+            enabled: process.is_some(),
+            next_run_at: process.and_then(|process| process.schedule_time),
+        },
+    ))
+}
+
+/// Checks the saved cards of a page of customers of the merchant for updates, starting at the
+/// specified offset. Returns the number of customers whose cards were checked.
+///
+/// Failures for a customer are logged and do not prevent the cards of other customers from being
+/// checked.
+#[instrument(skip_all)]
+pub async fn update_saved_cards(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    account_updater_client: &dyn AccountUpdaterInterface,
+    customer_offset: u32,
+) -> RouterResult<usize> {
+    let constraints = CustomerListConstraints {
+        limit: state.conf.account_updater.customers_per_run,
+        offset: Some(customer_offset),
+    };
+    let customers = state
+        .store
+        .list_customers_by_merchant_id(
+            &state.into(),
+            merchant_account.get_id(),
+            key_store,
+            constraints,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list customers of the merchant")?;
+
+    for customer in customers.iter() {
+        if let Err(error) = Box::pin(update_saved_cards_for_customer(
+            state,
+            merchant_account,
+            key_store,
+            account_updater_client,
+            &customer.customer_id,
+        ))
+        .await
+        {
+            logger::error!(
+                ?error,
+                customer_id = ?customer.customer_id,
+                "Failed to update saved cards of customer"
+            );
+        }
+    }
+
+    Ok(customers.len())
+}
+
+async fn update_saved_cards_for_customer(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    account_updater_client: &dyn AccountUpdaterInterface,
+    customer_id: &id_type::CustomerId,
+) -> RouterResult<()> {
+    let payment_methods = state
+        .store
+        .find_payment_method_by_customer_id_merchant_id_status(
+            &state.into(),
+            key_store,
+            customer_id,
+            merchant_account.get_id(),
+            storage_enums::PaymentMethodStatus::Active,
+            None,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch payment methods of customer")?;
+
+    let expiry_cutoff = date_time::now()
+        .date()
+        .saturating_add(time::Duration::days(i64::from(
+            state.conf.account_updater.expiry_window_in_days,
+        )));
+
+    let mut cards_to_be_updated = HashMap::new();
+    let mut requests = Vec::new();
+    for payment_method in payment_methods
+        .into_iter()
+        .filter(|pm| pm.payment_method == Some(storage_enums::PaymentMethod::Card))
+    {
+        let Some(card_details) = get_card_details(&payment_method)? else {
+            continue;
+        };
+
+        let is_expiring = card_details
+            .expiry_month
+            .as_ref()
+            .zip(card_details.expiry_year.as_ref())
+            .and_then(|(month, year)| get_card_expiry_date(month.peek(), year.peek()))
+            .is_some_and(|expiry_date| expiry_date <= expiry_cutoff);
+        if !is_expiring {
+            continue;
+        }
+
+        let card = match cards::get_card_from_locker(
+            state,
+            customer_id,
+            merchant_account.get_id(),
+            payment_method
+                .locker_id
+                .as_ref()
+                .unwrap_or(&payment_method.payment_method_id),
+        )
+        .await
+        {
+            Ok(card) => card,
+            Err(error) => {
+                logger::error!(
+                    ?error,
+                    payment_method_id = %payment_method.payment_method_id,
+                    "Failed to fetch card from locker"
+                );
+                continue;
+            }
+        };
+
+        requests.push(AccountUpdateRequest {
+            reference_id: payment_method.payment_method_id.clone(),
+            card_number: Secret::new(card.card_number.get_card_no()),
+            card_exp_month: card.card_exp_month.clone(),
+            card_exp_year: card.card_exp_year.clone(),
+        });
+        cards_to_be_updated.insert(
+            payment_method.payment_method_id.clone(),
+            CardToBeUpdated {
+                payment_method,
+                card_details,
+                card,
+            },
+        );
+    }
+
+    if requests.is_empty() {
+        return Ok(());
+    }
+
+    let responses = account_updater_client
+        .get_account_updates(requests)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch account updates for saved cards")?;
+
+    for response in responses {
+        let Some(card_to_be_updated) = cards_to_be_updated.remove(&response.reference_id) else {
+            logger::warn!(
+                reference_id = %response.reference_id,
+                "Received account update for unknown card"
+            );
+            continue;
+        };
+
+        if let Err(error) = Box::pin(apply_account_update(
+            state,
+            merchant_account,
+            key_store,
+            card_to_be_updated,
+            response.update,
+        ))
+        .await
+        {
+            logger::error!(
+                ?error,
+                payment_method_id = %response.reference_id,
+                "Failed to apply account update to saved card"
+            );
+        }
+    }
+
+    Ok(())
+}
+
+async fn apply_account_update(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    card_to_be_updated: CardToBeUpdated,
+    update: AccountUpdate,
+) -> RouterResult<()> {
+    let CardToBeUpdated {
+        payment_method,
+        card_details,
+        card,
+    } = card_to_be_updated;
+
+    let (update_type, updated_card) = match update {
+        AccountUpdate::NoChange => return Ok(()),
+        AccountUpdate::ExpiryUpdated {
+            card_exp_month,
+            card_exp_year,
+        } => (
+            storage_enums::CardAccountUpdateType::ExpiryUpdated,
+            Some((card.card_number.clone(), card_exp_month, card_exp_year)),
+        ),
+        AccountUpdate::CardReissued {
+            card_number,
+            card_exp_month,
+            card_exp_year,
+        } => {
+            let card_number = ::cards::CardNumber::from_str(card_number.peek())
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Received invalid card number in account update")?;
+            (
+                storage_enums::CardAccountUpdateType::CardReissued,
+                Some((card_number, card_exp_month, card_exp_year)),
+            )
+        }
+        AccountUpdate::AccountClosed => (storage_enums::CardAccountUpdateType::AccountClosed, None),
+    };
+
+    let db = state.store.as_ref();
+    let key_manager_state = &state.into();
+    let (pm_update, updated_card_details, previous_locker_id) = match updated_card {
+        Some((card_number, card_exp_month, card_exp_year)) => {
+            helpers::validate_card_expiry(&card_exp_month, &card_exp_year)?;
+
+            let updated_card = api::CardDetail {
+                card_number,
+                card_exp_month,
+                card_exp_year,
+                card_holder_name: card.name_on_card.clone(),
+                nick_name: card.nick_name.clone().map(Secret::new),
+                card_issuing_country: None,
+                card_network: None,
+                card_issuer: None,
+                card_type: None,
+            };
+            let pm_create = api::PaymentMethodCreate {
+                payment_method: payment_method.payment_method,
+                payment_method_type: payment_method.payment_method_type,
+                payment_method_issuer: payment_method.payment_method_issuer.clone(),
+                payment_method_issuer_code: payment_method.payment_method_issuer_code,
+                #[cfg(feature = "payouts")]
+                bank_transfer: None,
+                card: Some(updated_card.clone()),
+                #[cfg(feature = "payouts")]
+                wallet: None,
+                metadata: None,
+                customer_id: Some(payment_method.customer_id.clone()),
+                client_secret: payment_method.client_secret.clone(),
+                payment_method_data: None,
+                card_network: None,
+                billing: None,
+                connector_mandate_details: None,
+                network_transaction_id: None,
+            };
+
+            // The updated card is stored under a new reference, and the payment method is pointed
+            // to it before the previous card is deleted, so that the payment method never refers
+            // to a card which is missing from the locker
+            let (add_card_response, _) = Box::pin(cards::add_card_to_locker(
+                state,
+                pm_create,
+                &updated_card,
+                &payment_method.customer_id,
+                merchant_account,
+                None,
+            ))
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to add updated card to locker")?;
+            let previous_locker_id = payment_method
+                .locker_id
+                .clone()
+                .unwrap_or_else(|| payment_method.payment_method_id.clone());
+
+            let updated_card_details = api::CardDetailsPaymentMethod {
+                last4_digits: Some(updated_card.card_number.get_last4()),
+                expiry_month: Some(updated_card.card_exp_month.clone()),
+                expiry_year: Some(updated_card.card_exp_year.clone()),
+                card_isin: Some(updated_card.card_number.get_card_isin()),
+                ..card_details.clone()
+            };
+            let pm_data_encrypted =
+                Some(api::PaymentMethodsData::Card(updated_card_details.clone()))
+                    .async_map(|pm_data| {
+                        cards::create_encrypted_data(key_manager_state, key_store, pm_data)
+                    })
+                    .await
+                    .transpose()
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Unable to encrypt payment method data")?;
+
+            (
+                storage::PaymentMethodUpdate::AdditionalDataUpdate {
+                    payment_method_data: pm_data_encrypted.map(Into::into),
+                    status: None,
+                    locker_id: Some(add_card_response.payment_method_id),
+                    payment_method: None,
+                    payment_method_type: None,
+                    payment_method_issuer: None,
+                    network_token_requestor_reference_id: None,
+                    network_token_locker_id: None,
+                    network_token_payment_method_data: None,
+                },
+                updated_card_details,
+                Some(previous_locker_id),
+            )
+        }
+        None => (
+            storage::PaymentMethodUpdate::StatusUpdate {
+                status: Some(storage_enums::PaymentMethodStatus::Inactive),
+            },
+            card_details.clone(),
+            None,
+        ),
+    };
+
+    let payment_method = db
+        .update_payment_method(
+            key_manager_state,
+            key_store,
+            payment_method,
+            pm_update,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update payment method")?;
+
+    // A card left behind in the locker is not referred to by any payment method, so failing to
+    // delete it does not fail the account update
+    if let Some(previous_locker_id) = previous_locker_id {
+        cards::delete_card_from_locker(
+            state,
+            &payment_method.customer_id,
+            &payment_method.merchant_id,
+            &previous_locker_id,
+        )
+        .await
+        .map_err(|error| {
+            logger::error!(
+                ?error,
+                payment_method_id = %payment_method.payment_method_id,
+                "Failed to delete the previous card from the locker"
+            )
+        })
+        .ok();
+    }
+
+    let account_update = db
+        .insert_card_account_update(storage::CardAccountUpdateNew {
+            update_id: common_utils::generate_id(consts::ID_LENGTH, "cau"),
+            merchant_id: payment_method.merchant_id.clone(),
+            customer_id: payment_method.customer_id.clone(),
+            payment_method_id: payment_method.payment_method_id.clone(),
+            update_type,
+            previous_last4: card_details.last4_digits,
+            previous_expiry_month: card_details.expiry_month.map(ExposeInterface::expose),
+            previous_expiry_year: card_details.expiry_year.map(ExposeInterface::expose),
+            updated_last4: updated_card_details.last4_digits.clone(),
+            updated_expiry_month: updated_card_details
+                .expiry_month
+                .clone()
+                .map(ExposeInterface::expose),
+            updated_expiry_year: updated_card_details
+                .expiry_year
+                .clone()
+                .map(ExposeInterface::expose),
+            created_at: date_time::now(),
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert card account update")?;
+
+    logger::info!(
+        update_id = %account_update.update_id,
+        payment_method_id = %account_update.payment_method_id,
+        update_type = %account_update.update_type,
+        "Applied account update to saved card"
+    );
+    metrics::CARD_ACCOUNT_UPDATE_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &add_attributes([("update_type", account_update.update_type.to_string())]),
+    );
+
+    trigger_payment_method_updated_webhook(
+        state,
+        merchant_account,
+        key_store,
+        payment_method,
+        updated_card_details,
+        &account_update.update_id,
+    )
+    .await;
+
+    Ok(())
+}
+
+async fn trigger_payment_method_updated_webhook(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_method: domain::PaymentMethod,
+    card_details: api::CardDetailsPaymentMethod,
+    update_id: &str,
+) {
+    let payment_method_id = payment_method.payment_method_id.clone();
+    let result = async {
+        let profile_id = merchant_account.default_profile.as_ref().ok_or(
+            errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "default_profile",
+            },
+        )?;
+        let business_profile = state
+            .store
+            .find_business_profile_by_profile_id(&state.into(), key_store, profile_id)
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+                id: profile_id.get_string_repr().to_owned(),
+            })?;
+
+        let created_at = payment_method.created_at;
+        let payment_method_response = api::PaymentMethodResponse {
+            merchant_id: payment_method.merchant_id,
+            customer_id: Some(payment_method.customer_id),
+            payment_method_id: payment_method.payment_method_id,
+            payment_method: payment_method.payment_method,
+            payment_method_type: payment_method.payment_method_type,
+            #[cfg(feature = "payouts")]
+            bank_transfer: None,
+            card: Some(api::CardDetailFromLocker::from(card_details)),
+            metadata: payment_method.metadata,
+            created: Some(created_at),
+            recurring_enabled: false,
+            installment_payment_enabled: false,
+            payment_experience: Some(vec![api_models::enums::PaymentExperience::RedirectToUrl]),
+            last_used_at: Some(payment_method.last_used_at),
+            client_secret: payment_method.client_secret,
+        };
+
+        // Every update of a payment method is a separate event
+        Box::pin(webhooks::create_event_and_trigger_outgoing_webhook(
+            state.clone(),
+            merchant_account.clone(),
+            business_profile,
+            key_store,
+            storage_enums::EventType::PaymentMethodUpdated,
+            storage_enums::EventClass::PaymentMethods,
+            format!("{payment_method_id}_{update_id}"),
+            storage_enums::EventObjectType::PaymentMethodDetails,
+            api_models::webhooks::OutgoingWebhookContent::PaymentMethodDetails(Box::new(
+                payment_method_response,
+            )),
+            Some(created_at),
+        ))
+        .await
+    }
+    .await;
+
+    if let Err(error) = result {
+        logger::error!(
+            ?error,
+            %payment_method_id,
+            "Failed to trigger payment method updated webhook"
+        );
+    }
+}
+
+fn get_card_details(
+    payment_method: &domain::PaymentMethod,
+) -> RouterResult<Option<api::CardDetailsPaymentMethod>> {
+    let payment_method_data = payment_method
+        .payment_method_data
+        .clone()
+        .map(|value| value.into_inner().expose())
+        .map(|value| value.parse_value::<api::PaymentMethodsData>("PaymentMethodsData"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to deserialize payment methods data")?;
+
+    Ok(match payment_method_data {
+        Some(api::PaymentMethodsData::Card(card_details)) => Some(card_details),
+        _ => None,
+    })
+}
+
+/// Returns the last day on which a card with the specified expiry month and year is valid.
+/// Two digit years are assumed to be in the current century.
+fn get_card_expiry_date(card_exp_month: &str, card_exp_year: &str) -> Option<time::Date> {
+    let month = card_exp_month
+        .trim()
+        .parse::<u8>()
+        .ok()
+        .and_then(|month| time::Month::try_from(month).ok())?;
+    let year = card_exp_year.trim().parse::<i32>().ok()?;
+    let year = if card_exp_year.trim().len() == 2 {
+        year.checked_add(2000)?
+    } else {
+        year
+    };
+
+    time::Date::from_calendar_date(year, month, time::util::days_in_year_month(year, month)).ok()
+}
+
+fn generate_task_id_for_account_updater_workflow(merchant_id: &id_type::MerchantId) -> String {
+    format!(
+        "{ACCOUNT_UPDATER_RUNNER}_{ACCOUNT_UPDATER_NAME}_{}",
+        merchant_id.get_string_repr()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::date;
+
+    use super::*;
+
+    #[test]
+    fn test_get_card_expiry_date() {
+        assert_eq!(
+            get_card_expiry_date("02", "2028"),
+            Some(date!(2028 - 02 - 29))
+        );
+        assert_eq!(
+            get_card_expiry_date("12", "27"),
+            Some(date!(2027 - 12 - 31))
+        );
+        assert_eq!(get_card_expiry_date("13", "2027"), None);
+        assert_eq!(get_card_expiry_date("ab", "2027"), None);
+    }
+}
@@ -3453,6 +3453,7 @@ pub async fn list_payment_methods(
                     .get(key.0)
                     .and_then(|pm_map| pm_map.get(payment_method_types_hm.0))
                     .cloned(),
+                installment_plans: None,
             })
         }
 
@@ -3491,6 +3492,7 @@ pub async fn list_payment_methods(
                     .get(key.0)
                     .and_then(|pm_map| pm_map.get(payment_method_types_hm.0))
                     .cloned(),
+                installment_plans: None,
             })
         }
 
@@ -3524,6 +3526,7 @@ pub async fn list_payment_methods(
                     .get(&enums::PaymentMethod::BankRedirect)
                     .and_then(|pm_map| pm_map.get(key.0))
                     .cloned(),
+                installment_plans: None,
             }
         })
     }
@@ -3560,6 +3563,7 @@ pub async fn list_payment_methods(
                     .get(&enums::PaymentMethod::BankDebit)
                     .and_then(|pm_map| pm_map.get(key.0))
                     .cloned(),
+                installment_plans: None,
             }
         })
     }
@@ -3596,6 +3600,7 @@ pub async fn list_payment_methods(
                     .get(&enums::PaymentMethod::BankTransfer)
                     .and_then(|pm_map| pm_map.get(key.0))
                     .cloned(),
+                installment_plans: None,
             }
         })
     }
@@ -3606,6 +3611,45 @@ pub async fn list_payment_methods(
             payment_method_types: bank_transfer_payment_method_types,
         });
     }
+    #[cfg(feature = "v1")]
+    if let Some(((card_bin, payment_intent), payment_attempt)) = req
+        .card_bin
+        .as_deref()
+        .zip(payment_intent.as_ref())
+        .zip(payment_attempt.as_ref())
+    {
+        let installment_plans = crate::core::installments::list_installment_plans(
+            &state,
+            &merchant_account,
+            &filtered_mcas,
+            payment_intent,
+            payment_attempt,
+            card_bin,
+            billing_address.as_ref().and_then(|address| address.country),
+        )
+        .await?;
+
+        for payment_method_type in payment_method_responses
+            .iter_mut()
+            .filter(|pm| pm.payment_method == api_enums::PaymentMethod::Card)
+            .flat_map(|pm| pm.payment_method_types.iter_mut())
+        {
+            let eligible_connectors = response
+                .iter()
+                .filter(|intermediate| {
+                    intermediate.payment_method_type == payment_method_type.payment_method_type
+                })
+                .map(|intermediate| intermediate.connector.as_str())
+                .collect::<HashSet<_>>();
+            let plans = installment_plans
+                .iter()
+                .filter(|plan| eligible_connectors.contains(plan.connector.as_str()))
+                .cloned()
+                .collect::<Vec<_>>();
+            payment_method_type.installment_plans = (!plans.is_empty()).then_some(plans);
+        }
+    }
+
     let currency = payment_intent.as_ref().and_then(|pi| pi.currency);
     let skip_external_tax_calculation = payment_intent
         .as_ref()
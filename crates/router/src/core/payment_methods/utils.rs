@@ -58,14 +58,16 @@ pub async fn refresh_pm_filters_cache(
     key: &str,
     graph: cgraph::ConstraintGraph<dir::DirValue>,
 ) -> Arc<hyperswitch_constraint_graph::ConstraintGraph<dir::DirValue>> {
+    let graph_size = graph.approximate_size();
     let pm_filter_graph = Arc::new(graph);
     PM_FILTERS_CGRAPH_CACHE
-        .push(
+        .push_with_size(
             CacheKey {
                 key: key.to_string(),
                 prefix: state.tenant.redis_key_prefix.clone(),
             },
             pm_filter_graph.clone(),
+            graph_size,
         )
         .await;
     pm_filter_graph
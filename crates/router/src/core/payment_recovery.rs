@@ -0,0 +1,628 @@
+use std::str::FromStr;
+
+use api_models::{gsm::GsmDecision, mandates::RecurringDetails};
+use common_utils::{date_time, ext_traits::StringExt, id_type, types::MinorUnit};
+use diesel_models::enums as storage_enums;
+use error_stack::ResultExt;
+use hyperswitch_domain_models::payments::{payment_attempt::PaymentAttempt, PaymentIntent};
+use router_env::{instrument, logger, metrics::add_attributes, tracing};
+use scheduler::{consumer::types::process_data, utils as pt_utils};
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResult},
+        payments,
+    },
+    db::StorageInterface,
+    routes::{metrics, SessionState},
+    services::{self, ApplicationResponse},
+    types::{
+        api::{self as api_types, payments as payment_types},
+        domain, storage,
+    },
+};
+
+const PAYMENT_RECOVERY_TAG: &str = "PAYMENT_RECOVERY";
+const PAYMENT_RECOVERY_NAME: &str = "PAYMENT_RECOVERY";
+const PAYMENT_RECOVERY_RUNNER: diesel_models::ProcessTrackerRunner =
+    diesel_models::ProcessTrackerRunner::PaymentRecoveryWorkflow;
+
+/// The flow with which GSM rules for declined payments are looked up.
+const AUTHORIZE_FLOW: &str = "Authorize";
+
+/// A failed off-session payment, which can be recovered by charging the customer again using the
+/// same mandate or saved payment method.
+#[derive(Clone, Debug)]
+pub struct FailedRecurringPayment {
+    pub payment_id: id_type::PaymentId,
+    pub profile_id: id_type::ProfileId,
+    pub customer_id: id_type::CustomerId,
+    pub mandate_id: Option<String>,
+    pub payment_method_id: Option<String>,
+    pub connector: Option<String>,
+    pub amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub subscription_id: Option<String>,
+}
+
+impl FailedRecurringPayment {
+    /// Returns the details of the payment if it is a failed merchant initiated payment, made using
+    /// a mandate or a saved payment method.
+    pub fn from_payment(
+        payment_intent: &PaymentIntent,
+        payment_attempt: &PaymentAttempt,
+    ) -> Option<Self> {
+        if payment_intent.status != storage_enums::IntentStatus::Failed
+            || payment_intent.off_session != Some(true)
+            || (payment_attempt.mandate_id.is_none() && payment_attempt.payment_method_id.is_none())
+        {
+            return None;
+        }
+
+        Some(Self {
+            payment_id: payment_intent.payment_id.clone(),
+            profile_id: payment_intent.profile_id.clone()?,
+            customer_id: payment_intent.customer_id.clone()?,
+            mandate_id: payment_attempt.mandate_id.clone(),
+            payment_method_id: payment_attempt.payment_method_id.clone(),
+            connector: payment_attempt.connector.clone(),
+            amount: payment_intent.amount,
+            currency: payment_intent.currency?,
+            error_code: payment_attempt.error_code.clone(),
+            error_message: payment_attempt.error_message.clone(),
+            subscription_id: None,
+        })
+    }
+}
+
+/// The outcome of retrying a failed recurring payment.
+pub enum RecoveryPaymentOutcome {
+    /// The payment succeeded, or is being processed by the connector
+    Recovered { payment_id: id_type::PaymentId },
+    /// The payment failed again, or could not be created at all
+    Failed {
+        payment_id: Option<id_type::PaymentId>,
+        connector: Option<String>,
+        error_code: Option<String>,
+        error_message: Option<String>,
+    },
+}
+
+/// Starts the recovery of a failed recurring payment, if the recovery of failed payments has been
+/// enabled for the merchant. Payments which cannot be retried at all are recorded as lost right
+/// away, so that they are accounted for in analytics.
+#[instrument(skip_all)]
+pub async fn schedule_payment_recovery(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    organization_id: &id_type::OrganizationId,
+    failed_payment: FailedRecurringPayment,
+) -> RouterResult<Option<storage::PaymentRecovery>> {
+    let db = &*state.store;
+
+    if !is_payment_recovery_enabled(db, merchant_id).await {
+        return Ok(None);
+    }
+
+    if let Some(recovery) = db
+        .find_payment_recovery_by_merchant_id_payment_id(merchant_id, &failed_payment.payment_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch payment recovery")?
+    {
+        logger::debug!(recovery_id = %recovery.recovery_id, "Payment is already being recovered");
+        return Ok(Some(recovery));
+    }
+
+    let decline_category = get_decline_category(
+        state,
+        failed_payment.connector.as_ref(),
+        failed_payment.error_code.clone(),
+        failed_payment.error_message.clone(),
+    )
+    .await;
+    let mapping = get_payment_recovery_mapping(db).await;
+    let schedule_time = get_recovery_schedule_time(&mapping, decline_category, 0);
+
+    let now = date_time::now();
+    let recovery = db
+        .insert_payment_recovery(storage::PaymentRecoveryNew {
+            recovery_id: common_utils::generate_id(consts::ID_LENGTH, "recovery"),
+            merchant_id: merchant_id.to_owned(),
+            organization_id: organization_id.to_owned(),
+            profile_id: failed_payment.profile_id,
+            latest_payment_id: Some(failed_payment.payment_id.get_string_repr().to_owned()),
+            payment_id: failed_payment.payment_id,
+            customer_id: failed_payment.customer_id,
+            subscription_id: failed_payment.subscription_id,
+            mandate_id: failed_payment.mandate_id,
+            payment_method_id: failed_payment.payment_method_id,
+            connector: failed_payment.connector,
+            amount: failed_payment.amount,
+            currency: failed_payment.currency,
+            status: if schedule_time.is_some() {
+                storage_enums::PaymentRecoveryStatus::Scheduled
+            } else {
+                storage_enums::PaymentRecoveryStatus::Lost
+            },
+            decline_category: decline_category.to_string(),
+            attempt_count: 0,
+            error_code: failed_payment.error_code,
+            error_message: failed_payment.error_message,
+            created_at: now,
+            modified_at: now,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert payment recovery")?;
+
+    match schedule_time {
+        Some(schedule_time) => {
+            add_payment_recovery_task(db, &recovery, schedule_time).await?;
+            metrics::PAYMENT_RECOVERY_SCHEDULED_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &add_attributes([("decline_category", decline_category.to_string())]),
+            );
+        }
+        None => {
+            logger::info!(
+                recovery_id = %recovery.recovery_id,
+                "Retries of failed payments are disabled, not retrying"
+            );
+            metrics::PAYMENT_RECOVERY_LOST_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &add_attributes([("decline_category", decline_category.to_string())]),
+            );
+        }
+    }
+
+    Ok(Some(recovery))
+}
+
+/// Starts the recovery of the payment if it is a failed recurring payment. This is called whenever
+/// the status of a payment moves to failed, whether through the payment request itself, a sync
+/// with the connector or a webhook. Failures are only logged, as they must not affect the payment
+/// itself.
+#[instrument(skip_all)]
+pub async fn schedule_payment_recovery_if_applicable(
+    state: &SessionState,
+    payment_intent: &PaymentIntent,
+    payment_attempt: &PaymentAttempt,
+) {
+    let Some(failed_payment) =
+        FailedRecurringPayment::from_payment(payment_intent, payment_attempt)
+    else {
+        return;
+    };
+
+    let result = async {
+        // Failed payments created by the recovery and subscription billing workflows are handled
+        // by the workflows themselves
+        if is_created_by_workflow(
+            state,
+            &payment_intent.merchant_id,
+            &payment_intent.payment_id,
+        )
+        .await?
+        {
+            return Ok(None);
+        }
+
+        schedule_payment_recovery(
+            state,
+            &payment_intent.merchant_id,
+            &payment_intent.organization_id,
+            failed_payment,
+        )
+        .await
+    }
+    .await;
+
+    if let Err(error) = result {
+        logger::error!(
+            ?error,
+            payment_id = ?payment_intent.payment_id,
+            "Failed to schedule recovery of failed recurring payment"
+        );
+    }
+}
+
+/// Whether the payment was created by the payment recovery or subscription billing workflow. The
+/// IDs of such payments are derived from the ID of the recovery or the subscription, followed by
+/// the attempt or the billing cycle.
+async fn is_created_by_workflow(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    payment_id: &id_type::PaymentId,
+) -> RouterResult<bool> {
+    let Some((parent_id, _)) = payment_id.get_string_repr().rsplit_once('_') else {
+        return Ok(false);
+    };
+    let db = &*state.store;
+
+    let recovery = db
+        .find_payment_recovery_by_merchant_id_recovery_id(merchant_id, parent_id)
+        .await;
+    let subscription = db
+        .find_subscription_by_merchant_id_subscription_id(merchant_id, parent_id)
+        .await;
+
+    let is_found = |result: errors::CustomResult<(), errors::StorageError>| match result {
+        Ok(()) => Ok(true),
+        Err(error) if error.current_context().is_db_not_found() => Ok(false),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the workflow which created the payment"),
+    };
+
+    Ok(is_found(recovery.map(|_| ()))? || is_found(subscription.map(|_| ()))?)
+}
+
+/// Charges the customer again for a payment which is being recovered.
+#[instrument(skip_all)]
+pub async fn create_recovery_payment(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    recovery: &storage::PaymentRecovery,
+) -> RouterResult<RecoveryPaymentOutcome> {
+    let recurring_details = match (&recovery.mandate_id, &recovery.payment_method_id) {
+        (Some(mandate_id), _) => RecurringDetails::MandateId(mandate_id.clone()),
+        (None, Some(payment_method_id)) => {
+            RecurringDetails::PaymentMethodId(payment_method_id.clone())
+        }
+        (None, None) => Err(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Payment recovery has neither a mandate nor a payment method")?,
+    };
+    let payment_id = get_recovery_payment_id(recovery)?;
+
+    let req = payment_types::PaymentsRequest {
+        payment_id: Some(payment_types::PaymentIdType::PaymentIntentId(
+            payment_id.clone(),
+        )),
+        merchant_id: Some(recovery.merchant_id.clone()),
+        amount: Some(recovery.amount.into()),
+        currency: Some(recovery.currency),
+        customer_id: Some(recovery.customer_id.clone()),
+        confirm: Some(true),
+        off_session: Some(true),
+        recurring_details: Some(recurring_details),
+        profile_id: Some(recovery.profile_id.clone()),
+        description: Some(format!(
+            "Retry {} of failed payment {}",
+            recovery.attempt_count.saturating_add(1),
+            recovery.payment_id.get_string_repr()
+        )),
+        ..Default::default()
+    };
+
+    let payments_response = Box::pin(payments::payments_core::<
+        api_types::Authorize,
+        payment_types::PaymentsResponse,
+        _,
+        _,
+        _,
+        payments::PaymentData<api_types::Authorize>,
+    >(
+        state.clone(),
+        state.get_req_state(),
+        merchant_account.clone(),
+        Some(recovery.profile_id.clone()),
+        key_store.clone(),
+        payments::PaymentCreate,
+        req,
+        services::AuthFlow::Merchant,
+        payments::CallConnectorAction::Trigger,
+        None,
+        hyperswitch_domain_models::payments::HeaderPayload::default(),
+    ))
+    .await;
+
+    let payments_response = match payments_response {
+        Ok(ApplicationResponse::Json(response))
+        | Ok(ApplicationResponse::JsonWithHeaders((response, _))) => response,
+        // The payment for this attempt was created by an earlier run of the task which could not
+        // record its outcome, so its current status is used instead
+        Err(error)
+            if matches!(
+                error.current_context(),
+                errors::ApiErrorResponse::DuplicatePayment { .. }
+            ) =>
+        {
+            retrieve_recovery_payment(state, merchant_account, key_store, &payment_id).await?
+        }
+        Err(error) => {
+            logger::error!(?error, "Failed to create payment for recovery");
+            let (error_code, error_message) = match error.current_context() {
+                errors::ApiErrorResponse::ExternalConnectorError { code, message, .. } => {
+                    (Some(code.clone()), Some(message.clone()))
+                }
+                other => (None, Some(other.to_string())),
+            };
+            return Ok(RecoveryPaymentOutcome::Failed {
+                payment_id: None,
+                connector: recovery.connector.clone(),
+                error_code,
+                error_message,
+            });
+        }
+        Ok(_) => Err(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unexpected response from payments core")?,
+    };
+
+    Ok(match payments_response.status {
+        storage_enums::IntentStatus::Succeeded
+        | storage_enums::IntentStatus::Processing
+        | storage_enums::IntentStatus::RequiresCapture
+        | storage_enums::IntentStatus::PartiallyCaptured
+        | storage_enums::IntentStatus::PartiallyCapturedAndCapturable => {
+            RecoveryPaymentOutcome::Recovered {
+                payment_id: payments_response.payment_id,
+            }
+        }
+        storage_enums::IntentStatus::Failed
+        | storage_enums::IntentStatus::Cancelled
+        | storage_enums::IntentStatus::RequiresCustomerAction
+        | storage_enums::IntentStatus::RequiresMerchantAction
+        | storage_enums::IntentStatus::RequiresPaymentMethod
+        | storage_enums::IntentStatus::RequiresConfirmation => RecoveryPaymentOutcome::Failed {
+            payment_id: Some(payments_response.payment_id),
+            connector: payments_response.connector,
+            error_code: payments_response.error_code,
+            error_message: payments_response.error_message,
+        },
+    })
+}
+
+async fn retrieve_recovery_payment(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_id: &id_type::PaymentId,
+) -> RouterResult<payment_types::PaymentsResponse> {
+    let req = payment_types::PaymentsRetrieveRequest {
+        resource_id: payment_types::PaymentIdType::PaymentIntentId(payment_id.clone()),
+        merchant_id: Some(merchant_account.get_id().to_owned()),
+        force_sync: false,
+        ..Default::default()
+    };
+
+    match Box::pin(payments::payments_core::<
+        api_types::PSync,
+        payment_types::PaymentsResponse,
+        _,
+        _,
+        _,
+        payments::PaymentData<api_types::PSync>,
+    >(
+        state.clone(),
+        state.get_req_state(),
+        merchant_account.clone(),
+        None,
+        key_store.clone(),
+        payments::PaymentStatus,
+        req,
+        services::AuthFlow::Merchant,
+        payments::CallConnectorAction::Avoid,
+        None,
+        hyperswitch_domain_models::payments::HeaderPayload::default(),
+    ))
+    .await?
+    {
+        ApplicationResponse::Json(response)
+        | ApplicationResponse::JsonWithHeaders((response, _)) => Ok(response),
+        _ => Err(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unexpected response from payments core"),
+    }
+}
+
+/// Payment IDs are derived from the attempt count so that a payment is never retried twice for
+/// the same attempt.
+fn get_recovery_payment_id(
+    recovery: &storage::PaymentRecovery,
+) -> RouterResult<id_type::PaymentId> {
+    id_type::PaymentId::try_from(std::borrow::Cow::Owned(format!(
+        "{}_{}",
+        recovery.recovery_id,
+        recovery.attempt_count.saturating_add(1)
+    )))
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct payment ID for payment recovery")
+}
+
+/// Looks up the decision configured in GSM for the decline. Declines which are not configured in
+/// GSM result in `DoDefault`.
+#[instrument(skip_all)]
+pub async fn get_decline_category(
+    state: &SessionState,
+    connector: Option<&String>,
+    error_code: Option<String>,
+    error_message: Option<String>,
+) -> GsmDecision {
+    let Some(connector) = connector else {
+        return GsmDecision::DoDefault;
+    };
+
+    payments::helpers::get_gsm_record(
+        state,
+        error_code,
+        error_message,
+        connector.to_owned(),
+        AUTHORIZE_FLOW.to_string(),
+    )
+    .await
+    .and_then(|gsm| {
+        GsmDecision::from_str(&gsm.decision)
+            .map_err(|error| logger::warn!(?error, "Failed to parse GSM decision"))
+            .ok()
+    })
+    .unwrap_or_default()
+}
+
+/// Returns the time at which a payment should be retried, given the decline category of its last
+/// failure and the number of retries which have already been made. Returns `None` once
+/// `max_retries_count` retries have been made.
+///
+/// Declines without a GSM decision are treated as soft declines, and follow the `retry` schedule.
+pub fn get_recovery_schedule_time(
+    mapping: &process_data::PaymentRecoveryProcessTrackerMapping,
+    decline_category: GsmDecision,
+    retry_count: i32,
+) -> Option<time::PrimitiveDateTime> {
+    let retry_mapping = match decline_category {
+        GsmDecision::Retry | GsmDecision::DoDefault => &mapping.retry_mapping,
+        GsmDecision::Requeue => &mapping.requeue_mapping,
+    };
+
+    pt_utils::get_time_from_delta(pt_utils::get_payment_recovery_schedule_time(
+        retry_mapping,
+        mapping.max_retries_count,
+        retry_count,
+    ))
+}
+
+/// Get the retry schedules for failed recurring payments.
+///
+/// The schedules can be configured in configs with this key: `pt_mapping_payment_recovery`.
+///
+/// ```json
+/// {
+///   "retry_mapping": {
+///     "start_after": 3600,
+///     "frequencies": [[21600, 2]]
+///   },
+///   "requeue_mapping": {
+///     "start_after": 86400,
+///     "frequencies": [[259200, 2]]
+///   },
+///   "max_retries_count": 3
+/// }
+/// ```
+#[instrument(skip_all)]
+pub async fn get_payment_recovery_mapping(
+    db: &dyn StorageInterface,
+) -> process_data::PaymentRecoveryProcessTrackerMapping {
+    let key = "pt_mapping_payment_recovery";
+
+    let result = db
+        .find_config_by_key(key)
+        .await
+        .map(|value| value.config)
+        .and_then(|config| {
+            config
+                .parse_struct("PaymentRecoveryProcessTrackerMapping")
+                .change_context(errors::StorageError::DeserializationFailed)
+        });
+
+    result.unwrap_or_else(|error| {
+        if error.current_context().is_db_not_found() {
+            logger::debug!("Payment recovery retry config `{key}` not found, ignoring");
+        } else {
+            logger::error!(
+                ?error,
+                "Failed to read payment recovery retry config `{key}`"
+            );
+        }
+        process_data::PaymentRecoveryProcessTrackerMapping::default()
+    })
+}
+
+async fn is_payment_recovery_enabled(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+) -> bool {
+    let config = db
+        .find_config_by_key_unwrap_or(
+            &merchant_id.get_payment_recovery_enabled_key(),
+            Some("false".to_string()),
+        )
+        .await;
+    match config {
+        Ok(conf) => conf.config == "true",
+        Err(error) => {
+            logger::error!(?error);
+            false
+        }
+    }
+}
+
+async fn add_payment_recovery_task(
+    db: &dyn StorageInterface,
+    recovery: &storage::PaymentRecovery,
+    schedule_time: time::PrimitiveDateTime,
+) -> RouterResult<()> {
+    let task_id = format!(
+        "{PAYMENT_RECOVERY_RUNNER}_{PAYMENT_RECOVERY_NAME}_{}",
+        recovery.recovery_id
+    );
+    let tracking_data = storage::PaymentRecoveryTrackingData {
+        merchant_id: recovery.merchant_id.clone(),
+        recovery_id: recovery.recovery_id.clone(),
+    };
+
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        task_id,
+        PAYMENT_RECOVERY_NAME,
+        PAYMENT_RECOVERY_RUNNER,
+        [PAYMENT_RECOVERY_TAG],
+        tracking_data,
+        schedule_time,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct payment recovery task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert payment recovery task")?;
+
+    metrics::TASKS_ADDED_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &add_attributes([("flow", "PaymentRecovery")]),
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_declines_without_gsm_decision_are_retried_until_the_attempt_cap() {
+        let mapping = process_data::PaymentRecoveryProcessTrackerMapping::default();
+
+        for retry_count in 0..mapping.max_retries_count {
+            assert!(
+                get_recovery_schedule_time(&mapping, GsmDecision::DoDefault, retry_count).is_some()
+            );
+        }
+        assert!(get_recovery_schedule_time(
+            &mapping,
+            GsmDecision::DoDefault,
+            mapping.max_retries_count
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_declines_without_gsm_decision_follow_the_retry_schedule() {
+        let mapping = process_data::PaymentRecoveryProcessTrackerMapping::default();
+        let before = date_time::now();
+
+        let retry_time = get_recovery_schedule_time(&mapping, GsmDecision::DoDefault, 0);
+        let requeue_time = get_recovery_schedule_time(&mapping, GsmDecision::Requeue, 0);
+
+        let retry_delay = time::Duration::seconds(i64::from(mapping.retry_mapping.start_after));
+        assert!(
+            retry_time.is_some_and(|retry_time| retry_time >= before.saturating_add(retry_delay))
+        );
+        assert!(retry_time < requeue_time);
+    }
+}
@@ -3762,6 +3762,7 @@ where
     pub poll_config: Option<router_types::PollConfig>,
     pub tax_data: Option<TaxData>,
     pub session_id: Option<String>,
+    pub payment_splits: Vec<storage::PaymentSplit>,
 }
 
 #[derive(Clone, serde::Serialize, Debug)]
@@ -6142,6 +6143,7 @@ pub trait OperationSessionGetters<F> {
     fn get_disputes(&self) -> Vec<storage::Dispute>;
     fn get_authorizations(&self) -> Vec<diesel_models::authorization::Authorization>;
     fn get_attempts(&self) -> Option<Vec<storage::PaymentAttempt>>;
+    fn get_payment_splits(&self) -> Vec<storage::PaymentSplit>;
     fn get_recurring_details(&self) -> Option<&RecurringDetails>;
     // TODO: this should be a mandatory field, should we throw an error instead of returning an Option?
     fn get_payment_intent_profile_id(&self) -> Option<&id_type::ProfileId>;
@@ -6273,6 +6275,10 @@ impl<F: Clone> OperationSessionGetters<F> for PaymentData<F> {
         self.attempts.clone()
     }
 
+    fn get_payment_splits(&self) -> Vec<storage::PaymentSplit> {
+        self.payment_splits.clone()
+    }
+
     fn get_recurring_details(&self) -> Option<&RecurringDetails> {
         self.recurring_details.as_ref()
     }
@@ -6516,6 +6522,10 @@ impl<F: Clone> OperationSessionGetters<F> for PaymentIntentData<F> {
         todo!()
     }
 
+    fn get_payment_splits(&self) -> Vec<storage::PaymentSplit> {
+        todo!()
+    }
+
     fn get_recurring_details(&self) -> Option<&RecurringDetails> {
         todo!()
     }
@@ -6725,6 +6735,10 @@ impl<F: Clone> OperationSessionGetters<F> for PaymentConfirmData<F> {
         todo!()
     }
 
+    fn get_payment_splits(&self) -> Vec<storage::PaymentSplit> {
+        todo!()
+    }
+
     fn get_recurring_details(&self) -> Option<&RecurringDetails> {
         todo!()
     }
@@ -48,6 +48,8 @@ mod dummy_connector_default_impl {
 
     impl<const T: u8> api::PaymentPostSessionTokensV2 for connector::DummyConnector<T> {}
 
+    impl<const T: u8> api::PaymentInstallmentPlansV2 for connector::DummyConnector<T> {}
+
     impl<const T: u8>
         services::ConnectorIntegrationV2<
             api::Authorize,
@@ -214,6 +216,15 @@ mod dummy_connector_default_impl {
         > for connector::DummyConnector<T>
     {
     }
+    impl<const T: u8>
+        services::ConnectorIntegrationV2<
+            api::InstallmentPlans,
+            types::PaymentFlowData,
+            types::InstallmentPlansRequestData,
+            types::InstallmentPlansResponseData,
+        > for connector::DummyConnector<T>
+    {
+    }
 
     impl<const T: u8>
         services::ConnectorIntegrationV2<
@@ -593,6 +604,7 @@ macro_rules! default_imp_for_new_connector_integration_payment {
             impl api::TaxCalculationV2 for $path::$connector{}
             impl api::PaymentSessionUpdateV2 for $path::$connector{}
             impl api::PaymentPostSessionTokensV2 for $path::$connector{}
+            impl api::PaymentInstallmentPlansV2 for $path::$connector{}
             impl
             services::ConnectorIntegrationV2<api::Authorize,types::PaymentFlowData, types::PaymentsAuthorizeData, types::PaymentsResponseData>
             for $path::$connector{}
@@ -685,6 +697,13 @@ macro_rules! default_imp_for_new_connector_integration_payment {
                 types::PaymentsPostSessionTokensData,
                 types::PaymentsResponseData,
                 > for $path::$connector{}
+
+            impl services::ConnectorIntegrationV2<
+            api::InstallmentPlans,
+            types::PaymentFlowData,
+                types::InstallmentPlansRequestData,
+                types::InstallmentPlansResponseData,
+                > for $path::$connector{}
     )*
     };
 }
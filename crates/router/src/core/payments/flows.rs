@@ -1188,17 +1188,6 @@ macro_rules! default_imp_for_payouts_retrieve {
     };
 }
 
-#[cfg(feature = "payouts")]
-#[cfg(feature = "dummy_connector")]
-impl<const T: u8> api::PayoutSync for connector::DummyConnector<T> {}
-#[cfg(feature = "payouts")]
-#[cfg(feature = "dummy_connector")]
-impl<const T: u8>
-    services::ConnectorIntegration<api::PoSync, types::PayoutsData, types::PayoutsResponseData>
-    for connector::DummyConnector<T>
-{
-}
-
 #[cfg(feature = "payouts")]
 default_imp_for_payouts_retrieve!(
     connector::Adyenplatform,
@@ -1345,17 +1334,6 @@ macro_rules! default_imp_for_payouts_fulfill {
     };
 }
 
-#[cfg(feature = "payouts")]
-#[cfg(feature = "dummy_connector")]
-impl<const T: u8> api::PayoutFulfill for connector::DummyConnector<T> {}
-#[cfg(feature = "payouts")]
-#[cfg(feature = "dummy_connector")]
-impl<const T: u8>
-    services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
-    for connector::DummyConnector<T>
-{
-}
-
 #[cfg(feature = "payouts")]
 default_imp_for_payouts_fulfill!(
     connector::Aci,
@@ -2954,3 +2932,79 @@ default_imp_for_post_session_tokens!(
     connector::Worldpay,
     connector::Zsl
 );
+
+macro_rules! default_imp_for_installment_plans {
+    ($($path:ident::$connector:ident),*) => {
+        $( impl api::PaymentInstallmentPlans for $path::$connector {}
+            impl
+            services::ConnectorIntegration<
+                api::InstallmentPlans,
+                types::InstallmentPlansRequestData,
+                types::InstallmentPlansResponseData
+        > for $path::$connector
+        {}
+    )*
+    };
+}
+#[cfg(feature = "dummy_connector")]
+impl<const T: u8> api::PaymentInstallmentPlans for connector::DummyConnector<T> {}
+#[cfg(feature = "dummy_connector")]
+impl<const T: u8>
+    services::ConnectorIntegration<
+        api::InstallmentPlans,
+        types::InstallmentPlansRequestData,
+        types::InstallmentPlansResponseData,
+    > for connector::DummyConnector<T>
+{
+}
+
+default_imp_for_installment_plans!(
+    connector::Aci,
+    connector::Adyen,
+    connector::Adyenplatform,
+    connector::Airwallex,
+    connector::Authorizedotnet,
+    connector::Bamboraapac,
+    connector::Bankofamerica,
+    connector::Bluesnap,
+    connector::Boku,
+    connector::Braintree,
+    connector::Checkout,
+    connector::Cybersource,
+    connector::Datatrans,
+    connector::Ebanx,
+    connector::Globalpay,
+    connector::Gocardless,
+    connector::Gpayments,
+    connector::Iatapay,
+    connector::Itaubank,
+    connector::Klarna,
+    connector::Mifinity,
+    connector::Multisafepay,
+    connector::Netcetera,
+    connector::Nmi,
+    connector::Noon,
+    connector::Nuvei,
+    connector::Opayo,
+    connector::Opennode,
+    connector::Paybox,
+    connector::Payme,
+    connector::Payone,
+    connector::Paypal,
+    connector::Placetopay,
+    connector::Plaid,
+    connector::Prophetpay,
+    connector::Rapyd,
+    connector::Razorpay,
+    connector::Riskified,
+    connector::Shift4,
+    connector::Signifyd,
+    connector::Stripe,
+    connector::Threedsecureio,
+    connector::Trustpay,
+    connector::Wellsfargo,
+    connector::Wellsfargopayout,
+    connector::Wise,
+    connector::Worldpay,
+    connector::Zsl
+);
@@ -3857,6 +3857,7 @@ pub fn router_data_type_conversion<F1, F2, Req1, Req2, Res1, Res2>(
         integrity_check: Ok(()),
         connector_wallets_details: router_data.connector_wallets_details,
         additional_merchant_data: router_data.additional_merchant_data,
+        merchant_connector_id: router_data.merchant_connector_id,
         header_payload: router_data.header_payload,
     }
 }
@@ -4070,6 +4071,7 @@ impl AttemptType {
             organization_id: old_payment_attempt.organization_id,
             profile_id: old_payment_attempt.profile_id,
             connector_mandate_detail: None,
+            installment_plan: old_payment_attempt.installment_plan,
         }
     }
 
@@ -203,6 +203,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsCancelRequest>
             poll_config: None,
             tax_data: None,
             session_id: None,
+            payment_splits: vec![],
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -254,6 +254,7 @@ impl<F: Send + Clone> GetTracker<F, payments::PaymentData<F>, api::PaymentsCaptu
             poll_config: None,
             tax_data: None,
             session_id: None,
+            payment_splits: vec![],
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
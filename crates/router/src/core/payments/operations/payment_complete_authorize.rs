@@ -345,6 +345,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Co
             poll_config: None,
             tax_data: None,
             session_id: None,
+            payment_splits: vec![],
         };
 
         let customer_details = Some(CustomerDetails {
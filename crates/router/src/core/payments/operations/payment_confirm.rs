@@ -31,12 +31,13 @@ use crate::{
         authentication,
         blocklist::utils as blocklist_utils,
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
+        installments,
         mandate::helpers as m_helpers,
         payments::{
             self, helpers, operations, populate_surcharge_details, CustomerDetails, PaymentAddress,
             PaymentData,
         },
-        utils as core_utils,
+        split_payments, utils as core_utils,
     },
     routes::{app::ReqState, SessionState},
     services,
@@ -714,6 +715,28 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             .net_amount
             .set_order_tax_amount(order_tax_amount);
 
+        if let Some(installment_plan) = request.installment_plan.as_ref() {
+            payment_attempt.installment_plan = Some(
+                installments::resolve_installment_plan(
+                    state,
+                    &payment_intent.payment_id,
+                    payment_attempt.connector.as_deref(),
+                    installment_plan,
+                )
+                .await?,
+            );
+        }
+
+        let payment_splits = split_payments::find_payment_splits(
+            &*state.store,
+            merchant_id,
+            &payment_intent.payment_id,
+        )
+        .await?;
+        if let Some(amount) = request.amount {
+            split_payments::validate_payment_splits_amount(&payment_splits, amount.into())?;
+        }
+
         let payment_data = PaymentData {
             flow: PhantomData,
             payment_intent,
@@ -754,6 +777,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             poll_config: None,
             tax_data: None,
             session_id: None,
+            payment_splits,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -1099,6 +1123,20 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
     where
         F: 'b + Send,
     {
+        // The connector and the card are only known once the payment has been routed and the
+        // payment method data has been resolved
+        if let Some(installment_plan) = payment_data.payment_attempt.installment_plan.as_ref() {
+            let card_number = match payment_data.payment_method_data.as_ref() {
+                Some(domain::PaymentMethodData::Card(card)) => Some(&card.card_number),
+                _ => None,
+            };
+            installments::validate_installment_plan_for_payment(
+                installment_plan,
+                payment_data.payment_attempt.connector.as_deref(),
+                card_number,
+            )?;
+        }
+
         let payment_method = payment_data.payment_attempt.payment_method;
         let browser_info = payment_data.payment_attempt.browser_info.clone();
         let frm_message = payment_data.frm_message.clone();
@@ -1284,6 +1322,7 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
         let m_error_code = error_code.clone();
         let m_error_message = error_message.clone();
         let m_fingerprint_id = payment_data.payment_attempt.fingerprint_id.clone();
+        let m_installment_plan = payment_data.payment_attempt.installment_plan.clone();
         let m_db = state.clone().store;
         let surcharge_amount = payment_data
             .surcharge_details
@@ -1339,6 +1378,7 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
                         client_source,
                         client_version,
                         customer_acceptance: payment_data.payment_attempt.customer_acceptance,
+                        installment_plan: m_installment_plan,
                         net_amount:
                             hyperswitch_domain_models::payments::payment_attempt::NetAmount::new(
                                 payment_data.payment_attempt.net_amount.get_order_amount(),
@@ -1517,6 +1557,20 @@ impl<F: Send + Clone> ValidateRequest<F, api::PaymentsRequest, PaymentData<F>> f
             helpers::validate_max_amount(amount)?;
         }
 
+        if request.split_payments.is_some() {
+            return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "`split_payments` can only be provided while creating the payment"
+                    .to_string(),
+            }));
+        }
+
+        if let Some(installment_plan) = request.installment_plan.as_ref() {
+            installments::validate_installment_plan_selection(
+                installment_plan,
+                request.payment_method,
+            )?;
+        }
+
         let request_merchant_id = request.merchant_id.as_ref();
         helpers::validate_merchant_id(merchant_account.get_id(), request_merchant_id)
             .change_context(errors::ApiErrorResponse::InvalidDataFormat {
@@ -32,11 +32,12 @@ use crate::{
     consts,
     core::{
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
+        installments,
         mandate::helpers as m_helpers,
         payment_link,
         payment_methods::cards::create_encrypted_data,
         payments::{self, helpers, operations, CustomerDetails, PaymentAddress, PaymentData},
-        utils as core_utils,
+        split_payments, utils as core_utils,
     },
     db::StorageInterface,
     routes::{app::ReqState, SessionState},
@@ -335,6 +336,21 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 payment_id: payment_id.clone(),
             })?;
 
+        let payment_splits = match &request.split_payments {
+            Some(split_payments) => {
+                split_payments::create_payment_splits(
+                    db,
+                    merchant_id,
+                    &payment_id,
+                    currency,
+                    split_payments,
+                    payment_intent.amount,
+                )
+                .await?
+            }
+            None => vec![],
+        };
+
         if let Some(order_details) = &request.order_details {
             helpers::validate_order_details_amount(
                 order_details.to_owned(),
@@ -560,6 +576,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             poll_config: None,
             tax_data: None,
             session_id: None,
+            payment_splits,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -968,6 +985,22 @@ impl<F: Send + Clone> ValidateRequest<F, api::PaymentsRequest, PaymentData<F>> f
             helpers::validate_platform_fees_for_marketplace(amount, charges)?;
         };
 
+        if let Some(split_payments) = &request.split_payments {
+            let amount = request.amount.get_required_value("amount")?;
+            split_payments::validate_split_payments(
+                split_payments,
+                request.charges.as_ref(),
+                amount,
+            )?;
+        };
+
+        if let Some(installment_plan) = &request.installment_plan {
+            installments::validate_installment_plan_selection(
+                installment_plan,
+                request.payment_method,
+            )?;
+        };
+
         let _request_straight_through: Option<api::routing::StraightThroughAlgorithm> = request
             .routing
             .clone()
@@ -1052,6 +1085,15 @@ impl PaymentCreate {
                     payment_method_data_request.payment_method_data.as_ref()
                 });
 
+        let installment_plan = request
+            .installment_plan
+            .as_ref()
+            .async_map(|installment_plan| {
+                installments::resolve_installment_plan(state, payment_id, None, installment_plan)
+            })
+            .await
+            .transpose()?;
+
         let created_at @ modified_at @ last_synced = Some(common_utils::date_time::now());
         let status = helpers::payment_attempt_status_fsm(payment_method_data, request.confirm);
         let (amount, currency) = (money.0, Some(money.1));
@@ -1211,6 +1253,7 @@ impl PaymentCreate {
                 organization_id: organization_id.clone(),
                 profile_id,
                 connector_mandate_detail: None,
+                installment_plan,
             },
             additional_pm_data,
 
@@ -165,6 +165,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsPostSessionToke
             poll_config: None,
             tax_data: None,
             session_id: None,
+            payment_splits: vec![],
         };
         let get_trackers_response = operations::GetTrackerResponse {
             operation: Box::new(self),
@@ -27,8 +27,9 @@ use crate::{
     consts,
     core::{
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
-        mandate, payment_methods,
+        invoices, mandate, payment_methods,
         payment_methods::cards::create_encrypted_data,
+        payment_recovery,
         payments::{
             helpers::{
                 self as payments_helpers,
@@ -1889,6 +1890,7 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
         },
     };
 
+    let previous_intent_status = payment_data.payment_intent.status;
     let m_db = state.clone().store;
     let m_key_store = key_store.clone();
     let m_payment_data_payment_intent = payment_data.payment_intent.clone();
@@ -1966,6 +1968,38 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
             );
         }
     }
+    if payment_intent.status == enums::IntentStatus::Succeeded {
+        let state = state.clone();
+        let payment_intent = payment_intent.clone();
+        tokio::spawn(
+            async move {
+                invoices::update_invoice_on_payment_success(&state, &payment_intent)
+                    .await
+                    .map_err(|error| logger::error!(invoice_update_error=?error))
+                    .ok();
+            }
+            .in_current_span(),
+        );
+    }
+    // Failed merchant initiated payments are retried later on if recovery is enabled
+    if payment_intent.status == enums::IntentStatus::Failed
+        && previous_intent_status != enums::IntentStatus::Failed
+    {
+        let state = state.clone();
+        let payment_intent = payment_intent.clone();
+        let payment_attempt = payment_attempt.clone();
+        tokio::spawn(
+            async move {
+                payment_recovery::schedule_payment_recovery_if_applicable(
+                    &state,
+                    &payment_intent,
+                    &payment_attempt,
+                )
+                .await
+            }
+            .in_current_span(),
+        );
+    }
     payment_data.payment_intent = payment_intent;
     payment_data.payment_attempt = payment_attempt;
     router_data.payment_method_status.and_then(|status| {
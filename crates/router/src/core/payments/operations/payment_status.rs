@@ -15,6 +15,7 @@ use crate::{
             helpers, operations, types as payment_types, CustomerDetails, PaymentAddress,
             PaymentData,
         },
+        split_payments,
     },
     routes::{app::ReqState, SessionState},
     services,
@@ -439,6 +440,13 @@ async fn get_tracker_for_sync<
             None
         };
 
+    let payment_splits = split_payments::find_payment_splits(
+        db,
+        &payment_intent.merchant_id,
+        &payment_intent.payment_id,
+    )
+    .await?;
+
     let merchant_id = payment_intent.merchant_id.clone();
     let authentication = payment_attempt.authentication_id.clone().async_map(|authentication_id| async move {
             db.find_authentication_by_merchant_id_authentication_id(
@@ -506,6 +514,7 @@ async fn get_tracker_for_sync<
         poll_config: None,
         tax_data: None,
         session_id: None,
+        payment_splits,
     };
 
     let get_trackers_response = operations::GetTrackerResponse {
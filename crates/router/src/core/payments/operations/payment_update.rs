@@ -23,7 +23,7 @@ use crate::{
         mandate::helpers as m_helpers,
         payment_methods::cards::create_encrypted_data,
         payments::{self, helpers, operations, CustomerDetails, PaymentAddress, PaymentData},
-        utils as core_utils,
+        split_payments, utils as core_utils,
     },
     routes::{app::ReqState, SessionState},
     services,
@@ -363,6 +363,12 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 (Box::new(self), amount)
             };
 
+        let payment_splits =
+            split_payments::find_payment_splits(db, merchant_id, &payment_id).await?;
+        if let Some(amount) = request.amount {
+            split_payments::validate_payment_splits_amount(&payment_splits, amount.into())?;
+        }
+
         payment_intent.status = if request
             .payment_method_data
             .as_ref()
@@ -480,6 +486,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             poll_config: None,
             tax_data: None,
             session_id: None,
+            payment_splits,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -954,6 +961,13 @@ impl<F: Send + Clone> ValidateRequest<F, api::PaymentsRequest, PaymentData<F>> f
         if let Some(session_expiry) = &request.session_expiry {
             helpers::validate_session_expiry(session_expiry.to_owned())?;
         }
+
+        if request.split_payments.is_some() {
+            return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "`split_payments` can only be provided while creating the payment"
+                    .to_string(),
+            }));
+        }
         let payment_id = request
             .payment_id
             .clone()
@@ -653,6 +653,7 @@ pub fn make_new_payment_attempt(
         charge_id: Default::default(),
         customer_acceptance: Default::default(),
         connector_mandate_detail: Default::default(),
+        installment_plan: old_payment_attempt.installment_plan,
     }
 }
 
@@ -446,17 +446,20 @@ pub async fn refresh_routing_cache_v1(
     algorithm_id: &common_utils::id_type::RoutingId,
     profile_id: &common_utils::id_type::ProfileId,
 ) -> RoutingResult<Arc<CachedAlgorithm>> {
-    let algorithm = {
+    // The size of the stored algorithm is used as the estimate of the memory held by the cached
+    // algorithm, as the interpreter backend of an advanced algorithm holds the parsed program
+    let (algorithm, algorithm_size) = {
         let algorithm = state
             .store
             .find_routing_algorithm_by_profile_id_algorithm_id(profile_id, algorithm_id)
             .await
             .change_context(errors::RoutingError::DslMissingInDb)?;
+        let algorithm_size = algorithm.algorithm_data.to_string().len();
         let algorithm: routing_types::RoutingAlgorithm = algorithm
             .algorithm_data
             .parse_value("RoutingAlgorithm")
             .change_context(errors::RoutingError::DslParsingError)?;
-        algorithm
+        (algorithm, algorithm_size)
     };
 
     let cached_algorithm = match algorithm {
@@ -477,12 +480,13 @@ pub async fn refresh_routing_cache_v1(
     let arc_cached_algorithm = Arc::new(cached_algorithm);
 
     ROUTING_CACHE
-        .push(
+        .push_with_size(
             CacheKey {
                 key,
                 prefix: state.tenant.redis_key_prefix.clone(),
             },
             arc_cached_algorithm.clone(),
+            algorithm_size,
         )
         .await;
 
@@ -646,19 +650,20 @@ pub async fn refresh_cgraph_cache<'a>(
         connector_configs,
         default_configs,
     };
-    let cgraph = Arc::new(
-        mca_graph::make_mca_graph(api_mcas, &config_pm_filters)
-            .change_context(errors::RoutingError::KgraphCacheRefreshFailed)
-            .attach_printable("when construction cgraph")?,
-    );
+    let cgraph = mca_graph::make_mca_graph(api_mcas, &config_pm_filters)
+        .change_context(errors::RoutingError::KgraphCacheRefreshFailed)
+        .attach_printable("when construction cgraph")?;
+    let cgraph_size = cgraph.approximate_size();
+    let cgraph = Arc::new(cgraph);
 
     CGRAPH_CACHE
-        .push(
+        .push_with_size(
             CacheKey {
                 key,
                 prefix: state.tenant.redis_key_prefix.clone(),
             },
             Arc::clone(&cgraph),
+            cgraph_size,
         )
         .await;
 
@@ -29,7 +29,7 @@ use crate::{
     core::{
         errors::{self, RouterResponse, RouterResult},
         payments::{self, helpers},
-        utils as core_utils,
+        split_payments, utils as core_utils,
     },
     headers::X_PAYMENT_CONFIRM_SOURCE,
     routes::{metrics, SessionState},
@@ -158,6 +158,7 @@ where
         connector_response: None,
         integrity_check: Ok(()),
         additional_merchant_data: None,
+        merchant_connector_id: merchant_connector_account.get_mca_id(),
         header_payload: None,
     };
     Ok(router_data)
@@ -276,6 +277,8 @@ pub async fn construct_payment_router_data_for_authorize<'a>(
         authentication_data: None,
         customer_acceptance: None,
         charges: None,
+        split_payments: None,
+        installment_plan: None,
         merchant_order_reference_id: None,
         integrity_object: None,
         shipping_cost: payment_data.payment_intent.amount_details.shipping_cost,
@@ -354,6 +357,7 @@ pub async fn construct_payment_router_data_for_authorize<'a>(
         connector_response: None,
         integrity_check: Ok(()),
         additional_merchant_data: None,
+        merchant_connector_id: merchant_connector_account.get_mca_id(),
         header_payload,
     };
 
@@ -569,6 +573,7 @@ where
                 types::AdditionalMerchantData::OpenBankingRecipientData(data),
             )
         }),
+        merchant_connector_id: merchant_connector_account.get_mca_id(),
         header_payload,
     };
 
@@ -1471,6 +1476,12 @@ where
             }
         };
 
+        let split_payments_response = split_payments::get_split_payments_response(
+            &payment_data.get_payment_splits(),
+            payment_attempt.get_total_amount(),
+            payment_attempt.connector.as_deref(),
+        );
+
         let mandate_data = payment_data.get_setup_mandate().map(|d| api::MandateData {
             customer_acceptance: d
                 .customer_acceptance
@@ -1642,6 +1653,10 @@ where
                 .map(|info| info.status),
             updated: Some(payment_intent.modified_at),
             charges: charges_response,
+            split_payments: split_payments_response,
+            installment_plan: payment_attempt
+                .installment_plan
+                .map(ForeignFrom::foreign_from),
             frm_metadata: payment_intent.frm_metadata,
             merchant_order_reference_id: payment_intent.merchant_order_reference_id,
             order_tax_amount,
@@ -1900,6 +1915,8 @@ impl ForeignFrom<(storage::PaymentIntent, storage::PaymentAttempt)> for api::Pay
             payment_method_status: None,
             updated: None,
             charges: None,
+            split_payments: None,
+            installment_plan: None,
             frm_metadata: None,
             order_tax_amount: None,
             connector_mandate_id:None,
@@ -2147,6 +2164,19 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsAuthoriz
             None => None,
         };
 
+        let split_payments =
+            payment_data
+                .payment_attempt
+                .connector
+                .as_deref()
+                .and_then(|connector| {
+                    split_payments::get_split_payments_data(
+                        connector,
+                        &payment_data.payment_splits,
+                        amount,
+                    )
+                });
+
         let merchant_order_reference_id = payment_data
             .payment_intent
             .merchant_order_reference_id
@@ -2196,6 +2226,8 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsAuthoriz
                 .transpose()?,
             customer_acceptance: payment_data.customer_acceptance,
             charges,
+            split_payments,
+            installment_plan: payment_data.payment_attempt.installment_plan.clone(),
             merchant_order_reference_id,
             integrity_object: None,
             shipping_cost,
@@ -0,0 +1,641 @@
+use std::collections::HashSet;
+
+use common_utils::{
+    consts::DEFAULT_LOCALE,
+    date_time,
+    errors::{CustomResult, ErrorSwitch},
+    ext_traits::{Encode, ValueExt},
+    types::MinorUnit,
+};
+use diesel_models::enums as storage_enums;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, metrics::add_attributes, tracing};
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        files::helpers as file_helpers,
+        payouts::{self, validator},
+        utils as core_utils,
+    },
+    db::StorageInterface,
+    routes::{metrics, SessionState},
+    services::ApplicationResponse,
+    types::{
+        api::{self, payouts as payout_types},
+        domain, storage,
+        transformers::ForeignTryFrom,
+    },
+};
+
+const PAYOUT_BATCH_TAG: &str = "PAYOUT_BATCH";
+const PAYOUT_BATCH_NAME: &str = "PAYOUT_BATCH";
+const PAYOUT_BATCH_RUNNER: diesel_models::ProcessTrackerRunner =
+    diesel_models::ProcessTrackerRunner::PayoutBatchWorkflow;
+
+/// The maximum number of payouts which can be created from a single file.
+const MAX_PAYOUT_BATCH_ROWS: usize = 10000;
+
+/// The number of rows processed each time the payout batch task is run, so that a large file does
+/// not hold up the scheduler and the progress of the batch is persisted regularly.
+const PAYOUT_BATCH_CHUNK_SIZE: usize = 100;
+
+const CSV_MIME_TYPE: &str = "text/csv";
+const JSON_MIME_TYPE: &str = "application/json";
+
+#[instrument(skip_all)]
+pub async fn payout_batch_create_core(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: payout_types::PayoutBatchCreateRequest,
+) -> RouterResponse<payout_types::PayoutBatchResponse> {
+    let db = &*state.store;
+    let merchant_id = merchant_account.get_id();
+
+    let profile_id = core_utils::get_profile_id_from_business_details(
+        &(&state).into(),
+        &key_store,
+        None,
+        None,
+        &merchant_account,
+        req.profile_id.as_ref(),
+        db,
+        true,
+    )
+    .await?;
+
+    let requests =
+        get_payout_batch_requests(&state, &merchant_account, &key_store, &req.file_id).await?;
+    validate_payout_batch_requests(db, &merchant_account, &requests).await?;
+    let total_count = i32::try_from(requests.len())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to convert the number of rows of the payout batch file")?;
+
+    let now = date_time::now();
+    let batch_new = storage::PayoutBatchNew {
+        batch_id: common_utils::generate_id(consts::ID_LENGTH, "batch"),
+        merchant_id: merchant_id.clone(),
+        profile_id,
+        file_id: req.file_id,
+        status: storage_enums::PayoutBatchStatus::Pending,
+        total_count,
+        processed_count: 0,
+        succeeded_count: 0,
+        failed_count: 0,
+        results: serde_json::Value::Array(vec![]),
+        result_file_id: None,
+        created_at: now,
+        modified_at: now,
+    };
+    let batch = db
+        .insert_payout_batch(batch_new)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert payout batch")?;
+
+    add_payout_batch_task(db, &batch, now).await?;
+
+    Ok(ApplicationResponse::Json(
+        payout_types::PayoutBatchResponse::foreign_try_from(batch)?,
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn payout_batch_retrieve_core(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    req: payout_types::PayoutBatchId,
+) -> RouterResponse<payout_types::PayoutBatchResponse> {
+    let batch = state
+        .store
+        .find_payout_batch_by_merchant_id_batch_id(merchant_account.get_id(), &req.batch_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Payout batch `{}` does not exist", req.batch_id),
+        })?;
+
+    Ok(ApplicationResponse::Json(
+        payout_types::PayoutBatchResponse::foreign_try_from(batch)?,
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn payout_batch_results_retrieve_core(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: payout_types::PayoutBatchId,
+) -> RouterResponse<serde_json::Value> {
+    let batch = state
+        .store
+        .find_payout_batch_by_merchant_id_batch_id(merchant_account.get_id(), &req.batch_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Payout batch `{}` does not exist", req.batch_id),
+        })?;
+    let result_file_id =
+        batch
+            .result_file_id
+            .ok_or(errors::ApiErrorResponse::PreconditionFailed {
+                message: "The results of the payout batch are not available yet".to_string(),
+            })?;
+
+    let file_info = file_helpers::retrieve_file_and_provider_file_id_from_file_id(
+        &state,
+        Some(result_file_id),
+        &merchant_account,
+        &key_store,
+        api::FileDataRequired::Required,
+    )
+    .await?;
+
+    Ok(ApplicationResponse::FileData((
+        file_info
+            .file_data
+            .ok_or(errors::ApiErrorResponse::FileNotAvailable)
+            .attach_printable("File data not found")?,
+        mime::TEXT_CSV,
+    )))
+}
+
+/// Reads the payouts to be created from the file uploaded for the batch.
+async fn get_payout_batch_requests(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    file_id: &str,
+) -> RouterResult<Vec<payout_types::PayoutCreateRequest>> {
+    let file_info = file_helpers::retrieve_file_and_provider_file_id_from_file_id(
+        state,
+        Some(file_id.to_owned()),
+        merchant_account,
+        key_store,
+        api::FileDataRequired::Required,
+    )
+    .await?;
+    let file_data = file_info
+        .file_data
+        .ok_or(errors::ApiErrorResponse::FileNotAvailable)
+        .attach_printable("File data not found")?;
+
+    parse_payout_batch_file(&file_data, file_info.file_type.as_deref())
+}
+
+fn parse_payout_batch_file(
+    file_data: &[u8],
+    file_type: Option<&str>,
+) -> CustomResult<Vec<payout_types::PayoutCreateRequest>, errors::ApiErrorResponse> {
+    match file_type {
+        Some(CSV_MIME_TYPE) => csv::Reader::from_reader(file_data)
+            .deserialize::<payout_types::PayoutBatchRecord>()
+            .enumerate()
+            .map(|(index, record)| {
+                record
+                    .map(payout_types::PayoutCreateRequest::from)
+                    .map_err(|error| errors::ApiErrorResponse::InvalidRequestData {
+                        message: format!("Invalid row {} in the file: {error}", index + 1),
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(error_stack::Report::from),
+        Some(JSON_MIME_TYPE) => serde_json::from_slice(file_data).map_err(|error| {
+            report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("Invalid payouts in the file: {error}"),
+            })
+        }),
+        _ => Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "The payout batch file must either be a CSV or a JSON file".to_string(),
+        })),
+    }
+}
+
+/// Validates all the rows of the file before any payout is created, so that a file with mistakes
+/// can be corrected and uploaded again as a whole.
+async fn validate_payout_batch_requests(
+    db: &dyn StorageInterface,
+    merchant_account: &domain::MerchantAccount,
+    requests: &[payout_types::PayoutCreateRequest],
+) -> RouterResult<()> {
+    if requests.is_empty() {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "The payout batch file does not contain any payouts".to_string(),
+        }));
+    }
+    if requests.len() > MAX_PAYOUT_BATCH_ROWS {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "The payout batch file cannot contain more than {MAX_PAYOUT_BATCH_ROWS} payouts"
+            ),
+        }));
+    }
+
+    let mut payout_ids = HashSet::new();
+    let mut row_errors = Vec::new();
+    for (index, req) in requests.iter().enumerate() {
+        let row_number = index + 1;
+        if let Err(error) = validator::validate_payout_batch_row(merchant_account, req) {
+            let mut api_error = error.switch();
+            row_errors.push(format!(
+                "row {row_number}: {}",
+                api_error.get_internal_error_mut().error_message
+            ));
+            continue;
+        }
+
+        if let Some(payout_id) = &req.payout_id {
+            let is_duplicate = !payout_ids.insert(payout_id.clone())
+                || validator::validate_uniqueness_of_payout_id_against_merchant_id(
+                    db,
+                    payout_id,
+                    merchant_account.get_id(),
+                    merchant_account.storage_scheme,
+                )
+                .await?
+                .is_some();
+            if is_duplicate {
+                row_errors.push(format!("row {row_number}: duplicate payout_id {payout_id}"));
+            }
+        }
+    }
+
+    if row_errors.is_empty() {
+        Ok(())
+    } else {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("Invalid payouts in the file: {}", row_errors.join(", ")),
+        }))
+    }
+}
+
+async fn add_payout_batch_task(
+    db: &dyn StorageInterface,
+    batch: &storage::PayoutBatch,
+    schedule_time: time::PrimitiveDateTime,
+) -> RouterResult<()> {
+    let task_id = format!(
+        "{PAYOUT_BATCH_RUNNER}_{PAYOUT_BATCH_NAME}_{}",
+        batch.batch_id
+    );
+    let tracking_data = storage::PayoutBatchTrackingData {
+        merchant_id: batch.merchant_id.clone(),
+        batch_id: batch.batch_id.clone(),
+    };
+
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        task_id,
+        PAYOUT_BATCH_NAME,
+        PAYOUT_BATCH_RUNNER,
+        [PAYOUT_BATCH_TAG],
+        tracking_data,
+        schedule_time,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct payout batch task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert payout batch task")?;
+
+    metrics::TASKS_ADDED_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &add_attributes([("flow", "PayoutBatch")]),
+    );
+
+    Ok(())
+}
+
+/// Creates the payouts for the next chunk of rows of the file which have not been processed yet,
+/// and returns the batch with its progress updated.
+#[instrument(skip_all, fields(batch_id = %batch.batch_id))]
+pub async fn process_payout_batch_rows(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    batch: storage::PayoutBatch,
+) -> RouterResult<storage::PayoutBatch> {
+    let requests =
+        get_payout_batch_requests(state, merchant_account, key_store, &batch.file_id).await?;
+    let mut results = get_payout_batch_results(&batch)?;
+    let processed_count = usize::try_from(batch.processed_count)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid processed count of the payout batch")?;
+
+    for (index, req) in requests
+        .into_iter()
+        .enumerate()
+        .skip(processed_count)
+        .take(PAYOUT_BATCH_CHUNK_SIZE)
+    {
+        let row_number = i32::try_from(index + 1)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to convert the row number of the payout batch file")?;
+        let row_result =
+            create_payout_for_row(state, merchant_account, key_store, &batch, row_number, req)
+                .await?;
+        results.push(row_result);
+    }
+
+    let processed_count = i32::try_from(results.len())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to convert the processed count of the payout batch")?;
+    let failed_count = i32::try_from(
+        results
+            .iter()
+            .filter(|result| result.error_code.is_some())
+            .count(),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to convert the failed count of the payout batch")?;
+
+    state
+        .store
+        .update_payout_batch_by_merchant_id_batch_id(
+            &batch.merchant_id,
+            &batch.batch_id,
+            storage::PayoutBatchUpdate::ProgressUpdate {
+                processed_count,
+                succeeded_count: processed_count.saturating_sub(failed_count),
+                failed_count,
+                results: results
+                    .encode_to_value()
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to encode the results of the payout batch")?,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the progress of the payout batch")
+}
+
+/// Creates the payout for a row of the file. Payouts which could not be created are recorded in
+/// the results of the batch rather than failing the batch as a whole.
+async fn create_payout_for_row(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    batch: &storage::PayoutBatch,
+    row_number: i32,
+    mut req: payout_types::PayoutCreateRequest,
+) -> RouterResult<payout_types::PayoutBatchRowResult> {
+    // The payout_id is derived from the row when not provided, so that rows are not paid out
+    // twice when the task is retried after the payout has been created
+    let is_payout_id_provided = req.payout_id.is_some();
+    let payout_id = req
+        .payout_id
+        .take()
+        .unwrap_or_else(|| format!("{}_{row_number}", batch.batch_id));
+    let amount = req.amount.map(MinorUnit::from);
+    let currency = req.currency;
+    req.payout_id = Some(payout_id.clone());
+    req.profile_id = Some(batch.profile_id.clone());
+    req.confirm = Some(true);
+
+    let response = Box::pin(payouts::payouts_create_core(
+        state.clone(),
+        merchant_account.clone(),
+        key_store.clone(),
+        req,
+        DEFAULT_LOCALE,
+    ))
+    .await;
+
+    match response {
+        Ok(ApplicationResponse::Json(payout)) => Ok(payout_types::PayoutBatchRowResult {
+            row_number,
+            payout_id: Some(payout.payout_id),
+            status: Some(payout.status),
+            error_code: payout.error_code,
+            error_message: payout.error_message,
+        }),
+        Ok(_) => Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Unexpected response from payouts create"),
+        Err(error) => match error.current_context() {
+            errors::ApiErrorResponse::DuplicatePayout { .. } => {
+                let payout = state
+                    .store
+                    .find_payout_by_merchant_id_payout_id(
+                        merchant_account.get_id(),
+                        &payout_id,
+                        merchant_account.storage_scheme,
+                    )
+                    .await
+                    .to_not_found_response(errors::ApiErrorResponse::PayoutNotFound)?;
+
+                // A payout_id provided in the file may belong to a payout which was created
+                // outside of this batch, and is only taken as the payout of the row if it matches
+                // the row
+                if is_payout_id_provided
+                    && !is_payout_created_for_row(&payout, batch, amount, currency)
+                {
+                    logger::info!(row_number, "Payout of the batch row already exists");
+                    return Ok(get_failed_row_result(row_number, error.current_context()));
+                }
+
+                Ok(payout_types::PayoutBatchRowResult {
+                    row_number,
+                    payout_id: Some(payout_id),
+                    status: Some(payout.status),
+                    error_code: None,
+                    error_message: None,
+                })
+            }
+            errors::ApiErrorResponse::InternalServerError => Err(error),
+            api_error => {
+                logger::info!(?error, row_number, "Failed to create payout of the batch");
+                Ok(get_failed_row_result(row_number, api_error))
+            }
+        },
+    }
+}
+
+/// Whether the payout was created by this batch for a row with the given amount and currency.
+fn is_payout_created_for_row(
+    payout: &hyperswitch_domain_models::payouts::payouts::Payouts,
+    batch: &storage::PayoutBatch,
+    amount: Option<MinorUnit>,
+    currency: Option<storage_enums::Currency>,
+) -> bool {
+    payout.profile_id == batch.profile_id
+        && payout.created_at >= batch.created_at
+        && amount == Some(payout.amount)
+        && currency == Some(payout.destination_currency)
+}
+
+fn get_failed_row_result(
+    row_number: i32,
+    api_error: &errors::ApiErrorResponse,
+) -> payout_types::PayoutBatchRowResult {
+    let mut api_error = api_error.switch();
+    let api_error = api_error.get_internal_error_mut();
+    payout_types::PayoutBatchRowResult {
+        row_number,
+        payout_id: None,
+        status: None,
+        error_code: Some(format!(
+            "{}_{:02}",
+            api_error.sub_code, api_error.error_identifier
+        )),
+        error_message: Some(api_error.error_message.clone()),
+    }
+}
+
+/// Uploads the file with the results of all the rows, and marks the batch as processed.
+#[instrument(skip_all, fields(batch_id = %batch.batch_id))]
+pub async fn complete_payout_batch(
+    state: &SessionState,
+    batch: storage::PayoutBatch,
+) -> RouterResult<storage::PayoutBatch> {
+    let results = get_payout_batch_results(&batch)?;
+    let mut writer = csv::Writer::from_writer(vec![]);
+    for result in &results {
+        writer
+            .serialize(result)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to write the results of the payout batch")?;
+    }
+    let file_data = writer
+        .into_inner()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to write the results of the payout batch")?;
+
+    let result_file_id = common_utils::generate_id(consts::ID_LENGTH, "file");
+    let file_key = format!("{}/{}", batch.merchant_id.get_string_repr(), result_file_id);
+    let file_size = i32::try_from(file_data.len())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to convert the size of the payout batch results file")?;
+    state
+        .file_storage_client
+        .upload_file(&file_key, file_data)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to upload the results of the payout batch")?;
+    state
+        .store
+        .insert_file_metadata(diesel_models::file::FileMetadataNew {
+            file_id: result_file_id.clone(),
+            merchant_id: batch.merchant_id.clone(),
+            file_name: Some(format!("{}_results.csv", batch.batch_id)),
+            file_size,
+            file_type: CSV_MIME_TYPE.to_string(),
+            provider_file_id: Some(file_key),
+            file_upload_provider: Some(storage_enums::FileUploadProvider::Router),
+            available: true,
+            connector_label: None,
+            profile_id: Some(batch.profile_id.clone()),
+            merchant_connector_id: None,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the results file of the payout batch")?;
+
+    let status = match (batch.succeeded_count, batch.failed_count) {
+        (_, 0) => storage_enums::PayoutBatchStatus::Completed,
+        (0, _) => storage_enums::PayoutBatchStatus::Failed,
+        _ => storage_enums::PayoutBatchStatus::PartiallyCompleted,
+    };
+    state
+        .store
+        .update_payout_batch_by_merchant_id_batch_id(
+            &batch.merchant_id,
+            &batch.batch_id,
+            storage::PayoutBatchUpdate::CompletionUpdate {
+                status,
+                result_file_id: Some(result_file_id),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to mark the payout batch as processed")
+}
+
+fn get_payout_batch_results(
+    batch: &storage::PayoutBatch,
+) -> RouterResult<Vec<payout_types::PayoutBatchRowResult>> {
+    batch
+        .results
+        .clone()
+        .parse_value("PayoutBatchRowResult")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the results of the payout batch")
+}
+
+impl ForeignTryFrom<storage::PayoutBatch> for payout_types::PayoutBatchResponse {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+
+    fn foreign_try_from(batch: storage::PayoutBatch) -> Result<Self, Self::Error> {
+        let results = get_payout_batch_results(&batch)?;
+        Ok(Self {
+            batch_id: batch.batch_id,
+            merchant_id: batch.merchant_id,
+            profile_id: batch.profile_id,
+            file_id: batch.file_id,
+            status: batch.status,
+            total_count: batch.total_count,
+            processed_count: batch.processed_count,
+            succeeded_count: batch.succeeded_count,
+            failed_count: batch.failed_count,
+            results,
+            result_file_id: batch.result_file_id,
+            created: batch.created_at,
+            updated: batch.modified_at,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use common_utils::types::MinorUnit;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_payout_batch_csv_file() {
+        let file_data = "payout_id,amount,currency,customer_id,payout_type,payout_token,connector,description,priority,auto_fulfill,email,name\n\
+                         payout_1,1000,USD,cus_1,bank,token_1,adyen,Salary,instant,true,john@example.com,John Doe\n\
+                         ,250,EUR,,,,,,,,,\n";
+        let requests = parse_payout_batch_file(file_data.as_bytes(), Some(CSV_MIME_TYPE)).unwrap();
+
+        assert_eq!(requests.len(), 2);
+        let first = requests.first().unwrap();
+        assert_eq!(first.payout_id.as_deref(), Some("payout_1"));
+        assert_eq!(
+            first.amount,
+            Some(api_models::payments::Amount::from(MinorUnit::new(1000)))
+        );
+        assert_eq!(first.currency, Some(storage_enums::Currency::USD));
+        assert_eq!(
+            first.connector,
+            Some(vec![api_models::enums::PayoutConnectors::Adyen])
+        );
+        assert_eq!(first.auto_fulfill, Some(true));
+        let second = requests.get(1).unwrap();
+        assert_eq!(second.payout_id, None);
+        assert_eq!(second.currency, Some(storage_enums::Currency::EUR));
+        assert_eq!(second.connector, None);
+    }
+
+    #[test]
+    fn test_parse_payout_batch_file_reports_invalid_row() {
+        let file_data = "amount,currency\n1000,USD\n250,XYZ\n";
+        let error = parse_payout_batch_file(file_data.as_bytes(), Some(CSV_MIME_TYPE)).unwrap_err();
+
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::InvalidRequestData { message } if message.starts_with("Invalid row 2")
+        ));
+    }
+
+    #[test]
+    fn test_parse_payout_batch_json_file() {
+        let file_data = r#"[{"amount": 1000, "currency": "USD"}]"#;
+        let requests = parse_payout_batch_file(file_data.as_bytes(), Some(JSON_MIME_TYPE)).unwrap();
+
+        assert_eq!(requests.len(), 1);
+        assert!(parse_payout_batch_file(file_data.as_bytes(), None).is_err());
+    }
+}
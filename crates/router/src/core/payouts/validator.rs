@@ -203,6 +203,52 @@ pub fn validate_payout_link_request(
     Ok(())
 }
 
+/// Validates a row of a payout batch file on below checks, without making any changes
+/// - merchant_id passed is same as the one in merchant_account table
+/// - amount and currency are present
+/// - payout links are not requested, as the payouts of a batch are confirmed on creation
+pub fn validate_payout_batch_row(
+    merchant_account: &domain::MerchantAccount,
+    req: &payouts::PayoutCreateRequest,
+) -> Result<(), errors::ApiErrorResponse> {
+    if req
+        .merchant_id
+        .as_ref()
+        .is_some_and(|merchant_id| merchant_id != merchant_account.get_id())
+    {
+        return Err(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "merchant_id".to_string(),
+            expected_format: "merchant_id from merchant account".to_string(),
+        });
+    }
+
+    if req.amount.is_none() {
+        return Err(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "amount",
+        });
+    }
+
+    if req.currency.is_none() {
+        return Err(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "currency",
+        });
+    }
+
+    if req.payout_link.unwrap_or(false) {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "payout links cannot be created in a payout batch".to_string(),
+        });
+    }
+
+    if req.payout_token.is_some() && req.customer_id.is_none() && req.customer.is_none() {
+        return Err(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "customer or customer_id when payout_token is provided",
+        });
+    }
+
+    Ok(())
+}
+
 #[cfg(feature = "olap")]
 pub(super) fn validate_payout_list_request(
     req: &payouts::PayoutListConstraints,
@@ -0,0 +1,796 @@
+use std::collections::HashSet;
+
+use common_utils::{
+    date_time,
+    errors::{CustomResult, ErrorSwitch},
+    ext_traits::{Encode, ValueExt},
+};
+use diesel_models::enums as storage_enums;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, metrics::add_attributes, tracing};
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        files::helpers as file_helpers,
+        refunds,
+    },
+    db::StorageInterface,
+    routes::{metrics, SessionState},
+    services::ApplicationResponse,
+    types::{
+        api::{self, refunds as refund_types},
+        domain, storage,
+        transformers::{ForeignFrom, ForeignTryFrom},
+    },
+};
+
+const REFUND_BATCH_TAG: &str = "REFUND_BATCH";
+const REFUND_BATCH_NAME: &str = "REFUND_BATCH";
+const REFUND_BATCH_RUNNER: diesel_models::ProcessTrackerRunner =
+    diesel_models::ProcessTrackerRunner::RefundBatchWorkflow;
+
+/// The maximum number of refunds which can be created from a single file.
+const MAX_REFUND_BATCH_ROWS: usize = 10000;
+
+/// The number of rows processed each time the refund batch task is run, so that a large file does
+/// not hold up the scheduler and the progress of the batch is persisted regularly.
+const REFUND_BATCH_CHUNK_SIZE: usize = 100;
+
+/// The number of refunds of the batches of a merchant sent to a connector every minute, unless
+/// configured otherwise for the merchant and connector.
+const DEFAULT_REFUND_BATCH_RATE_LIMIT: usize = 100;
+
+const CSV_MIME_TYPE: &str = "text/csv";
+const JSON_MIME_TYPE: &str = "application/json";
+
+/// A row of the report of a refund batch, containing the latest status of the refund.
+#[derive(Debug, serde::Serialize)]
+struct RefundBatchReportRecord {
+    row_number: i32,
+    payment_id: common_utils::id_type::PaymentId,
+    refund_id: Option<String>,
+    connector: Option<String>,
+    amount: Option<i64>,
+    currency: Option<String>,
+    status: Option<refund_types::RefundStatus>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    scheduled_at: Option<time::PrimitiveDateTime>,
+    error_code: Option<String>,
+    error_message: Option<String>,
+}
+
+#[instrument(skip_all)]
+pub async fn refund_batch_create_core(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: refund_types::RefundBatchCreateRequest,
+) -> RouterResponse<refund_types::RefundBatchResponse> {
+    let db = &*state.store;
+
+    let records =
+        get_refund_batch_records(&state, &merchant_account, &key_store, &req.file_id).await?;
+    validate_refund_batch_records(
+        db,
+        merchant_account.get_id(),
+        merchant_account.storage_scheme,
+        &records,
+    )
+    .await?;
+    let total_count = i32::try_from(records.len())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to convert the number of rows of the refund batch file")?;
+
+    let now = date_time::now();
+    let batch_new = storage::RefundBatchNew {
+        batch_id: common_utils::generate_id(consts::ID_LENGTH, "batch"),
+        merchant_id: merchant_account.get_id().clone(),
+        file_id: req.file_id,
+        status: storage_enums::RefundBatchStatus::Pending,
+        total_count,
+        processed_count: 0,
+        succeeded_count: 0,
+        failed_count: 0,
+        results: serde_json::Value::Array(vec![]),
+        created_at: now,
+        modified_at: now,
+    };
+    let batch = db
+        .insert_refund_batch(batch_new)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert refund batch")?;
+
+    add_refund_batch_task(db, &batch, now).await?;
+
+    Ok(ApplicationResponse::Json(
+        refund_types::RefundBatchResponse::foreign_try_from(batch)?,
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn refund_batch_retrieve_core(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    req: refund_types::RefundBatchId,
+) -> RouterResponse<refund_types::RefundBatchResponse> {
+    let batch = state
+        .store
+        .find_refund_batch_by_merchant_id_batch_id(merchant_account.get_id(), &req.batch_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Refund batch `{}` does not exist", req.batch_id),
+        })?;
+
+    Ok(ApplicationResponse::Json(
+        refund_types::RefundBatchResponse::foreign_try_from(batch)?,
+    ))
+}
+
+/// Generates a CSV report of the rows of the batch, with the latest status of each refund.
+#[instrument(skip_all)]
+pub async fn refund_batch_report_core(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    req: refund_types::RefundBatchId,
+) -> RouterResponse<serde_json::Value> {
+    let db = &*state.store;
+    let batch = db
+        .find_refund_batch_by_merchant_id_batch_id(merchant_account.get_id(), &req.batch_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Refund batch `{}` does not exist", req.batch_id),
+        })?;
+
+    let mut writer = csv::Writer::from_writer(vec![]);
+    for result in get_refund_batch_results(&batch)? {
+        let refund = match &result.refund_id {
+            Some(refund_id) => Some(
+                db.find_refund_by_merchant_id_refund_id(
+                    merchant_account.get_id(),
+                    refund_id,
+                    merchant_account.storage_scheme,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::RefundNotFound)?,
+            ),
+            None => None,
+        };
+        let record = RefundBatchReportRecord {
+            row_number: result.row_number,
+            payment_id: result.payment_id,
+            refund_id: result.refund_id,
+            connector: result.connector,
+            amount: refund
+                .as_ref()
+                .map(|refund| refund.refund_amount.get_amount_as_i64()),
+            currency: refund.as_ref().map(|refund| refund.currency.to_string()),
+            status: refund
+                .as_ref()
+                .map(|refund| refund_types::RefundStatus::foreign_from(refund.refund_status))
+                .or(result.status),
+            scheduled_at: result.scheduled_at,
+            error_code: refund
+                .as_ref()
+                .and_then(|refund| refund.refund_error_code.clone())
+                .or(result.error_code),
+            error_message: refund
+                .as_ref()
+                .and_then(|refund| refund.refund_error_message.clone())
+                .or(result.error_message),
+        };
+        writer
+            .serialize(record)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to write the report of the refund batch")?;
+    }
+    let file_data = writer
+        .into_inner()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to write the report of the refund batch")?;
+
+    Ok(ApplicationResponse::FileData((file_data, mime::TEXT_CSV)))
+}
+
+/// Reads the refunds to be created from the file uploaded for the batch.
+async fn get_refund_batch_records(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    file_id: &str,
+) -> RouterResult<Vec<refund_types::RefundBatchRecord>> {
+    let file_info = file_helpers::retrieve_file_and_provider_file_id_from_file_id(
+        state,
+        Some(file_id.to_owned()),
+        merchant_account,
+        key_store,
+        api::FileDataRequired::Required,
+    )
+    .await?;
+    let file_data = file_info
+        .file_data
+        .ok_or(errors::ApiErrorResponse::FileNotAvailable)
+        .attach_printable("File data not found")?;
+
+    parse_refund_batch_file(&file_data, file_info.file_type.as_deref())
+}
+
+fn parse_refund_batch_file(
+    file_data: &[u8],
+    file_type: Option<&str>,
+) -> CustomResult<Vec<refund_types::RefundBatchRecord>, errors::ApiErrorResponse> {
+    match file_type {
+        Some(CSV_MIME_TYPE) => csv::Reader::from_reader(file_data)
+            .deserialize::<refund_types::RefundBatchRecord>()
+            .enumerate()
+            .map(|(index, record)| {
+                record.map_err(|error| errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!("Invalid row {} in the file: {error}", index + 1),
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(error_stack::Report::from),
+        Some(JSON_MIME_TYPE) => serde_json::from_slice(file_data).map_err(|error| {
+            report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("Invalid refunds in the file: {error}"),
+            })
+        }),
+        _ => Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "The refund batch file must either be a CSV or a JSON file".to_string(),
+        })),
+    }
+}
+
+/// Validates all the rows of the file before any refund is created, so that a file with mistakes
+/// can be corrected and uploaded again as a whole. The payments are validated when the refunds are
+/// created, as looking up thousands of payments would hold up the request.
+async fn validate_refund_batch_records(
+    db: &dyn StorageInterface,
+    merchant_id: &common_utils::id_type::MerchantId,
+    storage_scheme: storage_enums::MerchantStorageScheme,
+    records: &[refund_types::RefundBatchRecord],
+) -> RouterResult<()> {
+    if records.is_empty() {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "The refund batch file does not contain any refunds".to_string(),
+        }));
+    }
+    if records.len() > MAX_REFUND_BATCH_ROWS {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "The refund batch file cannot contain more than {MAX_REFUND_BATCH_ROWS} refunds"
+            ),
+        }));
+    }
+
+    let mut refund_ids = HashSet::new();
+    let mut row_errors = Vec::new();
+    for (index, record) in records.iter().enumerate() {
+        let row_number = index + 1;
+        if record
+            .amount
+            .is_some_and(|amount| amount <= common_utils::types::MinorUnit::new(0))
+        {
+            row_errors.push(format!(
+                "row {row_number}: amount must be a positive integer"
+            ));
+            continue;
+        }
+
+        if let Some(refund_id) = &record.refund_id {
+            let is_duplicate = !refund_ids.insert(refund_id.clone())
+                || db
+                    .find_refund_by_merchant_id_refund_id(merchant_id, refund_id, storage_scheme)
+                    .await
+                    .map(|_| true)
+                    .or_else(|error| {
+                        if error.current_context().is_db_not_found() {
+                            Ok(false)
+                        } else {
+                            Err(error)
+                        }
+                    })
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed while finding refund, database error")?;
+            if is_duplicate {
+                row_errors.push(format!("row {row_number}: duplicate refund_id {refund_id}"));
+            }
+        }
+    }
+
+    if row_errors.is_empty() {
+        Ok(())
+    } else {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("Invalid refunds in the file: {}", row_errors.join(", ")),
+        }))
+    }
+}
+
+async fn add_refund_batch_task(
+    db: &dyn StorageInterface,
+    batch: &storage::RefundBatch,
+    schedule_time: time::PrimitiveDateTime,
+) -> RouterResult<()> {
+    let task_id = format!(
+        "{REFUND_BATCH_RUNNER}_{REFUND_BATCH_NAME}_{}",
+        batch.batch_id
+    );
+    let tracking_data = storage::RefundBatchTrackingData {
+        merchant_id: batch.merchant_id.clone(),
+        batch_id: batch.batch_id.clone(),
+    };
+
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        task_id,
+        REFUND_BATCH_NAME,
+        REFUND_BATCH_RUNNER,
+        [REFUND_BATCH_TAG],
+        tracking_data,
+        schedule_time,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct refund batch task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert refund batch task")?;
+
+    metrics::TASKS_ADDED_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &add_attributes([("flow", "RefundBatch")]),
+    );
+
+    Ok(())
+}
+
+/// Creates the refunds for the next chunk of rows of the file which have not been processed yet,
+/// and returns the batch with its progress updated.
+#[instrument(skip_all, fields(batch_id = %batch.batch_id))]
+pub async fn process_refund_batch_rows(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    batch: storage::RefundBatch,
+) -> RouterResult<storage::RefundBatch> {
+    let records =
+        get_refund_batch_records(state, merchant_account, key_store, &batch.file_id).await?;
+    let mut results = get_refund_batch_results(&batch)?;
+    let processed_count = usize::try_from(batch.processed_count)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid processed count of the refund batch")?;
+
+    for (index, record) in records
+        .into_iter()
+        .enumerate()
+        .skip(processed_count)
+        .take(REFUND_BATCH_CHUNK_SIZE)
+    {
+        let row_number = i32::try_from(index + 1)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to convert the row number of the refund batch file")?;
+        let row_result = create_refund_for_row(
+            state,
+            merchant_account,
+            key_store,
+            &batch,
+            row_number,
+            record,
+        )
+        .await?;
+        results.push(row_result);
+    }
+
+    let processed_count = i32::try_from(results.len())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to convert the processed count of the refund batch")?;
+    let failed_count = i32::try_from(
+        results
+            .iter()
+            .filter(|result| result.refund_id.is_none())
+            .count(),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to convert the failed count of the refund batch")?;
+
+    state
+        .store
+        .update_refund_batch_by_merchant_id_batch_id(
+            &batch.merchant_id,
+            &batch.batch_id,
+            storage::RefundBatchUpdate::ProgressUpdate {
+                processed_count,
+                succeeded_count: processed_count.saturating_sub(failed_count),
+                failed_count,
+                results: results
+                    .encode_to_value()
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to encode the results of the refund batch")?,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the progress of the refund batch")
+}
+
+/// Creates the refund for a row of the file. Refunds which could not be created are recorded in
+/// the results of the batch rather than failing the batch as a whole.
+async fn create_refund_for_row(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    batch: &storage::RefundBatch,
+    row_number: i32,
+    record: refund_types::RefundBatchRecord,
+) -> RouterResult<refund_types::RefundBatchRowResult> {
+    let payment_id = record.payment_id.clone();
+    // The refund_id is derived from the row when not provided, so that rows are not refunded
+    // twice when the task is retried after the refund has been created
+    let refund_id = record
+        .refund_id
+        .unwrap_or_else(|| format!("{}_{row_number}", batch.batch_id));
+
+    // The refund may have been created already, if the task is being retried
+    let existing_refund = state
+        .store
+        .find_refund_by_merchant_id_refund_id(
+            merchant_account.get_id(),
+            &refund_id,
+            merchant_account.storage_scheme,
+        )
+        .await;
+    match existing_refund {
+        Ok(refund) => {
+            return Ok(refund_types::RefundBatchRowResult {
+                row_number,
+                payment_id,
+                refund_id: Some(refund.refund_id),
+                status: Some(refund_types::RefundStatus::foreign_from(
+                    refund.refund_status,
+                )),
+                connector: Some(refund.connector),
+                scheduled_at: None,
+                error_code: None,
+                error_message: None,
+            })
+        }
+        Err(error) if !error.current_context().is_db_not_found() => {
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed while finding refund, database error")
+        }
+        Err(_) => (),
+    }
+
+    let refund = match refunds::get_refundable_payment(
+        state,
+        merchant_account,
+        key_store,
+        &record.payment_id,
+        record.amount,
+    )
+    .await
+    {
+        Ok((payment_intent, payment_attempt, amount)) => {
+            let execute_at = match payment_attempt.connector.as_deref() {
+                Some(connector) => Some(
+                    get_refund_execution_time(state, merchant_account.get_id(), connector).await?,
+                ),
+                None => None,
+            };
+            let req = refund_types::RefundRequest {
+                payment_id: record.payment_id,
+                refund_id: Some(refund_id.clone()),
+                amount: record.amount,
+                reason: record.reason,
+                refund_type: Some(refund_types::RefundType::Scheduled),
+                ..Default::default()
+            };
+            Box::pin(refunds::validate_and_create_refund(
+                state,
+                merchant_account,
+                key_store,
+                &payment_attempt,
+                &payment_intent,
+                amount,
+                req,
+                None,
+                execute_at,
+            ))
+            .await
+            .map(|refund| (refund, execute_at))
+        }
+        Err(error) => Err(error),
+    };
+
+    match refund {
+        Ok((refund, execute_at)) => Ok(refund_types::RefundBatchRowResult {
+            row_number,
+            payment_id,
+            refund_id: Some(refund.refund_id),
+            status: Some(refund.status),
+            connector: Some(refund.connector),
+            scheduled_at: execute_at,
+            error_code: refund.error_code,
+            error_message: refund.error_message,
+        }),
+        Err(error) => match error.current_context() {
+            errors::ApiErrorResponse::InternalServerError => Err(error),
+            api_error => {
+                logger::info!(?error, row_number, "Failed to create refund of the batch");
+                let mut api_error = api_error.switch();
+                let api_error = api_error.get_internal_error_mut();
+                Ok(refund_types::RefundBatchRowResult {
+                    row_number,
+                    payment_id,
+                    refund_id: None,
+                    status: None,
+                    connector: None,
+                    scheduled_at: None,
+                    error_code: Some(format!(
+                        "{}_{:02}",
+                        api_error.sub_code, api_error.error_identifier
+                    )),
+                    error_message: Some(api_error.error_message.clone()),
+                })
+            }
+        },
+    }
+}
+
+/// Spreads out the refunds sent to each connector by the refund batches of a merchant, so that
+/// the rate limits of the connector are not exceeded. A refund is scheduled in the earliest minute
+/// in which fewer than the configured number of refunds have been scheduled for the connector,
+/// across all the batches of the merchant which are being processed.
+async fn get_refund_execution_time(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    connector: &str,
+) -> RouterResult<time::PrimitiveDateTime> {
+    let key = merchant_id.get_refund_batch_rate_limit_key(connector);
+    let rate_limit = state
+        .store
+        .find_config_by_key_unwrap_or(&key, Some(DEFAULT_REFUND_BATCH_RATE_LIMIT.to_string()))
+        .await
+        .map_err(|error| {
+            logger::error!(
+                ?error,
+                "Failed to read refund batch rate limit config `{key}`"
+            )
+        })
+        .ok()
+        .and_then(|config| config.config.parse::<usize>().ok())
+        .filter(|rate_limit| *rate_limit > 0)
+        .unwrap_or(DEFAULT_REFUND_BATCH_RATE_LIMIT);
+
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let schedule_key = merchant_id.get_refund_batch_schedule_key(connector);
+    let now = date_time::now();
+    let current_minute = now.assume_utc().unix_timestamp().div_euclid(60);
+
+    // The earliest minute which may have free slots is tracked, so that the minutes which are
+    // already full are not incremented again for every refund
+    let first_free_minute = redis_conn
+        .get_key::<Option<i64>>(&schedule_key)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the refund batch schedule from redis")?
+        .map_or(current_minute, |minute| minute.max(current_minute));
+
+    let mut minute = first_free_minute;
+    loop {
+        let slot_key = format!("{schedule_key}_{minute}");
+        let scheduled_count = redis_conn
+            .increment_fields_in_hash(&slot_key, &[("count", 1)])
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to increment the refund batch schedule in redis")?
+            .into_iter()
+            .next()
+            .unwrap_or_default();
+        redis_conn
+            .set_expiry(&slot_key, get_schedule_expiry(current_minute, minute))
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to set expiry of the refund batch schedule in redis")?;
+
+        if scheduled_count <= rate_limit {
+            break;
+        }
+        minute += 1;
+    }
+
+    if minute != first_free_minute {
+        redis_conn
+            .set_key_with_expiry(
+                &schedule_key,
+                minute,
+                get_schedule_expiry(current_minute, minute),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update the refund batch schedule in redis")?;
+    }
+
+    let execute_at = time::OffsetDateTime::from_unix_timestamp(minute * 60)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to compute the execution time of the refund")?;
+    Ok(time::PrimitiveDateTime::new(execute_at.date(), execute_at.time()).max(now))
+}
+
+/// The schedule of a minute is kept until the minute has passed.
+fn get_schedule_expiry(current_minute: i64, minute: i64) -> i64 {
+    (minute - current_minute + 1) * 60
+}
+
+/// Marks the batch as processed, once the refunds of all the rows have been created.
+#[instrument(skip_all, fields(batch_id = %batch.batch_id))]
+pub async fn complete_refund_batch(
+    state: &SessionState,
+    batch: storage::RefundBatch,
+) -> RouterResult<storage::RefundBatch> {
+    let status = match (batch.succeeded_count, batch.failed_count) {
+        (_, 0) => storage_enums::RefundBatchStatus::Completed,
+        (0, _) => storage_enums::RefundBatchStatus::Failed,
+        _ => storage_enums::RefundBatchStatus::PartiallyCompleted,
+    };
+    state
+        .store
+        .update_refund_batch_by_merchant_id_batch_id(
+            &batch.merchant_id,
+            &batch.batch_id,
+            storage::RefundBatchUpdate::CompletionUpdate { status },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to mark the refund batch as processed")
+}
+
+fn get_refund_batch_results(
+    batch: &storage::RefundBatch,
+) -> RouterResult<Vec<refund_types::RefundBatchRowResult>> {
+    batch
+        .results
+        .clone()
+        .parse_value("RefundBatchRowResult")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the results of the refund batch")
+}
+
+impl ForeignTryFrom<storage::RefundBatch> for refund_types::RefundBatchResponse {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+
+    fn foreign_try_from(batch: storage::RefundBatch) -> Result<Self, Self::Error> {
+        let results = get_refund_batch_results(&batch)?;
+        Ok(Self {
+            batch_id: batch.batch_id,
+            merchant_id: batch.merchant_id,
+            file_id: batch.file_id,
+            status: batch.status,
+            total_count: batch.total_count,
+            processed_count: batch.processed_count,
+            succeeded_count: batch.succeeded_count,
+            failed_count: batch.failed_count,
+            results,
+            created: batch.created_at,
+            updated: batch.modified_at,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+    use common_utils::types::MinorUnit;
+    use storage_impl::MockDb;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_refund_batch_csv_file() {
+        let file_data = "payment_id,amount,reason,refund_id\n\
+                         pay_1,100,Customer returned product,ref_1\n\
+                         pay_2,,,\n";
+        let records = parse_refund_batch_file(file_data.as_bytes(), Some(CSV_MIME_TYPE)).unwrap();
+
+        assert_eq!(records.len(), 2);
+        let first = records.first().unwrap();
+        assert_eq!(first.payment_id.get_string_repr(), "pay_1");
+        assert_eq!(first.amount, Some(MinorUnit::new(100)));
+        assert_eq!(first.refund_id.as_deref(), Some("ref_1"));
+        let second = records.get(1).unwrap();
+        assert_eq!(second.amount, None);
+        assert_eq!(second.reason, None);
+        assert_eq!(second.refund_id, None);
+    }
+
+    #[test]
+    fn test_parse_refund_batch_file_reports_invalid_row() {
+        let file_data = "payment_id,amount\npay_1,100\npay_2,ten\n";
+        let error = parse_refund_batch_file(file_data.as_bytes(), Some(CSV_MIME_TYPE)).unwrap_err();
+
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::InvalidRequestData { message } if message.starts_with("Invalid row 2")
+        ));
+    }
+
+    #[test]
+    fn test_parse_refund_batch_json_file() {
+        let file_data = r#"[{"payment_id": "pay_1", "amount": 100}, {"payment_id": "pay_2"}]"#;
+        let records = parse_refund_batch_file(file_data.as_bytes(), Some(JSON_MIME_TYPE)).unwrap();
+
+        assert_eq!(records.len(), 2);
+        assert!(parse_refund_batch_file(file_data.as_bytes(), Some("text/plain")).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_validate_refund_batch_records() {
+        let db = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store");
+        let merchant_id = common_utils::id_type::MerchantId::default();
+        let record = |payment_id: &str, amount: Option<i64>, refund_id: Option<&str>| {
+            refund_types::RefundBatchRecord {
+                payment_id: common_utils::id_type::PaymentId::try_from(std::borrow::Cow::Owned(
+                    payment_id.to_string(),
+                ))
+                .unwrap(),
+                amount: amount.map(MinorUnit::new),
+                reason: None,
+                refund_id: refund_id.map(ToString::to_string),
+            }
+        };
+
+        let valid_records = vec![
+            record("pay_1", Some(100), Some("ref_1")),
+            record("pay_2", None, None),
+        ];
+        assert!(validate_refund_batch_records(
+            &db,
+            &merchant_id,
+            storage_enums::MerchantStorageScheme::PostgresOnly,
+            &valid_records,
+        )
+        .await
+        .is_ok());
+
+        let invalid_records = vec![
+            record("pay_1", Some(0), None),
+            record("pay_2", Some(100), Some("ref_1")),
+            record("pay_3", Some(100), Some("ref_1")),
+        ];
+        let error = validate_refund_batch_records(
+            &db,
+            &merchant_id,
+            storage_enums::MerchantStorageScheme::PostgresOnly,
+            &invalid_records,
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::InvalidRequestData { message }
+                if message.contains("row 1: amount must be a positive integer")
+                    && message.contains("row 3: duplicate refund_id ref_1")
+                    && !message.contains("row 2")
+        ));
+
+        assert!(validate_refund_batch_records(
+            &db,
+            &merchant_id,
+            storage_enums::MerchantStorageScheme::PostgresOnly,
+            &[],
+        )
+        .await
+        .is_err());
+    }
+}
@@ -25,7 +25,7 @@ use crate::{
     core::{
         errors::{self, ConnectorErrorExt, RouterResponse, RouterResult, StorageErrorExt},
         payments::{self, access_token, types::PaymentCharges},
-        utils as core_utils,
+        split_payments, utils as core_utils,
     },
     db, logger,
     routes::{metrics, SessionState},
@@ -53,16 +53,63 @@ pub async fn refund_create_core(
     req: refunds::RefundRequest,
 ) -> RouterResponse<refunds::RefundResponse> {
     let db = &*state.store;
-    let (merchant_id, payment_intent, payment_attempt, amount);
+    let merchant_id = merchant_account.get_id();
 
-    merchant_id = merchant_account.get_id();
+    let (payment_intent, payment_attempt, amount) = get_refundable_payment(
+        &state,
+        &merchant_account,
+        &key_store,
+        &req.payment_id,
+        req.amount,
+    )
+    .await?;
 
-    payment_intent = db
+    let creds_identifier = req
+        .merchant_connector_details
+        .as_ref()
+        .map(|mcd| mcd.creds_identifier.to_owned());
+    req.merchant_connector_details
+        .to_owned()
+        .async_map(|mcd| async {
+            payments::helpers::insert_merchant_connector_creds_to_config(db, merchant_id, mcd).await
+        })
+        .await
+        .transpose()?;
+
+    Box::pin(validate_and_create_refund(
+        &state,
+        &merchant_account,
+        &key_store,
+        &payment_attempt,
+        &payment_intent,
+        amount,
+        req,
+        creds_identifier,
+        None,
+    ))
+    .await
+    .map(services::ApplicationResponse::Json)
+}
+
+/// Fetches the payment against which a refund is initiated, along with the amount to be refunded,
+/// which defaults to the amount captured for the payment.
+#[instrument(skip_all)]
+pub async fn get_refundable_payment(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_id: &common_utils::id_type::PaymentId,
+    refund_amount: Option<MinorUnit>,
+) -> RouterResult<(storage::PaymentIntent, storage::PaymentAttempt, MinorUnit)> {
+    let db = &*state.store;
+    let merchant_id = merchant_account.get_id();
+
+    let payment_intent = db
         .find_payment_intent_by_payment_id_merchant_id(
-            &(&state).into(),
-            &req.payment_id,
+            &state.into(),
+            payment_id,
             merchant_id,
-            &key_store,
+            key_store,
             merchant_account.storage_scheme,
         )
         .await
@@ -83,8 +130,7 @@ pub async fn refund_create_core(
     )?;
 
     // Amount is not passed in request refer from payment intent.
-    amount = req
-        .amount
+    let amount = refund_amount
         .or(payment_intent.amount_captured)
         .ok_or(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("amount captured is none in a successful payment")?;
@@ -98,39 +144,16 @@ pub async fn refund_create_core(
         .attach_printable("amount less than or equal to zero"))
     })?;
 
-    payment_attempt = db
+    let payment_attempt = db
         .find_payment_attempt_last_successful_or_partially_captured_attempt_by_payment_id_merchant_id(
-            &req.payment_id,
+            payment_id,
             merchant_id,
             merchant_account.storage_scheme,
         )
         .await
         .to_not_found_response(errors::ApiErrorResponse::SuccessfulPaymentNotFound)?;
 
-    let creds_identifier = req
-        .merchant_connector_details
-        .as_ref()
-        .map(|mcd| mcd.creds_identifier.to_owned());
-    req.merchant_connector_details
-        .to_owned()
-        .async_map(|mcd| async {
-            payments::helpers::insert_merchant_connector_creds_to_config(db, merchant_id, mcd).await
-        })
-        .await
-        .transpose()?;
-
-    Box::pin(validate_and_create_refund(
-        &state,
-        &merchant_account,
-        &key_store,
-        &payment_attempt,
-        &payment_intent,
-        amount,
-        req,
-        creds_identifier,
-    ))
-    .await
-    .map(services::ApplicationResponse::Json)
+    Ok((payment_intent, payment_attempt, amount))
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -363,6 +386,14 @@ pub async fn trigger_refund_to_gateway(
                 refund.refund_id
             )
         })?;
+
+    split_payments::update_split_refunds(state, &response, merchant_account.storage_scheme)
+        .await
+        .inspect_err(|error| {
+            logger::error!(?error, "Failed to reverse the shares of the split payment")
+        })
+        .ok();
+
     Ok(response)
 }
 
@@ -669,6 +700,14 @@ pub async fn sync_refund_with_gateway(
                 refund.refund_id
             )
         })?;
+
+    split_payments::update_split_refunds(state, &response, merchant_account.storage_scheme)
+        .await
+        .inspect_err(|error| {
+            logger::error!(?error, "Failed to reverse the shares of the split payment")
+        })
+        .ok();
+
     Ok(response)
 }
 
@@ -721,6 +760,7 @@ pub async fn validate_and_create_refund(
     refund_amount: MinorUnit,
     req: refunds::RefundRequest,
     creds_identifier: Option<String>,
+    execute_at: Option<time::PrimitiveDateTime>,
 ) -> RouterResult<refunds::RefundResponse> {
     let db = &*state.store;
 
@@ -873,6 +913,7 @@ pub async fn validate_and_create_refund(
                 payment_intent,
                 creds_identifier,
                 charges,
+                execute_at,
             ))
             .await?
         }
@@ -1205,6 +1246,7 @@ pub async fn schedule_refund_execution(
     payment_intent: &storage::PaymentIntent,
     creds_identifier: Option<String>,
     charges: Option<ChargeRefunds>,
+    execute_at: Option<time::PrimitiveDateTime>,
 ) -> RouterResult<storage::Refund> {
     // refunds::RefundResponse> {
     let db = &*state.store;
@@ -1225,7 +1267,7 @@ pub async fn schedule_refund_execution(
                     // Execute the refund task based on refund_type
                     match refund_type {
                         api_models::refunds::RefundType::Scheduled => {
-                            add_refund_execute_task(db, &refund, runner)
+                            add_refund_execute_task(db, &refund, runner, execute_at)
                                 .await
                                 .change_context(errors::ApiErrorResponse::InternalServerError)
                                 .attach_printable_lazy(|| format!("Failed while pushing refund execute task to scheduler, refund_id: {}", refund.refund_id))?;
@@ -1581,11 +1623,13 @@ pub async fn add_refund_execute_task(
     db: &dyn db::StorageInterface,
     refund: &storage::Refund,
     runner: storage::ProcessTrackerRunner,
+    schedule_time: Option<time::PrimitiveDateTime>,
 ) -> RouterResult<storage::ProcessTracker> {
     let task = "EXECUTE_REFUND";
     let process_tracker_id = format!("{runner}_{task}_{}", refund.internal_reference_id);
     let tag = ["REFUND"];
-    let schedule_time = common_utils::date_time::now();
+    // Refunds are executed right away, unless they are throttled to be sent to the connector later
+    let schedule_time = schedule_time.unwrap_or_else(common_utils::date_time::now);
     let refund_workflow_tracking_data = refund_to_refund_core_workflow_model(refund);
     let process_tracker_entry = storage::ProcessTrackerNew::new(
         process_tracker_id,
@@ -583,14 +583,18 @@ pub async fn refresh_success_based_routing_cache(
     key: &str,
     success_based_routing_config: routing_types::SuccessBasedRoutingConfig,
 ) -> Arc<routing_types::SuccessBasedRoutingConfig> {
+    let config_size = serde_json::to_vec(&success_based_routing_config)
+        .map(|serialized| serialized.len())
+        .unwrap_or(std::mem::size_of::<routing_types::SuccessBasedRoutingConfig>());
     let config = Arc::new(success_based_routing_config);
     cache::SUCCESS_BASED_DYNAMIC_ALGORITHM_CACHE
-        .push(
+        .push_with_size(
             cache::CacheKey {
                 key: key.to_string(),
                 prefix: state.tenant.redis_key_prefix.clone(),
             },
             config.clone(),
+            config_size,
         )
         .await;
     config
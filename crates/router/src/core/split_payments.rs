@@ -0,0 +1,574 @@
+use std::collections::HashSet;
+
+use api_models::payments::{
+    PaymentChargeRequest, SplitFeeBearer, SplitPaymentRecipientResponse, SplitPaymentShare,
+    SplitPaymentsRequest, SplitPaymentsResponse,
+};
+use common_utils::{
+    date_time, generate_id, id_type,
+    types::{MinorUnit, Percentage},
+};
+use diesel_models::enums as storage_enums;
+use error_stack::{report, ResultExt};
+use hyperswitch_domain_models::router_request_types::{
+    SplitPaymentRecipientData, SplitPaymentsData,
+};
+use router_env::{instrument, tracing};
+
+#[cfg(feature = "v1")]
+use crate::routes::SessionState;
+use crate::{
+    consts,
+    core::errors::{self, RouterResult, StorageErrorExt},
+    db::StorageInterface,
+    types::{api::enums as api_enums, storage},
+};
+
+/// Computes the amount to be transferred to each of the recipients of a split payment, in the
+/// order in which the recipients were specified in the request.
+///
+/// Percentage shares are floored, so that the shares never add up to more than the amount of the
+/// payment. Any remainder is kept by the platform.
+fn get_recipient_amounts(
+    split_payments: &SplitPaymentsRequest,
+    amount: MinorUnit,
+) -> RouterResult<Vec<MinorUnit>> {
+    split_payments
+        .recipients
+        .iter()
+        .map(|recipient| match &recipient.share {
+            SplitPaymentShare::Fixed { amount } => Ok(*amount),
+            SplitPaymentShare::Percentage { percentage } => {
+                Percentage::<2>::from_string(percentage.to_string())
+                    .change_context(errors::ApiErrorResponse::InvalidRequestData {
+                        message: format!(
+                            "Invalid percentage share for recipient {}, expected a value between \
+                             0 and 100 with up to 2 decimal digits",
+                            recipient.recipient_account_id
+                        ),
+                    })?
+                    .apply_and_floor_result(amount)
+                    .change_context(errors::ApiErrorResponse::InvalidRequestData {
+                        message: "Unable to apply percentage share to the amount".to_string(),
+                    })
+            }
+        })
+        .collect()
+}
+
+/// Validates the split of a payment among the recipients of a marketplace payment.
+pub fn validate_split_payments(
+    split_payments: &SplitPaymentsRequest,
+    charges: Option<&PaymentChargeRequest>,
+    amount: api_models::payments::Amount,
+) -> RouterResult<()> {
+    if charges.is_some() {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "`charges` and `split_payments` cannot be provided together".to_string(),
+        }));
+    }
+
+    if split_payments.recipients.is_empty() {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`split_payments.recipients` must contain at least one recipient".to_string(),
+        }));
+    }
+
+    let mut recipient_account_ids = HashSet::new();
+    if !split_payments
+        .recipients
+        .iter()
+        .all(|recipient| recipient_account_ids.insert(recipient.recipient_account_id.as_str()))
+    {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "A recipient can only be specified once in `split_payments.recipients`"
+                .to_string(),
+        }));
+    }
+
+    if let SplitFeeBearer::Recipient {
+        recipient_account_id,
+    } = &split_payments.fee_bearer
+    {
+        if !recipient_account_ids.contains(recipient_account_id.as_str()) {
+            return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "`split_payments.fee_bearer` must be one of the recipients of the payment"
+                    .to_string(),
+            }));
+        }
+    }
+
+    let amount = MinorUnit::from(amount);
+    if amount == MinorUnit::zero() {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "`split_payments` cannot be provided for zero amount payments".to_string(),
+        }));
+    }
+
+    let recipient_amounts = get_recipient_amounts(split_payments, amount)?;
+    if recipient_amounts
+        .iter()
+        .any(|recipient_amount| *recipient_amount <= MinorUnit::zero())
+    {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "The share of every recipient must be greater than zero".to_string(),
+        }));
+    }
+
+    let total_recipient_amount: MinorUnit = recipient_amounts.into_iter().sum();
+    if total_recipient_amount > amount {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "The sum of the shares of the recipients ({}) cannot exceed the amount of the \
+                 payment ({})",
+                total_recipient_amount, amount
+            ),
+        }));
+    }
+
+    Ok(())
+}
+
+/// Validates that the shares of the recipients of a payment do not exceed its updated amount.
+pub fn validate_payment_splits_amount(
+    payment_splits: &[storage::PaymentSplit],
+    amount: MinorUnit,
+) -> RouterResult<()> {
+    let total_recipient_amount: MinorUnit = payment_splits
+        .iter()
+        .map(|payment_split| payment_split.amount)
+        .sum();
+    if total_recipient_amount > amount {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "The amount of the payment cannot be less than the sum of the shares of its \
+                 recipients ({})",
+                total_recipient_amount
+            ),
+        }));
+    }
+
+    Ok(())
+}
+
+/// Creates a ledger entry for the share of each recipient of a split payment.
+#[instrument(skip_all)]
+pub async fn create_payment_splits(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    payment_id: &id_type::PaymentId,
+    currency: storage_enums::Currency,
+    split_payments: &SplitPaymentsRequest,
+    amount: MinorUnit,
+) -> RouterResult<Vec<storage::PaymentSplit>> {
+    let recipient_amounts = get_recipient_amounts(split_payments, amount)?;
+    let now = date_time::now();
+    let mut payment_splits = Vec::with_capacity(recipient_amounts.len());
+
+    for (recipient, recipient_amount) in split_payments.recipients.iter().zip(recipient_amounts) {
+        let is_fee_bearer = matches!(
+            &split_payments.fee_bearer,
+            SplitFeeBearer::Recipient { recipient_account_id }
+                if recipient_account_id == &recipient.recipient_account_id
+        );
+        let payment_split = db
+            .insert_payment_split(storage::PaymentSplitNew {
+                split_id: generate_id(consts::ID_LENGTH, "split"),
+                merchant_id: merchant_id.to_owned(),
+                payment_id: payment_id.to_owned(),
+                recipient_account_id: recipient.recipient_account_id.clone(),
+                amount: recipient_amount,
+                refunded_amount: MinorUnit::zero(),
+                currency,
+                is_fee_bearer,
+                created_at: now,
+                modified_at: now,
+            })
+            .await
+            .to_duplicate_response(errors::ApiErrorResponse::DuplicatePayment {
+                payment_id: payment_id.to_owned(),
+            })?;
+        payment_splits.push(payment_split);
+    }
+
+    Ok(payment_splits)
+}
+
+pub async fn find_payment_splits(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    payment_id: &id_type::PaymentId,
+) -> RouterResult<Vec<storage::PaymentSplit>> {
+    db.find_payment_splits_by_merchant_id_payment_id(merchant_id, payment_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the splits of the payment")
+}
+
+/// Returns how the shares of a split payment are transferred to the recipients when the payment is
+/// processed through the given connector.
+///
+/// Adyen supports splitting a payment among any number of balance accounts, whereas Stripe can only
+/// transfer the payment to a single connected account using a destination charge, in which case
+/// the fees are borne by the platform. In all other cases, the shares are only tracked in the
+/// ledger and have to be transferred to the recipients by the platform.
+pub fn get_split_settlement_type(
+    connector: &str,
+    payment_splits: &[storage::PaymentSplit],
+) -> api_enums::SplitSettlementType {
+    match connector.parse::<api_enums::Connector>() {
+        Ok(api_enums::Connector::Adyen) => api_enums::SplitSettlementType::Connector,
+        Ok(api_enums::Connector::Stripe) if matches!(payment_splits, [payment_split] if !payment_split.is_fee_bearer) => {
+            api_enums::SplitSettlementType::Connector
+        }
+        _ => api_enums::SplitSettlementType::Ledger,
+    }
+}
+
+fn get_platform_amount(payment_splits: &[storage::PaymentSplit], amount: MinorUnit) -> MinorUnit {
+    payment_splits
+        .iter()
+        .fold(amount, |platform_amount, payment_split| {
+            platform_amount - payment_split.amount
+        })
+}
+
+/// Returns the split of the payment to be sent to the connector, if the connector transfers the
+/// shares to the recipients itself.
+pub fn get_split_payments_data(
+    connector: &str,
+    payment_splits: &[storage::PaymentSplit],
+    amount: MinorUnit,
+) -> Option<SplitPaymentsData> {
+    if payment_splits.is_empty()
+        || get_split_settlement_type(connector, payment_splits)
+            != api_enums::SplitSettlementType::Connector
+    {
+        return None;
+    }
+
+    Some(SplitPaymentsData {
+        recipients: payment_splits
+            .iter()
+            .map(|payment_split| SplitPaymentRecipientData {
+                split_id: payment_split.split_id.clone(),
+                recipient_account_id: payment_split.recipient_account_id.clone(),
+                amount: payment_split.amount,
+                is_fee_bearer: payment_split.is_fee_bearer,
+            })
+            .collect(),
+        platform_amount: get_platform_amount(payment_splits, amount),
+    })
+}
+
+/// Returns the shares of the recipients of a split payment to be reversed by a refund, if the
+/// connector transferred the shares to the recipients itself.
+///
+/// The share of each recipient is reversed in proportion to the amount refunded, and is floored so
+/// that a recipient is never debited more than its share. Any remainder of the refund is borne by
+/// the platform.
+pub fn get_split_refunds_data(
+    connector: &str,
+    payment_splits: &[storage::PaymentSplit],
+    payment_amount: MinorUnit,
+    refund_amount: MinorUnit,
+) -> RouterResult<Option<SplitPaymentsData>> {
+    if payment_splits.is_empty()
+        || payment_amount <= MinorUnit::zero()
+        || get_split_settlement_type(connector, payment_splits)
+            != api_enums::SplitSettlementType::Connector
+    {
+        return Ok(None);
+    }
+
+    let recipients = payment_splits
+        .iter()
+        .map(|payment_split| {
+            let split_amount = i128::from(payment_split.amount.get_amount_as_i64());
+            let remaining_amount = i128::from(
+                (payment_split.amount - payment_split.refunded_amount).get_amount_as_i64(),
+            );
+            let amount = split_amount
+                .saturating_mul(i128::from(refund_amount.get_amount_as_i64()))
+                .checked_div(i128::from(payment_amount.get_amount_as_i64()))
+                .unwrap_or_default()
+                .clamp(0, remaining_amount.max(0));
+            let amount = i64::try_from(amount)
+                .map(MinorUnit::new)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Refunded amount of the split overflowed")?;
+
+            Ok(SplitPaymentRecipientData {
+                split_id: payment_split.split_id.clone(),
+                recipient_account_id: payment_split.recipient_account_id.clone(),
+                amount,
+                is_fee_bearer: payment_split.is_fee_bearer,
+            })
+        })
+        .collect::<RouterResult<Vec<_>>>()?;
+    let platform_amount = recipients
+        .iter()
+        .fold(refund_amount, |platform_amount, recipient| {
+            platform_amount - recipient.amount
+        });
+
+    Ok(Some(SplitPaymentsData {
+        recipients,
+        platform_amount,
+    }))
+}
+
+pub fn get_split_payments_response(
+    payment_splits: &[storage::PaymentSplit],
+    amount: MinorUnit,
+    connector: Option<&str>,
+) -> Option<SplitPaymentsResponse> {
+    if payment_splits.is_empty() {
+        return None;
+    }
+
+    Some(SplitPaymentsResponse {
+        recipients: payment_splits
+            .iter()
+            .map(|payment_split| SplitPaymentRecipientResponse {
+                split_id: payment_split.split_id.clone(),
+                recipient_account_id: payment_split.recipient_account_id.clone(),
+                amount: payment_split.amount,
+                refunded_amount: payment_split.refunded_amount,
+                is_fee_bearer: payment_split.is_fee_bearer,
+            })
+            .collect(),
+        platform_amount: get_platform_amount(payment_splits, amount),
+        settlement_type: connector
+            .map(|connector| get_split_settlement_type(connector, payment_splits)),
+    })
+}
+
+/// Reverses the shares of the recipients of a split payment in proportion to the amount refunded,
+/// once a refund of the payment has succeeded.
+///
+/// The refunded amounts are recomputed from all the successful refunds of the payment, so that
+/// processing the same refund update more than once does not reverse the shares twice.
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+pub async fn update_split_refunds(
+    state: &SessionState,
+    refund: &storage::Refund,
+    storage_scheme: storage_enums::MerchantStorageScheme,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let (merchant_id, payment_id, payment_amount) =
+        (&refund.merchant_id, &refund.payment_id, refund.total_amount);
+    if refund.refund_status != storage_enums::RefundStatus::Success
+        || payment_amount <= MinorUnit::zero()
+    {
+        return Ok(());
+    }
+
+    let payment_splits = find_payment_splits(db, merchant_id, payment_id).await?;
+    if payment_splits.is_empty() {
+        return Ok(());
+    }
+
+    let total_refunded_amount = db
+        .find_refund_by_payment_id_merchant_id(payment_id, merchant_id, storage_scheme)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the refunds of the payment")?
+        .into_iter()
+        .filter(|refund| refund.refund_status == storage_enums::RefundStatus::Success)
+        .fold(0_i128, |total, refund| {
+            total.saturating_add(i128::from(refund.refund_amount.get_amount_as_i64()))
+        });
+
+    for payment_split in payment_splits {
+        let split_amount = i128::from(payment_split.amount.get_amount_as_i64());
+        let refunded_amount = split_amount
+            .saturating_mul(total_refunded_amount)
+            .checked_div(i128::from(payment_amount.get_amount_as_i64()))
+            .unwrap_or_default()
+            .min(split_amount);
+        let refunded_amount = i64::try_from(refunded_amount)
+            .map(MinorUnit::new)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Refunded amount of the split overflowed")?;
+
+        if refunded_amount != payment_split.refunded_amount {
+            db.update_payment_split_by_merchant_id_split_id(
+                merchant_id,
+                &payment_split.split_id,
+                storage::PaymentSplitUpdate::RefundUpdate { refunded_amount },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update the refunded amount of the split")?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use api_models::payments::SplitPaymentRecipient;
+
+    use super::*;
+
+    fn percentage_recipient(recipient_account_id: &str, percentage: f32) -> SplitPaymentRecipient {
+        SplitPaymentRecipient {
+            recipient_account_id: recipient_account_id.to_string(),
+            share: SplitPaymentShare::Percentage { percentage },
+        }
+    }
+
+    fn fixed_recipient(recipient_account_id: &str, amount: i64) -> SplitPaymentRecipient {
+        SplitPaymentRecipient {
+            recipient_account_id: recipient_account_id.to_string(),
+            share: SplitPaymentShare::Fixed {
+                amount: MinorUnit::new(amount),
+            },
+        }
+    }
+
+    fn payment_split(recipient_account_id: &str, amount: i64) -> storage::PaymentSplit {
+        storage::PaymentSplit {
+            split_id: format!("split_{recipient_account_id}"),
+            merchant_id: id_type::MerchantId::default(),
+            payment_id: id_type::PaymentId::default(),
+            recipient_account_id: recipient_account_id.to_string(),
+            amount: MinorUnit::new(amount),
+            refunded_amount: MinorUnit::zero(),
+            currency: storage_enums::Currency::USD,
+            is_fee_bearer: false,
+            created_at: date_time::now(),
+            modified_at: date_time::now(),
+        }
+    }
+
+    #[test]
+    fn test_percentage_shares_are_floored() {
+        let split_payments = SplitPaymentsRequest {
+            recipients: vec![
+                percentage_recipient("acct_1", 50.0),
+                percentage_recipient("acct_2", 50.0),
+            ],
+            fee_bearer: SplitFeeBearer::Platform,
+        };
+        let amount = MinorUnit::new(101);
+
+        assert!(validate_split_payments(&split_payments, None, amount.into()).is_ok());
+        assert_eq!(
+            get_recipient_amounts(&split_payments, amount).unwrap(),
+            vec![MinorUnit::new(50), MinorUnit::new(50)]
+        );
+    }
+
+    #[test]
+    fn test_remainder_of_shares_is_retained_by_platform() {
+        let payment_splits = vec![payment_split("acct_1", 50), payment_split("acct_2", 50)];
+        let split_payments_data =
+            get_split_payments_data("adyen", &payment_splits, MinorUnit::new(101)).unwrap();
+
+        assert_eq!(split_payments_data.platform_amount, MinorUnit::new(1));
+    }
+
+    #[test]
+    fn test_invalid_split_payments_are_rejected() {
+        let exceeding_shares = SplitPaymentsRequest {
+            recipients: vec![
+                fixed_recipient("acct_1", 600),
+                percentage_recipient("acct_2", 50.0),
+            ],
+            fee_bearer: SplitFeeBearer::Platform,
+        };
+        let duplicate_recipients = SplitPaymentsRequest {
+            recipients: vec![
+                fixed_recipient("acct_1", 100),
+                fixed_recipient("acct_1", 100),
+            ],
+            fee_bearer: SplitFeeBearer::Platform,
+        };
+        let unknown_fee_bearer = SplitPaymentsRequest {
+            recipients: vec![fixed_recipient("acct_1", 100)],
+            fee_bearer: SplitFeeBearer::Recipient {
+                recipient_account_id: "acct_2".to_string(),
+            },
+        };
+        // 0.01% of 99 is floored to zero
+        let zero_share = SplitPaymentsRequest {
+            recipients: vec![percentage_recipient("acct_1", 0.01)],
+            fee_bearer: SplitFeeBearer::Platform,
+        };
+
+        for (split_payments, amount) in [
+            (exceeding_shares, 1000),
+            (duplicate_recipients, 1000),
+            (unknown_fee_bearer, 1000),
+            (zero_share, 99),
+        ] {
+            assert!(
+                validate_split_payments(&split_payments, None, MinorUnit::new(amount).into())
+                    .is_err()
+            );
+        }
+    }
+
+    #[test]
+    fn test_split_refunds_are_proportional_and_floored() {
+        let mut payment_splits = vec![payment_split("acct_1", 50), payment_split("acct_2", 30)];
+        let split_refunds_data = get_split_refunds_data(
+            "adyen",
+            &payment_splits,
+            MinorUnit::new(101),
+            MinorUnit::new(51),
+        )
+        .unwrap()
+        .unwrap();
+        let refunded_amounts: Vec<_> = split_refunds_data
+            .recipients
+            .iter()
+            .map(|recipient| recipient.amount)
+            .collect();
+
+        assert_eq!(
+            refunded_amounts,
+            vec![MinorUnit::new(25), MinorUnit::new(15)]
+        );
+        assert_eq!(split_refunds_data.platform_amount, MinorUnit::new(11));
+
+        // A recipient is never debited more than what is left of its share
+        if let Some(payment_split) = payment_splits.first_mut() {
+            payment_split.refunded_amount = MinorUnit::new(40);
+        }
+        let split_refunds_data = get_split_refunds_data(
+            "adyen",
+            &payment_splits,
+            MinorUnit::new(101),
+            MinorUnit::new(51),
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(
+            split_refunds_data
+                .recipients
+                .first()
+                .map(|recipient| recipient.amount),
+            Some(MinorUnit::new(10))
+        );
+    }
+
+    #[test]
+    fn test_split_refunds_are_not_sent_for_ledger_settlement() {
+        let payment_splits = vec![payment_split("acct_1", 50), payment_split("acct_2", 30)];
+
+        assert!(get_split_refunds_data(
+            "stripe",
+            &payment_splits,
+            MinorUnit::new(100),
+            MinorUnit::new(50),
+        )
+        .unwrap()
+        .is_none());
+    }
+}
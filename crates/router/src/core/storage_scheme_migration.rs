@@ -0,0 +1,734 @@
+use std::borrow::Cow;
+
+use api_models::admin as admin_types;
+use common_utils::{date_time, ext_traits::ValueExt, id_type};
+use diesel_models::{
+    enums::{MerchantStorageScheme, ProcessTrackerStatus, StorageSchemeMigrationStatus},
+    process_tracker::business_status,
+    StorageSchemeMigrationTrackingData,
+};
+use error_stack::ResultExt;
+use router_env::{instrument, logger, metrics::add_attributes, tracing};
+
+use crate::{
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::{metrics, SessionState},
+    services::ApplicationResponse,
+    types::{
+        domain, storage,
+        transformers::{ForeignFrom, ForeignInto},
+    },
+};
+
+const STORAGE_SCHEME_MIGRATION_TAG: &str = "MERCHANT_ACCOUNT";
+const STORAGE_SCHEME_MIGRATION_NAME: &str = "STORAGE_SCHEME_MIGRATION";
+const STORAGE_SCHEME_MIGRATION_RUNNER: diesel_models::ProcessTrackerRunner =
+    diesel_models::ProcessTrackerRunner::StorageSchemeMigrationWorkflow;
+
+/// Statuses of a migration task waiting for its next step, which can be rolled back
+const ROLLBACK_ALLOWED_PROCESS_STATUSES: [ProcessTrackerStatus; 2] =
+    [ProcessTrackerStatus::New, ProcessTrackerStatus::Pending];
+
+#[instrument(skip_all)]
+pub async fn start_storage_scheme_migration(
+    state: SessionState,
+    req: admin_types::StorageSchemeMigrationRequest,
+) -> RouterResponse<admin_types::StorageSchemeMigrationResponse> {
+    let db = state.store.as_ref();
+    let (merchant_account, _key_store) =
+        get_merchant_account_and_key_store(&state, &req.merchant_id).await?;
+
+    if merchant_account.storage_scheme == req.storage_scheme {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "Merchant account already uses the `{}` storage scheme",
+                req.storage_scheme
+            ),
+        })?
+    }
+
+    if req.storage_scheme == MerchantStorageScheme::RedisKv
+        && state.conf.as_ref().is_kv_soft_kill_mode()
+    {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Kv cannot be enabled when application is in soft_kill_mode".to_owned(),
+        })?
+    }
+
+    let migration_config = &state.conf.storage_scheme_migration;
+    let verification_sample_size = req
+        .verification_sample_size
+        .unwrap_or(migration_config.default_verification_sample_size);
+    if verification_sample_size > migration_config.max_verification_sample_size {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "Verification sample size must not exceed {}",
+                migration_config.max_verification_sample_size
+            ),
+        })?
+    }
+
+    let task_id = generate_task_id_for_storage_scheme_migration_workflow(&req.merchant_id);
+    let existing_process = db
+        .find_process_by_id(&task_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch storage scheme migration task")?;
+
+    let current_time = date_time::now();
+    let tracking_data = StorageSchemeMigrationTrackingData {
+        merchant_id: req.merchant_id.clone(),
+        source_storage_scheme: merchant_account.storage_scheme,
+        target_storage_scheme: req.storage_scheme,
+        status: StorageSchemeMigrationStatus::Draining,
+        verification_sample_size,
+        pending_kv_entries: 0,
+        drain_attempts: 0,
+        sampled_keys: 0,
+        mismatched_keys: Vec::new(),
+        failure_reason: None,
+        started_at: current_time,
+        switched_at: None,
+        completed_at: None,
+    };
+
+    match existing_process {
+        Some(process) if process.status != ProcessTrackerStatus::Finish => {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "A storage scheme migration is already in progress for this merchant"
+                    .to_owned(),
+            })?
+        }
+        // A migration has been run for this merchant before, the same task is reused
+        Some(process) => {
+            let tracking_data = serde_json::to_value(&tracking_data)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to serialize storage scheme migration tracking data")?;
+            db.as_scheduler()
+                .update_process(
+                    process,
+                    storage::ProcessTrackerUpdate::Update {
+                        name: None,
+                        retry_count: Some(0),
+                        schedule_time: Some(current_time),
+                        tracking_data: Some(tracking_data),
+                        business_status: Some(String::from(business_status::PENDING)),
+                        status: Some(ProcessTrackerStatus::New),
+                        updated_at: Some(current_time),
+                    },
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to reschedule storage scheme migration task")?;
+        }
+        None => {
+            let process_tracker_entry = storage::ProcessTrackerNew::new(
+                task_id,
+                STORAGE_SCHEME_MIGRATION_NAME,
+                STORAGE_SCHEME_MIGRATION_RUNNER,
+                [STORAGE_SCHEME_MIGRATION_TAG],
+                tracking_data.clone(),
+                current_time,
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to construct storage scheme migration task")?;
+
+            db.insert_process(process_tracker_entry)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to insert storage scheme migration task")?;
+        }
+    }
+
+    metrics::TASKS_ADDED_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &add_attributes([("flow", "StorageSchemeMigration")]),
+    );
+
+    Ok(ApplicationResponse::Json(tracking_data.foreign_into()))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_storage_scheme_migration(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+) -> RouterResponse<admin_types::StorageSchemeMigrationResponse> {
+    let (_process, tracking_data) = find_storage_scheme_migration(&state, &merchant_id).await?;
+
+    Ok(ApplicationResponse::Json(tracking_data.foreign_into()))
+}
+
+/// Stops a migration in progress and restores the storage scheme the merchant account had when
+/// the migration was started.
+///
+/// The rollback is rejected while the workflow is executing a step of the migration, so that the
+/// step does not overwrite the rollback. Rolling back again only restores the storage scheme.
+#[instrument(skip_all)]
+pub async fn rollback_storage_scheme_migration(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+) -> RouterResponse<admin_types::StorageSchemeMigrationResponse> {
+    let db = state.store.as_ref();
+    let (process, mut tracking_data) = find_storage_scheme_migration(&state, &merchant_id).await?;
+
+    if tracking_data.status != StorageSchemeMigrationStatus::RolledBack {
+        if process.status == ProcessTrackerStatus::Finish {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "No storage scheme migration is in progress for this merchant".to_owned(),
+            })?
+        }
+
+        let current_time = date_time::now();
+        tracking_data.status = StorageSchemeMigrationStatus::RolledBack;
+        tracking_data.completed_at = Some(current_time);
+        let tracking_data_value = serde_json::to_value(&tracking_data)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to serialize storage scheme migration tracking data")?;
+
+        // Only a task waiting for its next step is updated, a task picked up by the scheduler
+        // in the meantime is left to the workflow
+        let updated_processes = db
+            .as_scheduler()
+            .update_process_if_unchanged(
+                process,
+                ROLLBACK_ALLOWED_PROCESS_STATUSES.to_vec(),
+                storage::ProcessTrackerUpdate::Update {
+                    name: None,
+                    retry_count: None,
+                    schedule_time: None,
+                    tracking_data: Some(tracking_data_value),
+                    business_status: Some(String::from(business_status::REVOKED)),
+                    status: Some(ProcessTrackerStatus::Finish),
+                    updated_at: Some(current_time),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update storage scheme migration task")?;
+
+        if updated_processes == 0 {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "A step of the storage scheme migration is being executed, retry the rollback shortly".to_owned(),
+            })?
+        }
+    }
+
+    if tracking_data.switched_at.is_some() {
+        switch_storage_scheme(&state, &merchant_id, tracking_data.source_storage_scheme).await?;
+    }
+
+    Ok(ApplicationResponse::Json(tracking_data.foreign_into()))
+}
+
+/// Whether the storage scheme of the merchant account is switched before draining its KV entries.
+///
+/// A merchant leaving KV keeps pushing entries to the drainer streams until it is switched, so it
+/// is switched first. A merchant entering KV is switched only after the entries left over from an
+/// earlier KV period have been drained and verified.
+pub fn is_switched_before_draining(target_storage_scheme: MerchantStorageScheme) -> bool {
+    target_storage_scheme == MerchantStorageScheme::PostgresOnly
+}
+
+async fn get_merchant_account_and_key_store(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<(domain::MerchantAccount, domain::MerchantKeyStore)> {
+    let db = state.store.as_ref();
+    let key_manager_state = &state.into();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(key_manager_state, merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    Ok((merchant_account, key_store))
+}
+
+async fn find_storage_scheme_migration(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<(storage::ProcessTracker, StorageSchemeMigrationTrackingData)> {
+    let task_id = generate_task_id_for_storage_scheme_migration_workflow(merchant_id);
+    let process = state
+        .store
+        .find_process_by_id(&task_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch storage scheme migration task")?
+        .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "No storage scheme migration found for this merchant".to_owned(),
+        })?;
+
+    let tracking_data = process
+        .tracking_data
+        .clone()
+        .parse_value("StorageSchemeMigrationTrackingData")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    Ok((process, tracking_data))
+}
+
+/// Updates the storage scheme of the merchant account without any checks.
+pub async fn switch_storage_scheme(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    storage_scheme: MerchantStorageScheme,
+) -> RouterResult<()> {
+    let (merchant_account, key_store) =
+        get_merchant_account_and_key_store(state, merchant_id).await?;
+    if merchant_account.storage_scheme == storage_scheme {
+        return Ok(());
+    }
+
+    state
+        .store
+        .update_merchant(
+            &state.into(),
+            merchant_account,
+            storage::MerchantAccountUpdate::StorageSchemeUpdate { storage_scheme },
+            &key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("failed to switch merchant_storage_scheme")?;
+
+    Ok(())
+}
+
+/// Counts the entries of the merchant which are yet to be written to the database by the drainer.
+///
+/// The drainer removes entries from its streams once they have been processed, so every entry of
+/// the merchant still present in any of the partitions is pending.
+#[instrument(skip_all)]
+pub async fn count_pending_kv_entries(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<u64> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let drainer_config = &state.conf.drainer;
+    let global_id_prefix = format!("mid_{}_", merchant_id.get_string_repr());
+    let mut pending_entries = 0;
+
+    for partition in 0..drainer_config.num_partitions {
+        // Example: {shard_5}_public_drainer_stream
+        let stream_name = format!(
+            "{{shard_{partition}}}_{}_{}",
+            state.tenant.schema, drainer_config.stream_name
+        );
+        let mut last_entry_id = String::from("0-0");
+
+        loop {
+            let read_result = match redis_conn
+                .stream_read_entries(
+                    stream_name.as_str(),
+                    last_entry_id.as_str(),
+                    Some(drainer_config.max_read_count),
+                )
+                .await
+            {
+                Ok(read_result) => read_result,
+                Err(error)
+                    if matches!(
+                        error.current_context(),
+                        redis_interface::errors::RedisError::StreamEmptyOrNotAvailable
+                    ) =>
+                {
+                    break
+                }
+                Err(error) => {
+                    return Err(error
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable_lazy(|| {
+                            format!("Failed to read entries from drainer stream {stream_name}")
+                        }))
+                }
+            };
+
+            let entries = read_result.into_values().flatten().collect::<Vec<_>>();
+            pending_entries += entries
+                .iter()
+                .filter(|(_, fields)| is_merchant_stream_entry(fields, &global_id_prefix))
+                .count();
+
+            match entries.last() {
+                Some((entry_id, _))
+                    if u64::try_from(entries.len())
+                        .is_ok_and(|read_count| read_count >= drainer_config.max_read_count) =>
+                {
+                    last_entry_id.clone_from(entry_id)
+                }
+                _ => break,
+            }
+        }
+    }
+
+    u64::try_from(pending_entries)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to convert pending entries count")
+}
+
+fn is_merchant_stream_entry(
+    fields: &std::collections::HashMap<String, String>,
+    global_id_prefix: &str,
+) -> bool {
+    fields
+        .get("global_id")
+        .is_some_and(|global_id| global_id.starts_with(global_id_prefix))
+}
+
+/// Compares the payment intents, payment attempts and refunds of the merchant present in redis
+/// against their copies in the database. Returns the number of keys compared and the keys holding
+/// entries which differ.
+#[instrument(skip_all)]
+pub async fn verify_sampled_kv_entries(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    sample_size: u16,
+) -> RouterResult<(u16, Vec<String>)> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let (_merchant_account, key_store) =
+        get_merchant_account_and_key_store(state, merchant_id).await?;
+
+    let key_prefix = format!("mid_{}_pid_", merchant_id.get_string_repr());
+    // Keys returned by the scan include the prefix of the redis connection
+    let prefixed_key_prefix = redis_conn.add_prefix(&key_prefix);
+    let keys = redis_conn
+        .scan(
+            &format!("{key_prefix}*"),
+            Some(u32::from(sample_size)),
+            None,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to scan KV entries of the merchant")?;
+
+    let mut sampled_keys = 0;
+    let mut mismatched_keys = Vec::new();
+
+    for payment_id in keys
+        .iter()
+        .filter_map(|key| key.strip_prefix(&prefixed_key_prefix))
+    {
+        if sampled_keys >= sample_size {
+            break;
+        }
+
+        let Ok(payment_id) = id_type::PaymentId::try_from(Cow::Owned(payment_id.to_owned())) else {
+            continue;
+        };
+        let key = format!("{key_prefix}{}", payment_id.get_string_repr());
+
+        // The key may hold any of the payment intent, the payment attempts and the refunds of the
+        // payment, so each entry present in redis is compared against the database
+        let redis_payment_intent = redis_conn
+            .get_hash_field_and_deserialize::<diesel_models::PaymentIntent>(
+                &key,
+                &payment_id.get_hash_key_for_kv_store(),
+                "PaymentIntent",
+            )
+            .await
+            .ok();
+        let redis_payment_attempts = redis_conn
+            .hscan_and_deserialize::<diesel_models::PaymentAttempt>(&key, "pa_*", None)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to read payment attempts from KV")?;
+        let redis_refunds = redis_conn
+            .hscan_and_deserialize::<diesel_models::Refund>(&key, "pa_*_ref_*", None)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to read refunds from KV")?;
+
+        if redis_payment_intent.is_none()
+            && redis_payment_attempts.is_empty()
+            && redis_refunds.is_empty()
+        {
+            logger::debug!(%key, "Skipping KV entry without payment data during verification");
+            continue;
+        }
+        sampled_keys += 1;
+
+        let mut is_consistent = true;
+
+        if let Some(redis_payment_intent) = redis_payment_intent {
+            let database_payment_intent = state
+                .store
+                .find_payment_intent_by_payment_id_merchant_id(
+                    &state.into(),
+                    &payment_id,
+                    merchant_id,
+                    &key_store,
+                    MerchantStorageScheme::PostgresOnly,
+                )
+                .await;
+            is_consistent &= database_payment_intent.is_ok_and(|payment_intent| {
+                is_same_version(
+                    (payment_intent.status, payment_intent.modified_at),
+                    (
+                        redis_payment_intent.status,
+                        redis_payment_intent.modified_at,
+                    ),
+                )
+            });
+        }
+
+        for redis_payment_attempt in redis_payment_attempts {
+            if !is_consistent {
+                break;
+            }
+            let database_payment_attempt = state
+                .store
+                .find_payment_attempt_by_attempt_id_merchant_id(
+                    &redis_payment_attempt.attempt_id,
+                    merchant_id,
+                    MerchantStorageScheme::PostgresOnly,
+                )
+                .await;
+            is_consistent &= database_payment_attempt.is_ok_and(|payment_attempt| {
+                is_same_version(
+                    (payment_attempt.status, payment_attempt.modified_at),
+                    (
+                        redis_payment_attempt.status,
+                        redis_payment_attempt.modified_at,
+                    ),
+                )
+            });
+        }
+
+        for redis_refund in redis_refunds {
+            if !is_consistent {
+                break;
+            }
+            let database_refund = state
+                .store
+                .find_refund_by_merchant_id_refund_id(
+                    merchant_id,
+                    &redis_refund.refund_id,
+                    MerchantStorageScheme::PostgresOnly,
+                )
+                .await;
+            is_consistent &= database_refund.is_ok_and(|refund| {
+                is_same_version(
+                    (refund.refund_status, refund.modified_at),
+                    (redis_refund.refund_status, redis_refund.modified_at),
+                )
+            });
+        }
+
+        if !is_consistent {
+            mismatched_keys.push(key);
+        }
+    }
+
+    Ok((sampled_keys, mismatched_keys))
+}
+
+/// Entries are considered the same version when both their status and modification time match
+fn is_same_version<S: PartialEq>(
+    database_version: (S, time::PrimitiveDateTime),
+    redis_version: (S, time::PrimitiveDateTime),
+) -> bool {
+    database_version == redis_version
+}
+
+pub fn generate_task_id_for_storage_scheme_migration_workflow(
+    merchant_id: &id_type::MerchantId,
+) -> String {
+    format!(
+        "{STORAGE_SCHEME_MIGRATION_RUNNER}_{STORAGE_SCHEME_MIGRATION_NAME}_{}",
+        merchant_id.get_string_repr()
+    )
+}
+
+impl ForeignFrom<StorageSchemeMigrationTrackingData>
+    for admin_types::StorageSchemeMigrationResponse
+{
+    fn foreign_from(tracking_data: StorageSchemeMigrationTrackingData) -> Self {
+        Self {
+            merchant_id: tracking_data.merchant_id,
+            status: tracking_data.status,
+            source_storage_scheme: tracking_data.source_storage_scheme,
+            target_storage_scheme: tracking_data.target_storage_scheme,
+            pending_kv_entries: tracking_data.pending_kv_entries,
+            sampled_keys: tracking_data.sampled_keys,
+            mismatched_keys: tracking_data.mismatched_keys,
+            failure_reason: tracking_data.failure_reason,
+            started_at: tracking_data.started_at,
+            switched_at: tracking_data.switched_at,
+            completed_at: tracking_data.completed_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::{collections::HashMap, sync::Arc};
+
+    use scheduler::workflows::ProcessTrackerWorkflow;
+
+    use super::*;
+    use crate::{
+        routes::{
+            self,
+            app::{settings::Settings, StorageImpl},
+        },
+        services,
+        workflows::storage_scheme_migration::StorageSchemeMigrationWorkflow,
+    };
+
+    #[test]
+    fn test_merchant_leaving_kv_is_switched_before_draining() {
+        assert!(is_switched_before_draining(
+            MerchantStorageScheme::PostgresOnly
+        ));
+        assert!(!is_switched_before_draining(MerchantStorageScheme::RedisKv));
+    }
+
+    #[test]
+    fn test_stream_entries_are_matched_by_merchant() {
+        let global_id_prefix = "mid_merchant_1_";
+        let entry =
+            |global_id: &str| HashMap::from([("global_id".to_string(), global_id.to_string())]);
+
+        assert!(is_merchant_stream_entry(
+            &entry("mid_merchant_1_pid_pay_1"),
+            global_id_prefix
+        ));
+        assert!(!is_merchant_stream_entry(
+            &entry("mid_merchant_10_pid_pay_1"),
+            global_id_prefix
+        ));
+        assert!(!is_merchant_stream_entry(&HashMap::new(), global_id_prefix));
+    }
+
+    #[test]
+    fn test_entries_differing_in_status_or_modification_time_are_mismatched() {
+        let modified_at = date_time::now();
+        let later = modified_at.saturating_add(time::Duration::seconds(1));
+
+        assert!(is_same_version(
+            ("succeeded", modified_at),
+            ("succeeded", modified_at)
+        ));
+        assert!(!is_same_version(
+            ("succeeded", modified_at),
+            ("processing", modified_at)
+        ));
+        assert!(!is_same_version(
+            ("succeeded", modified_at),
+            ("succeeded", later)
+        ));
+    }
+
+    #[test]
+    fn test_only_waiting_tasks_can_be_rolled_back() {
+        assert!(ROLLBACK_ALLOWED_PROCESS_STATUSES.contains(&ProcessTrackerStatus::New));
+        assert!(ROLLBACK_ALLOWED_PROCESS_STATUSES.contains(&ProcessTrackerStatus::Pending));
+        assert!(!ROLLBACK_ALLOWED_PROCESS_STATUSES.contains(&ProcessTrackerStatus::Processing));
+        assert!(!ROLLBACK_ALLOWED_PROCESS_STATUSES.contains(&ProcessTrackerStatus::ProcessStarted));
+    }
+
+    #[allow(clippy::panic)]
+    #[tokio::test]
+    async fn test_migration_with_failed_step_can_be_rolled_back() {
+        let (tx, _) = tokio::sync::oneshot::channel();
+        let app_state = Box::pin(routes::AppState::with_storage(
+            Settings::default(),
+            StorageImpl::Mock,
+            tx,
+            Box::new(services::MockApiClient),
+        ))
+        .await;
+        let state = Arc::new(app_state)
+            .get_session_state("public", || {})
+            .unwrap();
+        let merchant_id = id_type::MerchantId::try_from(Cow::from("merchant_1")).unwrap();
+
+        let current_time = date_time::now();
+        let tracking_data = StorageSchemeMigrationTrackingData {
+            merchant_id: merchant_id.clone(),
+            source_storage_scheme: MerchantStorageScheme::PostgresOnly,
+            target_storage_scheme: MerchantStorageScheme::RedisKv,
+            status: StorageSchemeMigrationStatus::Draining,
+            verification_sample_size: 10,
+            pending_kv_entries: 0,
+            drain_attempts: 0,
+            sampled_keys: 0,
+            mismatched_keys: Vec::new(),
+            failure_reason: None,
+            started_at: current_time,
+            switched_at: None,
+            completed_at: None,
+        };
+        let mut process_tracker_entry = storage::ProcessTrackerNew::new(
+            generate_task_id_for_storage_scheme_migration_workflow(&merchant_id),
+            STORAGE_SCHEME_MIGRATION_NAME,
+            STORAGE_SCHEME_MIGRATION_RUNNER,
+            [STORAGE_SCHEME_MIGRATION_TAG],
+            tracking_data,
+            current_time,
+        )
+        .unwrap();
+        // The scheduler marks the task as started before executing a step of the migration
+        process_tracker_entry.status = ProcessTrackerStatus::ProcessStarted;
+        let process = state
+            .store
+            .insert_process(process_tracker_entry)
+            .await
+            .unwrap();
+
+        StorageSchemeMigrationWorkflow
+            .error_handler(
+                &state,
+                process,
+                errors::ProcessTrackerError::FlowExecutionError {
+                    flow: "StorageSchemeMigration",
+                },
+            )
+            .await
+            .unwrap();
+
+        let (process, _tracking_data) = find_storage_scheme_migration(&state, &merchant_id)
+            .await
+            .unwrap();
+        assert_eq!(process.status, ProcessTrackerStatus::Pending);
+        assert_eq!(process.retry_count, 1);
+
+        let response = rollback_storage_scheme_migration(state.clone(), merchant_id.clone())
+            .await
+            .unwrap();
+        let ApplicationResponse::Json(response) = response else {
+            panic!("Unexpected response from storage scheme migration rollback");
+        };
+        assert_eq!(response.status, StorageSchemeMigrationStatus::RolledBack);
+
+        let (process, tracking_data) = find_storage_scheme_migration(&state, &merchant_id)
+            .await
+            .unwrap();
+        assert_eq!(process.status, ProcessTrackerStatus::Finish);
+        assert_eq!(process.business_status, business_status::REVOKED);
+        assert_eq!(
+            tracking_data.status,
+            StorageSchemeMigrationStatus::RolledBack
+        );
+    }
+}
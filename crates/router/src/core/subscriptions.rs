@@ -0,0 +1,1035 @@
+use api_models::{mandates::RecurringDetails, subscriptions as subscription_types};
+use common_utils::{date_time, id_type};
+use diesel_models::{enums as storage_enums, process_tracker::business_status};
+use error_stack::ResultExt;
+use router_env::{instrument, logger, metrics::add_attributes, tracing};
+use time::PrimitiveDateTime;
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments, webhooks,
+    },
+    routes::{metrics, SessionState},
+    services::{self, ApplicationResponse},
+    types::{
+        api::{self as api_types, payments as payment_types},
+        domain, storage,
+        transformers::{ForeignFrom, ForeignInto},
+    },
+};
+
+const SUBSCRIPTION_BILLING_TAG: &str = "SUBSCRIPTION";
+const SUBSCRIPTION_BILLING_NAME: &str = "SUBSCRIPTION_BILLING";
+const SUBSCRIPTION_BILLING_RUNNER: diesel_models::ProcessTrackerRunner =
+    diesel_models::ProcessTrackerRunner::SubscriptionBillingWorkflow;
+
+#[instrument(skip_all)]
+pub async fn create_subscription_plan(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    req: subscription_types::SubscriptionPlanCreateRequest,
+) -> RouterResponse<subscription_types::SubscriptionPlanResponse> {
+    if req.amount.get_amount_as_i64() <= 0 {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Amount of a subscription plan must be greater than zero".to_owned(),
+        })?
+    }
+    let interval_count = req.interval_count.unwrap_or(1);
+    if interval_count == 0 {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`interval_count` must be greater than zero".to_owned(),
+        })?
+    }
+
+    let now = date_time::now();
+    let plan_new = storage::SubscriptionPlanNew {
+        plan_id: common_utils::generate_id(consts::ID_LENGTH, "plan"),
+        merchant_id: merchant_account.get_id().to_owned(),
+        name: req.name,
+        description: req.description,
+        amount: req.amount,
+        currency: req.currency,
+        billing_interval: req.interval,
+        interval_count: i32::from(interval_count),
+        trial_period_days: req.trial_period_days.map(i32::from),
+        is_active: true,
+        metadata: req.metadata,
+        created_at: now,
+        modified_at: now,
+    };
+
+    let plan = state
+        .store
+        .insert_subscription_plan(plan_new)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert subscription plan")?;
+
+    Ok(ApplicationResponse::Json(plan.foreign_into()))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_subscription_plan(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    plan_id: String,
+) -> RouterResponse<subscription_types::SubscriptionPlanResponse> {
+    let plan = state
+        .store
+        .find_subscription_plan_by_merchant_id_plan_id(merchant_account.get_id(), &plan_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Subscription plan `{plan_id}` does not exist"),
+        })?;
+
+    Ok(ApplicationResponse::Json(plan.foreign_into()))
+}
+
+#[instrument(skip_all)]
+pub async fn update_subscription_plan(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    plan_id: String,
+    req: subscription_types::SubscriptionPlanUpdateRequest,
+) -> RouterResponse<subscription_types::SubscriptionPlanResponse> {
+    let plan_update = storage::SubscriptionPlanUpdate::Update {
+        name: req.name,
+        description: req.description,
+        is_active: req.is_active,
+        metadata: req.metadata,
+    };
+
+    let plan = state
+        .store
+        .update_subscription_plan_by_merchant_id_plan_id(
+            merchant_account.get_id(),
+            &plan_id,
+            plan_update,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Subscription plan `{plan_id}` does not exist"),
+        })?;
+
+    Ok(ApplicationResponse::Json(plan.foreign_into()))
+}
+
+#[instrument(skip_all)]
+pub async fn list_subscription_plans(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<subscription_types::SubscriptionPlanListResponse> {
+    let plans = state
+        .store
+        .list_subscription_plans_by_merchant_id(merchant_account.get_id())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list subscription plans")?;
+
+    let data: Vec<subscription_types::SubscriptionPlanResponse> =
+        plans.into_iter().map(ForeignInto::foreign_into).collect();
+
+    Ok(ApplicationResponse::Json(
+        subscription_types::SubscriptionPlanListResponse {
+            count: data.len(),
+            data,
+        },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn create_subscription(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: subscription_types::SubscriptionCreateRequest,
+) -> RouterResponse<subscription_types::SubscriptionResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let merchant_id = merchant_account.get_id();
+
+    let plan = db
+        .find_subscription_plan_by_merchant_id_plan_id(merchant_id, &req.plan_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Subscription plan `{}` does not exist", req.plan_id),
+        })?;
+    if !plan.is_active {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "New subscriptions cannot be created for an inactive plan".to_owned(),
+        })?
+    }
+
+    db.find_customer_by_customer_id_merchant_id(
+        key_manager_state,
+        &req.customer_id,
+        merchant_id,
+        &key_store,
+        merchant_account.storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+
+    let (mandate_id, payment_method_id) = match req.recurring_details {
+        RecurringDetails::MandateId(mandate_id) => {
+            let mandate = db
+                .find_mandate_by_merchant_id_mandate_id(
+                    merchant_id,
+                    &mandate_id,
+                    merchant_account.storage_scheme,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?;
+            if mandate.customer_id != req.customer_id {
+                Err(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "Mandate does not belong to the customer".to_owned(),
+                })?
+            }
+            if mandate.mandate_status != storage_enums::MandateStatus::Active {
+                Err(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "Mandate is not active".to_owned(),
+                })?
+            }
+            (Some(mandate_id), None)
+        }
+        RecurringDetails::PaymentMethodId(payment_method_id) => {
+            let payment_method = db
+                .find_payment_method(
+                    key_manager_state,
+                    &key_store,
+                    &payment_method_id,
+                    merchant_account.storage_scheme,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+            if payment_method.customer_id != req.customer_id {
+                Err(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "Payment method does not belong to the customer".to_owned(),
+                })?
+            }
+            (None, Some(payment_method_id))
+        }
+        RecurringDetails::ProcessorPaymentToken(_)
+        | RecurringDetails::NetworkTransactionIdAndCardDetails(_) => {
+            Err(errors::ApiErrorResponse::NotSupported {
+                message: "Only `mandate_id` and `payment_method_id` recurring details are \
+                          supported for subscriptions"
+                    .to_owned(),
+            })?
+        }
+    };
+
+    let profile_id = req
+        .profile_id
+        .or(merchant_account.default_profile.clone())
+        .ok_or(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "profile_id",
+        })?;
+    db.find_business_profile_by_merchant_id_profile_id(
+        key_manager_state,
+        &key_store,
+        merchant_id,
+        &profile_id,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+        id: profile_id.get_string_repr().to_owned(),
+    })?;
+
+    let now = date_time::now();
+    let start_date = req.start_date.unwrap_or(now);
+    if start_date < now {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`start_date` must not be in the past".to_owned(),
+        })?
+    }
+    let trial_end = plan.trial_period_days.map(|trial_period_days| {
+        start_date.saturating_add(time::Duration::days(i64::from(trial_period_days)))
+    });
+    // The first billing period starts at the end of the trial, or at the start date if the plan
+    // has no trial, and is charged for when it starts
+    let (status, first_billing_date) = match trial_end {
+        Some(trial_end) => (storage_enums::SubscriptionStatus::Trialing, trial_end),
+        None => (storage_enums::SubscriptionStatus::Active, start_date),
+    };
+
+    let subscription_new = storage::SubscriptionNew {
+        subscription_id: common_utils::generate_id(consts::ID_LENGTH, "sub"),
+        merchant_id: merchant_id.to_owned(),
+        profile_id,
+        customer_id: req.customer_id,
+        plan_id: plan.plan_id,
+        status,
+        mandate_id,
+        payment_method_id,
+        current_period_start: start_date,
+        current_period_end: first_billing_date,
+        trial_end,
+        billing_cycle: 0,
+        latest_payment_id: None,
+        cancel_at_period_end: false,
+        cancelled_at: None,
+        metadata: req.metadata,
+        created_at: now,
+        modified_at: now,
+        billing_anchor: first_billing_date,
+    };
+    let subscription = db
+        .insert_subscription(subscription_new)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert subscription")?;
+
+    add_subscription_billing_task(db, &subscription).await?;
+
+    trigger_subscription_webhook(
+        &state,
+        &merchant_account,
+        &key_store,
+        &subscription,
+        storage_enums::EventType::SubscriptionCreated,
+        subscription.subscription_id.clone(),
+    )
+    .await;
+
+    Ok(ApplicationResponse::Json(subscription.foreign_into()))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_subscription(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    subscription_id: String,
+) -> RouterResponse<subscription_types::SubscriptionResponse> {
+    let subscription = state
+        .store
+        .find_subscription_by_merchant_id_subscription_id(
+            merchant_account.get_id(),
+            &subscription_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Subscription `{subscription_id}` does not exist"),
+        })?;
+
+    Ok(ApplicationResponse::Json(subscription.foreign_into()))
+}
+
+#[instrument(skip_all)]
+pub async fn list_subscriptions(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    constraints: subscription_types::SubscriptionListConstraints,
+) -> RouterResponse<subscription_types::SubscriptionListResponse> {
+    let subscriptions = state
+        .store
+        .filter_subscriptions_by_merchant_id_constraints(
+            merchant_account.get_id(),
+            constraints.customer_id.as_ref(),
+            constraints.status,
+            constraints.limit,
+            constraints.offset,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list subscriptions")?;
+
+    let data: Vec<subscription_types::SubscriptionResponse> = subscriptions
+        .into_iter()
+        .map(ForeignInto::foreign_into)
+        .collect();
+
+    Ok(ApplicationResponse::Json(
+        subscription_types::SubscriptionListResponse {
+            count: data.len(),
+            data,
+        },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn cancel_subscription(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    subscription_id: String,
+    req: subscription_types::SubscriptionCancelRequest,
+) -> RouterResponse<subscription_types::SubscriptionResponse> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_account.get_id();
+
+    let subscription = db
+        .find_subscription_by_merchant_id_subscription_id(merchant_id, &subscription_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Subscription `{subscription_id}` does not exist"),
+        })?;
+    if subscription.status == storage_enums::SubscriptionStatus::Cancelled {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Subscription has already been cancelled".to_owned(),
+        })?
+    }
+
+    // A subscription which is past due is not billed again, so it can only be cancelled right away
+    let cancel_immediately = !req.cancel_at_period_end
+        || subscription.status == storage_enums::SubscriptionStatus::PastDue;
+    let subscription_update = if cancel_immediately {
+        storage::SubscriptionUpdate::CancellationUpdate {
+            status: Some(storage_enums::SubscriptionStatus::Cancelled),
+            cancel_at_period_end: false,
+            cancelled_at: Some(date_time::now()),
+        }
+    } else {
+        storage::SubscriptionUpdate::CancellationUpdate {
+            status: None,
+            cancel_at_period_end: true,
+            cancelled_at: None,
+        }
+    };
+
+    let subscription = db
+        .update_subscription_by_merchant_id_subscription_id(
+            merchant_id,
+            &subscription_id,
+            subscription_update,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update subscription")?;
+
+    if cancel_immediately {
+        revoke_subscription_billing_task(db, &subscription.subscription_id).await?;
+        trigger_subscription_webhook(
+            &state,
+            &merchant_account,
+            &key_store,
+            &subscription,
+            storage_enums::EventType::SubscriptionCancelled,
+            subscription.subscription_id.clone(),
+        )
+        .await;
+    }
+
+    Ok(ApplicationResponse::Json(subscription.foreign_into()))
+}
+
+/// The outcome of charging a customer for a billing period of their subscription.
+pub enum SubscriptionPaymentOutcome {
+    /// The payment succeeded, or is being processed by the connector
+    Charged { payment_id: id_type::PaymentId },
+    /// The payment failed, or could not be created at all
+    Failed {
+        payment_id: Option<id_type::PaymentId>,
+        connector: Option<String>,
+        error_code: Option<String>,
+        error_message: Option<String>,
+    },
+}
+
+/// Creates an off-session payment against the mandate or the saved payment method of the
+/// subscription, for the billing period starting at `current_period_end`.
+#[instrument(skip_all)]
+pub async fn create_subscription_payment(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    subscription: &storage::Subscription,
+    plan: &storage::SubscriptionPlan,
+) -> RouterResult<SubscriptionPaymentOutcome> {
+    let recurring_details = match (&subscription.mandate_id, &subscription.payment_method_id) {
+        (Some(mandate_id), _) => RecurringDetails::MandateId(mandate_id.clone()),
+        (None, Some(payment_method_id)) => {
+            RecurringDetails::PaymentMethodId(payment_method_id.clone())
+        }
+        (None, None) => Err(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Subscription has neither a mandate nor a payment method")?,
+    };
+    let payment_id = get_subscription_payment_id(subscription)?;
+
+    let req = payment_types::PaymentsRequest {
+        payment_id: Some(payment_types::PaymentIdType::PaymentIntentId(
+            payment_id.clone(),
+        )),
+        merchant_id: Some(merchant_account.get_id().to_owned()),
+        amount: Some(plan.amount.into()),
+        currency: Some(plan.currency),
+        customer_id: Some(subscription.customer_id.clone()),
+        confirm: Some(true),
+        off_session: Some(true),
+        recurring_details: Some(recurring_details),
+        profile_id: Some(subscription.profile_id.clone()),
+        description: Some(format!(
+            "Subscription {} billing cycle {}",
+            subscription.subscription_id,
+            subscription.billing_cycle.saturating_add(1)
+        )),
+        ..Default::default()
+    };
+
+    let payments_response = Box::pin(payments::payments_core::<
+        api_types::Authorize,
+        payment_types::PaymentsResponse,
+        _,
+        _,
+        _,
+        payments::PaymentData<api_types::Authorize>,
+    >(
+        state.clone(),
+        state.get_req_state(),
+        merchant_account.clone(),
+        Some(subscription.profile_id.clone()),
+        key_store.clone(),
+        payments::PaymentCreate,
+        req,
+        services::AuthFlow::Merchant,
+        payments::CallConnectorAction::Trigger,
+        None,
+        hyperswitch_domain_models::payments::HeaderPayload::default(),
+    ))
+    .await;
+
+    let payments_response = match payments_response {
+        Ok(ApplicationResponse::Json(response))
+        | Ok(ApplicationResponse::JsonWithHeaders((response, _))) => response,
+        // The payment for this billing cycle was created by an earlier run of the task which
+        // could not record its outcome, so its current status is used instead
+        Err(error)
+            if matches!(
+                error.current_context(),
+                errors::ApiErrorResponse::DuplicatePayment { .. }
+            ) =>
+        {
+            retrieve_subscription_payment(state, merchant_account, key_store, &payment_id).await?
+        }
+        Err(error) => {
+            logger::error!(?error, "Failed to create subscription payment");
+            let (error_code, error_message) = match error.current_context() {
+                errors::ApiErrorResponse::ExternalConnectorError { code, message, .. } => {
+                    (Some(code.clone()), Some(message.clone()))
+                }
+                other => (None, Some(other.to_string())),
+            };
+            return Ok(SubscriptionPaymentOutcome::Failed {
+                payment_id: None,
+                connector: None,
+                error_code,
+                error_message,
+            });
+        }
+        Ok(_) => Err(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unexpected response from payments core")?,
+    };
+
+    Ok(match payments_response.status {
+        storage_enums::IntentStatus::Succeeded
+        | storage_enums::IntentStatus::Processing
+        | storage_enums::IntentStatus::RequiresCapture
+        | storage_enums::IntentStatus::PartiallyCaptured
+        | storage_enums::IntentStatus::PartiallyCapturedAndCapturable => {
+            SubscriptionPaymentOutcome::Charged {
+                payment_id: payments_response.payment_id,
+            }
+        }
+        storage_enums::IntentStatus::Failed
+        | storage_enums::IntentStatus::Cancelled
+        | storage_enums::IntentStatus::RequiresCustomerAction
+        | storage_enums::IntentStatus::RequiresMerchantAction
+        | storage_enums::IntentStatus::RequiresPaymentMethod
+        | storage_enums::IntentStatus::RequiresConfirmation => SubscriptionPaymentOutcome::Failed {
+            payment_id: Some(payments_response.payment_id),
+            connector: payments_response.connector,
+            error_code: payments_response.error_code,
+            error_message: payments_response.error_message,
+        },
+    })
+}
+
+async fn retrieve_subscription_payment(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_id: &id_type::PaymentId,
+) -> RouterResult<payment_types::PaymentsResponse> {
+    let req = payment_types::PaymentsRetrieveRequest {
+        resource_id: payment_types::PaymentIdType::PaymentIntentId(payment_id.clone()),
+        merchant_id: Some(merchant_account.get_id().to_owned()),
+        force_sync: false,
+        ..Default::default()
+    };
+
+    match Box::pin(payments::payments_core::<
+        api_types::PSync,
+        payment_types::PaymentsResponse,
+        _,
+        _,
+        _,
+        payments::PaymentData<api_types::PSync>,
+    >(
+        state.clone(),
+        state.get_req_state(),
+        merchant_account.clone(),
+        None,
+        key_store.clone(),
+        payments::PaymentStatus,
+        req,
+        services::AuthFlow::Merchant,
+        payments::CallConnectorAction::Avoid,
+        None,
+        hyperswitch_domain_models::payments::HeaderPayload::default(),
+    ))
+    .await?
+    {
+        ApplicationResponse::Json(response)
+        | ApplicationResponse::JsonWithHeaders((response, _)) => Ok(response),
+        _ => Err(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unexpected response from payments core"),
+    }
+}
+
+/// Moves the subscription to its next billing period once the payment for it has succeeded.
+#[instrument(skip_all)]
+pub async fn renew_subscription(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    subscription: &storage::Subscription,
+    plan: &storage::SubscriptionPlan,
+    payment_id: &id_type::PaymentId,
+) -> RouterResult<storage::Subscription> {
+    // The periods are computed from the billing anchor rather than from the end of the previous
+    // period, so that a day of the month clamped in a shorter month is restored in the next one
+    let interval_count = u32::try_from(plan.interval_count).unwrap_or(1);
+    let paid_periods = u32::try_from(subscription.billing_cycle).unwrap_or_default();
+    let (current_period_start, current_period_end) = get_billing_period(
+        subscription.billing_anchor,
+        plan.billing_interval,
+        interval_count,
+        paid_periods,
+    )
+    .ok_or(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to compute the billing period")?;
+
+    let subscription = state
+        .store
+        .update_subscription_by_merchant_id_subscription_id(
+            &subscription.merchant_id,
+            &subscription.subscription_id,
+            storage::SubscriptionUpdate::RenewalUpdate {
+                current_period_start,
+                current_period_end,
+                billing_cycle: subscription.billing_cycle.saturating_add(1),
+                latest_payment_id: payment_id.get_string_repr().to_owned(),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update subscription")?;
+    trigger_subscription_webhook(
+        state,
+        merchant_account,
+        key_store,
+        &subscription,
+        storage_enums::EventType::SubscriptionRenewed,
+        payment_id.get_string_repr().to_owned(),
+    )
+    .await;
+
+    Ok(subscription)
+}
+
+/// Renews a past due subscription once the failed payment of its billing period has been
+/// recovered, and schedules the billing of the next period.
+#[instrument(skip_all)]
+pub async fn resume_subscription_after_recovery(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    subscription_id: &str,
+    payment_id: &id_type::PaymentId,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let subscription = db
+        .find_subscription_by_merchant_id_subscription_id(
+            merchant_account.get_id(),
+            subscription_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Subscription does not exist in our records".to_owned(),
+        })?;
+
+    if subscription.status != storage_enums::SubscriptionStatus::PastDue {
+        logger::info!(
+            %subscription_id,
+            status = %subscription.status,
+            "Subscription is not past due anymore, not resuming it"
+        );
+        return Ok(());
+    }
+
+    let plan = db
+        .find_subscription_plan_by_merchant_id_plan_id(
+            merchant_account.get_id(),
+            &subscription.plan_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch subscription plan")?;
+    let subscription = renew_subscription(
+        state,
+        merchant_account,
+        key_store,
+        &subscription,
+        &plan,
+        payment_id,
+    )
+    .await?;
+
+    // The billing task was finished when the payment failed, so it is reset for the next period
+    let task_id = generate_task_id_for_subscription_billing_workflow(subscription_id);
+    match db
+        .find_process_by_id(&task_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch subscription billing task")?
+    {
+        Some(process) => {
+            db.as_scheduler()
+                .update_process(
+                    process,
+                    storage::ProcessTrackerUpdate::Update {
+                        name: None,
+                        retry_count: Some(0),
+                        schedule_time: Some(subscription.current_period_end),
+                        tracking_data: None,
+                        business_status: Some(String::from(business_status::PENDING)),
+                        status: Some(storage_enums::ProcessTrackerStatus::New),
+                        updated_at: Some(date_time::now()),
+                    },
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to reset subscription billing task")?;
+            metrics::TASKS_RESET_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &add_attributes([("flow", "SubscriptionBilling")]),
+            );
+        }
+        None => add_subscription_billing_task(db, &subscription).await?,
+    }
+
+    Ok(())
+}
+
+/// Payment IDs are derived from the billing cycle so that a billing period is never charged twice.
+fn get_subscription_payment_id(
+    subscription: &storage::Subscription,
+) -> RouterResult<id_type::PaymentId> {
+    id_type::PaymentId::try_from(std::borrow::Cow::Owned(format!(
+        "{}_{}",
+        subscription.subscription_id,
+        subscription.billing_cycle.saturating_add(1)
+    )))
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct payment ID for subscription")
+}
+
+/// Triggers an outgoing webhook for the subscription. Failures are only logged, as the change to
+/// the subscription has already been persisted.
+#[instrument(skip_all)]
+pub async fn trigger_subscription_webhook(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    subscription: &storage::Subscription,
+    event_type: storage_enums::EventType,
+    primary_object_id: String,
+) {
+    let result = async {
+        let business_profile = state
+            .store
+            .find_business_profile_by_profile_id(&state.into(), key_store, &subscription.profile_id)
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+                id: subscription.profile_id.get_string_repr().to_owned(),
+            })?;
+
+        Box::pin(webhooks::create_event_and_trigger_outgoing_webhook(
+            state.clone(),
+            merchant_account.clone(),
+            business_profile,
+            key_store,
+            event_type,
+            storage_enums::EventClass::Subscriptions,
+            primary_object_id,
+            storage_enums::EventObjectType::SubscriptionDetails,
+            api_models::webhooks::OutgoingWebhookContent::SubscriptionDetails(Box::new(
+                subscription.clone().foreign_into(),
+            )),
+            Some(subscription.created_at),
+        ))
+        .await
+    }
+    .await;
+
+    if let Err(error) = result {
+        logger::error!(
+            ?error,
+            subscription_id = %subscription.subscription_id,
+            ?event_type,
+            "Failed to trigger subscription webhook"
+        );
+    }
+}
+
+async fn add_subscription_billing_task(
+    db: &dyn crate::db::StorageInterface,
+    subscription: &storage::Subscription,
+) -> RouterResult<()> {
+    let task_id = generate_task_id_for_subscription_billing_workflow(&subscription.subscription_id);
+    let tracking_data = storage::SubscriptionBillingTrackingData {
+        merchant_id: subscription.merchant_id.clone(),
+        subscription_id: subscription.subscription_id.clone(),
+    };
+
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        task_id,
+        SUBSCRIPTION_BILLING_NAME,
+        SUBSCRIPTION_BILLING_RUNNER,
+        [SUBSCRIPTION_BILLING_TAG],
+        tracking_data,
+        subscription.current_period_end,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct subscription billing task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert subscription billing task")?;
+
+    metrics::TASKS_ADDED_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &add_attributes([("flow", "SubscriptionBilling")]),
+    );
+
+    Ok(())
+}
+
+async fn revoke_subscription_billing_task(
+    db: &dyn crate::db::StorageInterface,
+    subscription_id: &str,
+) -> RouterResult<()> {
+    let task_id = generate_task_id_for_subscription_billing_workflow(subscription_id);
+    let process = db
+        .find_process_by_id(&task_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch subscription billing task")?;
+
+    match process {
+        Some(process) if process.status != storage_enums::ProcessTrackerStatus::Finish => db
+            .as_scheduler()
+            .finish_process_with_business_status(process, business_status::REVOKED)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to revoke subscription billing task"),
+        _ => Ok(()),
+    }
+}
+
+pub fn generate_task_id_for_subscription_billing_workflow(subscription_id: &str) -> String {
+    format!("{SUBSCRIPTION_BILLING_RUNNER}_{SUBSCRIPTION_BILLING_NAME}_{subscription_id}")
+}
+
+/// Returns the time which lies `interval_count` billing intervals after `from`. When adding
+/// months or years, the day of the month is clamped to the last day of the resulting month, so
+/// that a subscription started on January 31st is billed on February 28th (or 29th).
+pub fn add_billing_interval(
+    from: PrimitiveDateTime,
+    interval: storage_enums::SubscriptionInterval,
+    interval_count: u32,
+) -> Option<PrimitiveDateTime> {
+    match interval {
+        storage_enums::SubscriptionInterval::Day => {
+            from.checked_add(time::Duration::days(i64::from(interval_count)))
+        }
+        storage_enums::SubscriptionInterval::Week => {
+            from.checked_add(time::Duration::weeks(i64::from(interval_count)))
+        }
+        storage_enums::SubscriptionInterval::Month => add_months(from, i64::from(interval_count)),
+        storage_enums::SubscriptionInterval::Year => {
+            add_months(from, i64::from(interval_count).checked_mul(12)?)
+        }
+    }
+}
+
+/// Returns the start and end of the billing period at `period_index`, the first period starting
+/// at the billing anchor. Both are computed from the anchor, so that clamping the day of the month
+/// does not carry over to the following periods.
+pub fn get_billing_period(
+    billing_anchor: PrimitiveDateTime,
+    interval: storage_enums::SubscriptionInterval,
+    interval_count: u32,
+    period_index: u32,
+) -> Option<(PrimitiveDateTime, PrimitiveDateTime)> {
+    let period_start = add_billing_interval(
+        billing_anchor,
+        interval,
+        interval_count.checked_mul(period_index)?,
+    )?;
+    let period_end = add_billing_interval(
+        billing_anchor,
+        interval,
+        interval_count.checked_mul(period_index.checked_add(1)?)?,
+    )?;
+
+    Some((period_start, period_end))
+}
+
+fn add_months(from: PrimitiveDateTime, months: i64) -> Option<PrimitiveDateTime> {
+    let total_months = i64::from(from.year())
+        .checked_mul(12)?
+        .checked_add(i64::from(u8::from(from.month())))?
+        .checked_sub(1)?
+        .checked_add(months)?;
+    let year = i32::try_from(total_months.div_euclid(12)).ok()?;
+    let month = time::Month::try_from(
+        u8::try_from(total_months.rem_euclid(12))
+            .ok()?
+            .checked_add(1)?,
+    )
+    .ok()?;
+    let day = from.day().min(time::util::days_in_year_month(year, month));
+    let date = time::Date::from_calendar_date(year, month, day).ok()?;
+
+    Some(PrimitiveDateTime::new(date, from.time()))
+}
+
+impl ForeignFrom<storage::SubscriptionPlan> for subscription_types::SubscriptionPlanResponse {
+    fn foreign_from(plan: storage::SubscriptionPlan) -> Self {
+        Self {
+            plan_id: plan.plan_id,
+            merchant_id: plan.merchant_id,
+            name: plan.name,
+            description: plan.description,
+            amount: plan.amount,
+            currency: plan.currency,
+            interval: plan.billing_interval,
+            interval_count: u16::try_from(plan.interval_count).unwrap_or_default(),
+            trial_period_days: plan
+                .trial_period_days
+                .and_then(|trial_period_days| u16::try_from(trial_period_days).ok()),
+            is_active: plan.is_active,
+            metadata: plan.metadata,
+            created_at: plan.created_at,
+        }
+    }
+}
+
+impl ForeignFrom<storage::Subscription> for subscription_types::SubscriptionResponse {
+    fn foreign_from(subscription: storage::Subscription) -> Self {
+        Self {
+            subscription_id: subscription.subscription_id,
+            merchant_id: subscription.merchant_id,
+            profile_id: subscription.profile_id,
+            customer_id: subscription.customer_id,
+            plan_id: subscription.plan_id,
+            status: subscription.status,
+            mandate_id: subscription.mandate_id,
+            payment_method_id: subscription.payment_method_id,
+            current_period_start: subscription.current_period_start,
+            current_period_end: subscription.current_period_end,
+            trial_end: subscription.trial_end,
+            billing_cycle: u32::try_from(subscription.billing_cycle).unwrap_or_default(),
+            latest_payment_id: subscription.latest_payment_id,
+            cancel_at_period_end: subscription.cancel_at_period_end,
+            cancelled_at: subscription.cancelled_at,
+            metadata: subscription.metadata,
+            created_at: subscription.created_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn test_add_billing_interval() {
+        let from = datetime!(2024-01-31 10:00);
+
+        assert_eq!(
+            add_billing_interval(from, storage_enums::SubscriptionInterval::Day, 1),
+            Some(datetime!(2024-02-01 10:00))
+        );
+        assert_eq!(
+            add_billing_interval(from, storage_enums::SubscriptionInterval::Week, 2),
+            Some(datetime!(2024-02-14 10:00))
+        );
+        // The day of the month is clamped to the end of shorter months
+        assert_eq!(
+            add_billing_interval(from, storage_enums::SubscriptionInterval::Month, 1),
+            Some(datetime!(2024-02-29 10:00))
+        );
+        assert_eq!(
+            add_billing_interval(from, storage_enums::SubscriptionInterval::Month, 11),
+            Some(datetime!(2024-12-31 10:00))
+        );
+        assert_eq!(
+            add_billing_interval(from, storage_enums::SubscriptionInterval::Month, 13),
+            Some(datetime!(2025-02-28 10:00))
+        );
+        assert_eq!(
+            add_billing_interval(
+                datetime!(2024-02-29 00:00),
+                storage_enums::SubscriptionInterval::Year,
+                1
+            ),
+            Some(datetime!(2025-02-28 00:00))
+        );
+    }
+
+    #[test]
+    fn test_billing_periods_do_not_drift() {
+        let billing_anchor = datetime!(2024-01-31 10:00);
+        let period = |period_index| {
+            get_billing_period(
+                billing_anchor,
+                storage_enums::SubscriptionInterval::Month,
+                1,
+                period_index,
+            )
+        };
+
+        assert_eq!(
+            period(0),
+            Some((datetime!(2024-01-31 10:00), datetime!(2024-02-29 10:00)))
+        );
+        // The day of the month clamped in February is restored in March
+        assert_eq!(
+            period(1),
+            Some((datetime!(2024-02-29 10:00), datetime!(2024-03-31 10:00)))
+        );
+        assert_eq!(
+            period(2),
+            Some((datetime!(2024-03-31 10:00), datetime!(2024-04-30 10:00)))
+        );
+        assert_eq!(
+            get_billing_period(
+                billing_anchor,
+                storage_enums::SubscriptionInterval::Week,
+                2,
+                3
+            ),
+            Some((datetime!(2024-03-13 10:00), datetime!(2024-03-27 10:00)))
+        );
+    }
+}
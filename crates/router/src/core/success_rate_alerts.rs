@@ -0,0 +1,792 @@
+use std::collections::{HashMap, HashSet};
+
+use analytics::enums::AuthInfo;
+use api_models::analytics::{
+    self as analytics_types,
+    payments::{MetricsBucketResponse, PaymentDimensions, PaymentMetrics},
+    success_rate_alerts as alert_types,
+};
+use common_utils::{date_time, id_type, types::TimeRange};
+use diesel_models::{
+    enums::{self as storage_enums, ProcessTrackerStatus},
+    process_tracker::business_status,
+};
+use error_stack::{report, ResultExt};
+use num_traits::ToPrimitive;
+use router_env::{instrument, logger, metrics::add_attributes, tracing};
+use time::PrimitiveDateTime;
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        webhooks,
+    },
+    routes::{app::SessionStateInfo, metrics, SessionState},
+    services::ApplicationResponse,
+    types::{api, domain, storage},
+};
+
+const SUCCESS_RATE_ALERT_TAG: &str = "SUCCESS_RATE_ALERT";
+const SUCCESS_RATE_ALERT_NAME: &str = "SUCCESS_RATE_ALERT";
+const SUCCESS_RATE_ALERT_RUNNER: diesel_models::ProcessTrackerRunner =
+    diesel_models::ProcessTrackerRunner::SuccessRateAlertWorkflow;
+
+#[instrument(skip_all)]
+pub async fn retrieve_success_rate_alert_config(
+    state: SessionState,
+    key_store: domain::MerchantKeyStore,
+    merchant_id: &id_type::MerchantId,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<alert_types::SuccessRateAlertConfigResponse> {
+    validate_profile(&state, &key_store, merchant_id, &profile_id).await?;
+    let config = get_success_rate_alert_config(&state, &profile_id)
+        .await?
+        .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Success rate alerts are not configured for the profile".to_owned(),
+        })?;
+
+    Ok(ApplicationResponse::Json(
+        alert_types::SuccessRateAlertConfigResponse { profile_id, config },
+    ))
+}
+
+/// Updates the alert rules of the profile. The evaluation of the rules is scheduled afresh, so
+/// alerts which were active under the previous rules are raised again if the drop persists.
+#[instrument(skip_all)]
+pub async fn update_success_rate_alert_config(
+    state: SessionState,
+    key_store: domain::MerchantKeyStore,
+    merchant_id: &id_type::MerchantId,
+    profile_id: id_type::ProfileId,
+    config: alert_types::SuccessRateAlertConfig,
+) -> RouterResponse<alert_types::SuccessRateAlertConfigResponse> {
+    validate_profile(&state, &key_store, merchant_id, &profile_id).await?;
+    validate_config(&config)?;
+
+    let db = state.store.as_ref();
+    let key = profile_id.get_success_rate_alert_config_key();
+    let value = serde_json::to_string(&config)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize success rate alert config")?;
+    match db.find_config_by_key(&key).await {
+        Ok(_) => {
+            db.update_config_by_key(
+                &key,
+                storage::ConfigUpdate::Update {
+                    config: Some(value),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update success rate alert config")?;
+        }
+        Err(error) if error.current_context().is_db_not_found() => {
+            db.insert_config(storage::ConfigNew { key, config: value })
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to insert success rate alert config")?;
+        }
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch success rate alert config")?,
+    }
+
+    if config.enabled {
+        schedule_success_rate_alert_task(&state, merchant_id, &profile_id, &config).await?;
+    } else {
+        revoke_success_rate_alert_task(&state, &profile_id).await?;
+    }
+
+    Ok(ApplicationResponse::Json(
+        alert_types::SuccessRateAlertConfigResponse { profile_id, config },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn delete_success_rate_alert_config(
+    state: SessionState,
+    key_store: domain::MerchantKeyStore,
+    merchant_id: &id_type::MerchantId,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<alert_types::SuccessRateAlertConfigDeleteResponse> {
+    validate_profile(&state, &key_store, merchant_id, &profile_id).await?;
+
+    state
+        .store
+        .delete_config_by_key(&profile_id.get_success_rate_alert_config_key())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Success rate alerts are not configured for the profile".to_owned(),
+        })?;
+    revoke_success_rate_alert_task(&state, &profile_id).await?;
+
+    Ok(ApplicationResponse::Json(
+        alert_types::SuccessRateAlertConfigDeleteResponse {
+            profile_id,
+            deleted: true,
+        },
+    ))
+}
+
+/// Returns the alert rules of the profile, or `None` if alerts have not been configured for the
+/// profile.
+pub async fn get_success_rate_alert_config(
+    state: &SessionState,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<Option<alert_types::SuccessRateAlertConfig>> {
+    use common_utils::ext_traits::StringExt;
+
+    match state
+        .store
+        .find_config_by_key(&profile_id.get_success_rate_alert_config_key())
+        .await
+    {
+        Ok(config) => config
+            .config
+            .parse_struct("SuccessRateAlertConfig")
+            .map(Some)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse success rate alert config"),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch success rate alert config"),
+    }
+}
+
+async fn validate_profile(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    merchant_id: &id_type::MerchantId,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<()> {
+    state
+        .store
+        .find_business_profile_by_merchant_id_profile_id(
+            &state.into(),
+            key_store,
+            merchant_id,
+            profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })
+        .map(|_| ())
+}
+
+fn validate_config(config: &alert_types::SuccessRateAlertConfig) -> RouterResult<()> {
+    let invalid = |message: &str| {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: message.to_owned(),
+        }))
+    };
+
+    if config.metrics.is_empty() {
+        return invalid("At least one metric is required");
+    }
+    if config.channels.is_empty() {
+        return invalid("At least one channel is required");
+    }
+    if !(5..=1440).contains(&config.evaluation_window_minutes) {
+        return invalid("`evaluation_window_minutes` must be between 5 and 1440");
+    }
+    if !(1..=90).contains(&config.baseline_window_days) {
+        return invalid("`baseline_window_days` must be between 1 and 90");
+    }
+    if !(config.min_drop_percentage > 0.0 && config.min_drop_percentage <= 100.0) {
+        return invalid("`min_drop_percentage` must be greater than 0 and at most 100");
+    }
+    if !(config.min_z_score >= 0.0 && config.min_z_score.is_finite()) {
+        return invalid("`min_z_score` must not be negative");
+    }
+    if config
+        .channels
+        .contains(&alert_types::SuccessRateAlertChannel::Email)
+        && config.recipients.is_empty()
+    {
+        return invalid("At least one recipient is required for alerts delivered by email");
+    }
+
+    Ok(())
+}
+
+/// The success rate of a segment of payments over a window.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct SuccessRateSample {
+    /// The success rate, as a percentage
+    success_rate: f64,
+    payment_count: u64,
+}
+
+/// Returns the z-score of the drop of the success rate from the baseline sample to the current
+/// sample, computed with a two-proportion z-test. The score is positive when the success rate has
+/// dropped.
+fn get_drop_z_score(baseline: SuccessRateSample, current: SuccessRateSample) -> Option<f64> {
+    let baseline_count = baseline.payment_count.to_f64()?;
+    let current_count = current.payment_count.to_f64()?;
+    if baseline_count <= 0.0 || current_count <= 0.0 {
+        return None;
+    }
+
+    let baseline_rate = baseline.success_rate / 100.0;
+    let current_rate = current.success_rate / 100.0;
+    let pooled_rate = (baseline_rate * baseline_count + current_rate * current_count)
+        / (baseline_count + current_count);
+    let standard_error =
+        (pooled_rate * (1.0 - pooled_rate) * (1.0 / baseline_count + 1.0 / current_count)).sqrt();
+
+    // All payments in both windows either succeeded or failed, so the rates are equal
+    if standard_error <= 0.0 {
+        return Some(0.0);
+    }
+
+    Some((baseline_rate - current_rate) / standard_error)
+}
+
+/// Returns the z-score of the drop if the current success rate is significantly below its
+/// baseline under the alert rules.
+fn get_significant_drop(
+    config: &alert_types::SuccessRateAlertConfig,
+    baseline: SuccessRateSample,
+    current: SuccessRateSample,
+) -> Option<f64> {
+    if current.payment_count < config.min_payment_count
+        || baseline.payment_count < config.min_payment_count
+    {
+        return None;
+    }
+    if baseline.success_rate - current.success_rate < config.min_drop_percentage {
+        return None;
+    }
+
+    get_drop_z_score(baseline, current).filter(|z_score| *z_score >= config.min_z_score)
+}
+
+fn get_payment_dimension(dimension: alert_types::SuccessRateAlertDimension) -> PaymentDimensions {
+    match dimension {
+        alert_types::SuccessRateAlertDimension::Connector => PaymentDimensions::Connector,
+        alert_types::SuccessRateAlertDimension::PaymentMethod => PaymentDimensions::PaymentMethod,
+        alert_types::SuccessRateAlertDimension::PaymentMethodType => {
+            PaymentDimensions::PaymentMethodType
+        }
+        alert_types::SuccessRateAlertDimension::CardNetwork => PaymentDimensions::CardNetwork,
+    }
+}
+
+fn get_payment_metric(metric: alert_types::SuccessRateAlertMetric) -> PaymentMetrics {
+    match metric {
+        alert_types::SuccessRateAlertMetric::SuccessRate => PaymentMetrics::PaymentSuccessRate,
+        alert_types::SuccessRateAlertMetric::ConnectorSuccessRate => {
+            PaymentMetrics::ConnectorSuccessRate
+        }
+    }
+}
+
+/// Loads the success rate of every segment of the configured dimensions over the time range.
+///
+/// The connector success rate only considers payment attempts which reached a connector, so it is
+/// always broken down by connector for the payment count of a segment to be its sample size.
+async fn get_success_rate_samples(
+    state: &SessionState,
+    auth: &AuthInfo,
+    config: &alert_types::SuccessRateAlertConfig,
+    metric: alert_types::SuccessRateAlertMetric,
+    time_range: TimeRange,
+) -> RouterResult<HashMap<alert_types::SuccessRateAlertSegment, SuccessRateSample>> {
+    let mut dimensions = config.dimensions.clone();
+    if metric == alert_types::SuccessRateAlertMetric::ConnectorSuccessRate
+        && !dimensions.contains(&alert_types::SuccessRateAlertDimension::Connector)
+    {
+        dimensions.push(alert_types::SuccessRateAlertDimension::Connector);
+    }
+
+    let request = analytics_types::GetPaymentMetricRequest {
+        time_series: None,
+        time_range,
+        group_by_names: dimensions
+            .iter()
+            .copied()
+            .map(get_payment_dimension)
+            .collect(),
+        filters: Default::default(),
+        metrics: HashSet::from([get_payment_metric(metric), PaymentMetrics::PaymentCount]),
+        distribution: None,
+        delta: false,
+    };
+    let response = analytics::payments::get_metrics(&state.pool, auth, request)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to compute the success rate of payments")?;
+
+    Ok(response
+        .query_data
+        .into_iter()
+        .filter_map(|bucket| {
+            let MetricsBucketResponse { values, dimensions } = bucket;
+            let success_rate = match metric {
+                alert_types::SuccessRateAlertMetric::SuccessRate => values.payment_success_rate,
+                alert_types::SuccessRateAlertMetric::ConnectorSuccessRate => {
+                    values.connector_success_rate
+                }
+            }?;
+            let segment = alert_types::SuccessRateAlertSegment {
+                connector: dimensions.connector,
+                payment_method: dimensions.payment_method,
+                payment_method_type: dimensions.payment_method_type,
+                card_network: dimensions.card_network,
+            };
+
+            Some((
+                segment,
+                SuccessRateSample {
+                    success_rate,
+                    payment_count: values.payment_count.unwrap_or_default(),
+                },
+            ))
+        })
+        .collect())
+}
+
+fn get_alert_id(
+    profile_id: &id_type::ProfileId,
+    metric: alert_types::SuccessRateAlertMetric,
+    segment: &alert_types::SuccessRateAlertSegment,
+) -> String {
+    let segment = [
+        &segment.connector,
+        &segment.payment_method,
+        &segment.payment_method_type,
+        &segment.card_network,
+    ]
+    .into_iter()
+    .map(|value| value.as_deref().unwrap_or("*"))
+    .collect::<Vec<_>>()
+    .join("_");
+
+    format!("{}_{metric}_{segment}", profile_id.get_string_repr())
+}
+
+/// Evaluates the alert rules of the profile over the evaluation window ending now, and raises an
+/// alert for every segment whose success rate dropped significantly below its baseline, or
+/// recovered from such a drop. Returns the identifiers of the alerts active after the evaluation.
+#[instrument(skip_all)]
+pub async fn evaluate_success_rate_alerts(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    business_profile: &domain::Profile,
+    config: &alert_types::SuccessRateAlertConfig,
+    active_alerts: Vec<String>,
+) -> RouterResult<Vec<String>> {
+    let profile_id = business_profile.get_id();
+    let auth = AuthInfo::ProfileLevel {
+        org_id: merchant_account.get_org_id().to_owned(),
+        merchant_id: merchant_account.get_id().to_owned(),
+        profile_ids: vec![profile_id.to_owned()],
+    };
+
+    let now = date_time::now();
+    let window_start = now.saturating_sub(time::Duration::minutes(i64::from(
+        config.evaluation_window_minutes,
+    )));
+    let baseline_start =
+        window_start.saturating_sub(time::Duration::days(i64::from(config.baseline_window_days)));
+    let window = TimeRange {
+        start_time: window_start,
+        end_time: Some(now),
+    };
+    let baseline_window = TimeRange {
+        start_time: baseline_start,
+        end_time: Some(window_start),
+    };
+
+    let mut active_alerts = active_alerts.into_iter().collect::<HashSet<_>>();
+    for metric in config.metrics.iter().copied() {
+        let current_samples =
+            get_success_rate_samples(state, &auth, config, metric, window).await?;
+        let baseline_samples =
+            get_success_rate_samples(state, &auth, config, metric, baseline_window).await?;
+
+        for (segment, current) in current_samples {
+            let Some(baseline) = baseline_samples.get(&segment).copied() else {
+                continue;
+            };
+            let alert_id = get_alert_id(profile_id, metric, &segment);
+            let z_score = get_significant_drop(config, baseline, current);
+            let status = match (z_score.is_some(), active_alerts.contains(&alert_id)) {
+                (true, false) => {
+                    active_alerts.insert(alert_id.clone());
+                    alert_types::SuccessRateAlertStatus::Triggered
+                }
+                // A segment without enough payments in the window is not considered to have
+                // recovered
+                (false, true) if current.payment_count >= config.min_payment_count => {
+                    active_alerts.remove(&alert_id);
+                    alert_types::SuccessRateAlertStatus::Resolved
+                }
+                _ => continue,
+            };
+
+            let alert = alert_types::SuccessRateAlert {
+                alert_id,
+                merchant_id: merchant_account.get_id().to_owned(),
+                profile_id: profile_id.to_owned(),
+                status,
+                metric,
+                segment,
+                success_rate: current.success_rate,
+                baseline_success_rate: baseline.success_rate,
+                payment_count: current.payment_count,
+                baseline_payment_count: baseline.payment_count,
+                z_score: z_score
+                    .or_else(|| get_drop_z_score(baseline, current))
+                    .unwrap_or_default(),
+                window_start,
+                window_end: now,
+                created_at: now,
+            };
+            raise_alert(
+                state,
+                merchant_account,
+                key_store,
+                business_profile,
+                config,
+                alert,
+            )
+            .await;
+        }
+    }
+
+    Ok(active_alerts.into_iter().collect())
+}
+
+/// Delivers the alert through the channels of the alert rules. Failures in delivering the alert
+/// are only logged, so that the alert is not raised again through the other channels.
+async fn raise_alert(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    business_profile: &domain::Profile,
+    config: &alert_types::SuccessRateAlertConfig,
+    alert: alert_types::SuccessRateAlert,
+) {
+    logger::info!(
+        alert_id = %alert.alert_id,
+        status = %alert.status,
+        success_rate = alert.success_rate,
+        baseline_success_rate = alert.baseline_success_rate,
+        "Raising success rate alert"
+    );
+    metrics::SUCCESS_RATE_ALERTS_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &add_attributes([
+            ("metric", alert.metric.to_string()),
+            ("status", alert.status.to_string()),
+        ]),
+    );
+
+    for channel in config.channels.iter() {
+        match channel {
+            alert_types::SuccessRateAlertChannel::OutgoingWebhook => {
+                let event_type = match alert.status {
+                    alert_types::SuccessRateAlertStatus::Triggered => {
+                        storage_enums::EventType::PaymentSuccessRateDropped
+                    }
+                    alert_types::SuccessRateAlertStatus::Resolved => {
+                        storage_enums::EventType::PaymentSuccessRateRecovered
+                    }
+                };
+                let result = Box::pin(webhooks::create_event_and_trigger_outgoing_webhook(
+                    state.clone(),
+                    merchant_account.clone(),
+                    business_profile.clone(),
+                    key_store,
+                    event_type,
+                    storage_enums::EventClass::Alerts,
+                    format!(
+                        "{}_{}",
+                        alert.alert_id,
+                        alert.created_at.assume_utc().unix_timestamp()
+                    ),
+                    storage_enums::EventObjectType::SuccessRateAlertDetails,
+                    api::OutgoingWebhookContent::SuccessRateAlertDetails(Box::new(alert.clone())),
+                    Some(alert.created_at),
+                ))
+                .await;
+
+                if let Err(error) = result {
+                    logger::error!(
+                        ?error,
+                        alert_id = %alert.alert_id,
+                        "Failed to trigger success rate alert webhook"
+                    );
+                }
+            }
+            alert_types::SuccessRateAlertChannel::Email => {
+                send_alert_email(state, config, &alert).await
+            }
+            alert_types::SuccessRateAlertChannel::Kafka => state.event_handler().log_event(&alert),
+        }
+    }
+}
+
+#[cfg(feature = "email")]
+async fn send_alert_email(
+    state: &SessionState,
+    config: &alert_types::SuccessRateAlertConfig,
+    alert: &alert_types::SuccessRateAlert,
+) {
+    use crate::{consts, services::email::types::SuccessRateAlert};
+
+    let subject = match alert.status {
+        alert_types::SuccessRateAlertStatus::Triggered => {
+            consts::EMAIL_SUBJECT_SUCCESS_RATE_DROPPED
+        }
+        alert_types::SuccessRateAlertStatus::Resolved => {
+            consts::EMAIL_SUBJECT_SUCCESS_RATE_RECOVERED
+        }
+    };
+
+    for recipient in config.recipients.iter() {
+        let result = async {
+            let email_contents = SuccessRateAlert {
+                recipient_email: domain::UserEmail::from_pii_email(recipient.clone())
+                    .change_context(errors::ApiErrorResponse::InternalServerError)?,
+                subject,
+                alert: alert.clone(),
+            };
+
+            state
+                .email_client
+                .clone()
+                .compose_and_send_email(
+                    Box::new(email_contents),
+                    state.conf.proxy.https_url.as_ref(),
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+        }
+        .await;
+
+        if let Err(error) = result {
+            logger::error!(
+                ?error,
+                alert_id = %alert.alert_id,
+                "Failed to send success rate alert email"
+            );
+        }
+    }
+}
+
+#[cfg(not(feature = "email"))]
+async fn send_alert_email(
+    _state: &SessionState,
+    _config: &alert_types::SuccessRateAlertConfig,
+    alert: &alert_types::SuccessRateAlert,
+) {
+    logger::warn!(
+        alert_id = %alert.alert_id,
+        "Email is not enabled, skipping success rate alert email"
+    );
+}
+
+/// Schedules the evaluation of the alert rules of the profile at the end of the first evaluation
+/// window. The task of the profile is reused if alerts were configured for the profile before.
+async fn schedule_success_rate_alert_task(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    profile_id: &id_type::ProfileId,
+    config: &alert_types::SuccessRateAlertConfig,
+) -> RouterResult<()> {
+    let db = state.store.as_ref();
+    let task_id = generate_task_id_for_success_rate_alert_workflow(profile_id);
+    let schedule_time = get_next_evaluation_time(config, date_time::now());
+    let existing_process = db
+        .find_process_by_id(&task_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch success rate alert task")?;
+    let tracking_data = storage::SuccessRateAlertTrackingData {
+        merchant_id: merchant_id.to_owned(),
+        profile_id: profile_id.to_owned(),
+        active_alerts: Vec::new(),
+    };
+
+    match existing_process {
+        Some(process) => {
+            let tracking_data = serde_json::to_value(&tracking_data)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to serialize success rate alert tracking data")?;
+            db.as_scheduler()
+                .update_process(
+                    process,
+                    storage::ProcessTrackerUpdate::Update {
+                        name: None,
+                        retry_count: Some(0),
+                        schedule_time: Some(schedule_time),
+                        tracking_data: Some(tracking_data),
+                        business_status: Some(String::from(business_status::PENDING)),
+                        status: Some(ProcessTrackerStatus::New),
+                        updated_at: Some(date_time::now()),
+                    },
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to reschedule success rate alert task")?;
+        }
+        None => {
+            let process_tracker_entry = storage::ProcessTrackerNew::new(
+                task_id,
+                SUCCESS_RATE_ALERT_NAME,
+                SUCCESS_RATE_ALERT_RUNNER,
+                [SUCCESS_RATE_ALERT_TAG],
+                tracking_data,
+                schedule_time,
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to construct success rate alert task")?;
+
+            db.insert_process(process_tracker_entry)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to insert success rate alert task")?;
+            metrics::TASKS_ADDED_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &add_attributes([("flow", "SuccessRateAlert")]),
+            );
+        }
+    }
+
+    Ok(())
+}
+
+async fn revoke_success_rate_alert_task(
+    state: &SessionState,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<()> {
+    let db = state.store.as_ref();
+    let process = db
+        .find_process_by_id(&generate_task_id_for_success_rate_alert_workflow(
+            profile_id,
+        ))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch success rate alert task")?;
+
+    if let Some(process) = process.filter(|process| process.status != ProcessTrackerStatus::Finish)
+    {
+        db.as_scheduler()
+            .finish_process_with_business_status(process, business_status::REVOKED)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to revoke success rate alert task")?;
+    }
+
+    Ok(())
+}
+
+/// Reschedules the task of the profile at the end of the next evaluation window, along with the
+/// alerts active after the evaluation.
+pub async fn reschedule_success_rate_alert_task(
+    state: &SessionState,
+    process: storage::ProcessTracker,
+    config: &alert_types::SuccessRateAlertConfig,
+    tracking_data: storage::SuccessRateAlertTrackingData,
+) -> Result<(), errors::ProcessTrackerError> {
+    let now = date_time::now();
+    let tracking_data = serde_json::to_value(&tracking_data)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize success rate alert tracking data")?;
+
+    state
+        .store
+        .as_scheduler()
+        .update_process(
+            process,
+            storage::ProcessTrackerUpdate::Update {
+                name: None,
+                retry_count: Some(0),
+                schedule_time: Some(get_next_evaluation_time(config, now)),
+                tracking_data: Some(tracking_data),
+                business_status: None,
+                status: Some(ProcessTrackerStatus::New),
+                updated_at: Some(now),
+            },
+        )
+        .await?;
+    metrics::TASKS_RESET_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &add_attributes([("flow", "SuccessRateAlert")]),
+    );
+
+    Ok(())
+}
+
+fn get_next_evaluation_time(
+    config: &alert_types::SuccessRateAlertConfig,
+    now: PrimitiveDateTime,
+) -> PrimitiveDateTime {
+    now.saturating_add(time::Duration::minutes(i64::from(
+        config.evaluation_window_minutes,
+    )))
+}
+
+pub fn generate_task_id_for_success_rate_alert_workflow(profile_id: &id_type::ProfileId) -> String {
+    format!(
+        "{SUCCESS_RATE_ALERT_RUNNER}_{SUCCESS_RATE_ALERT_NAME}_{}",
+        profile_id.get_string_repr()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(success_rate: f64, payment_count: u64) -> SuccessRateSample {
+        SuccessRateSample {
+            success_rate,
+            payment_count,
+        }
+    }
+
+    #[test]
+    fn test_significant_drop() {
+        let config = alert_types::SuccessRateAlertConfig::default();
+
+        // A drop of 20 percentage points over a large number of payments is significant
+        let z_score = get_significant_drop(&config, sample(90.0, 10_000), sample(70.0, 500));
+        assert!(z_score.is_some_and(|z_score| z_score > config.min_z_score));
+
+        // The same drop over too few payments is not evaluated
+        assert_eq!(
+            get_significant_drop(&config, sample(90.0, 10_000), sample(70.0, 20)),
+            None
+        );
+
+        // A drop below the minimum drop percentage is ignored even if statistically significant
+        assert_eq!(
+            get_significant_drop(&config, sample(90.0, 100_000), sample(85.0, 50_000)),
+            None
+        );
+
+        // A drop explained by the volume of payments is not significant
+        let config = alert_types::SuccessRateAlertConfig {
+            min_payment_count: 1,
+            ..config
+        };
+        assert_eq!(
+            get_significant_drop(&config, sample(60.0, 10), sample(40.0, 10)),
+            None
+        );
+
+        // An increase of the success rate is never a drop
+        assert_eq!(
+            get_significant_drop(&config, sample(70.0, 10_000), sample(90.0, 500)),
+            None
+        );
+    }
+}
@@ -36,6 +36,7 @@ use crate::{
     core::{
         errors::{self, RouterResult, StorageErrorExt},
         payments::PaymentData,
+        split_payments,
     },
     db::StorageInterface,
     routes::SessionState,
@@ -216,6 +217,7 @@ pub async fn construct_payout_router_data<'a, F>(
         connector_response: None,
         integrity_check: Ok(()),
         additional_merchant_data: None,
+        merchant_connector_id: merchant_connector_account.get_mca_id(),
         header_payload: None,
     };
 
@@ -327,6 +329,19 @@ pub async fn construct_refund_router_data<'a, F>(
 
     let connector_refund_id = refund.get_optional_connector_refund_id().cloned();
 
+    let payment_splits = split_payments::find_payment_splits(
+        &*state.store,
+        merchant_account.get_id(),
+        &payment_attempt.payment_id,
+    )
+    .await?;
+    let split_refunds = split_payments::get_split_refunds_data(
+        connector_id,
+        &payment_splits,
+        payment_amount,
+        refund.refund_amount,
+    )?;
+
     let router_data = types::RouterData {
         flow: PhantomData,
         merchant_id: merchant_account.get_id().clone(),
@@ -363,6 +378,7 @@ pub async fn construct_refund_router_data<'a, F>(
             connector_refund_id: connector_refund_id.clone(),
             browser_info,
             charges,
+            split_refunds,
             integrity_object: None,
         },
 
@@ -394,6 +410,7 @@ pub async fn construct_refund_router_data<'a, F>(
         connector_response: None,
         integrity_check: Ok(()),
         additional_merchant_data: None,
+        merchant_connector_id: merchant_connector_account.get_mca_id(),
         header_payload: None,
     };
 
@@ -704,6 +721,7 @@ pub async fn construct_accept_dispute_router_data<'a>(
         connector_response: None,
         integrity_check: Ok(()),
         additional_merchant_data: None,
+        merchant_connector_id: merchant_connector_account.get_mca_id(),
         header_payload: None,
     };
     Ok(router_data)
@@ -799,6 +817,7 @@ pub async fn construct_submit_evidence_router_data<'a>(
         connector_response: None,
         integrity_check: Ok(()),
         additional_merchant_data: None,
+        merchant_connector_id: merchant_connector_account.get_mca_id(),
         header_payload: None,
     };
     Ok(router_data)
@@ -900,6 +919,7 @@ pub async fn construct_upload_file_router_data<'a>(
         connector_response: None,
         integrity_check: Ok(()),
         additional_merchant_data: None,
+        merchant_connector_id: merchant_connector_account.get_mca_id(),
         header_payload: None,
     };
     Ok(router_data)
@@ -1021,6 +1041,7 @@ pub async fn construct_payments_dynamic_tax_calculation_router_data<'a, F: Clone
         minor_amount_captured: None,
         integrity_check: Ok(()),
         additional_merchant_data: None,
+        merchant_connector_id: Some(merchant_connector_account.get_id()),
         header_payload: None,
     };
     Ok(router_data)
@@ -1119,6 +1140,7 @@ pub async fn construct_defend_dispute_router_data<'a>(
         connector_response: None,
         integrity_check: Ok(()),
         additional_merchant_data: None,
+        merchant_connector_id: merchant_connector_account.get_mca_id(),
         header_payload: None,
     };
     Ok(router_data)
@@ -1211,6 +1233,7 @@ pub async fn construct_retrieve_file_router_data<'a>(
         connector_response: None,
         integrity_check: Ok(()),
         additional_merchant_data: None,
+        merchant_connector_id: merchant_connector_account.get_mca_id(),
         header_payload: None,
     };
     Ok(router_data)
@@ -1,11 +1,18 @@
+mod endpoint_health;
+mod event_ordering;
 mod incoming;
+#[cfg(feature = "olap")]
+pub mod incoming_webhook_archive;
 mod outgoing;
 pub mod types;
 pub mod utils;
 #[cfg(feature = "olap")]
+pub mod webhook_endpoints;
+#[cfg(feature = "olap")]
 pub mod webhook_events;
 
 pub(crate) use self::{
+    event_ordering::{get_pending_earlier_events, hold_back_event_delivery},
     incoming::incoming_webhooks_wrapper,
     outgoing::{
         create_event_and_trigger_outgoing_webhook, get_outgoing_webhook_request,
@@ -0,0 +1,144 @@
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::{
+        errors::{self, CustomResult},
+        metrics,
+    },
+    routes::SessionState,
+    types::{domain, storage},
+};
+
+/// Records the outcome of a webhook delivery attempt to a webhook endpoint. The endpoint is
+/// suspended once the number of consecutive failed deliveries stored for the endpoint reaches the
+/// configured threshold, and the merchant is notified about the suspension.
+#[instrument(skip_all, fields(endpoint_id = %webhook_endpoint.endpoint_id))]
+pub(super) async fn record_delivery_outcome(
+    state: &SessionState,
+    merchant_key_store: &domain::MerchantKeyStore,
+    webhook_endpoint: storage::WebhookEndpoint,
+    is_delivered: bool,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    let now = common_utils::date_time::now();
+    let webhook_endpoint = if is_delivered {
+        state
+            .store
+            .update_webhook_endpoint_by_merchant_id_endpoint_id(
+                &webhook_endpoint.merchant_id,
+                &webhook_endpoint.endpoint_id,
+                storage::WebhookEndpointUpdate::DeliverySucceeded { delivered_at: now },
+            )
+            .await
+    } else {
+        // The failure count is incremented in the database rather than computed from the endpoint
+        // read at the start of the delivery, as concurrent deliveries to the same endpoint would
+        // otherwise overwrite each other's failures.
+        state
+            .store
+            .record_webhook_endpoint_delivery_failure(
+                &webhook_endpoint.merchant_id,
+                &webhook_endpoint.endpoint_id,
+                now,
+            )
+            .await
+    }
+    .change_context(errors::WebhooksFlowError::WebhookEndpointUpdationFailed)
+    .attach_printable("Failed to record webhook delivery outcome for webhook endpoint")?;
+
+    let failure_threshold = state.conf.webhooks.endpoint_suspension_failure_threshold;
+    let should_suspend = failure_threshold > 0
+        && webhook_endpoint.is_enabled
+        && u32::try_from(webhook_endpoint.consecutive_failures)
+            .is_ok_and(|consecutive_failures| consecutive_failures >= failure_threshold);
+    if !should_suspend {
+        return Ok(());
+    }
+
+    let webhook_endpoint = state
+        .store
+        .update_webhook_endpoint_by_merchant_id_endpoint_id(
+            &webhook_endpoint.merchant_id,
+            &webhook_endpoint.endpoint_id,
+            storage::WebhookEndpointUpdate::Suspend { suspended_at: now },
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::WebhookEndpointUpdationFailed)
+        .attach_printable("Failed to suspend webhook endpoint")?;
+
+    logger::info!(
+        consecutive_failures = webhook_endpoint.consecutive_failures,
+        "Suspended webhook endpoint after repeated delivery failures"
+    );
+    metrics::WEBHOOK_ENDPOINT_SUSPENDED_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &[metrics::KeyValue::new(
+            super::MERCHANT_ID,
+            webhook_endpoint.merchant_id.get_string_repr().to_owned(),
+        )],
+    );
+
+    #[cfg(feature = "email")]
+    send_suspension_email(state, merchant_key_store, &webhook_endpoint).await;
+    #[cfg(not(feature = "email"))]
+    let _ = merchant_key_store;
+
+    Ok(())
+}
+
+/// Failures in sending the email are only logged, as the suspension is visible in the health of
+/// the webhook endpoint as well.
+#[cfg(feature = "email")]
+async fn send_suspension_email(
+    state: &SessionState,
+    merchant_key_store: &domain::MerchantKeyStore,
+    webhook_endpoint: &storage::WebhookEndpoint,
+) {
+    use crate::{
+        consts, services::email::types::WebhookEndpointSuspended, types::api, utils::OptionExt,
+    };
+
+    let result = async {
+        let merchant_account = state
+            .store
+            .find_merchant_account_by_merchant_id(
+                &state.into(),
+                &webhook_endpoint.merchant_id,
+                merchant_key_store,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+        let email_id = merchant_account
+            .merchant_details
+            .parse_value::<api::MerchantDetails>("MerchantDetails")
+            .change_context(errors::ApiErrorResponse::InternalServerError)?
+            .primary_email
+            .ok_or(errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "primary_email",
+            })?;
+        let email_contents = WebhookEndpointSuspended {
+            recipient_email: domain::UserEmail::from_pii_email(email_id)
+                .change_context(errors::ApiErrorResponse::InternalServerError)?,
+            subject: consts::EMAIL_SUBJECT_WEBHOOK_ENDPOINT_SUSPENDED,
+            endpoint_id: webhook_endpoint.endpoint_id.clone(),
+            url: webhook_endpoint.url.clone(),
+            consecutive_failures: webhook_endpoint.consecutive_failures,
+        };
+
+        state
+            .email_client
+            .clone()
+            .compose_and_send_email(
+                Box::new(email_contents),
+                state.conf.proxy.https_url.as_ref(),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+    }
+    .await;
+
+    if let Err(error) = result {
+        logger::error!(?error, "Failed to send webhook endpoint suspension email");
+    }
+}
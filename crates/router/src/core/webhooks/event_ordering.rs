@@ -0,0 +1,592 @@
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+
+use super::outgoing::get_outgoing_webhook_retry_process_tracker_id;
+use crate::{
+    core::errors::{self, CustomResult},
+    routes::SessionState,
+    types::{domain, storage, storage::enums},
+};
+
+const SEQUENCE_NUMBER_KEY_PREFIX: &str = "WEBHOOK_EVENT_SEQUENCE";
+const SEQUENCE_NUMBER_FIELD: &str = "sequence_number";
+/// The sequence number counter of an object is dropped after a week without events for the
+/// object, and seeded again from the events table when the object has another event.
+const SEQUENCE_NUMBER_TTL_SECS: i64 = 7 * 24 * 60 * 60;
+
+/// Obtains the next sequence number for the events of the specified object. Sequence numbers start
+/// from one and increase monotonically across all events of the object.
+#[instrument(skip_all)]
+pub(super) async fn get_next_sequence_number(
+    state: &SessionState,
+    merchant_key_store: &domain::MerchantKeyStore,
+    primary_object_id: &str,
+) -> CustomResult<i64, errors::WebhooksFlowError> {
+    let merchant_id = &merchant_key_store.merchant_id;
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::WebhooksFlowError::EventSequenceNumberGenerationFailed)
+        .attach_printable("Failed to get redis connection")?;
+    let key = format!(
+        "{SEQUENCE_NUMBER_KEY_PREFIX}_{}_{primary_object_id}",
+        merchant_id.get_string_repr()
+    );
+
+    let is_counter_present = redis_conn
+        .exists::<Vec<u8>>(&key)
+        .await
+        .change_context(errors::WebhooksFlowError::EventSequenceNumberGenerationFailed)?;
+    if !is_counter_present {
+        // Continue from the sequence numbers already assigned to the events of the object
+        let last_sequence_number = state
+            .store
+            .list_initial_events_by_merchant_id_primary_object_id(
+                &state.into(),
+                merchant_id,
+                primary_object_id,
+                merchant_key_store,
+            )
+            .await
+            .change_context(errors::WebhooksFlowError::EventSequenceNumberGenerationFailed)
+            .attach_printable("Failed to list events for object")?
+            .into_iter()
+            .filter_map(|event| event.sequence_number)
+            .max()
+            .unwrap_or(0);
+
+        redis_conn
+            .set_hash_field_if_not_exist(&key, SEQUENCE_NUMBER_FIELD, last_sequence_number, None)
+            .await
+            .change_context(errors::WebhooksFlowError::EventSequenceNumberGenerationFailed)?;
+    }
+
+    let sequence_number = redis_conn
+        .increment_fields_in_hash(&key, &[(SEQUENCE_NUMBER_FIELD, 1)])
+        .await
+        .change_context(errors::WebhooksFlowError::EventSequenceNumberGenerationFailed)?
+        .first()
+        .copied()
+        .and_then(|sequence_number| i64::try_from(sequence_number).ok())
+        .ok_or(errors::WebhooksFlowError::EventSequenceNumberGenerationFailed)
+        .attach_printable("Failed to increment event sequence number")?;
+
+    redis_conn
+        .set_expiry(&key, SEQUENCE_NUMBER_TTL_SECS)
+        .await
+        .change_context(errors::WebhooksFlowError::EventSequenceNumberGenerationFailed)?;
+
+    Ok(sequence_number)
+}
+
+/// Obtains the initial events of the object created for the webhook endpoint with a lower sequence
+/// number than the specified one, which are still awaiting delivery: they have not been
+/// acknowledged by the endpoint, and their delivery retries have not been exhausted.
+#[instrument(skip_all)]
+pub(crate) async fn get_pending_earlier_events(
+    state: &SessionState,
+    merchant_key_store: &domain::MerchantKeyStore,
+    primary_object_id: &str,
+    webhook_endpoint_id: &str,
+    sequence_number: i64,
+) -> CustomResult<Vec<domain::Event>, errors::WebhooksFlowError> {
+    let earlier_events = state
+        .store
+        .list_initial_events_by_merchant_id_primary_object_id(
+            &state.into(),
+            &merchant_key_store.merchant_id,
+            primary_object_id,
+            merchant_key_store,
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::EventOrderingCheckFailed)
+        .attach_printable("Failed to list events for object")?
+        .into_iter()
+        .filter(|event| {
+            event.webhook_endpoint_id.as_deref() == Some(webhook_endpoint_id)
+                && event
+                    .sequence_number
+                    .is_some_and(|event_sequence_number| event_sequence_number < sequence_number)
+        });
+
+    let mut pending_events = Vec::new();
+    for event in earlier_events {
+        if is_event_awaiting_delivery(state, merchant_key_store, &event).await? {
+            pending_events.push(event);
+        }
+    }
+    pending_events.sort_by_key(|event| event.sequence_number);
+
+    Ok(pending_events)
+}
+
+/// Reschedules the delivery of an event held back for strict ordering. Holding back an event is not
+/// a delivery attempt, so the retry count of the task is left unchanged and the event retains all
+/// of its delivery retries once the earlier events have been delivered.
+#[instrument(skip_all, fields(process_tracker_id = %process.id))]
+pub(crate) async fn hold_back_event_delivery(
+    state: &SessionState,
+    process: storage::ProcessTracker,
+    schedule_time: time::PrimitiveDateTime,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    let retry_count = process.retry_count;
+    state
+        .store
+        .as_scheduler()
+        .update_process(
+            process,
+            storage::ProcessTrackerUpdate::StatusRetryUpdate {
+                status: enums::ProcessTrackerStatus::Pending,
+                retry_count,
+                schedule_time,
+            },
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::OutgoingWebhookProcessTrackerTaskUpdateFailed)?;
+
+    Ok(())
+}
+
+/// Once an event has been acknowledged by a webhook endpoint with strict ordering enabled, brings
+/// forward the delivery of the next event of the object which was held back for the endpoint.
+#[instrument(skip_all, fields(event_id = %delivered_event.event_id))]
+pub(super) async fn release_next_held_event(
+    state: &SessionState,
+    merchant_key_store: &domain::MerchantKeyStore,
+    delivered_event: &domain::Event,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    let (Some(webhook_endpoint_id), Some(sequence_number)) = (
+        delivered_event.webhook_endpoint_id.as_deref(),
+        delivered_event.sequence_number,
+    ) else {
+        return Ok(());
+    };
+
+    // Only the earliest pending event of the object can be released, and only if it comes after
+    // the delivered event
+    let mut pending_events = get_pending_earlier_events(
+        state,
+        merchant_key_store,
+        &delivered_event.primary_object_id,
+        webhook_endpoint_id,
+        i64::MAX,
+    )
+    .await?
+    .into_iter();
+    let Some(next_event) = pending_events.next() else {
+        return Ok(());
+    };
+    if next_event
+        .sequence_number
+        .map_or(true, |next_sequence_number| {
+            next_sequence_number < sequence_number
+        })
+    {
+        logger::debug!(
+            next_event_id = %next_event.event_id,
+            "Earlier events are still awaiting delivery, not releasing held event"
+        );
+        return Ok(());
+    }
+
+    let process_tracker_id = get_outgoing_webhook_retry_process_tracker_id(
+        &merchant_key_store.merchant_id,
+        &next_event.event_id,
+    );
+    let Some(process) = state
+        .store
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::WebhooksFlowError::OutgoingWebhookProcessTrackerTaskUpdateFailed)?
+    else {
+        return Ok(());
+    };
+    if !matches!(
+        process.status,
+        enums::ProcessTrackerStatus::New | enums::ProcessTrackerStatus::Pending
+    ) {
+        return Ok(());
+    }
+
+    logger::debug!(
+        next_event_id = %next_event.event_id,
+        "Releasing event held back for strict ordering"
+    );
+    let status = process.status;
+    let retry_count = process.retry_count;
+    state
+        .store
+        .as_scheduler()
+        .update_process(
+            process,
+            storage::ProcessTrackerUpdate::StatusRetryUpdate {
+                status,
+                retry_count,
+                schedule_time: common_utils::date_time::now(),
+            },
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::OutgoingWebhookProcessTrackerTaskUpdateFailed)?;
+
+    Ok(())
+}
+
+/// Whether the initial event is neither acknowledged by the merchant via any of its delivery
+/// attempts, nor done with its delivery retries.
+async fn is_event_awaiting_delivery(
+    state: &SessionState,
+    merchant_key_store: &domain::MerchantKeyStore,
+    initial_event: &domain::Event,
+) -> CustomResult<bool, errors::WebhooksFlowError> {
+    if initial_event.is_webhook_notified {
+        return Ok(false);
+    }
+
+    let merchant_id = &merchant_key_store.merchant_id;
+    let process_tracker_id =
+        get_outgoing_webhook_retry_process_tracker_id(merchant_id, &initial_event.event_id);
+    let is_retry_task_active = state
+        .store
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::WebhooksFlowError::EventOrderingCheckFailed)
+        .attach_printable("Failed to find outgoing webhook retry task")?
+        .is_some_and(|process| process.status != enums::ProcessTrackerStatus::Finish);
+    if !is_retry_task_active {
+        return Ok(false);
+    }
+
+    let is_acknowledged = state
+        .store
+        .list_events_by_merchant_id_initial_attempt_id(
+            &state.into(),
+            merchant_id,
+            &initial_event.event_id,
+            merchant_key_store,
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::EventOrderingCheckFailed)
+        .attach_printable("Failed to list delivery attempts for event")?
+        .iter()
+        .any(|event| event.is_webhook_notified);
+
+    Ok(!is_acknowledged)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+    use std::sync::Arc;
+
+    use common_utils::{type_name, types::keymanager::Identifier};
+    use time::macros::datetime;
+
+    use super::*;
+    use crate::{
+        routes::{
+            self,
+            app::{settings::Settings, StorageImpl},
+        },
+        services,
+    };
+
+    const PAYMENT_ID: &str = "pay_ordered";
+    const ENDPOINT_ID: &str = "whe_ordered";
+
+    async fn get_state_and_key_store() -> (SessionState, domain::MerchantKeyStore) {
+        let (tx, _) = tokio::sync::oneshot::channel();
+        let app_state = Box::pin(routes::AppState::with_storage(
+            Settings::default(),
+            StorageImpl::Mock,
+            tx,
+            Box::new(services::MockApiClient),
+        ))
+        .await;
+        let state = Arc::new(app_state)
+            .get_session_state("public", || {})
+            .unwrap();
+
+        let merchant_id =
+            common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1"))
+                .unwrap();
+        let key_manager_state = &(&state).into();
+        let master_key = state.store.get_master_key();
+        let merchant_key_store = state
+            .store
+            .insert_merchant_key_store(
+                key_manager_state,
+                domain::MerchantKeyStore {
+                    merchant_id: merchant_id.clone(),
+                    key: domain::types::crypto_operation(
+                        key_manager_state,
+                        type_name!(domain::MerchantKeyStore),
+                        domain::types::CryptoOperation::Encrypt(
+                            services::generate_aes256_key().unwrap().to_vec().into(),
+                        ),
+                        Identifier::Merchant(merchant_id.clone()),
+                        master_key,
+                    )
+                    .await
+                    .and_then(|val| val.try_into_operation())
+                    .unwrap(),
+                    created_at: datetime!(2023-02-01 0:00),
+                },
+                &master_key.to_vec().into(),
+            )
+            .await
+            .unwrap();
+
+        (state, merchant_key_store)
+    }
+
+    /// Inserts an initial event for the payment, along with its retry task when a task status is
+    /// specified.
+    async fn insert_event(
+        state: &SessionState,
+        merchant_key_store: &domain::MerchantKeyStore,
+        event_id: &str,
+        sequence_number: i64,
+        webhook_endpoint_id: &str,
+        is_webhook_notified: bool,
+        task_status: Option<enums::ProcessTrackerStatus>,
+    ) -> domain::Event {
+        let event = insert_delivery_attempt(
+            state,
+            merchant_key_store,
+            event_id,
+            event_id,
+            sequence_number,
+            webhook_endpoint_id,
+            is_webhook_notified,
+        )
+        .await;
+
+        if let Some(status) = task_status {
+            insert_retry_task(state, merchant_key_store, event_id, status, 0).await;
+        }
+
+        event
+    }
+
+    async fn insert_delivery_attempt(
+        state: &SessionState,
+        merchant_key_store: &domain::MerchantKeyStore,
+        event_id: &str,
+        initial_attempt_id: &str,
+        sequence_number: i64,
+        webhook_endpoint_id: &str,
+        is_webhook_notified: bool,
+    ) -> domain::Event {
+        let now = common_utils::date_time::now();
+        state
+            .store
+            .insert_event(
+                &state.into(),
+                domain::Event {
+                    event_id: event_id.into(),
+                    event_type: enums::EventType::PaymentSucceeded,
+                    event_class: enums::EventClass::Payments,
+                    is_webhook_notified,
+                    primary_object_id: PAYMENT_ID.into(),
+                    primary_object_type: enums::EventObjectType::PaymentDetails,
+                    created_at: now,
+                    merchant_id: Some(merchant_key_store.merchant_id.clone()),
+                    business_profile_id: None,
+                    primary_object_created_at: Some(now),
+                    idempotent_event_id: Some(event_id.into()),
+                    initial_attempt_id: Some(initial_attempt_id.into()),
+                    request: None,
+                    response: None,
+                    delivery_attempt: Some(enums::WebhookDeliveryAttempt::InitialAttempt),
+                    metadata: None,
+                    webhook_endpoint_id: Some(webhook_endpoint_id.into()),
+                    delivery_latency_ms: None,
+                    sequence_number: Some(sequence_number),
+                },
+                merchant_key_store,
+            )
+            .await
+            .unwrap()
+    }
+
+    async fn insert_retry_task(
+        state: &SessionState,
+        merchant_key_store: &domain::MerchantKeyStore,
+        event_id: &str,
+        status: enums::ProcessTrackerStatus,
+        retry_count: i32,
+    ) -> storage::ProcessTracker {
+        let mut process_tracker_entry = storage::ProcessTrackerNew::new(
+            get_outgoing_webhook_retry_process_tracker_id(
+                &merchant_key_store.merchant_id,
+                event_id,
+            ),
+            "OUTGOING_WEBHOOK_RETRY",
+            storage::ProcessTrackerRunner::OutgoingWebhookRetryWorkflow,
+            ["OUTGOING_WEBHOOKS"],
+            serde_json::json!({}),
+            datetime!(2100-01-01 0:00),
+        )
+        .unwrap();
+        process_tracker_entry.status = status;
+        process_tracker_entry.retry_count = retry_count;
+
+        state
+            .store
+            .insert_process(process_tracker_entry)
+            .await
+            .unwrap()
+    }
+
+    async fn find_retry_task(
+        state: &SessionState,
+        merchant_key_store: &domain::MerchantKeyStore,
+        event_id: &str,
+    ) -> storage::ProcessTracker {
+        state
+            .store
+            .find_process_by_id(&get_outgoing_webhook_retry_process_tracker_id(
+                &merchant_key_store.merchant_id,
+                event_id,
+            ))
+            .await
+            .unwrap()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_get_pending_earlier_events() {
+        let (state, key_store) = get_state_and_key_store().await;
+        let pending = Some(enums::ProcessTrackerStatus::Pending);
+
+        // Acknowledged by the initial delivery attempt
+        insert_event(&state, &key_store, "evt_1", 1, ENDPOINT_ID, true, pending).await;
+        // Acknowledged by a retried delivery attempt
+        insert_event(&state, &key_store, "evt_2", 2, ENDPOINT_ID, false, pending).await;
+        insert_delivery_attempt(
+            &state,
+            &key_store,
+            "evt_2_retry",
+            "evt_2",
+            2,
+            ENDPOINT_ID,
+            true,
+        )
+        .await;
+        // Inserted out of order, to verify that the pending events are sorted
+        insert_event(&state, &key_store, "evt_6", 6, ENDPOINT_ID, false, pending).await;
+        insert_event(&state, &key_store, "evt_3", 3, ENDPOINT_ID, false, pending).await;
+        // Delivery retries exhausted
+        let finished = Some(enums::ProcessTrackerStatus::Finish);
+        insert_event(&state, &key_store, "evt_4", 4, ENDPOINT_ID, false, finished).await;
+        // Created for a different webhook endpoint
+        insert_event(&state, &key_store, "evt_5", 5, "whe_other", false, pending).await;
+
+        let pending_event_ids = |events: Vec<domain::Event>| {
+            events
+                .into_iter()
+                .map(|event| event.event_id)
+                .collect::<Vec<_>>()
+        };
+
+        let pending_events =
+            get_pending_earlier_events(&state, &key_store, PAYMENT_ID, ENDPOINT_ID, 6)
+                .await
+                .unwrap();
+        assert_eq!(pending_event_ids(pending_events), vec!["evt_3"]);
+
+        let pending_events =
+            get_pending_earlier_events(&state, &key_store, PAYMENT_ID, ENDPOINT_ID, i64::MAX)
+                .await
+                .unwrap();
+        assert_eq!(pending_event_ids(pending_events), vec!["evt_3", "evt_6"]);
+
+        let pending_events =
+            get_pending_earlier_events(&state, &key_store, PAYMENT_ID, ENDPOINT_ID, 3)
+                .await
+                .unwrap();
+        assert!(pending_events.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_release_next_held_event() {
+        let (state, key_store) = get_state_and_key_store().await;
+        let pending = Some(enums::ProcessTrackerStatus::Pending);
+
+        let delivered_event =
+            insert_event(&state, &key_store, "evt_1", 1, ENDPOINT_ID, true, pending).await;
+        insert_event(&state, &key_store, "evt_2", 2, ENDPOINT_ID, false, None).await;
+        let held_task = insert_retry_task(
+            &state,
+            &key_store,
+            "evt_2",
+            enums::ProcessTrackerStatus::Pending,
+            1,
+        )
+        .await;
+        insert_event(&state, &key_store, "evt_3", 3, ENDPOINT_ID, false, pending).await;
+
+        release_next_held_event(&state, &key_store, &delivered_event)
+            .await
+            .unwrap();
+
+        let released_task = find_retry_task(&state, &key_store, "evt_2").await;
+        assert!(released_task.schedule_time < held_task.schedule_time);
+        assert!(released_task.schedule_time <= Some(common_utils::date_time::now()));
+        assert_eq!(released_task.retry_count, held_task.retry_count);
+        assert_eq!(released_task.status, enums::ProcessTrackerStatus::Pending);
+
+        // Only the next event of the object is released
+        let later_task = find_retry_task(&state, &key_store, "evt_3").await;
+        assert_eq!(later_task.schedule_time, held_task.schedule_time);
+    }
+
+    #[tokio::test]
+    async fn test_release_next_held_event_waits_for_earlier_events() {
+        let (state, key_store) = get_state_and_key_store().await;
+        let pending = Some(enums::ProcessTrackerStatus::Pending);
+
+        insert_event(&state, &key_store, "evt_1", 1, ENDPOINT_ID, false, pending).await;
+        let delivered_event =
+            insert_event(&state, &key_store, "evt_2", 2, ENDPOINT_ID, true, pending).await;
+        let held_task = insert_retry_task(
+            &state,
+            &key_store,
+            "evt_3",
+            enums::ProcessTrackerStatus::Pending,
+            0,
+        )
+        .await;
+        insert_delivery_attempt(&state, &key_store, "evt_3", "evt_3", 3, ENDPOINT_ID, false).await;
+
+        release_next_held_event(&state, &key_store, &delivered_event)
+            .await
+            .unwrap();
+
+        let task = find_retry_task(&state, &key_store, "evt_3").await;
+        assert_eq!(task.schedule_time, held_task.schedule_time);
+    }
+
+    #[tokio::test]
+    async fn test_hold_back_event_delivery_keeps_retry_count() {
+        let (state, key_store) = get_state_and_key_store().await;
+        let process = insert_retry_task(
+            &state,
+            &key_store,
+            "evt_1",
+            enums::ProcessTrackerStatus::ProcessStarted,
+            2,
+        )
+        .await;
+        let schedule_time = datetime!(2030-01-01 0:00);
+
+        hold_back_event_delivery(&state, process, schedule_time)
+            .await
+            .unwrap();
+
+        let task = find_retry_task(&state, &key_store, "evt_1").await;
+        assert_eq!(task.retry_count, 2);
+        assert_eq!(task.schedule_time, Some(schedule_time));
+        assert_eq!(task.status, enums::ProcessTrackerStatus::Pending);
+    }
+}
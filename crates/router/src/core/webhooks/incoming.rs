@@ -3,25 +3,32 @@ use std::{str::FromStr, time::Instant};
 use actix_web::FromRequest;
 #[cfg(feature = "payouts")]
 use api_models::payouts as payout_models;
-use api_models::webhooks::{self, WebhookResponseTracker};
-use common_utils::{errors::ReportSwitchExt, events::ApiEventsType};
+use api_models::{
+    incoming_webhook_archive::IncomingWebhookRequestContent,
+    webhooks::{self, WebhookResponseTracker},
+};
+use common_utils::{
+    errors::ReportSwitchExt, events::ApiEventsType, ext_traits::Encode, type_name,
+    types::keymanager::Identifier,
+};
 use error_stack::{report, ResultExt};
 use hyperswitch_domain_models::{
     payments::HeaderPayload,
     router_request_types::VerifyWebhookSourceRequestData,
     router_response_types::{VerifyWebhookSourceResponseData, VerifyWebhookStatus},
+    type_encryption::{crypto_operation, CryptoOperation},
 };
 use hyperswitch_interfaces::webhooks::IncomingWebhookRequestDetails;
-use masking::ExposeInterface;
+use masking::{ExposeInterface, PeekInterface, Secret};
 use router_env::{instrument, metrics::add_attributes, tracing, tracing_actix_web::RequestId};
 
 use super::{types, utils, MERCHANT_ID};
 use crate::{
     consts,
     core::{
-        api_locking,
+        api_locking, disputes,
         errors::{self, ConnectorErrorExt, CustomResult, RouterResponse, StorageErrorExt},
-        metrics, payments, refunds, utils as core_utils,
+        metrics, payments, refunds, split_payments, utils as core_utils,
         webhooks::utils::construct_webhook_router_data,
     },
     db::StorageInterface,
@@ -61,17 +68,42 @@ pub async fn incoming_webhooks_wrapper<W: types::OutgoingWebhookType>(
     body: actix_web::web::Bytes,
 ) -> RouterResponse<serde_json::Value> {
     let start_instant = Instant::now();
-    let (application_response, webhooks_response_tracker, serialized_req) =
-        Box::pin(incoming_webhooks_core::<W>(
-            state.clone(),
-            req_state,
-            req,
-            merchant_account.clone(),
-            key_store,
-            connector_name_or_mca_id,
-            body.clone(),
-        ))
-        .await?;
+    let request_details = IncomingWebhookRequestDetails {
+        method: req.method().clone(),
+        uri: req.uri().clone(),
+        headers: req.headers(),
+        query_params: req.query_string().to_string(),
+        body: &body,
+    };
+    let mut archive_details = types::IncomingWebhookArchiveDetails::default();
+    let core_result = Box::pin(incoming_webhooks_core::<W>(
+        state.clone(),
+        req_state,
+        request_details,
+        merchant_account.clone(),
+        key_store.clone(),
+        connector_name_or_mca_id,
+        &mut archive_details,
+    ))
+    .await;
+
+    // Failing to archive the webhook must not affect the response sent to the connector
+    if let Err(error) = archive_incoming_webhook(
+        &state,
+        &key_store,
+        merchant_account.get_id(),
+        connector_name_or_mca_id,
+        req,
+        &body,
+        archive_details,
+        &core_result,
+    )
+    .await
+    {
+        logger::error!(?error, "Failed to archive incoming webhook");
+    }
+
+    let (application_response, webhooks_response_tracker, serialized_req) = core_result?;
 
     logger::info!(incoming_webhook_payload = ?serialized_req);
 
@@ -114,20 +146,124 @@ pub async fn incoming_webhooks_wrapper<W: types::OutgoingWebhookType>(
     Ok(application_response)
 }
 
+/// Records the incoming webhook request and the outcome of processing it in the incoming webhook
+/// archive, so that it can be inspected and reprocessed later.
+#[allow(clippy::too_many_arguments)]
 #[instrument(skip_all)]
-async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
+async fn archive_incoming_webhook(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    merchant_id: &common_utils::id_type::MerchantId,
+    connector_name_or_mca_id: &str,
+    req: &actix_web::HttpRequest,
+    body: &[u8],
+    archive_details: types::IncomingWebhookArchiveDetails,
+    core_result: &IncomingWebhookCoreResult,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    let key_manager_state = &state.into();
+    let request_content = IncomingWebhookRequestContent {
+        method: req.method().to_string(),
+        uri: req.uri().to_string(),
+        headers: req
+            .headers()
+            .iter()
+            .map(|(name, value)| {
+                (
+                    name.as_str().to_owned(),
+                    Secret::new(String::from_utf8_lossy(value.as_bytes()).into_owned()),
+                )
+            })
+            .collect(),
+        // Connectors send textual (JSON, XML or form encoded) webhook bodies
+        body: Secret::new(String::from_utf8_lossy(body).into_owned()),
+    };
+    let request = crypto_operation(
+        key_manager_state,
+        type_name!(domain::IncomingWebhookArchive),
+        CryptoOperation::Encrypt(
+            request_content
+                .encode_to_string_of_json()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to encode incoming webhook request content")
+                .map(Secret::new)?,
+        ),
+        Identifier::Merchant(key_store.merchant_id.clone()),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encrypt incoming webhook request content")?;
+
+    let (processing_status, error_code, error_message) =
+        get_incoming_webhook_processing_outcome(core_result);
+    let now = common_utils::date_time::now();
+    let archive = domain::IncomingWebhookArchive {
+        webhook_id: generate_id(consts::ID_LENGTH, "iwh"),
+        merchant_id: merchant_id.to_owned(),
+        connector_id_or_name: connector_name_or_mca_id.to_owned(),
+        connector_name: archive_details.connector_name,
+        merchant_connector_id: archive_details.merchant_connector_id,
+        profile_id: archive_details.profile_id,
+        request,
+        event_type: archive_details.event_type,
+        source_verified: archive_details.source_verified,
+        processing_status,
+        error_code,
+        error_message,
+        reprocess_count: 0,
+        last_reprocessed_at: None,
+        created_at: now,
+        modified_at: now,
+    };
+
+    state
+        .store
+        .insert_incoming_webhook_archive(key_manager_state, archive, key_store)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert incoming webhook in archive")?;
+
+    Ok(())
+}
+
+pub(super) fn get_incoming_webhook_processing_outcome(
+    core_result: &IncomingWebhookCoreResult,
+) -> (
+    enums::IncomingWebhookProcessingStatus,
+    Option<String>,
+    Option<String>,
+) {
+    match core_result {
+        Ok(_) => (
+            enums::IncomingWebhookProcessingStatus::Processed,
+            None,
+            None,
+        ),
+        Err(error) => (
+            enums::IncomingWebhookProcessingStatus::Failed,
+            Some(error.current_context().error_code()),
+            Some(error.current_context().error_message()),
+        ),
+    }
+}
+
+pub(super) type IncomingWebhookCoreResult = errors::RouterResult<(
+    services::ApplicationResponse<serde_json::Value>,
+    WebhookResponseTracker,
+    serde_json::Value,
+)>;
+
+#[instrument(skip_all)]
+pub(super) async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
     state: SessionState,
     req_state: ReqState,
-    req: &actix_web::HttpRequest,
+    request_details: IncomingWebhookRequestDetails<'_>,
     merchant_account: domain::MerchantAccount,
     key_store: domain::MerchantKeyStore,
     connector_name_or_mca_id: &str,
-    body: actix_web::web::Bytes,
-) -> errors::RouterResult<(
-    services::ApplicationResponse<serde_json::Value>,
-    WebhookResponseTracker,
-    serde_json::Value,
-)> {
+    archive_details: &mut types::IncomingWebhookArchiveDetails,
+) -> IncomingWebhookCoreResult {
     let key_manager_state = &(&state).into();
 
     metrics::WEBHOOK_INCOMING_COUNT.add(
@@ -138,14 +274,6 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
             merchant_account.get_id().get_string_repr().to_owned(),
         )],
     );
-    let mut request_details = IncomingWebhookRequestDetails {
-        method: req.method().clone(),
-        uri: req.uri().clone(),
-        headers: req.headers(),
-        query_params: req.query_string().to_string(),
-        body: &body,
-    };
-
     // Fetch the merchant connector account to get the webhooks source secret
     // `webhooks source secret` is a secret shared between the merchant and connector
     // This is used for source verification and webhooks integrity
@@ -156,6 +284,10 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
         &key_store,
     )
     .await?;
+    archive_details.connector_name = Some(connector_name.clone());
+    archive_details.merchant_connector_id = merchant_connector_account
+        .as_ref()
+        .map(|merchant_connector_account| merchant_connector_account.get_id());
 
     let decoded_body = connector
         .decode_webhook_body(
@@ -172,7 +304,10 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
         .switch()
         .attach_printable("There was an error in incoming webhook body decoding")?;
 
-    request_details.body = &decoded_body;
+    let request_details = IncomingWebhookRequestDetails {
+        body: &decoded_body,
+        ..request_details
+    };
 
     let event_type = match connector
         .get_webhook_event_type(&request_details)
@@ -218,6 +353,9 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
         }
     };
     logger::info!(event_type=?event_type);
+    archive_details.event_type = serde_json::to_value(event_type)
+        .ok()
+        .and_then(|event_type| event_type.as_str().map(ToOwned::to_owned));
 
     let is_webhook_event_supported = !matches!(
         event_type,
@@ -268,6 +406,9 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
             }
         };
 
+        archive_details.merchant_connector_id = Some(merchant_connector_account.get_id());
+        archive_details.profile_id = Some(merchant_connector_account.profile_id.clone());
+
         let source_verified = if connectors_with_source_verification_call
             .connectors_with_webhook_source_verification_call
             .contains(&connector_enum)
@@ -311,6 +452,7 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
                 .switch()
                 .attach_printable("There was an issue in incoming webhook source verification")?
         };
+        archive_details.source_verified = Some(source_verified);
 
         if source_verified {
             metrics::WEBHOOK_SOURCE_VERIFIED_COUNT.add(
@@ -837,6 +979,13 @@ async fn refunds_incoming_webhook_flow(
         .await
         .attach_printable_lazy(|| format!("Failed while updating refund: refund_id: {refund_id}"))?
     };
+    split_payments::update_split_refunds(&state, &updated_refund, merchant_account.storage_scheme)
+        .await
+        .inspect_err(|error| {
+            logger::error!(?error, "Failed to reverse the shares of the split payment")
+        })
+        .ok();
+
     let event_type: Option<enums::EventType> = updated_refund.refund_status.foreign_into();
 
     // If event is NOT an UnsupportedEvent, trigger Outgoing Webhook
@@ -1399,6 +1548,17 @@ async fn disputes_incoming_webhook_flow(
             connector.id(),
         )
         .await?;
+        // The dispute has already been updated, so failures in tracking its deadline are only
+        // logged
+        if let Err(error) =
+            disputes::deadlines::track_dispute_deadline(&*state.store, &dispute_object).await
+        {
+            logger::error!(
+                ?error,
+                dispute_id = %dispute_object.dispute_id,
+                "Failed to track dispute deadline"
+            );
+        }
         let disputes_response = Box::new(dispute_object.clone().foreign_into());
         let event_type: enums::EventType = dispute_object.dispute_status.foreign_into();
 
@@ -0,0 +1,557 @@
+use std::str::FromStr;
+
+use actix_web::http::header::{HeaderMap, HeaderName, HeaderValue};
+use api_models::{incoming_webhook_archive as archive_types, webhooks};
+use error_stack::ResultExt;
+use hyperswitch_interfaces::webhooks::IncomingWebhookRequestDetails;
+use masking::{ExposeInterface, PeekInterface};
+use router_env::{instrument, tracing};
+
+use super::{incoming, types};
+use crate::{
+    core::errors::{self, RouterResponse, StorageErrorExt},
+    routes::{app::ReqState, SessionState},
+    services::ApplicationResponse,
+    types::{domain, storage},
+    utils::StringExt,
+};
+
+const INCOMING_WEBHOOKS_LIST_MAX_LIMIT: i64 = 100;
+
+#[instrument(skip_all)]
+pub async fn list_incoming_webhooks(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    constraints: archive_types::IncomingWebhookListConstraints,
+) -> RouterResponse<Vec<archive_types::IncomingWebhookListItemResponse>> {
+    let limit = match constraints.limit.map(i64::from) {
+        Some(limit) if limit <= INCOMING_WEBHOOKS_LIST_MAX_LIMIT => limit,
+        Some(_) => Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "`limit` must be a number less than or equal to {INCOMING_WEBHOOKS_LIST_MAX_LIMIT}"
+            ),
+        })?,
+        None => INCOMING_WEBHOOKS_LIST_MAX_LIMIT,
+    };
+    let offset = constraints
+        .offset
+        .map(i64::from)
+        .filter(|offset| *offset > 0);
+
+    let archives = state
+        .store
+        .list_incoming_webhook_archives_by_merchant_id_constraints(
+            &(&state).into(),
+            merchant_account.get_id(),
+            storage::IncomingWebhookArchiveListConstraints {
+                created_after: constraints.created_after,
+                created_before: constraints.created_before,
+                connector_name: constraints.connector_name,
+                merchant_connector_id: constraints.merchant_connector_id,
+                profile_id: constraints.profile_id,
+                processing_status: constraints.processing_status,
+                limit: Some(limit),
+                offset,
+            },
+            &key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list incoming webhooks with specified constraints")?;
+
+    Ok(ApplicationResponse::Json(
+        archives.into_iter().map(Into::into).collect(),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_incoming_webhook(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    webhook_id: String,
+) -> RouterResponse<archive_types::IncomingWebhookRetrieveResponse> {
+    let archive = find_incoming_webhook(&state, &merchant_account, &key_store, &webhook_id).await?;
+
+    Ok(ApplicationResponse::Json(
+        archive_types::IncomingWebhookRetrieveResponse::try_from(archive)?,
+    ))
+}
+
+/// Runs the archived incoming webhook request through the incoming webhooks flow again, and
+/// records the outcome in the archive. This allows applying status updates that were missed when
+/// the webhook was originally received, for example due to a bug or a misconfigured webhook
+/// secret.
+#[instrument(skip_all)]
+pub async fn reprocess_incoming_webhook(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    webhook_id: String,
+) -> RouterResponse<archive_types::IncomingWebhookRetrieveResponse> {
+    let archive = find_incoming_webhook(&state, &merchant_account, &key_store, &webhook_id).await?;
+
+    let request_content: archive_types::IncomingWebhookRequestContent = archive
+        .request
+        .get_inner()
+        .peek()
+        .parse_struct("IncomingWebhookRequestContent")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse incoming webhook request information")?;
+
+    let method = actix_web::http::Method::from_str(&request_content.method)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse HTTP method of archived incoming webhook")?;
+    let uri = actix_web::http::Uri::from_str(&request_content.uri)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse URI of archived incoming webhook")?;
+    let headers = get_header_map(request_content.headers)?;
+    let body = request_content.body.expose().into_bytes();
+
+    let request_details = IncomingWebhookRequestDetails {
+        method,
+        query_params: uri.query().unwrap_or_default().to_owned(),
+        uri,
+        headers: &headers,
+        body: &body,
+    };
+    let mut archive_details = types::IncomingWebhookArchiveDetails::default();
+    let core_result = Box::pin(
+        incoming::incoming_webhooks_core::<webhooks::OutgoingWebhook>(
+            state.clone(),
+            req_state,
+            request_details,
+            merchant_account.clone(),
+            key_store.clone(),
+            &archive.connector_id_or_name,
+            &mut archive_details,
+        ),
+    )
+    .await;
+
+    let (processing_status, error_code, error_message) =
+        incoming::get_incoming_webhook_processing_outcome(&core_result);
+    let updated_archive = state
+        .store
+        .update_incoming_webhook_archive_by_merchant_id_webhook_id(
+            &(&state).into(),
+            merchant_account.get_id(),
+            &webhook_id,
+            storage::IncomingWebhookArchiveUpdate::ReprocessUpdate {
+                connector_name: archive_details.connector_name,
+                merchant_connector_id: archive_details.merchant_connector_id,
+                profile_id: archive_details.profile_id,
+                event_type: archive_details.event_type,
+                source_verified: archive_details.source_verified,
+                processing_status,
+                error_code,
+                error_message,
+                reprocess_count: archive.reprocess_count.saturating_add(1),
+            },
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Incoming webhook not found".to_string(),
+        })?;
+
+    Ok(ApplicationResponse::Json(
+        archive_types::IncomingWebhookRetrieveResponse::try_from(updated_archive)?,
+    ))
+}
+
+async fn find_incoming_webhook(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    webhook_id: &str,
+) -> errors::RouterResult<domain::IncomingWebhookArchive> {
+    state
+        .store
+        .find_incoming_webhook_archive_by_merchant_id_webhook_id(
+            &state.into(),
+            merchant_account.get_id(),
+            webhook_id,
+            key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Incoming webhook not found".to_string(),
+        })
+}
+
+fn get_header_map(
+    headers: Vec<(String, masking::Secret<String>)>,
+) -> errors::RouterResult<HeaderMap> {
+    let mut header_map = HeaderMap::new();
+    for (name, value) in headers {
+        let name = HeaderName::from_str(&name)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse header name of archived incoming webhook")?;
+        let value = HeaderValue::from_str(&value.expose())
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse header value of archived incoming webhook")?;
+        header_map.append(name, value);
+    }
+    Ok(header_map)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+    use std::sync::Arc;
+
+    use common_utils::{ext_traits::Encode, type_name, types::keymanager::Identifier};
+    use diesel_models::enums::IncomingWebhookProcessingStatus;
+    use masking::Secret;
+    use time::macros::datetime;
+
+    use super::*;
+    use crate::{
+        routes::{
+            self,
+            app::{settings::Settings, StorageImpl},
+            AppState,
+        },
+        services,
+    };
+
+    async fn get_state_and_key_store() -> (Arc<AppState>, SessionState, domain::MerchantKeyStore) {
+        let (tx, _) = tokio::sync::oneshot::channel();
+        let app_state = Arc::new(
+            Box::pin(routes::AppState::with_storage(
+                Settings::default(),
+                StorageImpl::Mock,
+                tx,
+                Box::new(services::MockApiClient),
+            ))
+            .await,
+        );
+        let state = app_state
+            .clone()
+            .get_session_state("public", || {})
+            .unwrap();
+
+        let merchant_id =
+            common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1"))
+                .unwrap();
+        let key_manager_state = &(&state).into();
+        let master_key = state.store.get_master_key();
+        let merchant_key_store = state
+            .store
+            .insert_merchant_key_store(
+                key_manager_state,
+                domain::MerchantKeyStore {
+                    merchant_id: merchant_id.clone(),
+                    key: domain::types::crypto_operation(
+                        key_manager_state,
+                        type_name!(domain::MerchantKeyStore),
+                        domain::types::CryptoOperation::Encrypt(
+                            services::generate_aes256_key().unwrap().to_vec().into(),
+                        ),
+                        Identifier::Merchant(merchant_id.clone()),
+                        master_key,
+                    )
+                    .await
+                    .and_then(|val| val.try_into_operation())
+                    .unwrap(),
+                    created_at: datetime!(2023-02-01 0:00),
+                },
+                &master_key.to_vec().into(),
+            )
+            .await
+            .unwrap();
+
+        (app_state, state, merchant_key_store)
+    }
+
+    fn get_merchant_account(
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> domain::MerchantAccount {
+        let now = common_utils::date_time::now();
+        domain::MerchantAccount::from(domain::MerchantAccountSetter {
+            merchant_id: merchant_id.clone(),
+            return_url: None,
+            enable_payment_response_hash: false,
+            payment_response_hash_key: None,
+            redirect_to_merchant_with_http_post: false,
+            merchant_name: None,
+            merchant_details: None,
+            webhook_details: None,
+            sub_merchants_enabled: None,
+            parent_merchant_id: None,
+            publishable_key: "pk_archive".into(),
+            storage_scheme: storage::enums::MerchantStorageScheme::PostgresOnly,
+            locker_id: None,
+            metadata: None,
+            routing_algorithm: None,
+            primary_business_details: serde_json::json!([]),
+            frm_routing_algorithm: None,
+            created_at: now,
+            modified_at: now,
+            intent_fulfillment_time: None,
+            payout_routing_algorithm: None,
+            organization_id: common_utils::id_type::OrganizationId::default(),
+            is_recon_enabled: false,
+            default_profile: None,
+            recon_status: storage::enums::ReconStatus::NotRequested,
+            payment_link_config: None,
+            pm_collect_link_config: None,
+            version: common_enums::ApiVersion::V1,
+        })
+    }
+
+    async fn insert_archive(
+        state: &SessionState,
+        merchant_key_store: &domain::MerchantKeyStore,
+        webhook_id: &str,
+        connector_id_or_name: &str,
+        processing_status: IncomingWebhookProcessingStatus,
+        created_at: time::PrimitiveDateTime,
+    ) -> domain::IncomingWebhookArchive {
+        let key_manager_state = &state.into();
+        let request_content = archive_types::IncomingWebhookRequestContent {
+            method: "POST".into(),
+            uri: format!("/webhooks/merchant_1/{connector_id_or_name}"),
+            headers: vec![(
+                "content-type".into(),
+                Secret::new("application/json".into()),
+            )],
+            body: Secret::new(r#"{"id":"evt_archived"}"#.into()),
+        };
+        let request = domain::types::crypto_operation(
+            key_manager_state,
+            type_name!(domain::IncomingWebhookArchive),
+            domain::types::CryptoOperation::Encrypt(Secret::new(
+                request_content.encode_to_string_of_json().unwrap(),
+            )),
+            Identifier::Merchant(merchant_key_store.merchant_id.clone()),
+            merchant_key_store.key.get_inner().peek(),
+        )
+        .await
+        .and_then(|val| val.try_into_operation())
+        .unwrap();
+
+        state
+            .store
+            .insert_incoming_webhook_archive(
+                key_manager_state,
+                domain::IncomingWebhookArchive {
+                    webhook_id: webhook_id.into(),
+                    merchant_id: merchant_key_store.merchant_id.clone(),
+                    connector_id_or_name: connector_id_or_name.into(),
+                    connector_name: Some("stripe".into()),
+                    merchant_connector_id: None,
+                    profile_id: None,
+                    request,
+                    event_type: None,
+                    source_verified: Some(true),
+                    processing_status,
+                    error_code: None,
+                    error_message: None,
+                    reprocess_count: 0,
+                    last_reprocessed_at: None,
+                    created_at,
+                    modified_at: created_at,
+                },
+                merchant_key_store,
+            )
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_list_incoming_webhooks() {
+        let (_, state, key_store) = get_state_and_key_store().await;
+        let merchant_account = get_merchant_account(&key_store.merchant_id);
+        let processed = IncomingWebhookProcessingStatus::Processed;
+        let failed = IncomingWebhookProcessingStatus::Failed;
+        insert_archive(
+            &state,
+            &key_store,
+            "wh_1",
+            "stripe",
+            processed,
+            datetime!(2024-01-01 0:00),
+        )
+        .await;
+        insert_archive(
+            &state,
+            &key_store,
+            "wh_2",
+            "stripe",
+            failed,
+            datetime!(2024-01-02 0:00),
+        )
+        .await;
+        insert_archive(
+            &state,
+            &key_store,
+            "wh_3",
+            "stripe",
+            processed,
+            datetime!(2024-01-03 0:00),
+        )
+        .await;
+
+        let list = |constraints| {
+            list_incoming_webhooks(
+                state.clone(),
+                merchant_account.clone(),
+                key_store.clone(),
+                constraints,
+            )
+        };
+        let get_webhook_ids =
+            |response: ApplicationResponse<Vec<archive_types::IncomingWebhookListItemResponse>>| {
+                match response {
+                    ApplicationResponse::Json(archives) => archives
+                        .into_iter()
+                        .map(|archive| archive.webhook_id)
+                        .collect::<Vec<_>>(),
+                    _ => Vec::new(),
+                }
+            };
+
+        // The most recently received webhooks are listed first
+        let response = list(archive_types::IncomingWebhookListConstraints::default())
+            .await
+            .unwrap();
+        assert_eq!(get_webhook_ids(response), vec!["wh_3", "wh_2", "wh_1"]);
+
+        let response = list(archive_types::IncomingWebhookListConstraints {
+            processing_status: Some(failed),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+        assert_eq!(get_webhook_ids(response), vec!["wh_2"]);
+
+        let response = list(archive_types::IncomingWebhookListConstraints {
+            limit: Some(1),
+            offset: Some(1),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+        assert_eq!(get_webhook_ids(response), vec!["wh_2"]);
+
+        // The maximum limit is accepted, larger limits are rejected
+        let limit = u16::try_from(INCOMING_WEBHOOKS_LIST_MAX_LIMIT).unwrap();
+        assert!(list(archive_types::IncomingWebhookListConstraints {
+            limit: Some(limit),
+            ..Default::default()
+        })
+        .await
+        .is_ok());
+        assert!(list(archive_types::IncomingWebhookListConstraints {
+            limit: Some(limit.saturating_add(1)),
+            ..Default::default()
+        })
+        .await
+        .is_err());
+    }
+
+    #[allow(clippy::panic)]
+    #[tokio::test]
+    async fn test_retrieve_incoming_webhook() {
+        let (_, state, key_store) = get_state_and_key_store().await;
+        let merchant_account = get_merchant_account(&key_store.merchant_id);
+        insert_archive(
+            &state,
+            &key_store,
+            "wh_1",
+            "stripe",
+            IncomingWebhookProcessingStatus::Processed,
+            datetime!(2024-01-01 0:00),
+        )
+        .await;
+
+        let response = retrieve_incoming_webhook(
+            state.clone(),
+            merchant_account.clone(),
+            key_store.clone(),
+            "wh_1".into(),
+        )
+        .await
+        .unwrap();
+        let ApplicationResponse::Json(response) = response else {
+            panic!("Unexpected response for retrieving incoming webhook")
+        };
+        assert_eq!(response.webhook_information.webhook_id, "wh_1");
+        assert_eq!(response.request.method, "POST");
+        assert_eq!(response.request.body.expose(), r#"{"id":"evt_archived"}"#);
+
+        let error = retrieve_incoming_webhook(state, merchant_account, key_store, "wh_2".into())
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::GenericNotFoundError { .. }
+        ));
+    }
+
+    #[allow(clippy::panic)]
+    #[tokio::test]
+    async fn test_reprocess_incoming_webhook_records_outcome() {
+        let (app_state, state, key_store) = get_state_and_key_store().await;
+        let merchant_account = get_merchant_account(&key_store.merchant_id);
+        // The merchant connector account the webhook was received for no longer exists, so
+        // reprocessing the webhook fails
+        insert_archive(
+            &state,
+            &key_store,
+            "wh_1",
+            "mca_deleted",
+            IncomingWebhookProcessingStatus::Processed,
+            datetime!(2024-01-01 0:00),
+        )
+        .await;
+
+        for expected_reprocess_count in 1..=2 {
+            let response = reprocess_incoming_webhook(
+                state.clone(),
+                app_state.get_req_state(),
+                merchant_account.clone(),
+                key_store.clone(),
+                "wh_1".into(),
+            )
+            .await
+            .unwrap();
+            let ApplicationResponse::Json(response) = response else {
+                panic!("Unexpected response for reprocessing incoming webhook")
+            };
+            let webhook_information = response.webhook_information;
+            assert_eq!(
+                webhook_information.processing_status,
+                IncomingWebhookProcessingStatus::Failed
+            );
+            assert!(webhook_information.error_code.is_some());
+            assert!(webhook_information.error_message.is_some());
+            assert_eq!(
+                webhook_information.reprocess_count,
+                expected_reprocess_count
+            );
+            assert!(webhook_information.last_reprocessed_at.is_some());
+        }
+
+        // The outcome of reprocessing is stored in the archive
+        let archive = state
+            .store
+            .find_incoming_webhook_archive_by_merchant_id_webhook_id(
+                &(&state).into(),
+                &key_store.merchant_id,
+                "wh_1",
+                &key_store,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            archive.processing_status,
+            IncomingWebhookProcessingStatus::Failed
+        );
+        assert_eq!(archive.reprocess_count, 2);
+    }
+}
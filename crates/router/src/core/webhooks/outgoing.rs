@@ -21,7 +21,7 @@ use router_env::{
     tracing::{self, Instrument},
 };
 
-use super::{types, utils, MERCHANT_ID};
+use super::{endpoint_health, event_ordering, types, utils, MERCHANT_ID};
 #[cfg(feature = "stripe")]
 use crate::compatibility::stripe::webhooks as stripe_webhooks;
 use crate::{
@@ -47,6 +47,23 @@ use crate::{
 };
 
 const OUTGOING_WEBHOOK_TIMEOUT_SECS: u64 = 5;
+const OUTGOING_WEBHOOK_RETRY_TASK: &str = "OUTGOING_WEBHOOK_RETRY";
+
+/// A destination for outgoing webhooks: either the webhook URL configured on the business profile,
+/// or one of the webhook endpoints registered for the business profile.
+enum WebhookDeliveryTarget {
+    BusinessProfile,
+    Endpoint(Box<storage::WebhookEndpoint>),
+}
+
+impl WebhookDeliveryTarget {
+    fn get_webhook_endpoint(&self) -> Option<&storage::WebhookEndpoint> {
+        match self {
+            Self::BusinessProfile => None,
+            Self::Endpoint(endpoint) => Some(endpoint.as_ref()),
+        }
+    }
+}
 
 #[allow(clippy::too_many_arguments)]
 #[instrument(skip_all)]
@@ -62,40 +79,189 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
     content: api::OutgoingWebhookContent,
     primary_object_created_at: Option<time::PrimitiveDateTime>,
 ) -> CustomResult<(), errors::ApiErrorResponse> {
-    let delivery_attempt = enums::WebhookDeliveryAttempt::InitialAttempt;
-    let idempotent_event_id =
-        utils::get_idempotent_event_id(&primary_object_id, event_type, delivery_attempt);
-    let webhook_url_result = get_webhook_url_from_business_profile(&business_profile);
+    if !state.conf.webhooks.outgoing_enabled {
+        logger::debug!(
+            business_profile_id=?business_profile.get_id(),
+            %primary_object_id,
+            ?event_type,
+            "Outgoing webhooks are disabled in application configuration; skipping outgoing \
+             webhooks for event"
+        );
+        return Ok(());
+    }
 
-    if !state.conf.webhooks.outgoing_enabled
-        || webhook_url_result.is_err()
-        || webhook_url_result.as_ref().is_ok_and(String::is_empty)
-    {
+    let delivery_targets =
+        get_webhook_delivery_targets(&state, &business_profile, event_type).await;
+    if delivery_targets.is_empty() {
         logger::debug!(
             business_profile_id=?business_profile.get_id(),
-            %idempotent_event_id,
-            "Outgoing webhooks are disabled in application configuration, or merchant webhook URL \
-             could not be obtained; skipping outgoing webhooks for event"
+            %primary_object_id,
+            ?event_type,
+            "Merchant webhook URL could not be obtained and no webhook endpoints are subscribed \
+             to the event; skipping outgoing webhooks for event"
         );
         return Ok(());
     }
 
+    // The events created for all the delivery targets share the same sequence number
+    let sequence_number =
+        event_ordering::get_next_sequence_number(&state, merchant_key_store, &primary_object_id)
+            .await
+            .inspect_err(|error| {
+                logger::error!(?error, "Failed to obtain sequence number for event");
+            })
+            .ok();
+
+    // Each delivery target gets its own event and retry task, so that a failure in delivering the
+    // webhook to one target does not affect the delivery to the other targets.
+    let mut result = Ok(());
+    for delivery_target in delivery_targets {
+        let target_result = Box::pin(create_event_and_trigger_outgoing_webhook_for_target(
+            state.clone(),
+            &merchant_account,
+            business_profile.clone(),
+            merchant_key_store,
+            event_type,
+            event_class,
+            primary_object_id.clone(),
+            primary_object_type,
+            content.clone(),
+            primary_object_created_at,
+            sequence_number,
+            delivery_target,
+        ))
+        .await
+        .inspect_err(|error| {
+            logger::error!(
+                ?error,
+                "Failed to trigger outgoing webhook for delivery target"
+            );
+        });
+        result = result.and(target_result);
+    }
+
+    result
+}
+
+/// Obtains the destinations to which the outgoing webhook for the specified event type has to be
+/// delivered: the webhook URL configured on the business profile (if any), and the webhook
+/// endpoints of the business profile which are subscribed to the event type.
+async fn get_webhook_delivery_targets(
+    state: &SessionState,
+    business_profile: &domain::Profile,
+    event_type: enums::EventType,
+) -> Vec<WebhookDeliveryTarget> {
+    let mut delivery_targets = Vec::new();
+
+    if get_webhook_url_from_business_profile(business_profile)
+        .is_ok_and(|webhook_url| !webhook_url.is_empty())
+    {
+        delivery_targets.push(WebhookDeliveryTarget::BusinessProfile);
+    }
+
+    match state
+        .store
+        .list_webhook_endpoints_by_merchant_id_profile_id(
+            &business_profile.merchant_id,
+            business_profile.get_id(),
+        )
+        .await
+    {
+        Ok(endpoints) => delivery_targets.extend(
+            endpoints
+                .into_iter()
+                .filter(|endpoint| endpoint.is_subscribed_to(event_type))
+                .map(|endpoint| WebhookDeliveryTarget::Endpoint(Box::new(endpoint))),
+        ),
+        Err(error) => logger::error!(?error, "Failed to list webhook endpoints"),
+    }
+
+    delivery_targets
+}
+
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all)]
+async fn create_event_and_trigger_outgoing_webhook_for_target(
+    state: SessionState,
+    merchant_account: &domain::MerchantAccount,
+    business_profile: domain::Profile,
+    merchant_key_store: &domain::MerchantKeyStore,
+    event_type: enums::EventType,
+    event_class: enums::EventClass,
+    primary_object_id: String,
+    primary_object_type: enums::EventObjectType,
+    content: api::OutgoingWebhookContent,
+    primary_object_created_at: Option<time::PrimitiveDateTime>,
+    sequence_number: Option<i64>,
+    delivery_target: WebhookDeliveryTarget,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    let delivery_attempt = enums::WebhookDeliveryAttempt::InitialAttempt;
+    let webhook_endpoint = delivery_target.get_webhook_endpoint();
+    let webhook_endpoint_id = webhook_endpoint.map(|endpoint| endpoint.endpoint_id.clone());
+    let idempotent_event_id =
+        utils::get_idempotent_event_id(&primary_object_id, event_type, delivery_attempt);
+    let idempotent_event_id = match &webhook_endpoint_id {
+        Some(endpoint_id) => format!("{idempotent_event_id}_{endpoint_id}"),
+        None => idempotent_event_id,
+    };
+
     let event_id = utils::generate_event_id();
     let merchant_id = business_profile.merchant_id.clone();
     let now = common_utils::date_time::now();
 
+    // Webhook endpoints with strict ordering enabled receive the event only after the earlier
+    // events of the object have been delivered; the event references the earlier events which
+    // were pending when it was created.
+    let (previous_event_ids, is_delivery_held) = match (
+        webhook_endpoint.filter(|endpoint| endpoint.strict_ordering),
+        sequence_number,
+    ) {
+        (Some(endpoint), Some(sequence_number)) => {
+            match event_ordering::get_pending_earlier_events(
+                &state,
+                merchant_key_store,
+                &primary_object_id,
+                &endpoint.endpoint_id,
+                sequence_number,
+            )
+            .await
+            {
+                Ok(pending_events) => {
+                    let previous_event_ids = pending_events
+                        .into_iter()
+                        .map(|event| event.event_id)
+                        .collect::<Vec<_>>();
+                    let is_delivery_held = !previous_event_ids.is_empty();
+                    (previous_event_ids, is_delivery_held)
+                }
+                Err(error) => {
+                    // Leave the delivery to the retry task, which checks the order again
+                    logger::error!(?error, "Failed to obtain pending events for object");
+                    (Vec::new(), true)
+                }
+            }
+        }
+        _ => (Vec::new(), false),
+    };
+
     let outgoing_webhook = api::OutgoingWebhook {
         merchant_id: merchant_id.clone(),
         event_id: event_id.clone(),
         event_type,
         content: content.clone(),
         timestamp: now,
+        sequence_number,
+        previous_event_ids,
     };
 
-    let request_content =
-        get_outgoing_webhook_request(&merchant_account, outgoing_webhook, &business_profile)
-            .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
-            .attach_printable("Failed to construct outgoing webhook request content")?;
+    let request_content = get_outgoing_webhook_request(
+        merchant_account,
+        outgoing_webhook,
+        &business_profile,
+        webhook_endpoint,
+    )
+    .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+    .attach_printable("Failed to construct outgoing webhook request content")?;
 
     let event_metadata = storage::EventMetadata::foreign_from(&content);
     let key_manager_state = &(&state).into();
@@ -134,6 +300,9 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
         response: None,
         delivery_attempt: Some(delivery_attempt),
         metadata: Some(event_metadata),
+        webhook_endpoint_id,
+        delivery_latency_ms: None,
+        sequence_number,
     };
 
     let event_insert_result = state
@@ -156,6 +325,16 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
         }
     }?;
 
+    // Events are only recorded for suspended webhook endpoints, they are delivered once the
+    // endpoint is re-enabled
+    if webhook_endpoint.is_some_and(storage::WebhookEndpoint::is_suspended) {
+        logger::debug!(
+            event_id=%event.event_id,
+            "Webhook endpoint is suspended; skipping delivery of outgoing webhook"
+        );
+        return Ok(());
+    }
+
     let process_tracker = add_outgoing_webhook_retry_task_to_process_tracker(
         &*state.store,
         &business_profile,
@@ -170,6 +349,14 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
     })
     .ok();
 
+    if is_delivery_held {
+        logger::debug!(
+            event_id=%event.event_id,
+            "Earlier events of the object are awaiting delivery; holding back outgoing webhook"
+        );
+        return Ok(());
+    }
+
     let cloned_key_store = merchant_key_store.clone();
     // Using a tokio spawn here and not arbiter because not all caller of this function
     // may have an actix arbiter
@@ -244,11 +431,11 @@ async fn trigger_webhook_to_merchant(
     delivery_attempt: enums::WebhookDeliveryAttempt,
     process_tracker: Option<storage::ProcessTracker>,
 ) -> CustomResult<(), errors::WebhooksFlowError> {
-    let webhook_url = match (
-        get_webhook_url_from_business_profile(&business_profile),
+    let webhook_destination = match (
+        get_webhook_destination_for_event(&state, &business_profile, &event).await,
         process_tracker.clone(),
     ) {
-        (Ok(webhook_url), _) => Ok(webhook_url),
+        (Ok(webhook_destination), _) => Ok(webhook_destination),
         (Err(error), Some(process_tracker)) => {
             if !error
                 .current_context()
@@ -269,27 +456,54 @@ async fn trigger_webhook_to_merchant(
         (Err(error), None) => Err(error),
     }?;
 
-    let event_id = event.event_id;
-
-    let headers = request_content
-        .headers
+    // The request content may have been constructed well before this delivery attempt, sign it
+    // again so that the signature timestamp and secrets are current
+    let mut headers = request_content.headers;
+    let body = request_content.body.expose();
+    types::refresh_outgoing_webhook_signatures(
+        &mut headers,
+        &body,
+        &webhook_destination.signing_secrets,
+    )?;
+
+    let headers = headers
         .into_iter()
         .map(|(name, value)| (name, value.into_masked()))
         .collect();
     let request = services::RequestBuilder::new()
         .method(services::Method::Post)
-        .url(&webhook_url)
+        .url(&webhook_destination.url)
         .attach_default_headers()
         .headers(headers)
-        .set_body(RequestContent::RawBytes(
-            request_content.body.expose().into_bytes(),
-        ))
+        .set_body(RequestContent::RawBytes(body.into_bytes()))
         .build();
 
+    let delivery_start_time = std::time::Instant::now();
     let response = state
         .api_client
         .send_request(&state, request, Some(OUTGOING_WEBHOOK_TIMEOUT_SECS), false)
         .await;
+    let delivery_latency_ms = i32::try_from(delivery_start_time.elapsed().as_millis()).ok();
+
+    let is_delivered = response
+        .as_ref()
+        .is_ok_and(|response| response.status().is_success());
+    let is_strictly_ordered = webhook_destination
+        .webhook_endpoint
+        .as_ref()
+        .is_some_and(|endpoint| endpoint.strict_ordering);
+    if let Some(webhook_endpoint) = webhook_destination.webhook_endpoint {
+        let _ = endpoint_health::record_delivery_outcome(
+            &state,
+            merchant_key_store,
+            webhook_endpoint,
+            is_delivered,
+        )
+        .await
+        .inspect_err(|error| {
+            logger::error!(?error, "Failed to record webhook endpoint delivery outcome");
+        });
+    }
 
     metrics::WEBHOOK_OUTGOING_COUNT.add(
         &metrics::CONTEXT,
@@ -308,8 +522,9 @@ async fn trigger_webhook_to_merchant(
                     state.clone(),
                     merchant_key_store.clone(),
                     &business_profile.merchant_id,
-                    &event_id,
+                    &event.event_id,
                     client_error,
+                    delivery_latency_ms,
                     delivery_attempt,
                     ScheduleWebhookRetry::NoSchedule,
                 )
@@ -321,8 +536,9 @@ async fn trigger_webhook_to_merchant(
                     state.clone(),
                     merchant_key_store.clone(),
                     &business_profile.merchant_id,
-                    &event_id,
+                    &event.event_id,
                     response,
+                    delivery_latency_ms,
                 )
                 .await?;
 
@@ -358,8 +574,9 @@ async fn trigger_webhook_to_merchant(
                         state.clone(),
                         merchant_key_store.clone(),
                         &business_profile.merchant_id,
-                        &event_id,
+                        &event.event_id,
                         client_error,
+                        delivery_latency_ms,
                         delivery_attempt,
                         ScheduleWebhookRetry::WithProcessTracker(Box::new(process_tracker)),
                     )
@@ -371,8 +588,9 @@ async fn trigger_webhook_to_merchant(
                         state.clone(),
                         merchant_key_store.clone(),
                         &business_profile.merchant_id,
-                        &event_id,
+                        &event.event_id,
                         response,
+                        delivery_latency_ms,
                     )
                     .await?;
 
@@ -404,8 +622,9 @@ async fn trigger_webhook_to_merchant(
                     state.clone(),
                     merchant_key_store.clone(),
                     &business_profile.merchant_id,
-                    &event_id,
+                    &event.event_id,
                     client_error,
+                    delivery_latency_ms,
                     delivery_attempt,
                     ScheduleWebhookRetry::NoSchedule,
                 )
@@ -417,8 +636,9 @@ async fn trigger_webhook_to_merchant(
                     state.clone(),
                     merchant_key_store.clone(),
                     &business_profile.merchant_id,
-                    &event_id,
+                    &event.event_id,
                     response,
+                    delivery_latency_ms,
                 )
                 .await?;
 
@@ -426,7 +646,7 @@ async fn trigger_webhook_to_merchant(
                     increment_webhook_outgoing_received_count(&business_profile.merchant_id);
                 } else {
                     error_response_handler(
-                        state,
+                        state.clone(),
                         &business_profile.merchant_id,
                         delivery_attempt,
                         status_code.as_u16(),
@@ -439,6 +659,17 @@ async fn trigger_webhook_to_merchant(
         },
     }
 
+    if is_delivered && is_strictly_ordered {
+        let _ = event_ordering::release_next_held_event(&state, merchant_key_store, &event)
+            .await
+            .inspect_err(|error| {
+                logger::error!(
+                    ?error,
+                    "Failed to release event held back for strict ordering"
+                );
+            });
+    }
+
     Ok(())
 }
 
@@ -543,17 +774,14 @@ pub(crate) async fn add_outgoing_webhook_retry_task_to_process_tracker(
     };
 
     let runner = storage::ProcessTrackerRunner::OutgoingWebhookRetryWorkflow;
-    let task = "OUTGOING_WEBHOOK_RETRY";
     let tag = ["OUTGOING_WEBHOOKS"];
-    let process_tracker_id = scheduler::utils::get_process_tracker_id(
-        runner,
-        task,
-        &event.event_id,
+    let process_tracker_id = get_outgoing_webhook_retry_process_tracker_id(
         &business_profile.merchant_id,
+        &event.event_id,
     );
     let process_tracker_entry = storage::ProcessTrackerNew::new(
         process_tracker_id,
-        task,
+        OUTGOING_WEBHOOK_RETRY_TASK,
         runner,
         tag,
         tracking_data,
@@ -581,6 +809,65 @@ pub(crate) async fn add_outgoing_webhook_retry_task_to_process_tracker(
     }
 }
 
+pub(super) fn get_outgoing_webhook_retry_process_tracker_id(
+    merchant_id: &common_utils::id_type::MerchantId,
+    event_id: &str,
+) -> String {
+    scheduler::utils::get_process_tracker_id(
+        storage::ProcessTrackerRunner::OutgoingWebhookRetryWorkflow,
+        OUTGOING_WEBHOOK_RETRY_TASK,
+        event_id,
+        merchant_id,
+    )
+}
+
+/// The destination to which the webhook for an event is delivered.
+struct WebhookDestination {
+    url: String,
+    signing_secrets: types::WebhookSigningSecrets,
+    webhook_endpoint: Option<storage::WebhookEndpoint>,
+}
+
+/// Obtains the URL to which the webhook for the event has to be delivered, along with the secrets
+/// used for signing it: those of the webhook endpoint the event was created for, or those
+/// configured on the business profile.
+async fn get_webhook_destination_for_event(
+    state: &SessionState,
+    business_profile: &domain::Profile,
+    event: &domain::Event,
+) -> CustomResult<WebhookDestination, errors::WebhooksFlowError> {
+    let Some(endpoint_id) = &event.webhook_endpoint_id else {
+        return get_webhook_url_from_business_profile(business_profile).map(|url| {
+            WebhookDestination {
+                url,
+                signing_secrets: types::WebhookSigningSecrets::from_business_profile(
+                    business_profile,
+                ),
+                webhook_endpoint: None,
+            }
+        });
+    };
+
+    let endpoint = state
+        .store
+        .find_webhook_endpoint_by_merchant_id_endpoint_id(
+            &business_profile.merchant_id,
+            endpoint_id,
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::WebhookEndpointNotFound)?;
+
+    if endpoint.is_enabled {
+        Ok(WebhookDestination {
+            url: endpoint.url.clone(),
+            signing_secrets: types::WebhookSigningSecrets::from_webhook_endpoint(&endpoint),
+            webhook_endpoint: Some(endpoint),
+        })
+    } else {
+        Err(report!(errors::WebhooksFlowError::WebhookEndpointDisabled))
+    }
+}
+
 fn get_webhook_url_from_business_profile(
     business_profile: &domain::Profile,
 ) -> CustomResult<String, errors::WebhooksFlowError> {
@@ -597,15 +884,20 @@ fn get_webhook_url_from_business_profile(
         .map(ExposeInterface::expose)
 }
 
+/// Constructs the outgoing webhook request. Webhooks delivered to a webhook endpoint are signed
+/// using the secrets of the endpoint, the remaining ones using the payment response hash keys of
+/// the business profile.
 pub(crate) fn get_outgoing_webhook_request(
     merchant_account: &domain::MerchantAccount,
     outgoing_webhook: api::OutgoingWebhook,
     business_profile: &domain::Profile,
+    webhook_endpoint: Option<&storage::WebhookEndpoint>,
 ) -> CustomResult<OutgoingWebhookRequestContent, errors::WebhooksFlowError> {
     #[inline]
     fn get_outgoing_webhook_request_inner<WebhookType: types::OutgoingWebhookType>(
         outgoing_webhook: api::OutgoingWebhook,
         business_profile: &domain::Profile,
+        webhook_endpoint: Option<&storage::WebhookEndpoint>,
     ) -> CustomResult<OutgoingWebhookRequestContent, errors::WebhooksFlowError> {
         let mut headers = vec![
             (
@@ -619,7 +911,10 @@ pub(crate) fn get_outgoing_webhook_request(
         ];
 
         let transformed_outgoing_webhook = WebhookType::from(outgoing_webhook);
-        let payment_response_hash_key = business_profile.payment_response_hash_key.clone();
+        let signing_secrets = match webhook_endpoint {
+            Some(endpoint) => types::WebhookSigningSecrets::from_webhook_endpoint(endpoint),
+            None => types::WebhookSigningSecrets::from_business_profile(business_profile),
+        };
         let custom_headers = business_profile
             .outgoing_webhook_custom_http_headers
             .clone()
@@ -638,12 +933,15 @@ pub(crate) fn get_outgoing_webhook_request(
                     .map(|(key, value)| (key.clone(), value.clone().into_masked())),
             );
         };
-        let outgoing_webhooks_signature = transformed_outgoing_webhook
-            .get_outgoing_webhooks_signature(payment_response_hash_key)?;
+        let outgoing_webhooks_signature =
+            transformed_outgoing_webhook.get_outgoing_webhooks_signature(&signing_secrets)?;
 
         if let Some(signature) = outgoing_webhooks_signature.signature {
             WebhookType::add_webhook_header(&mut headers, signature)
         }
+        if let Some(timestamped_signature) = outgoing_webhooks_signature.timestamped_signature {
+            WebhookType::add_timestamped_webhook_header(&mut headers, timestamped_signature)
+        }
 
         Ok(OutgoingWebhookRequestContent {
             body: outgoing_webhooks_signature.payload,
@@ -656,12 +954,17 @@ pub(crate) fn get_outgoing_webhook_request(
 
     match merchant_account.get_compatible_connector() {
         #[cfg(feature = "stripe")]
-        Some(api_models::enums::Connector::Stripe) => get_outgoing_webhook_request_inner::<
-            stripe_webhooks::StripeOutgoingWebhook,
-        >(outgoing_webhook, business_profile),
+        Some(api_models::enums::Connector::Stripe) => {
+            get_outgoing_webhook_request_inner::<stripe_webhooks::StripeOutgoingWebhook>(
+                outgoing_webhook,
+                business_profile,
+                webhook_endpoint,
+            )
+        }
         _ => get_outgoing_webhook_request_inner::<webhooks::OutgoingWebhook>(
             outgoing_webhook,
             business_profile,
+            webhook_endpoint,
         ),
     }
 }
@@ -678,6 +981,7 @@ async fn update_event_if_client_error(
     merchant_id: &common_utils::id_type::MerchantId,
     event_id: &str,
     error_message: String,
+    delivery_latency_ms: Option<i32>,
 ) -> CustomResult<domain::Event, errors::WebhooksFlowError> {
     let is_webhook_notified = false;
     let key_manager_state = &(&state).into();
@@ -710,6 +1014,7 @@ async fn update_event_if_client_error(
             .change_context(errors::WebhooksFlowError::WebhookEventUpdationFailed)
             .attach_printable("Failed to encrypt outgoing webhook response content")?,
         ),
+        delivery_latency_ms,
     };
 
     state
@@ -731,6 +1036,7 @@ async fn api_client_error_handler(
     merchant_id: &common_utils::id_type::MerchantId,
     event_id: &str,
     client_error: error_stack::Report<errors::ApiClientError>,
+    delivery_latency_ms: Option<i32>,
     delivery_attempt: enums::WebhookDeliveryAttempt,
     schedule_webhook_retry: ScheduleWebhookRetry,
 ) -> CustomResult<(), errors::WebhooksFlowError> {
@@ -742,6 +1048,7 @@ async fn api_client_error_handler(
         merchant_id,
         event_id,
         "Unable to send request to merchant server".to_string(),
+        delivery_latency_ms,
     )
     .await?;
 
@@ -772,6 +1079,7 @@ async fn update_event_in_storage(
     merchant_id: &common_utils::id_type::MerchantId,
     event_id: &str,
     response: reqwest::Response,
+    delivery_latency_ms: Option<i32>,
 ) -> CustomResult<domain::Event, errors::WebhooksFlowError> {
     let status_code = response.status();
     let is_webhook_notified = status_code.is_success();
@@ -832,6 +1140,7 @@ async fn update_event_in_storage(
             .change_context(errors::WebhooksFlowError::WebhookEventUpdationFailed)
             .attach_printable("Failed to encrypt outgoing webhook response content")?,
         ),
+        delivery_latency_ms,
     };
     state
         .store
@@ -935,6 +1244,21 @@ impl ForeignFrom<&api::OutgoingWebhookContent> for storage::EventMetadata {
             webhooks::OutgoingWebhookContent::PayoutDetails(payout_response) => Self::Payout {
                 payout_id: payout_response.payout_id.clone(),
             },
+            webhooks::OutgoingWebhookContent::SubscriptionDetails(subscription_response) => {
+                Self::Subscription {
+                    subscription_id: subscription_response.subscription_id.clone(),
+                }
+            }
+            webhooks::OutgoingWebhookContent::PaymentMethodDetails(payment_method_response) => {
+                Self::PaymentMethod {
+                    payment_method_id: payment_method_response.payment_method_id.clone(),
+                }
+            }
+            webhooks::OutgoingWebhookContent::SuccessRateAlertDetails(alert) => {
+                Self::SuccessRateAlert {
+                    alert_id: alert.alert_id.clone(),
+                }
+            }
         }
     }
 }
@@ -979,5 +1303,522 @@ fn get_outgoing_webhook_event_content_from_event_metadata(
             mandate_id,
             content: serde_json::Value::Null,
         },
+        diesel_models::EventMetadata::Subscription { subscription_id } => {
+            OutgoingWebhookEventContent::Subscription {
+                subscription_id,
+                content: serde_json::Value::Null,
+            }
+        }
+        diesel_models::EventMetadata::PaymentMethod { payment_method_id } => {
+            OutgoingWebhookEventContent::PaymentMethod {
+                payment_method_id,
+                content: serde_json::Value::Null,
+            }
+        }
+        diesel_models::EventMetadata::SuccessRateAlert { alert_id } => {
+            OutgoingWebhookEventContent::SuccessRateAlert {
+                alert_id,
+                content: serde_json::Value::Null,
+            }
+        }
     })
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+    use std::{collections::HashSet, sync::Arc};
+
+    use common_utils::types::MinorUnit;
+    use time::macros::datetime;
+
+    use super::*;
+    use crate::routes::{
+        self,
+        app::{settings::Settings, StorageImpl},
+    };
+
+    const REFUND_ID: &str = "ref_fan_out";
+
+    async fn get_state_and_key_store() -> (SessionState, domain::MerchantKeyStore) {
+        let mut conf = Settings::default();
+        conf.webhooks.outgoing_enabled = true;
+        let (tx, _) = tokio::sync::oneshot::channel();
+        let app_state = Box::pin(routes::AppState::with_storage(
+            conf,
+            StorageImpl::Mock,
+            tx,
+            Box::new(services::MockApiClient),
+        ))
+        .await;
+        let state = Arc::new(app_state)
+            .get_session_state("public", || {})
+            .unwrap();
+
+        let merchant_id =
+            common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1"))
+                .unwrap();
+        let key_manager_state = &(&state).into();
+        let master_key = state.store.get_master_key();
+        let merchant_key_store = state
+            .store
+            .insert_merchant_key_store(
+                key_manager_state,
+                domain::MerchantKeyStore {
+                    merchant_id: merchant_id.clone(),
+                    key: crypto_operation(
+                        key_manager_state,
+                        type_name!(domain::MerchantKeyStore),
+                        CryptoOperation::Encrypt(
+                            services::generate_aes256_key().unwrap().to_vec().into(),
+                        ),
+                        Identifier::Merchant(merchant_id.clone()),
+                        master_key,
+                    )
+                    .await
+                    .and_then(|val| val.try_into_operation())
+                    .unwrap(),
+                    created_at: datetime!(2023-02-01 0:00),
+                },
+                &master_key.to_vec().into(),
+            )
+            .await
+            .unwrap();
+
+        (state, merchant_key_store)
+    }
+
+    fn get_merchant_account(
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> domain::MerchantAccount {
+        let now = common_utils::date_time::now();
+        domain::MerchantAccount::from(domain::MerchantAccountSetter {
+            merchant_id: merchant_id.clone(),
+            return_url: None,
+            enable_payment_response_hash: false,
+            payment_response_hash_key: None,
+            redirect_to_merchant_with_http_post: false,
+            merchant_name: None,
+            merchant_details: None,
+            webhook_details: None,
+            sub_merchants_enabled: None,
+            parent_merchant_id: None,
+            publishable_key: "pk_fan_out".into(),
+            storage_scheme: enums::MerchantStorageScheme::PostgresOnly,
+            locker_id: None,
+            metadata: None,
+            routing_algorithm: None,
+            primary_business_details: serde_json::json!([]),
+            frm_routing_algorithm: None,
+            created_at: now,
+            modified_at: now,
+            intent_fulfillment_time: None,
+            payout_routing_algorithm: None,
+            organization_id: common_utils::id_type::OrganizationId::default(),
+            is_recon_enabled: false,
+            default_profile: None,
+            recon_status: enums::ReconStatus::NotRequested,
+            payment_link_config: None,
+            pm_collect_link_config: None,
+            version: common_enums::ApiVersion::V1,
+        })
+    }
+
+    /// A business profile without a webhook URL, so that webhooks are delivered to its webhook
+    /// endpoints only.
+    fn get_business_profile(merchant_id: &common_utils::id_type::MerchantId) -> domain::Profile {
+        let now = common_utils::date_time::now();
+        domain::Profile::from(domain::ProfileSetter {
+            profile_id: common_utils::id_type::ProfileId::try_from(std::borrow::Cow::from(
+                "pro_fan_out",
+            ))
+            .unwrap(),
+            merchant_id: merchant_id.clone(),
+            profile_name: "default".into(),
+            created_at: now,
+            modified_at: now,
+            return_url: None,
+            enable_payment_response_hash: false,
+            payment_response_hash_key: None,
+            redirect_to_merchant_with_http_post: false,
+            webhook_details: None,
+            metadata: None,
+            routing_algorithm: None,
+            intent_fulfillment_time: None,
+            frm_routing_algorithm: None,
+            payout_routing_algorithm: None,
+            is_recon_enabled: false,
+            applepay_verified_domains: None,
+            payment_link_config: None,
+            session_expiry: None,
+            authentication_connector_details: None,
+            payout_link_config: None,
+            is_extended_card_info_enabled: None,
+            extended_card_info_config: None,
+            is_connector_agnostic_mit_enabled: None,
+            use_billing_as_payment_method_billing: None,
+            collect_shipping_details_from_wallet_connector: None,
+            collect_billing_details_from_wallet_connector: None,
+            outgoing_webhook_custom_http_headers: None,
+            always_collect_billing_details_from_wallet_connector: None,
+            always_collect_shipping_details_from_wallet_connector: None,
+            tax_connector_id: None,
+            is_tax_connector_enabled: false,
+            dynamic_routing_algorithm: None,
+            is_network_tokenization_enabled: false,
+            is_auto_retries_enabled: false,
+            max_auto_retries_enabled: None,
+            previous_payment_response_hash_key: None,
+            previous_payment_response_hash_key_expires_at: None,
+        })
+    }
+
+    async fn insert_webhook_endpoint(
+        state: &SessionState,
+        business_profile: &domain::Profile,
+        endpoint_id: &str,
+        enabled_events: Vec<enums::EventType>,
+    ) -> storage::WebhookEndpoint {
+        let now = common_utils::date_time::now();
+        state
+            .store
+            .insert_webhook_endpoint(storage::WebhookEndpointNew {
+                endpoint_id: endpoint_id.into(),
+                merchant_id: business_profile.merchant_id.clone(),
+                profile_id: business_profile.get_id().to_owned(),
+                url: format!("https://example.com/webhooks/{endpoint_id}"),
+                secret: Secret::new(format!("whsec_{endpoint_id}")),
+                enabled_events,
+                is_enabled: true,
+                description: None,
+                created_at: now,
+                modified_at: now,
+                previous_secret: None,
+                previous_secret_expires_at: None,
+                consecutive_failures: 0,
+                last_successful_delivery_at: None,
+                last_failed_delivery_at: None,
+                suspended_at: None,
+                strict_ordering: false,
+            })
+            .await
+            .unwrap()
+    }
+
+    async fn trigger_refund_webhook(
+        state: &SessionState,
+        merchant_key_store: &domain::MerchantKeyStore,
+        business_profile: &domain::Profile,
+        event_type: enums::EventType,
+    ) {
+        let now = common_utils::date_time::now();
+        let status = match event_type {
+            enums::EventType::RefundFailed => api_models::refunds::RefundStatus::Failed,
+            _ => api_models::refunds::RefundStatus::Succeeded,
+        };
+        let content = api::OutgoingWebhookContent::RefundDetails(Box::new(
+            api_models::refunds::RefundResponse {
+                refund_id: REFUND_ID.into(),
+                payment_id: common_utils::id_type::PaymentId::try_from(std::borrow::Cow::Borrowed(
+                    "pay_fan_out",
+                ))
+                .unwrap(),
+                amount: MinorUnit::new(100),
+                currency: "USD".into(),
+                status,
+                reason: None,
+                metadata: None,
+                error_message: None,
+                error_code: None,
+                created_at: Some(now),
+                updated_at: Some(now),
+                connector: "stripe".into(),
+                profile_id: Some(business_profile.get_id().to_owned()),
+                merchant_connector_id: None,
+                charges: None,
+            },
+        ));
+
+        create_event_and_trigger_outgoing_webhook(
+            state.clone(),
+            get_merchant_account(&merchant_key_store.merchant_id),
+            business_profile.clone(),
+            merchant_key_store,
+            event_type,
+            enums::EventClass::Refunds,
+            REFUND_ID.into(),
+            enums::EventObjectType::RefundDetails,
+            content,
+            Some(now),
+        )
+        .await
+        .unwrap();
+    }
+
+    async fn list_refund_events(
+        state: &SessionState,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> Vec<domain::Event> {
+        state
+            .store
+            .list_initial_events_by_merchant_id_primary_object_id(
+                &state.into(),
+                &merchant_key_store.merchant_id,
+                REFUND_ID,
+                merchant_key_store,
+            )
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_outgoing_webhook_is_fanned_out_to_subscribed_endpoints() {
+        let (state, key_store) = get_state_and_key_store().await;
+        let business_profile = get_business_profile(&key_store.merchant_id);
+        let refund_events = vec![
+            enums::EventType::RefundSucceeded,
+            enums::EventType::RefundFailed,
+        ];
+        insert_webhook_endpoint(&state, &business_profile, "whe_1", refund_events.clone()).await;
+        insert_webhook_endpoint(&state, &business_profile, "whe_2", refund_events).await;
+        insert_webhook_endpoint(
+            &state,
+            &business_profile,
+            "whe_payments",
+            vec![enums::EventType::PaymentSucceeded],
+        )
+        .await;
+
+        trigger_refund_webhook(
+            &state,
+            &key_store,
+            &business_profile,
+            enums::EventType::RefundSucceeded,
+        )
+        .await;
+
+        // One event per subscribed endpoint, none for the endpoint not subscribed to the event
+        let events = list_refund_events(&state, &key_store).await;
+        let endpoint_ids = events
+            .iter()
+            .map(|event| event.webhook_endpoint_id.clone().unwrap())
+            .collect::<HashSet<_>>();
+        assert_eq!(events.len(), 2);
+        assert_eq!(
+            endpoint_ids,
+            HashSet::from(["whe_1".to_string(), "whe_2".to_string()])
+        );
+
+        // The event IDs and idempotent event IDs are unique per endpoint
+        let event_ids = events
+            .iter()
+            .map(|event| event.event_id.clone())
+            .collect::<HashSet<_>>();
+        assert_eq!(event_ids.len(), 2);
+        let idempotent_event_ids = events
+            .iter()
+            .map(|event| event.idempotent_event_id.clone().unwrap())
+            .collect::<HashSet<_>>();
+        assert_eq!(idempotent_event_ids.len(), 2);
+        for event in &events {
+            let endpoint_id = event.webhook_endpoint_id.as_deref().unwrap();
+            assert!(event
+                .idempotent_event_id
+                .as_deref()
+                .unwrap()
+                .ends_with(&format!("_{endpoint_id}")));
+
+            // Each event is retried independently of the others
+            let process = state
+                .store
+                .find_process_by_id(&get_outgoing_webhook_retry_process_tracker_id(
+                    &key_store.merchant_id,
+                    &event.event_id,
+                ))
+                .await
+                .unwrap();
+            assert!(process.is_some());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_outgoing_webhook_is_not_duplicated_for_endpoints() {
+        let (state, key_store) = get_state_and_key_store().await;
+        let business_profile = get_business_profile(&key_store.merchant_id);
+        let refund_events = vec![enums::EventType::RefundSucceeded];
+        insert_webhook_endpoint(&state, &business_profile, "whe_1", refund_events.clone()).await;
+        insert_webhook_endpoint(&state, &business_profile, "whe_2", refund_events).await;
+
+        trigger_refund_webhook(
+            &state,
+            &key_store,
+            &business_profile,
+            enums::EventType::RefundSucceeded,
+        )
+        .await;
+        let events = list_refund_events(&state, &key_store).await;
+        assert_eq!(events.len(), 2);
+
+        // Triggering the webhook for the same event again does not create new events for the
+        // endpoints
+        trigger_refund_webhook(
+            &state,
+            &key_store,
+            &business_profile,
+            enums::EventType::RefundSucceeded,
+        )
+        .await;
+        let events_after_retrigger = list_refund_events(&state, &key_store).await;
+        let event_ids = events
+            .iter()
+            .map(|event| event.event_id.clone())
+            .collect::<HashSet<_>>();
+        let event_ids_after_retrigger = events_after_retrigger
+            .iter()
+            .map(|event| event.event_id.clone())
+            .collect::<HashSet<_>>();
+        assert_eq!(event_ids_after_retrigger, event_ids);
+    }
+
+    async fn suspend_webhook_endpoint(
+        state: &SessionState,
+        merchant_key_store: &domain::MerchantKeyStore,
+        endpoint_id: &str,
+    ) {
+        state
+            .store
+            .update_webhook_endpoint_by_merchant_id_endpoint_id(
+                &merchant_key_store.merchant_id,
+                endpoint_id,
+                storage::WebhookEndpointUpdate::Suspend {
+                    suspended_at: common_utils::date_time::now(),
+                },
+            )
+            .await
+            .unwrap();
+    }
+
+    async fn find_retry_task(
+        state: &SessionState,
+        merchant_key_store: &domain::MerchantKeyStore,
+        event_id: &str,
+    ) -> Option<storage::ProcessTracker> {
+        state
+            .store
+            .find_process_by_id(&get_outgoing_webhook_retry_process_tracker_id(
+                &merchant_key_store.merchant_id,
+                event_id,
+            ))
+            .await
+            .unwrap()
+    }
+
+    #[allow(clippy::panic)]
+    #[tokio::test]
+    async fn test_suspended_endpoint_receives_no_delivery() {
+        let (state, key_store) = get_state_and_key_store().await;
+        let business_profile = get_business_profile(&key_store.merchant_id);
+        let refund_events = vec![enums::EventType::RefundSucceeded];
+        insert_webhook_endpoint(
+            &state,
+            &business_profile,
+            "whe_active",
+            refund_events.clone(),
+        )
+        .await;
+        insert_webhook_endpoint(&state, &business_profile, "whe_suspended", refund_events).await;
+        suspend_webhook_endpoint(&state, &key_store, "whe_suspended").await;
+
+        trigger_refund_webhook(
+            &state,
+            &key_store,
+            &business_profile,
+            enums::EventType::RefundSucceeded,
+        )
+        .await;
+
+        // The event is recorded for the suspended endpoint, but only scheduled for delivery to
+        // the active endpoint
+        let events = list_refund_events(&state, &key_store).await;
+        assert_eq!(events.len(), 2);
+        for event in &events {
+            let retry_task = find_retry_task(&state, &key_store, &event.event_id).await;
+            match event.webhook_endpoint_id.as_deref() {
+                Some("whe_active") => assert!(retry_task.is_some()),
+                Some("whe_suspended") => assert!(retry_task.is_none()),
+                endpoint_id => panic!("Unexpected webhook endpoint `{endpoint_id:?}`"),
+            }
+        }
+    }
+
+    #[cfg(feature = "olap")]
+    #[allow(clippy::panic)]
+    #[tokio::test]
+    async fn test_reenabled_endpoint_resumes_delivery() {
+        let (state, key_store) = get_state_and_key_store().await;
+        let business_profile = get_business_profile(&key_store.merchant_id);
+        insert_webhook_endpoint(
+            &state,
+            &business_profile,
+            "whe_suspended",
+            vec![
+                enums::EventType::RefundSucceeded,
+                enums::EventType::RefundFailed,
+            ],
+        )
+        .await;
+        suspend_webhook_endpoint(&state, &key_store, "whe_suspended").await;
+
+        trigger_refund_webhook(
+            &state,
+            &key_store,
+            &business_profile,
+            enums::EventType::RefundSucceeded,
+        )
+        .await;
+
+        // The event missed while the endpoint was suspended is replayed when it is re-enabled
+        let response = super::super::webhook_endpoints::reenable_webhook_endpoint(
+            state.clone(),
+            get_merchant_account(&key_store.merchant_id),
+            key_store.clone(),
+            business_profile.get_id().to_owned(),
+            "whe_suspended".into(),
+        )
+        .await
+        .unwrap();
+        let services::ApplicationResponse::Json(response) = response else {
+            panic!("Unexpected response for re-enabling webhook endpoint");
+        };
+        assert_eq!(response.replayed_events, 1);
+
+        let endpoint = state
+            .store
+            .find_webhook_endpoint_by_merchant_id_endpoint_id(
+                &key_store.merchant_id,
+                "whe_suspended",
+            )
+            .await
+            .unwrap();
+        assert!(endpoint.is_enabled);
+        assert!(!endpoint.is_suspended());
+
+        // Events created after the endpoint is re-enabled are scheduled for delivery again
+        trigger_refund_webhook(
+            &state,
+            &key_store,
+            &business_profile,
+            enums::EventType::RefundFailed,
+        )
+        .await;
+        let event = list_refund_events(&state, &key_store)
+            .await
+            .into_iter()
+            .find(|event| event.event_type == enums::EventType::RefundFailed)
+            .unwrap();
+        assert_eq!(event.webhook_endpoint_id.as_deref(), Some("whe_suspended"));
+        assert!(find_retry_task(&state, &key_store, &event.event_id)
+            .await
+            .is_some());
+    }
+}
@@ -1,14 +1,187 @@
 use api_models::webhooks;
-use common_utils::{crypto::SignMessage, ext_traits::Encode};
+use common_utils::{crypto::SignMessage, date_time, ext_traits::Encode};
 use error_stack::ResultExt;
-use masking::Secret;
+use masking::{PeekInterface, Secret};
 use serde::Serialize;
 
-use crate::{core::errors, headers, services::request::Maskable, types::storage::enums};
+use crate::{
+    core::errors,
+    headers,
+    services::request::Maskable,
+    types::{domain, storage, storage::enums},
+};
 
 pub struct OutgoingWebhookPayloadWithSignature {
     pub payload: Secret<String>,
     pub signature: Option<String>,
+    pub timestamped_signature: Option<String>,
+}
+
+/// The secrets used for signing outgoing webhooks. While a secret is being rotated, the previous
+/// secret remains valid until it expires, and webhooks are signed using both secrets.
+#[derive(Clone, Debug, Default)]
+pub struct WebhookSigningSecrets {
+    current: Option<Secret<String>>,
+    previous: Option<Secret<String>>,
+}
+
+impl WebhookSigningSecrets {
+    pub fn new(
+        current: Option<Secret<String>>,
+        previous: Option<Secret<String>>,
+        previous_expires_at: Option<time::PrimitiveDateTime>,
+    ) -> Self {
+        let now = date_time::now();
+        let previous =
+            previous.filter(|_| previous_expires_at.is_some_and(|expires_at| expires_at > now));
+
+        Self { current, previous }
+    }
+
+    pub fn from_business_profile(business_profile: &domain::Profile) -> Self {
+        Self::new(
+            business_profile
+                .payment_response_hash_key
+                .clone()
+                .map(Secret::new),
+            business_profile
+                .previous_payment_response_hash_key
+                .clone()
+                .map(Secret::new),
+            business_profile.previous_payment_response_hash_key_expires_at,
+        )
+    }
+
+    pub fn from_webhook_endpoint(webhook_endpoint: &storage::WebhookEndpoint) -> Self {
+        Self::new(
+            Some(webhook_endpoint.secret.clone()),
+            webhook_endpoint.previous_secret.clone(),
+            webhook_endpoint.previous_secret_expires_at,
+        )
+    }
+
+    pub fn get_current_secret(&self) -> Option<&Secret<String>> {
+        self.current.as_ref()
+    }
+
+    fn iter(&self) -> impl Iterator<Item = &Secret<String>> {
+        self.current.iter().chain(self.previous.iter())
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum WebhookSignatureAlgorithm {
+    HmacSha256,
+    HmacSha512,
+}
+
+impl WebhookSignatureAlgorithm {
+    fn sign(
+        self,
+        secret: &Secret<String>,
+        message: &[u8],
+    ) -> errors::CustomResult<String, errors::WebhooksFlowError> {
+        let key = secret.peek().as_bytes();
+        match self {
+            Self::HmacSha256 => common_utils::crypto::HmacSha256.sign_message(key, message),
+            Self::HmacSha512 => common_utils::crypto::HmacSha512.sign_message(key, message),
+        }
+        .map(hex::encode)
+        .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
+        .attach_printable("Failed to sign the message")
+    }
+}
+
+/// Signs the webhook payload using the current secret, without a timestamp.
+pub fn get_payload_signature(
+    payload: &str,
+    signing_secrets: &WebhookSigningSecrets,
+    algorithm: WebhookSignatureAlgorithm,
+) -> errors::CustomResult<Option<String>, errors::WebhooksFlowError> {
+    signing_secrets
+        .get_current_secret()
+        .map(|secret| algorithm.sign(secret, payload.as_bytes()))
+        .transpose()
+}
+
+/// Signs `{timestamp}.{payload}` using each of the active secrets, and constructs a signature of
+/// the form `t={timestamp},v1={signature}[,v1={signature}]`. The timestamp allows the recipient
+/// to reject replayed webhooks, and the signature computed using the previous secret allows the
+/// recipient to continue verifying webhooks while a secret is being rotated.
+pub fn get_timestamped_signature(
+    payload: &str,
+    signing_secrets: &WebhookSigningSecrets,
+    algorithm: WebhookSignatureAlgorithm,
+) -> errors::CustomResult<Option<String>, errors::WebhooksFlowError> {
+    let timestamp = date_time::now_unix_timestamp();
+    let signed_payload = format!("{timestamp}.{payload}");
+
+    let signatures = signing_secrets
+        .iter()
+        .map(|secret| {
+            algorithm
+                .sign(secret, signed_payload.as_bytes())
+                .map(|signature| format!("v1={signature}"))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok((!signatures.is_empty()).then(|| format!("t={timestamp},{}", signatures.join(","))))
+}
+
+/// Recomputes the signature headers of a stored outgoing webhook request, so that the timestamp
+/// reflects the time of delivery and the signatures reflect the currently active secrets. The
+/// timestamped signature header is added if the request was stored without one, such as when it
+/// was constructed before a secret was configured.
+pub fn refresh_outgoing_webhook_signatures(
+    headers: &mut Vec<(String, Secret<String>)>,
+    payload: &str,
+    signing_secrets: &WebhookSigningSecrets,
+) -> errors::CustomResult<(), errors::WebhooksFlowError> {
+    let mut has_timestamped_signature = false;
+    for (name, value) in headers.iter_mut() {
+        let signature = if name.eq_ignore_ascii_case(headers::X_WEBHOOK_SIGNATURE) {
+            get_payload_signature(
+                payload,
+                signing_secrets,
+                WebhookSignatureAlgorithm::HmacSha512,
+            )?
+        } else if name.eq_ignore_ascii_case(headers::X_WEBHOOK_SIGNATURE_TIMESTAMPED) {
+            has_timestamped_signature = true;
+            get_timestamped_signature(
+                payload,
+                signing_secrets,
+                WebhookSignatureAlgorithm::HmacSha512,
+            )?
+        } else if name.eq_ignore_ascii_case(headers::STRIPE_COMPATIBLE_WEBHOOK_SIGNATURE) {
+            has_timestamped_signature = true;
+            get_timestamped_signature(
+                payload,
+                signing_secrets,
+                WebhookSignatureAlgorithm::HmacSha256,
+            )?
+        } else {
+            None
+        };
+
+        if let Some(signature) = signature {
+            *value = Secret::new(signature);
+        }
+    }
+
+    if !has_timestamped_signature {
+        if let Some(signature) = get_timestamped_signature(
+            payload,
+            signing_secrets,
+            WebhookSignatureAlgorithm::HmacSha512,
+        )? {
+            headers.push((
+                headers::X_WEBHOOK_SIGNATURE_TIMESTAMPED.to_string(),
+                Secret::new(signature),
+            ));
+        }
+    }
+
+    Ok(())
 }
 
 pub trait OutgoingWebhookType:
@@ -16,44 +189,62 @@ pub trait OutgoingWebhookType:
 {
     fn get_outgoing_webhooks_signature(
         &self,
-        payment_response_hash_key: Option<impl AsRef<[u8]>>,
+        signing_secrets: &WebhookSigningSecrets,
     ) -> errors::CustomResult<OutgoingWebhookPayloadWithSignature, errors::WebhooksFlowError>;
 
-    fn add_webhook_header(header: &mut Vec<(String, Maskable<String>)>, signature: String);
+    fn add_webhook_header(header: &mut Vec<(String, Maskable<String>)>, signature: String) {
+        header.push((headers::X_WEBHOOK_SIGNATURE.to_string(), signature.into()))
+    }
+
+    fn add_timestamped_webhook_header(
+        header: &mut Vec<(String, Maskable<String>)>,
+        timestamped_signature: String,
+    ) {
+        header.push((
+            headers::X_WEBHOOK_SIGNATURE_TIMESTAMPED.to_string(),
+            timestamped_signature.into(),
+        ))
+    }
 }
 
 impl OutgoingWebhookType for webhooks::OutgoingWebhook {
     fn get_outgoing_webhooks_signature(
         &self,
-        payment_response_hash_key: Option<impl AsRef<[u8]>>,
+        signing_secrets: &WebhookSigningSecrets,
     ) -> errors::CustomResult<OutgoingWebhookPayloadWithSignature, errors::WebhooksFlowError> {
         let webhook_signature_payload = self
             .encode_to_string_of_json()
             .change_context(errors::WebhooksFlowError::OutgoingWebhookEncodingFailed)
             .attach_printable("failed encoding outgoing webhook payload")?;
 
-        let signature = payment_response_hash_key
-            .map(|key| {
-                common_utils::crypto::HmacSha512::sign_message(
-                    &common_utils::crypto::HmacSha512,
-                    key.as_ref(),
-                    webhook_signature_payload.as_bytes(),
-                )
-            })
-            .transpose()
-            .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
-            .attach_printable("Failed to sign the message")?
-            .map(hex::encode);
+        let signature = get_payload_signature(
+            &webhook_signature_payload,
+            signing_secrets,
+            WebhookSignatureAlgorithm::HmacSha512,
+        )?;
+        let timestamped_signature = get_timestamped_signature(
+            &webhook_signature_payload,
+            signing_secrets,
+            WebhookSignatureAlgorithm::HmacSha512,
+        )?;
 
         Ok(OutgoingWebhookPayloadWithSignature {
             payload: webhook_signature_payload.into(),
             signature,
+            timestamped_signature,
         })
     }
+}
 
-    fn add_webhook_header(header: &mut Vec<(String, Maskable<String>)>, signature: String) {
-        header.push((headers::X_WEBHOOK_SIGNATURE.to_string(), signature.into()))
-    }
+/// Information obtained while processing an incoming webhook, which is recorded along with the
+/// webhook in the incoming webhook archive.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct IncomingWebhookArchiveDetails {
+    pub(crate) connector_name: Option<String>,
+    pub(crate) merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+    pub(crate) profile_id: Option<common_utils::id_type::ProfileId>,
+    pub(crate) event_type: Option<String>,
+    pub(crate) source_verified: Option<bool>,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -66,3 +257,181 @@ pub(crate) struct OutgoingWebhookTrackingData {
     pub(crate) primary_object_type: enums::EventObjectType,
     pub(crate) initial_attempt_id: Option<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    const PAYLOAD: &str = r#"{"event_type":"payment_succeeded"}"#;
+
+    fn secrets_with_previous(previous_expires_in: time::Duration) -> WebhookSigningSecrets {
+        WebhookSigningSecrets::new(
+            Some(Secret::new("current_secret".to_string())),
+            Some(Secret::new("previous_secret".to_string())),
+            Some(date_time::now().saturating_add(previous_expires_in)),
+        )
+    }
+
+    fn sign(secret: &str, timestamp: &str) -> String {
+        let message = format!("{timestamp}.{PAYLOAD}");
+        hex::encode(
+            common_utils::crypto::HmacSha512
+                .sign_message(secret.as_bytes(), message.as_bytes())
+                .unwrap(),
+        )
+    }
+
+    fn get_header<'a>(headers: &'a [(String, Secret<String>)], name: &str) -> Option<&'a str> {
+        headers
+            .iter()
+            .find(|(header_name, _)| header_name == name)
+            .map(|(_, value)| value.peek().as_str())
+    }
+
+    #[test]
+    fn test_timestamped_signature_signs_timestamp_and_payload() {
+        let signing_secrets =
+            WebhookSigningSecrets::new(Some(Secret::new("current_secret".to_string())), None, None);
+        let signature = get_timestamped_signature(
+            PAYLOAD,
+            &signing_secrets,
+            WebhookSignatureAlgorithm::HmacSha512,
+        )
+        .unwrap()
+        .unwrap();
+
+        let (timestamp, signatures) = signature.split_once(',').unwrap();
+        let timestamp = timestamp.strip_prefix("t=").unwrap();
+        assert!(timestamp.parse::<i64>().is_ok());
+        assert_eq!(
+            signatures,
+            format!("v1={}", sign("current_secret", timestamp))
+        );
+    }
+
+    #[test]
+    fn test_webhooks_are_signed_with_both_secrets_during_rotation() {
+        let signing_secrets = secrets_with_previous(time::Duration::hours(1));
+        let signature = get_timestamped_signature(
+            PAYLOAD,
+            &signing_secrets,
+            WebhookSignatureAlgorithm::HmacSha512,
+        )
+        .unwrap()
+        .unwrap();
+
+        let mut parts = signature.split(',');
+        let timestamp = parts.next().unwrap().strip_prefix("t=").unwrap();
+        assert_eq!(
+            parts.collect::<Vec<_>>(),
+            vec![
+                format!("v1={}", sign("current_secret", timestamp)),
+                format!("v1={}", sign("previous_secret", timestamp)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_expired_previous_secret_is_not_used() {
+        let signing_secrets = secrets_with_previous(-time::Duration::hours(1));
+        let signature = get_timestamped_signature(
+            PAYLOAD,
+            &signing_secrets,
+            WebhookSignatureAlgorithm::HmacSha512,
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(signature.matches("v1=").count(), 1);
+
+        let signing_secrets = WebhookSigningSecrets::new(
+            Some(Secret::new("current_secret".to_string())),
+            Some(Secret::new("previous_secret".to_string())),
+            None,
+        );
+        let signature = get_timestamped_signature(
+            PAYLOAD,
+            &signing_secrets,
+            WebhookSignatureAlgorithm::HmacSha512,
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(signature.matches("v1=").count(), 1);
+
+        assert!(get_timestamped_signature(
+            PAYLOAD,
+            &WebhookSigningSecrets::default(),
+            WebhookSignatureAlgorithm::HmacSha512,
+        )
+        .unwrap()
+        .is_none());
+    }
+
+    #[test]
+    fn test_refresh_replaces_existing_signature_headers() {
+        let signing_secrets = secrets_with_previous(time::Duration::hours(1));
+        let mut headers = vec![
+            (
+                headers::X_WEBHOOK_SIGNATURE.to_string(),
+                Secret::new("stale".to_string()),
+            ),
+            (
+                headers::X_WEBHOOK_SIGNATURE_TIMESTAMPED.to_string(),
+                Secret::new("t=0,v1=stale".to_string()),
+            ),
+        ];
+
+        refresh_outgoing_webhook_signatures(&mut headers, PAYLOAD, &signing_secrets).unwrap();
+
+        assert_eq!(headers.len(), 2);
+        assert_eq!(
+            get_header(&headers, headers::X_WEBHOOK_SIGNATURE),
+            get_payload_signature(
+                PAYLOAD,
+                &signing_secrets,
+                WebhookSignatureAlgorithm::HmacSha512
+            )
+            .unwrap()
+            .as_deref()
+        );
+        let timestamped_signature =
+            get_header(&headers, headers::X_WEBHOOK_SIGNATURE_TIMESTAMPED).unwrap();
+        assert!(!timestamped_signature.starts_with("t=0,"));
+        assert_eq!(timestamped_signature.matches("v1=").count(), 2);
+    }
+
+    #[test]
+    fn test_refresh_inserts_missing_timestamped_signature_header() {
+        let signing_secrets = secrets_with_previous(time::Duration::hours(1));
+        let mut headers = vec![(
+            headers::X_WEBHOOK_SIGNATURE.to_string(),
+            Secret::new("stale".to_string()),
+        )];
+
+        refresh_outgoing_webhook_signatures(&mut headers, PAYLOAD, &signing_secrets).unwrap();
+
+        assert_eq!(headers.len(), 2);
+        let timestamped_signature =
+            get_header(&headers, headers::X_WEBHOOK_SIGNATURE_TIMESTAMPED).unwrap();
+        assert!(timestamped_signature.starts_with("t="));
+        assert_eq!(timestamped_signature.matches("v1=").count(), 2);
+
+        // Stripe compatible webhooks already carry a timestamped signature
+        let mut headers = vec![(
+            headers::STRIPE_COMPATIBLE_WEBHOOK_SIGNATURE.to_string(),
+            Secret::new("t=0,v1=stale".to_string()),
+        )];
+        refresh_outgoing_webhook_signatures(&mut headers, PAYLOAD, &signing_secrets).unwrap();
+        assert_eq!(headers.len(), 1);
+
+        // Nothing is inserted when there is no secret to sign the webhook with
+        let mut headers = Vec::new();
+        refresh_outgoing_webhook_signatures(
+            &mut headers,
+            PAYLOAD,
+            &WebhookSigningSecrets::default(),
+        )
+        .unwrap();
+        assert!(headers.is_empty());
+    }
+}
@@ -0,0 +1,521 @@
+use api_models::webhook_endpoints as endpoint_types;
+use common_utils::{crypto::generate_cryptographically_secure_random_string, date_time, id_type};
+use error_stack::{report, ResultExt};
+use masking::Secret;
+use router_env::{instrument, logger, tracing, Instrument};
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{domain, storage, transformers::ForeignFrom},
+};
+
+const WEBHOOK_ENDPOINT_ID_PREFIX: &str = "whe";
+const WEBHOOK_SECRET_LENGTH: usize = 64;
+const DEFAULT_PREVIOUS_WEBHOOK_SECRET_EXPIRY_SECS: u32 = 24 * 60 * 60;
+const MAX_PREVIOUS_WEBHOOK_SECRET_EXPIRY_SECS: u32 = 7 * 24 * 60 * 60;
+const HEALTH_WINDOW_HOURS: i64 = 24;
+const HEALTH_WINDOW_MAX_DELIVERY_ATTEMPTS: i64 = 1000;
+const MAX_REPLAYED_EVENTS: i64 = 1000;
+
+#[instrument(skip_all)]
+pub async fn create_webhook_endpoint(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    req: endpoint_types::WebhookEndpointCreateRequest,
+) -> RouterResponse<endpoint_types::WebhookEndpointResponse> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_account.get_id();
+
+    validate_profile(&state, &key_store, merchant_id, &profile_id).await?;
+    validate_webhook_endpoint_url(&req.url)?;
+    validate_enabled_events(&req.enabled_events)?;
+
+    let secret = req
+        .secret
+        .unwrap_or_else(|| Secret::new(generate_webhook_secret()));
+
+    let now = date_time::now();
+    let endpoint_new = storage::WebhookEndpointNew {
+        endpoint_id: common_utils::generate_id(consts::ID_LENGTH, WEBHOOK_ENDPOINT_ID_PREFIX),
+        merchant_id: merchant_id.to_owned(),
+        profile_id,
+        url: req.url,
+        secret,
+        enabled_events: req.enabled_events,
+        is_enabled: req.is_enabled.unwrap_or(true),
+        description: req.description,
+        created_at: now,
+        modified_at: now,
+        previous_secret: None,
+        previous_secret_expires_at: None,
+        consecutive_failures: 0,
+        last_successful_delivery_at: None,
+        last_failed_delivery_at: None,
+        suspended_at: None,
+        strict_ordering: req.strict_ordering.unwrap_or(false),
+    };
+
+    let endpoint = db
+        .insert_webhook_endpoint(endpoint_new)
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+            message: "Webhook endpoint with the given ID already exists".to_string(),
+        })?;
+
+    let secret = endpoint.secret.clone();
+    let mut response = endpoint_types::WebhookEndpointResponse::foreign_from(endpoint);
+    // The secret is only revealed when the endpoint is created
+    response.secret = Some(secret);
+
+    Ok(ApplicationResponse::Json(response))
+}
+
+#[instrument(skip_all)]
+pub async fn list_webhook_endpoints(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<Vec<endpoint_types::WebhookEndpointResponse>> {
+    let merchant_id = merchant_account.get_id();
+    validate_profile(&state, &key_store, merchant_id, &profile_id).await?;
+
+    let endpoints = state
+        .store
+        .list_webhook_endpoints_by_merchant_id_profile_id(merchant_id, &profile_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list webhook endpoints")?;
+
+    Ok(ApplicationResponse::Json(
+        endpoints
+            .into_iter()
+            .map(endpoint_types::WebhookEndpointResponse::foreign_from)
+            .collect(),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_webhook_endpoint(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: id_type::ProfileId,
+    endpoint_id: String,
+) -> RouterResponse<endpoint_types::WebhookEndpointResponse> {
+    let endpoint =
+        find_webhook_endpoint(&state, merchant_account.get_id(), &profile_id, &endpoint_id).await?;
+
+    Ok(ApplicationResponse::Json(
+        endpoint_types::WebhookEndpointResponse::foreign_from(endpoint),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn update_webhook_endpoint(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: id_type::ProfileId,
+    endpoint_id: String,
+    req: endpoint_types::WebhookEndpointUpdateRequest,
+) -> RouterResponse<endpoint_types::WebhookEndpointResponse> {
+    let merchant_id = merchant_account.get_id();
+    find_webhook_endpoint(&state, merchant_id, &profile_id, &endpoint_id).await?;
+
+    if let Some(url) = &req.url {
+        validate_webhook_endpoint_url(url)?;
+    }
+    if let Some(enabled_events) = &req.enabled_events {
+        validate_enabled_events(enabled_events)?;
+    }
+
+    let endpoint_update = storage::WebhookEndpointUpdate::Update {
+        url: req.url,
+        enabled_events: req.enabled_events,
+        is_enabled: req.is_enabled,
+        description: req.description,
+        strict_ordering: req.strict_ordering,
+    };
+
+    let endpoint = state
+        .store
+        .update_webhook_endpoint_by_merchant_id_endpoint_id(
+            merchant_id,
+            &endpoint_id,
+            endpoint_update,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Webhook endpoint not found".to_string(),
+        })?;
+
+    Ok(ApplicationResponse::Json(
+        endpoint_types::WebhookEndpointResponse::foreign_from(endpoint),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn delete_webhook_endpoint(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: id_type::ProfileId,
+    endpoint_id: String,
+) -> RouterResponse<endpoint_types::WebhookEndpointDeleteResponse> {
+    let merchant_id = merchant_account.get_id();
+    find_webhook_endpoint(&state, merchant_id, &profile_id, &endpoint_id).await?;
+
+    let deleted = state
+        .store
+        .delete_webhook_endpoint_by_merchant_id_endpoint_id(merchant_id, &endpoint_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Webhook endpoint not found".to_string(),
+        })?;
+
+    Ok(ApplicationResponse::Json(
+        endpoint_types::WebhookEndpointDeleteResponse {
+            endpoint_id,
+            deleted,
+        },
+    ))
+}
+
+/// Computes the health of the webhook endpoint from the delivery attempts made to it in the last
+/// day.
+#[instrument(skip_all)]
+pub async fn retrieve_webhook_endpoint_health(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    endpoint_id: String,
+) -> RouterResponse<endpoint_types::WebhookEndpointHealthResponse> {
+    let merchant_id = merchant_account.get_id();
+    let endpoint = find_webhook_endpoint(&state, merchant_id, &profile_id, &endpoint_id).await?;
+
+    let window_start = date_time::now().saturating_sub(time::Duration::hours(HEALTH_WINDOW_HOURS));
+    let events = state
+        .store
+        .list_events_by_merchant_id_webhook_endpoint_id(
+            &(&state).into(),
+            merchant_id,
+            &endpoint_id,
+            window_start,
+            HEALTH_WINDOW_MAX_DELIVERY_ATTEMPTS,
+            &key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list events for webhook endpoint")?;
+
+    // Events recorded while the endpoint is suspended, or whose delivery is yet to be attempted,
+    // do not have a response
+    let delivery_attempts = events
+        .iter()
+        .filter(|event| event.response.is_some())
+        .collect::<Vec<_>>();
+    let successful_delivery_attempts = delivery_attempts
+        .iter()
+        .filter(|event| event.is_webhook_notified)
+        .count();
+    let latencies = delivery_attempts
+        .iter()
+        .filter_map(|event| event.delivery_latency_ms)
+        .collect::<Vec<_>>();
+
+    let delivery_attempts = u32::try_from(delivery_attempts.len()).unwrap_or(u32::MAX);
+    let successful_delivery_attempts =
+        u32::try_from(successful_delivery_attempts).unwrap_or(u32::MAX);
+    let success_rate = (delivery_attempts > 0)
+        .then(|| f64::from(successful_delivery_attempts) / f64::from(delivery_attempts));
+
+    let status = if endpoint.is_enabled {
+        endpoint_types::WebhookEndpointStatus::Enabled
+    } else if endpoint.is_suspended() {
+        endpoint_types::WebhookEndpointStatus::Suspended
+    } else {
+        endpoint_types::WebhookEndpointStatus::Disabled
+    };
+
+    Ok(ApplicationResponse::Json(
+        endpoint_types::WebhookEndpointHealthResponse {
+            endpoint_id: endpoint.endpoint_id,
+            status,
+            consecutive_failures: endpoint.consecutive_failures,
+            last_successful_delivery_at: endpoint.last_successful_delivery_at,
+            last_failed_delivery_at: endpoint.last_failed_delivery_at,
+            suspended_at: endpoint.suspended_at,
+            window_start,
+            delivery_attempts,
+            successful_delivery_attempts,
+            success_rate,
+            p95_latency_ms: get_percentile(latencies, 95),
+        },
+    ))
+}
+
+/// Re-enables a suspended webhook endpoint, and delivers the events missed by the endpoint since
+/// the last successful delivery to it again. The events are delivered in the background, in the
+/// order in which they were created.
+#[instrument(skip_all)]
+pub async fn reenable_webhook_endpoint(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    endpoint_id: String,
+) -> RouterResponse<endpoint_types::WebhookEndpointReenableResponse> {
+    let merchant_id = merchant_account.get_id();
+    let endpoint = find_webhook_endpoint(&state, merchant_id, &profile_id, &endpoint_id).await?;
+
+    if !endpoint.is_suspended() {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Only suspended webhook endpoints can be re-enabled".to_string(),
+        }));
+    }
+
+    let missed_events = state
+        .store
+        .list_initial_events_by_merchant_id_webhook_endpoint_id(
+            &(&state).into(),
+            merchant_id,
+            &endpoint_id,
+            endpoint.last_successful_delivery_at,
+            MAX_REPLAYED_EVENTS,
+            &key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list events missed by webhook endpoint")?;
+
+    state
+        .store
+        .update_webhook_endpoint_by_merchant_id_endpoint_id(
+            merchant_id,
+            &endpoint_id,
+            storage::WebhookEndpointUpdate::Reenable,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Webhook endpoint not found".to_string(),
+        })?;
+
+    let replayed_events = u32::try_from(missed_events.len()).unwrap_or(u32::MAX);
+    let event_ids = missed_events
+        .into_iter()
+        .map(|event| event.event_id)
+        .collect::<Vec<_>>();
+    tokio::spawn(
+        replay_missed_events(
+            state.clone(),
+            merchant_id.to_owned(),
+            endpoint_id.clone(),
+            event_ids,
+        )
+        .in_current_span(),
+    );
+
+    Ok(ApplicationResponse::Json(
+        endpoint_types::WebhookEndpointReenableResponse {
+            endpoint_id,
+            replayed_events,
+        },
+    ))
+}
+
+/// Delivers the specified events again, stopping if the webhook endpoint is disabled or suspended
+/// in the meantime.
+async fn replay_missed_events(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    endpoint_id: String,
+    event_ids: Vec<String>,
+) {
+    for event_id in event_ids {
+        let is_enabled = state
+            .store
+            .find_webhook_endpoint_by_merchant_id_endpoint_id(&merchant_id, &endpoint_id)
+            .await
+            .is_ok_and(|endpoint| endpoint.is_enabled);
+        if !is_enabled {
+            logger::info!(
+                %endpoint_id,
+                "Webhook endpoint is no longer enabled; stopping replay of missed events"
+            );
+            break;
+        }
+
+        if let Err(error) = Box::pin(super::webhook_events::retry_delivery_attempt(
+            state.clone(),
+            merchant_id.clone(),
+            event_id.clone(),
+        ))
+        .await
+        {
+            logger::error!(?error, %event_id, "Failed to replay missed event");
+        }
+    }
+}
+
+/// Obtains the specified percentile of the values using the nearest-rank method.
+fn get_percentile(mut values: Vec<i32>, percentile: usize) -> Option<i32> {
+    values.sort_unstable();
+    let rank = values
+        .len()
+        .checked_mul(percentile)?
+        .div_ceil(100)
+        .checked_sub(1)?;
+    values.get(rank).copied()
+}
+
+/// Replaces the secret of the webhook endpoint with a newly generated one. Webhooks continue to be
+/// signed using the previous secret as well, until it expires.
+#[instrument(skip_all)]
+pub async fn rotate_webhook_endpoint_secret(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: id_type::ProfileId,
+    endpoint_id: String,
+    req: endpoint_types::WebhookSecretRotateRequest,
+) -> RouterResponse<endpoint_types::WebhookSecretRotateResponse> {
+    let merchant_id = merchant_account.get_id();
+    let endpoint = find_webhook_endpoint(&state, merchant_id, &profile_id, &endpoint_id).await?;
+    let previous_secret_expires_at = get_previous_webhook_secret_expires_at(req.expires_in)?;
+
+    let secret = Secret::new(generate_webhook_secret());
+    let endpoint_update = storage::WebhookEndpointUpdate::SecretUpdate {
+        secret: secret.clone(),
+        previous_secret: endpoint.secret,
+        previous_secret_expires_at,
+    };
+
+    state
+        .store
+        .update_webhook_endpoint_by_merchant_id_endpoint_id(
+            merchant_id,
+            &endpoint_id,
+            endpoint_update,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Webhook endpoint not found".to_string(),
+        })?;
+
+    Ok(ApplicationResponse::Json(
+        endpoint_types::WebhookSecretRotateResponse {
+            secret,
+            previous_secret_expires_at: Some(previous_secret_expires_at),
+        },
+    ))
+}
+
+pub(crate) fn generate_webhook_secret() -> String {
+    generate_cryptographically_secure_random_string(WEBHOOK_SECRET_LENGTH)
+}
+
+/// Obtains the time until which the previous secret remains valid after a secret is rotated.
+pub(crate) fn get_previous_webhook_secret_expires_at(
+    expires_in: Option<u32>,
+) -> RouterResult<time::PrimitiveDateTime> {
+    let expires_in = expires_in.unwrap_or(DEFAULT_PREVIOUS_WEBHOOK_SECRET_EXPIRY_SECS);
+    if expires_in > MAX_PREVIOUS_WEBHOOK_SECRET_EXPIRY_SECS {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "expires_in cannot exceed {MAX_PREVIOUS_WEBHOOK_SECRET_EXPIRY_SECS} seconds"
+            ),
+        }));
+    }
+
+    Ok(date_time::now().saturating_add(time::Duration::seconds(i64::from(expires_in))))
+}
+
+async fn validate_profile(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    merchant_id: &id_type::MerchantId,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<()> {
+    state
+        .store
+        .find_business_profile_by_merchant_id_profile_id(
+            &state.into(),
+            key_store,
+            merchant_id,
+            profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })
+        .map(|_| ())
+}
+
+/// Fetches the webhook endpoint, ensuring that it belongs to the specified business profile.
+async fn find_webhook_endpoint(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    profile_id: &id_type::ProfileId,
+    endpoint_id: &str,
+) -> RouterResult<storage::WebhookEndpoint> {
+    let not_found_error = || errors::ApiErrorResponse::GenericNotFoundError {
+        message: "Webhook endpoint not found".to_string(),
+    };
+
+    let endpoint = state
+        .store
+        .find_webhook_endpoint_by_merchant_id_endpoint_id(merchant_id, endpoint_id)
+        .await
+        .to_not_found_response(not_found_error())?;
+
+    if endpoint.profile_id != *profile_id {
+        return Err(report!(not_found_error()));
+    }
+
+    Ok(endpoint)
+}
+
+fn validate_webhook_endpoint_url(url: &str) -> RouterResult<()> {
+    let is_valid = url::Url::parse(url)
+        .map(|url| matches!(url.scheme(), "http" | "https") && url.has_host())
+        .unwrap_or(false);
+
+    if is_valid {
+        Ok(())
+    } else {
+        Err(report!(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "url"
+        }))
+    }
+}
+
+fn validate_enabled_events(enabled_events: &[storage::enums::EventType]) -> RouterResult<()> {
+    if enabled_events.is_empty() {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "enabled_events must contain at least one event type".to_string(),
+        }))
+    } else {
+        Ok(())
+    }
+}
+
+impl ForeignFrom<storage::WebhookEndpoint> for endpoint_types::WebhookEndpointResponse {
+    fn foreign_from(endpoint: storage::WebhookEndpoint) -> Self {
+        Self {
+            endpoint_id: endpoint.endpoint_id,
+            merchant_id: endpoint.merchant_id,
+            profile_id: endpoint.profile_id,
+            url: endpoint.url,
+            secret: None,
+            enabled_events: endpoint.enabled_events,
+            is_enabled: endpoint.is_enabled,
+            description: endpoint.description,
+            strict_ordering: endpoint.strict_ordering,
+            created_at: endpoint.created_at,
+            modified_at: endpoint.modified_at,
+        }
+    }
+}
@@ -216,6 +216,9 @@ pub async fn retry_delivery_attempt(
         response: None,
         delivery_attempt: Some(delivery_attempt),
         metadata: event_to_retry.metadata,
+        webhook_endpoint_id: event_to_retry.webhook_endpoint_id,
+        delivery_latency_ms: None,
+        sequence_number: event_to_retry.sequence_number,
     };
 
     let event = store
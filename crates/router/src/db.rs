@@ -1,4 +1,5 @@
 pub mod address;
+pub mod analytics_report_schedule;
 pub mod api_keys;
 pub mod authentication;
 pub mod authorization;
@@ -7,6 +8,7 @@ pub mod blocklist_fingerprint;
 pub mod blocklist_lookup;
 pub mod business_profile;
 pub mod capture;
+pub mod card_account_update;
 pub mod cards_info;
 pub mod configs;
 pub mod customers;
@@ -14,11 +16,14 @@ pub mod dashboard_metadata;
 pub mod dispute;
 pub mod ephemeral_key;
 pub mod events;
+pub mod export_job;
 pub mod file;
 pub mod fraud_check;
 pub mod generic_link;
 pub mod gsm;
 pub mod health_check;
+pub mod incoming_webhook_archive;
+pub mod invoice;
 pub mod kafka_store;
 pub mod locker_mock_up;
 pub mod mandate;
@@ -28,15 +33,22 @@ pub mod merchant_key_store;
 pub mod organization;
 pub mod payment_link;
 pub mod payment_method;
+pub mod payment_recovery;
+pub mod payment_split;
+pub mod payout_batch;
 pub mod refund;
+pub mod refund_batch;
+pub mod reusable_payment_link;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod subscription;
 pub mod unified_translations;
 pub mod user;
 pub mod user_authentication_method;
 pub mod user_key_store;
 pub mod user_role;
+pub mod webhook_endpoint;
 use common_utils::id_type;
 use diesel_models::{
     fraud_check::{FraudCheck, FraudCheckUpdate},
@@ -95,6 +107,7 @@ pub trait StorageInterface:
     + dispute::DisputeInterface
     + ephemeral_key::EphemeralKeyInterface
     + events::EventInterface
+    + incoming_webhook_archive::IncomingWebhookArchiveInterface
     + file::FileMetadataInterface
     + FraudCheckInterface
     + locker_mock_up::LockerMockUpInterface
@@ -111,8 +124,15 @@ pub trait StorageInterface:
     + PayoutAttemptInterface
     + PayoutsInterface
     + refund::RefundInterface
+    + refund_batch::RefundBatchInterface
+    + reusable_payment_link::ReusablePaymentLinkInterface
+    + webhook_endpoint::WebhookEndpointInterface
     + reverse_lookup::ReverseLookupInterface
     + cards_info::CardsInfoInterface
+    + card_account_update::CardAccountUpdateInterface
+    + invoice::InvoiceInterface
+    + analytics_report_schedule::AnalyticsReportScheduleInterface
+    + export_job::ExportJobInterface
     + merchant_key_store::MerchantKeyStoreInterface
     + MasterKeyInterface
     + payment_link::PaymentLinkInterface
@@ -121,6 +141,10 @@ pub trait StorageInterface:
     + business_profile::ProfileInterface
     + OrganizationInterface
     + routing_algorithm::RoutingAlgorithmInterface
+    + subscription::SubscriptionInterface
+    + payment_recovery::PaymentRecoveryInterface
+    + payment_split::PaymentSplitInterface
+    + payout_batch::PayoutBatchInterface
     + gsm::GsmInterface
     + unified_translations::UnifiedTranslationsInterface
     + user_role::UserRoleInterface
@@ -0,0 +1,228 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait AnalyticsReportScheduleInterface {
+    async fn insert_analytics_report_schedule(
+        &self,
+        schedule_new: storage::AnalyticsReportScheduleNew,
+    ) -> CustomResult<storage::AnalyticsReportSchedule, errors::StorageError>;
+
+    async fn find_analytics_report_schedule_by_merchant_id_report_schedule_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        report_schedule_id: &str,
+    ) -> CustomResult<storage::AnalyticsReportSchedule, errors::StorageError>;
+
+    async fn list_analytics_report_schedules_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::AnalyticsReportSchedule>, errors::StorageError>;
+
+    async fn update_analytics_report_schedule_by_merchant_id_report_schedule_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        report_schedule_id: &str,
+        schedule_update: storage::AnalyticsReportScheduleUpdate,
+    ) -> CustomResult<storage::AnalyticsReportSchedule, errors::StorageError>;
+
+    async fn delete_analytics_report_schedule_by_merchant_id_report_schedule_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        report_schedule_id: &str,
+    ) -> CustomResult<bool, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl AnalyticsReportScheduleInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_analytics_report_schedule(
+        &self,
+        schedule_new: storage::AnalyticsReportScheduleNew,
+    ) -> CustomResult<storage::AnalyticsReportSchedule, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        schedule_new
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_analytics_report_schedule_by_merchant_id_report_schedule_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        report_schedule_id: &str,
+    ) -> CustomResult<storage::AnalyticsReportSchedule, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::AnalyticsReportSchedule::find_by_merchant_id_report_schedule_id(
+            &conn,
+            merchant_id,
+            report_schedule_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_analytics_report_schedules_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::AnalyticsReportSchedule>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::AnalyticsReportSchedule::list_by_merchant_id(&conn, merchant_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_analytics_report_schedule_by_merchant_id_report_schedule_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        report_schedule_id: &str,
+        schedule_update: storage::AnalyticsReportScheduleUpdate,
+    ) -> CustomResult<storage::AnalyticsReportSchedule, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::AnalyticsReportSchedule::update_by_merchant_id_report_schedule_id(
+            &conn,
+            merchant_id,
+            report_schedule_id,
+            schedule_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_analytics_report_schedule_by_merchant_id_report_schedule_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        report_schedule_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::AnalyticsReportSchedule::delete_by_merchant_id_report_schedule_id(
+            &conn,
+            merchant_id,
+            report_schedule_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl AnalyticsReportScheduleInterface for MockDb {
+    async fn insert_analytics_report_schedule(
+        &self,
+        _schedule_new: storage::AnalyticsReportScheduleNew,
+    ) -> CustomResult<storage::AnalyticsReportSchedule, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_analytics_report_schedule_by_merchant_id_report_schedule_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _report_schedule_id: &str,
+    ) -> CustomResult<storage::AnalyticsReportSchedule, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_analytics_report_schedules_by_merchant_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::AnalyticsReportSchedule>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_analytics_report_schedule_by_merchant_id_report_schedule_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _report_schedule_id: &str,
+        _schedule_update: storage::AnalyticsReportScheduleUpdate,
+    ) -> CustomResult<storage::AnalyticsReportSchedule, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn delete_analytics_report_schedule_by_merchant_id_report_schedule_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _report_schedule_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl AnalyticsReportScheduleInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_analytics_report_schedule(
+        &self,
+        schedule_new: storage::AnalyticsReportScheduleNew,
+    ) -> CustomResult<storage::AnalyticsReportSchedule, errors::StorageError> {
+        self.diesel_store
+            .insert_analytics_report_schedule(schedule_new)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_analytics_report_schedule_by_merchant_id_report_schedule_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        report_schedule_id: &str,
+    ) -> CustomResult<storage::AnalyticsReportSchedule, errors::StorageError> {
+        self.diesel_store
+            .find_analytics_report_schedule_by_merchant_id_report_schedule_id(
+                merchant_id,
+                report_schedule_id,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_analytics_report_schedules_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::AnalyticsReportSchedule>, errors::StorageError> {
+        self.diesel_store
+            .list_analytics_report_schedules_by_merchant_id(merchant_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_analytics_report_schedule_by_merchant_id_report_schedule_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        report_schedule_id: &str,
+        schedule_update: storage::AnalyticsReportScheduleUpdate,
+    ) -> CustomResult<storage::AnalyticsReportSchedule, errors::StorageError> {
+        self.diesel_store
+            .update_analytics_report_schedule_by_merchant_id_report_schedule_id(
+                merchant_id,
+                report_schedule_id,
+                schedule_update,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_analytics_report_schedule_by_merchant_id_report_schedule_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        report_schedule_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .delete_analytics_report_schedule_by_merchant_id_report_schedule_id(
+                merchant_id,
+                report_schedule_id,
+            )
+            .await
+    }
+}
@@ -0,0 +1,57 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait CardAccountUpdateInterface {
+    async fn insert_card_account_update(
+        &self,
+        update_new: storage::CardAccountUpdateNew,
+    ) -> CustomResult<storage::CardAccountUpdate, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl CardAccountUpdateInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_card_account_update(
+        &self,
+        update_new: storage::CardAccountUpdateNew,
+    ) -> CustomResult<storage::CardAccountUpdate, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        update_new
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl CardAccountUpdateInterface for MockDb {
+    async fn insert_card_account_update(
+        &self,
+        _update_new: storage::CardAccountUpdateNew,
+    ) -> CustomResult<storage::CardAccountUpdate, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl CardAccountUpdateInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_card_account_update(
+        &self,
+        update_new: storage::CardAccountUpdateNew,
+    ) -> CustomResult<storage::CardAccountUpdate, errors::StorageError> {
+        self.diesel_store
+            .insert_card_account_update(update_new)
+            .await
+    }
+}
@@ -1,4 +1,5 @@
 use common_utils::{ext_traits::AsyncExt, types::keymanager::KeyManagerState};
+use diesel_models::errors::DatabaseError;
 use error_stack::{report, ResultExt};
 use router_env::{instrument, tracing};
 
@@ -84,6 +85,28 @@ where
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError>;
 
+    #[allow(clippy::too_many_arguments)]
+    async fn list_events_by_merchant_id_webhook_endpoint_id(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        webhook_endpoint_id: &str,
+        created_after: time::PrimitiveDateTime,
+        limit: i64,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError>;
+
+    #[allow(clippy::too_many_arguments)]
+    async fn list_initial_events_by_merchant_id_webhook_endpoint_id(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        webhook_endpoint_id: &str,
+        created_after: Option<time::PrimitiveDateTime>,
+        limit: i64,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError>;
+
     async fn update_event_by_merchant_id_event_id(
         &self,
         state: &KeyManagerState,
@@ -330,6 +353,84 @@ impl EventInterface for Store {
         .await
     }
 
+    #[instrument(skip_all)]
+    async fn list_events_by_merchant_id_webhook_endpoint_id(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        webhook_endpoint_id: &str,
+        created_after: time::PrimitiveDateTime,
+        limit: i64,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Event::list_by_merchant_id_webhook_endpoint_id(
+            &conn,
+            merchant_id,
+            webhook_endpoint_id,
+            created_after,
+            limit,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+        .async_and_then(|events| async {
+            let mut domain_events = Vec::with_capacity(events.len());
+            for event in events.into_iter() {
+                domain_events.push(
+                    event
+                        .convert(
+                            state,
+                            merchant_key_store.key.get_inner(),
+                            merchant_key_store.merchant_id.clone().into(),
+                        )
+                        .await
+                        .change_context(errors::StorageError::DecryptionError)?,
+                );
+            }
+            Ok(domain_events)
+        })
+        .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_initial_events_by_merchant_id_webhook_endpoint_id(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        webhook_endpoint_id: &str,
+        created_after: Option<time::PrimitiveDateTime>,
+        limit: i64,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Event::list_initial_attempts_by_merchant_id_webhook_endpoint_id(
+            &conn,
+            merchant_id,
+            webhook_endpoint_id,
+            created_after,
+            limit,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+        .async_and_then(|events| async {
+            let mut domain_events = Vec::with_capacity(events.len());
+            for event in events.into_iter() {
+                domain_events.push(
+                    event
+                        .convert(
+                            state,
+                            merchant_key_store.key.get_inner(),
+                            merchant_key_store.merchant_id.clone().into(),
+                        )
+                        .await
+                        .change_context(errors::StorageError::DecryptionError)?,
+                );
+            }
+            Ok(domain_events)
+        })
+        .await
+    }
+
     #[instrument(skip_all)]
     async fn update_event_by_merchant_id_event_id(
         &self,
@@ -363,6 +464,22 @@ impl EventInterface for MockDb {
     ) -> CustomResult<domain::Event, errors::StorageError> {
         let mut locked_events = self.events.lock().await;
 
+        // The idempotent event ID is unique in the database, which prevents duplicate events from
+        // being created for the same delivery
+        if event
+            .idempotent_event_id
+            .as_ref()
+            .is_some_and(|idempotent_event_id| {
+                locked_events.iter().any(|stored_event| {
+                    stored_event.idempotent_event_id.as_ref() == Some(idempotent_event_id)
+                })
+            })
+        {
+            Err(errors::StorageError::DatabaseError(
+                DatabaseError::UniqueViolation.into(),
+            ))?
+        }
+
         let stored_event = Conversion::convert(event)
             .await
             .change_context(errors::StorageError::EncryptionError)?;
@@ -656,6 +773,85 @@ impl EventInterface for MockDb {
         Ok(domain_events)
     }
 
+    async fn list_events_by_merchant_id_webhook_endpoint_id(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        webhook_endpoint_id: &str,
+        created_after: time::PrimitiveDateTime,
+        limit: i64,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
+        let locked_events = self.events.lock().await;
+        let mut events = locked_events
+            .iter()
+            .filter(|event| {
+                event.merchant_id == Some(merchant_id.to_owned())
+                    && event.webhook_endpoint_id.as_deref() == Some(webhook_endpoint_id)
+                    && event.created_at >= created_after
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        events.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        let limit = usize::try_from(limit).unwrap_or(usize::MAX);
+
+        let mut domain_events = Vec::with_capacity(events.len());
+
+        for event in events.into_iter().take(limit) {
+            let domain_event = event
+                .convert(
+                    state,
+                    merchant_key_store.key.get_inner(),
+                    merchant_key_store.merchant_id.clone().into(),
+                )
+                .await
+                .change_context(errors::StorageError::DecryptionError)?;
+            domain_events.push(domain_event);
+        }
+
+        Ok(domain_events)
+    }
+
+    async fn list_initial_events_by_merchant_id_webhook_endpoint_id(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        webhook_endpoint_id: &str,
+        created_after: Option<time::PrimitiveDateTime>,
+        limit: i64,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
+        let locked_events = self.events.lock().await;
+        let mut events = locked_events
+            .iter()
+            .filter(|event| {
+                event.merchant_id == Some(merchant_id.to_owned())
+                    && event.initial_attempt_id.as_ref() == Some(&event.event_id)
+                    && event.webhook_endpoint_id.as_deref() == Some(webhook_endpoint_id)
+                    && created_after.map_or(true, |created_after| event.created_at > created_after)
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        events.sort_by_key(|event| event.created_at);
+        let limit = usize::try_from(limit).unwrap_or(usize::MAX);
+
+        let mut domain_events = Vec::with_capacity(events.len());
+
+        for event in events.into_iter().take(limit) {
+            let domain_event = event
+                .convert(
+                    state,
+                    merchant_key_store.key.get_inner(),
+                    merchant_key_store.merchant_id.clone().into(),
+                )
+                .await
+                .change_context(errors::StorageError::DecryptionError)?;
+            domain_events.push(domain_event);
+        }
+
+        Ok(domain_events)
+    }
+
     async fn update_event_by_merchant_id_event_id(
         &self,
         state: &KeyManagerState,
@@ -676,9 +872,11 @@ impl EventInterface for MockDb {
             domain::EventUpdate::UpdateResponse {
                 is_webhook_notified,
                 response,
+                delivery_latency_ms,
             } => {
                 event_to_update.is_webhook_notified = is_webhook_notified;
                 event_to_update.response = response.map(Into::into);
+                event_to_update.delivery_latency_ms = delivery_latency_ms;
             }
         }
 
@@ -799,6 +997,9 @@ mod tests {
                         )
                         .unwrap(),
                     }),
+                    webhook_endpoint_id: None,
+                    delivery_latency_ms: None,
+                    sequence_number: None,
                 },
                 &merchant_key_store,
             )
@@ -815,6 +1016,7 @@ mod tests {
                 domain::EventUpdate::UpdateResponse {
                     is_webhook_notified: true,
                     response: None,
+                    delivery_latency_ms: Some(120),
                 },
                 &merchant_key_store,
             )
@@ -822,6 +1024,7 @@ mod tests {
             .unwrap();
 
         assert!(updated_event.is_webhook_notified);
+        assert_eq!(updated_event.delivery_latency_ms, Some(120));
         assert_eq!(updated_event.primary_object_id, payment_id);
         assert_eq!(updated_event.event_id, event_id);
     }
@@ -0,0 +1,179 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait ExportJobInterface {
+    async fn insert_export_job(
+        &self,
+        export_new: storage::ExportJobNew,
+    ) -> CustomResult<storage::ExportJob, errors::StorageError>;
+
+    async fn find_export_job_by_merchant_id_export_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        export_id: &str,
+    ) -> CustomResult<storage::ExportJob, errors::StorageError>;
+
+    async fn list_export_jobs_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ExportJob>, errors::StorageError>;
+
+    async fn update_export_job_by_merchant_id_export_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        export_id: &str,
+        export_update: storage::ExportJobUpdate,
+    ) -> CustomResult<storage::ExportJob, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl ExportJobInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_export_job(
+        &self,
+        export_new: storage::ExportJobNew,
+    ) -> CustomResult<storage::ExportJob, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        export_new
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_export_job_by_merchant_id_export_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        export_id: &str,
+    ) -> CustomResult<storage::ExportJob, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ExportJob::find_by_merchant_id_export_id(&conn, merchant_id, export_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_export_jobs_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ExportJob>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ExportJob::list_by_merchant_id(&conn, merchant_id, limit, offset)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_export_job_by_merchant_id_export_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        export_id: &str,
+        export_update: storage::ExportJobUpdate,
+    ) -> CustomResult<storage::ExportJob, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ExportJob::update_by_merchant_id_export_id(
+            &conn,
+            merchant_id,
+            export_id,
+            export_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl ExportJobInterface for MockDb {
+    async fn insert_export_job(
+        &self,
+        _export_new: storage::ExportJobNew,
+    ) -> CustomResult<storage::ExportJob, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_export_job_by_merchant_id_export_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _export_id: &str,
+    ) -> CustomResult<storage::ExportJob, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_export_jobs_by_merchant_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _limit: Option<i64>,
+        _offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ExportJob>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_export_job_by_merchant_id_export_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _export_id: &str,
+        _export_update: storage::ExportJobUpdate,
+    ) -> CustomResult<storage::ExportJob, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl ExportJobInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_export_job(
+        &self,
+        export_new: storage::ExportJobNew,
+    ) -> CustomResult<storage::ExportJob, errors::StorageError> {
+        self.diesel_store.insert_export_job(export_new).await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_export_job_by_merchant_id_export_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        export_id: &str,
+    ) -> CustomResult<storage::ExportJob, errors::StorageError> {
+        self.diesel_store
+            .find_export_job_by_merchant_id_export_id(merchant_id, export_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_export_jobs_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ExportJob>, errors::StorageError> {
+        self.diesel_store
+            .list_export_jobs_by_merchant_id(merchant_id, limit, offset)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_export_job_by_merchant_id_export_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        export_id: &str,
+        export_update: storage::ExportJobUpdate,
+    ) -> CustomResult<storage::ExportJob, errors::StorageError> {
+        self.diesel_store
+            .update_export_job_by_merchant_id_export_id(merchant_id, export_id, export_update)
+            .await
+    }
+}
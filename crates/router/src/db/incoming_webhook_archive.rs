@@ -0,0 +1,403 @@
+use common_utils::{ext_traits::AsyncExt, types::keymanager::KeyManagerState};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::{
+        domain::{
+            self,
+            behaviour::{Conversion, ReverseConversion},
+        },
+        storage,
+    },
+};
+
+#[async_trait::async_trait]
+pub trait IncomingWebhookArchiveInterface
+where
+    domain::IncomingWebhookArchive: Conversion<
+        DstType = storage::IncomingWebhookArchive,
+        NewDstType = storage::IncomingWebhookArchiveNew,
+    >,
+{
+    async fn insert_incoming_webhook_archive(
+        &self,
+        state: &KeyManagerState,
+        archive: domain::IncomingWebhookArchive,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookArchive, errors::StorageError>;
+
+    async fn find_incoming_webhook_archive_by_merchant_id_webhook_id(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        webhook_id: &str,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookArchive, errors::StorageError>;
+
+    async fn list_incoming_webhook_archives_by_merchant_id_constraints(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        constraints: storage::IncomingWebhookArchiveListConstraints,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::IncomingWebhookArchive>, errors::StorageError>;
+
+    async fn update_incoming_webhook_archive_by_merchant_id_webhook_id(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        webhook_id: &str,
+        archive_update: storage::IncomingWebhookArchiveUpdate,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookArchive, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl IncomingWebhookArchiveInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_incoming_webhook_archive(
+        &self,
+        state: &KeyManagerState,
+        archive: domain::IncomingWebhookArchive,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookArchive, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        archive
+            .construct_new()
+            .await
+            .change_context(errors::StorageError::EncryptionError)?
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))?
+            .convert(
+                state,
+                merchant_key_store.key.get_inner(),
+                merchant_key_store.merchant_id.clone().into(),
+            )
+            .await
+            .change_context(errors::StorageError::DecryptionError)
+    }
+
+    #[instrument(skip_all)]
+    async fn find_incoming_webhook_archive_by_merchant_id_webhook_id(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        webhook_id: &str,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookArchive, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::IncomingWebhookArchive::find_by_merchant_id_webhook_id(
+            &conn,
+            merchant_id,
+            webhook_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))?
+        .convert(
+            state,
+            merchant_key_store.key.get_inner(),
+            merchant_key_store.merchant_id.clone().into(),
+        )
+        .await
+        .change_context(errors::StorageError::DecryptionError)
+    }
+
+    #[instrument(skip_all)]
+    async fn list_incoming_webhook_archives_by_merchant_id_constraints(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        constraints: storage::IncomingWebhookArchiveListConstraints,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::IncomingWebhookArchive>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::IncomingWebhookArchive::list_by_merchant_id_constraints(
+            &conn,
+            merchant_id,
+            constraints,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+        .async_and_then(|archives| async {
+            let mut domain_archives = Vec::with_capacity(archives.len());
+            for archive in archives.into_iter() {
+                domain_archives.push(
+                    archive
+                        .convert(
+                            state,
+                            merchant_key_store.key.get_inner(),
+                            merchant_key_store.merchant_id.clone().into(),
+                        )
+                        .await
+                        .change_context(errors::StorageError::DecryptionError)?,
+                );
+            }
+            Ok(domain_archives)
+        })
+        .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_incoming_webhook_archive_by_merchant_id_webhook_id(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        webhook_id: &str,
+        archive_update: storage::IncomingWebhookArchiveUpdate,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookArchive, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::IncomingWebhookArchive::update_by_merchant_id_webhook_id(
+            &conn,
+            merchant_id,
+            webhook_id,
+            archive_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))?
+        .convert(
+            state,
+            merchant_key_store.key.get_inner(),
+            merchant_key_store.merchant_id.clone().into(),
+        )
+        .await
+        .change_context(errors::StorageError::DecryptionError)
+    }
+}
+
+#[async_trait::async_trait]
+impl IncomingWebhookArchiveInterface for MockDb {
+    async fn insert_incoming_webhook_archive(
+        &self,
+        state: &KeyManagerState,
+        archive: domain::IncomingWebhookArchive,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookArchive, errors::StorageError> {
+        let mut locked_archives = self.incoming_webhook_archives.lock().await;
+        let stored_archive = Conversion::convert(archive)
+            .await
+            .change_context(errors::StorageError::EncryptionError)?;
+
+        locked_archives.push(stored_archive.clone());
+
+        stored_archive
+            .convert(
+                state,
+                merchant_key_store.key.get_inner(),
+                merchant_key_store.merchant_id.clone().into(),
+            )
+            .await
+            .change_context(errors::StorageError::DecryptionError)
+    }
+
+    async fn find_incoming_webhook_archive_by_merchant_id_webhook_id(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        webhook_id: &str,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookArchive, errors::StorageError> {
+        let locked_archives = self.incoming_webhook_archives.lock().await;
+        locked_archives
+            .iter()
+            .find(|archive| archive.merchant_id == *merchant_id && archive.webhook_id == webhook_id)
+            .cloned()
+            .async_map(|archive| async {
+                archive
+                    .convert(
+                        state,
+                        merchant_key_store.key.get_inner(),
+                        merchant_key_store.merchant_id.clone().into(),
+                    )
+                    .await
+                    .change_context(errors::StorageError::DecryptionError)
+            })
+            .await
+            .transpose()?
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No incoming webhook available with webhook_id = {webhook_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn list_incoming_webhook_archives_by_merchant_id_constraints(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        constraints: storage::IncomingWebhookArchiveListConstraints,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::IncomingWebhookArchive>, errors::StorageError> {
+        let locked_archives = self.incoming_webhook_archives.lock().await;
+        let mut archives = locked_archives
+            .iter()
+            .filter(|archive| {
+                archive.merchant_id == *merchant_id
+                    && constraints
+                        .created_after
+                        .map_or(true, |created_after| archive.created_at >= created_after)
+                    && constraints
+                        .created_before
+                        .map_or(true, |created_before| archive.created_at <= created_before)
+                    && constraints
+                        .connector_name
+                        .as_ref()
+                        .map_or(true, |connector_name| {
+                            archive.connector_name.as_ref() == Some(connector_name)
+                        })
+                    && constraints.merchant_connector_id.as_ref().map_or(
+                        true,
+                        |merchant_connector_id| {
+                            archive.merchant_connector_id.as_ref() == Some(merchant_connector_id)
+                        },
+                    )
+                    && constraints.profile_id.as_ref().map_or(true, |profile_id| {
+                        archive.profile_id.as_ref() == Some(profile_id)
+                    })
+                    && constraints
+                        .processing_status
+                        .map_or(true, |processing_status| {
+                            archive.processing_status == processing_status
+                        })
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        archives.sort_by(|first, second| second.created_at.cmp(&first.created_at));
+
+        let offset = constraints
+            .offset
+            .map(usize::try_from)
+            .transpose()
+            .map_err(|_| errors::StorageError::MockDbError)?
+            .unwrap_or(0);
+        let limit = constraints
+            .limit
+            .map(usize::try_from)
+            .transpose()
+            .map_err(|_| errors::StorageError::MockDbError)?
+            .unwrap_or(usize::MAX);
+
+        let mut domain_archives = Vec::new();
+        for archive in archives.into_iter().skip(offset).take(limit) {
+            domain_archives.push(
+                archive
+                    .convert(
+                        state,
+                        merchant_key_store.key.get_inner(),
+                        merchant_key_store.merchant_id.clone().into(),
+                    )
+                    .await
+                    .change_context(errors::StorageError::DecryptionError)?,
+            );
+        }
+
+        Ok(domain_archives)
+    }
+
+    async fn update_incoming_webhook_archive_by_merchant_id_webhook_id(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        webhook_id: &str,
+        archive_update: storage::IncomingWebhookArchiveUpdate,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookArchive, errors::StorageError> {
+        let mut locked_archives = self.incoming_webhook_archives.lock().await;
+        let archive = locked_archives
+            .iter_mut()
+            .find(|archive| archive.merchant_id == *merchant_id && archive.webhook_id == webhook_id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No incoming webhook available with webhook_id = {webhook_id}"
+            )))?;
+        *archive = archive_update.apply_changeset(archive.clone());
+
+        archive
+            .clone()
+            .convert(
+                state,
+                merchant_key_store.key.get_inner(),
+                merchant_key_store.merchant_id.clone().into(),
+            )
+            .await
+            .change_context(errors::StorageError::DecryptionError)
+    }
+}
+
+#[async_trait::async_trait]
+impl IncomingWebhookArchiveInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_incoming_webhook_archive(
+        &self,
+        state: &KeyManagerState,
+        archive: domain::IncomingWebhookArchive,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookArchive, errors::StorageError> {
+        self.diesel_store
+            .insert_incoming_webhook_archive(state, archive, merchant_key_store)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_incoming_webhook_archive_by_merchant_id_webhook_id(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        webhook_id: &str,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookArchive, errors::StorageError> {
+        self.diesel_store
+            .find_incoming_webhook_archive_by_merchant_id_webhook_id(
+                state,
+                merchant_id,
+                webhook_id,
+                merchant_key_store,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_incoming_webhook_archives_by_merchant_id_constraints(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        constraints: storage::IncomingWebhookArchiveListConstraints,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::IncomingWebhookArchive>, errors::StorageError> {
+        self.diesel_store
+            .list_incoming_webhook_archives_by_merchant_id_constraints(
+                state,
+                merchant_id,
+                constraints,
+                merchant_key_store,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_incoming_webhook_archive_by_merchant_id_webhook_id(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        webhook_id: &str,
+        archive_update: storage::IncomingWebhookArchiveUpdate,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookArchive, errors::StorageError> {
+        self.diesel_store
+            .update_incoming_webhook_archive_by_merchant_id_webhook_id(
+                state,
+                merchant_id,
+                webhook_id,
+                archive_update,
+                merchant_key_store,
+            )
+            .await
+    }
+}
@@ -0,0 +1,200 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage::{self, enums},
+};
+
+#[async_trait::async_trait]
+pub trait InvoiceInterface {
+    async fn insert_invoice(
+        &self,
+        invoice_new: storage::InvoiceNew,
+    ) -> CustomResult<storage::Invoice, errors::StorageError>;
+
+    async fn find_invoice_by_merchant_id_invoice_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        invoice_id: &str,
+    ) -> CustomResult<storage::Invoice, errors::StorageError>;
+
+    async fn filter_invoices_by_merchant_id_constraints(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: Option<&common_utils::id_type::CustomerId>,
+        status: Option<enums::InvoiceStatus>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::Invoice>, errors::StorageError>;
+
+    async fn update_invoice_by_merchant_id_invoice_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        invoice_id: &str,
+        invoice_update: storage::InvoiceUpdate,
+    ) -> CustomResult<storage::Invoice, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl InvoiceInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_invoice(
+        &self,
+        invoice_new: storage::InvoiceNew,
+    ) -> CustomResult<storage::Invoice, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        invoice_new
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_invoice_by_merchant_id_invoice_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        invoice_id: &str,
+    ) -> CustomResult<storage::Invoice, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Invoice::find_by_merchant_id_invoice_id(&conn, merchant_id, invoice_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn filter_invoices_by_merchant_id_constraints(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: Option<&common_utils::id_type::CustomerId>,
+        status: Option<enums::InvoiceStatus>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::Invoice>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Invoice::filter_by_merchant_id_constraints(
+            &conn,
+            merchant_id,
+            customer_id,
+            status,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_invoice_by_merchant_id_invoice_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        invoice_id: &str,
+        invoice_update: storage::InvoiceUpdate,
+    ) -> CustomResult<storage::Invoice, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::Invoice::update_by_merchant_id_invoice_id(
+            &conn,
+            merchant_id,
+            invoice_id,
+            invoice_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl InvoiceInterface for MockDb {
+    async fn insert_invoice(
+        &self,
+        _invoice_new: storage::InvoiceNew,
+    ) -> CustomResult<storage::Invoice, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_invoice_by_merchant_id_invoice_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _invoice_id: &str,
+    ) -> CustomResult<storage::Invoice, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn filter_invoices_by_merchant_id_constraints(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _customer_id: Option<&common_utils::id_type::CustomerId>,
+        _status: Option<enums::InvoiceStatus>,
+        _limit: Option<i64>,
+        _offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::Invoice>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_invoice_by_merchant_id_invoice_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _invoice_id: &str,
+        _invoice_update: storage::InvoiceUpdate,
+    ) -> CustomResult<storage::Invoice, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl InvoiceInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_invoice(
+        &self,
+        invoice_new: storage::InvoiceNew,
+    ) -> CustomResult<storage::Invoice, errors::StorageError> {
+        self.diesel_store.insert_invoice(invoice_new).await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_invoice_by_merchant_id_invoice_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        invoice_id: &str,
+    ) -> CustomResult<storage::Invoice, errors::StorageError> {
+        self.diesel_store
+            .find_invoice_by_merchant_id_invoice_id(merchant_id, invoice_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn filter_invoices_by_merchant_id_constraints(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: Option<&common_utils::id_type::CustomerId>,
+        status: Option<enums::InvoiceStatus>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::Invoice>, errors::StorageError> {
+        self.diesel_store
+            .filter_invoices_by_merchant_id_constraints(
+                merchant_id,
+                customer_id,
+                status,
+                limit,
+                offset,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_invoice_by_merchant_id_invoice_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        invoice_id: &str,
+        invoice_update: storage::InvoiceUpdate,
+    ) -> CustomResult<storage::Invoice, errors::StorageError> {
+        self.diesel_store
+            .update_invoice_by_merchant_id_invoice_id(merchant_id, invoice_id, invoice_update)
+            .await
+    }
+}
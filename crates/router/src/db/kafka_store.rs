@@ -745,6 +745,48 @@ impl EventInterface for KafkaStore {
             .await
     }
 
+    async fn list_events_by_merchant_id_webhook_endpoint_id(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &id_type::MerchantId,
+        webhook_endpoint_id: &str,
+        created_after: PrimitiveDateTime,
+        limit: i64,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
+        self.diesel_store
+            .list_events_by_merchant_id_webhook_endpoint_id(
+                state,
+                merchant_id,
+                webhook_endpoint_id,
+                created_after,
+                limit,
+                merchant_key_store,
+            )
+            .await
+    }
+
+    async fn list_initial_events_by_merchant_id_webhook_endpoint_id(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &id_type::MerchantId,
+        webhook_endpoint_id: &str,
+        created_after: Option<PrimitiveDateTime>,
+        limit: i64,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
+        self.diesel_store
+            .list_initial_events_by_merchant_id_webhook_endpoint_id(
+                state,
+                merchant_id,
+                webhook_endpoint_id,
+                created_after,
+                limit,
+                merchant_key_store,
+            )
+            .await
+    }
+
     async fn list_initial_events_by_profile_id_primary_object_id(
         &self,
         state: &KeyManagerState,
@@ -2298,6 +2340,17 @@ impl ProcessTrackerInterface for KafkaStore {
             .await
     }
 
+    async fn update_process_if_unchanged(
+        &self,
+        this: storage::ProcessTracker,
+        statuses: Vec<ProcessTrackerStatus>,
+        process: storage::ProcessTrackerUpdate,
+    ) -> CustomResult<usize, errors::StorageError> {
+        self.diesel_store
+            .update_process_if_unchanged(this, statuses, process)
+            .await
+    }
+
     async fn insert_process(
         &self,
         new: storage::ProcessTrackerNew,
@@ -0,0 +1,185 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait PaymentRecoveryInterface {
+    async fn insert_payment_recovery(
+        &self,
+        recovery_new: storage::PaymentRecoveryNew,
+    ) -> CustomResult<storage::PaymentRecovery, errors::StorageError>;
+
+    async fn find_payment_recovery_by_merchant_id_recovery_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        recovery_id: &str,
+    ) -> CustomResult<storage::PaymentRecovery, errors::StorageError>;
+
+    async fn find_payment_recovery_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Option<storage::PaymentRecovery>, errors::StorageError>;
+
+    async fn update_payment_recovery_by_merchant_id_recovery_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        recovery_id: &str,
+        recovery_update: storage::PaymentRecoveryUpdate,
+    ) -> CustomResult<storage::PaymentRecovery, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PaymentRecoveryInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_payment_recovery(
+        &self,
+        recovery_new: storage::PaymentRecoveryNew,
+    ) -> CustomResult<storage::PaymentRecovery, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        recovery_new
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payment_recovery_by_merchant_id_recovery_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        recovery_id: &str,
+    ) -> CustomResult<storage::PaymentRecovery, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PaymentRecovery::find_by_merchant_id_recovery_id(&conn, merchant_id, recovery_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payment_recovery_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Option<storage::PaymentRecovery>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PaymentRecovery::find_optional_by_merchant_id_payment_id(
+            &conn,
+            merchant_id,
+            payment_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_payment_recovery_by_merchant_id_recovery_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        recovery_id: &str,
+        recovery_update: storage::PaymentRecoveryUpdate,
+    ) -> CustomResult<storage::PaymentRecovery, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::PaymentRecovery::update_by_merchant_id_recovery_id(
+            &conn,
+            merchant_id,
+            recovery_id,
+            recovery_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentRecoveryInterface for MockDb {
+    async fn insert_payment_recovery(
+        &self,
+        _recovery_new: storage::PaymentRecoveryNew,
+    ) -> CustomResult<storage::PaymentRecovery, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_payment_recovery_by_merchant_id_recovery_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _recovery_id: &str,
+    ) -> CustomResult<storage::PaymentRecovery, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_payment_recovery_by_merchant_id_payment_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Option<storage::PaymentRecovery>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_payment_recovery_by_merchant_id_recovery_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _recovery_id: &str,
+        _recovery_update: storage::PaymentRecoveryUpdate,
+    ) -> CustomResult<storage::PaymentRecovery, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentRecoveryInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_payment_recovery(
+        &self,
+        recovery_new: storage::PaymentRecoveryNew,
+    ) -> CustomResult<storage::PaymentRecovery, errors::StorageError> {
+        self.diesel_store
+            .insert_payment_recovery(recovery_new)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payment_recovery_by_merchant_id_recovery_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        recovery_id: &str,
+    ) -> CustomResult<storage::PaymentRecovery, errors::StorageError> {
+        self.diesel_store
+            .find_payment_recovery_by_merchant_id_recovery_id(merchant_id, recovery_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payment_recovery_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Option<storage::PaymentRecovery>, errors::StorageError> {
+        self.diesel_store
+            .find_payment_recovery_by_merchant_id_payment_id(merchant_id, payment_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_payment_recovery_by_merchant_id_recovery_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        recovery_id: &str,
+        recovery_update: storage::PaymentRecoveryUpdate,
+    ) -> CustomResult<storage::PaymentRecovery, errors::StorageError> {
+        self.diesel_store
+            .update_payment_recovery_by_merchant_id_recovery_id(
+                merchant_id,
+                recovery_id,
+                recovery_update,
+            )
+            .await
+    }
+}
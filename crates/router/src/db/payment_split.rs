@@ -0,0 +1,138 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait PaymentSplitInterface {
+    async fn insert_payment_split(
+        &self,
+        split_new: storage::PaymentSplitNew,
+    ) -> CustomResult<storage::PaymentSplit, errors::StorageError>;
+
+    async fn find_payment_splits_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Vec<storage::PaymentSplit>, errors::StorageError>;
+
+    async fn update_payment_split_by_merchant_id_split_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        split_id: &str,
+        split_update: storage::PaymentSplitUpdate,
+    ) -> CustomResult<storage::PaymentSplit, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PaymentSplitInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_payment_split(
+        &self,
+        split_new: storage::PaymentSplitNew,
+    ) -> CustomResult<storage::PaymentSplit, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        split_new
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payment_splits_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Vec<storage::PaymentSplit>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PaymentSplit::find_by_merchant_id_payment_id(&conn, merchant_id, payment_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_payment_split_by_merchant_id_split_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        split_id: &str,
+        split_update: storage::PaymentSplitUpdate,
+    ) -> CustomResult<storage::PaymentSplit, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::PaymentSplit::update_by_merchant_id_split_id(
+            &conn,
+            merchant_id,
+            split_id,
+            split_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentSplitInterface for MockDb {
+    async fn insert_payment_split(
+        &self,
+        _split_new: storage::PaymentSplitNew,
+    ) -> CustomResult<storage::PaymentSplit, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_payment_splits_by_merchant_id_payment_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Vec<storage::PaymentSplit>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_payment_split_by_merchant_id_split_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _split_id: &str,
+        _split_update: storage::PaymentSplitUpdate,
+    ) -> CustomResult<storage::PaymentSplit, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentSplitInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_payment_split(
+        &self,
+        split_new: storage::PaymentSplitNew,
+    ) -> CustomResult<storage::PaymentSplit, errors::StorageError> {
+        self.diesel_store.insert_payment_split(split_new).await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payment_splits_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Vec<storage::PaymentSplit>, errors::StorageError> {
+        self.diesel_store
+            .find_payment_splits_by_merchant_id_payment_id(merchant_id, payment_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_payment_split_by_merchant_id_split_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        split_id: &str,
+        split_update: storage::PaymentSplitUpdate,
+    ) -> CustomResult<storage::PaymentSplit, errors::StorageError> {
+        self.diesel_store
+            .update_payment_split_by_merchant_id_split_id(merchant_id, split_id, split_update)
+            .await
+    }
+}
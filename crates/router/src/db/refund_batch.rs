@@ -0,0 +1,138 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait RefundBatchInterface {
+    async fn insert_refund_batch(
+        &self,
+        batch_new: storage::RefundBatchNew,
+    ) -> CustomResult<storage::RefundBatch, errors::StorageError>;
+
+    async fn find_refund_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<storage::RefundBatch, errors::StorageError>;
+
+    async fn update_refund_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+        batch_update: storage::RefundBatchUpdate,
+    ) -> CustomResult<storage::RefundBatch, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl RefundBatchInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_refund_batch(
+        &self,
+        batch_new: storage::RefundBatchNew,
+    ) -> CustomResult<storage::RefundBatch, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        batch_new
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_refund_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<storage::RefundBatch, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::RefundBatch::find_by_merchant_id_batch_id(&conn, merchant_id, batch_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_refund_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+        batch_update: storage::RefundBatchUpdate,
+    ) -> CustomResult<storage::RefundBatch, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::RefundBatch::update_by_merchant_id_batch_id(
+            &conn,
+            merchant_id,
+            batch_id,
+            batch_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl RefundBatchInterface for MockDb {
+    async fn insert_refund_batch(
+        &self,
+        _batch_new: storage::RefundBatchNew,
+    ) -> CustomResult<storage::RefundBatch, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_refund_batch_by_merchant_id_batch_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _batch_id: &str,
+    ) -> CustomResult<storage::RefundBatch, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_refund_batch_by_merchant_id_batch_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _batch_id: &str,
+        _batch_update: storage::RefundBatchUpdate,
+    ) -> CustomResult<storage::RefundBatch, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl RefundBatchInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_refund_batch(
+        &self,
+        batch_new: storage::RefundBatchNew,
+    ) -> CustomResult<storage::RefundBatch, errors::StorageError> {
+        self.diesel_store.insert_refund_batch(batch_new).await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_refund_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<storage::RefundBatch, errors::StorageError> {
+        self.diesel_store
+            .find_refund_batch_by_merchant_id_batch_id(merchant_id, batch_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_refund_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+        batch_update: storage::RefundBatchUpdate,
+    ) -> CustomResult<storage::RefundBatch, errors::StorageError> {
+        self.diesel_store
+            .update_refund_batch_by_merchant_id_batch_id(merchant_id, batch_id, batch_update)
+            .await
+    }
+}
@@ -0,0 +1,181 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait ReusablePaymentLinkInterface {
+    async fn insert_reusable_payment_link(
+        &self,
+        link_new: storage::ReusablePaymentLinkNew,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError>;
+
+    async fn find_reusable_payment_link_by_merchant_id_link_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        link_id: &str,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError>;
+
+    async fn list_reusable_payment_links_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ReusablePaymentLink>, errors::StorageError>;
+
+    async fn update_reusable_payment_link_by_merchant_id_link_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        link_id: &str,
+        link_update: storage::ReusablePaymentLinkUpdate,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl ReusablePaymentLinkInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_reusable_payment_link(
+        &self,
+        link_new: storage::ReusablePaymentLinkNew,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        link_new
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_reusable_payment_link_by_merchant_id_link_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        link_id: &str,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ReusablePaymentLink::find_by_merchant_id_link_id(&conn, merchant_id, link_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_reusable_payment_links_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ReusablePaymentLink>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ReusablePaymentLink::list_by_merchant_id(&conn, merchant_id, limit, offset)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_reusable_payment_link_by_merchant_id_link_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        link_id: &str,
+        link_update: storage::ReusablePaymentLinkUpdate,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ReusablePaymentLink::update_by_merchant_id_link_id(
+            &conn,
+            merchant_id,
+            link_id,
+            link_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl ReusablePaymentLinkInterface for MockDb {
+    async fn insert_reusable_payment_link(
+        &self,
+        _link_new: storage::ReusablePaymentLinkNew,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_reusable_payment_link_by_merchant_id_link_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _link_id: &str,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_reusable_payment_links_by_merchant_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _limit: Option<i64>,
+        _offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ReusablePaymentLink>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_reusable_payment_link_by_merchant_id_link_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _link_id: &str,
+        _link_update: storage::ReusablePaymentLinkUpdate,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl ReusablePaymentLinkInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_reusable_payment_link(
+        &self,
+        link_new: storage::ReusablePaymentLinkNew,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError> {
+        self.diesel_store
+            .insert_reusable_payment_link(link_new)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_reusable_payment_link_by_merchant_id_link_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        link_id: &str,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError> {
+        self.diesel_store
+            .find_reusable_payment_link_by_merchant_id_link_id(merchant_id, link_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_reusable_payment_links_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ReusablePaymentLink>, errors::StorageError> {
+        self.diesel_store
+            .list_reusable_payment_links_by_merchant_id(merchant_id, limit, offset)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_reusable_payment_link_by_merchant_id_link_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        link_id: &str,
+        link_update: storage::ReusablePaymentLinkUpdate,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError> {
+        self.diesel_store
+            .update_reusable_payment_link_by_merchant_id_link_id(merchant_id, link_id, link_update)
+            .await
+    }
+}
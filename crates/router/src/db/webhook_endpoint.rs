@@ -0,0 +1,347 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait WebhookEndpointInterface {
+    async fn insert_webhook_endpoint(
+        &self,
+        endpoint_new: storage::WebhookEndpointNew,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError>;
+
+    async fn find_webhook_endpoint_by_merchant_id_endpoint_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        endpoint_id: &str,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError>;
+
+    async fn list_webhook_endpoints_by_merchant_id_profile_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: &common_utils::id_type::ProfileId,
+    ) -> CustomResult<Vec<storage::WebhookEndpoint>, errors::StorageError>;
+
+    async fn update_webhook_endpoint_by_merchant_id_endpoint_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        endpoint_id: &str,
+        endpoint_update: storage::WebhookEndpointUpdate,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError>;
+
+    async fn record_webhook_endpoint_delivery_failure(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        endpoint_id: &str,
+        failed_at: time::PrimitiveDateTime,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError>;
+
+    async fn delete_webhook_endpoint_by_merchant_id_endpoint_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        endpoint_id: &str,
+    ) -> CustomResult<bool, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl WebhookEndpointInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_webhook_endpoint(
+        &self,
+        endpoint_new: storage::WebhookEndpointNew,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        endpoint_new
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_webhook_endpoint_by_merchant_id_endpoint_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        endpoint_id: &str,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::WebhookEndpoint::find_by_merchant_id_endpoint_id(&conn, merchant_id, endpoint_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_webhook_endpoints_by_merchant_id_profile_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: &common_utils::id_type::ProfileId,
+    ) -> CustomResult<Vec<storage::WebhookEndpoint>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::WebhookEndpoint::list_by_merchant_id_profile_id(&conn, merchant_id, profile_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_webhook_endpoint_by_merchant_id_endpoint_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        endpoint_id: &str,
+        endpoint_update: storage::WebhookEndpointUpdate,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::WebhookEndpoint::update_by_merchant_id_endpoint_id(
+            &conn,
+            merchant_id,
+            endpoint_id,
+            endpoint_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn record_webhook_endpoint_delivery_failure(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        endpoint_id: &str,
+        failed_at: time::PrimitiveDateTime,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::WebhookEndpoint::record_delivery_failure_by_merchant_id_endpoint_id(
+            &conn,
+            merchant_id,
+            endpoint_id,
+            failed_at,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_webhook_endpoint_by_merchant_id_endpoint_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        endpoint_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::WebhookEndpoint::delete_by_merchant_id_endpoint_id(&conn, merchant_id, endpoint_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl WebhookEndpointInterface for MockDb {
+    async fn insert_webhook_endpoint(
+        &self,
+        endpoint_new: storage::WebhookEndpointNew,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError> {
+        let mut webhook_endpoints = self.webhook_endpoints.lock().await;
+        if webhook_endpoints
+            .iter()
+            .any(|endpoint| endpoint.endpoint_id == endpoint_new.endpoint_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "endpoint_id",
+                key: Some(endpoint_new.endpoint_id.clone()),
+            })?
+        }
+
+        let endpoint = storage::WebhookEndpoint {
+            endpoint_id: endpoint_new.endpoint_id,
+            merchant_id: endpoint_new.merchant_id,
+            profile_id: endpoint_new.profile_id,
+            url: endpoint_new.url,
+            secret: endpoint_new.secret,
+            enabled_events: endpoint_new.enabled_events,
+            is_enabled: endpoint_new.is_enabled,
+            description: endpoint_new.description,
+            created_at: endpoint_new.created_at,
+            modified_at: endpoint_new.modified_at,
+            previous_secret: endpoint_new.previous_secret,
+            previous_secret_expires_at: endpoint_new.previous_secret_expires_at,
+            consecutive_failures: endpoint_new.consecutive_failures,
+            last_successful_delivery_at: endpoint_new.last_successful_delivery_at,
+            last_failed_delivery_at: endpoint_new.last_failed_delivery_at,
+            suspended_at: endpoint_new.suspended_at,
+            strict_ordering: endpoint_new.strict_ordering,
+        };
+        webhook_endpoints.push(endpoint.clone());
+
+        Ok(endpoint)
+    }
+
+    async fn find_webhook_endpoint_by_merchant_id_endpoint_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        endpoint_id: &str,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError> {
+        self.webhook_endpoints
+            .lock()
+            .await
+            .iter()
+            .find(|endpoint| {
+                endpoint.merchant_id == *merchant_id && endpoint.endpoint_id == endpoint_id
+            })
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No webhook endpoint available with endpoint_id = {endpoint_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn list_webhook_endpoints_by_merchant_id_profile_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: &common_utils::id_type::ProfileId,
+    ) -> CustomResult<Vec<storage::WebhookEndpoint>, errors::StorageError> {
+        Ok(self
+            .webhook_endpoints
+            .lock()
+            .await
+            .iter()
+            .filter(|endpoint| {
+                endpoint.merchant_id == *merchant_id && endpoint.profile_id == *profile_id
+            })
+            .cloned()
+            .collect())
+    }
+
+    async fn update_webhook_endpoint_by_merchant_id_endpoint_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        endpoint_id: &str,
+        endpoint_update: storage::WebhookEndpointUpdate,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError> {
+        let mut webhook_endpoints = self.webhook_endpoints.lock().await;
+        let endpoint = webhook_endpoints
+            .iter_mut()
+            .find(|endpoint| {
+                endpoint.merchant_id == *merchant_id && endpoint.endpoint_id == endpoint_id
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No webhook endpoint available with endpoint_id = {endpoint_id}"
+            )))?;
+        *endpoint = endpoint_update.apply_changeset(endpoint.clone());
+
+        Ok(endpoint.clone())
+    }
+
+    async fn record_webhook_endpoint_delivery_failure(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        endpoint_id: &str,
+        failed_at: time::PrimitiveDateTime,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError> {
+        let mut webhook_endpoints = self.webhook_endpoints.lock().await;
+        let endpoint = webhook_endpoints
+            .iter_mut()
+            .find(|endpoint| {
+                endpoint.merchant_id == *merchant_id && endpoint.endpoint_id == endpoint_id
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No webhook endpoint available with endpoint_id = {endpoint_id}"
+            )))?;
+        endpoint.consecutive_failures = endpoint.consecutive_failures.saturating_add(1);
+        endpoint.last_failed_delivery_at = Some(failed_at);
+
+        Ok(endpoint.clone())
+    }
+
+    async fn delete_webhook_endpoint_by_merchant_id_endpoint_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        endpoint_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let mut webhook_endpoints = self.webhook_endpoints.lock().await;
+        let endpoints_count = webhook_endpoints.len();
+        webhook_endpoints.retain(|endpoint| {
+            endpoint.merchant_id != *merchant_id || endpoint.endpoint_id != endpoint_id
+        });
+
+        Ok(webhook_endpoints.len() != endpoints_count)
+    }
+}
+
+#[async_trait::async_trait]
+impl WebhookEndpointInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_webhook_endpoint(
+        &self,
+        endpoint_new: storage::WebhookEndpointNew,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError> {
+        self.diesel_store
+            .insert_webhook_endpoint(endpoint_new)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_webhook_endpoint_by_merchant_id_endpoint_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        endpoint_id: &str,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError> {
+        self.diesel_store
+            .find_webhook_endpoint_by_merchant_id_endpoint_id(merchant_id, endpoint_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_webhook_endpoints_by_merchant_id_profile_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: &common_utils::id_type::ProfileId,
+    ) -> CustomResult<Vec<storage::WebhookEndpoint>, errors::StorageError> {
+        self.diesel_store
+            .list_webhook_endpoints_by_merchant_id_profile_id(merchant_id, profile_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_webhook_endpoint_by_merchant_id_endpoint_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        endpoint_id: &str,
+        endpoint_update: storage::WebhookEndpointUpdate,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError> {
+        self.diesel_store
+            .update_webhook_endpoint_by_merchant_id_endpoint_id(
+                merchant_id,
+                endpoint_id,
+                endpoint_update,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn record_webhook_endpoint_delivery_failure(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        endpoint_id: &str,
+        failed_at: time::PrimitiveDateTime,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError> {
+        self.diesel_store
+            .record_webhook_endpoint_delivery_failure(merchant_id, endpoint_id, failed_at)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_webhook_endpoint_by_merchant_id_endpoint_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        endpoint_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .delete_webhook_endpoint_by_merchant_id_endpoint_id(merchant_id, endpoint_id)
+            .await
+    }
+}
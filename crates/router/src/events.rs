@@ -19,6 +19,7 @@ pub mod audit_events;
 pub mod connector_api_logs;
 pub mod event_logger;
 pub mod outgoing_webhook_logs;
+pub mod success_rate_alerts;
 #[derive(Debug, Serialize, Clone, Copy)]
 #[serde(rename_all = "snake_case")]
 pub enum EventType {
@@ -35,6 +36,7 @@ pub enum EventType {
     Payout,
     Consolidated,
     Authentication,
+    SuccessRateAlert,
 }
 
 #[derive(Debug, Default, Deserialize, Clone)]
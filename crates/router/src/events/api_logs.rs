@@ -8,10 +8,11 @@ use time::OffsetDateTime;
 use super::EventType;
 #[cfg(feature = "dummy_connector")]
 use crate::routes::dummy_connector::types::{
-    DummyConnectorPaymentCompleteRequest, DummyConnectorPaymentConfirmRequest,
-    DummyConnectorPaymentRequest, DummyConnectorPaymentResponse,
-    DummyConnectorPaymentRetrieveRequest, DummyConnectorRefundRequest,
-    DummyConnectorRefundResponse, DummyConnectorRefundRetrieveRequest,
+    DummyConnectorCaptureRequest, DummyConnectorPaymentCompleteRequest,
+    DummyConnectorPaymentConfirmRequest, DummyConnectorPaymentRequest,
+    DummyConnectorPaymentResponse, DummyConnectorPaymentRetrieveRequest,
+    DummyConnectorRefundRequest, DummyConnectorRefundResponse, DummyConnectorRefundRetrieveRequest,
+    DummyConnectorScenario, DummyConnectorScenarioCreateRequest, DummyConnectorScenarioRequest,
 };
 use crate::{
     core::payments::PaymentsRedirectResponseData,
@@ -120,7 +121,11 @@ impl_api_event_type!(
         DummyConnectorPaymentConfirmRequest,
         DummyConnectorRefundRetrieveRequest,
         DummyConnectorRefundResponse,
-        DummyConnectorRefundRequest
+        DummyConnectorRefundRequest,
+        DummyConnectorCaptureRequest,
+        DummyConnectorScenario,
+        DummyConnectorScenarioRequest,
+        DummyConnectorScenarioCreateRequest
     )
 );
 
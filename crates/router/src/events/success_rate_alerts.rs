@@ -0,0 +1,14 @@
+use api_models::analytics::success_rate_alerts::SuccessRateAlert;
+
+use super::EventType;
+use crate::services::kafka::KafkaMessage;
+
+impl KafkaMessage for SuccessRateAlert {
+    fn event_type(&self) -> EventType {
+        EventType::SuccessRateAlert
+    }
+
+    fn key(&self) -> String {
+        format!("{}_{}", self.alert_id, self.status)
+    }
+}
@@ -22,6 +22,10 @@ pub mod files;
 pub mod fraud_check;
 pub mod gsm;
 pub mod health;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod incoming_webhook_archive;
+#[cfg(feature = "v1")]
+pub mod invoices;
 pub mod lock_utils;
 #[cfg(feature = "v1")]
 pub mod locker_migration;
@@ -46,6 +50,8 @@ pub mod recon;
 pub mod refunds;
 #[cfg(feature = "olap")]
 pub mod routing;
+#[cfg(feature = "v1")]
+pub mod subscriptions;
 #[cfg(feature = "olap")]
 pub mod user;
 #[cfg(feature = "olap")]
@@ -55,6 +61,8 @@ pub mod verification;
 #[cfg(feature = "olap")]
 pub mod verify_connector;
 #[cfg(all(feature = "olap", feature = "v1"))]
+pub mod webhook_endpoints;
+#[cfg(all(feature = "olap", feature = "v1"))]
 pub mod webhook_events;
 #[cfg(feature = "v1")]
 pub mod webhooks;
@@ -67,12 +75,14 @@ pub use self::app::Forex;
 pub use self::app::Recon;
 pub use self::app::{
     ApiKeys, AppState, ApplePayCertificatesMigration, Cache, Cards, Configs, ConnectorOnboarding,
-    Customers, Disputes, EphemeralKey, Files, Gsm, Health, Mandates, MerchantAccount,
+    Customers, Disputes, EphemeralKey, Files, Gsm, Health, Invoices, Mandates, MerchantAccount,
     MerchantConnectorAccount, PaymentLink, PaymentMethods, Payments, Poll, Profile, ProfileNew,
-    Refunds, SessionState, User, Webhooks,
+    Refunds, SessionState, Subscriptions, User, Webhooks,
 };
 #[cfg(feature = "olap")]
-pub use self::app::{Blocklist, Organization, Routing, Verify, WebhookEvents};
+pub use self::app::{
+    Blocklist, IncomingWebhookArchive, Organization, Routing, Verify, WebhookEvents,
+};
 #[cfg(feature = "payouts")]
 pub use self::app::{PayoutLink, Payouts};
 #[cfg(all(
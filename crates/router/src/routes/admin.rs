@@ -884,6 +884,147 @@ pub async fn merchant_account_kv_status(
     .await
 }
 
+/// Merchant Account - Storage Scheme Migration
+///
+/// Start a managed migration of the Merchant Account to another storage scheme
+#[cfg(all(feature = "v1", feature = "kv_store"))]
+#[instrument(skip_all, fields(flow = ?Flow::StorageSchemeMigrationCreate))]
+pub async fn merchant_account_storage_scheme_migrate(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    json_payload: web::Json<admin::StorageSchemeMigrationRequest>,
+) -> HttpResponse {
+    let flow = Flow::StorageSchemeMigrationCreate;
+    let mut payload = json_payload.into_inner();
+    payload.merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req, _| {
+            crate::core::storage_scheme_migration::start_storage_scheme_migration(state, req)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Account - Storage Scheme Migration Status
+///
+/// Retrieve the progress of the storage scheme migration of the Merchant Account
+#[cfg(all(feature = "v1", feature = "kv_store"))]
+#[instrument(skip_all, fields(flow = ?Flow::StorageSchemeMigrationRetrieve))]
+pub async fn merchant_account_storage_scheme_migration_status(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+) -> HttpResponse {
+    let flow = Flow::StorageSchemeMigrationRetrieve;
+    let merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        merchant_id,
+        |state, _, req, _| {
+            crate::core::storage_scheme_migration::retrieve_storage_scheme_migration(state, req)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Account - Storage Scheme Migration Rollback
+///
+/// Roll back the storage scheme migration in progress for the Merchant Account
+#[cfg(all(feature = "v1", feature = "kv_store"))]
+#[instrument(skip_all, fields(flow = ?Flow::StorageSchemeMigrationRollback))]
+pub async fn merchant_account_storage_scheme_migration_rollback(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+) -> HttpResponse {
+    let flow = Flow::StorageSchemeMigrationRollback;
+    let merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        merchant_id,
+        |state, _, req, _| {
+            crate::core::storage_scheme_migration::rollback_storage_scheme_migration(state, req)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Account - Toggle Account Updater
+///
+/// Enable or disable the periodic checking of the saved cards of the Merchant Account for updates
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::AccountUpdaterToggle))]
+pub async fn merchant_account_toggle_account_updater(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    json_payload: web::Json<admin::ToggleAccountUpdaterRequest>,
+) -> HttpResponse {
+    let flow = Flow::AccountUpdaterToggle;
+    let mut payload = json_payload.into_inner();
+    payload.merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req, _| {
+            crate::core::payment_methods::account_updater::toggle_account_updater(state, req)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Account - Account Updater Status
+///
+/// Retrieve whether the saved cards of the Merchant Account are checked for updates
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::AccountUpdaterRetrieve))]
+pub async fn merchant_account_account_updater_status(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+) -> HttpResponse {
+    let flow = Flow::AccountUpdaterRetrieve;
+    let merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        merchant_id,
+        |state, _, req, _| {
+            crate::core::payment_methods::account_updater::retrieve_account_updater_status(
+                state, req,
+            )
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 /// Merchant Account - KV Status
 ///
 /// Toggle KV mode for the Merchant Account
@@ -9,7 +9,10 @@ use common_enums::TransactionType;
 use common_utils::crypto::Blake3;
 #[cfg(feature = "email")]
 use external_services::email::{ses::AwsSes, EmailService};
-use external_services::{file_storage::FileStorageInterface, grpc_client::GrpcClients};
+use external_services::{
+    account_updater::AccountUpdaterInterface, file_storage::FileStorageInterface,
+    grpc_client::GrpcClients,
+};
 use hyperswitch_interfaces::{
     encryption_interface::EncryptionManagementInterface,
     secrets_interface::secret_state::{RawSecret, SecuredSecret},
@@ -30,6 +33,8 @@ use super::dummy_connector::*;
     feature = "oltp"
 ))]
 use super::ephemeral_key::*;
+#[cfg(all(feature = "olap", feature = "v1"))]
+use super::incoming_webhook_archive;
 #[cfg(any(feature = "olap", feature = "oltp"))]
 use super::payment_methods::*;
 #[cfg(feature = "payouts")]
@@ -48,6 +53,8 @@ use super::poll;
 use super::routing;
 #[cfg(feature = "olap")]
 use super::verification::{apple_pay_merchant_registration, retrieve_apple_pay_verified_domains};
+#[cfg(all(feature = "olap", feature = "v1"))]
+use super::webhook_endpoints;
 #[cfg(all(feature = "oltp", feature = "v1"))]
 use super::webhooks::*;
 use super::{
@@ -59,7 +66,7 @@ use super::{apple_pay_certificates_migration, blocklist, payment_link, webhook_e
 #[cfg(any(feature = "olap", feature = "oltp"))]
 use super::{configs::*, customers::*, payments};
 #[cfg(all(any(feature = "olap", feature = "oltp"), feature = "v1"))]
-use super::{mandates::*, refunds::*};
+use super::{invoices, mandates::*, refunds::*, subscriptions};
 #[cfg(feature = "olap")]
 pub use crate::analytics::opensearch::OpenSearchClient;
 #[cfg(feature = "olap")]
@@ -100,6 +107,7 @@ pub struct SessionState {
     #[cfg(feature = "olap")]
     pub pool: AnalyticsProvider,
     pub file_storage_client: Arc<dyn FileStorageInterface>,
+    pub account_updater_client: Option<Arc<dyn AccountUpdaterInterface>>,
     pub request_id: Option<RequestId>,
     pub base_url: String,
     pub tenant: Tenant,
@@ -202,6 +210,7 @@ pub struct AppState {
     pub opensearch_client: Arc<OpenSearchClient>,
     pub request_id: Option<RequestId>,
     pub file_storage_client: Arc<dyn FileStorageInterface>,
+    pub account_updater_client: Option<Arc<dyn AccountUpdaterInterface>>,
     pub encryption_client: Arc<dyn EncryptionManagementInterface>,
     pub grpc_client: Arc<GrpcClients>,
 }
@@ -353,6 +362,8 @@ impl AppState {
 
             let file_storage_client = conf.file_storage.get_file_storage_client().await;
 
+            let account_updater_client = conf.account_updater.source.get_account_updater_client();
+
             let grpc_client = conf.grpc_client.get_grpc_client_interface().await;
 
             Self {
@@ -370,6 +381,7 @@ impl AppState {
                 opensearch_client,
                 request_id: None,
                 file_storage_client,
+                account_updater_client,
                 encryption_client,
                 grpc_client,
             }
@@ -445,6 +457,7 @@ impl AppState {
             #[cfg(feature = "olap")]
             pool: self.pools.get(tenant).ok_or_else(err)?.clone(),
             file_storage_client: self.file_storage_client.clone(),
+            account_updater_client: self.account_updater_client.clone(),
             request_id: self.request_id,
             base_url: tenant_conf.base_url.clone(),
             tenant: tenant_conf.clone(),
@@ -486,12 +499,27 @@ impl DummyConnector {
                 web::resource("/payments/{payment_id}")
                     .route(web::get().to(dummy_connector_payment_data)),
             )
+            .service(
+                web::resource("/{payment_id}/capture")
+                    .route(web::post().to(dummy_connector_capture)),
+            )
             .service(
                 web::resource("/{payment_id}/refund").route(web::post().to(dummy_connector_refund)),
             )
             .service(
                 web::resource("/refunds/{refund_id}")
                     .route(web::get().to(dummy_connector_refund_data)),
+            )
+            .service(web::resource("/payout").route(web::post().to(dummy_connector_payout)))
+            .service(
+                web::resource("/payouts/{payout_id}")
+                    .route(web::get().to(dummy_connector_payout_data)),
+            )
+            .service(
+                web::resource("/scenarios/{scenario_id}")
+                    .route(web::post().to(dummy_connector_create_scenario))
+                    .route(web::get().to(dummy_connector_scenario_data))
+                    .route(web::delete().to(dummy_connector_delete_scenario)),
             );
         web::scope("/dummy-connector")
             .app_data(web::Data::new(state))
@@ -1036,6 +1064,14 @@ impl Refunds {
             route = route
                 .service(web::resource("").route(web::post().to(refunds_create)))
                 .service(web::resource("/sync").route(web::post().to(refunds_retrieve_with_body)))
+                .service(web::resource("/batch").route(web::post().to(refund_batch_create)))
+                .service(
+                    web::resource("/batch/{batch_id}").route(web::get().to(refund_batch_retrieve)),
+                )
+                .service(
+                    web::resource("/batch/{batch_id}/report")
+                        .route(web::get().to(refund_batch_report)),
+                )
                 .service(
                     web::resource("/{id}")
                         .route(web::get().to(refunds_retrieve))
@@ -1055,6 +1091,19 @@ impl Payouts {
         let mut route = web::scope("/payouts").app_data(web::Data::new(state));
         route = route.service(web::resource("/create").route(web::post().to(payouts_create)));
 
+        #[cfg(feature = "v1")]
+        {
+            route = route
+                .service(web::resource("/batch").route(web::post().to(payout_batch_create)))
+                .service(
+                    web::resource("/batch/{batch_id}").route(web::get().to(payout_batch_retrieve)),
+                )
+                .service(
+                    web::resource("/batch/{batch_id}/results")
+                        .route(web::get().to(payout_batch_results_retrieve)),
+                );
+        }
+
         #[cfg(feature = "olap")]
         {
             route = route
@@ -1279,7 +1328,7 @@ impl MerchantAccount {
 #[cfg(all(feature = "olap", feature = "v1"))]
 impl MerchantAccount {
     pub fn server(state: AppState) -> Scope {
-        web::scope("/accounts")
+        let mut route = web::scope("/accounts")
             .app_data(web::Data::new(state))
             .service(web::resource("").route(web::post().to(admin::merchant_account_create)))
             .service(web::resource("/list").route(web::get().to(admin::merchant_account_list)))
@@ -1288,6 +1337,28 @@ impl MerchantAccount {
                     .route(web::post().to(admin::merchant_account_toggle_kv))
                     .route(web::get().to(admin::merchant_account_kv_status)),
             )
+            .service(
+                web::resource("/{id}/account_updater")
+                    .route(web::post().to(admin::merchant_account_toggle_account_updater))
+                    .route(web::get().to(admin::merchant_account_account_updater_status)),
+            );
+
+        #[cfg(feature = "kv_store")]
+        {
+            route = route
+                .service(
+                    web::resource("/{id}/kv/migrate")
+                        .route(web::post().to(admin::merchant_account_storage_scheme_migrate))
+                        .route(
+                            web::get().to(admin::merchant_account_storage_scheme_migration_status),
+                        ),
+                )
+                .service(web::resource("/{id}/kv/migrate/rollback").route(
+                    web::post().to(admin::merchant_account_storage_scheme_migration_rollback),
+                ));
+        }
+
+        route
             .service(
                 web::resource("/transfer")
                     .route(web::post().to(admin::merchant_account_transfer_keys)),
@@ -1402,6 +1473,60 @@ impl Mandates {
     }
 }
 
+pub struct Subscriptions;
+
+#[cfg(all(any(feature = "olap", feature = "oltp"), feature = "v1"))]
+impl Subscriptions {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/subscriptions")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("")
+                    .route(web::post().to(subscriptions::create_subscription))
+                    .route(web::get().to(subscriptions::list_subscriptions)),
+            )
+            .service(
+                web::resource("/plans")
+                    .route(web::post().to(subscriptions::create_subscription_plan))
+                    .route(web::get().to(subscriptions::list_subscription_plans)),
+            )
+            .service(
+                web::resource("/plans/{plan_id}")
+                    .route(web::get().to(subscriptions::retrieve_subscription_plan))
+                    .route(web::post().to(subscriptions::update_subscription_plan)),
+            )
+            .service(
+                web::resource("/{subscription_id}")
+                    .route(web::get().to(subscriptions::retrieve_subscription)),
+            )
+            .service(
+                web::resource("/{subscription_id}/cancel")
+                    .route(web::post().to(subscriptions::cancel_subscription)),
+            )
+    }
+}
+
+pub struct Invoices;
+
+#[cfg(all(any(feature = "olap", feature = "oltp"), feature = "v1"))]
+impl Invoices {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/invoices")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("")
+                    .route(web::post().to(invoices::create_invoice))
+                    .route(web::get().to(invoices::list_invoices)),
+            )
+            .service(
+                web::resource("/{invoice_id}").route(web::get().to(invoices::retrieve_invoice)),
+            )
+            .service(
+                web::resource("/{invoice_id}/void").route(web::post().to(invoices::void_invoice)),
+            )
+    }
+}
+
 pub struct Webhooks;
 
 #[cfg(all(feature = "oltp", feature = "v1"))]
@@ -1493,6 +1618,9 @@ impl ApiKeys {
                     .route(web::put().to(api_keys::api_key_update))
                     .route(web::delete().to(api_keys::api_key_revoke)),
             )
+            .service(
+                web::resource("/{key_id}/rotate").route(web::post().to(api_keys::api_key_rotate)),
+            )
     }
 }
 
@@ -1509,6 +1637,9 @@ impl ApiKeys {
                     .route(web::post().to(api_keys::api_key_update))
                     .route(web::delete().to(api_keys::api_key_revoke)),
             )
+            .service(
+                web::resource("/{key_id}/rotate").route(web::post().to(api_keys::api_key_rotate)),
+            )
     }
 }
 
@@ -1546,6 +1677,10 @@ impl Disputes {
                     .route(web::put().to(disputes::attach_dispute_evidence))
                     .route(web::delete().to(disputes::delete_dispute_evidence)),
             )
+            .service(
+                web::resource("/evidence/{dispute_id}/draft")
+                    .route(web::post().to(disputes::create_dispute_evidence_draft)),
+            )
             .service(
                 web::resource("/evidence/{dispute_id}")
                     .route(web::get().to(disputes::retrieve_dispute_evidence)),
@@ -1589,6 +1724,7 @@ impl Cache {
         web::scope("/cache")
             .app_data(web::Data::new(state))
             .service(web::resource("/invalidate/{key}").route(web::post().to(invalidate)))
+            .service(web::resource("/stats").route(web::get().to(retrieve_stats)))
     }
 }
 
@@ -1600,6 +1736,20 @@ impl PaymentLink {
         web::scope("/payment_link")
             .app_data(web::Data::new(state))
             .service(web::resource("/list").route(web::post().to(payment_link::payments_link_list)))
+            .service(
+                web::resource("/reusable")
+                    .route(web::post().to(payment_link::reusable_payment_link_create))
+                    .route(web::get().to(payment_link::reusable_payment_link_list)),
+            )
+            .service(
+                web::resource("/reusable/{link_id}")
+                    .route(web::get().to(payment_link::reusable_payment_link_retrieve))
+                    .route(web::post().to(payment_link::reusable_payment_link_update)),
+            )
+            .service(
+                web::resource("/reusable/{merchant_id}/{link_id}")
+                    .route(web::get().to(payment_link::initiate_reusable_payment_link)),
+            )
             .service(
                 web::resource("/{payment_link_id}")
                     .route(web::get().to(payment_link::payment_link_retrieve)),
@@ -1737,6 +1887,50 @@ impl Profile {
                     .service(
                         web::resource("/toggle_connector_agnostic_mit")
                             .route(web::post().to(profiles::toggle_connector_agnostic_mit)),
+                    )
+                    .service(
+                        web::resource("/rotate_webhook_secret")
+                            .route(web::post().to(profiles::rotate_profile_webhook_secret)),
+                    )
+                    .service(
+                        web::resource("/success_rate_alerts")
+                            .route(web::get().to(profiles::retrieve_success_rate_alert_config))
+                            .route(web::post().to(profiles::update_success_rate_alert_config))
+                            .route(web::delete().to(profiles::delete_success_rate_alert_config)),
+                    )
+                    .service(
+                        web::scope("/webhook_endpoints")
+                            .service(
+                                web::resource("")
+                                    .route(
+                                        web::post().to(webhook_endpoints::create_webhook_endpoint),
+                                    )
+                                    .route(
+                                        web::get().to(webhook_endpoints::list_webhook_endpoints),
+                                    ),
+                            )
+                            .service(
+                                web::resource("/{endpoint_id}")
+                                    .route(
+                                        web::get().to(webhook_endpoints::retrieve_webhook_endpoint),
+                                    )
+                                    .route(
+                                        web::post().to(webhook_endpoints::update_webhook_endpoint),
+                                    )
+                                    .route(
+                                        web::delete()
+                                            .to(webhook_endpoints::delete_webhook_endpoint),
+                                    ),
+                            )
+                            .service(web::resource("/{endpoint_id}/rotate_secret").route(
+                                web::post().to(webhook_endpoints::rotate_webhook_endpoint_secret),
+                            ))
+                            .service(web::resource("/{endpoint_id}/health").route(
+                                web::get().to(webhook_endpoints::retrieve_webhook_endpoint_health),
+                            ))
+                            .service(web::resource("/{endpoint_id}/reenable").route(
+                                web::post().to(webhook_endpoints::reenable_webhook_endpoint),
+                            )),
                     ),
             )
     }
@@ -2088,6 +2282,32 @@ impl ConnectorOnboarding {
     }
 }
 
+#[cfg(feature = "olap")]
+pub struct IncomingWebhookArchive;
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+impl IncomingWebhookArchive {
+    pub fn server(config: AppState) -> Scope {
+        web::scope("/incoming_webhooks/{merchant_id}")
+            .app_data(web::Data::new(config))
+            .service(
+                web::resource("")
+                    .route(web::get().to(incoming_webhook_archive::list_incoming_webhooks)),
+            )
+            .service(
+                web::scope("/{webhook_id}")
+                    .service(
+                        web::resource("").route(
+                            web::get().to(incoming_webhook_archive::retrieve_incoming_webhook),
+                        ),
+                    )
+                    .service(web::resource("reprocess").route(
+                        web::post().to(incoming_webhook_archive::reprocess_incoming_webhook),
+                    )),
+            )
+    }
+}
+
 #[cfg(feature = "olap")]
 pub struct WebhookEvents;
 
@@ -28,3 +28,19 @@ pub async fn invalidate(
     )
     .await
 }
+
+#[instrument(skip_all, fields(flow = ?Flow::CacheStatsRetrieve))]
+pub async fn retrieve_stats(state: web::Data<AppState>, req: HttpRequest) -> impl Responder {
+    let flow = Flow::CacheStatsRetrieve;
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |_, _, _, _| cache::retrieve_stats(),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
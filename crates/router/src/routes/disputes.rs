@@ -435,6 +435,61 @@ pub async fn retrieve_dispute_evidence(
     .await
 }
 
+/// Disputes - Create Dispute Evidence Draft
+///
+/// To prepare the evidence for a dispute from the data of the disputed payment. A receipt of the
+/// payment is generated unless one is already attached, and the draft can be reviewed before it is
+/// submitted
+#[utoipa::path(
+    post,
+    path = "/disputes/evidence/{dispute_id}/draft",
+    params(
+        ("dispute_id" = String, Path, description = "The identifier for dispute")
+    ),
+    responses(
+        (status = 200, description = "The dispute evidence draft was created successfully", body = SubmitEvidenceRequest),
+        (status = 404, description = "Dispute does not exist in our records")
+    ),
+    tag = "Disputes",
+    operation_id = "Create a Dispute Evidence Draft",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::CreateDisputeEvidenceDraft))]
+pub async fn create_dispute_evidence_draft(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::CreateDisputeEvidenceDraft;
+    let dispute_id = dispute_types::DisputeId {
+        dispute_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        dispute_id,
+        |state, auth: auth::AuthenticationData, req, _| {
+            disputes::evidence::create_evidence_draft(
+                state,
+                auth.merchant_account,
+                auth.profile_id,
+                auth.key_store,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::ProfileDisputeWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 /// Disputes - Delete Evidence attached to a Dispute
 ///
 /// To delete an evidence file attached to a dispute
@@ -65,12 +65,13 @@ pub async fn dummy_connector_payment(
 ) -> impl actix_web::Responder {
     let payload = json_payload.into_inner();
     let flow = types::Flow::DummyPaymentCreate;
+    let scenario_id = utils::get_scenario_id(&req);
     Box::pin(api::server_wrap(
         flow,
         state,
         &req,
         payload,
-        |state, _: (), req, _| core::payment(state, req),
+        |state, _: (), req, _| core::payment(state, req, scenario_id.clone()),
         &auth::NoAuth,
         api_locking::LockAction::NotApplicable,
     ))
@@ -85,12 +86,13 @@ pub async fn dummy_connector_payment_data(
     let flow = types::Flow::DummyPaymentRetrieve;
     let payment_id = path.into_inner();
     let payload = types::DummyConnectorPaymentRetrieveRequest { payment_id };
+    let scenario_id = utils::get_scenario_id(&req);
     api::server_wrap(
         flow,
         state,
         &req,
         payload,
-        |state, _: (), req, _| core::payment_data(state, req),
+        |state, _: (), req, _| core::payment_data(state, req, scenario_id.clone()),
         &auth::NoAuth,
         api_locking::LockAction::NotApplicable,
     )
@@ -106,12 +108,13 @@ pub async fn dummy_connector_refund(
     let flow = types::Flow::DummyRefundCreate;
     let mut payload = json_payload.into_inner();
     payload.payment_id = Some(path.into_inner());
+    let scenario_id = utils::get_scenario_id(&req);
     api::server_wrap(
         flow,
         state,
         &req,
         payload,
-        |state, _: (), req, _| core::refund_payment(state, req),
+        |state, _: (), req, _| core::refund_payment(state, req, scenario_id.clone()),
         &auth::NoAuth,
         api_locking::LockAction::NotApplicable,
     )
@@ -126,14 +129,143 @@ pub async fn dummy_connector_refund_data(
     let flow = types::Flow::DummyRefundRetrieve;
     let refund_id = path.into_inner();
     let payload = types::DummyConnectorRefundRetrieveRequest { refund_id };
+    let scenario_id = utils::get_scenario_id(&req);
     api::server_wrap(
         flow,
         state,
         &req,
         payload,
-        |state, _: (), req, _| core::refund_data(state, req),
+        |state, _: (), req, _| core::refund_data(state, req, scenario_id.clone()),
         &auth::NoAuth,
         api_locking::LockAction::NotApplicable,
     )
     .await
 }
+#[instrument(skip_all, fields(flow = ?types::Flow::DummyPaymentCapture))]
+pub async fn dummy_connector_capture(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<types::DummyConnectorCaptureRequest>,
+    path: web::Path<common_utils::id_type::PaymentId>,
+) -> impl actix_web::Responder {
+    let flow = types::Flow::DummyPaymentCapture;
+    let mut payload = json_payload.into_inner();
+    payload.payment_id = Some(path.into_inner());
+    let scenario_id = utils::get_scenario_id(&req);
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _: (), req, _| core::capture_payment(state, req, scenario_id.clone()),
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+#[instrument(skip_all, fields(flow = ?types::Flow::DummyPayoutCreate))]
+pub async fn dummy_connector_payout(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<types::DummyConnectorPayoutRequest>,
+) -> impl actix_web::Responder {
+    let flow = types::Flow::DummyPayoutCreate;
+    let payload = json_payload.into_inner();
+    let scenario_id = utils::get_scenario_id(&req);
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _: (), req, _| core::payout(state, req, scenario_id.clone()),
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+#[instrument(skip_all, fields(flow = ?types::Flow::DummyPayoutRetrieve))]
+pub async fn dummy_connector_payout_data(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl actix_web::Responder {
+    let flow = types::Flow::DummyPayoutRetrieve;
+    let payout_id = path.into_inner();
+    let payload = types::DummyConnectorPayoutRetrieveRequest { payout_id };
+    let scenario_id = utils::get_scenario_id(&req);
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _: (), req, _| core::payout_data(state, req, scenario_id.clone()),
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+#[instrument(skip_all, fields(flow = ?types::Flow::DummyScenarioCreate))]
+pub async fn dummy_connector_create_scenario(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<types::DummyConnectorScenario>,
+    path: web::Path<String>,
+) -> impl actix_web::Responder {
+    let flow = types::Flow::DummyScenarioCreate;
+    let payload = types::DummyConnectorScenarioCreateRequest {
+        scenario_id: path.into_inner(),
+        scenario: json_payload.into_inner(),
+    };
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _: (), req, _| core::create_scenario(state, req),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+#[instrument(skip_all, fields(flow = ?types::Flow::DummyScenarioRetrieve))]
+pub async fn dummy_connector_scenario_data(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl actix_web::Responder {
+    let flow = types::Flow::DummyScenarioRetrieve;
+    let payload = types::DummyConnectorScenarioRequest {
+        scenario_id: path.into_inner(),
+    };
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _: (), req, _| core::scenario_data(state, req),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+#[instrument(skip_all, fields(flow = ?types::Flow::DummyScenarioDelete))]
+pub async fn dummy_connector_delete_scenario(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl actix_web::Responder {
+    let flow = types::Flow::DummyScenarioDelete;
+    let payload = types::DummyConnectorScenarioRequest {
+        scenario_id: path.into_inner(),
+    };
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _: (), req, _| core::delete_scenario(state, req),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
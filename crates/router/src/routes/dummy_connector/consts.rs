@@ -1,3 +1,6 @@
 pub const ATTEMPT_ID_PREFIX: &str = "dummy_attempt";
 pub const REFUND_ID_PREFIX: &str = "dummy_ref";
+pub const DISPUTE_ID_PREFIX: &str = "dummy_dispute";
+pub const PAYOUT_ID_PREFIX: &str = "dummy_payout";
+pub const SCENARIO_KEY_PREFIX: &str = "dummy_scenario_";
 pub const THREE_DS_CSS: &str = include_str!("threeds_page.css");
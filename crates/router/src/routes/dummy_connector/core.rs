@@ -1,6 +1,7 @@
 use app::SessionState;
 use common_utils::generate_id_with_default_len;
 use error_stack::ResultExt;
+use router_env::{logger, tracing::Instrument};
 
 use super::{errors, types, utils};
 use crate::{
@@ -12,6 +13,7 @@ use crate::{
 pub async fn payment(
     state: SessionState,
     req: types::DummyConnectorPaymentRequest,
+    scenario_id: Option<String>,
 ) -> types::DummyConnectorResponse<types::DummyConnectorPaymentResponse> {
     utils::tokio_mock_sleep(
         state.conf.dummy_connector.payment_duration,
@@ -19,9 +21,34 @@ pub async fn payment(
     )
     .await;
 
+    let scenario_step = utils::get_scenario_step(
+        &state,
+        scenario_id.as_deref(),
+        types::DummyConnectorScenarioFlow::Payment,
+    )
+    .await?;
+    if let Some((step, _)) = scenario_step.as_ref() {
+        utils::apply_scenario_step(step).await?;
+    }
+
     let payment_attempt: types::DummyConnectorPaymentAttempt = req.into();
-    let payment_data =
-        types::DummyConnectorPaymentData::process_payment_attempt(&state, payment_attempt)?;
+    let payment_data = match scenario_step.as_ref().map(|(step, _)| &step.outcome) {
+        Some(types::DummyConnectorScenarioOutcome::Decline { code, message }) => {
+            types::DummyConnectorPaymentData {
+                error_code: Some(code.to_owned()),
+                error_message: Some(message.to_owned()),
+                ..payment_attempt.build_payment_data(
+                    types::DummyConnectorStatus::Failed,
+                    None,
+                    None,
+                )
+            }
+        }
+        Some(types::DummyConnectorScenarioOutcome::Pending { .. }) => {
+            payment_attempt.build_payment_data(types::DummyConnectorStatus::Pending, None, None)
+        }
+        _ => types::DummyConnectorPaymentData::process_payment_attempt(&state, payment_attempt)?,
+    };
 
     utils::store_data_in_redis(
         &state,
@@ -37,12 +64,45 @@ pub async fn payment(
         state.conf.dummy_connector.payment_ttl,
     )
     .await?;
+
+    if let Some((step, webhook_url)) = scenario_step {
+        match step.outcome {
+            types::DummyConnectorScenarioOutcome::Pending {
+                resolve_after_secs,
+                final_status,
+                webhook_delay_secs,
+            } => spawn_scenario_task(resolve_payment(
+                state.clone(),
+                payment_data.payment_id.get_string_repr().to_owned(),
+                final_status.for_capture_method(payment_data.capture_method),
+                resolve_after_secs,
+                webhook_url.map(|webhook_url| (webhook_url, webhook_delay_secs)),
+            )),
+            types::DummyConnectorScenarioOutcome::Dispute {
+                after_secs,
+                amount,
+                reason,
+            } => spawn_scenario_task(open_dispute(
+                state.clone(),
+                payment_data.payment_id.get_string_repr().to_owned(),
+                after_secs,
+                amount,
+                reason,
+                webhook_url,
+            )),
+            types::DummyConnectorScenarioOutcome::Default
+            | types::DummyConnectorScenarioOutcome::Timeout
+            | types::DummyConnectorScenarioOutcome::ServerError { .. }
+            | types::DummyConnectorScenarioOutcome::Decline { .. } => {}
+        }
+    }
     Ok(api::ApplicationResponse::Json(payment_data.into()))
 }
 
 pub async fn payment_data(
     state: SessionState,
     req: types::DummyConnectorPaymentRetrieveRequest,
+    scenario_id: Option<String>,
 ) -> types::DummyConnectorResponse<types::DummyConnectorPaymentResponse> {
     utils::tokio_mock_sleep(
         state.conf.dummy_connector.payment_retrieve_duration,
@@ -50,6 +110,16 @@ pub async fn payment_data(
     )
     .await;
 
+    if let Some((step, _)) = utils::get_scenario_step(
+        &state,
+        scenario_id.as_deref(),
+        types::DummyConnectorScenarioFlow::PaymentRetrieve,
+    )
+    .await?
+    {
+        utils::apply_scenario_step(&step).await?;
+    }
+
     let payment_data = utils::get_payment_data_from_payment_id(&state, req.payment_id).await?;
     Ok(api::ApplicationResponse::Json(payment_data.into()))
 }
@@ -110,7 +180,7 @@ pub async fn payment_complete(
 
     if let Ok(payment_data) = payment_data {
         let updated_payment_data = types::DummyConnectorPaymentData {
-            status: payment_status,
+            status: payment_status.for_capture_method(payment_data.capture_method),
             next_action: None,
             ..payment_data
         };
@@ -147,6 +217,7 @@ pub async fn payment_complete(
 pub async fn refund_payment(
     state: SessionState,
     req: types::DummyConnectorRefundRequest,
+    scenario_id: Option<String>,
 ) -> types::DummyConnectorResponse<types::DummyConnectorRefundResponse> {
     utils::tokio_mock_sleep(
         state.conf.dummy_connector.refund_duration,
@@ -154,6 +225,16 @@ pub async fn refund_payment(
     )
     .await;
 
+    let scenario_step = utils::get_scenario_step(
+        &state,
+        scenario_id.as_deref(),
+        types::DummyConnectorScenarioFlow::Refund,
+    )
+    .await?;
+    if let Some((step, _)) = scenario_step.as_ref() {
+        utils::apply_scenario_step(step).await?;
+    }
+
     let payment_id = req
         .payment_id
         .get_required_value("payment_id")
@@ -167,8 +248,20 @@ pub async fn refund_payment(
 
     payment_data.is_eligible_for_refund(req.amount)?;
 
+    let refund_status = match scenario_step.as_ref().map(|(step, _)| &step.outcome) {
+        Some(types::DummyConnectorScenarioOutcome::Decline { .. }) => {
+            types::DummyConnectorStatus::Failed
+        }
+        Some(types::DummyConnectorScenarioOutcome::Pending { .. }) => {
+            types::DummyConnectorStatus::Processing
+        }
+        _ => types::DummyConnectorStatus::Succeeded,
+    };
+
     let refund_id = generate_id_with_default_len(consts::REFUND_ID_PREFIX);
-    payment_data.eligible_amount -= req.amount;
+    if refund_status != types::DummyConnectorStatus::Failed {
+        payment_data.eligible_amount -= req.amount;
+    }
 
     utils::store_data_in_redis(
         &state,
@@ -179,7 +272,7 @@ pub async fn refund_payment(
     .await?;
 
     let refund_data = types::DummyConnectorRefundResponse::new(
-        types::DummyConnectorStatus::Succeeded,
+        refund_status,
         refund_id.to_owned(),
         payment_data.currency,
         common_utils::date_time::now(),
@@ -189,17 +282,41 @@ pub async fn refund_payment(
 
     utils::store_data_in_redis(
         &state,
-        refund_id,
+        refund_id.clone(),
         refund_data.to_owned(),
         state.conf.dummy_connector.refund_ttl,
     )
     .await?;
+
+    if let Some((
+        types::DummyConnectorScenarioStep {
+            outcome:
+                types::DummyConnectorScenarioOutcome::Pending {
+                    resolve_after_secs,
+                    final_status,
+                    webhook_delay_secs,
+                },
+            ..
+        },
+        webhook_url,
+    )) = scenario_step
+    {
+        spawn_scenario_task(resolve_refund(
+            state.clone(),
+            payment_id.get_string_repr().to_owned(),
+            refund_id,
+            final_status,
+            resolve_after_secs,
+            webhook_url.map(|webhook_url| (webhook_url, webhook_delay_secs)),
+        ));
+    }
     Ok(api::ApplicationResponse::Json(refund_data))
 }
 
 pub async fn refund_data(
     state: SessionState,
     req: types::DummyConnectorRefundRetrieveRequest,
+    scenario_id: Option<String>,
 ) -> types::DummyConnectorResponse<types::DummyConnectorRefundResponse> {
     let refund_id = req.refund_id;
     utils::tokio_mock_sleep(
@@ -208,6 +325,16 @@ pub async fn refund_data(
     )
     .await;
 
+    if let Some((step, _)) = utils::get_scenario_step(
+        &state,
+        scenario_id.as_deref(),
+        types::DummyConnectorScenarioFlow::RefundRetrieve,
+    )
+    .await?
+    {
+        utils::apply_scenario_step(&step).await?;
+    }
+
     let redis_conn = state
         .store
         .get_redis_conn()
@@ -222,3 +349,458 @@ pub async fn refund_data(
         .change_context(errors::DummyConnectorErrors::RefundNotFound)?;
     Ok(api::ApplicationResponse::Json(refund_data))
 }
+
+pub async fn capture_payment(
+    state: SessionState,
+    req: types::DummyConnectorCaptureRequest,
+    scenario_id: Option<String>,
+) -> types::DummyConnectorResponse<types::DummyConnectorPaymentResponse> {
+    utils::tokio_mock_sleep(
+        state.conf.dummy_connector.payment_duration,
+        state.conf.dummy_connector.payment_tolerance,
+    )
+    .await;
+
+    let scenario_step = utils::get_scenario_step(
+        &state,
+        scenario_id.as_deref(),
+        types::DummyConnectorScenarioFlow::Capture,
+    )
+    .await?;
+    if let Some((step, _)) = scenario_step.as_ref() {
+        utils::apply_scenario_step(step).await?;
+    }
+
+    let payment_id = req
+        .payment_id
+        .get_required_value("payment_id")
+        .change_context(errors::DummyConnectorErrors::MissingRequiredField {
+            field_name: "payment_id",
+        })?;
+
+    let payment_data =
+        utils::get_payment_data_from_payment_id(&state, payment_id.get_string_repr().to_owned())
+            .await?;
+
+    payment_data.is_eligible_for_capture(req.amount)?;
+
+    let payment_data = match scenario_step.as_ref().map(|(step, _)| &step.outcome) {
+        Some(types::DummyConnectorScenarioOutcome::Decline { code, message }) => {
+            types::DummyConnectorPaymentData {
+                status: types::DummyConnectorStatus::Failed,
+                error_code: Some(code.to_owned()),
+                error_message: Some(message.to_owned()),
+                ..payment_data
+            }
+        }
+        Some(types::DummyConnectorScenarioOutcome::Pending { .. }) => {
+            types::DummyConnectorPaymentData {
+                status: types::DummyConnectorStatus::Pending,
+                amount_captured: Some(req.amount),
+                ..payment_data
+            }
+        }
+        _ => types::DummyConnectorPaymentData {
+            status: types::DummyConnectorStatus::Succeeded,
+            amount_captured: Some(req.amount),
+            eligible_amount: req.amount,
+            ..payment_data
+        },
+    };
+
+    utils::store_data_in_redis(
+        &state,
+        payment_id.get_string_repr().to_owned(),
+        payment_data.clone(),
+        state.conf.dummy_connector.payment_ttl,
+    )
+    .await?;
+
+    if let Some((
+        types::DummyConnectorScenarioStep {
+            outcome:
+                types::DummyConnectorScenarioOutcome::Pending {
+                    resolve_after_secs,
+                    final_status,
+                    webhook_delay_secs,
+                },
+            ..
+        },
+        webhook_url,
+    )) = scenario_step
+    {
+        spawn_scenario_task(resolve_payment(
+            state.clone(),
+            payment_id.get_string_repr().to_owned(),
+            final_status,
+            resolve_after_secs,
+            webhook_url.map(|webhook_url| (webhook_url, webhook_delay_secs)),
+        ));
+    }
+    Ok(api::ApplicationResponse::Json(payment_data.into()))
+}
+
+pub async fn payout(
+    state: SessionState,
+    req: types::DummyConnectorPayoutRequest,
+    scenario_id: Option<String>,
+) -> types::DummyConnectorResponse<types::DummyConnectorPayoutResponse> {
+    utils::tokio_mock_sleep(
+        state.conf.dummy_connector.payout_duration,
+        state.conf.dummy_connector.payout_tolerance,
+    )
+    .await;
+
+    let scenario_step = utils::get_scenario_step(
+        &state,
+        scenario_id.as_deref(),
+        types::DummyConnectorScenarioFlow::Payout,
+    )
+    .await?;
+    if let Some((step, _)) = scenario_step.as_ref() {
+        utils::apply_scenario_step(step).await?;
+    }
+
+    let payout_data = match scenario_step.as_ref().map(|(step, _)| &step.outcome) {
+        Some(types::DummyConnectorScenarioOutcome::Decline { code, message }) => {
+            types::DummyConnectorPayoutResponse {
+                error_code: Some(code.to_owned()),
+                error_message: Some(message.to_owned()),
+                ..types::DummyConnectorPayoutResponse::new(types::DummyConnectorStatus::Failed, req)
+            }
+        }
+        Some(types::DummyConnectorScenarioOutcome::Pending { .. }) => {
+            types::DummyConnectorPayoutResponse::new(types::DummyConnectorStatus::Processing, req)
+        }
+        _ => types::DummyConnectorPayoutResponse::new(types::DummyConnectorStatus::Succeeded, req),
+    };
+
+    utils::store_data_in_redis(
+        &state,
+        payout_data.id.clone(),
+        payout_data.clone(),
+        state.conf.dummy_connector.payout_ttl,
+    )
+    .await?;
+
+    if let Some((
+        types::DummyConnectorScenarioStep {
+            outcome:
+                types::DummyConnectorScenarioOutcome::Pending {
+                    resolve_after_secs,
+                    final_status,
+                    webhook_delay_secs,
+                },
+            ..
+        },
+        webhook_url,
+    )) = scenario_step
+    {
+        spawn_scenario_task(resolve_payout(
+            state.clone(),
+            payout_data.id.clone(),
+            final_status,
+            resolve_after_secs,
+            webhook_url.map(|webhook_url| (webhook_url, webhook_delay_secs)),
+        ));
+    }
+    Ok(api::ApplicationResponse::Json(payout_data))
+}
+
+pub async fn payout_data(
+    state: SessionState,
+    req: types::DummyConnectorPayoutRetrieveRequest,
+    scenario_id: Option<String>,
+) -> types::DummyConnectorResponse<types::DummyConnectorPayoutResponse> {
+    utils::tokio_mock_sleep(
+        state.conf.dummy_connector.payout_retrieve_duration,
+        state.conf.dummy_connector.payout_retrieve_tolerance,
+    )
+    .await;
+
+    if let Some((step, _)) = utils::get_scenario_step(
+        &state,
+        scenario_id.as_deref(),
+        types::DummyConnectorScenarioFlow::PayoutRetrieve,
+    )
+    .await?
+    {
+        utils::apply_scenario_step(&step).await?;
+    }
+
+    let payout_data = utils::get_payout_data_from_payout_id(&state, &req.payout_id).await?;
+    Ok(api::ApplicationResponse::Json(payout_data))
+}
+
+pub async fn create_scenario(
+    state: SessionState,
+    req: types::DummyConnectorScenarioCreateRequest,
+) -> types::DummyConnectorResponse<types::DummyConnectorScenario> {
+    req.scenario.validate_webhook_url(&state.base_url)?;
+    utils::store_data_in_redis(
+        &state,
+        format!("{}{}", consts::SCENARIO_KEY_PREFIX, req.scenario_id),
+        req.scenario.clone(),
+        state.conf.dummy_connector.scenario_ttl,
+    )
+    .await?;
+    Ok(api::ApplicationResponse::Json(req.scenario))
+}
+
+pub async fn scenario_data(
+    state: SessionState,
+    req: types::DummyConnectorScenarioRequest,
+) -> types::DummyConnectorResponse<types::DummyConnectorScenario> {
+    let scenario = utils::get_scenario(&state, &req.scenario_id).await?;
+    Ok(api::ApplicationResponse::Json(scenario))
+}
+
+pub async fn delete_scenario(
+    state: SessionState,
+    req: types::DummyConnectorScenarioRequest,
+) -> types::DummyConnectorResponse<()> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::DummyConnectorErrors::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    redis_conn
+        .delete_key(format!("{}{}", consts::SCENARIO_KEY_PREFIX, req.scenario_id).as_str())
+        .await
+        .change_context(errors::DummyConnectorErrors::InternalServerError)
+        .attach_printable("Failed to delete the scenario")?;
+    Ok(api::ApplicationResponse::StatusOk)
+}
+
+/// Runs the delayed part of a scenario outcome in the background, after the response for the
+/// request has been sent
+fn spawn_scenario_task(
+    task: impl std::future::Future<Output = types::DummyConnectorResult<()>> + Send + 'static,
+) {
+    tokio::spawn(
+        async move {
+            if let Err(error) = task.await {
+                logger::error!(?error, "Failed to run the dummy connector scenario outcome");
+            }
+        }
+        .in_current_span(),
+    );
+}
+
+async fn resolve_payment(
+    state: SessionState,
+    payment_id: String,
+    final_status: types::DummyConnectorStatus,
+    resolve_after_secs: u64,
+    webhook: Option<(String, u64)>,
+) -> types::DummyConnectorResult<()> {
+    tokio::time::sleep(tokio::time::Duration::from_secs(resolve_after_secs)).await;
+
+    let payment_data = utils::get_payment_data_from_payment_id(&state, payment_id).await?;
+    let payment_data = match final_status {
+        types::DummyConnectorStatus::Succeeded => types::DummyConnectorPaymentData {
+            status: final_status,
+            eligible_amount: payment_data
+                .amount_captured
+                .unwrap_or(payment_data.eligible_amount),
+            ..payment_data
+        },
+        _ => types::DummyConnectorPaymentData {
+            status: final_status,
+            ..payment_data
+        },
+    };
+    utils::store_data_in_redis(
+        &state,
+        payment_data.payment_id.get_string_repr().to_owned(),
+        payment_data.clone(),
+        state.conf.dummy_connector.payment_ttl,
+    )
+    .await?;
+
+    let event_type = match payment_data.status {
+        types::DummyConnectorStatus::Succeeded => {
+            types::DummyConnectorWebhookEventType::PaymentSucceeded
+        }
+        types::DummyConnectorStatus::Authorized => {
+            types::DummyConnectorWebhookEventType::PaymentAuthorized
+        }
+        types::DummyConnectorStatus::Failed => types::DummyConnectorWebhookEventType::PaymentFailed,
+        types::DummyConnectorStatus::Processing | types::DummyConnectorStatus::Pending => {
+            return Ok(())
+        }
+    };
+    if let Some((webhook_url, webhook_delay_secs)) = webhook {
+        tokio::time::sleep(tokio::time::Duration::from_secs(webhook_delay_secs)).await;
+        utils::send_webhook(
+            &state,
+            &webhook_url,
+            types::DummyConnectorWebhook {
+                event_type,
+                data: types::DummyConnectorWebhookData::Payment(payment_data.into()),
+            },
+        )
+        .await?;
+    }
+    Ok(())
+}
+
+async fn resolve_refund(
+    state: SessionState,
+    payment_id: String,
+    refund_id: String,
+    final_status: types::DummyConnectorStatus,
+    resolve_after_secs: u64,
+    webhook: Option<(String, u64)>,
+) -> types::DummyConnectorResult<()> {
+    tokio::time::sleep(tokio::time::Duration::from_secs(resolve_after_secs)).await;
+
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::DummyConnectorErrors::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let refund_data = redis_conn
+        .get_and_deserialize_key::<types::DummyConnectorRefundResponse>(
+            refund_id.as_str(),
+            "DummyConnectorRefundResponse",
+        )
+        .await
+        .change_context(errors::DummyConnectorErrors::RefundNotFound)?;
+    let refund_data = types::DummyConnectorRefundResponse {
+        status: final_status,
+        ..refund_data
+    };
+
+    let event_type = match refund_data.status {
+        types::DummyConnectorStatus::Succeeded => {
+            types::DummyConnectorWebhookEventType::RefundSucceeded
+        }
+        types::DummyConnectorStatus::Failed => {
+            // The amount held for the refund is released once it fails
+            let mut payment_data =
+                utils::get_payment_data_from_payment_id(&state, payment_id.clone()).await?;
+            payment_data.eligible_amount += refund_data.refund_amount;
+            utils::store_data_in_redis(
+                &state,
+                payment_id,
+                payment_data,
+                state.conf.dummy_connector.payment_ttl,
+            )
+            .await?;
+            types::DummyConnectorWebhookEventType::RefundFailed
+        }
+        types::DummyConnectorStatus::Processing
+        | types::DummyConnectorStatus::Pending
+        | types::DummyConnectorStatus::Authorized => return Ok(()),
+    };
+    utils::store_data_in_redis(
+        &state,
+        refund_id,
+        refund_data.clone(),
+        state.conf.dummy_connector.refund_ttl,
+    )
+    .await?;
+
+    if let Some((webhook_url, webhook_delay_secs)) = webhook {
+        tokio::time::sleep(tokio::time::Duration::from_secs(webhook_delay_secs)).await;
+        utils::send_webhook(
+            &state,
+            &webhook_url,
+            types::DummyConnectorWebhook {
+                event_type,
+                data: types::DummyConnectorWebhookData::Refund(refund_data),
+            },
+        )
+        .await?;
+    }
+    Ok(())
+}
+
+async fn resolve_payout(
+    state: SessionState,
+    payout_id: String,
+    final_status: types::DummyConnectorStatus,
+    resolve_after_secs: u64,
+    webhook: Option<(String, u64)>,
+) -> types::DummyConnectorResult<()> {
+    tokio::time::sleep(tokio::time::Duration::from_secs(resolve_after_secs)).await;
+
+    let payout_data = utils::get_payout_data_from_payout_id(&state, &payout_id).await?;
+    let payout_data = types::DummyConnectorPayoutResponse {
+        status: final_status,
+        ..payout_data
+    };
+
+    let event_type = match payout_data.status {
+        types::DummyConnectorStatus::Succeeded => {
+            types::DummyConnectorWebhookEventType::PayoutSucceeded
+        }
+        types::DummyConnectorStatus::Failed => types::DummyConnectorWebhookEventType::PayoutFailed,
+        types::DummyConnectorStatus::Processing
+        | types::DummyConnectorStatus::Pending
+        | types::DummyConnectorStatus::Authorized => return Ok(()),
+    };
+    utils::store_data_in_redis(
+        &state,
+        payout_id,
+        payout_data.clone(),
+        state.conf.dummy_connector.payout_ttl,
+    )
+    .await?;
+
+    if let Some((webhook_url, webhook_delay_secs)) = webhook {
+        tokio::time::sleep(tokio::time::Duration::from_secs(webhook_delay_secs)).await;
+        utils::send_webhook(
+            &state,
+            &webhook_url,
+            types::DummyConnectorWebhook {
+                event_type,
+                data: types::DummyConnectorWebhookData::Payout(payout_data),
+            },
+        )
+        .await?;
+    }
+    Ok(())
+}
+
+async fn open_dispute(
+    state: SessionState,
+    payment_id: String,
+    after_secs: u64,
+    amount: Option<i64>,
+    reason: Option<String>,
+    webhook_url: Option<String>,
+) -> types::DummyConnectorResult<()> {
+    let Some(webhook_url) = webhook_url else {
+        logger::warn!("Dispute is not opened since the scenario has no webhook url");
+        return Ok(());
+    };
+    tokio::time::sleep(tokio::time::Duration::from_secs(after_secs)).await;
+
+    let payment_data = utils::get_payment_data_from_payment_id(&state, payment_id).await?;
+    if payment_data.status != types::DummyConnectorStatus::Succeeded {
+        logger::warn!(payment_status = %payment_data.status, "Dispute is not opened since the payment is not successful");
+        return Ok(());
+    }
+
+    let dispute = types::DummyConnectorDispute {
+        id: generate_id_with_default_len(consts::DISPUTE_ID_PREFIX),
+        amount: amount.unwrap_or(payment_data.amount_captured.unwrap_or(payment_data.amount)),
+        payment_id: payment_data.payment_id,
+        currency: payment_data.currency,
+        reason,
+        status: types::DummyConnectorDisputeStatus::Opened,
+        created: common_utils::date_time::now(),
+    };
+    utils::send_webhook(
+        &state,
+        &webhook_url,
+        types::DummyConnectorWebhook {
+            event_type: types::DummyConnectorWebhookEventType::DisputeOpened,
+            data: types::DummyConnectorWebhookData::Dispute(dispute),
+        },
+    )
+    .await
+}
@@ -37,6 +37,27 @@ pub enum DummyConnectorErrors {
 
     #[error(error_type = ErrorType::InvalidRequestError, code = "DC_08", message = "Payment declined: {message}")]
     PaymentDeclined { message: &'static str },
+
+    #[error(error_type = ErrorType::InvalidRequestError, code = "DC_09", message = "Payment is not authorized")]
+    PaymentNotAuthorized,
+
+    #[error(error_type = ErrorType::InvalidRequestError, code = "DC_10", message = "The capture amount exceeds the amount authorized")]
+    CaptureAmountExceedsPaymentAmount,
+
+    #[error(error_type = ErrorType::ObjectNotFound, code = "DC_11", message = "Scenario does not exist in our records")]
+    ScenarioNotFound,
+
+    #[error(error_type = ErrorType::ServerNotAvailable, code = "DC_12", message = "Server error with status code {status_code}")]
+    ServerError { status_code: u16 },
+
+    #[error(error_type = ErrorType::ServerNotAvailable, code = "DC_13", message = "Request timed out")]
+    GatewayTimeout,
+
+    #[error(error_type = ErrorType::InvalidRequestError, code = "DC_14", message = "The webhook url must be a webhooks endpoint of this server")]
+    InvalidWebhookUrl,
+
+    #[error(error_type = ErrorType::ObjectNotFound, code = "DC_15", message = "Payout does not exist in our records")]
+    PayoutNotFound,
 }
 
 impl core::fmt::Display for DummyConnectorErrors {
@@ -83,6 +104,30 @@ impl common_utils::errors::ErrorSwitch<api_models::errors::types::ApiErrorRespon
             Self::PaymentDeclined { message: _ } => {
                 AER::BadRequest(ApiError::new("DC", 8, self.error_message(), None))
             }
+            Self::PaymentNotAuthorized => {
+                AER::BadRequest(ApiError::new("DC", 9, self.error_message(), None))
+            }
+            Self::CaptureAmountExceedsPaymentAmount => {
+                AER::BadRequest(ApiError::new("DC", 10, self.error_message(), None))
+            }
+            Self::ScenarioNotFound => {
+                AER::NotFound(ApiError::new("DC", 11, self.error_message(), None))
+            }
+            Self::ServerError { status_code } => AER::ConnectorError(
+                ApiError::new("DC", 12, self.error_message(), None),
+                reqwest::StatusCode::from_u16(*status_code)
+                    .unwrap_or(reqwest::StatusCode::INTERNAL_SERVER_ERROR),
+            ),
+            Self::GatewayTimeout => AER::ConnectorError(
+                ApiError::new("DC", 13, self.error_message(), None),
+                reqwest::StatusCode::GATEWAY_TIMEOUT,
+            ),
+            Self::InvalidWebhookUrl => {
+                AER::BadRequest(ApiError::new("DC", 14, self.error_message(), None))
+            }
+            Self::PayoutNotFound => {
+                AER::NotFound(ApiError::new("DC", 15, self.error_message(), None))
+            }
         }
     }
 }
@@ -1,4 +1,4 @@
-use api_models::enums::Currency;
+use api_models::enums::{CaptureMethod, Currency};
 use common_utils::{errors::CustomResult, generate_id_with_default_len};
 use error_stack::report;
 use masking::Secret;
@@ -16,8 +16,14 @@ pub enum Flow {
     DummyPaymentRetrieve,
     DummyPaymentAuthorize,
     DummyPaymentComplete,
+    DummyPaymentCapture,
     DummyRefundCreate,
     DummyRefundRetrieve,
+    DummyPayoutCreate,
+    DummyPayoutRetrieve,
+    DummyScenarioCreate,
+    DummyScenarioRetrieve,
+    DummyScenarioDelete,
 }
 
 impl FlowMetric for Flow {}
@@ -64,6 +70,8 @@ pub enum DummyConnectorStatus {
     #[default]
     Processing,
     Failed,
+    Authorized,
+    Pending,
 }
 
 #[derive(Clone, Debug, serde::Serialize, Eq, PartialEq, serde::Deserialize)]
@@ -95,18 +103,23 @@ impl DummyConnectorPaymentAttempt {
         next_action: Option<DummyConnectorNextAction>,
         return_url: Option<String>,
     ) -> DummyConnectorPaymentData {
+        let capture_method = self.payment_request.capture_method.unwrap_or_default();
         DummyConnectorPaymentData {
             attempt_id: self.attempt_id,
             payment_id: self.payment_id,
-            status,
+            status: status.for_capture_method(capture_method),
             amount: self.payment_request.amount,
             eligible_amount: self.payment_request.amount,
+            amount_captured: None,
             connector: self.payment_request.connector,
             created: self.timestamp,
             currency: self.payment_request.currency,
             payment_method_type: self.payment_request.payment_method_data.into(),
+            capture_method,
             next_action,
             return_url,
+            error_code: None,
+            error_message: None,
         }
     }
 }
@@ -118,6 +131,19 @@ pub struct DummyConnectorPaymentRequest {
     pub payment_method_data: DummyConnectorPaymentMethodData,
     pub return_url: Option<String>,
     pub connector: DummyConnectors,
+    pub capture_method: Option<CaptureMethod>,
+}
+
+impl DummyConnectorStatus {
+    /// Payments captured manually are only authorized when they succeed
+    pub fn for_capture_method(self, capture_method: CaptureMethod) -> Self {
+        match (self, capture_method) {
+            (Self::Succeeded, CaptureMethod::Manual | CaptureMethod::ManualMultiple) => {
+                Self::Authorized
+            }
+            (status, _) => status,
+        }
+    }
 }
 
 pub trait GetPaymentMethodDetails {
@@ -252,13 +278,18 @@ pub struct DummyConnectorPaymentData {
     pub status: DummyConnectorStatus,
     pub amount: i64,
     pub eligible_amount: i64,
+    pub amount_captured: Option<i64>,
     pub currency: Currency,
     #[serde(with = "common_utils::custom_serde::iso8601")]
     pub created: PrimitiveDateTime,
     pub payment_method_type: DummyConnectorPaymentMethodType,
     pub connector: DummyConnectors,
+    #[serde(default)]
+    pub capture_method: CaptureMethod,
     pub next_action: Option<DummyConnectorNextAction>,
     pub return_url: Option<String>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
 }
 
 impl DummyConnectorPaymentData {
@@ -275,6 +306,20 @@ impl DummyConnectorPaymentData {
         }
         Ok(())
     }
+
+    pub fn is_eligible_for_capture(&self, capture_amount: i64) -> DummyConnectorResult<()> {
+        if self.status != DummyConnectorStatus::Authorized {
+            return Err(report!(DummyConnectorErrors::PaymentNotAuthorized)
+                .attach_printable("Payment is not authorized to process the capture"));
+        }
+        if self.amount < capture_amount {
+            return Err(
+                report!(DummyConnectorErrors::CaptureAmountExceedsPaymentAmount)
+                    .attach_printable("Authorized amount is lesser than capture amount"),
+            );
+        }
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq)]
@@ -288,11 +333,17 @@ pub struct DummyConnectorPaymentResponse {
     pub status: DummyConnectorStatus,
     pub id: common_utils::id_type::PaymentId,
     pub amount: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount_captured: Option<i64>,
     pub currency: Currency,
     #[serde(with = "common_utils::custom_serde::iso8601")]
     pub created: PrimitiveDateTime,
     pub payment_method_type: DummyConnectorPaymentMethodType,
     pub next_action: Option<DummyConnectorNextAction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_message: Option<String>,
 }
 
 impl From<DummyConnectorPaymentData> for DummyConnectorPaymentResponse {
@@ -301,10 +352,13 @@ impl From<DummyConnectorPaymentData> for DummyConnectorPaymentResponse {
             status: value.status,
             id: value.payment_id,
             amount: value.amount,
+            amount_captured: value.amount_captured,
             currency: value.currency,
             created: value.created,
             payment_method_type: value.payment_method_type,
             next_action: value.next_action,
+            error_code: value.error_code,
+            error_message: value.error_message,
         }
     }
 }
@@ -330,6 +384,12 @@ pub struct DummyConnectorPaymentCompleteBody {
     pub confirm: bool,
 }
 
+#[derive(Default, Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DummyConnectorCaptureRequest {
+    pub amount: i64,
+    pub payment_id: Option<common_utils::id_type::PaymentId>,
+}
+
 #[derive(Default, Debug, serde::Serialize, Eq, PartialEq, serde::Deserialize)]
 pub struct DummyConnectorRefundRequest {
     pub amount: i64,
@@ -372,7 +432,320 @@ pub struct DummyConnectorRefundRetrieveRequest {
     pub refund_id: String,
 }
 
+#[derive(Clone, Debug, serde::Serialize, Eq, PartialEq, serde::Deserialize)]
+pub struct DummyConnectorPayoutRequest {
+    pub amount: i64,
+    pub currency: Currency,
+    pub connector: DummyConnectors,
+}
+
+#[derive(Clone, Debug, serde::Serialize, Eq, PartialEq, serde::Deserialize)]
+pub struct DummyConnectorPayoutResponse {
+    pub status: DummyConnectorStatus,
+    pub id: String,
+    pub amount: i64,
+    pub currency: Currency,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_message: Option<String>,
+}
+
+impl DummyConnectorPayoutResponse {
+    pub fn new(status: DummyConnectorStatus, req: DummyConnectorPayoutRequest) -> Self {
+        Self {
+            status,
+            id: generate_id_with_default_len(consts::PAYOUT_ID_PREFIX),
+            amount: req.amount,
+            currency: req.currency,
+            created: common_utils::date_time::now(),
+            error_code: None,
+            error_message: None,
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DummyConnectorPayoutRetrieveRequest {
+    pub payout_id: String,
+}
+
+/// A script of outcomes for the requests received with the api key of a merchant connector
+/// account, used to reproduce connector behaviour such as retries, timeouts and webhook races
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, PartialEq)]
+pub struct DummyConnectorScenario {
+    pub steps: Vec<DummyConnectorScenarioStep>,
+    /// The url to which the webhooks for the asynchronous outcomes are sent, which must be a
+    /// webhooks endpoint of this server, such as
+    /// `{hyperswitch_base_url}/webhooks/{merchant_id}/{merchant_connector_id}`
+    pub webhook_url: Option<String>,
+}
+
+impl DummyConnectorScenario {
+    /// The webhooks of a scenario are only sent to the webhooks endpoints of this server, so that
+    /// a scenario cannot be used to send requests to arbitrary urls.
+    pub fn validate_webhook_url(&self, base_url: &str) -> DummyConnectorResult<()> {
+        let webhooks_url = format!("{}/webhooks/", base_url.trim_end_matches('/'));
+        match &self.webhook_url {
+            Some(webhook_url) if !webhook_url.starts_with(&webhooks_url) => {
+                Err(report!(DummyConnectorErrors::InvalidWebhookUrl))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Takes the next step scripted for the flow. Steps are applied in order, each one to as many
+    /// requests as its `times`, after which the flow behaves as it would without a scenario.
+    pub fn next_step(
+        &mut self,
+        flow: DummyConnectorScenarioFlow,
+    ) -> Option<DummyConnectorScenarioStep> {
+        let step = self
+            .steps
+            .iter_mut()
+            .find(|step| step.flow == flow && step.times > 0)?;
+        step.times = step.times.saturating_sub(1);
+        Some(step.clone())
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, PartialEq)]
+pub struct DummyConnectorScenarioStep {
+    pub flow: DummyConnectorScenarioFlow,
+    #[serde(default = "default_scenario_step_times")]
+    pub times: u32,
+    /// Latency injected before the request is processed, in milliseconds
+    #[serde(default)]
+    pub latency_ms: u64,
+    pub outcome: DummyConnectorScenarioOutcome,
+}
+
+fn default_scenario_step_times() -> u32 {
+    1
+}
+
+#[derive(
+    Clone, Copy, Debug, serde::Serialize, serde::Deserialize, strum::Display, Eq, PartialEq,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum DummyConnectorScenarioFlow {
+    Payment,
+    PaymentRetrieve,
+    Capture,
+    Refund,
+    RefundRetrieve,
+    Payout,
+    PayoutRetrieve,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DummyConnectorScenarioOutcome {
+    /// Process the request as it would be without a scenario
+    Default,
+    /// Hold the request beyond the connector request timeout and respond with a gateway timeout
+    Timeout,
+    /// Respond with the given server error status code
+    ServerError { status_code: u16 },
+    /// Fail the payment, capture, refund or payout with the given error code and message
+    Decline { code: String, message: String },
+    /// Keep the payment, capture, refund or payout pending, and move it to the final status after
+    /// the given delay, sending a webhook `webhook_delay_secs` after that
+    Pending {
+        resolve_after_secs: u64,
+        final_status: DummyConnectorStatus,
+        #[serde(default)]
+        webhook_delay_secs: u64,
+    },
+    /// Process the payment and open a dispute for it after the given delay
+    Dispute {
+        after_secs: u64,
+        amount: Option<i64>,
+        reason: Option<String>,
+    },
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct DummyConnectorScenarioRequest {
+    pub scenario_id: String,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct DummyConnectorScenarioCreateRequest {
+    pub scenario_id: String,
+    pub scenario: DummyConnectorScenario,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct DummyConnectorDispute {
+    pub id: String,
+    pub payment_id: common_utils::id_type::PaymentId,
+    pub amount: i64,
+    pub currency: Currency,
+    pub reason: Option<String>,
+    pub status: DummyConnectorDisputeStatus,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DummyConnectorDisputeStatus {
+    Opened,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct DummyConnectorWebhook {
+    pub event_type: DummyConnectorWebhookEventType,
+    pub data: DummyConnectorWebhookData,
+}
+
+#[derive(Clone, Copy, Debug, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DummyConnectorWebhookEventType {
+    PaymentSucceeded,
+    PaymentAuthorized,
+    PaymentFailed,
+    RefundSucceeded,
+    RefundFailed,
+    DisputeOpened,
+    PayoutSucceeded,
+    PayoutFailed,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+#[serde(tag = "type", content = "object", rename_all = "snake_case")]
+pub enum DummyConnectorWebhookData {
+    Payment(DummyConnectorPaymentResponse),
+    Refund(DummyConnectorRefundResponse),
+    Dispute(DummyConnectorDispute),
+    Payout(DummyConnectorPayoutResponse),
+}
+
 pub type DummyConnectorResponse<T> =
     CustomResult<services::ApplicationResponse<T>, DummyConnectorErrors>;
 
 pub type DummyConnectorResult<T> = CustomResult<T, DummyConnectorErrors>;
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_scenario_steps_are_applied_in_order() {
+        let server_error = DummyConnectorScenarioOutcome::ServerError { status_code: 503 };
+        let mut scenario = DummyConnectorScenario {
+            steps: vec![
+                DummyConnectorScenarioStep {
+                    flow: DummyConnectorScenarioFlow::Payment,
+                    times: 2,
+                    latency_ms: 0,
+                    outcome: server_error.clone(),
+                },
+                DummyConnectorScenarioStep {
+                    flow: DummyConnectorScenarioFlow::Refund,
+                    times: 1,
+                    latency_ms: 0,
+                    outcome: DummyConnectorScenarioOutcome::Timeout,
+                },
+                DummyConnectorScenarioStep {
+                    flow: DummyConnectorScenarioFlow::Payment,
+                    times: 1,
+                    latency_ms: 500,
+                    outcome: DummyConnectorScenarioOutcome::Default,
+                },
+            ],
+            webhook_url: None,
+        };
+
+        let outcomes =
+            std::iter::from_fn(|| scenario.next_step(DummyConnectorScenarioFlow::Payment))
+                .map(|step| step.outcome)
+                .collect::<Vec<_>>();
+        assert_eq!(
+            outcomes,
+            vec![
+                server_error.clone(),
+                server_error,
+                DummyConnectorScenarioOutcome::Default
+            ]
+        );
+        assert!(scenario
+            .next_step(DummyConnectorScenarioFlow::PaymentRetrieve)
+            .is_none());
+        assert_eq!(
+            scenario
+                .next_step(DummyConnectorScenarioFlow::Refund)
+                .map(|step| step.outcome),
+            Some(DummyConnectorScenarioOutcome::Timeout)
+        );
+    }
+
+    #[cfg(feature = "payouts")]
+    #[test]
+    fn test_payout_webhook_is_parsed_by_the_connector() {
+        use crate::{connector::dummyconnector::transformers, types::api};
+
+        let payout = DummyConnectorPayoutResponse::new(
+            DummyConnectorStatus::Failed,
+            DummyConnectorPayoutRequest {
+                amount: 1000,
+                currency: Currency::EUR,
+                connector: DummyConnectors::PhonyPay,
+            },
+        );
+        let webhook = serde_json::to_value(DummyConnectorWebhook {
+            event_type: DummyConnectorWebhookEventType::PayoutFailed,
+            data: DummyConnectorWebhookData::Payout(payout.clone()),
+        })
+        .unwrap();
+
+        let webhook: transformers::DummyConnectorWebhook = serde_json::from_value(webhook).unwrap();
+        assert!(matches!(
+            api::IncomingWebhookEvent::from(webhook.event_type),
+            api::IncomingWebhookEvent::PayoutFailure
+        ));
+        assert!(matches!(
+            webhook.data.get_object_reference_id(),
+            api_models::webhooks::ObjectReferenceId::PayoutId(
+                api_models::webhooks::PayoutIdType::ConnectorPayoutId(payout_id)
+            ) if payout_id == payout.id
+        ));
+    }
+
+    #[test]
+    fn test_scenario_webhook_url_must_target_this_server() {
+        let scenario_with_webhook_url = |webhook_url: Option<&str>| DummyConnectorScenario {
+            steps: Vec::new(),
+            webhook_url: webhook_url.map(ToOwned::to_owned),
+        };
+        let base_url = "http://localhost:8080";
+
+        assert!(scenario_with_webhook_url(None)
+            .validate_webhook_url(base_url)
+            .is_ok());
+        assert!(
+            scenario_with_webhook_url(Some("http://localhost:8080/webhooks/merchant_1/mca_1"))
+                .validate_webhook_url(base_url)
+                .is_ok()
+        );
+        assert!(
+            scenario_with_webhook_url(Some("http://localhost:8080/webhooks/merchant_1/mca_1"))
+                .validate_webhook_url("http://localhost:8080/")
+                .is_ok()
+        );
+        for webhook_url in [
+            "http://169.254.169.254/latest/meta-data",
+            "http://localhost:8080.example.com/webhooks/merchant_1/mca_1",
+            "http://localhost:8080/accounts",
+        ] {
+            assert!(scenario_with_webhook_url(Some(webhook_url))
+                .validate_webhook_url(base_url)
+                .is_err());
+        }
+    }
+}
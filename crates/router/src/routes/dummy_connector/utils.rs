@@ -1,17 +1,18 @@
 use std::fmt::Debug;
 
-use common_utils::ext_traits::AsyncExt;
+use common_utils::{ext_traits::AsyncExt, request::RequestContent};
 use error_stack::{report, ResultExt};
 use masking::PeekInterface;
 use maud::html;
 use rand::{distributions::Uniform, prelude::Distribution};
+use router_env::logger;
 use tokio::time as tokio;
 
 use super::{
     consts, errors,
     types::{self, GetPaymentMethodDetails},
 };
-use crate::{configs::settings, routes::SessionState};
+use crate::{configs::settings, headers, routes::SessionState, services};
 
 pub async fn tokio_mock_sleep(delay: u64, tolerance: u64) {
     let mut rng = rand::thread_rng();
@@ -64,6 +65,25 @@ pub async fn get_payment_data_from_payment_id(
         .change_context(errors::DummyConnectorErrors::PaymentNotFound)
 }
 
+pub async fn get_payout_data_from_payout_id(
+    state: &SessionState,
+    payout_id: &str,
+) -> types::DummyConnectorResult<types::DummyConnectorPayoutResponse> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::DummyConnectorErrors::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    redis_conn
+        .get_and_deserialize_key::<types::DummyConnectorPayoutResponse>(
+            payout_id,
+            "types DummyConnectorPayoutResponse",
+        )
+        .await
+        .change_context(errors::DummyConnectorErrors::PayoutNotFound)
+}
+
 pub async fn get_payment_data_by_attempt_id(
     state: &SessionState,
     attempt_id: String,
@@ -89,6 +109,115 @@ pub async fn get_payment_data_by_attempt_id(
         .change_context(errors::DummyConnectorErrors::PaymentNotFound)
 }
 
+/// The scenario applied to a request is selected by the api key sent by the connector
+/// integration, which is the api key configured on the merchant connector account
+pub fn get_scenario_id(req: &actix_web::HttpRequest) -> Option<String> {
+    req.headers()
+        .get(headers::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .map(ToOwned::to_owned)
+}
+
+pub async fn get_scenario(
+    state: &SessionState,
+    scenario_id: &str,
+) -> types::DummyConnectorResult<types::DummyConnectorScenario> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::DummyConnectorErrors::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    redis_conn
+        .get_and_deserialize_key::<types::DummyConnectorScenario>(
+            format!("{}{}", consts::SCENARIO_KEY_PREFIX, scenario_id).as_str(),
+            "DummyConnectorScenario",
+        )
+        .await
+        .change_context(errors::DummyConnectorErrors::ScenarioNotFound)
+}
+
+/// Takes the next step scripted for the flow, if a scenario is configured for the api key the
+/// request was sent with. Returns the step along with the webhook url of the scenario.
+pub async fn get_scenario_step(
+    state: &SessionState,
+    scenario_id: Option<&str>,
+    flow: types::DummyConnectorScenarioFlow,
+) -> types::DummyConnectorResult<Option<(types::DummyConnectorScenarioStep, Option<String>)>> {
+    let Some(scenario_id) = scenario_id else {
+        return Ok(None);
+    };
+    // Requests sent without a scenario are processed as usual
+    let Ok(mut scenario) = get_scenario(state, scenario_id).await else {
+        return Ok(None);
+    };
+
+    let Some(step) = scenario.next_step(flow) else {
+        return Ok(None);
+    };
+    logger::info!(scenario_id, %flow, outcome = ?step.outcome, "Applying dummy connector scenario step");
+    store_data_in_redis(
+        state,
+        format!("{}{}", consts::SCENARIO_KEY_PREFIX, scenario_id),
+        scenario.clone(),
+        state.conf.dummy_connector.scenario_ttl,
+    )
+    .await?;
+
+    Ok(Some((step, scenario.webhook_url)))
+}
+
+/// Waits for the latency injected by the step, and fails the request if the step injects a
+/// timeout or a server error
+pub async fn apply_scenario_step(
+    step: &types::DummyConnectorScenarioStep,
+) -> types::DummyConnectorResult<()> {
+    tokio::sleep(tokio::Duration::from_millis(step.latency_ms)).await;
+
+    match step.outcome {
+        types::DummyConnectorScenarioOutcome::Timeout => {
+            // Held for longer than the connector request timeout, so that the caller gives up on
+            // the request before it is responded to
+            tokio::sleep(tokio::Duration::from_secs(
+                crate::consts::REQUEST_TIME_OUT.saturating_add(1),
+            ))
+            .await;
+            Err(report!(errors::DummyConnectorErrors::GatewayTimeout))
+        }
+        types::DummyConnectorScenarioOutcome::ServerError { status_code } => {
+            Err(report!(errors::DummyConnectorErrors::ServerError {
+                status_code
+            }))
+        }
+        types::DummyConnectorScenarioOutcome::Default
+        | types::DummyConnectorScenarioOutcome::Decline { .. }
+        | types::DummyConnectorScenarioOutcome::Pending { .. }
+        | types::DummyConnectorScenarioOutcome::Dispute { .. } => Ok(()),
+    }
+}
+
+pub async fn send_webhook(
+    state: &SessionState,
+    webhook_url: &str,
+    webhook: types::DummyConnectorWebhook,
+) -> types::DummyConnectorResult<()> {
+    let request = services::RequestBuilder::new()
+        .method(services::Method::Post)
+        .url(webhook_url)
+        .attach_default_headers()
+        .set_body(RequestContent::Json(Box::new(webhook)))
+        .build();
+
+    let response = state
+        .api_client
+        .send_request(state, request, None, false)
+        .await
+        .change_context(errors::DummyConnectorErrors::InternalServerError)
+        .attach_printable("Failed to send the webhook")?;
+    logger::info!(status_code = %response.status(), webhook_url, "Sent dummy connector webhook");
+    Ok(())
+}
+
 pub fn get_authorize_page(
     payment_data: types::DummyConnectorPaymentData,
     return_url: String,
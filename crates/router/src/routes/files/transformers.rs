@@ -64,10 +64,12 @@ pub async fn get_create_file_request(
         Err(errors::ApiErrorResponse::MissingFile)
             .attach_printable("Missing / Invalid file in the request")?
     }
-    // Get file mime type using 'infer'
-    let kind = infer::get(&file).ok_or(errors::ApiErrorResponse::MissingFileContentType)?;
-    let file_type = kind
-        .mime_type()
+    // Get file mime type using 'infer', text files are identified by the extension of the file
+    let mime_type = infer::get(&file)
+        .map(|kind| kind.mime_type())
+        .or_else(|| get_text_file_mime_type(file_name.as_deref()))
+        .ok_or(errors::ApiErrorResponse::MissingFileContentType)?;
+    let file_type = mime_type
         .parse::<mime::Mime>()
         .change_context(errors::ApiErrorResponse::MissingFileContentType)
         .attach_printable("File content type error")?;
@@ -80,3 +82,12 @@ pub async fn get_create_file_request(
         dispute_id,
     })
 }
+
+fn get_text_file_mime_type(file_name: Option<&str>) -> Option<&'static str> {
+    let extension = file_name?.rsplit_once('.')?.1.to_lowercase();
+    match extension.as_str() {
+        "csv" => Some("text/csv"),
+        "json" => Some("application/json"),
+        _ => None,
+    }
+}
@@ -0,0 +1,134 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::incoming_webhook_archive::{
+    IncomingWebhookListConstraints, IncomingWebhookListRequestInternal,
+    IncomingWebhookRequestInternal,
+};
+use router_env::{instrument, tracing, Flow};
+
+use crate::{
+    core::{api_locking, webhooks::incoming_webhook_archive},
+    routes::AppState,
+    services::{api, authentication as auth, authorization::permissions::Permission},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::IncomingWebhookList))]
+pub async fn list_incoming_webhooks(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    query: web::Query<IncomingWebhookListConstraints>,
+) -> impl Responder {
+    let flow = Flow::IncomingWebhookList;
+    let merchant_id = path.into_inner();
+
+    let request_internal = IncomingWebhookListRequestInternal {
+        merchant_id: merchant_id.clone(),
+        constraints: query.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, auth_data, request_internal, _| {
+            incoming_webhook_archive::list_incoming_webhooks(
+                state,
+                auth_data.merchant_account,
+                auth_data.key_store,
+                request_internal.constraints,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantWebhookEventRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::IncomingWebhookRetrieve))]
+pub async fn retrieve_incoming_webhook(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(common_utils::id_type::MerchantId, String)>,
+) -> impl Responder {
+    let flow = Flow::IncomingWebhookRetrieve;
+    let (merchant_id, webhook_id) = path.into_inner();
+
+    let request_internal = IncomingWebhookRequestInternal {
+        merchant_id: merchant_id.clone(),
+        webhook_id,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, auth_data, request_internal, _| {
+            incoming_webhook_archive::retrieve_incoming_webhook(
+                state,
+                auth_data.merchant_account,
+                auth_data.key_store,
+                request_internal.webhook_id,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantWebhookEventRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::IncomingWebhookReprocess))]
+pub async fn reprocess_incoming_webhook(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(common_utils::id_type::MerchantId, String)>,
+) -> impl Responder {
+    let flow = Flow::IncomingWebhookReprocess;
+    let (merchant_id, webhook_id) = path.into_inner();
+
+    let request_internal = IncomingWebhookRequestInternal {
+        merchant_id: merchant_id.clone(),
+        webhook_id,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, auth_data, request_internal, req_state| {
+            incoming_webhook_archive::reprocess_incoming_webhook(
+                state,
+                req_state,
+                auth_data.merchant_account,
+                auth_data.key_store,
+                request_internal.webhook_id,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantWebhookEventWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
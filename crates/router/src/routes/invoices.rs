@@ -0,0 +1,174 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::invoices as invoice_types;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, invoices},
+    services::{api, authentication as auth, authorization::permissions::Permission},
+};
+
+/// Invoices - Create
+///
+/// Creates an invoice for a customer along with a hosted invoice page through which it can be paid
+#[utoipa::path(
+    post,
+    path = "/invoices",
+    request_body = InvoiceCreateRequest,
+    responses(
+        (status = 200, description = "Invoice created", body = InvoiceResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Invoices",
+    operation_id = "Create an Invoice",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::InvoiceCreate))]
+pub async fn create_invoice(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<invoice_types::InvoiceCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::InvoiceCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            invoices::create_invoice(state, auth.merchant_account, auth.key_store, req)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Invoices - Retrieve
+///
+/// Retrieves an invoice. The status of the invoice is refreshed from its payment.
+#[utoipa::path(
+    get,
+    path = "/invoices/{invoice_id}",
+    params(
+        ("invoice_id" = String, Path, description = "The identifier for the invoice")
+    ),
+    responses(
+        (status = 200, description = "Invoice retrieved", body = InvoiceResponse),
+        (status = 404, description = "Invoice does not exist in our records")
+    ),
+    tag = "Invoices",
+    operation_id = "Retrieve an Invoice",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::InvoiceRetrieve))]
+pub async fn retrieve_invoice(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::InvoiceRetrieve;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, auth: auth::AuthenticationData, invoice_id, _| {
+            invoices::retrieve_invoice(state, auth.merchant_account, auth.key_store, invoice_id)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantPaymentRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Invoices - List
+///
+/// Lists the invoices of the merchant, optionally filtered by customer and status
+#[utoipa::path(
+    get,
+    path = "/invoices",
+    params(
+        ("customer_id" = Option<String>, Query, description = "Only return invoices of the given customer"),
+        ("status" = Option<InvoiceStatus>, Query, description = "Only return invoices with the given status"),
+        ("limit" = Option<i64>, Query, description = "The maximum number of invoices to be returned"),
+        ("offset" = Option<i64>, Query, description = "The number of invoices to skip")
+    ),
+    responses(
+        (status = 200, description = "Invoices retrieved", body = InvoiceListResponse),
+    ),
+    tag = "Invoices",
+    operation_id = "List all Invoices",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::InvoiceList))]
+pub async fn list_invoices(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<invoice_types::InvoiceListConstraints>,
+) -> HttpResponse {
+    let flow = Flow::InvoiceList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, auth: auth::AuthenticationData, constraints, _| {
+            invoices::list_invoices(state, auth.merchant_account, constraints)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantPaymentRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Invoices - Void
+///
+/// Voids an unpaid invoice. The hosted invoice page can no longer be used to pay it.
+#[utoipa::path(
+    post,
+    path = "/invoices/{invoice_id}/void",
+    params(
+        ("invoice_id" = String, Path, description = "The identifier for the invoice")
+    ),
+    responses(
+        (status = 200, description = "Invoice voided", body = InvoiceResponse),
+        (status = 400, description = "Invoice has already been paid"),
+        (status = 404, description = "Invoice does not exist in our records")
+    ),
+    tag = "Invoices",
+    operation_id = "Void an Invoice",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::InvoiceVoid))]
+pub async fn void_invoice(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::InvoiceVoid;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, auth: auth::AuthenticationData, invoice_id, _| {
+            invoices::void_invoice(state, auth.merchant_account, auth.key_store, invoice_id)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
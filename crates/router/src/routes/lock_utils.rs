@@ -14,6 +14,8 @@ pub enum ApiIdentifier {
     Ephemeral,
     Health,
     Mandates,
+    Subscriptions,
+    Invoices,
     PaymentMethods,
     PaymentMethodAuth,
     Payouts,
@@ -47,7 +49,12 @@ impl From<Flow> for ApiIdentifier {
             | Flow::MerchantsAccountUpdate
             | Flow::MerchantsAccountDelete
             | Flow::MerchantTransferKey
-            | Flow::MerchantAccountList => Self::MerchantAccount,
+            | Flow::MerchantAccountList
+            | Flow::StorageSchemeMigrationCreate
+            | Flow::StorageSchemeMigrationRetrieve
+            | Flow::StorageSchemeMigrationRollback
+            | Flow::AccountUpdaterToggle
+            | Flow::AccountUpdaterRetrieve => Self::MerchantAccount,
 
             Flow::OrganizationCreate | Flow::OrganizationRetrieve | Flow::OrganizationUpdate => {
                 Self::Organization
@@ -100,6 +107,19 @@ impl From<Flow> for ApiIdentifier {
             Flow::DeepHealthCheck | Flow::HealthCheck => Self::Health,
             Flow::MandatesRetrieve | Flow::MandatesRevoke | Flow::MandatesList => Self::Mandates,
 
+            Flow::SubscriptionPlanCreate
+            | Flow::SubscriptionPlanRetrieve
+            | Flow::SubscriptionPlanUpdate
+            | Flow::SubscriptionPlanList
+            | Flow::SubscriptionCreate
+            | Flow::SubscriptionRetrieve
+            | Flow::SubscriptionList
+            | Flow::SubscriptionCancel => Self::Subscriptions,
+
+            Flow::InvoiceCreate | Flow::InvoiceRetrieve | Flow::InvoiceList | Flow::InvoiceVoid => {
+                Self::Invoices
+            }
+
             Flow::PaymentMethodsCreate
             | Flow::PaymentMethodsMigrate
             | Flow::PaymentMethodsList
@@ -150,7 +170,10 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PayoutsFilter
             | Flow::PayoutsAccounts
             | Flow::PayoutsConfirm
-            | Flow::PayoutLinkInitiate => Self::Payouts,
+            | Flow::PayoutLinkInitiate
+            | Flow::PayoutBatchCreate
+            | Flow::PayoutBatchRetrieve
+            | Flow::PayoutBatchResultsRetrieve => Self::Payouts,
 
             Flow::RefundsCreate
             | Flow::RefundsRetrieve
@@ -159,18 +182,33 @@ impl From<Flow> for ApiIdentifier {
             | Flow::RefundsList
             | Flow::RefundsFilters
             | Flow::RefundsAggregate
-            | Flow::RefundsManualUpdate => Self::Refunds,
+            | Flow::RefundsManualUpdate
+            | Flow::RefundBatchCreate
+            | Flow::RefundBatchRetrieve
+            | Flow::RefundBatchReport => Self::Refunds,
 
             Flow::FrmFulfillment
             | Flow::IncomingWebhookReceive
+            | Flow::IncomingWebhookList
+            | Flow::IncomingWebhookRetrieve
+            | Flow::IncomingWebhookReprocess
             | Flow::WebhookEventInitialDeliveryAttemptList
             | Flow::WebhookEventDeliveryAttemptList
-            | Flow::WebhookEventDeliveryRetry => Self::Webhooks,
+            | Flow::WebhookEventDeliveryRetry
+            | Flow::WebhookEndpointCreate
+            | Flow::WebhookEndpointList
+            | Flow::WebhookEndpointRetrieve
+            | Flow::WebhookEndpointUpdate
+            | Flow::WebhookEndpointDelete
+            | Flow::WebhookEndpointSecretRotate
+            | Flow::WebhookEndpointHealthRetrieve
+            | Flow::WebhookEndpointReenable => Self::Webhooks,
 
             Flow::ApiKeyCreate
             | Flow::ApiKeyRetrieve
             | Flow::ApiKeyUpdate
             | Flow::ApiKeyRevoke
+            | Flow::ApiKeyRotate
             | Flow::ApiKeyList => Self::ApiKeys,
 
             Flow::DisputesRetrieve
@@ -179,6 +217,7 @@ impl From<Flow> for ApiIdentifier {
             | Flow::DisputesEvidenceSubmit
             | Flow::AttachDisputeEvidence
             | Flow::RetrieveDisputeEvidence
+            | Flow::CreateDisputeEvidenceDraft
             | Flow::DisputesAggregate
             | Flow::DeleteDisputeEvidence => Self::Disputes,
 
@@ -186,7 +225,7 @@ impl From<Flow> for ApiIdentifier {
 
             Flow::CreateFile | Flow::DeleteFile | Flow::RetrieveFile => Self::Files,
 
-            Flow::CacheInvalidate => Self::Cache,
+            Flow::CacheInvalidate | Flow::CacheStatsRetrieve => Self::Cache,
 
             Flow::ProfileCreate
             | Flow::ProfileUpdate
@@ -194,13 +233,22 @@ impl From<Flow> for ApiIdentifier {
             | Flow::ProfileDelete
             | Flow::ProfileList
             | Flow::ToggleExtendedCardInfo
-            | Flow::ToggleConnectorAgnosticMit => Self::Profile,
+            | Flow::ToggleConnectorAgnosticMit
+            | Flow::ProfileWebhookSecretRotate
+            | Flow::SuccessRateAlertConfigRetrieve
+            | Flow::SuccessRateAlertConfigUpdate
+            | Flow::SuccessRateAlertConfigDelete => Self::Profile,
 
             Flow::PaymentLinkRetrieve
             | Flow::PaymentLinkInitiate
             | Flow::PaymentSecureLinkInitiate
             | Flow::PaymentLinkList
-            | Flow::PaymentLinkStatus => Self::PaymentLink,
+            | Flow::PaymentLinkStatus
+            | Flow::ReusablePaymentLinkCreate
+            | Flow::ReusablePaymentLinkRetrieve
+            | Flow::ReusablePaymentLinkUpdate
+            | Flow::ReusablePaymentLinkList
+            | Flow::ReusablePaymentLinkInitiate => Self::PaymentLink,
 
             Flow::Verification => Self::Verification,
 
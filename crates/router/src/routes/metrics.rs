@@ -53,6 +53,7 @@ counter_metric!(CUSTOMER_REDACTED, GLOBAL_METER);
 
 counter_metric!(API_KEY_CREATED, GLOBAL_METER);
 counter_metric!(API_KEY_REVOKED, GLOBAL_METER);
+counter_metric!(API_KEY_ROTATED, GLOBAL_METER);
 
 counter_metric!(MCA_CREATE, GLOBAL_METER);
 
@@ -118,6 +119,33 @@ counter_metric!(AUTO_PAYOUT_RETRY_GSM_MATCH_COUNT, GLOBAL_METER);
 counter_metric!(AUTO_PAYOUT_RETRY_EXHAUSTED_COUNT, GLOBAL_METER);
 counter_metric!(AUTO_RETRY_PAYOUT_COUNT, GLOBAL_METER);
 
+// Metrics for recovery of failed recurring payments
+counter_metric!(PAYMENT_RECOVERY_SCHEDULED_COUNT, GLOBAL_METER);
+counter_metric!(PAYMENT_RECOVERY_ATTEMPT_COUNT, GLOBAL_METER);
+counter_metric!(PAYMENT_RECOVERY_RECOVERED_COUNT, GLOBAL_METER);
+counter_metric!(PAYMENT_RECOVERY_LOST_COUNT, GLOBAL_METER);
+
+// Metrics for tracking of dispute deadlines
+counter_metric!(DISPUTE_DEADLINE_REMINDER_SENT_COUNT, GLOBAL_METER);
+counter_metric!(DISPUTE_AUTO_ACCEPTED_COUNT, GLOBAL_METER);
+
+// Metrics for the card account updater
+counter_metric!(CARD_ACCOUNT_UPDATE_COUNT, GLOBAL_METER);
+
+// Metrics for hosted invoices
+counter_metric!(INVOICE_REMINDER_COUNT, GLOBAL_METER);
+counter_metric!(INVOICE_PAID_COUNT, GLOBAL_METER);
+
+// Metrics for scheduled analytics reports
+counter_metric!(ANALYTICS_REPORTS_DELIVERED_COUNT, GLOBAL_METER);
+
+// Metrics for success rate alerts
+counter_metric!(SUCCESS_RATE_ALERTS_COUNT, GLOBAL_METER);
+
+// Metrics for exports
+counter_metric!(EXPORTS_COMPLETED_COUNT, GLOBAL_METER);
+counter_metric!(EXPORTS_FAILED_COUNT, GLOBAL_METER);
+
 // Scheduler / Process Tracker related metrics
 counter_metric!(TASKS_ADDED_COUNT, GLOBAL_METER); // Tasks added to process tracker
 counter_metric!(TASK_ADDITION_FAILURES_COUNT, GLOBAL_METER); // Failures in task addition to process tracker
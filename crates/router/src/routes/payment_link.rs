@@ -2,7 +2,10 @@ use actix_web::{web, Responder};
 use router_env::{instrument, tracing, Flow};
 
 use crate::{
-    core::{api_locking, payment_link::*},
+    core::{
+        api_locking,
+        payment_link::{reusable, *},
+    },
     services::{api, authentication as auth},
     AppState,
 };
@@ -143,7 +146,7 @@ pub async fn initiate_secure_payment_link(
         (status = 200, description = "The payment link list was retrieved successfully"),
         (status = 401, description = "Unauthorized request")
     ),
-    tag = "Payment Link",
+    tag = "Payments",
     operation_id = "List all Payment links",
     security(("api_key" = []))
 )]
@@ -205,3 +208,203 @@ pub async fn payment_link_status(
     ))
     .await
 }
+
+/// Reusable Payment Link - Create
+///
+/// Creates a payment link which can be paid through any number of times. Every visit to the link
+/// creates a new payment.
+#[utoipa::path(
+    post,
+    path = "/payment_link/reusable",
+    request_body = ReusablePaymentLinkCreateRequest,
+    responses(
+        (status = 200, description = "Reusable payment link created", body = ReusablePaymentLinkResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Payments",
+    operation_id = "Create a Reusable Payment Link",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::ReusablePaymentLinkCreate))]
+pub async fn reusable_payment_link_create(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<api_models::payments::ReusablePaymentLinkCreateRequest>,
+) -> impl Responder {
+    let flow = Flow::ReusablePaymentLinkCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            reusable::create_reusable_payment_link(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Reusable Payment Link - Retrieve
+///
+/// Retrieves a reusable payment link
+#[utoipa::path(
+    get,
+    path = "/payment_link/reusable/{link_id}",
+    params(
+        ("link_id" = String, Path, description = "The identifier for the reusable payment link")
+    ),
+    responses(
+        (status = 200, description = "Reusable payment link retrieved", body = ReusablePaymentLinkResponse),
+        (status = 404, description = "No payment link found")
+    ),
+    tag = "Payments",
+    operation_id = "Retrieve a Reusable Payment Link",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::ReusablePaymentLinkRetrieve))]
+pub async fn reusable_payment_link_retrieve(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::ReusablePaymentLinkRetrieve;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, auth: auth::AuthenticationData, link_id, _| {
+            reusable::retrieve_reusable_payment_link(state, auth.merchant_account, link_id)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Reusable Payment Link - Update
+///
+/// Updates a reusable payment link. The amount of a link cannot be changed, a link can be
+/// deactivated instead.
+#[utoipa::path(
+    post,
+    path = "/payment_link/reusable/{link_id}",
+    params(
+        ("link_id" = String, Path, description = "The identifier for the reusable payment link")
+    ),
+    request_body = ReusablePaymentLinkUpdateRequest,
+    responses(
+        (status = 200, description = "Reusable payment link updated", body = ReusablePaymentLinkResponse),
+        (status = 404, description = "No payment link found")
+    ),
+    tag = "Payments",
+    operation_id = "Update a Reusable Payment Link",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::ReusablePaymentLinkUpdate))]
+pub async fn reusable_payment_link_update(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<api_models::payments::ReusablePaymentLinkUpdateRequest>,
+) -> impl Responder {
+    let flow = Flow::ReusablePaymentLinkUpdate;
+    let link_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            reusable::update_reusable_payment_link(
+                state,
+                auth.merchant_account,
+                link_id.clone(),
+                req,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Reusable Payment Link - List
+///
+/// Lists the reusable payment links of the merchant
+#[utoipa::path(
+    get,
+    path = "/payment_link/reusable",
+    params(
+        ("limit" = Option<i64>, Query, description = "The maximum number of links to be returned"),
+        ("offset" = Option<i64>, Query, description = "The number of links to skip")
+    ),
+    responses(
+        (status = 200, description = "Reusable payment links retrieved", body = ReusablePaymentLinkListResponse),
+    ),
+    tag = "Payments",
+    operation_id = "List all Reusable Payment Links",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::ReusablePaymentLinkList))]
+pub async fn reusable_payment_link_list(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    query: web::Query<api_models::payments::ReusablePaymentLinkListConstraints>,
+) -> impl Responder {
+    let flow = Flow::ReusablePaymentLinkList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, auth: auth::AuthenticationData, constraints, _| {
+            reusable::list_reusable_payment_links(state, auth.merchant_account, constraints)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn initiate_reusable_payment_link(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<(common_utils::id_type::MerchantId, String)>,
+    query: web::Query<api_models::payments::ReusablePaymentLinkInitiateQuery>,
+) -> impl Responder {
+    let flow = Flow::ReusablePaymentLinkInitiate;
+    let (merchant_id, link_id) = path.into_inner();
+
+    let payload = api_models::payments::ReusablePaymentLinkInitiateRequest {
+        merchant_id: merchant_id.clone(),
+        link_id,
+        amount: query.into_inner().amount,
+    };
+    let headers = req.headers();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, payload, _| {
+            reusable::initiate_reusable_payment_link_flow(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                payload,
+                headers,
+            )
+        },
+        &crate::services::authentication::MerchantIdAuth(merchant_id),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
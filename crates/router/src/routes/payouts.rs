@@ -7,6 +7,8 @@ use common_utils::consts;
 use router_env::{instrument, tracing, Flow};
 
 use super::app::AppState;
+#[cfg(feature = "v1")]
+use crate::core::payout_batch;
 use crate::{
     core::{api_locking, payouts::*},
     headers::ACCEPT_LANGUAGE,
@@ -205,6 +207,94 @@ pub async fn payouts_fulfill(
     .await
 }
 
+/// Payouts - Batch Create
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutBatchCreate))]
+pub async fn payout_batch_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<payout_types::PayoutBatchCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::PayoutBatchCreate;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            payout_batch::payout_batch_create_core(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payouts - Batch Retrieve
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutBatchRetrieve))]
+pub async fn payout_batch_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PayoutBatchRetrieve;
+    let payload = payout_types::PayoutBatchId {
+        batch_id: path.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            payout_batch::payout_batch_retrieve_core(state, auth.merchant_account, req)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payouts - Batch Results Retrieve
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutBatchResultsRetrieve))]
+pub async fn payout_batch_results_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PayoutBatchResultsRetrieve;
+    let payload = payout_types::PayoutBatchId {
+        batch_id: path.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            payout_batch::payout_batch_results_retrieve_core(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 /// Payouts - List
 #[cfg(feature = "olap")]
 #[instrument(skip_all, fields(flow = ?Flow::PayoutsList))]
@@ -0,0 +1,345 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::subscriptions as subscription_types;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, subscriptions},
+    services::{api, authentication as auth, authorization::permissions::Permission},
+};
+
+/// Subscription Plans - Create
+///
+/// Creates a plan which customers can be subscribed to
+#[utoipa::path(
+    post,
+    path = "/subscriptions/plans",
+    request_body = SubscriptionPlanCreateRequest,
+    responses(
+        (status = 200, description = "Subscription plan created", body = SubscriptionPlanResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Create a Subscription Plan",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionPlanCreate))]
+pub async fn create_subscription_plan(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<subscription_types::SubscriptionPlanCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::SubscriptionPlanCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            subscriptions::create_subscription_plan(state, auth.merchant_account, req)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Subscription Plans - Retrieve
+///
+/// Retrieves a subscription plan
+#[utoipa::path(
+    get,
+    path = "/subscriptions/plans/{plan_id}",
+    params(
+        ("plan_id" = String, Path, description = "The identifier for the subscription plan")
+    ),
+    responses(
+        (status = 200, description = "Subscription plan retrieved", body = SubscriptionPlanResponse),
+        (status = 404, description = "Subscription plan does not exist in our records")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Retrieve a Subscription Plan",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionPlanRetrieve))]
+pub async fn retrieve_subscription_plan(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::SubscriptionPlanRetrieve;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, auth: auth::AuthenticationData, plan_id, _| {
+            subscriptions::retrieve_subscription_plan(state, auth.merchant_account, plan_id)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantMandateRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Subscription Plans - Update
+///
+/// Updates a subscription plan. The amount and the billing interval of a plan cannot be changed.
+#[utoipa::path(
+    post,
+    path = "/subscriptions/plans/{plan_id}",
+    params(
+        ("plan_id" = String, Path, description = "The identifier for the subscription plan")
+    ),
+    request_body = SubscriptionPlanUpdateRequest,
+    responses(
+        (status = 200, description = "Subscription plan updated", body = SubscriptionPlanResponse),
+        (status = 404, description = "Subscription plan does not exist in our records")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Update a Subscription Plan",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionPlanUpdate))]
+pub async fn update_subscription_plan(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<subscription_types::SubscriptionPlanUpdateRequest>,
+) -> HttpResponse {
+    let flow = Flow::SubscriptionPlanUpdate;
+    let plan_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            subscriptions::update_subscription_plan(
+                state,
+                auth.merchant_account,
+                plan_id.clone(),
+                req,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Subscription Plans - List
+///
+/// Lists the subscription plans of the merchant
+#[utoipa::path(
+    get,
+    path = "/subscriptions/plans",
+    responses(
+        (status = 200, description = "List of subscription plans", body = SubscriptionPlanListResponse)
+    ),
+    tag = "Subscriptions",
+    operation_id = "List all Subscription Plans",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionPlanList))]
+pub async fn list_subscription_plans(state: web::Data<AppState>, req: HttpRequest) -> HttpResponse {
+    let flow = Flow::SubscriptionPlanList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, _, _| {
+            subscriptions::list_subscription_plans(state, auth.merchant_account)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantMandateRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Subscriptions - Create
+///
+/// Subscribes a customer to a plan. The customer is charged off-session on every billing date,
+/// using the mandate or the saved payment method provided.
+#[utoipa::path(
+    post,
+    path = "/subscriptions",
+    request_body = SubscriptionCreateRequest,
+    responses(
+        (status = 200, description = "Subscription created", body = SubscriptionResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Create a Subscription",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionCreate))]
+pub async fn create_subscription(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<subscription_types::SubscriptionCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::SubscriptionCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            subscriptions::create_subscription(state, auth.merchant_account, auth.key_store, req)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Subscriptions - Retrieve
+///
+/// Retrieves a subscription
+#[utoipa::path(
+    get,
+    path = "/subscriptions/{subscription_id}",
+    params(
+        ("subscription_id" = String, Path, description = "The identifier for the subscription")
+    ),
+    responses(
+        (status = 200, description = "Subscription retrieved", body = SubscriptionResponse),
+        (status = 404, description = "Subscription does not exist in our records")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Retrieve a Subscription",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionRetrieve))]
+pub async fn retrieve_subscription(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::SubscriptionRetrieve;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, auth: auth::AuthenticationData, subscription_id, _| {
+            subscriptions::retrieve_subscription(state, auth.merchant_account, subscription_id)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantMandateRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Subscriptions - List
+///
+/// Lists the subscriptions of the merchant, optionally filtered by customer and status
+#[utoipa::path(
+    get,
+    path = "/subscriptions",
+    params(
+        ("customer_id" = Option<String>, Query, description = "Only return subscriptions of the given customer"),
+        ("status" = Option<SubscriptionStatus>, Query, description = "Only return subscriptions with the given status"),
+        ("limit" = Option<i64>, Query, description = "The maximum number of subscriptions to include in the response"),
+        ("offset" = Option<i64>, Query, description = "The number of subscriptions to skip"),
+    ),
+    responses(
+        (status = 200, description = "List of subscriptions", body = SubscriptionListResponse)
+    ),
+    tag = "Subscriptions",
+    operation_id = "List all Subscriptions",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionList))]
+pub async fn list_subscriptions(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: web::Query<subscription_types::SubscriptionListConstraints>,
+) -> HttpResponse {
+    let flow = Flow::SubscriptionList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            subscriptions::list_subscriptions(state, auth.merchant_account, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantMandateRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Subscriptions - Cancel
+///
+/// Cancels a subscription, either right away or at the end of the current billing period
+#[utoipa::path(
+    post,
+    path = "/subscriptions/{subscription_id}/cancel",
+    params(
+        ("subscription_id" = String, Path, description = "The identifier for the subscription")
+    ),
+    request_body = SubscriptionCancelRequest,
+    responses(
+        (status = 200, description = "Subscription cancelled", body = SubscriptionResponse),
+        (status = 404, description = "Subscription does not exist in our records")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Cancel a Subscription",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionCancel))]
+pub async fn cancel_subscription(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<subscription_types::SubscriptionCancelRequest>,
+) -> HttpResponse {
+    let flow = Flow::SubscriptionCancel;
+    let subscription_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            subscriptions::cancel_subscription(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                subscription_id.clone(),
+                req,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -17,7 +17,10 @@ use actix_web::{
     http::header::{HeaderName, HeaderValue},
     web, FromRequest, HttpRequest, HttpResponse, Responder, ResponseError,
 };
-pub use client::{proxy_bypass_urls, ApiClient, MockApiClient, ProxyClient};
+pub use client::{
+    fixtures::{FixtureApiClient, FixtureMode},
+    proxy_bypass_urls, ApiClient, MockApiClient, ProxyClient,
+};
 pub use common_enums::enums::PaymentAction;
 pub use common_utils::request::{ContentType, Method, Request, RequestBuilder};
 use common_utils::{
@@ -241,6 +244,7 @@ where
                         req.dispute_id.clone(),
                         status_code,
                     );
+                    connector_event.set_merchant_connector_id(req.merchant_connector_id.clone());
 
                     match response {
                         Ok(body) => {
@@ -265,6 +269,10 @@ where
                                         });
                                     match handle_response_result {
                                         Ok(mut data) => {
+                                            if let Err(error_response) = &data.response {
+                                                connector_event
+                                                    .set_error_code(error_response.code.clone());
+                                            }
                                             state.event_handler().log_event(&connector_event);
                                             data.connector_http_status_code =
                                                 connector_http_status_code;
@@ -306,6 +314,7 @@ where
                                                     body,
                                                     Some(&mut connector_event),
                                                 )?;
+                                            connector_event.set_error_code(error_res.code.clone());
                                             state.event_handler().log_event(&connector_event);
                                             error_res
                                         }
@@ -318,6 +327,7 @@ where
                                             if let Some(status) = error_res.attempt_status {
                                                 router_data.status = status;
                                             };
+                                            connector_event.set_error_code(error_res.code.clone());
                                             state.event_handler().log_event(&connector_event);
                                             error_res
                                         }
@@ -332,6 +342,9 @@ where
                         }
                         Err(error) => {
                             connector_event.set_error(json!({"error": error.to_string()}));
+                            if error.current_context().is_upstream_timeout() {
+                                connector_event.set_timeout();
+                            }
                             state.event_handler().log_event(&connector_event);
                             if error.current_context().is_upstream_timeout() {
                                 let error_response = ErrorResponse {
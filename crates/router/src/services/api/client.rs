@@ -1,3 +1,5 @@
+pub mod fixtures;
+
 use std::time::Duration;
 
 use base64::Engine;
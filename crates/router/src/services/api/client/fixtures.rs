@@ -0,0 +1,474 @@
+//! Recording and replaying of connector HTTP interactions.
+//!
+//! In record mode the requests are sent to the connector and every request/response pair is
+//! written to a fixture file, with the secrets in the request masked. In replay mode the
+//! responses are served from the fixture file instead, which allows the connector integration
+//! tests to be run without network access.
+
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use common_utils::request::RequestContent;
+use error_stack::ResultExt;
+use http::Method;
+use masking::ErasedMaskSerialize;
+use router_env::{logger, tracing_actix_web::RequestId};
+use tokio::sync::Mutex;
+
+use super::{ApiClient, RequestBuilder};
+use crate::{
+    core::errors::{ApiClientError, CustomResult},
+    routes::SessionState,
+    services::Request,
+};
+
+const MASKED_VALUE: &str = "*** masked ***";
+
+/// Fields whose values are masked wherever they appear in a recorded URL query or response body
+const SENSITIVE_FIELDS: &[&str] = &[
+    "access_token",
+    "api_key",
+    "apikey",
+    "client_secret",
+    "id_token",
+    "password",
+    "refresh_token",
+    "secret_key",
+];
+
+/// Response headers which are not recorded, either because they may carry secrets or because they
+/// describe the encoding of the original response rather than the recorded body
+const SKIPPED_RESPONSE_HEADERS: &[&str] = &[
+    "authorization",
+    "connection",
+    "content-encoding",
+    "content-length",
+    "set-cookie",
+    "transfer-encoding",
+];
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, strum::Display, strum::EnumString)]
+#[strum(serialize_all = "snake_case")]
+pub enum FixtureMode {
+    /// Send the requests to the connector and record the interactions
+    Record,
+    /// Serve the responses from previously recorded interactions
+    Replay,
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct RecordedRequest {
+    pub method: String,
+    pub url: String,
+    pub body: Option<serde_json::Value>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(tag = "format", content = "content", rename_all = "snake_case")]
+pub enum RecordedBody {
+    Json(serde_json::Value),
+    Text(String),
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct RecordedResponse {
+    pub status_code: u16,
+    pub headers: BTreeMap<String, String>,
+    pub body: RecordedBody,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct RecordedInteraction {
+    pub request: RecordedRequest,
+    pub response: RecordedResponse,
+}
+
+///
+/// Api client recording the connector interactions to, or replaying them from, a fixture file
+///
+#[derive(Clone)]
+pub struct FixtureApiClient {
+    inner: Box<dyn ApiClient>,
+    mode: FixtureMode,
+    fixture_path: PathBuf,
+    ignored_body_fields: Vec<String>,
+    // The interactions recorded so far in record mode, or the ones not yet served in replay mode
+    interactions: Arc<Mutex<Vec<Option<RecordedInteraction>>>>,
+}
+
+impl FixtureApiClient {
+    /// Creates a client for the fixture file at `fixture_path`. In record mode the requests are
+    /// sent through `inner` and the file is overwritten, in replay mode the file must exist.
+    pub fn new(
+        inner: Box<dyn ApiClient>,
+        mode: FixtureMode,
+        fixture_path: PathBuf,
+    ) -> CustomResult<Self, ApiClientError> {
+        let interactions = match mode {
+            FixtureMode::Record => Vec::new(),
+            FixtureMode::Replay => load_fixture(&fixture_path)?.into_iter().map(Some).collect(),
+        };
+
+        Ok(Self {
+            inner,
+            mode,
+            fixture_path,
+            ignored_body_fields: Vec::new(),
+            interactions: Arc::new(Mutex::new(interactions)),
+        })
+    }
+
+    /// Request body fields which are ignored while matching a request against the recorded ones,
+    /// such as timestamps or references generated afresh for every run
+    pub fn with_ignored_body_fields(mut self, fields: Vec<String>) -> Self {
+        self.ignored_body_fields = fields;
+        self
+    }
+
+    async fn record(
+        &self,
+        state: &SessionState,
+        recorded_request: RecordedRequest,
+        request: Request,
+        option_timeout_secs: Option<u64>,
+        forward_to_kafka: bool,
+    ) -> CustomResult<reqwest::Response, ApiClientError> {
+        let response = self
+            .inner
+            .send_request(state, request, option_timeout_secs, forward_to_kafka)
+            .await?;
+
+        let status_code = response.status().as_u16();
+        let headers = response
+            .headers()
+            .iter()
+            .filter(|(name, _)| !SKIPPED_RESPONSE_HEADERS.contains(&name.as_str()))
+            .filter_map(|(name, value)| {
+                value
+                    .to_str()
+                    .ok()
+                    .map(|value| (name.to_string(), value.to_owned()))
+            })
+            .collect::<BTreeMap<_, _>>();
+        let body = response
+            .bytes()
+            .await
+            .change_context(ApiClientError::ResponseDecodingFailed)
+            .attach_printable("Error while reading the response to be recorded")?;
+
+        // The caller receives the response as sent by the connector, only the fixture is masked
+        let recorded_body = match serde_json::from_slice::<serde_json::Value>(&body) {
+            Ok(mut value) => {
+                mask_sensitive_fields(&mut value);
+                RecordedBody::Json(value)
+            }
+            Err(_) => RecordedBody::Text(String::from_utf8_lossy(&body).into_owned()),
+        };
+
+        let mut interactions = self.interactions.lock().await;
+        interactions.push(Some(RecordedInteraction {
+            request: recorded_request,
+            response: RecordedResponse {
+                status_code,
+                headers: headers.clone(),
+                body: recorded_body,
+            },
+        }));
+        save_fixture(&self.fixture_path, &interactions)?;
+
+        build_response(status_code, &headers, body.to_vec())
+    }
+
+    async fn replay(
+        &self,
+        recorded_request: RecordedRequest,
+    ) -> CustomResult<reqwest::Response, ApiClientError> {
+        let request = strip_ignored_fields(recorded_request, &self.ignored_body_fields);
+        let mut interactions = self.interactions.lock().await;
+
+        // Interactions are served in the order they were recorded, so that identical requests,
+        // such as repeated syncs, receive the responses in the same order as while recording
+        let interaction = interactions
+            .iter_mut()
+            .find(|interaction| {
+                interaction.as_ref().is_some_and(|interaction| {
+                    strip_ignored_fields(interaction.request.clone(), &self.ignored_body_fields)
+                        == request
+                })
+            })
+            .and_then(Option::take)
+            .ok_or(ApiClientError::UnexpectedState)
+            .attach_printable_lazy(|| {
+                format!(
+                    "No recorded interaction in {} matches the request {} {}",
+                    self.fixture_path.display(),
+                    request.method,
+                    request.url
+                )
+            })?;
+
+        let body = match interaction.response.body {
+            RecordedBody::Json(value) => serde_json::to_vec(&value)
+                .change_context(ApiClientError::UnexpectedState)
+                .attach_printable("Failed to serialize the recorded response body")?,
+            RecordedBody::Text(text) => text.into_bytes(),
+        };
+
+        build_response(
+            interaction.response.status_code,
+            &interaction.response.headers,
+            body,
+        )
+    }
+}
+
+#[async_trait::async_trait]
+impl ApiClient for FixtureApiClient {
+    fn request(
+        &self,
+        method: Method,
+        url: String,
+    ) -> CustomResult<Box<dyn RequestBuilder>, ApiClientError> {
+        self.inner.request(method, url)
+    }
+
+    fn request_with_certificate(
+        &self,
+        method: Method,
+        url: String,
+        certificate: Option<masking::Secret<String>>,
+        certificate_key: Option<masking::Secret<String>>,
+    ) -> CustomResult<Box<dyn RequestBuilder>, ApiClientError> {
+        self.inner
+            .request_with_certificate(method, url, certificate, certificate_key)
+    }
+
+    async fn send_request(
+        &self,
+        state: &SessionState,
+        request: Request,
+        option_timeout_secs: Option<u64>,
+        forward_to_kafka: bool,
+    ) -> CustomResult<reqwest::Response, ApiClientError> {
+        let recorded_request = get_recorded_request(&request)?;
+        logger::debug!(mode = %self.mode, method = %recorded_request.method, url = %recorded_request.url);
+
+        match self.mode {
+            FixtureMode::Record => {
+                self.record(
+                    state,
+                    recorded_request,
+                    request,
+                    option_timeout_secs,
+                    forward_to_kafka,
+                )
+                .await
+            }
+            FixtureMode::Replay => self.replay(recorded_request).await,
+        }
+    }
+
+    fn add_request_id(&mut self, request_id: RequestId) {
+        self.inner.add_request_id(request_id)
+    }
+
+    fn get_request_id(&self) -> Option<String> {
+        self.inner.get_request_id()
+    }
+
+    fn add_flow_name(&mut self, flow_name: String) {
+        self.inner.add_flow_name(flow_name)
+    }
+}
+
+fn load_fixture(fixture_path: &Path) -> CustomResult<Vec<RecordedInteraction>, ApiClientError> {
+    let contents = std::fs::read(fixture_path)
+        .change_context(ApiClientError::UnexpectedState)
+        .attach_printable_lazy(|| {
+            format!("Failed to read the fixture {}", fixture_path.display())
+        })?;
+    serde_json::from_slice(&contents)
+        .change_context(ApiClientError::UnexpectedState)
+        .attach_printable_lazy(|| format!("Failed to parse the fixture {}", fixture_path.display()))
+}
+
+fn save_fixture(
+    fixture_path: &Path,
+    interactions: &[Option<RecordedInteraction>],
+) -> CustomResult<(), ApiClientError> {
+    let interactions = interactions.iter().flatten().collect::<Vec<_>>();
+    let contents = serde_json::to_vec_pretty(&interactions)
+        .change_context(ApiClientError::UnexpectedState)
+        .attach_printable("Failed to serialize the recorded interactions")?;
+
+    if let Some(parent) = fixture_path.parent() {
+        std::fs::create_dir_all(parent)
+            .change_context(ApiClientError::UnexpectedState)
+            .attach_printable("Failed to create the fixture directory")?;
+    }
+    std::fs::write(fixture_path, contents)
+        .change_context(ApiClientError::UnexpectedState)
+        .attach_printable_lazy(|| format!("Failed to write the fixture {}", fixture_path.display()))
+}
+
+/// Builds the representation of the request that is recorded and matched against, with the
+/// secrets in the body and the URL query masked. Headers are not recorded, since they carry the
+/// connector credentials.
+fn get_recorded_request(request: &Request) -> CustomResult<RecordedRequest, ApiClientError> {
+    let body = match request.body.as_ref() {
+        Some(
+            RequestContent::Json(body)
+            | RequestContent::FormUrlEncoded(body)
+            | RequestContent::Xml(body),
+        ) => Some(
+            body.masked_serialize()
+                .change_context(ApiClientError::BodySerializationFailed)?,
+        ),
+        Some(RequestContent::FormData(_)) => {
+            Some(serde_json::Value::String("<multipart form>".to_owned()))
+        }
+        Some(RequestContent::RawBytes(bytes)) => Some(serde_json::Value::String(format!(
+            "<{} bytes>",
+            bytes.len()
+        ))),
+        None => None,
+    };
+
+    Ok(RecordedRequest {
+        method: request.method.to_string(),
+        url: mask_url(&request.url),
+        body,
+    })
+}
+
+fn mask_url(url: &str) -> String {
+    match url::Url::parse(url) {
+        Ok(mut parsed) if parsed.query().is_some() => {
+            let query = parsed
+                .query_pairs()
+                .map(|(key, value)| {
+                    let value = if is_sensitive_field(&key) {
+                        MASKED_VALUE.into()
+                    } else {
+                        value
+                    };
+                    (key.into_owned(), value.into_owned())
+                })
+                .collect::<Vec<_>>();
+            parsed.query_pairs_mut().clear().extend_pairs(query);
+            parsed.to_string()
+        }
+        _ => url.to_owned(),
+    }
+}
+
+fn is_sensitive_field(field: &str) -> bool {
+    SENSITIVE_FIELDS
+        .iter()
+        .any(|sensitive_field| field.eq_ignore_ascii_case(sensitive_field))
+}
+
+fn mask_sensitive_fields(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                if is_sensitive_field(key) {
+                    *value = serde_json::Value::String(MASKED_VALUE.to_owned());
+                } else {
+                    mask_sensitive_fields(value);
+                }
+            }
+        }
+        serde_json::Value::Array(values) => values.iter_mut().for_each(mask_sensitive_fields),
+        _ => {}
+    }
+}
+
+fn strip_ignored_fields(
+    mut request: RecordedRequest,
+    ignored_fields: &[String],
+) -> RecordedRequest {
+    fn strip(value: &mut serde_json::Value, ignored_fields: &[String]) {
+        match value {
+            serde_json::Value::Object(map) => {
+                map.retain(|key, _| !ignored_fields.contains(key));
+                map.values_mut()
+                    .for_each(|value| strip(value, ignored_fields));
+            }
+            serde_json::Value::Array(values) => values
+                .iter_mut()
+                .for_each(|value| strip(value, ignored_fields)),
+            _ => {}
+        }
+    }
+
+    if let Some(body) = request.body.as_mut() {
+        strip(body, ignored_fields);
+    }
+    request
+}
+
+fn build_response(
+    status_code: u16,
+    headers: &BTreeMap<String, String>,
+    body: Vec<u8>,
+) -> CustomResult<reqwest::Response, ApiClientError> {
+    headers
+        .iter()
+        .fold(
+            http::Response::builder().status(status_code),
+            |builder, (name, value)| builder.header(name, value),
+        )
+        .body(body)
+        .map(reqwest::Response::from)
+        .change_context(ApiClientError::UnexpectedState)
+        .attach_printable("Failed to construct the response from the recorded interaction")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recorded_request_matching() {
+        let recorded = RecordedRequest {
+            method: "POST".to_owned(),
+            url: mask_url("https://sandbox.connector.com/v1/payments?api_key=sk_test_123&page=1"),
+            body: Some(serde_json::json!({"amount": 100, "timestamp": "2024-12-01T10:00:00Z"})),
+        };
+        let incoming = RecordedRequest {
+            body: Some(serde_json::json!({"amount": 100, "timestamp": "2024-12-09T08:30:00Z"})),
+            ..recorded.clone()
+        };
+
+        assert!(!recorded.url.contains("sk_test_123"));
+        assert!(recorded.url.contains("page=1"));
+        assert_ne!(recorded, incoming);
+
+        let ignored_fields = vec!["timestamp".to_owned()];
+        assert_eq!(
+            strip_ignored_fields(recorded, &ignored_fields),
+            strip_ignored_fields(incoming, &ignored_fields)
+        );
+    }
+
+    #[test]
+    fn test_response_masking() {
+        let mut body = serde_json::json!({
+            "access_token": "eyJhbGciOi",
+            "payments": [{"id": "pay_123", "client_secret": "pay_123_secret_456"}],
+        });
+        mask_sensitive_fields(&mut body);
+
+        assert_eq!(
+            body,
+            serde_json::json!({
+                "access_token": MASKED_VALUE,
+                "payments": [{"id": "pay_123", "client_secret": MASKED_VALUE}],
+            })
+        );
+    }
+}
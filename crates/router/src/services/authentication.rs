@@ -390,9 +390,13 @@ where
             .get_id_type_from_header::<id_type::ProfileId>(headers::X_PROFILE_ID)?;
 
         let api_key = api_keys::PlaintextApiKey::from(api_key);
-        let hash_key = {
+        let (hash_key, last_used_update_interval_in_secs) = {
             let config = state.conf();
-            config.api_keys.get_inner().get_hash_key()?
+            let api_keys_config = config.api_keys.get_inner();
+            (
+                api_keys_config.get_hash_key()?,
+                api_keys_config.last_used_update_interval_in_secs,
+            )
         };
         let hashed_api_key = api_key.keyed_hash(hash_key.peek());
 
@@ -414,6 +418,12 @@ where
                 .attach_printable("API key has expired");
         }
 
+        api_keys::update_api_key_last_used(
+            state.store(),
+            &stored_api_key,
+            last_used_update_interval_in_secs,
+        );
+
         let key_manager_state = &(&state.session_state()).into();
 
         let key_store = state
@@ -477,9 +487,13 @@ where
         }
 
         let api_key = api_keys::PlaintextApiKey::from(api_key);
-        let hash_key = {
+        let (hash_key, last_used_update_interval_in_secs) = {
             let config = state.conf();
-            config.api_keys.get_inner().get_hash_key()?
+            let api_keys_config = config.api_keys.get_inner();
+            (
+                api_keys_config.get_hash_key()?,
+                api_keys_config.last_used_update_interval_in_secs,
+            )
         };
         let hashed_api_key = api_key.keyed_hash(hash_key.peek());
 
@@ -501,6 +515,12 @@ where
                 .attach_printable("API key has expired");
         }
 
+        api_keys::update_api_key_last_used(
+            state.store(),
+            &stored_api_key,
+            last_used_update_interval_in_secs,
+        );
+
         let key_manager_state = &(&state.session_state()).into();
 
         let key_store = state
@@ -75,6 +75,7 @@ fn get_default_router_data<F, Req, Resp>(
         minor_amount_captured: None,
         integrity_check: Ok(()),
         additional_merchant_data: None,
+        merchant_connector_id: None,
         header_payload: None,
     }
 }
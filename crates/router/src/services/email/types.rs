@@ -57,6 +57,49 @@ pub enum EmailBody {
         api_key_name: String,
         prefix: String,
     },
+    DisputeDeadlineReminder {
+        dispute_id: String,
+        payment_id: String,
+        challenge_required_by: String,
+        hours_left: u16,
+    },
+    InvoiceReminder {
+        merchant_name: String,
+        invoice_id: String,
+        amount: String,
+        due_date: String,
+        payment_link: String,
+        is_overdue: bool,
+    },
+    WebhookEndpointSuspended {
+        endpoint_id: String,
+        url: String,
+        consecutive_failures: i32,
+    },
+    AnalyticsReport {
+        report_name: String,
+        period_start: String,
+        period_end: String,
+        report_table: String,
+    },
+    SuccessRateAlert {
+        alert_message: String,
+        profile_id: String,
+        metric: String,
+        segment: String,
+        success_rate: String,
+        baseline_success_rate: String,
+        payment_count: u64,
+        window_start: String,
+        window_end: String,
+    },
+    ExportCompleted {
+        export_id: String,
+        export_type: String,
+        format: String,
+        row_count: i32,
+        download_url: String,
+    },
 }
 
 pub mod html {
@@ -145,6 +188,98 @@ Email         : {user_email}
                 prefix = prefix,
                 expires_in = expires_in,
             ),
+            EmailBody::DisputeDeadlineReminder {
+                dispute_id,
+                payment_id,
+                challenge_required_by,
+                hours_left,
+            } => format!(
+                include_str!("assets/dispute_deadline_reminder.html"),
+                dispute_id = dispute_id,
+                payment_id = payment_id,
+                challenge_required_by = challenge_required_by,
+                hours_left = hours_left,
+            ),
+            EmailBody::InvoiceReminder {
+                merchant_name,
+                invoice_id,
+                amount,
+                due_date,
+                payment_link,
+                is_overdue,
+            } => {
+                let due_date_message = if is_overdue {
+                    format!("was due on <b>{due_date}</b> and is now overdue")
+                } else {
+                    format!("is due on <b>{due_date}</b>")
+                };
+                format!(
+                    include_str!("assets/invoice_reminder.html"),
+                    merchant_name = merchant_name,
+                    invoice_id = invoice_id,
+                    amount = amount,
+                    due_date_message = due_date_message,
+                    payment_link = payment_link,
+                )
+            }
+            EmailBody::WebhookEndpointSuspended {
+                endpoint_id,
+                url,
+                consecutive_failures,
+            } => format!(
+                include_str!("assets/webhook_endpoint_suspended.html"),
+                endpoint_id = endpoint_id,
+                url = url,
+                consecutive_failures = consecutive_failures,
+            ),
+            EmailBody::AnalyticsReport {
+                report_name,
+                period_start,
+                period_end,
+                report_table,
+            } => format!(
+                include_str!("assets/analytics_report.html"),
+                report_name = report_name,
+                period_start = period_start,
+                period_end = period_end,
+                report_table = report_table,
+            ),
+            EmailBody::SuccessRateAlert {
+                alert_message,
+                profile_id,
+                metric,
+                segment,
+                success_rate,
+                baseline_success_rate,
+                payment_count,
+                window_start,
+                window_end,
+            } => format!(
+                include_str!("assets/success_rate_alert.html"),
+                alert_message = alert_message,
+                profile_id = profile_id,
+                metric = metric,
+                segment = segment,
+                success_rate = success_rate,
+                baseline_success_rate = baseline_success_rate,
+                payment_count = payment_count,
+                window_start = window_start,
+                window_end = window_end,
+            ),
+            EmailBody::ExportCompleted {
+                export_id,
+                export_type,
+                format,
+                row_count,
+                download_url,
+            } => format!(
+                include_str!("assets/export_completed.html"),
+                export_id = export_id,
+                export_type = export_type,
+                format = format,
+                row_count = row_count,
+                download_url = download_url,
+            ),
         }
     }
 }
@@ -505,3 +640,205 @@ impl EmailData for ApiKeyExpiryReminder {
         })
     }
 }
+
+pub struct DisputeDeadlineReminder {
+    pub recipient_email: domain::UserEmail,
+    pub subject: &'static str,
+    pub dispute_id: String,
+    pub payment_id: common_utils::id_type::PaymentId,
+    pub challenge_required_by: time::PrimitiveDateTime,
+    pub hours_left: u16,
+}
+
+#[async_trait::async_trait]
+impl EmailData for DisputeDeadlineReminder {
+    async fn get_email_data(&self) -> CustomResult<EmailContents, EmailError> {
+        let recipient = self.recipient_email.clone().into_inner();
+
+        let body = html::get_html_body(EmailBody::DisputeDeadlineReminder {
+            dispute_id: self.dispute_id.clone(),
+            payment_id: self.payment_id.get_string_repr().to_owned(),
+            challenge_required_by: format!("{} UTC", self.challenge_required_by),
+            hours_left: self.hours_left,
+        });
+
+        Ok(EmailContents {
+            subject: self.subject.to_string(),
+            body: external_services::email::IntermediateString::new(body),
+            recipient,
+        })
+    }
+}
+
+pub struct InvoiceReminder {
+    pub recipient_email: domain::UserEmail,
+    pub subject: &'static str,
+    pub merchant_name: String,
+    pub invoice_id: String,
+    pub amount: String,
+    pub due_date: time::PrimitiveDateTime,
+    pub payment_link: String,
+    pub is_overdue: bool,
+}
+
+#[async_trait::async_trait]
+impl EmailData for InvoiceReminder {
+    async fn get_email_data(&self) -> CustomResult<EmailContents, EmailError> {
+        let recipient = self.recipient_email.clone().into_inner();
+
+        let body = html::get_html_body(EmailBody::InvoiceReminder {
+            merchant_name: self.merchant_name.clone(),
+            invoice_id: self.invoice_id.clone(),
+            amount: self.amount.clone(),
+            due_date: format!("{} UTC", self.due_date),
+            payment_link: self.payment_link.clone(),
+            is_overdue: self.is_overdue,
+        });
+
+        Ok(EmailContents {
+            subject: self.subject.to_string(),
+            body: external_services::email::IntermediateString::new(body),
+            recipient,
+        })
+    }
+}
+
+pub struct WebhookEndpointSuspended {
+    pub recipient_email: domain::UserEmail,
+    pub subject: &'static str,
+    pub endpoint_id: String,
+    pub url: String,
+    pub consecutive_failures: i32,
+}
+
+#[async_trait::async_trait]
+impl EmailData for WebhookEndpointSuspended {
+    async fn get_email_data(&self) -> CustomResult<EmailContents, EmailError> {
+        let recipient = self.recipient_email.clone().into_inner();
+
+        let body = html::get_html_body(EmailBody::WebhookEndpointSuspended {
+            endpoint_id: self.endpoint_id.clone(),
+            url: self.url.clone(),
+            consecutive_failures: self.consecutive_failures,
+        });
+
+        Ok(EmailContents {
+            subject: self.subject.to_string(),
+            body: external_services::email::IntermediateString::new(body),
+            recipient,
+        })
+    }
+}
+
+pub struct AnalyticsReport {
+    pub recipient_email: domain::UserEmail,
+    pub subject: &'static str,
+    pub report_name: String,
+    pub period_start: String,
+    pub period_end: String,
+    /// The report rendered as an HTML table, with its contents already escaped
+    pub report_table: String,
+}
+
+#[async_trait::async_trait]
+impl EmailData for AnalyticsReport {
+    async fn get_email_data(&self) -> CustomResult<EmailContents, EmailError> {
+        let recipient = self.recipient_email.clone().into_inner();
+
+        let body = html::get_html_body(EmailBody::AnalyticsReport {
+            report_name: self.report_name.clone(),
+            period_start: self.period_start.clone(),
+            period_end: self.period_end.clone(),
+            report_table: self.report_table.clone(),
+        });
+
+        Ok(EmailContents {
+            subject: self.subject.to_string(),
+            body: external_services::email::IntermediateString::new(body),
+            recipient,
+        })
+    }
+}
+
+pub struct SuccessRateAlert {
+    pub recipient_email: domain::UserEmail,
+    pub subject: &'static str,
+    pub alert: api_models::analytics::success_rate_alerts::SuccessRateAlert,
+}
+
+#[async_trait::async_trait]
+impl EmailData for SuccessRateAlert {
+    async fn get_email_data(&self) -> CustomResult<EmailContents, EmailError> {
+        use api_models::analytics::success_rate_alerts::SuccessRateAlertStatus;
+
+        let recipient = self.recipient_email.clone().into_inner();
+        let alert = &self.alert;
+        let alert_message = match alert.status {
+            SuccessRateAlertStatus::Triggered => "has dropped significantly below its baseline",
+            SuccessRateAlertStatus::Resolved => "has recovered and is back within its baseline",
+        };
+        let segment = [
+            ("Connector", &alert.segment.connector),
+            ("Payment method", &alert.segment.payment_method),
+            ("Payment method type", &alert.segment.payment_method_type),
+            ("Card network", &alert.segment.card_network),
+        ]
+        .into_iter()
+        .filter_map(|(name, value)| value.as_ref().map(|value| format!("{name}: {value}")))
+        .collect::<Vec<_>>();
+        let segment = if segment.is_empty() {
+            "all payments".to_owned()
+        } else {
+            segment.join(", ")
+        };
+
+        let body = html::get_html_body(EmailBody::SuccessRateAlert {
+            alert_message: alert_message.to_owned(),
+            profile_id: alert.profile_id.get_string_repr().to_owned(),
+            metric: alert.metric.to_string().replace('_', " "),
+            segment,
+            success_rate: format!("{:.2}%", alert.success_rate),
+            baseline_success_rate: format!("{:.2}%", alert.baseline_success_rate),
+            payment_count: alert.payment_count,
+            window_start: alert.window_start.to_string(),
+            window_end: alert.window_end.to_string(),
+        });
+
+        Ok(EmailContents {
+            subject: self.subject.to_string(),
+            body: external_services::email::IntermediateString::new(body),
+            recipient,
+        })
+    }
+}
+
+pub struct ExportCompleted {
+    pub recipient_email: domain::UserEmail,
+    pub subject: &'static str,
+    pub export_id: String,
+    pub export_type: String,
+    pub format: String,
+    pub row_count: i32,
+    pub download_url: String,
+}
+
+#[async_trait::async_trait]
+impl EmailData for ExportCompleted {
+    async fn get_email_data(&self) -> CustomResult<EmailContents, EmailError> {
+        let recipient = self.recipient_email.clone().into_inner();
+
+        let body = html::get_html_body(EmailBody::ExportCompleted {
+            export_id: self.export_id.clone(),
+            export_type: self.export_type.clone(),
+            format: self.format.clone(),
+            row_count: self.row_count,
+            download_url: self.download_url.clone(),
+        });
+
+        Ok(EmailContents {
+            subject: self.subject.to_string(),
+            body: external_services::email::IntermediateString::new(body),
+            recipient,
+        })
+    }
+}
@@ -161,6 +161,7 @@ pub struct KafkaSettings {
     payout_analytics_topic: String,
     consolidated_events_topic: String,
     authentication_analytics_topic: String,
+    success_rate_alerts_topic: String,
 }
 
 impl KafkaSettings {
@@ -248,6 +249,12 @@ impl KafkaSettings {
             },
         )?;
 
+        common_utils::fp_utils::when(self.success_rate_alerts_topic.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "Kafka Success Rate Alerts topic must not be empty".into(),
+            ))
+        })?;
+
         Ok(())
     }
 }
@@ -268,6 +275,7 @@ pub struct KafkaProducer {
     payout_analytics_topic: String,
     consolidated_events_topic: String,
     authentication_analytics_topic: String,
+    success_rate_alerts_topic: String,
     ckh_database_name: Option<String>,
 }
 
@@ -317,6 +325,7 @@ impl KafkaProducer {
             payout_analytics_topic: conf.payout_analytics_topic.clone(),
             consolidated_events_topic: conf.consolidated_events_topic.clone(),
             authentication_analytics_topic: conf.authentication_analytics_topic.clone(),
+            success_rate_alerts_topic: conf.success_rate_alerts_topic.clone(),
             ckh_database_name: None,
         })
     }
@@ -653,6 +662,7 @@ impl KafkaProducer {
             EventType::Payout => &self.payout_analytics_topic,
             EventType::Consolidated => &self.consolidated_events_topic,
             EventType::Authentication => &self.authentication_analytics_topic,
+            EventType::SuccessRateAlert => &self.success_rate_alerts_topic,
         }
     }
 }
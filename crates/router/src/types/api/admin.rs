@@ -8,8 +8,9 @@ pub use api_models::{
         MerchantAccountUpdate, MerchantConnectorCreate, MerchantConnectorDeleteResponse,
         MerchantConnectorDetails, MerchantConnectorDetailsWrap, MerchantConnectorId,
         MerchantConnectorResponse, MerchantDetails, MerchantId, PaymentMethodsEnabled,
-        ProfileCreate, ProfileResponse, ProfileUpdate, ToggleAllKVRequest, ToggleAllKVResponse,
-        ToggleKVRequest, ToggleKVResponse, WebhookDetails,
+        ProfileCreate, ProfileResponse, ProfileUpdate, StorageSchemeMigrationRequest,
+        StorageSchemeMigrationResponse, ToggleAllKVRequest, ToggleAllKVResponse, ToggleKVRequest,
+        ToggleKVResponse, WebhookDetails,
     },
     organization::{
         OrganizationCreateRequest, OrganizationId, OrganizationResponse, OrganizationUpdateRequest,
@@ -362,5 +363,7 @@ pub async fn create_profile_from_merchant_account(
         is_network_tokenization_enabled: request.is_network_tokenization_enabled,
         is_auto_retries_enabled: request.is_auto_retries_enabled.unwrap_or_default(),
         max_auto_retries_enabled: request.max_auto_retries_enabled.map(i16::from),
+        previous_payment_response_hash_key: None,
+        previous_payment_response_hash_key_expires_at: None,
     }))
 }
@@ -1,6 +1,7 @@
 pub use api_models::refunds::{
-    RefundRequest, RefundResponse, RefundStatus, RefundType, RefundUpdateRequest,
-    RefundsRetrieveRequest,
+    RefundBatchCreateRequest, RefundBatchId, RefundBatchRecord, RefundBatchResponse,
+    RefundBatchRowResult, RefundRequest, RefundResponse, RefundStatus, RefundType,
+    RefundUpdateRequest, RefundsRetrieveRequest,
 };
 pub use hyperswitch_domain_models::router_flow_types::refunds::{Execute, RSync};
 pub use hyperswitch_interfaces::api::refunds::{Refund, RefundExecute, RefundSync};
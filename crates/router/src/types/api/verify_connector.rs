@@ -55,6 +55,8 @@ impl VerifyConnectorData {
             authentication_data: None,
             customer_acceptance: None,
             charges: None,
+            split_payments: None,
+            installment_plan: None,
             merchant_order_reference_id: None,
             integrity_object: None,
             shipping_cost: None,
@@ -115,6 +117,7 @@ impl VerifyConnectorData {
             connector_response: None,
             integrity_check: Ok(()),
             additional_merchant_data: None,
+            merchant_connector_id: None,
             header_payload: None,
         }
     }
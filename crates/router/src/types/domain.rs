@@ -21,6 +21,7 @@ pub use merchant_account::*;
 
 mod address;
 mod event;
+mod incoming_webhook_archive;
 mod merchant_connector_account;
 mod merchant_key_store {
     pub use hyperswitch_domain_models::merchant_key_store::MerchantKeyStore;
@@ -41,6 +42,7 @@ pub use address::*;
 pub use business_profile::*;
 pub use consts::*;
 pub use event::*;
+pub use incoming_webhook_archive::*;
 pub use merchant_connector_account::*;
 pub use merchant_key_store::*;
 pub use payment_methods::*;
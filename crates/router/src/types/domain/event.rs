@@ -34,6 +34,9 @@ pub struct Event {
     pub response: OptionalEncryptableSecretString,
     pub delivery_attempt: Option<WebhookDeliveryAttempt>,
     pub metadata: Option<EventMetadata>,
+    pub webhook_endpoint_id: Option<String>,
+    pub delivery_latency_ms: Option<i32>,
+    pub sequence_number: Option<i64>,
 }
 
 #[derive(Debug)]
@@ -41,6 +44,7 @@ pub enum EventUpdate {
     UpdateResponse {
         is_webhook_notified: bool,
         response: OptionalEncryptableSecretString,
+        delivery_latency_ms: Option<i32>,
     },
 }
 
@@ -50,9 +54,11 @@ impl From<EventUpdate> for EventUpdateInternal {
             EventUpdate::UpdateResponse {
                 is_webhook_notified,
                 response,
+                delivery_latency_ms,
             } => Self {
                 is_webhook_notified: Some(is_webhook_notified),
                 response: response.map(Into::into),
+                delivery_latency_ms,
             },
         }
     }
@@ -81,6 +87,9 @@ impl super::behaviour::Conversion for Event {
             response: self.response.map(Into::into),
             delivery_attempt: self.delivery_attempt,
             metadata: self.metadata,
+            webhook_endpoint_id: self.webhook_endpoint_id,
+            delivery_latency_ms: self.delivery_latency_ms,
+            sequence_number: self.sequence_number,
         })
     }
 
@@ -132,6 +141,9 @@ impl super::behaviour::Conversion for Event {
             response: encryptable_event.response,
             delivery_attempt: item.delivery_attempt,
             metadata: item.metadata,
+            webhook_endpoint_id: item.webhook_endpoint_id,
+            delivery_latency_ms: item.delivery_latency_ms,
+            sequence_number: item.sequence_number,
         })
     }
 
@@ -153,6 +165,9 @@ impl super::behaviour::Conversion for Event {
             response: self.response.map(Into::into),
             delivery_attempt: self.delivery_attempt,
             metadata: self.metadata,
+            webhook_endpoint_id: self.webhook_endpoint_id,
+            delivery_latency_ms: self.delivery_latency_ms,
+            sequence_number: self.sequence_number,
         })
     }
 }
@@ -0,0 +1,124 @@
+use common_utils::{
+    crypto::Encryptable,
+    type_name,
+    types::keymanager::{Identifier, KeyManagerState},
+};
+use diesel_models::enums::IncomingWebhookProcessingStatus;
+use error_stack::ResultExt;
+use hyperswitch_domain_models::type_encryption::{crypto_operation, CryptoOperation};
+use masking::{PeekInterface, Secret};
+use time::PrimitiveDateTime;
+
+use crate::errors::{CustomResult, ValidationError};
+
+#[derive(Clone, Debug)]
+pub struct IncomingWebhookArchive {
+    pub webhook_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub connector_id_or_name: String,
+    pub connector_name: Option<String>,
+    pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+    pub request: Encryptable<Secret<String>>,
+    pub event_type: Option<String>,
+    pub source_verified: Option<bool>,
+    pub processing_status: IncomingWebhookProcessingStatus,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub reprocess_count: i32,
+    pub last_reprocessed_at: Option<PrimitiveDateTime>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[async_trait::async_trait]
+impl super::behaviour::Conversion for IncomingWebhookArchive {
+    type DstType = diesel_models::incoming_webhook_archive::IncomingWebhookArchive;
+    type NewDstType = diesel_models::incoming_webhook_archive::IncomingWebhookArchiveNew;
+
+    async fn convert(self) -> CustomResult<Self::DstType, ValidationError> {
+        Ok(
+            diesel_models::incoming_webhook_archive::IncomingWebhookArchive {
+                webhook_id: self.webhook_id,
+                merchant_id: self.merchant_id,
+                connector_id_or_name: self.connector_id_or_name,
+                connector_name: self.connector_name,
+                merchant_connector_id: self.merchant_connector_id,
+                profile_id: self.profile_id,
+                request: self.request.into(),
+                event_type: self.event_type,
+                source_verified: self.source_verified,
+                processing_status: self.processing_status,
+                error_code: self.error_code,
+                error_message: self.error_message,
+                reprocess_count: self.reprocess_count,
+                last_reprocessed_at: self.last_reprocessed_at,
+                created_at: self.created_at,
+                modified_at: self.modified_at,
+            },
+        )
+    }
+
+    async fn convert_back(
+        state: &KeyManagerState,
+        item: Self::DstType,
+        key: &Secret<Vec<u8>>,
+        key_manager_identifier: Identifier,
+    ) -> CustomResult<Self, ValidationError>
+    where
+        Self: Sized,
+    {
+        Ok(Self {
+            request: crypto_operation(
+                state,
+                type_name!(Self::DstType),
+                CryptoOperation::Decrypt(item.request),
+                key_manager_identifier,
+                key.peek(),
+            )
+            .await
+            .and_then(|val| val.try_into_operation())
+            .change_context(ValidationError::InvalidValue {
+                message: "Failed while decrypting incoming webhook data".to_string(),
+            })?,
+            webhook_id: item.webhook_id,
+            merchant_id: item.merchant_id,
+            connector_id_or_name: item.connector_id_or_name,
+            connector_name: item.connector_name,
+            merchant_connector_id: item.merchant_connector_id,
+            profile_id: item.profile_id,
+            event_type: item.event_type,
+            source_verified: item.source_verified,
+            processing_status: item.processing_status,
+            error_code: item.error_code,
+            error_message: item.error_message,
+            reprocess_count: item.reprocess_count,
+            last_reprocessed_at: item.last_reprocessed_at,
+            created_at: item.created_at,
+            modified_at: item.modified_at,
+        })
+    }
+
+    async fn construct_new(self) -> CustomResult<Self::NewDstType, ValidationError> {
+        Ok(
+            diesel_models::incoming_webhook_archive::IncomingWebhookArchiveNew {
+                webhook_id: self.webhook_id,
+                merchant_id: self.merchant_id,
+                connector_id_or_name: self.connector_id_or_name,
+                connector_name: self.connector_name,
+                merchant_connector_id: self.merchant_connector_id,
+                profile_id: self.profile_id,
+                request: self.request.into(),
+                event_type: self.event_type,
+                source_verified: self.source_verified,
+                processing_status: self.processing_status,
+                error_code: self.error_code,
+                error_message: self.error_message,
+                reprocess_count: self.reprocess_count,
+                last_reprocessed_at: self.last_reprocessed_at,
+                created_at: self.created_at,
+                modified_at: self.modified_at,
+            },
+        )
+    }
+}
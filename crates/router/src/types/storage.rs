@@ -1,4 +1,5 @@
 pub mod address;
+pub mod analytics_report_schedule;
 pub mod api_keys;
 pub mod authentication;
 pub mod authorization;
@@ -7,6 +8,7 @@ pub mod blocklist_fingerprint;
 pub mod blocklist_lookup;
 pub mod business_profile;
 pub mod capture;
+pub mod card_account_update;
 pub mod cards_info;
 pub mod configs;
 pub mod customers;
@@ -15,10 +17,13 @@ pub mod dispute;
 pub mod enums;
 pub mod ephemeral_key;
 pub mod events;
+pub mod export_job;
 pub mod file;
 pub mod fraud_check;
 pub mod generic_link;
 pub mod gsm;
+pub mod incoming_webhook_archive;
+pub mod invoice;
 #[cfg(feature = "kv_store")]
 pub mod kv;
 pub mod locker_mock_up;
@@ -29,16 +34,23 @@ pub mod merchant_key_store;
 pub mod payment_attempt;
 pub mod payment_link;
 pub mod payment_method;
+pub mod payment_recovery;
+pub mod payment_split;
 pub mod payout_attempt;
+pub mod payout_batch;
 pub mod payouts;
 pub mod refund;
+pub mod refund_batch;
+pub mod reusable_payment_link;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod subscription;
 pub mod unified_translations;
 pub mod user;
 pub mod user_authentication_method;
 pub mod user_role;
+pub mod webhook_endpoint;
 
 use std::collections::HashMap;
 
@@ -61,14 +73,16 @@ pub use hyperswitch_domain_models::payouts::{
 pub use scheduler::db::process_tracker;
 
 pub use self::{
-    address::*, api_keys::*, authentication::*, authorization::*, blocklist::*,
-    blocklist_fingerprint::*, blocklist_lookup::*, business_profile::*, capture::*, cards_info::*,
-    configs::*, customers::*, dashboard_metadata::*, dispute::*, ephemeral_key::*, events::*,
-    file::*, fraud_check::*, generic_link::*, gsm::*, locker_mock_up::*, mandate::*,
-    merchant_account::*, merchant_connector_account::*, merchant_key_store::*, payment_link::*,
-    payment_method::*, process_tracker::*, refund::*, reverse_lookup::*, role::*,
-    routing_algorithm::*, unified_translations::*, user::*, user_authentication_method::*,
-    user_role::*,
+    address::*, analytics_report_schedule::*, api_keys::*, authentication::*, authorization::*,
+    blocklist::*, blocklist_fingerprint::*, blocklist_lookup::*, business_profile::*, capture::*,
+    card_account_update::*, cards_info::*, configs::*, customers::*, dashboard_metadata::*,
+    dispute::*, ephemeral_key::*, events::*, export_job::*, file::*, fraud_check::*,
+    generic_link::*, gsm::*, incoming_webhook_archive::*, invoice::*, locker_mock_up::*,
+    mandate::*, merchant_account::*, merchant_connector_account::*, merchant_key_store::*,
+    payment_link::*, payment_method::*, payment_recovery::*, payment_split::*, payout_batch::*,
+    process_tracker::*, refund::*, refund_batch::*, reusable_payment_link::*, reverse_lookup::*,
+    role::*, routing_algorithm::*, subscription::*, unified_translations::*, user::*,
+    user_authentication_method::*, user_role::*, webhook_endpoint::*,
 };
 use crate::types::api::routing;
 
@@ -0,0 +1,4 @@
+pub use diesel_models::analytics_report_schedule::{
+    AnalyticsReportSchedule, AnalyticsReportScheduleNew, AnalyticsReportScheduleUpdate,
+    AnalyticsReportTrackingData,
+};
@@ -1 +1,3 @@
-pub use diesel_models::business_profile::{Profile, ProfileNew, ProfileUpdateInternal};
+pub use diesel_models::business_profile::{
+    Profile, ProfileNew, ProfileUpdateInternal, SuccessRateAlertTrackingData,
+};
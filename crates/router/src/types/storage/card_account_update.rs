@@ -0,0 +1,3 @@
+pub use diesel_models::card_account_update::{
+    AccountUpdaterTrackingData, CardAccountUpdate, CardAccountUpdateNew,
+};
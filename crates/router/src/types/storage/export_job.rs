@@ -0,0 +1,3 @@
+pub use diesel_models::export_job::{
+    ExportJob, ExportJobNew, ExportJobTrackingData, ExportJobUpdate,
+};
@@ -217,6 +217,7 @@ mod tests {
             profile_id: common_utils::generate_profile_id_of_default_length(),
             organization_id: Default::default(),
             connector_mandate_detail: Default::default(),
+            installment_plan: Default::default(),
         };
 
         let store = state
@@ -301,6 +302,7 @@ mod tests {
             profile_id: common_utils::generate_profile_id_of_default_length(),
             organization_id: Default::default(),
             connector_mandate_detail: Default::default(),
+            installment_plan: Default::default(),
         };
         let store = state
             .stores
@@ -398,6 +400,7 @@ mod tests {
             profile_id: common_utils::generate_profile_id_of_default_length(),
             organization_id: Default::default(),
             connector_mandate_detail: Default::default(),
+            installment_plan: Default::default(),
         };
         let store = state
             .stores
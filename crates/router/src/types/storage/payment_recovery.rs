@@ -0,0 +1,3 @@
+pub use diesel_models::payment_recovery::{
+    PaymentRecovery, PaymentRecoveryNew, PaymentRecoveryTrackingData, PaymentRecoveryUpdate,
+};
@@ -0,0 +1 @@
+pub use diesel_models::payment_split::{PaymentSplit, PaymentSplitNew, PaymentSplitUpdate};
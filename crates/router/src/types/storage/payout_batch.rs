@@ -0,0 +1,3 @@
+pub use diesel_models::payout_batch::{
+    PayoutBatch, PayoutBatchNew, PayoutBatchTrackingData, PayoutBatchUpdate,
+};
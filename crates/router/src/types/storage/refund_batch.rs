@@ -0,0 +1,3 @@
+pub use diesel_models::refund_batch::{
+    RefundBatch, RefundBatchNew, RefundBatchTrackingData, RefundBatchUpdate,
+};
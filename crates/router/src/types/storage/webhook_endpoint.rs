@@ -0,0 +1,3 @@
+pub use diesel_models::webhook_endpoint::{
+    WebhookEndpoint, WebhookEndpointNew, WebhookEndpointUpdate,
+};
@@ -847,6 +847,7 @@ impl
             api_key: StrongSecret::from(plaintext_api_key.peek().to_owned()),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            last_used: api_key.last_used,
         }
     }
 }
@@ -861,6 +862,7 @@ impl ForeignFrom<diesel_models::api_keys::ApiKey> for api_models::api_keys::Retr
             prefix: api_key.prefix.into(),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            last_used: api_key.last_used,
         }
     }
 }
@@ -1870,6 +1872,7 @@ impl TryFrom<domain::Event> for api_models::webhook_events::EventListItemRespons
             event_class: item.event_class,
             is_delivery_successful: item.is_webhook_notified,
             initial_attempt_id,
+            webhook_endpoint_id: item.webhook_endpoint_id,
             created: item.created_at,
         })
     }
@@ -1914,6 +1917,52 @@ impl TryFrom<domain::Event> for api_models::webhook_events::EventRetrieveRespons
     }
 }
 
+#[cfg(feature = "olap")]
+impl From<domain::IncomingWebhookArchive>
+    for api_models::incoming_webhook_archive::IncomingWebhookListItemResponse
+{
+    fn from(item: domain::IncomingWebhookArchive) -> Self {
+        Self {
+            webhook_id: item.webhook_id,
+            merchant_id: item.merchant_id,
+            connector_id_or_name: item.connector_id_or_name,
+            connector_name: item.connector_name,
+            merchant_connector_id: item.merchant_connector_id,
+            profile_id: item.profile_id,
+            event_type: item.event_type,
+            source_verified: item.source_verified,
+            processing_status: item.processing_status,
+            error_code: item.error_code,
+            error_message: item.error_message,
+            reprocess_count: item.reprocess_count,
+            last_reprocessed_at: item.last_reprocessed_at,
+            created: item.created_at,
+        }
+    }
+}
+
+#[cfg(feature = "olap")]
+impl TryFrom<domain::IncomingWebhookArchive>
+    for api_models::incoming_webhook_archive::IncomingWebhookRetrieveResponse
+{
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+
+    fn try_from(item: domain::IncomingWebhookArchive) -> Result<Self, Self::Error> {
+        let request = item
+            .request
+            .get_inner()
+            .peek()
+            .parse_struct("IncomingWebhookRequestContent")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse incoming webhook request information")?;
+
+        Ok(Self {
+            webhook_information: item.into(),
+            request,
+        })
+    }
+}
+
 impl ForeignFrom<api_models::admin::AuthenticationConnectorDetails>
     for diesel_models::business_profile::AuthenticationConnectorDetails
 {
@@ -2080,3 +2129,15 @@ impl ForeignFrom<diesel_models::business_profile::BusinessGenericLinkConfig>
         }
     }
 }
+
+impl ForeignFrom<diesel_models::InstallmentPlanDetails> for payments::InstallmentPlanDetails {
+    fn foreign_from(item: diesel_models::InstallmentPlanDetails) -> Self {
+        Self {
+            plan_id: item.plan_id,
+            number_of_installments: item.number_of_installments,
+            installment_amount: item.installment_amount,
+            total_amount: item.total_amount,
+            fee_amount: item.fee_amount,
+        }
+    }
+}
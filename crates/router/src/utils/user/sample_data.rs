@@ -359,6 +359,7 @@ pub async fn generate_sample_data(
             order_tax_amount: None,
             connector_transaction_data,
             connector_mandate_detail: None,
+            installment_plan: None,
         };
 
         let refund = if refunds_count < number_of_refunds && !is_failed_payment {
@@ -1,13 +1,37 @@
+#[cfg(feature = "v1")]
+pub mod account_updater;
+#[cfg(all(feature = "v1", feature = "olap"))]
+pub mod analytics_report;
 #[cfg(feature = "email")]
 pub mod api_key_expiry;
+pub mod api_key_rotation;
 #[cfg(feature = "payouts")]
 pub mod attach_payout_account_workflow;
 #[cfg(feature = "v1")]
+pub mod dispute_deadline;
+#[cfg(all(feature = "v1", feature = "olap"))]
+pub mod export_job;
+#[cfg(feature = "v1")]
+pub mod invoice_reminder;
+#[cfg(feature = "v1")]
 pub mod outgoing_webhook_retry;
 #[cfg(feature = "v1")]
 pub mod payment_method_status_update;
+#[cfg(feature = "v1")]
+pub mod payment_recovery;
 pub mod payment_sync;
+#[cfg(all(feature = "payouts", feature = "v1"))]
+pub mod payout_batch;
+#[cfg(feature = "v1")]
+pub mod refund_batch;
 #[cfg(feature = "v1")]
 pub mod refund_router;
+#[cfg(all(feature = "v1", feature = "kv_store"))]
+pub mod storage_scheme_migration;
+#[cfg(feature = "v1")]
+pub mod subscription_billing;
+#[cfg(all(feature = "v1", feature = "olap"))]
+pub mod success_rate_alert;
 #[cfg(feature = "v1")]
 pub mod tokenized_data;
+pub mod utils;
@@ -0,0 +1,128 @@
+use common_utils::{date_time, ext_traits::ValueExt};
+use diesel_models::enums as storage_enums;
+use router_env::{logger, metrics::add_attributes};
+use scheduler::workflows::ProcessTrackerWorkflow;
+
+use crate::{
+    core::payment_methods::account_updater,
+    errors,
+    routes::{metrics, SessionState},
+    types::storage,
+    workflows::utils as workflow_utils,
+};
+
+pub struct AccountUpdaterWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for AccountUpdaterWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let tracking_data: storage::AccountUpdaterTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("AccountUpdaterTrackingData")?;
+
+        let now = date_time::now();
+        let run_interval =
+            time::Duration::hours(i64::from(state.conf.account_updater.run_interval_in_hours));
+
+        let (customer_offset, schedule_time) = match state.account_updater_client.as_ref() {
+            Some(account_updater_client) => {
+                let db = &*state.store;
+                let key_manager_state = &state.into();
+                let key_store = db
+                    .get_merchant_key_store_by_merchant_id(
+                        key_manager_state,
+                        &tracking_data.merchant_id,
+                        &db.get_master_key().to_vec().into(),
+                    )
+                    .await?;
+                let merchant_account = db
+                    .find_merchant_account_by_merchant_id(
+                        key_manager_state,
+                        &tracking_data.merchant_id,
+                        &key_store,
+                    )
+                    .await?;
+
+                let customers_checked = account_updater::update_saved_cards(
+                    state,
+                    &merchant_account,
+                    &key_store,
+                    account_updater_client.as_ref(),
+                    tracking_data.customer_offset,
+                )
+                .await?;
+
+                // The next page of customers is picked up right away, the cycle starts over once
+                // all the customers of the merchant have been checked
+                if customers_checked >= usize::from(state.conf.account_updater.customers_per_run) {
+                    let customers_checked = u32::try_from(customers_checked)
+                        .map_err(|_| errors::ProcessTrackerError::TypeConversionError)?;
+                    (
+                        tracking_data
+                            .customer_offset
+                            .saturating_add(customers_checked),
+                        now,
+                    )
+                } else {
+                    (0, now.saturating_add(run_interval))
+                }
+            }
+            None => {
+                logger::warn!("Account updater source is not configured, skipping run");
+                (0, now.saturating_add(run_interval))
+            }
+        };
+
+        let tracking_data = storage::AccountUpdaterTrackingData {
+            customer_offset,
+            ..tracking_data
+        };
+        let tracking_data = serde_json::to_value(tracking_data)
+            .map_err(|_| errors::ProcessTrackerError::SerializationFailed)?;
+        state
+            .store
+            .as_scheduler()
+            .update_process(
+                process,
+                storage::ProcessTrackerUpdate::Update {
+                    name: None,
+                    retry_count: Some(0),
+                    schedule_time: Some(schedule_time),
+                    tracking_data: Some(tracking_data),
+                    business_status: None,
+                    status: Some(storage_enums::ProcessTrackerStatus::New),
+                    updated_at: Some(date_time::now()),
+                },
+            )
+            .await?;
+        metrics::TASKS_RESET_COUNT.add(
+            &metrics::CONTEXT,
+            1,
+            &add_attributes([("flow", "AccountUpdater")]),
+        );
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        let merchant_id = process
+            .tracking_data
+            .clone()
+            .parse_value::<storage::AccountUpdaterTrackingData>("AccountUpdaterTrackingData")
+            .ok()
+            .map(|tracking_data| tracking_data.merchant_id);
+
+        workflow_utils::retry_or_handle_process_error(state, process, merchant_id.as_ref(), error)
+            .await
+    }
+}
@@ -0,0 +1,117 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::{enums::ReportScheduleStatus, process_tracker::business_status};
+use router_env::logger;
+use scheduler::{consumer, workflows::ProcessTrackerWorkflow};
+
+use crate::{
+    core::analytics_reports, errors, routes::SessionState, types::storage,
+    workflows::utils as workflow_utils,
+};
+
+pub struct AnalyticsReportWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for AnalyticsReportWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let tracking_data: storage::AnalyticsReportTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("AnalyticsReportTrackingData")?;
+
+        let db = &*state.store;
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+        let schedule = match db
+            .find_analytics_report_schedule_by_merchant_id_report_schedule_id(
+                &tracking_data.merchant_id,
+                &tracking_data.report_schedule_id,
+            )
+            .await
+        {
+            Ok(schedule) => Some(schedule),
+            Err(error) if error.current_context().is_db_not_found() => None,
+            Err(error) => Err(error)?,
+        };
+
+        // Reports which have been deleted or paused are not generated anymore
+        let Some(schedule) =
+            schedule.filter(|schedule| schedule.status == ReportScheduleStatus::Active)
+        else {
+            return db
+                .as_scheduler()
+                .finish_process_with_business_status(process, business_status::REVOKED)
+                .await
+                .map_err(Into::into);
+        };
+
+        analytics_reports::generate_and_deliver_report(state, &merchant_account, &schedule).await?;
+        analytics_reports::reschedule_report_task(state, process, &schedule).await
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        let tracking_data: storage::AnalyticsReportTrackingData = match process
+            .tracking_data
+            .clone()
+            .parse_value("AnalyticsReportTrackingData")
+        {
+            Ok(tracking_data) => tracking_data,
+            Err(_) => {
+                return consumer::consumer_error_handler(state.store.as_scheduler(), process, error)
+                    .await
+            }
+        };
+
+        if let Some(schedule_time) =
+            workflow_utils::get_retry_schedule_time(&process, &tracking_data.merchant_id)
+        {
+            logger::error!(%process.id, ?error, "Failed to generate analytics report, retrying");
+            return state
+                .get_db()
+                .as_scheduler()
+                .retry_process(process, schedule_time)
+                .await
+                .map_err(Into::into);
+        }
+
+        // The run is skipped once the retries are exhausted, so that the following runs of the
+        // report are still generated
+        logger::error!(%process.id, ?error, "Failed to generate analytics report, skipping run");
+        match state
+            .store
+            .find_analytics_report_schedule_by_merchant_id_report_schedule_id(
+                &tracking_data.merchant_id,
+                &tracking_data.report_schedule_id,
+            )
+            .await
+        {
+            Ok(schedule) => analytics_reports::reschedule_report_task(state, process, &schedule)
+                .await
+                .map_err(|error| error_stack::report!(error)),
+            Err(_) => {
+                consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+            }
+        }
+    }
+}
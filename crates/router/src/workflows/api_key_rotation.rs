@@ -0,0 +1,71 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::{process_tracker::business_status, ApiKeyRotationTrackingData};
+use scheduler::workflows::ProcessTrackerWorkflow;
+
+use crate::{
+    core::api_keys, errors, routes::SessionState, types::storage,
+    workflows::utils as workflow_utils,
+};
+
+pub struct ApiKeyRotationWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for ApiKeyRotationWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: ApiKeyRotationTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("ApiKeyRotationTrackingData")?;
+
+        let rotated_api_key = db
+            .find_api_key_by_merchant_id_key_id_optional(
+                &tracking_data.merchant_id,
+                &tracking_data.key_id,
+            )
+            .await?;
+
+        // The rotated key may have been revoked manually during its grace period, in which case
+        // there is nothing left to do
+        let business_status = if rotated_api_key.is_some() {
+            api_keys::revoke_api_key(
+                state.clone(),
+                &tracking_data.merchant_id,
+                &tracking_data.key_id,
+            )
+            .await?;
+            business_status::COMPLETED_BY_PT
+        } else {
+            business_status::REVOKED
+        };
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, business_status)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        let merchant_id = process
+            .tracking_data
+            .clone()
+            .parse_value::<ApiKeyRotationTrackingData>("ApiKeyRotationTrackingData")
+            .ok()
+            .map(|tracking_data| tracking_data.merchant_id);
+
+        // Revoking the rotated key is idempotent, so transient failures are retried instead of
+        // leaving the rotated key usable past its grace period
+        workflow_utils::retry_or_handle_process_error(state, process, merchant_id.as_ref(), error)
+            .await
+    }
+}
@@ -0,0 +1,210 @@
+use common_utils::{date_time, ext_traits::ValueExt};
+use diesel_models::{
+    enums::{self as storage_enums, DisputeStatus},
+    process_tracker::business_status,
+};
+use router_env::{logger, metrics::add_attributes};
+use scheduler::workflows::ProcessTrackerWorkflow;
+
+use crate::{
+    core::disputes::{
+        self,
+        deadlines::{self, DisputeDeadlineAction},
+    },
+    errors,
+    routes::{metrics, SessionState},
+    types::{storage, transformers::ForeignFrom},
+    workflows::utils as workflow_utils,
+};
+
+pub struct DisputeDeadlineWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for DisputeDeadlineWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let tracking_data: storage::DisputeDeadlineTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("DisputeDeadlineTrackingData")?;
+
+        let db = &*state.store;
+        let dispute = db
+            .find_dispute_by_merchant_id_dispute_id(
+                &tracking_data.merchant_id,
+                &tracking_data.dispute_id,
+            )
+            .await?;
+
+        // Disputes which have been responded to, or whose deadline has passed, are not tracked
+        // anymore
+        let now = date_time::now();
+        let challenge_required_by = match dispute.challenge_required_by {
+            Some(challenge_required_by)
+                if dispute.dispute_status == DisputeStatus::DisputeOpened
+                    && challenge_required_by > now =>
+            {
+                challenge_required_by
+            }
+            _ => {
+                return db
+                    .as_scheduler()
+                    .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                    .await
+                    .map_err(Into::into);
+            }
+        };
+
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        let config = deadlines::get_dispute_deadline_config(db, &tracking_data.merchant_id).await;
+        let actions = config.get_actions(challenge_required_by);
+        let due_actions = actions
+            .iter()
+            .filter(|(due_at, _)| *due_at <= now)
+            .map(|(_, action)| *action)
+            .collect::<Vec<_>>();
+
+        if let Some(auto_accept) = config
+            .auto_accept
+            .as_ref()
+            .filter(|_| due_actions.contains(&DisputeDeadlineAction::AutoAccept))
+        {
+            if deadlines::is_eligible_for_auto_accept(auto_accept, &dispute)? {
+                let dispute = disputes::accept_dispute_with_connector(
+                    state,
+                    &merchant_account,
+                    &key_store,
+                    dispute,
+                )
+                .await?;
+                logger::info!(
+                    dispute_id = %dispute.dispute_id,
+                    dispute_status = %dispute.dispute_status,
+                    "Accepted dispute automatically"
+                );
+                metrics::DISPUTE_AUTO_ACCEPTED_COUNT.add(
+                    &metrics::CONTEXT,
+                    1,
+                    &add_attributes([("connector", dispute.connector.clone())]),
+                );
+                deadlines::trigger_dispute_webhook(
+                    state,
+                    &merchant_account,
+                    &key_store,
+                    &dispute,
+                    storage_enums::EventType::foreign_from(dispute.dispute_status),
+                    dispute.dispute_id.clone(),
+                )
+                .await?;
+
+                return db
+                    .as_scheduler()
+                    .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                    .await
+                    .map_err(Into::into);
+            }
+        }
+
+        // Only the latest of the reminders which are due is sent, so that a merchant is not sent
+        // several reminders at once for a dispute which was opened close to its deadline
+        let last_reminder_hours = tracking_data
+            .last_reminder_hours
+            .filter(|_| tracking_data.challenge_required_by == challenge_required_by);
+        let due_reminder_hours = due_actions.iter().rev().find_map(|action| match action {
+            DisputeDeadlineAction::Reminder { hours_left } => Some(*hours_left),
+            DisputeDeadlineAction::AutoAccept => None,
+        });
+        let last_reminder_hours = match due_reminder_hours {
+            Some(hours_left) if last_reminder_hours != Some(hours_left) => {
+                deadlines::send_dispute_deadline_reminder(
+                    state,
+                    &merchant_account,
+                    &key_store,
+                    &dispute,
+                    hours_left,
+                )
+                .await?;
+                Some(hours_left)
+            }
+            _ => last_reminder_hours,
+        };
+
+        match actions
+            .iter()
+            .map(|(due_at, _)| *due_at)
+            .find(|due_at| *due_at > now)
+        {
+            Some(schedule_time) => {
+                let tracking_data = storage::DisputeDeadlineTrackingData {
+                    challenge_required_by,
+                    last_reminder_hours,
+                    ..tracking_data
+                };
+                let tracking_data = serde_json::to_value(tracking_data)
+                    .map_err(|_| errors::ProcessTrackerError::SerializationFailed)?;
+                db.as_scheduler()
+                    .update_process(
+                        process,
+                        storage::ProcessTrackerUpdate::Update {
+                            name: None,
+                            retry_count: Some(0),
+                            schedule_time: Some(schedule_time),
+                            tracking_data: Some(tracking_data),
+                            business_status: None,
+                            status: Some(storage_enums::ProcessTrackerStatus::New),
+                            updated_at: Some(date_time::now()),
+                        },
+                    )
+                    .await?;
+                metrics::TASKS_RESET_COUNT.add(
+                    &metrics::CONTEXT,
+                    1,
+                    &add_attributes([("flow", "DisputeDeadline")]),
+                );
+
+                Ok(())
+            }
+            None => db
+                .as_scheduler()
+                .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                .await
+                .map_err(Into::into),
+        }
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        let merchant_id = process
+            .tracking_data
+            .clone()
+            .parse_value::<storage::DisputeDeadlineTrackingData>("DisputeDeadlineTrackingData")
+            .ok()
+            .map(|tracking_data| tracking_data.merchant_id);
+
+        // Outgoing webhooks for a reminder are idempotent, so transient failures are retried
+        workflow_utils::retry_or_handle_process_error(state, process, merchant_id.as_ref(), error)
+            .await
+    }
+}
@@ -0,0 +1,112 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::{enums::ExportStatus, process_tracker::business_status};
+use router_env::logger;
+use scheduler::{consumer, workflows::ProcessTrackerWorkflow};
+
+use crate::{
+    core::exports, errors, routes::SessionState, types::storage, workflows::utils as workflow_utils,
+};
+
+pub struct ExportJobWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for ExportJobWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let tracking_data: storage::ExportJobTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("ExportJobTrackingData")?;
+
+        let db = &*state.store;
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+        let export = db
+            .find_export_job_by_merchant_id_export_id(
+                &tracking_data.merchant_id,
+                &tracking_data.export_id,
+            )
+            .await?;
+
+        if matches!(
+            export.status,
+            ExportStatus::Completed | ExportStatus::Failed
+        ) {
+            return db
+                .as_scheduler()
+                .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                .await
+                .map_err(Into::into);
+        }
+
+        Box::pin(exports::run_export(
+            state,
+            &merchant_account,
+            &key_store,
+            &export,
+        ))
+        .await?;
+        exports::finish_export_task(state, process).await
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        let tracking_data: storage::ExportJobTrackingData = match process
+            .tracking_data
+            .clone()
+            .parse_value("ExportJobTrackingData")
+        {
+            Ok(tracking_data) => tracking_data,
+            Err(_) => {
+                return consumer::consumer_error_handler(state.store.as_scheduler(), process, error)
+                    .await
+            }
+        };
+
+        if let Some(schedule_time) =
+            workflow_utils::get_retry_schedule_time(&process, &tracking_data.merchant_id)
+        {
+            logger::error!(%process.id, ?error, "Failed to export records, retrying");
+            return state
+                .get_db()
+                .as_scheduler()
+                .retry_process(process, schedule_time)
+                .await
+                .map_err(Into::into);
+        }
+
+        // The export is marked as failed once the retries are exhausted, so that it is not
+        // reported as being processed indefinitely
+        logger::error!(%process.id, ?error, "Failed to export records, giving up");
+        if let Err(update_error) = exports::fail_export(
+            state,
+            &tracking_data,
+            "The records could not be exported".to_owned(),
+        )
+        .await
+        {
+            logger::error!(?update_error, "Failed to mark export as failed");
+        }
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
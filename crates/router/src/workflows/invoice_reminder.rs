@@ -0,0 +1,137 @@
+use common_utils::{date_time, ext_traits::ValueExt};
+use diesel_models::{
+    enums::{self as storage_enums, InvoiceStatus},
+    process_tracker::business_status,
+};
+use router_env::{logger, metrics::add_attributes};
+use scheduler::workflows::ProcessTrackerWorkflow;
+
+use crate::{
+    core::invoices,
+    errors,
+    routes::{metrics, SessionState},
+    types::storage,
+    workflows::utils as workflow_utils,
+};
+
+pub struct InvoiceReminderWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for InvoiceReminderWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let tracking_data: storage::InvoiceReminderTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("InvoiceReminderTrackingData")?;
+
+        let db = &*state.store;
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+        let invoice = db
+            .find_invoice_by_merchant_id_invoice_id(
+                &tracking_data.merchant_id,
+                &tracking_data.invoice_id,
+            )
+            .await?;
+        let invoice =
+            invoices::sync_invoice_status(state, &merchant_account, &key_store, invoice).await?;
+
+        // Invoices which have been paid or voided are not tracked anymore
+        if invoice.status != InvoiceStatus::Open {
+            return db
+                .as_scheduler()
+                .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                .await
+                .map_err(Into::into);
+        }
+
+        let now = date_time::now();
+        if invoice.due_date <= now {
+            let invoice =
+                invoices::mark_invoice_overdue(state, &merchant_account, &key_store, invoice)
+                    .await?;
+            logger::info!(invoice_id = %invoice.invoice_id, "Invoice is overdue");
+
+            return db
+                .as_scheduler()
+                .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                .await
+                .map_err(Into::into);
+        }
+
+        let reminder_days = &state.conf.invoices.reminder_days_before_due_date;
+        let last_reminder_days =
+            match invoices::get_due_invoice_reminder(invoice.due_date, reminder_days, now) {
+                Some(days) if tracking_data.last_reminder_days != Some(days) => {
+                    invoices::send_invoice_reminder(state, &merchant_account, &key_store, &invoice)
+                        .await?;
+                    Some(days)
+                }
+                _ => tracking_data.last_reminder_days,
+            };
+
+        let schedule_time =
+            invoices::get_next_invoice_reminder_time(invoice.due_date, reminder_days, now);
+        let tracking_data = storage::InvoiceReminderTrackingData {
+            last_reminder_days,
+            ..tracking_data
+        };
+        let tracking_data = serde_json::to_value(tracking_data)
+            .map_err(|_| errors::ProcessTrackerError::SerializationFailed)?;
+        db.as_scheduler()
+            .update_process(
+                process,
+                storage::ProcessTrackerUpdate::Update {
+                    name: None,
+                    retry_count: Some(0),
+                    schedule_time: Some(schedule_time),
+                    tracking_data: Some(tracking_data),
+                    business_status: None,
+                    status: Some(storage_enums::ProcessTrackerStatus::New),
+                    updated_at: Some(date_time::now()),
+                },
+            )
+            .await?;
+        metrics::TASKS_RESET_COUNT.add(
+            &metrics::CONTEXT,
+            1,
+            &add_attributes([("flow", "InvoiceReminder")]),
+        );
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        let merchant_id = process
+            .tracking_data
+            .clone()
+            .parse_value::<storage::InvoiceReminderTrackingData>("InvoiceReminderTrackingData")
+            .ok()
+            .map(|tracking_data| tracking_data.merchant_id);
+
+        workflow_utils::retry_or_handle_process_error(state, process, merchant_id.as_ref(), error)
+            .await
+    }
+}
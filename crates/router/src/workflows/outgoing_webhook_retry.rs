@@ -99,6 +99,87 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
             }
         };
 
+        if let Some(webhook_endpoint_id) = &initial_event.webhook_endpoint_id {
+            let webhook_endpoint = db
+                .find_webhook_endpoint_by_merchant_id_endpoint_id(
+                    &business_profile.merchant_id,
+                    webhook_endpoint_id,
+                )
+                .await?;
+
+            // Events missed by suspended endpoints are replayed when the endpoint is re-enabled
+            if webhook_endpoint.is_suspended() {
+                logger::info!(
+                    %initial_event.event_id,
+                    %webhook_endpoint_id,
+                    "Webhook endpoint is suspended, finishing task"
+                );
+                db.as_scheduler()
+                    .finish_process_with_business_status(
+                        process,
+                        business_status::WEBHOOK_ENDPOINT_SUSPENDED,
+                    )
+                    .await?;
+                return Ok(());
+            }
+
+            // Hold back the delivery while the earlier events of the object are awaiting delivery,
+            // unless the task has no retries left. Holding back the delivery does not consume a
+            // retry of the task.
+            if let Some(sequence_number) = initial_event
+                .sequence_number
+                .filter(|_| webhook_endpoint.strict_ordering)
+            {
+                let pending_events = webhooks_core::get_pending_earlier_events(
+                    state,
+                    &key_store,
+                    &initial_event.primary_object_id,
+                    webhook_endpoint_id,
+                    sequence_number,
+                )
+                .await
+                .map_err(|error| {
+                    logger::error!(?error, "Failed to obtain pending events for object");
+                    errors::ProcessTrackerError::EApiErrorResponse
+                })?;
+
+                if !pending_events.is_empty() {
+                    let schedule_time = get_webhook_delivery_retry_schedule_time(
+                        db,
+                        &business_profile.merchant_id,
+                        process.retry_count + 1,
+                    )
+                    .await;
+
+                    match schedule_time {
+                        Some(schedule_time) => {
+                            logger::info!(
+                                %initial_event.event_id,
+                                pending_event_ids=?pending_events
+                                    .iter()
+                                    .map(|event| &event.event_id)
+                                    .collect::<Vec<_>>(),
+                                "Earlier events of the object are awaiting delivery, holding back \
+                                 outgoing webhook"
+                            );
+                            webhooks_core::hold_back_event_delivery(state, process, schedule_time)
+                                .await
+                                .map_err(|error| {
+                                    logger::error!(?error, "Failed to hold back outgoing webhook");
+                                    errors::ProcessTrackerError::EApiErrorResponse
+                                })?;
+                            return Ok(());
+                        }
+                        None => logger::warn!(
+                            %initial_event.event_id,
+                            "Earlier events of the object are awaiting delivery, but no retries \
+                             are left; delivering outgoing webhook"
+                        ),
+                    }
+                }
+            }
+        }
+
         let now = common_utils::date_time::now();
         let new_event = domain::Event {
             event_id,
@@ -117,6 +198,9 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
             response: None,
             delivery_attempt: Some(delivery_attempt),
             metadata: initial_event.metadata,
+            webhook_endpoint_id: initial_event.webhook_endpoint_id,
+            delivery_latency_ms: None,
+            sequence_number: initial_event.sequence_number,
         };
 
         let event = db
@@ -176,12 +260,17 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
                             event_type,
                             content: content.clone(),
                             timestamp: event.created_at,
+                            sequence_number: event.sequence_number,
+                            previous_event_ids: Vec::new(),
                         };
 
                         let request_content = webhooks_core::get_outgoing_webhook_request(
                             &merchant_account,
                             outgoing_webhook,
                             &business_profile,
+                            // Events inserted by old versions of the application are not
+                            // associated with webhook endpoints
+                            None,
                         )
                         .map_err(|error| {
                             logger::error!(
@@ -552,5 +641,14 @@ async fn get_outgoing_webhook_content_and_event_type(
                 event_type,
             ))
         }
+        // Subscription, payment method and alert events are always stored along with their
+        // request content, and are retried without fetching the current state of the resource
+        diesel_models::enums::EventClass::Subscriptions
+        | diesel_models::enums::EventClass::PaymentMethods
+        | diesel_models::enums::EventClass::Alerts => {
+            Err(errors::ProcessTrackerError::ResourceFetchingFailed {
+                resource_name: tracking_data.primary_object_id.clone(),
+            })
+        }
     }
 }
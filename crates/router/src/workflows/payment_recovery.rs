@@ -0,0 +1,259 @@
+use common_utils::{date_time, ext_traits::ValueExt};
+use diesel_models::{
+    enums::{self as storage_enums, PaymentRecoveryStatus, SubscriptionStatus},
+    process_tracker::business_status,
+};
+use router_env::{logger, metrics::add_attributes};
+use scheduler::workflows::ProcessTrackerWorkflow;
+
+use crate::{
+    core::{
+        payment_recovery::{self, RecoveryPaymentOutcome},
+        subscriptions,
+    },
+    errors,
+    routes::{metrics, SessionState},
+    types::storage,
+    workflows::utils as workflow_utils,
+};
+
+pub struct PaymentRecoveryWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for PaymentRecoveryWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let tracking_data: storage::PaymentRecoveryTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("PaymentRecoveryTrackingData")?;
+
+        let db = &*state.store;
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+        let recovery = db
+            .find_payment_recovery_by_merchant_id_recovery_id(
+                &tracking_data.merchant_id,
+                &tracking_data.recovery_id,
+            )
+            .await?;
+
+        if recovery.status != PaymentRecoveryStatus::Scheduled {
+            return db
+                .as_scheduler()
+                .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                .await
+                .map_err(Into::into);
+        }
+
+        // The customer must not be charged for a subscription which has been cancelled meanwhile
+        if let Some(subscription_id) = &recovery.subscription_id {
+            let subscription = db
+                .find_subscription_by_merchant_id_subscription_id(
+                    &tracking_data.merchant_id,
+                    subscription_id,
+                )
+                .await?;
+            if subscription.status == SubscriptionStatus::Cancelled {
+                db.update_payment_recovery_by_merchant_id_recovery_id(
+                    &tracking_data.merchant_id,
+                    &tracking_data.recovery_id,
+                    storage::PaymentRecoveryUpdate::LostUpdate {
+                        decline_category: None,
+                        attempt_count: recovery.attempt_count,
+                        latest_payment_id: None,
+                        error_code: None,
+                        error_message: None,
+                    },
+                )
+                .await?;
+                metrics::PAYMENT_RECOVERY_LOST_COUNT.add(
+                    &metrics::CONTEXT,
+                    1,
+                    &add_attributes([("decline_category", recovery.decline_category.clone())]),
+                );
+
+                return db
+                    .as_scheduler()
+                    .finish_process_with_business_status(process, business_status::REVOKED)
+                    .await
+                    .map_err(Into::into);
+            }
+        }
+
+        let outcome = payment_recovery::create_recovery_payment(
+            state,
+            &merchant_account,
+            &key_store,
+            &recovery,
+        )
+        .await?;
+        let attempt_count = recovery.attempt_count.saturating_add(1);
+        metrics::PAYMENT_RECOVERY_ATTEMPT_COUNT.add(
+            &metrics::CONTEXT,
+            1,
+            &add_attributes([("decline_category", recovery.decline_category.clone())]),
+        );
+
+        match outcome {
+            RecoveryPaymentOutcome::Recovered { payment_id } => {
+                db.update_payment_recovery_by_merchant_id_recovery_id(
+                    &tracking_data.merchant_id,
+                    &tracking_data.recovery_id,
+                    storage::PaymentRecoveryUpdate::RecoveredUpdate {
+                        attempt_count,
+                        latest_payment_id: payment_id.get_string_repr().to_owned(),
+                    },
+                )
+                .await?;
+                metrics::PAYMENT_RECOVERY_RECOVERED_COUNT.add(
+                    &metrics::CONTEXT,
+                    1,
+                    &add_attributes([("decline_category", recovery.decline_category.clone())]),
+                );
+
+                if let Some(subscription_id) = &recovery.subscription_id {
+                    subscriptions::resume_subscription_after_recovery(
+                        state,
+                        &merchant_account,
+                        &key_store,
+                        subscription_id,
+                        &payment_id,
+                    )
+                    .await?;
+                }
+
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                    .await
+                    .map_err(Into::into)
+            }
+            RecoveryPaymentOutcome::Failed {
+                payment_id,
+                connector,
+                error_code,
+                error_message,
+            } => {
+                let decline_category = payment_recovery::get_decline_category(
+                    state,
+                    connector.as_ref(),
+                    error_code.clone(),
+                    error_message.clone(),
+                )
+                .await;
+                let mapping = payment_recovery::get_payment_recovery_mapping(db).await;
+                let schedule_time = payment_recovery::get_recovery_schedule_time(
+                    &mapping,
+                    decline_category,
+                    attempt_count,
+                );
+                let latest_payment_id =
+                    payment_id.map(|payment_id| payment_id.get_string_repr().to_owned());
+
+                match schedule_time {
+                    Some(schedule_time) => {
+                        db.update_payment_recovery_by_merchant_id_recovery_id(
+                            &tracking_data.merchant_id,
+                            &tracking_data.recovery_id,
+                            storage::PaymentRecoveryUpdate::RetryScheduledUpdate {
+                                decline_category: decline_category.to_string(),
+                                attempt_count,
+                                latest_payment_id,
+                                error_code,
+                                error_message,
+                            },
+                        )
+                        .await?;
+
+                        db.as_scheduler()
+                            .update_process(
+                                process,
+                                storage::ProcessTrackerUpdate::Update {
+                                    name: None,
+                                    retry_count: Some(0),
+                                    schedule_time: Some(schedule_time),
+                                    tracking_data: None,
+                                    business_status: None,
+                                    status: Some(storage_enums::ProcessTrackerStatus::New),
+                                    updated_at: Some(date_time::now()),
+                                },
+                            )
+                            .await?;
+                        metrics::TASKS_RESET_COUNT.add(
+                            &metrics::CONTEXT,
+                            1,
+                            &add_attributes([("flow", "PaymentRecovery")]),
+                        );
+
+                        Ok(())
+                    }
+                    None => {
+                        logger::info!(
+                            recovery_id = %recovery.recovery_id,
+                            %decline_category,
+                            attempt_count,
+                            "Failed to recover payment, not retrying anymore"
+                        );
+                        db.update_payment_recovery_by_merchant_id_recovery_id(
+                            &tracking_data.merchant_id,
+                            &tracking_data.recovery_id,
+                            storage::PaymentRecoveryUpdate::LostUpdate {
+                                decline_category: Some(decline_category.to_string()),
+                                attempt_count,
+                                latest_payment_id,
+                                error_code,
+                                error_message,
+                            },
+                        )
+                        .await?;
+                        metrics::PAYMENT_RECOVERY_LOST_COUNT.add(
+                            &metrics::CONTEXT,
+                            1,
+                            &add_attributes([("decline_category", decline_category.to_string())]),
+                        );
+
+                        db.as_scheduler()
+                            .finish_process_with_business_status(process, business_status::FAILURE)
+                            .await
+                            .map_err(Into::into)
+                    }
+                }
+            }
+        }
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        let merchant_id = process
+            .tracking_data
+            .clone()
+            .parse_value::<storage::PaymentRecoveryTrackingData>("PaymentRecoveryTrackingData")
+            .ok()
+            .map(|tracking_data| tracking_data.merchant_id);
+
+        // Transient failures (such as the database being unreachable) are retried, as the payment
+        // created for a retry is idempotent
+        workflow_utils::retry_or_handle_process_error(state, process, merchant_id.as_ref(), error)
+            .await
+    }
+}
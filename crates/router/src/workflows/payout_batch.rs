@@ -0,0 +1,129 @@
+use common_utils::{date_time, ext_traits::ValueExt};
+use diesel_models::{
+    enums::{self as storage_enums, PayoutBatchStatus},
+    process_tracker::business_status,
+};
+use router_env::{logger, metrics::add_attributes};
+use scheduler::workflows::ProcessTrackerWorkflow;
+
+use crate::{
+    core::payout_batch,
+    errors,
+    routes::{metrics, SessionState},
+    types::storage,
+    workflows::utils as workflow_utils,
+};
+
+pub struct PayoutBatchWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for PayoutBatchWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let tracking_data: storage::PayoutBatchTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("PayoutBatchTrackingData")?;
+
+        let db = &*state.store;
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+        let batch = db
+            .find_payout_batch_by_merchant_id_batch_id(
+                &tracking_data.merchant_id,
+                &tracking_data.batch_id,
+            )
+            .await?;
+
+        if !matches!(
+            batch.status,
+            PayoutBatchStatus::Pending | PayoutBatchStatus::Processing
+        ) {
+            return db
+                .as_scheduler()
+                .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                .await
+                .map_err(Into::into);
+        }
+
+        let batch = if batch.processed_count < batch.total_count {
+            payout_batch::process_payout_batch_rows(state, &merchant_account, &key_store, batch)
+                .await?
+        } else {
+            batch
+        };
+
+        if batch.processed_count < batch.total_count {
+            // Pick up the remaining rows of the file right away
+            db.as_scheduler()
+                .update_process(
+                    process,
+                    storage::ProcessTrackerUpdate::Update {
+                        name: None,
+                        retry_count: Some(0),
+                        schedule_time: Some(date_time::now()),
+                        tracking_data: None,
+                        business_status: None,
+                        status: Some(storage_enums::ProcessTrackerStatus::New),
+                        updated_at: Some(date_time::now()),
+                    },
+                )
+                .await?;
+            metrics::TASKS_RESET_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &add_attributes([("flow", "PayoutBatch")]),
+            );
+            return Ok(());
+        }
+
+        let batch = payout_batch::complete_payout_batch(state, batch).await?;
+        logger::info!(
+            batch_id = %batch.batch_id,
+            status = %batch.status,
+            succeeded_count = batch.succeeded_count,
+            failed_count = batch.failed_count,
+            "Processed payout batch"
+        );
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+            .await
+            .map_err(Into::into)
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        let merchant_id = process
+            .tracking_data
+            .clone()
+            .parse_value::<storage::PayoutBatchTrackingData>("PayoutBatchTrackingData")
+            .ok()
+            .map(|tracking_data| tracking_data.merchant_id);
+
+        // Transient failures are retried, as the rows which have been processed already are
+        // skipped and the payouts are created with deterministic payout IDs
+        workflow_utils::retry_or_handle_process_error(state, process, merchant_id.as_ref(), error)
+            .await
+    }
+}
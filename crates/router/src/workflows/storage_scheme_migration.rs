@@ -0,0 +1,241 @@
+use common_utils::{date_time, ext_traits::ValueExt};
+use diesel_models::{
+    enums::{self as storage_enums, StorageSchemeMigrationStatus},
+    process_tracker::business_status,
+    StorageSchemeMigrationTrackingData,
+};
+use router_env::{logger, metrics::add_attributes};
+use scheduler::workflows::ProcessTrackerWorkflow;
+
+use crate::{
+    core::storage_scheme_migration,
+    errors,
+    routes::{metrics, SessionState},
+    types::storage,
+    workflows::utils as workflow_utils,
+};
+
+pub struct StorageSchemeMigrationWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for StorageSchemeMigrationWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let mut tracking_data: StorageSchemeMigrationTrackingData =
+            process
+                .tracking_data
+                .clone()
+                .parse_value("StorageSchemeMigrationTrackingData")?;
+        let max_drain_attempts = state.conf.storage_scheme_migration.max_drain_attempts;
+        let merchant_id = tracking_data.merchant_id.clone();
+
+        match tracking_data.status {
+            StorageSchemeMigrationStatus::Draining => {
+                // New entries keep reaching the drainer streams while the merchant writes to
+                // redis, so a merchant leaving KV is moved to the database before draining
+                if tracking_data.switched_at.is_none()
+                    && storage_scheme_migration::is_switched_before_draining(
+                        tracking_data.target_storage_scheme,
+                    )
+                {
+                    storage_scheme_migration::switch_storage_scheme(
+                        state,
+                        &merchant_id,
+                        tracking_data.target_storage_scheme,
+                    )
+                    .await?;
+                    tracking_data.switched_at = Some(date_time::now());
+                }
+
+                tracking_data.pending_kv_entries =
+                    storage_scheme_migration::count_pending_kv_entries(state, &merchant_id).await?;
+                tracking_data.drain_attempts = tracking_data.drain_attempts.saturating_add(1);
+
+                if tracking_data.pending_kv_entries == 0 {
+                    tracking_data.status = StorageSchemeMigrationStatus::Verifying;
+                    // Verification can be started right away
+                    reschedule_migration(state, process, &tracking_data, date_time::now()).await
+                } else if tracking_data.drain_attempts >= max_drain_attempts {
+                    tracking_data.failure_reason = Some(format!(
+                        "{} entries of the merchant are yet to be drained",
+                        tracking_data.pending_kv_entries
+                    ));
+                    fail_migration(state, process, tracking_data).await
+                } else {
+                    reschedule_migration_after_poll_interval(state, process, &tracking_data).await
+                }
+            }
+            StorageSchemeMigrationStatus::Verifying => {
+                let (sampled_keys, mismatched_keys) =
+                    storage_scheme_migration::verify_sampled_kv_entries(
+                        state,
+                        &merchant_id,
+                        tracking_data.verification_sample_size,
+                    )
+                    .await?;
+                tracking_data.sampled_keys = sampled_keys;
+                tracking_data.mismatched_keys = mismatched_keys;
+
+                if tracking_data.mismatched_keys.is_empty() {
+                    if tracking_data.switched_at.is_none() {
+                        storage_scheme_migration::switch_storage_scheme(
+                            state,
+                            &merchant_id,
+                            tracking_data.target_storage_scheme,
+                        )
+                        .await?;
+                        tracking_data.switched_at = Some(date_time::now());
+                    }
+                    tracking_data.status = StorageSchemeMigrationStatus::Completed;
+                    tracking_data.completed_at = Some(date_time::now());
+                    finish_migration(
+                        state,
+                        process,
+                        &tracking_data,
+                        business_status::COMPLETED_BY_PT,
+                    )
+                    .await
+                } else if tracking_data.drain_attempts >= max_drain_attempts {
+                    tracking_data.failure_reason = Some(format!(
+                        "{} sampled entries of the merchant differ from the database",
+                        tracking_data.mismatched_keys.len()
+                    ));
+                    fail_migration(state, process, tracking_data).await
+                } else {
+                    // The mismatched entries may belong to payments which were updated after the
+                    // streams were found to be drained, so the drainer is given time to catch up
+                    tracking_data.status = StorageSchemeMigrationStatus::Draining;
+                    reschedule_migration_after_poll_interval(state, process, &tracking_data).await
+                }
+            }
+            StorageSchemeMigrationStatus::RolledBack => state
+                .get_db()
+                .as_scheduler()
+                .finish_process_with_business_status(process, business_status::REVOKED)
+                .await
+                .map_err(Into::into),
+            StorageSchemeMigrationStatus::Completed | StorageSchemeMigrationStatus::Failed => state
+                .get_db()
+                .as_scheduler()
+                .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                .await
+                .map_err(Into::into),
+        }
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        let merchant_id = process
+            .tracking_data
+            .clone()
+            .parse_value::<StorageSchemeMigrationTrackingData>("StorageSchemeMigrationTrackingData")
+            .ok()
+            .map(|tracking_data| tracking_data.merchant_id);
+
+        // Every step of the migration can be repeated, so a failed step is retried. The task is
+        // moved out of `ProcessStarted` either way, so that the migration can still be rolled
+        // back or started again.
+        workflow_utils::retry_or_handle_process_error(state, process, merchant_id.as_ref(), error)
+            .await
+    }
+}
+
+async fn reschedule_migration_after_poll_interval(
+    state: &SessionState,
+    process: storage::ProcessTracker,
+    tracking_data: &StorageSchemeMigrationTrackingData,
+) -> Result<(), errors::ProcessTrackerError> {
+    let poll_interval = i64::from(state.conf.storage_scheme_migration.poll_interval_in_secs);
+    let schedule_time = date_time::now().saturating_add(time::Duration::seconds(poll_interval));
+
+    reschedule_migration(state, process, tracking_data, schedule_time).await
+}
+
+async fn reschedule_migration(
+    state: &SessionState,
+    process: storage::ProcessTracker,
+    tracking_data: &StorageSchemeMigrationTrackingData,
+    schedule_time: time::PrimitiveDateTime,
+) -> Result<(), errors::ProcessTrackerError> {
+    let tracking_data = serde_json::to_value(tracking_data)
+        .map_err(|_| errors::ProcessTrackerError::SerializationFailed)?;
+
+    state
+        .get_db()
+        .as_scheduler()
+        .update_process(
+            process,
+            storage::ProcessTrackerUpdate::Update {
+                name: None,
+                retry_count: None,
+                schedule_time: Some(schedule_time),
+                tracking_data: Some(tracking_data),
+                business_status: None,
+                status: Some(storage_enums::ProcessTrackerStatus::New),
+                updated_at: Some(date_time::now()),
+            },
+        )
+        .await?;
+    metrics::TASKS_RESET_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &add_attributes([("flow", "StorageSchemeMigration")]),
+    );
+
+    Ok(())
+}
+
+/// Fails the migration. A merchant which has already been switched to the target storage scheme
+/// is left on it, as writes made since the switch are only present in the target storage.
+async fn fail_migration(
+    state: &SessionState,
+    process: storage::ProcessTracker,
+    mut tracking_data: StorageSchemeMigrationTrackingData,
+) -> Result<(), errors::ProcessTrackerError> {
+    logger::warn!(
+        merchant_id = ?tracking_data.merchant_id,
+        failure_reason = ?tracking_data.failure_reason,
+        switched_at = ?tracking_data.switched_at,
+        "Storage scheme migration failed"
+    );
+    tracking_data.status = StorageSchemeMigrationStatus::Failed;
+    tracking_data.completed_at = Some(date_time::now());
+
+    finish_migration(state, process, &tracking_data, business_status::FAILURE).await
+}
+
+async fn finish_migration(
+    state: &SessionState,
+    process: storage::ProcessTracker,
+    tracking_data: &StorageSchemeMigrationTrackingData,
+    business_status: &'static str,
+) -> Result<(), errors::ProcessTrackerError> {
+    let tracking_data = serde_json::to_value(tracking_data)
+        .map_err(|_| errors::ProcessTrackerError::SerializationFailed)?;
+
+    state
+        .get_db()
+        .as_scheduler()
+        .update_process(
+            process,
+            storage::ProcessTrackerUpdate::Update {
+                name: None,
+                retry_count: None,
+                schedule_time: None,
+                tracking_data: Some(tracking_data),
+                business_status: Some(String::from(business_status)),
+                status: Some(storage_enums::ProcessTrackerStatus::Finish),
+                updated_at: Some(date_time::now()),
+            },
+        )
+        .await?;
+
+    Ok(())
+}
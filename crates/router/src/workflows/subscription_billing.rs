@@ -0,0 +1,239 @@
+use common_utils::{date_time, ext_traits::ValueExt};
+use diesel_models::{
+    enums::{self as storage_enums, SubscriptionStatus},
+    process_tracker::business_status,
+};
+use router_env::{logger, metrics::add_attributes};
+use scheduler::workflows::ProcessTrackerWorkflow;
+
+use crate::{
+    core::{
+        payment_recovery,
+        subscriptions::{self, SubscriptionPaymentOutcome},
+    },
+    errors,
+    routes::{metrics, SessionState},
+    types::storage,
+    workflows::utils as workflow_utils,
+};
+
+pub struct SubscriptionBillingWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for SubscriptionBillingWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let tracking_data: storage::SubscriptionBillingTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("SubscriptionBillingTrackingData")?;
+
+        let db = &*state.store;
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+        let subscription = db
+            .find_subscription_by_merchant_id_subscription_id(
+                &tracking_data.merchant_id,
+                &tracking_data.subscription_id,
+            )
+            .await?;
+
+        if subscription.status == SubscriptionStatus::Cancelled {
+            return db
+                .as_scheduler()
+                .finish_process_with_business_status(process, business_status::REVOKED)
+                .await
+                .map_err(Into::into);
+        }
+
+        if subscription.cancel_at_period_end {
+            let subscription = db
+                .update_subscription_by_merchant_id_subscription_id(
+                    &tracking_data.merchant_id,
+                    &tracking_data.subscription_id,
+                    storage::SubscriptionUpdate::CancellationUpdate {
+                        status: Some(SubscriptionStatus::Cancelled),
+                        cancel_at_period_end: true,
+                        cancelled_at: Some(date_time::now()),
+                    },
+                )
+                .await?;
+            subscriptions::trigger_subscription_webhook(
+                state,
+                &merchant_account,
+                &key_store,
+                &subscription,
+                storage_enums::EventType::SubscriptionCancelled,
+                subscription.subscription_id.clone(),
+            )
+            .await;
+
+            return db
+                .as_scheduler()
+                .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                .await
+                .map_err(Into::into);
+        }
+
+        let plan = db
+            .find_subscription_plan_by_merchant_id_plan_id(
+                &tracking_data.merchant_id,
+                &subscription.plan_id,
+            )
+            .await?;
+
+        let outcome = subscriptions::create_subscription_payment(
+            state,
+            &merchant_account,
+            &key_store,
+            &subscription,
+            &plan,
+        )
+        .await?;
+
+        match outcome {
+            SubscriptionPaymentOutcome::Charged { payment_id } => {
+                let subscription = subscriptions::renew_subscription(
+                    state,
+                    &merchant_account,
+                    &key_store,
+                    &subscription,
+                    &plan,
+                    &payment_id,
+                )
+                .await?;
+
+                db.as_scheduler()
+                    .update_process(
+                        process,
+                        storage::ProcessTrackerUpdate::Update {
+                            name: None,
+                            retry_count: Some(0),
+                            schedule_time: Some(subscription.current_period_end),
+                            tracking_data: None,
+                            business_status: None,
+                            status: Some(storage_enums::ProcessTrackerStatus::New),
+                            updated_at: Some(date_time::now()),
+                        },
+                    )
+                    .await?;
+                metrics::TASKS_RESET_COUNT.add(
+                    &metrics::CONTEXT,
+                    1,
+                    &add_attributes([("flow", "SubscriptionBilling")]),
+                );
+
+                Ok(())
+            }
+            SubscriptionPaymentOutcome::Failed {
+                payment_id,
+                connector,
+                error_code,
+                error_message,
+            } => {
+                logger::warn!(
+                    subscription_id = %subscription.subscription_id,
+                    ?payment_id,
+                    ?error_code,
+                    ?error_message,
+                    "Subscription payment failed"
+                );
+                let latest_payment_id = payment_id
+                    .as_ref()
+                    .map(|payment_id| payment_id.get_string_repr().to_owned());
+                let subscription = db
+                    .update_subscription_by_merchant_id_subscription_id(
+                        &tracking_data.merchant_id,
+                        &tracking_data.subscription_id,
+                        storage::SubscriptionUpdate::PaymentFailedUpdate {
+                            latest_payment_id: latest_payment_id.clone(),
+                        },
+                    )
+                    .await?;
+                subscriptions::trigger_subscription_webhook(
+                    state,
+                    &merchant_account,
+                    &key_store,
+                    &subscription,
+                    storage_enums::EventType::SubscriptionPaymentFailed,
+                    latest_payment_id.unwrap_or_else(|| subscription.subscription_id.clone()),
+                )
+                .await;
+
+                // The subscription is resumed by the recovery workflow if a retry of the payment
+                // succeeds. Payments which could not be created at all are not retried.
+                if let Some(payment_id) = payment_id {
+                    let failed_payment = payment_recovery::FailedRecurringPayment {
+                        payment_id,
+                        profile_id: subscription.profile_id.clone(),
+                        customer_id: subscription.customer_id.clone(),
+                        mandate_id: subscription.mandate_id.clone(),
+                        payment_method_id: subscription.payment_method_id.clone(),
+                        connector,
+                        amount: plan.amount,
+                        currency: plan.currency,
+                        error_code,
+                        error_message,
+                        subscription_id: Some(subscription.subscription_id.clone()),
+                    };
+                    if let Err(error) = payment_recovery::schedule_payment_recovery(
+                        state,
+                        merchant_account.get_id(),
+                        merchant_account.get_org_id(),
+                        failed_payment,
+                    )
+                    .await
+                    {
+                        logger::error!(
+                            ?error,
+                            subscription_id = %subscription.subscription_id,
+                            "Failed to schedule recovery of subscription payment"
+                        );
+                    }
+                }
+
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, business_status::FAILURE)
+                    .await
+                    .map_err(Into::into)
+            }
+        }
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        let merchant_id = process
+            .tracking_data
+            .clone()
+            .parse_value::<storage::SubscriptionBillingTrackingData>(
+                "SubscriptionBillingTrackingData",
+            )
+            .ok()
+            .map(|tracking_data| tracking_data.merchant_id);
+
+        // Transient failures (such as the database being unreachable) are retried, as the payment
+        // created for a billing cycle is idempotent
+        workflow_utils::retry_or_handle_process_error(state, process, merchant_id.as_ref(), error)
+            .await
+    }
+}
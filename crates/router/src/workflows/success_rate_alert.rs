@@ -0,0 +1,116 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use router_env::logger;
+use scheduler::{consumer, workflows::ProcessTrackerWorkflow};
+
+use crate::{core::success_rate_alerts, errors, routes::SessionState, types::storage};
+
+pub struct SuccessRateAlertWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for SuccessRateAlertWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let tracking_data: storage::SuccessRateAlertTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("SuccessRateAlertTrackingData")?;
+
+        // Alerts are not evaluated anymore once they have been disabled or deleted
+        let Some(config) =
+            success_rate_alerts::get_success_rate_alert_config(state, &tracking_data.profile_id)
+                .await?
+                .filter(|config| config.enabled)
+        else {
+            return state
+                .store
+                .as_scheduler()
+                .finish_process_with_business_status(process, business_status::REVOKED)
+                .await
+                .map_err(Into::into);
+        };
+
+        let db = &*state.store;
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+        let business_profile = db
+            .find_business_profile_by_profile_id(
+                key_manager_state,
+                &key_store,
+                &tracking_data.profile_id,
+            )
+            .await?;
+
+        let active_alerts = success_rate_alerts::evaluate_success_rate_alerts(
+            state,
+            &merchant_account,
+            &key_store,
+            &business_profile,
+            &config,
+            tracking_data.active_alerts.clone(),
+        )
+        .await?;
+
+        success_rate_alerts::reschedule_success_rate_alert_task(
+            state,
+            process,
+            &config,
+            storage::SuccessRateAlertTrackingData {
+                active_alerts,
+                ..tracking_data
+            },
+        )
+        .await
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        let tracking_data = process
+            .tracking_data
+            .clone()
+            .parse_value::<storage::SuccessRateAlertTrackingData>("SuccessRateAlertTrackingData");
+        let Ok(tracking_data) = tracking_data else {
+            return consumer::consumer_error_handler(state.store.as_scheduler(), process, error)
+                .await;
+        };
+
+        // The evaluation is skipped, and the alert rules are evaluated again at the end of the
+        // next window
+        logger::error!(%process.id, ?error, "Failed to evaluate success rate alerts, skipping");
+        match success_rate_alerts::get_success_rate_alert_config(state, &tracking_data.profile_id)
+            .await
+        {
+            Ok(Some(config)) if config.enabled => {
+                success_rate_alerts::reschedule_success_rate_alert_task(
+                    state,
+                    process,
+                    &config,
+                    tracking_data,
+                )
+                .await
+                .map_err(|error| error_stack::report!(error))
+            }
+            _ => consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await,
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use common_utils::{date_time, id_type};
+use router_env::logger;
+use scheduler::{
+    consumer::{self, types::process_data},
+    utils as pt_utils,
+};
+
+use crate::{errors, routes::SessionState, types::storage};
+
+/// Returns the time at which a failed process is to be retried, based on the default process
+/// tracker mapping of the merchant, or `None` once the retries of the process are exhausted.
+pub fn get_retry_schedule_time(
+    process: &storage::ProcessTracker,
+    merchant_id: &id_type::MerchantId,
+) -> Option<time::PrimitiveDateTime> {
+    pt_utils::get_schedule_time(
+        process_data::ConnectorPTMapping::default(),
+        merchant_id,
+        process.retry_count.saturating_add(1),
+    )
+    .map(|delay| date_time::now().saturating_add(time::Duration::seconds(delay.into())))
+}
+
+/// Error handler of workflows whose execution is idempotent, so that transient failures (such as
+/// the database being unreachable) can be retried. The process is handed to the consumer error
+/// handler once its retries are exhausted, or if the merchant it belongs to is not known.
+pub async fn retry_or_handle_process_error(
+    state: &SessionState,
+    process: storage::ProcessTracker,
+    merchant_id: Option<&id_type::MerchantId>,
+    error: errors::ProcessTrackerError,
+) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+    match merchant_id.and_then(|merchant_id| get_retry_schedule_time(&process, merchant_id)) {
+        Some(schedule_time) => {
+            logger::error!(%process.id, ?process.runner, ?error, "Failed while executing workflow, retrying");
+            state
+                .get_db()
+                .as_scheduler()
+                .retry_process(process, schedule_time)
+                .await
+                .map_err(Into::into)
+        }
+        None => consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await,
+    }
+}
@@ -127,6 +127,7 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
         dispute_id: None,
         integrity_check: Ok(()),
         additional_merchant_data: None,
+        merchant_connector_id: None,
         header_payload: None,
     }
 }
@@ -196,6 +197,7 @@ fn construct_refund_router_data<F>() -> types::RefundsRouterData<F> {
         dispute_id: None,
         integrity_check: Ok(()),
         additional_merchant_data: None,
+        merchant_connector_id: None,
         header_payload: None,
     }
 }
@@ -1,4 +1,12 @@
-use std::{fmt::Debug, marker::PhantomData, str::FromStr, sync::Arc, time::Duration};
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    marker::PhantomData,
+    path::PathBuf,
+    str::FromStr,
+    sync::{Arc, Mutex, OnceLock},
+    time::Duration,
+};
 
 use async_trait::async_trait;
 use common_utils::pii::Email;
@@ -405,6 +413,7 @@ pub trait ConnectorActions: Connector {
                 connector_refund_id: Some(refund_id),
                 browser_info: None,
                 charges: None,
+                split_refunds: None,
                 integrity_object: None,
             }),
             payment_info,
@@ -545,6 +554,7 @@ pub trait ConnectorActions: Connector {
             connector_response: None,
             integrity_check: Ok(()),
             additional_merchant_data: None,
+            merchant_connector_id: None,
             header_payload: None,
         }
     }
@@ -588,13 +598,15 @@ pub trait ConnectorActions: Connector {
             .connector
             .get_connector_integration();
         let request = self.get_payout_request(None, payout_type, payment_info);
+        let conf = Settings::new().unwrap();
+        let api_client = get_api_client(&conf);
         let tx: oneshot::Sender<()> = oneshot::channel().0;
 
         let app_state = Box::pin(routes::AppState::with_storage(
-            Settings::new().unwrap(),
+            conf,
             StorageImpl::PostgresqlTest,
             tx,
-            Box::new(services::MockApiClient),
+            api_client,
         ))
         .await;
         let state = Arc::new(app_state)
@@ -628,13 +640,15 @@ pub trait ConnectorActions: Connector {
             .connector
             .get_connector_integration();
         let request = self.get_payout_request(connector_payout_id, payout_type, payment_info);
+        let conf = Settings::new().unwrap();
+        let api_client = get_api_client(&conf);
         let tx: oneshot::Sender<()> = oneshot::channel().0;
 
         let app_state = Box::pin(routes::AppState::with_storage(
-            Settings::new().unwrap(),
+            conf,
             StorageImpl::PostgresqlTest,
             tx,
-            Box::new(services::MockApiClient),
+            api_client,
         ))
         .await;
         let state = Arc::new(app_state)
@@ -669,13 +683,15 @@ pub trait ConnectorActions: Connector {
             .get_connector_integration();
         let mut request = self.get_payout_request(None, payout_type, payment_info);
         request.connector_customer = connector_customer;
+        let conf = Settings::new().unwrap();
+        let api_client = get_api_client(&conf);
         let tx: oneshot::Sender<()> = oneshot::channel().0;
 
         let app_state = Box::pin(routes::AppState::with_storage(
-            Settings::new().unwrap(),
+            conf,
             StorageImpl::PostgresqlTest,
             tx,
-            Box::new(services::MockApiClient),
+            api_client,
         ))
         .await;
         let state = Arc::new(app_state)
@@ -709,13 +725,15 @@ pub trait ConnectorActions: Connector {
             .connector
             .get_connector_integration();
         let request = self.get_payout_request(Some(connector_payout_id), payout_type, payment_info);
+        let conf = Settings::new().unwrap();
+        let api_client = get_api_client(&conf);
         let tx: oneshot::Sender<()> = oneshot::channel().0;
 
         let app_state = Box::pin(routes::AppState::with_storage(
-            Settings::new().unwrap(),
+            conf,
             StorageImpl::PostgresqlTest,
             tx,
-            Box::new(services::MockApiClient),
+            api_client,
         ))
         .await;
         let state = Arc::new(app_state)
@@ -800,13 +818,15 @@ pub trait ConnectorActions: Connector {
             .connector
             .get_connector_integration();
         let request = self.get_payout_request(None, payout_type, payment_info);
+        let conf = Settings::new().unwrap();
+        let api_client = get_api_client(&conf);
         let tx = oneshot::channel().0;
 
         let app_state = Box::pin(routes::AppState::with_storage(
-            Settings::new().unwrap(),
+            conf,
             StorageImpl::PostgresqlTest,
             tx,
-            Box::new(services::MockApiClient),
+            api_client,
         ))
         .await;
         let state = Arc::new(app_state)
@@ -837,13 +857,14 @@ async fn call_connector<
     integration: BoxedConnectorIntegrationInterface<T, ResourceCommonData, Req, Resp>,
 ) -> Result<RouterData<T, Req, Resp>, Report<ConnectorError>> {
     let conf = Settings::new().unwrap();
+    let api_client = get_api_client(&conf);
     let tx: oneshot::Sender<()> = oneshot::channel().0;
 
     let app_state = Box::pin(routes::AppState::with_storage(
         conf,
         StorageImpl::PostgresqlTest,
         tx,
-        Box::new(services::MockApiClient),
+        api_client,
     ))
     .await;
     let state = Arc::new(app_state)
@@ -859,6 +880,74 @@ async fn call_connector<
     .await
 }
 
+/// Environment variable selecting whether the connector interactions are recorded to (`record`)
+/// or replayed from (`replay`) the fixtures, or the connector is called without fixtures (`live`).
+/// When unset, the fixture of the test is replayed if it has been recorded, and the connector is
+/// called otherwise, except on CI where the fixture is required.
+const FIXTURE_MODE_ENV: &str = "CONNECTOR_FIXTURE_MODE";
+const LIVE_FIXTURE_MODE: &str = "live";
+/// Environment variable set by CI providers, such as GitHub Actions
+const CI_ENV: &str = "CI";
+
+static FIXTURE_CLIENTS: OnceLock<Mutex<HashMap<PathBuf, services::FixtureApiClient>>> =
+    OnceLock::new();
+
+/// Returns the api client for the current test. The fixture of a test is named after the test,
+/// for example `fixtures/stripe/should_only_authorize_payment.json`, and its client is shared by
+/// all the connector calls made in the test so that they are recorded to the same fixture.
+fn get_api_client(conf: &Settings) -> Box<dyn services::ApiClient> {
+    let proxy_client = Box::new(
+        services::ProxyClient::new(
+            conf.proxy.clone(),
+            services::proxy_bypass_urls(
+                conf.key_manager.get_inner(),
+                &conf.locker,
+                &conf.proxy.bypass_proxy_urls,
+            ),
+        )
+        .unwrap(),
+    );
+
+    let test_name = std::thread::current()
+        .name()
+        .map(ToOwned::to_owned)
+        .expect("Tests are expected to run on a thread named after the test");
+    let fixture_path = test_name
+        .split("::")
+        .fold(
+            PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/connectors/fixtures"),
+            |path, segment| path.join(segment),
+        )
+        .with_extension("json");
+
+    let mode = match std::env::var(FIXTURE_MODE_ENV) {
+        Ok(mode) if mode == LIVE_FIXTURE_MODE => return proxy_client,
+        Ok(mode) => services::FixtureMode::from_str(&mode).unwrap(),
+        Err(_) if fixture_path.exists() || std::env::var_os(CI_ENV).is_some() => {
+            services::FixtureMode::Replay
+        }
+        Err(_) => return proxy_client,
+    };
+    // Fail with a clear message rather than silently calling the connector, so that a test
+    // without a fixture does not pass on CI by reaching the connector sandbox
+    assert!(
+        mode != services::FixtureMode::Replay || fixture_path.exists(),
+        "No fixture has been recorded for `{test_name}` at `{}`. Record it by running the test \
+         with `{FIXTURE_MODE_ENV}=record`, or run it against the connector with \
+         `{FIXTURE_MODE_ENV}={LIVE_FIXTURE_MODE}`.",
+        fixture_path.display()
+    );
+
+    let mut clients = FIXTURE_CLIENTS
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap();
+    let client = clients.entry(fixture_path.clone()).or_insert_with(|| {
+        services::FixtureApiClient::new(proxy_client, mode, fixture_path).unwrap()
+    });
+    Box::new(client.clone())
+}
+
 pub struct MockConfig {
     pub address: Option<String>,
     pub mocks: Vec<Mock>,
@@ -945,6 +1034,8 @@ impl Default for PaymentAuthorizeType {
             authentication_data: None,
             customer_acceptance: None,
             charges: None,
+            split_payments: None,
+            installment_plan: None,
             integrity_object: None,
             merchant_order_reference_id: None,
             shipping_cost: None,
@@ -1031,6 +1122,7 @@ impl Default for PaymentRefundType {
             connector_refund_id: None,
             browser_info: None,
             charges: None,
+            split_refunds: None,
             integrity_object: None,
         };
         Self(data)
@@ -442,6 +442,8 @@ async fn payments_create_core() {
         payment_method_status: None,
         updated: None,
         charges: None,
+        split_payments: None,
+        installment_plan: None,
         frm_metadata: None,
         merchant_order_reference_id: None,
         order_tax_amount: None,
@@ -701,6 +703,8 @@ async fn payments_create_core_adyen_no_redirect() {
             payment_method_status: None,
             updated: None,
             charges: None,
+            split_payments: None,
+            installment_plan: None,
             frm_metadata: None,
             merchant_order_reference_id: None,
             order_tax_amount: None,
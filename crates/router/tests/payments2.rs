@@ -203,6 +203,8 @@ async fn payments_create_core() {
         payment_method_status: None,
         updated: None,
         charges: None,
+        split_payments: None,
+        installment_plan: None,
         frm_metadata: None,
         merchant_order_reference_id: None,
         order_tax_amount: None,
@@ -470,6 +472,8 @@ async fn payments_create_core_adyen_no_redirect() {
             payment_method_id: None,
             payment_method_status: None,
             updated: None,
+            split_payments: None,
+            installment_plan: None,
             charges: None,
             frm_metadata: None,
             merchant_order_reference_id: None,
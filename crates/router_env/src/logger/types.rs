@@ -89,6 +89,16 @@ pub enum Flow {
     MerchantConnectorsList,
     /// Merchant Transfer Keys
     MerchantTransferKey,
+    /// Merchant storage scheme migration create flow.
+    StorageSchemeMigrationCreate,
+    /// Merchant storage scheme migration retrieve flow.
+    StorageSchemeMigrationRetrieve,
+    /// Merchant storage scheme migration rollback flow.
+    StorageSchemeMigrationRollback,
+    /// Merchant account updater toggle flow.
+    AccountUpdaterToggle,
+    /// Merchant account updater retrieve flow.
+    AccountUpdaterRetrieve,
     /// ConfigKey create flow.
     ConfigKeyCreate,
     /// ConfigKey fetch flow.
@@ -117,6 +127,30 @@ pub enum Flow {
     MandatesRevoke,
     /// Mandates list flow.
     MandatesList,
+    /// Subscription plan create flow.
+    SubscriptionPlanCreate,
+    /// Subscription plan retrieve flow.
+    SubscriptionPlanRetrieve,
+    /// Subscription plan update flow.
+    SubscriptionPlanUpdate,
+    /// Subscription plan list flow.
+    SubscriptionPlanList,
+    /// Subscription create flow.
+    SubscriptionCreate,
+    /// Subscription retrieve flow.
+    SubscriptionRetrieve,
+    /// Subscription list flow.
+    SubscriptionList,
+    /// Subscription cancel flow.
+    SubscriptionCancel,
+    /// Invoice create flow.
+    InvoiceCreate,
+    /// Invoice retrieve flow.
+    InvoiceRetrieve,
+    /// Invoice list flow.
+    InvoiceList,
+    /// Invoice void flow.
+    InvoiceVoid,
     /// Payment methods create flow.
     PaymentMethodsCreate,
     /// Payment methods migrate flow.
@@ -198,6 +232,15 @@ pub enum Flow {
     PayoutsAccounts,
     /// Payout link initiate flow
     PayoutLinkInitiate,
+    #[cfg(feature = "payouts")]
+    /// Payout batch create flow
+    PayoutBatchCreate,
+    #[cfg(feature = "payouts")]
+    /// Payout batch retrieve flow
+    PayoutBatchRetrieve,
+    #[cfg(feature = "payouts")]
+    /// Payout batch results retrieve flow
+    PayoutBatchResultsRetrieve,
     /// Payments Redirect flow
     PaymentsRedirect,
     /// Payemnts Complete Authorize Flow
@@ -216,6 +259,12 @@ pub enum Flow {
     RefundsFilters,
     /// Refunds aggregates flow
     RefundsAggregate,
+    /// Refund batch create flow
+    RefundBatchCreate,
+    /// Refund batch retrieve flow
+    RefundBatchRetrieve,
+    /// Refund batch report flow
+    RefundBatchReport,
     // Retrieve forex flow.
     RetrieveForexFlow,
     /// Toggles recon service for a merchant.
@@ -260,6 +309,12 @@ pub enum Flow {
     ToggleBlocklistGuard,
     /// Incoming Webhook Receive
     IncomingWebhookReceive,
+    /// List incoming webhooks from the archive
+    IncomingWebhookList,
+    /// Retrieve an incoming webhook from the archive
+    IncomingWebhookRetrieve,
+    /// Reprocess an archived incoming webhook
+    IncomingWebhookReprocess,
     /// Validate payment method flow
     ValidatePaymentMethod,
     /// API Key create flow
@@ -270,6 +325,8 @@ pub enum Flow {
     ApiKeyUpdate,
     /// API Key revoke flow
     ApiKeyRevoke,
+    /// API Key rotate flow
+    ApiKeyRotate,
     /// API Key list flow
     ApiKeyList,
     /// Dispute Retrieve flow
@@ -298,8 +355,12 @@ pub enum Flow {
     DisputesAggregate,
     /// Retrieve Dispute Evidence flow
     RetrieveDisputeEvidence,
+    /// Create Dispute Evidence Draft flow
+    CreateDisputeEvidenceDraft,
     /// Invalidate cache flow
     CacheInvalidate,
+    /// Retrieve cache stats flow
+    CacheStatsRetrieve,
     /// Payment Link Retrieve flow
     PaymentLinkRetrieve,
     /// payment Link Initiate flow
@@ -310,6 +371,16 @@ pub enum Flow {
     PaymentLinkList,
     /// Payment Link Status
     PaymentLinkStatus,
+    /// Reusable Payment Link Create flow
+    ReusablePaymentLinkCreate,
+    /// Reusable Payment Link Retrieve flow
+    ReusablePaymentLinkRetrieve,
+    /// Reusable Payment Link Update flow
+    ReusablePaymentLinkUpdate,
+    /// Reusable Payment Link List flow
+    ReusablePaymentLinkList,
+    /// Reusable Payment Link Initiate flow
+    ReusablePaymentLinkInitiate,
     /// Create a profile
     ProfileCreate,
     /// Update a profile
@@ -492,6 +563,30 @@ pub enum Flow {
     WebhookEventDeliveryAttemptList,
     /// Manually retry the delivery for a webhook event
     WebhookEventDeliveryRetry,
+    /// Create a webhook endpoint
+    WebhookEndpointCreate,
+    /// List webhook endpoints of a business profile
+    WebhookEndpointList,
+    /// Retrieve a webhook endpoint
+    WebhookEndpointRetrieve,
+    /// Update a webhook endpoint
+    WebhookEndpointUpdate,
+    /// Delete a webhook endpoint
+    WebhookEndpointDelete,
+    /// Rotate the secret of a webhook endpoint
+    WebhookEndpointSecretRotate,
+    /// Retrieve the delivery health of a webhook endpoint
+    WebhookEndpointHealthRetrieve,
+    /// Re-enable a suspended webhook endpoint
+    WebhookEndpointReenable,
+    /// Rotate the webhook signing secret of a business profile
+    ProfileWebhookSecretRotate,
+    /// Retrieve the success rate alert rules of a business profile
+    SuccessRateAlertConfigRetrieve,
+    /// Create or update the success rate alert rules of a business profile
+    SuccessRateAlertConfigUpdate,
+    /// Delete the success rate alert rules of a business profile
+    SuccessRateAlertConfigDelete,
     /// Retrieve status of the Poll
     RetrievePollStatus,
     /// Toggles the extended card info feature in profile level
@@ -82,3 +82,39 @@ impl Default for OutgoingWebhookRetryProcessTrackerMapping {
         }
     }
 }
+
+/// Configuration for retrying failed recurring payments. The schedule which is used depends on
+/// the decision configured in GSM for the decline.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PaymentRecoveryProcessTrackerMapping {
+    /// Retry schedule for declines with the `retry` GSM decision, which are usually transient
+    /// errors that can be retried shortly.
+    pub retry_mapping: RetryMapping,
+
+    /// Retry schedule for declines with the `requeue` GSM decision, such as insufficient funds,
+    /// which are more likely to succeed after a few days.
+    pub requeue_mapping: RetryMapping,
+
+    /// Maximum number of retries made for a failed payment, across both schedules.
+    pub max_retries_count: i32,
+}
+
+impl Default for PaymentRecoveryProcessTrackerMapping {
+    fn default() -> Self {
+        Self {
+            retry_mapping: RetryMapping {
+                // 1st retry happens after 1 hour
+                start_after: 60 * 60,
+                // 2nd and 3rd retries happen at intervals of 6 hours each
+                frequencies: vec![(60 * 60 * 6, 2)],
+            },
+            requeue_mapping: RetryMapping {
+                // 1st retry happens after 1 day
+                start_after: 60 * 60 * 24,
+                // 2nd and 3rd retries happen at intervals of 3 days each
+                frequencies: vec![(60 * 60 * 24 * 3, 2)],
+            },
+            max_retries_count: 3,
+        }
+    }
+}
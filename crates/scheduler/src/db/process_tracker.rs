@@ -32,11 +32,32 @@ pub trait ProcessTrackerInterface: Send + Sync + 'static {
         task_update: storage::ProcessTrackerUpdate,
     ) -> CustomResult<usize, errors::StorageError>;
 
+    /// Updates the process only if it is in one of the `statuses` and has not been updated since
+    /// it was read, returning the number of updated processes
+    async fn update_process_if_unchanged(
+        &self,
+        this: storage::ProcessTracker,
+        statuses: Vec<storage_enums::ProcessTrackerStatus>,
+        process: storage::ProcessTrackerUpdate,
+    ) -> CustomResult<usize, errors::StorageError>;
+
     async fn insert_process(
         &self,
         new: storage::ProcessTrackerNew,
     ) -> CustomResult<storage::ProcessTracker, errors::StorageError>;
 
+    async fn update_process_if_unchanged(
+        &self,
+        this: storage::ProcessTracker,
+        statuses: Vec<storage_enums::ProcessTrackerStatus>,
+        process: storage::ProcessTrackerUpdate,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update_if_unchanged(&conn, statuses, process)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
     async fn reset_process(
         &self,
         this: storage::ProcessTracker,
@@ -256,11 +277,37 @@ impl ProcessTrackerInterface for MockDb {
 
     async fn update_process(
         &self,
-        _this: storage::ProcessTracker,
-        _process: storage::ProcessTrackerUpdate,
+        this: storage::ProcessTracker,
+        process: storage::ProcessTrackerUpdate,
     ) -> CustomResult<storage::ProcessTracker, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut processes = self.processes.lock().await;
+        let process_to_update = processes
+            .iter_mut()
+            .find(|existing_process| existing_process.id == this.id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No process tracker entry available with id = {}",
+                this.id
+            )))?;
+        *process_to_update = process.apply_changeset(process_to_update.clone());
+
+        Ok(process_to_update.clone())
+    }
+
+    async fn update_process_if_unchanged(
+        &self,
+        this: storage::ProcessTracker,
+        statuses: Vec<storage_enums::ProcessTrackerStatus>,
+        process: storage::ProcessTrackerUpdate,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let mut processes = self.processes.lock().await;
+        let Some(process_to_update) = processes.iter_mut().find(|existing_process| {
+            existing_process.id == this.id && statuses.contains(&existing_process.status)
+        }) else {
+            return Ok(0);
+        };
+        *process_to_update = process.apply_changeset(process_to_update.clone());
+
+        Ok(1)
     }
 
     async fn reset_process(
@@ -274,20 +321,36 @@ impl ProcessTrackerInterface for MockDb {
 
     async fn retry_process(
         &self,
-        _this: storage::ProcessTracker,
-        _schedule_time: PrimitiveDateTime,
+        this: storage::ProcessTracker,
+        schedule_time: PrimitiveDateTime,
     ) -> CustomResult<(), errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let retry_count = this.retry_count + 1;
+        self.update_process(
+            this,
+            storage::ProcessTrackerUpdate::StatusRetryUpdate {
+                status: storage_enums::ProcessTrackerStatus::Pending,
+                retry_count,
+                schedule_time,
+            },
+        )
+        .await?;
+        Ok(())
     }
 
     async fn finish_process_with_business_status(
         &self,
-        _this: storage::ProcessTracker,
-        _business_status: &'static str,
+        this: storage::ProcessTracker,
+        business_status: &'static str,
     ) -> CustomResult<(), errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        self.update_process(
+            this,
+            storage::ProcessTrackerUpdate::StatusUpdate {
+                status: storage_enums::ProcessTrackerStatus::Finish,
+                business_status: Some(String::from(business_status)),
+            },
+        )
+        .await?;
+        Ok(())
     }
 
     async fn process_tracker_update_process_status_by_ids(
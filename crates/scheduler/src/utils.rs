@@ -354,6 +354,25 @@ pub fn get_outgoing_webhook_retry_schedule_time(
     }
 }
 
+/// Get the delay before the next retry of a failed recurring payment, given the number of retries
+/// which have already been made. Returns `None` once `max_retries_count` retries have been made.
+pub fn get_payment_recovery_schedule_time(
+    retry_mapping: &process_data::RetryMapping,
+    max_retries_count: i32,
+    retry_count: i32,
+) -> Option<i32> {
+    if retry_count >= max_retries_count {
+        return None;
+    }
+
+    // For first try, get the `start_after` time
+    if retry_count == 0 {
+        Some(retry_mapping.start_after)
+    } else {
+        get_delay(retry_count, &retry_mapping.frequencies)
+    }
+}
+
 /// Get the delay based on the retry count
 pub fn get_delay<'a>(
     retry_count: i32,
@@ -440,4 +459,30 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_get_payment_recovery_schedule_time() {
+        let retry_mapping = process_data::RetryMapping {
+            start_after: 3600,
+            frequencies: vec![(21600, 2), (86400, 5)],
+        };
+
+        let retry_counts_and_expected_delays = [
+            (0, Some(3600)),
+            (1, Some(21600)),
+            (2, Some(21600)),
+            (3, Some(86400)),
+            (4, None),
+            (5, None),
+        ];
+
+        for (retry_count, expected_delay) in retry_counts_and_expected_delays {
+            let delay = get_payment_recovery_schedule_time(&retry_mapping, 4, retry_count);
+
+            assert_eq!(
+                delay, expected_delay,
+                "Delay and expected delay differ for `retry_count` = {retry_count}"
+            );
+        }
+    }
 }
@@ -17,3 +17,6 @@ gauge_metric!(IN_MEMORY_CACHE_ENTRY_COUNT, GLOBAL_METER);
 counter_metric!(IN_MEMORY_CACHE_HIT, GLOBAL_METER);
 counter_metric!(IN_MEMORY_CACHE_MISS, GLOBAL_METER);
 counter_metric!(IN_MEMORY_CACHE_EVICTION_COUNT, GLOBAL_METER);
+counter_metric!(IN_MEMORY_CACHE_STALE_HIT, GLOBAL_METER);
+counter_metric!(IN_MEMORY_CACHE_COALESCED_LOAD, GLOBAL_METER);
+gauge_metric!(IN_MEMORY_CACHE_WEIGHTED_SIZE, GLOBAL_METER);
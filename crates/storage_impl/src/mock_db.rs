@@ -60,6 +60,9 @@ pub struct MockDb {
     pub user_key_store: Arc<Mutex<Vec<store::user_key_store::UserKeyStore>>>,
     pub user_authentication_methods:
         Arc<Mutex<Vec<store::user_authentication_method::UserAuthenticationMethod>>>,
+    pub webhook_endpoints: Arc<Mutex<Vec<store::webhook_endpoint::WebhookEndpoint>>>,
+    pub incoming_webhook_archives:
+        Arc<Mutex<Vec<store::incoming_webhook_archive::IncomingWebhookArchive>>>,
 }
 
 impl MockDb {
@@ -105,6 +108,8 @@ impl MockDb {
             roles: Default::default(),
             user_key_store: Default::default(),
             user_authentication_methods: Default::default(),
+            webhook_endpoints: Default::default(),
+            incoming_webhook_archives: Default::default(),
         })
     }
 }
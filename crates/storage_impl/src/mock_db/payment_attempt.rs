@@ -182,6 +182,7 @@ impl PaymentAttemptInterface for MockDb {
             organization_id: payment_attempt.organization_id,
             profile_id: payment_attempt.profile_id,
             connector_mandate_detail: payment_attempt.connector_mandate_detail,
+            installment_plan: payment_attempt.installment_plan,
         };
         payment_attempts.push(payment_attempt.clone());
         Ok(payment_attempt)
@@ -534,6 +534,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                     organization_id: payment_attempt.organization_id.clone(),
                     profile_id: payment_attempt.profile_id.clone(),
                     connector_mandate_detail: payment_attempt.connector_mandate_detail.clone(),
+                    installment_plan: payment_attempt.installment_plan.clone(),
                 };
 
                 let field = format!("pa_{}", created_attempt.attempt_id);
@@ -1459,6 +1460,7 @@ impl DataModelExt for PaymentAttempt {
             shipping_cost: self.net_amount.get_shipping_cost(),
             order_tax_amount: self.net_amount.get_order_tax_amount(),
             connector_mandate_detail: self.connector_mandate_detail,
+            installment_plan: self.installment_plan,
         }
     }
 
@@ -1535,6 +1537,7 @@ impl DataModelExt for PaymentAttempt {
             organization_id: storage_model.organization_id,
             profile_id: storage_model.profile_id,
             connector_mandate_detail: storage_model.connector_mandate_detail,
+            installment_plan: storage_model.installment_plan,
         }
     }
 }
@@ -1618,6 +1621,7 @@ impl DataModelExt for PaymentAttemptNew {
             shipping_cost: self.net_amount.get_shipping_cost(),
             order_tax_amount: self.net_amount.get_order_tax_amount(),
             connector_mandate_detail: self.connector_mandate_detail,
+            installment_plan: self.installment_plan,
         }
     }
 
@@ -1690,6 +1694,7 @@ impl DataModelExt for PaymentAttemptNew {
             organization_id: storage_model.organization_id,
             profile_id: storage_model.profile_id,
             connector_mandate_detail: storage_model.connector_mandate_detail,
+            installment_plan: storage_model.installment_plan,
         }
     }
 }
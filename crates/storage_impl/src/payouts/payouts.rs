@@ -126,6 +126,7 @@ impl<T: DatabaseStore> PayoutsInterface for KVRouterStore<T> {
                     payout_link_id: new.payout_link_id.clone(),
                     client_secret: new.client_secret.clone(),
                     priority: new.priority,
+                    organization_id: new.organization_id.clone(),
                 };
 
                 let redis_entry = kv::TypedSql {
@@ -941,6 +942,7 @@ impl DataModelExt for Payouts {
             payout_link_id: self.payout_link_id,
             client_secret: self.client_secret,
             priority: self.priority,
+            organization_id: self.organization_id,
         }
     }
 
@@ -970,6 +972,7 @@ impl DataModelExt for Payouts {
             payout_link_id: storage_model.payout_link_id,
             client_secret: storage_model.client_secret,
             priority: storage_model.priority,
+            organization_id: storage_model.organization_id,
         }
     }
 }
@@ -1002,6 +1005,7 @@ impl DataModelExt for PayoutsNew {
             payout_link_id: self.payout_link_id,
             client_secret: self.client_secret,
             priority: self.priority,
+            organization_id: self.organization_id,
         }
     }
 
@@ -1031,6 +1035,7 @@ impl DataModelExt for PayoutsNew {
             payout_link_id: storage_model.payout_link_id,
             client_secret: storage_model.client_secret,
             priority: storage_model.priority,
+            organization_id: storage_model.organization_id,
         }
     }
 }
@@ -1,4 +1,14 @@
-use std::{any::Any, borrow::Cow, fmt::Debug, sync::Arc};
+use std::{
+    any::Any,
+    borrow::Cow,
+    collections::HashMap,
+    fmt::Debug,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc, Mutex, PoisonError,
+    },
+    time::{Duration, Instant},
+};
 
 use common_utils::{
     errors::{self, CustomResult},
@@ -6,10 +16,11 @@ use common_utils::{
 };
 use dyn_clone::DynClone;
 use error_stack::{Report, ResultExt};
-use moka::future::Cache as MokaCache;
+use moka::{future::Cache as MokaCache, notification::RemovalCause};
 use once_cell::sync::Lazy;
 use redis_interface::{errors::RedisError, RedisConnectionPool, RedisValue};
 use router_env::{
+    logger,
     metrics::add_attributes,
     tracing::{self, instrument},
 };
@@ -29,20 +40,44 @@ const CACHE_TTL: u64 = 30 * 60;
 /// Time to idle 10 mins
 const CACHE_TTI: u64 = 10 * 60;
 
+/// Entries older than 20 mins are served stale while a single request revalidates them
+const CACHE_STALE_AFTER: u64 = 20 * 60;
+
 /// Max Capacity of Cache in MB
 const MAX_CAPACITY: u64 = 30;
 
 /// Config Cache with time_to_live as 30 mins and time_to_idle as 10 mins.
-pub static CONFIG_CACHE: Lazy<Cache> =
-    Lazy::new(|| Cache::new("CONFIG_CACHE", CACHE_TTL, CACHE_TTI, None));
+pub static CONFIG_CACHE: Lazy<Cache> = Lazy::new(|| {
+    Cache::new(
+        "CONFIG_CACHE",
+        CACHE_TTL,
+        CACHE_TTI,
+        Some(CACHE_STALE_AFTER),
+        None,
+    )
+});
 
 /// Accounts cache with time_to_live as 30 mins and size limit
-pub static ACCOUNTS_CACHE: Lazy<Cache> =
-    Lazy::new(|| Cache::new("ACCOUNTS_CACHE", CACHE_TTL, CACHE_TTI, Some(MAX_CAPACITY)));
+pub static ACCOUNTS_CACHE: Lazy<Cache> = Lazy::new(|| {
+    Cache::new(
+        "ACCOUNTS_CACHE",
+        CACHE_TTL,
+        CACHE_TTI,
+        Some(CACHE_STALE_AFTER),
+        Some(MAX_CAPACITY),
+    )
+});
 
 /// Routing Cache
-pub static ROUTING_CACHE: Lazy<Cache> =
-    Lazy::new(|| Cache::new("ROUTING_CACHE", CACHE_TTL, CACHE_TTI, Some(MAX_CAPACITY)));
+pub static ROUTING_CACHE: Lazy<Cache> = Lazy::new(|| {
+    Cache::new(
+        "ROUTING_CACHE",
+        CACHE_TTL,
+        CACHE_TTI,
+        Some(CACHE_STALE_AFTER),
+        Some(MAX_CAPACITY),
+    )
+});
 
 /// 3DS Decision Manager Cache
 pub static DECISION_MANAGER_CACHE: Lazy<Cache> = Lazy::new(|| {
@@ -50,17 +85,32 @@ pub static DECISION_MANAGER_CACHE: Lazy<Cache> = Lazy::new(|| {
         "DECISION_MANAGER_CACHE",
         CACHE_TTL,
         CACHE_TTI,
+        Some(CACHE_STALE_AFTER),
         Some(MAX_CAPACITY),
     )
 });
 
 /// Surcharge Cache
-pub static SURCHARGE_CACHE: Lazy<Cache> =
-    Lazy::new(|| Cache::new("SURCHARGE_CACHE", CACHE_TTL, CACHE_TTI, Some(MAX_CAPACITY)));
+pub static SURCHARGE_CACHE: Lazy<Cache> = Lazy::new(|| {
+    Cache::new(
+        "SURCHARGE_CACHE",
+        CACHE_TTL,
+        CACHE_TTI,
+        Some(CACHE_STALE_AFTER),
+        Some(MAX_CAPACITY),
+    )
+});
 
 /// CGraph Cache
-pub static CGRAPH_CACHE: Lazy<Cache> =
-    Lazy::new(|| Cache::new("CGRAPH_CACHE", CACHE_TTL, CACHE_TTI, Some(MAX_CAPACITY)));
+pub static CGRAPH_CACHE: Lazy<Cache> = Lazy::new(|| {
+    Cache::new(
+        "CGRAPH_CACHE",
+        CACHE_TTL,
+        CACHE_TTI,
+        Some(CACHE_STALE_AFTER),
+        Some(MAX_CAPACITY),
+    )
+});
 
 /// PM Filter CGraph Cache
 pub static PM_FILTERS_CGRAPH_CACHE: Lazy<Cache> = Lazy::new(|| {
@@ -68,6 +118,7 @@ pub static PM_FILTERS_CGRAPH_CACHE: Lazy<Cache> = Lazy::new(|| {
         "PM_FILTERS_CGRAPH_CACHE",
         CACHE_TTL,
         CACHE_TTI,
+        Some(CACHE_STALE_AFTER),
         Some(MAX_CAPACITY),
     )
 });
@@ -78,6 +129,7 @@ pub static SUCCESS_BASED_DYNAMIC_ALGORITHM_CACHE: Lazy<Cache> = Lazy::new(|| {
         "SUCCESS_BASED_DYNAMIC_ALGORITHM_CACHE",
         CACHE_TTL,
         CACHE_TTI,
+        Some(CACHE_STALE_AFTER),
         Some(MAX_CAPACITY),
     )
 });
@@ -146,7 +198,68 @@ dyn_clone::clone_trait_object!(Cacheable);
 
 pub struct Cache {
     name: &'static str,
-    inner: MokaCache<String, Arc<dyn Cacheable>>,
+    inner: MokaCache<String, CacheEntry>,
+    stale_after: Option<Duration>,
+    counters: Arc<CacheCounters>,
+    /// Locks used to coalesce concurrent loads of the same key, so that only one caller populates
+    /// the entry while the others wait for it to be available
+    in_flight_loads: Mutex<HashMap<String, Arc<tokio::sync::Mutex<()>>>>,
+}
+
+#[derive(Clone)]
+struct CacheEntry {
+    value: Arc<dyn Cacheable>,
+    /// Approximate size of the entry in bytes, used by the weigher to bound the cache memory
+    weight: u32,
+    /// Instant after which the entry is served stale until it is revalidated
+    stale_at: Option<Instant>,
+    /// Set while a caller is revalidating the stale entry, so that the other callers keep
+    /// serving the stale value instead of hitting the data store
+    is_revalidating: Arc<AtomicBool>,
+}
+
+#[derive(Debug, Default)]
+struct CacheCounters {
+    hits: AtomicU64,
+    stale_hits: AtomicU64,
+    misses: AtomicU64,
+    coalesced_loads: AtomicU64,
+    evictions: AtomicU64,
+}
+
+/// Point-in-time statistics of an in-memory cache
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct CacheStats {
+    pub name: &'static str,
+    pub entry_count: u64,
+    /// Approximate memory held by the cache entries, in bytes
+    pub weighted_size: u64,
+    /// Memory bound of the cache, in bytes
+    pub max_capacity: Option<u64>,
+    pub hits: u64,
+    pub stale_hits: u64,
+    pub misses: u64,
+    pub coalesced_loads: u64,
+    pub evictions: u64,
+}
+
+enum CacheLookup<T> {
+    Fresh(T),
+    Stale {
+        value: T,
+        /// Present only for the caller that has been elected to revalidate the entry
+        revalidation: Option<RevalidationGuard>,
+    },
+}
+
+/// Held by the caller revalidating a stale entry. Dropping the guard without replacing the entry
+/// allows another caller to attempt the revalidation.
+struct RevalidationGuard(Arc<AtomicBool>);
+
+impl Drop for RevalidationGuard {
+    fn drop(&mut self) {
+        self.0.store(false, Ordering::Release);
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -172,15 +285,23 @@ impl Cache {
     /// `name`        : Cache type name to be used as an attribute in metrics
     /// `time_to_live`: Time in seconds before an object is stored in a caching system before it’s deleted
     /// `time_to_idle`: Time in seconds before a `get` or `insert` operation an object is stored in a caching system before it's deleted
+    /// `stale_after` : Time in seconds after which an object is served stale while a single caller revalidates it
     /// `max_capacity`: Max size in MB's that the cache can hold
     pub fn new(
         name: &'static str,
         time_to_live: u64,
         time_to_idle: u64,
+        stale_after: Option<u64>,
         max_capacity: Option<u64>,
     ) -> Self {
+        let counters = Arc::new(CacheCounters::default());
+        let eviction_counters = counters.clone();
+
         // Record the metrics of manual invalidation of cache entry by the application
-        let eviction_listener = move |_, _, cause| {
+        let eviction_listener = move |_, _, cause: RemovalCause| {
+            if cause.was_evicted() {
+                eviction_counters.evictions.fetch_add(1, Ordering::Relaxed);
+            }
             metrics::IN_MEMORY_CACHE_EVICTION_COUNT.add(
                 &metrics::CONTEXT,
                 1,
@@ -191,8 +312,9 @@ impl Cache {
             );
         };
         let mut cache_builder = MokaCache::builder()
-            .time_to_live(std::time::Duration::from_secs(time_to_live))
-            .time_to_idle(std::time::Duration::from_secs(time_to_idle))
+            .time_to_live(Duration::from_secs(time_to_live))
+            .time_to_idle(Duration::from_secs(time_to_idle))
+            .weigher(|_key: &String, entry: &CacheEntry| entry.weight)
             .eviction_listener(eviction_listener);
 
         if let Some(capacity) = max_capacity {
@@ -202,11 +324,45 @@ impl Cache {
         Self {
             name,
             inner: cache_builder.build(),
+            stale_after: stale_after.map(Duration::from_secs),
+            counters,
+            in_flight_loads: Mutex::new(HashMap::new()),
         }
     }
 
+    /// Pushes the value into the cache, weighing it by its shallow size.
+    ///
+    /// Values owning heap allocations, such as `Arc`s, are underweighted. Use `push_with_size`
+    /// to weigh them by an estimate of the memory behind them, or `get_or_populate_in_memory` for
+    /// serializable values to have them weighed by their serialized size instead.
     pub async fn push<T: Cacheable>(&self, key: CacheKey, val: T) {
-        self.inner.insert(key.into(), Arc::new(val)).await;
+        self.insert(key.into(), val, std::mem::size_of::<T>()).await;
+    }
+
+    /// Pushes the value into the cache, weighing it by `size`, the estimated memory in bytes held
+    /// by the value including the data behind its pointers
+    pub async fn push_with_size<T: Cacheable>(&self, key: CacheKey, val: T, size: usize) {
+        self.insert(key.into(), val, size).await;
+    }
+
+    async fn push_serializable<T: Cacheable + serde::Serialize>(&self, key: String, val: T) {
+        let size = serde_json::to_vec(&val)
+            .map(|serialized| serialized.len())
+            .unwrap_or(std::mem::size_of::<T>());
+        self.insert(key, val, size).await;
+    }
+
+    async fn insert<T: Cacheable>(&self, key: String, val: T, size: usize) {
+        let weight = u32::try_from(key.len().saturating_add(size)).unwrap_or(u32::MAX);
+        let entry = CacheEntry {
+            value: Arc::new(val),
+            weight,
+            stale_at: self
+                .stale_after
+                .map(|stale_after| Instant::now() + stale_after),
+            is_revalidating: Arc::new(AtomicBool::new(false)),
+        };
+        self.inner.insert(key, entry).await;
     }
 
     pub async fn get_val<T: Clone + Cacheable>(&self, key: CacheKey) -> Option<T> {
@@ -214,24 +370,111 @@ impl Cache {
 
         // Add cache hit and cache miss metrics
         if val.is_some() {
-            metrics::IN_MEMORY_CACHE_HIT.add(
-                &metrics::CONTEXT,
-                1,
-                &add_attributes([("cache_type", self.name)]),
-            );
+            self.record_hit();
         } else {
-            metrics::IN_MEMORY_CACHE_MISS.add(
-                &metrics::CONTEXT,
-                1,
-                &add_attributes([("cache_type", self.name)]),
-            );
+            self.record_miss();
         }
 
-        let val = (*val?).as_any().downcast_ref::<T>().cloned();
+        let val = (*val?.value).as_any().downcast_ref::<T>().cloned();
 
         val
     }
 
+    /// Looks up the entry, electing the caller to revalidate it if it is stale and no other caller
+    /// is revalidating it already
+    async fn lookup<T: Clone + Cacheable>(&self, key: &str) -> Option<CacheLookup<T>> {
+        let Some(entry) = self.inner.get(key).await else {
+            self.record_miss();
+            return None;
+        };
+
+        let value = (*entry.value).as_any().downcast_ref::<T>().cloned()?;
+
+        if entry
+            .stale_at
+            .is_some_and(|stale_at| stale_at <= Instant::now())
+        {
+            self.record_stale_hit();
+            let revalidation = entry
+                .is_revalidating
+                .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+                .is_ok()
+                .then(|| RevalidationGuard(entry.is_revalidating.clone()));
+            Some(CacheLookup::Stale {
+                value,
+                revalidation,
+            })
+        } else {
+            self.record_hit();
+            Some(CacheLookup::Fresh(value))
+        }
+    }
+
+    /// Fetches the value without recording any metrics, irrespective of whether it is stale
+    async fn peek<T: Clone + Cacheable>(&self, key: &str) -> Option<T> {
+        let entry = self.inner.get(key).await?;
+        (*entry.value).as_any().downcast_ref::<T>().cloned()
+    }
+
+    fn acquire_load_lock(&self, key: &str) -> Arc<tokio::sync::Mutex<()>> {
+        let mut in_flight_loads = self
+            .in_flight_loads
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        in_flight_loads.entry(key.to_owned()).or_default().clone()
+    }
+
+    fn release_load_lock(&self, key: &str, load_lock: Arc<tokio::sync::Mutex<()>>) {
+        let mut in_flight_loads = self
+            .in_flight_loads
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        // The lock is only referenced by the map and the current caller, so no other caller is
+        // waiting on the load
+        if Arc::strong_count(&load_lock) <= 2 {
+            in_flight_loads.remove(key);
+        }
+    }
+
+    fn record_hit(&self) {
+        self.counters.hits.fetch_add(1, Ordering::Relaxed);
+        metrics::IN_MEMORY_CACHE_HIT.add(
+            &metrics::CONTEXT,
+            1,
+            &add_attributes([("cache_type", self.name)]),
+        );
+    }
+
+    fn record_stale_hit(&self) {
+        self.counters.stale_hits.fetch_add(1, Ordering::Relaxed);
+        metrics::IN_MEMORY_CACHE_STALE_HIT.add(
+            &metrics::CONTEXT,
+            1,
+            &add_attributes([("cache_type", self.name)]),
+        );
+    }
+
+    fn record_miss(&self) {
+        self.counters.misses.fetch_add(1, Ordering::Relaxed);
+        metrics::IN_MEMORY_CACHE_MISS.add(
+            &metrics::CONTEXT,
+            1,
+            &add_attributes([("cache_type", self.name)]),
+        );
+    }
+
+    fn record_coalesced_load(&self) {
+        self.counters
+            .coalesced_loads
+            .fetch_add(1, Ordering::Relaxed);
+        metrics::IN_MEMORY_CACHE_COALESCED_LOAD.add(
+            &metrics::CONTEXT,
+            1,
+            &add_attributes([("cache_type", self.name)]),
+        );
+    }
+
     /// Check if a key exists in cache
     pub async fn exists(&self, key: CacheKey) -> bool {
         self.inner.contains_key::<String>(&key.into())
@@ -263,7 +506,42 @@ impl Cache {
             self.get_entry_count(),
             &add_attributes([("cache_type", self.name)]),
         );
+        metrics::IN_MEMORY_CACHE_WEIGHTED_SIZE.observe(
+            &metrics::CONTEXT,
+            self.inner.weighted_size(),
+            &add_attributes([("cache_type", self.name)]),
+        );
     }
+
+    pub async fn get_stats(&self) -> CacheStats {
+        self.run_pending_tasks().await;
+
+        CacheStats {
+            name: self.name,
+            entry_count: self.get_entry_count(),
+            weighted_size: self.inner.weighted_size(),
+            max_capacity: self.inner.policy().max_capacity(),
+            hits: self.counters.hits.load(Ordering::Relaxed),
+            stale_hits: self.counters.stale_hits.load(Ordering::Relaxed),
+            misses: self.counters.misses.load(Ordering::Relaxed),
+            coalesced_loads: self.counters.coalesced_loads.load(Ordering::Relaxed),
+            evictions: self.counters.evictions.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Returns all the in-memory caches maintained by the application
+pub fn get_all_caches() -> [&'static Cache; 8] {
+    [
+        &CONFIG_CACHE,
+        &ACCOUNTS_CACHE,
+        &ROUTING_CACHE,
+        &DECISION_MANAGER_CACHE,
+        &SURCHARGE_CACHE,
+        &CGRAPH_CACHE,
+        &PM_FILTERS_CGRAPH_CACHE,
+        &SUCCESS_BASED_DYNAMIC_ALGORITHM_CACHE,
+    ]
 }
 
 #[instrument(skip_all)]
@@ -319,27 +597,61 @@ where
             RedisError::RedisConnectionError.into(),
         ))
         .attach_printable("Failed to get redis connection")?;
-    let cache_val = cache
-        .get_val::<T>(CacheKey {
-            key: key.to_string(),
-            prefix: redis.key_prefix.clone(),
-        })
-        .await;
-    if let Some(val) = cache_val {
-        Ok(val)
-    } else {
+    let cache_key: String = CacheKey {
+        key: key.to_string(),
+        prefix: redis.key_prefix.clone(),
+    }
+    .into();
+
+    match cache.lookup::<T>(&cache_key).await {
+        Some(CacheLookup::Fresh(val))
+        | Some(CacheLookup::Stale {
+            value: val,
+            revalidation: None,
+        }) => return Ok(val),
+        Some(CacheLookup::Stale {
+            value: stale_val,
+            revalidation: Some(_revalidation_guard),
+        }) => {
+            return match get_or_populate_redis(redis, key, fun).await {
+                Ok(val) => {
+                    cache.push_serializable(cache_key, val.clone()).await;
+                    Ok(val)
+                }
+                Err(error) => {
+                    logger::warn!(
+                        ?error,
+                        cache_type = cache.name(),
+                        "Failed to revalidate stale cache entry, serving the stale value"
+                    );
+                    Ok(stale_val)
+                }
+            };
+        }
+        None => {}
+    }
+
+    // Coalesce concurrent misses for the same key, so that only one of the callers hits the data
+    // store while the others wait for the entry to be populated
+    let load_lock = cache.acquire_load_lock(&cache_key);
+    let result: CustomResult<T, StorageError> = async {
+        let _load_guard = load_lock.lock().await;
+
+        if let Some(val) = cache.peek::<T>(&cache_key).await {
+            cache.record_coalesced_load();
+            return Ok(val);
+        }
+
         let val = get_or_populate_redis(redis, key, fun).await?;
         cache
-            .push(
-                CacheKey {
-                    key: key.to_string(),
-                    prefix: redis.key_prefix.clone(),
-                },
-                val.clone(),
-            )
+            .push_serializable(cache_key.clone(), val.clone())
             .await;
         Ok(val)
     }
+    .await;
+    cache.release_load_lock(&cache_key, load_lock);
+
+    result
 }
 
 #[instrument(skip_all)]
@@ -437,7 +749,7 @@ mod cache_tests {
 
     #[tokio::test]
     async fn construct_and_get_cache() {
-        let cache = Cache::new("test", 1800, 1800, None);
+        let cache = Cache::new("test", 1800, 1800, None, None);
         cache
             .push(
                 CacheKey {
@@ -460,7 +772,7 @@ mod cache_tests {
 
     #[tokio::test]
     async fn eviction_on_size_test() {
-        let cache = Cache::new("test", 2, 2, Some(0));
+        let cache = Cache::new("test", 2, 2, None, Some(0));
         cache
             .push(
                 CacheKey {
@@ -481,9 +793,82 @@ mod cache_tests {
         );
     }
 
+    #[tokio::test]
+    async fn eviction_on_weight_test() {
+        let cache = Cache::new("test", 1800, 1800, None, Some(1));
+        // The serialized value alone exceeds the capacity of 1 MB
+        cache
+            .push_serializable("prefix:key".to_string(), "a".repeat(2 * 1024 * 1024))
+            .await;
+        cache.run_pending_tasks().await;
+        assert_eq!(cache.peek::<String>("prefix:key").await, None);
+    }
+
+    #[tokio::test]
+    async fn eviction_on_estimated_size_test() {
+        let cache = Cache::new("test", 1800, 1800, None, Some(1));
+        // The shallow size of the `Arc` fits, but the estimated size exceeds the capacity of 1 MB
+        cache
+            .push_with_size(
+                CacheKey {
+                    key: "key".to_string(),
+                    prefix: "prefix".to_string(),
+                },
+                Arc::new("val".to_string()),
+                2 * 1024 * 1024,
+            )
+            .await;
+        cache.run_pending_tasks().await;
+        assert!(cache.peek::<Arc<String>>("prefix:key").await.is_none());
+    }
+
+    #[tokio::test]
+    async fn stale_entry_revalidated_by_single_caller() {
+        let cache = Cache::new("test", 1800, 1800, Some(0), None);
+        cache
+            .push(
+                CacheKey {
+                    key: "key".to_string(),
+                    prefix: "prefix".to_string(),
+                },
+                "val".to_string(),
+            )
+            .await;
+
+        let first_lookup = cache.lookup::<String>("prefix:key").await;
+        assert!(matches!(
+            first_lookup,
+            Some(CacheLookup::Stale {
+                revalidation: Some(_),
+                ..
+            })
+        ));
+
+        // Stale value is served without revalidation while another caller is revalidating
+        let second_lookup = cache.lookup::<String>("prefix:key").await;
+        assert!(matches!(
+            second_lookup,
+            Some(CacheLookup::Stale {
+                revalidation: None,
+                ..
+            })
+        ));
+
+        // Failed revalidation allows another caller to revalidate the entry
+        drop(first_lookup);
+        let third_lookup = cache.lookup::<String>("prefix:key").await;
+        assert!(matches!(
+            third_lookup,
+            Some(CacheLookup::Stale {
+                revalidation: Some(_),
+                ..
+            })
+        ));
+    }
+
     #[tokio::test]
     async fn invalidate_cache_for_key() {
-        let cache = Cache::new("test", 1800, 1800, None);
+        let cache = Cache::new("test", 1800, 1800, None, None);
         cache
             .push(
                 CacheKey {
@@ -514,7 +899,7 @@ mod cache_tests {
 
     #[tokio::test]
     async fn eviction_on_time_test() {
-        let cache = Cache::new("test", 2, 2, None);
+        let cache = Cache::new("test", 2, 2, None, None);
         cache
             .push(
                 CacheKey {
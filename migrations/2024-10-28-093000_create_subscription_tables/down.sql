@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS subscription_merchant_id_customer_id_index;
+
+DROP TABLE IF EXISTS subscription;
+
+DROP INDEX IF EXISTS subscription_plan_merchant_id_index;
+
+DROP TABLE IF EXISTS subscription_plan;
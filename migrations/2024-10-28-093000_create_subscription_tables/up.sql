@@ -0,0 +1,41 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS subscription_plan (
+    plan_id VARCHAR(64) NOT NULL PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    description VARCHAR(255),
+    amount BIGINT NOT NULL,
+    currency "Currency" NOT NULL,
+    billing_interval VARCHAR(32) NOT NULL,
+    interval_count INTEGER NOT NULL DEFAULT 1,
+    trial_period_days INTEGER,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    metadata JSONB,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS subscription_plan_merchant_id_index ON subscription_plan (merchant_id);
+
+CREATE TABLE IF NOT EXISTS subscription (
+    subscription_id VARCHAR(64) NOT NULL PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64) NOT NULL,
+    plan_id VARCHAR(64) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    mandate_id VARCHAR(64),
+    payment_method_id VARCHAR(64),
+    current_period_start TIMESTAMP NOT NULL,
+    current_period_end TIMESTAMP NOT NULL,
+    trial_end TIMESTAMP,
+    billing_cycle INTEGER NOT NULL DEFAULT 0,
+    latest_payment_id VARCHAR(64),
+    cancel_at_period_end BOOLEAN NOT NULL DEFAULT FALSE,
+    cancelled_at TIMESTAMP,
+    metadata JSONB,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS subscription_merchant_id_customer_id_index ON subscription (merchant_id, customer_id);
@@ -0,0 +1,9 @@
+-- Your SQL goes here
+ALTER TYPE "EventClass" ADD VALUE IF NOT EXISTS 'subscriptions';
+
+ALTER TYPE "EventObjectType" ADD VALUE IF NOT EXISTS 'subscription_details';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'subscription_created';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'subscription_renewed';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'subscription_payment_failed';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'subscription_cancelled';
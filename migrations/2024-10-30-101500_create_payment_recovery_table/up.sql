@@ -0,0 +1,25 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS payment_recovery (
+    recovery_id VARCHAR(64) NOT NULL PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    organization_id VARCHAR(32) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    payment_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64) NOT NULL,
+    subscription_id VARCHAR(64),
+    mandate_id VARCHAR(64),
+    payment_method_id VARCHAR(64),
+    connector VARCHAR(64),
+    amount BIGINT NOT NULL,
+    currency "Currency" NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    decline_category VARCHAR(32) NOT NULL,
+    attempt_count INTEGER NOT NULL DEFAULT 0,
+    latest_payment_id VARCHAR(64),
+    error_code VARCHAR(255),
+    error_message TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS payment_recovery_merchant_id_payment_id_index ON payment_recovery (merchant_id, payment_id);
@@ -0,0 +1,23 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS card_account_update (
+    update_id VARCHAR(64) NOT NULL PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64) NOT NULL,
+    payment_method_id VARCHAR(64) NOT NULL,
+    update_type VARCHAR(32) NOT NULL,
+    previous_last4 VARCHAR(4),
+    previous_expiry_month VARCHAR(2),
+    previous_expiry_year VARCHAR(4),
+    updated_last4 VARCHAR(4),
+    updated_expiry_month VARCHAR(2),
+    updated_expiry_year VARCHAR(4),
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS card_account_update_merchant_id_payment_method_id_index ON card_account_update (merchant_id, payment_method_id);
+
+ALTER TYPE "EventClass" ADD VALUE IF NOT EXISTS 'payment_methods';
+
+ALTER TYPE "EventObjectType" ADD VALUE IF NOT EXISTS 'payment_method_details';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'payment_method_updated';
@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS invoice_merchant_id_customer_id_index;
+
+DROP TABLE IF EXISTS invoice;
+
+DROP INDEX IF EXISTS reusable_payment_link_merchant_id_index;
+
+DROP TABLE IF EXISTS reusable_payment_link;
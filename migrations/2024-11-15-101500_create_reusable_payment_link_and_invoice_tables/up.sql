@@ -0,0 +1,42 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS reusable_payment_link (
+    link_id VARCHAR(64) NOT NULL PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    amount_type VARCHAR(32) NOT NULL,
+    amount BIGINT,
+    min_amount BIGINT,
+    max_amount BIGINT,
+    currency "Currency" NOT NULL,
+    description VARCHAR(255),
+    payment_link_config JSONB,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    metadata JSONB,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS reusable_payment_link_merchant_id_index ON reusable_payment_link (merchant_id);
+
+CREATE TABLE IF NOT EXISTS invoice (
+    invoice_id VARCHAR(64) NOT NULL PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    line_items JSONB NOT NULL,
+    currency "Currency" NOT NULL,
+    subtotal BIGINT NOT NULL,
+    tax_amount BIGINT NOT NULL,
+    total_amount BIGINT NOT NULL,
+    due_date TIMESTAMP NOT NULL,
+    payment_id VARCHAR(64) NOT NULL,
+    payment_link VARCHAR(255),
+    description VARCHAR(255),
+    metadata JSONB,
+    paid_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS invoice_merchant_id_customer_id_index ON invoice (merchant_id, customer_id);
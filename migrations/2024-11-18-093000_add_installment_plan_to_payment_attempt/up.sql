@@ -0,0 +1,5 @@
+-- Your SQL goes here
+ALTER TABLE
+    payment_attempt
+ADD
+    COLUMN IF NOT EXISTS installment_plan JSONB DEFAULT NULL;
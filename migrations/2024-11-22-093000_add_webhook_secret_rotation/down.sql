@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE webhook_endpoint
+    DROP COLUMN IF EXISTS previous_secret,
+    DROP COLUMN IF EXISTS previous_secret_expires_at;
+
+ALTER TABLE business_profile
+    DROP COLUMN IF EXISTS previous_payment_response_hash_key,
+    DROP COLUMN IF EXISTS previous_payment_response_hash_key_expires_at;
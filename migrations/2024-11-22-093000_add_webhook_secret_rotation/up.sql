@@ -0,0 +1,9 @@
+-- Your SQL goes here
+-- The previous payment response hash key remains valid for signing outgoing webhooks until it expires
+ALTER TABLE business_profile
+    ADD COLUMN IF NOT EXISTS previous_payment_response_hash_key VARCHAR(255),
+    ADD COLUMN IF NOT EXISTS previous_payment_response_hash_key_expires_at TIMESTAMP;
+
+ALTER TABLE webhook_endpoint
+    ADD COLUMN IF NOT EXISTS previous_secret VARCHAR(255),
+    ADD COLUMN IF NOT EXISTS previous_secret_expires_at TIMESTAMP;
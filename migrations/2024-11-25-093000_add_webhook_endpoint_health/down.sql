@@ -0,0 +1,10 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS events_merchant_id_webhook_endpoint_id_created_at_index;
+
+ALTER TABLE events DROP COLUMN IF EXISTS delivery_latency_ms;
+
+ALTER TABLE webhook_endpoint
+    DROP COLUMN IF EXISTS consecutive_failures,
+    DROP COLUMN IF EXISTS last_successful_delivery_at,
+    DROP COLUMN IF EXISTS last_failed_delivery_at,
+    DROP COLUMN IF EXISTS suspended_at;
@@ -0,0 +1,11 @@
+-- Your SQL goes here
+ALTER TABLE webhook_endpoint
+    ADD COLUMN IF NOT EXISTS consecutive_failures INTEGER NOT NULL DEFAULT 0,
+    ADD COLUMN IF NOT EXISTS last_successful_delivery_at TIMESTAMP,
+    ADD COLUMN IF NOT EXISTS last_failed_delivery_at TIMESTAMP,
+    ADD COLUMN IF NOT EXISTS suspended_at TIMESTAMP;
+
+-- Time taken by the merchant server to respond to a delivery attempt
+ALTER TABLE events ADD COLUMN IF NOT EXISTS delivery_latency_ms INTEGER;
+
+CREATE INDEX IF NOT EXISTS events_merchant_id_webhook_endpoint_id_created_at_index ON events (merchant_id, webhook_endpoint_id, created_at);
@@ -0,0 +1,21 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS incoming_webhook_archive (
+    webhook_id VARCHAR(64) NOT NULL PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    connector_id_or_name VARCHAR(64) NOT NULL,
+    connector_name VARCHAR(64),
+    merchant_connector_id VARCHAR(64),
+    profile_id VARCHAR(64),
+    request BYTEA NOT NULL,
+    event_type VARCHAR(64),
+    source_verified BOOLEAN,
+    processing_status VARCHAR(32) NOT NULL,
+    error_code VARCHAR(64),
+    error_message TEXT,
+    reprocess_count INTEGER NOT NULL DEFAULT 0,
+    last_reprocessed_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS incoming_webhook_archive_merchant_id_created_at_index ON incoming_webhook_archive (merchant_id, created_at);
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE webhook_endpoint DROP COLUMN IF EXISTS strict_ordering;
+
+ALTER TABLE events DROP COLUMN IF EXISTS sequence_number;
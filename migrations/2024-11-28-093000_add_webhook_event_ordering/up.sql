@@ -0,0 +1,5 @@
+-- Your SQL goes here
+-- Monotonically increasing sequence number of the event among the events of the same object
+ALTER TABLE events ADD COLUMN IF NOT EXISTS sequence_number BIGINT;
+
+ALTER TABLE webhook_endpoint ADD COLUMN IF NOT EXISTS strict_ordering BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,20 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS analytics_report_schedule (
+    report_schedule_id VARCHAR(64) NOT NULL PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    organization_id VARCHAR(32) NOT NULL,
+    profile_id VARCHAR(64),
+    name VARCHAR(255) NOT NULL,
+    domain VARCHAR(32) NOT NULL,
+    metric_request JSONB NOT NULL,
+    frequency VARCHAR(32) NOT NULL,
+    delivery_method VARCHAR(32) NOT NULL,
+    recipients TEXT[] NOT NULL DEFAULT '{}',
+    status VARCHAR(32) NOT NULL,
+    last_run_at TIMESTAMP,
+    next_run_at TIMESTAMP NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS analytics_report_schedule_merchant_id_index ON analytics_report_schedule (merchant_id);
@@ -0,0 +1,8 @@
+-- Your SQL goes here
+ALTER TYPE "EventClass" ADD VALUE IF NOT EXISTS 'alerts';
+
+ALTER TYPE "EventObjectType" ADD VALUE IF NOT EXISTS 'success_rate_alert_details';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'payment_success_rate_dropped';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'payment_success_rate_recovered';
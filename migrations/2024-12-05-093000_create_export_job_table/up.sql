@@ -0,0 +1,21 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS export_job (
+    export_id VARCHAR(64) NOT NULL PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    organization_id VARCHAR(32) NOT NULL,
+    profile_id VARCHAR(64),
+    export_type VARCHAR(32) NOT NULL,
+    analytics_domain VARCHAR(32),
+    format VARCHAR(32) NOT NULL,
+    constraints JSONB NOT NULL,
+    recipients TEXT[] NOT NULL DEFAULT '{}',
+    status VARCHAR(32) NOT NULL,
+    file_key VARCHAR(255),
+    row_count INTEGER,
+    error_message TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    completed_at TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS export_job_merchant_id_created_at_index ON export_job (merchant_id, created_at);
@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE subscription DROP COLUMN IF EXISTS billing_anchor;
@@ -0,0 +1,13 @@
+-- Your SQL goes here
+ALTER TABLE subscription ADD COLUMN IF NOT EXISTS billing_anchor TIMESTAMP;
+
+-- Subscriptions yet to be renewed, or with a trial, are anchored to the start of their first
+-- billing period. The others are anchored to the start of their current billing period.
+UPDATE subscription
+SET billing_anchor = COALESCE(
+        trial_end,
+        CASE WHEN billing_cycle = 0 THEN current_period_end ELSE current_period_start END
+    )
+WHERE billing_anchor IS NULL;
+
+ALTER TABLE subscription ALTER COLUMN billing_anchor SET NOT NULL;